@@ -0,0 +1,75 @@
+//! Mesure l'agrégation des scores d'un tour aux nombres d'objectifs démesurés : la moyenne entière
+//! ([`ScoringCalculator::calculate_average`]) face à une moyenne calculée en `f64`, puis une partie
+//! simulée entre deux bots à 10 000 objectifs par tour.
+//!
+//! Lancer avec `cargo bench --bench aggregation`.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use dual_game::controller::Difficulty;
+use dual_game::player::Player;
+use dual_game::rules::GameRules;
+use dual_game::scoring::ScoringCalculator;
+use dual_game::simulation;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Nombres d'objectifs par tour mesurés.
+const COUNTS: [usize; 3] = [10, 1_000, 10_000];
+
+/// Scores aléatoires reproductibles d'un tour de `count` objectifs.
+fn scores(count: usize) -> Vec<u32> {
+    let mut rng = StdRng::seed_from_u64(198);
+    (0..count).map(|_| rng.random_range(0..=150)).collect()
+}
+
+/// Moyenne arrondie à l'entier supérieur, calculée en `f64`.
+fn float_average(scores: &[u32]) -> u32 {
+    if scores.is_empty() {
+        return 0;
+    }
+    let sum: f64 = scores.iter().map(|&score| f64::from(score)).sum();
+    (sum / scores.len() as f64).ceil() as u32
+}
+
+/// Compare la moyenne entière et la moyenne en `f64` pour chaque nombre d'objectifs.
+fn averages(c: &mut Criterion) {
+    let mut group = c.benchmark_group("average");
+    for count in COUNTS {
+        let scores = scores(count);
+        group.bench_with_input(BenchmarkId::new("integer", count), &scores, |b, scores| {
+            b.iter(|| ScoringCalculator::calculate_average(black_box(scores)))
+        });
+        group.bench_with_input(BenchmarkId::new("float", count), &scores, |b, scores| {
+            b.iter(|| float_average(black_box(scores)))
+        });
+    }
+    group.finish();
+}
+
+/// Mesure une partie simulée entre deux bots à 10 000 objectifs par tour, au-delà de la limite
+/// par défaut.
+fn simulated_game(c: &mut Criterion) {
+    let players = [
+        Player::new(String::from("Alice"), 50, 75, 50),
+        Player::new(String::from("Bob"), 50, 75, 50),
+    ];
+    let rules = GameRules {
+        max_objectives: 10_000,
+        ..GameRules::default()
+    };
+    let mut group = c.benchmark_group("simulation");
+    group.sample_size(10);
+    group.bench_function("objectives_10000", |b| {
+        b.iter(|| {
+            let difficulties = [Difficulty::Normal, Difficulty::Normal];
+            let report = simulation::simulate(&players, difficulties, 1, 10_000, rules, Some(198));
+            black_box(report.unwrap())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, averages, simulated_game);
+criterion_main!(benches);
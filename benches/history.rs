@@ -0,0 +1,66 @@
+//! Compare le coût de l'historique d'une partie simulée de 200 manches, selon que chaque manche
+//! retient un clone des joueurs ou leurs instantanés ([`PlayerSnapshot`]).
+//!
+//! Lancer avec `cargo bench --bench history`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use dual_game::player::{Player, PlayerId, PlayerSnapshot};
+use dual_game::replay::{PlayerNames, RoundRecord};
+
+/// Nombre de manches de la partie simulée.
+const ROUNDS: u32 = 200;
+
+/// Joueurs de la partie simulée, à la vitalité suffisante pour tenir toutes les manches.
+fn players() -> Vec<Player> {
+    vec![
+        Player::builder("Maximilienne de la Tour")
+            .vitality(1000)
+            .avatar("🦊")
+            .build()
+            .unwrap(),
+        Player::builder("Bob").vitality(1000).build().unwrap(),
+    ]
+}
+
+/// Joue la partie simulée : le perdant de chaque manche perd un point de vitalité, puis `record`
+/// retient l'état des joueurs.
+fn simulate<T>(mut record: impl FnMut(u32, &[Player]) -> T) -> Vec<T> {
+    let mut players = players();
+    (1..=ROUNDS)
+        .map(|round| {
+            players[round as usize % 2].take_damage(1);
+            record(round, &players)
+        })
+        .collect()
+}
+
+/// Mesure l'historique retenant un clone des joueurs à chaque manche.
+fn cloned_players(c: &mut Criterion) {
+    c.bench_function("history_cloned_players", |b| {
+        b.iter(|| black_box(simulate(|round, players| (round, players.to_vec()))))
+    });
+}
+
+/// Mesure l'historique retenant les instantanés des joueurs, les noms n'étant retenus qu'une fois.
+fn snapshots(c: &mut Criterion) {
+    c.bench_function("history_snapshots", |b| {
+        b.iter(|| {
+            let names = PlayerNames::from_players(&players());
+            let rounds = simulate(|round, players| RoundRecord {
+                round,
+                players: players
+                    .iter()
+                    .enumerate()
+                    .map(|(index, player)| player.snapshot(PlayerId(index)))
+                    .collect::<Vec<PlayerSnapshot>>(),
+                mutator: None,
+            });
+            black_box((names, rounds))
+        })
+    });
+}
+
+criterion_group!(benches, cloned_players, snapshots);
+criterion_main!(benches);
@@ -0,0 +1,85 @@
+//! Compare le calcul des scores par les paliers du barème ([`ScoringConfig::score`]), objectif par
+//! objectif ([`ScoringCalculator::calculate_score`]) et par série
+//! ([`ScoringCalculator::score_batch`]), sur les arrêts aléatoires d'une simulation.
+//!
+//! Lancer avec `cargo bench --bench scoring`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use dual_game::scoring::{ScoringCalculator, ScoringConfig};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Nombre d'arrêts du compteur de la série mesurée.
+const STOPS: usize = 10_000;
+
+/// Force du joueur de la série mesurée.
+const STRENGTH: u32 = 50;
+
+/// Arrêts aléatoires reproductibles : objectifs, valeurs du compteur et miss.
+fn stops() -> (Vec<u32>, Vec<u32>, Vec<u32>) {
+    let mut rng = StdRng::seed_from_u64(158);
+    let mut values = |range: std::ops::RangeInclusive<u32>| -> Vec<u32> {
+        (0..STOPS)
+            .map(|_| rng.random_range(range.clone()))
+            .collect()
+    };
+    (values(0..=100), values(0..=100), values(0..=3))
+}
+
+/// Mesure le calcul des scores un à un, en parcourant les paliers du barème.
+fn tiers(c: &mut Criterion) {
+    let (objectives, counters, misses) = stops();
+    let scoring = ScoringConfig::default();
+    let mut scores = Vec::with_capacity(STOPS);
+    c.bench_function("score_tiers", |b| {
+        b.iter(|| {
+            scores.clear();
+            for index in 0..STOPS {
+                scores.push(scoring.score(
+                    objectives[index],
+                    counters[index],
+                    misses[index],
+                    STRENGTH,
+                ));
+            }
+            black_box(&scores);
+        })
+    });
+}
+
+/// Mesure le calcul des scores un à un.
+fn scalar(c: &mut Criterion) {
+    let (objectives, counters, misses) = stops();
+    let mut scores = Vec::with_capacity(STOPS);
+    c.bench_function("score_scalar", |b| {
+        b.iter(|| {
+            scores.clear();
+            for index in 0..STOPS {
+                scores.push(ScoringCalculator::calculate_score(
+                    objectives[index],
+                    counters[index],
+                    misses[index],
+                    STRENGTH,
+                ));
+            }
+            black_box(&scores);
+        })
+    });
+}
+
+/// Mesure le calcul des scores par série.
+fn batch(c: &mut Criterion) {
+    let (objectives, counters, misses) = stops();
+    let mut scores = Vec::with_capacity(STOPS);
+    c.bench_function("score_batch", |b| {
+        b.iter(|| {
+            ScoringCalculator::score_batch(&objectives, &counters, &misses, STRENGTH, &mut scores);
+            black_box(&scores);
+        })
+    });
+}
+
+criterion_group!(benches, tiers, scalar, batch);
+criterion_main!(benches);
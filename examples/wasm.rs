@@ -0,0 +1,102 @@
+//! Exemple d'intégration du moteur dans une page web avec `wasm-bindgen`.
+//!
+//! La page anime elle-même le compteur du joueur et transmet à [`WebGame::play_round`] les valeurs
+//! auxquelles il l'a arrêté ; le moteur joue alors la manche contre un bot et retourne son bilan en
+//! JSON. Aucune attente ni aucun thread : chaque appel est synchrone.
+//!
+//! ```sh
+//! cargo build --example wasm --target wasm32-unknown-unknown --no-default-features --features wasm
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/debug/examples/wasm.wasm
+//! ```
+
+use dual_game::console::NullConsole;
+use dual_game::controller::{BotController, Difficulty, ScriptedController, StopDecision};
+use dual_game::game::{Game, GameResult};
+use dual_game::output::{Output, Verbosity};
+use dual_game::player::Player;
+use dual_game::setup::DEFAULT_OBJECTIFS;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+/// Partie opposant le joueur de la page à un bot.
+#[wasm_bindgen]
+pub struct WebGame {
+    /// Partie en cours.
+    game: Game,
+    /// Décisions du joueur de la page, partagées avec son contrôleur dans la partie.
+    player: ScriptedController,
+    /// Issue de la partie, une fois terminée.
+    result: Option<GameResult>,
+}
+
+#[wasm_bindgen]
+impl WebGame {
+    /// Crée une partie reproductible entre le joueur `name` et un bot de difficulté normale.
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: &str, seed: u64) -> Result<WebGame, JsError> {
+        let players = vec![
+            Player::builder(name).build()?,
+            Player::builder("Bot").build()?,
+        ];
+        let mut game = Game::new(players, DEFAULT_OBJECTIFS);
+        game.set_output(Output::new(Box::new(NullConsole), Verbosity::Silent));
+        game.set_seed(seed);
+        let player = ScriptedController::new();
+        let bot = BotController::with_rng(Difficulty::Normal, StdRng::seed_from_u64(seed));
+        game.set_controller(0, Box::new(player.clone()));
+        game.set_controller(1, Box::new(bot));
+        game.start();
+        Ok(WebGame {
+            game,
+            player,
+            result: None,
+        })
+    }
+
+    /// Joue une manche et retourne son bilan en JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `stops` - Les valeurs du compteur arrêté par le joueur, une par objectif.
+    /// * `poison` - L'index du poison infligé au bot si le joueur gagne la manche.
+    pub fn play_round(&mut self, stops: Vec<u32>, poison: usize) -> Result<String, JsError> {
+        if self.result.is_some() {
+            return Err(JsError::new("la partie est terminée"));
+        }
+        let round = self.game.round;
+        self.player.clear();
+        for stop in stops {
+            self.player.push_stop(StopDecision {
+                counter_value: stop.min(100),
+                miss: 0,
+                stamina_spent: 0,
+            });
+        }
+        self.player.push_poison(poison);
+        self.result = self
+            .game
+            .play_round()
+            .map_err(|err| JsError::new(&err.to_string()))?;
+
+        let turns: Vec<_> = self
+            .game
+            .history
+            .iter()
+            .filter(|turn| turn.round == round)
+            .collect();
+        let winner = self
+            .result
+            .and_then(|result| result.winner())
+            .map(|id| self.game.player(id).name.clone());
+        let report = json!({
+            "round": round,
+            "turns": turns,
+            "vitality": self.game.players.iter().map(Player::vitality).collect::<Vec<_>>(),
+            "finished": self.result.is_some(),
+            "winner": winner,
+        });
+        Ok(report.to_string())
+    }
+}
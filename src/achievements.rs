@@ -0,0 +1,425 @@
+//! Module des succès débloqués par les profils.
+//!
+//! Chaque succès de [`ACHIEVEMENTS`] est vérifié en fin de partie, pour chaque joueur, à partir de
+//! l'historique de la partie ([`GameHistory`]) et de son résultat ([`GameResult`]) : [`earned`]
+//! retourne les succès obtenus, et [`Profile::unlock`](crate::profile::Profile::unlock) retient ceux
+//! qui n'étaient pas encore débloqués. Seules les victoires par élimination de l'adversaire comptent
+//! comme des victoires : un abandon n'en débloque aucun.
+//!
+//! Les succès portent sur l'historique retenu en mémoire (voir
+//! [`Game::retained_history`](crate::game::Game::retained_history)) : lorsque seules les dernières
+//! manches sont retenues, les succès fondés sur les premières manches peuvent échapper au joueur.
+
+use crate::game::GameResult;
+use crate::player::{PlayerId, PlayerSnapshot};
+use crate::replay::StopRecord;
+use crate::stats::{GameHistory, is_perfect};
+
+/// Vitalité sous laquelle un joueur doit être descendu pour réussir une remontada.
+pub const REMONTADA_VITALITY: u32 = 10;
+/// Nombre de manches d'un marathon.
+pub const MARATHON_ROUNDS: u32 = 15;
+/// Nombre maximal de manches d'une victoire éclair.
+pub const BLITZ_ROUNDS: u32 = 3;
+/// Nombre d'arrêts parfaits d'un tireur d'élite.
+pub const SHARPSHOOTER_PERFECTS: usize = 5;
+/// Dégâts infligés en une manche pour un coup de massue.
+pub const HEAVY_BLOW_DAMAGE: u32 = 30;
+/// Nombre de manches nulles d'une partie diplomate.
+pub const PEACEMAKER_DRAWS: usize = 3;
+/// Vitalité maximale du vainqueur d'une victoire sur le fil.
+pub const PHOTO_FINISH_VITALITY: u32 = 5;
+
+/// Vérifie si un joueur a obtenu un succès lors d'une partie.
+pub type Checker = fn(&GameHistory, &GameResult, PlayerId) -> bool;
+
+/// Succès disponibles, sous la forme `(identifiant, nom, vérification)`.
+///
+/// L'identifiant est celui enregistré dans les profils : il ne doit pas changer d'une version à
+/// l'autre.
+pub const ACHIEVEMENTS: [(&str, &str, Checker); 12] = [
+    ("first_win", "Première victoire", first_win),
+    ("perfect_round", "Manche parfaite", perfect_round),
+    ("flawless", "Sans faute", flawless),
+    ("remontada", "Remontada", remontada),
+    ("marathon", "Marathon", marathon),
+    ("blitz", "Éclair", blitz),
+    ("sharpshooter", "Tireur d'élite", sharpshooter),
+    ("untouchable", "Intouchable", untouchable),
+    ("no_miss", "Sans dépassement", no_miss),
+    ("heavy_blow", "Coup de massue", heavy_blow),
+    ("peacemaker", "Diplomate", peacemaker),
+    ("photo_finish", "Sur le fil", photo_finish),
+];
+
+/// Retourne les identifiants des succès obtenus par un joueur lors d'une partie, dans l'ordre de
+/// [`ACHIEVEMENTS`].
+pub fn earned(history: &GameHistory, result: &GameResult, player: PlayerId) -> Vec<&'static str> {
+    ACHIEVEMENTS
+        .iter()
+        .filter(|(_, _, check)| check(history, result, player))
+        .map(|(id, _, _)| *id)
+        .collect()
+}
+
+/// Retourne le nom d'un succès à partir de son identifiant, `None` s'il est inconnu.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::achievements::name;
+///
+/// assert_eq!(name("flawless"), Some("Sans faute"));
+/// assert_eq!(name("inconnu"), None);
+/// ```
+pub fn name(id: &str) -> Option<&'static str> {
+    ACHIEVEMENTS
+        .iter()
+        .find(|(known, _, _)| *known == id)
+        .map(|(_, name, _)| *name)
+}
+
+/// Indique si le joueur a remporté la partie en éliminant son adversaire.
+fn won(result: &GameResult, player: PlayerId) -> bool {
+    *result == GameResult::Victory { winner: player }
+}
+
+/// Retourne le numéro de la dernière manche jouée, 0 si aucune manche n'a été jouée.
+fn rounds_played(history: &GameHistory) -> u32 {
+    let last_round = history.rounds.last().map_or(0, |record| record.round);
+    let last_turn = history.turns.last().map_or(0, |turn| turn.round);
+    last_round.max(last_turn)
+}
+
+/// Parcourt les instantanés du joueur à la fin de chaque manche.
+fn snapshots<'a>(
+    history: &'a GameHistory,
+    player: PlayerId,
+) -> impl Iterator<Item = &'a PlayerSnapshot> {
+    history
+        .rounds
+        .iter()
+        .filter_map(move |record| record.players.get(player.index()))
+}
+
+/// Parcourt les arrêts du compteur du joueur.
+fn stops<'a>(history: &'a GameHistory, player: PlayerId) -> impl Iterator<Item = &'a StopRecord> {
+    history
+        .turns
+        .iter()
+        .filter(move |turn| turn.player == player.index())
+        .flat_map(|turn| &turn.stops)
+}
+
+/// Première victoire : remporter une partie.
+fn first_win(_: &GameHistory, result: &GameResult, player: PlayerId) -> bool {
+    won(result, player)
+}
+
+/// Manche parfaite : arrêter le compteur exactement sur chaque objectif d'une manche.
+fn perfect_round(history: &GameHistory, _: &GameResult, player: PlayerId) -> bool {
+    history.turns.iter().any(|turn| {
+        turn.player == player.index() && !turn.stops.is_empty() && turn.stops.iter().all(is_perfect)
+    })
+}
+
+/// Sans faute : remporter une partie sans avoir subi de poison.
+fn flawless(history: &GameHistory, result: &GameResult, player: PlayerId) -> bool {
+    won(result, player) && snapshots(history, player).all(|snapshot| snapshot.poisons == 0)
+}
+
+/// Remontada : remporter une partie après être descendu sous [`REMONTADA_VITALITY`] de vitalité.
+fn remontada(history: &GameHistory, result: &GameResult, player: PlayerId) -> bool {
+    won(result, player)
+        && snapshots(history, player).any(|snapshot| snapshot.vitality < REMONTADA_VITALITY)
+}
+
+/// Marathon : jouer une partie d'au moins [`MARATHON_ROUNDS`] manches.
+fn marathon(history: &GameHistory, _: &GameResult, _: PlayerId) -> bool {
+    rounds_played(history) >= MARATHON_ROUNDS
+}
+
+/// Éclair : remporter une partie en [`BLITZ_ROUNDS`] manches au plus.
+fn blitz(history: &GameHistory, result: &GameResult, player: PlayerId) -> bool {
+    won(result, player) && (1..=BLITZ_ROUNDS).contains(&rounds_played(history))
+}
+
+/// Tireur d'élite : réussir au moins [`SHARPSHOOTER_PERFECTS`] arrêts parfaits dans une partie.
+fn sharpshooter(history: &GameHistory, _: &GameResult, player: PlayerId) -> bool {
+    stops(history, player)
+        .filter(|stop| is_perfect(stop))
+        .count()
+        >= SHARPSHOOTER_PERFECTS
+}
+
+/// Intouchable : remporter une partie sans perdre une manche.
+fn untouchable(history: &GameHistory, result: &GameResult, player: PlayerId) -> bool {
+    won(result, player)
+        && history
+            .damage
+            .iter()
+            .all(|record| record.loser != player.index())
+}
+
+/// Sans dépassement : remporter une partie sans que le compteur dépasse jamais 100.
+fn no_miss(history: &GameHistory, result: &GameResult, player: PlayerId) -> bool {
+    won(result, player) && stops(history, player).all(|stop| stop.miss == 0)
+}
+
+/// Coup de massue : infliger au moins [`HEAVY_BLOW_DAMAGE`] dégâts en une manche.
+fn heavy_blow(history: &GameHistory, _: &GameResult, player: PlayerId) -> bool {
+    history
+        .damage
+        .iter()
+        .any(|record| record.winner == player.index() && record.dealt >= HEAVY_BLOW_DAMAGE)
+}
+
+/// Diplomate : jouer au moins [`PEACEMAKER_DRAWS`] manches nulles dans une partie.
+fn peacemaker(history: &GameHistory, _: &GameResult, _: PlayerId) -> bool {
+    history.draws.len() >= PEACEMAKER_DRAWS
+}
+
+/// Sur le fil : remporter une partie avec au plus [`PHOTO_FINISH_VITALITY`] de vitalité.
+fn photo_finish(history: &GameHistory, result: &GameResult, player: PlayerId) -> bool {
+    won(result, player)
+        && snapshots(history, player)
+            .last()
+            .is_some_and(|snapshot| snapshot.vitality <= PHOTO_FINISH_VITALITY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replay::{DamageRecord, DrawRecord, PlayerNames, RoundRecord, TurnRecord};
+
+    /// Historique synthétique d'une partie entre Alice et Bob.
+    #[derive(Default)]
+    struct Synthetic {
+        turns: Vec<TurnRecord>,
+        damage: Vec<DamageRecord>,
+        draws: Vec<DrawRecord>,
+        rounds: Vec<RoundRecord>,
+    }
+
+    impl Synthetic {
+        /// Ajoute un tour du joueur donné, chaque arrêt étant `(objectif, compteur, dépassements)`.
+        fn turn(mut self, round: u32, player: usize, stops: &[(u32, u32, u32)]) -> Self {
+            let stops = stops
+                .iter()
+                .map(|&(objective, counter, miss)| StopRecord {
+                    objective,
+                    counter,
+                    miss,
+                    score: 0,
+                    retried: false,
+                })
+                .collect();
+            self.turns.push(TurnRecord {
+                round,
+                player,
+                stops,
+                average: 0,
+                risk: None,
+            });
+            self
+        }
+
+        /// Ajoute la fin d'une manche : les dégâts infligés au perdant, puis l'état des joueurs,
+        /// chacun étant `(vitalité, poisons)`.
+        fn round(
+            mut self,
+            round: u32,
+            damage: Option<(usize, u32)>,
+            state: [(u32, u32); 2],
+        ) -> Self {
+            match damage {
+                Some((winner, dealt)) => self.damage.push(DamageRecord {
+                    round,
+                    winner,
+                    loser: 1 - winner,
+                    dealt,
+                    time_tiebreak: false,
+                    winners_curse: false,
+                }),
+                None => self.draws.push(DrawRecord {
+                    round,
+                    score: 0,
+                    consecutive: 1,
+                }),
+            }
+            let players = state
+                .iter()
+                .enumerate()
+                .map(|(index, &(vitality, poisons))| PlayerSnapshot {
+                    id: PlayerId(index),
+                    vitality,
+                    max_vitality: 50,
+                    speed: 75,
+                    strength: 50,
+                    poisons,
+                })
+                .collect();
+            self.rounds.push(RoundRecord {
+                round,
+                players,
+                mutator: None,
+            });
+            self
+        }
+
+        /// Retourne les succès obtenus par le joueur donné avec ce résultat.
+        fn earned(&self, result: GameResult, player: usize) -> Vec<&'static str> {
+            let names = PlayerNames::from(vec![String::from("Alice"), String::from("Bob")]);
+            let history = GameHistory {
+                names: &names,
+                turns: &self.turns,
+                damage: &self.damage,
+                draws: &self.draws,
+                rounds: &self.rounds,
+            };
+            earned(&history, &result, PlayerId(player))
+        }
+    }
+
+    /// Victoire d'Alice par élimination de Bob.
+    const ALICE_WINS: GameResult = GameResult::Victory {
+        winner: PlayerId(0),
+    };
+
+    /// Vérifie que les identifiants des succès sont uniques et retrouvent leur nom.
+    #[test]
+    fn test_registry_ids_unique() {
+        for (index, (id, label, _)) in ACHIEVEMENTS.iter().enumerate() {
+            assert_eq!(name(id), Some(*label));
+            assert!(
+                ACHIEVEMENTS[..index]
+                    .iter()
+                    .all(|(other, _, _)| other != id)
+            );
+        }
+    }
+
+    /// Vérifie la manche parfaite : tous les objectifs d'une manche atteints exactement, sans
+    /// dépassement.
+    #[test]
+    fn test_perfect_round() {
+        let history = Synthetic::default()
+            .turn(1, 0, &[(40, 40, 0), (60, 61, 0)])
+            .turn(1, 1, &[(40, 40, 1), (60, 60, 0)]);
+        assert!(
+            !history
+                .earned(GameResult::Draw, 0)
+                .contains(&"perfect_round")
+        );
+        assert!(
+            !history
+                .earned(GameResult::Draw, 1)
+                .contains(&"perfect_round")
+        );
+
+        let history = history.turn(2, 0, &[(12, 12, 0), (87, 87, 0)]);
+        assert!(
+            history
+                .earned(GameResult::Draw, 0)
+                .contains(&"perfect_round")
+        );
+    }
+
+    /// Vérifie le sans faute : une victoire sans poison, même après avoir perdu une manche dont le
+    /// poison a été contré.
+    #[test]
+    fn test_flawless() {
+        let history = Synthetic::default()
+            .round(1, Some((1, 20)), [(30, 0), (50, 0)])
+            .round(2, Some((0, 50)), [(30, 0), (0, 1)]);
+        assert!(history.earned(ALICE_WINS, 0).contains(&"flawless"));
+        assert!(!history.earned(ALICE_WINS, 0).contains(&"untouchable"));
+
+        let poisoned = Synthetic::default()
+            .round(1, Some((1, 10)), [(40, 1), (50, 0)])
+            .round(2, Some((0, 50)), [(40, 1), (0, 0)]);
+        assert!(!poisoned.earned(ALICE_WINS, 0).contains(&"flawless"));
+    }
+
+    /// Vérifie la remontada : une victoire après être descendu sous 10 de vitalité, mais pas une
+    /// défaite ni une victoire toujours au-dessus.
+    #[test]
+    fn test_remontada() {
+        let history = Synthetic::default()
+            .round(1, Some((1, 45)), [(5, 1), (50, 0)])
+            .round(2, Some((0, 30)), [(5, 1), (20, 1)])
+            .round(3, Some((0, 30)), [(5, 1), (0, 2)]);
+        assert!(history.earned(ALICE_WINS, 0).contains(&"remontada"));
+        assert!(history.earned(ALICE_WINS, 0).contains(&"photo_finish"));
+        assert!(history.earned(ALICE_WINS, 0).contains(&"heavy_blow"));
+        assert!(!history.earned(GameResult::Draw, 0).contains(&"remontada"));
+
+        let comfortable =
+            Synthetic::default().round(1, Some((0, 50)), [(REMONTADA_VITALITY, 0), (0, 0)]);
+        assert!(!comfortable.earned(ALICE_WINS, 0).contains(&"remontada"));
+    }
+
+    /// Vérifie le marathon, atteint dès la quinzième manche quel que soit le résultat.
+    #[test]
+    fn test_marathon() {
+        let mut history = Synthetic::default();
+        for round in 1..MARATHON_ROUNDS {
+            history = history.round(round, None, [(50, 0), (50, 0)]);
+        }
+        assert!(!history.earned(GameResult::Draw, 1).contains(&"marathon"));
+        assert!(history.earned(GameResult::Draw, 1).contains(&"peacemaker"));
+        let history = history.round(MARATHON_ROUNDS, Some((0, 50)), [(50, 0), (0, 0)]);
+        assert!(history.earned(ALICE_WINS, 1).contains(&"marathon"));
+    }
+
+    /// Vérifie les succès d'une victoire éclair : elle ne compte que pour le vainqueur, et un abandon
+    /// n'en débloque aucun.
+    #[test]
+    fn test_blitz_and_forfeit() {
+        let history = Synthetic::default()
+            .turn(1, 0, &[(30, 30, 0), (70, 75, 0)])
+            .round(1, Some((0, 50)), [(50, 0), (0, 1)]);
+        assert_eq!(
+            history.earned(ALICE_WINS, 0),
+            [
+                "first_win",
+                "flawless",
+                "blitz",
+                "untouchable",
+                "no_miss",
+                "heavy_blow"
+            ]
+        );
+        assert_eq!(history.earned(ALICE_WINS, 1), Vec::<&str>::new());
+
+        let forfeit = GameResult::Forfeit {
+            forfeited: PlayerId(1),
+            winner: PlayerId(0),
+        };
+        assert_eq!(history.earned(forfeit, 0), ["heavy_blow"]);
+    }
+
+    /// Vérifie le tireur d'élite, qui compte les arrêts parfaits de toutes les manches.
+    #[test]
+    fn test_sharpshooter() {
+        let history = Synthetic::default()
+            .turn(1, 0, &[(10, 10, 0), (20, 20, 0), (30, 31, 0)])
+            .turn(2, 0, &[(40, 40, 0), (50, 50, 1), (60, 60, 0)]);
+        assert!(
+            !history
+                .earned(GameResult::Draw, 0)
+                .contains(&"sharpshooter")
+        );
+        let history = history.turn(3, 0, &[(70, 70, 0)]);
+        assert!(
+            history
+                .earned(GameResult::Draw, 0)
+                .contains(&"sharpshooter")
+        );
+        assert!(
+            !history
+                .earned(GameResult::Draw, 1)
+                .contains(&"sharpshooter")
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//! Module de la partie asynchrone, pilotée manche par manche depuis un serveur asynchrone.
+//!
+//! Avec la fonctionnalité `async`, une [`AsyncGame`] joue une [`Game`] sans bloquer : chaque
+//! décision d'un joueur (arrêt du compteur, poison, contre-proposition, boutique, prise de risque,
+//! interdiction) est attendue auprès de l'hôte par le trait [`GameIo`]. Le compteur n'est pas lancé :
+//! l'hôte fournit directement la valeur d'arrêt de chaque objectif, si bien qu'aucun thread n'est
+//! créé et qu'une même tâche peut mener de nombreuses parties simultanées.
+//!
+//! Seule l'orchestration de la manche diffère de [`Game::play_round`] : le tirage des objectifs, le
+//! calcul des scores, les dégâts, les poisons, l'historique, le rendu et les observateurs sont ceux de
+//! la partie synchrone. Seul le mode classique est pris en charge ; les contrôleurs de la partie ne
+//! sont pas sollicités. De la cadence de la partie, seule la pause entre les manches est retenue,
+//! confirmée auprès de l'hôte : l'hôte rythme lui-même le début des tours et les objectifs.
+
+use std::error::Error;
+use std::fmt;
+use std::ops::ControlFlow;
+
+use crate::controller::{CounterView, StopDecision};
+use crate::game::{Game, GameResult, PoisonChoice, RoundResolution};
+use crate::items::Item;
+use crate::player::{Player, PlayerId};
+use crate::poison::PoisonType;
+use crate::risk::Risk;
+use crate::rules::GameMode;
+
+/// Choix demandé à un joueur au cours d'une manche.
+///
+/// Chaque variante décrit ses options ; la réponse de l'hôte ([`GameIo::prompt_choice`]) est l'index
+/// de l'option choisie, un index hors limites valant refus.
+#[derive(Clone, Copy, Debug)]
+pub enum Prompt<'a> {
+    /// Le vainqueur de la manche choisit le poison appliqué au perdant parmi `options`. Un refus
+    /// fait poser de nouveau la question, jusqu'à ce qu'un poison soit choisi ou que la partie soit
+    /// interrompue.
+    Poison {
+        /// Poisons proposés.
+        options: &'a [PoisonType],
+        /// Perdant de la manche.
+        opponent: &'a Player,
+    },
+    /// Le perdant choisit de subir le poison (0) ou de le contrer (1) en subissant `extra_damage`
+    /// points de dégâts supplémentaires.
+    Counter {
+        /// Poison choisi par le vainqueur.
+        poison: &'a PoisonType,
+        /// Dégâts supplémentaires subis en cas de contre-proposition.
+        extra_damage: u32,
+    },
+    /// Le joueur choisit l'objet à acheter à la boutique parmi `items`. Un refus n'achète rien.
+    Item {
+        /// Objets proposés.
+        items: &'a [Item],
+    },
+    /// Le joueur choisit sa prise de risque parmi `options` avant son tour. Un refus équivaut à
+    /// [`Risk::Normal`].
+    Risk {
+        /// Prises de risque proposées.
+        options: &'a [Risk],
+        /// Adversaire du joueur.
+        opponent: &'a Player,
+    },
+    /// Le joueur qui a le moins de vitalité choisit le poison interdit pour la manche parmi `options`.
+    /// Un refus n'en interdit aucun.
+    Ban {
+        /// Poisons qui peuvent être interdits.
+        options: &'a [PoisonType],
+        /// Adversaire du joueur.
+        opponent: &'a Player,
+    },
+    /// Le joueur confirme le passage à la manche `round`, lorsque la cadence de la partie marque une
+    /// pause entre les manches (voir [`Pacing`](crate::pacing::Pacing)). La réponse est ignorée.
+    Continue {
+        /// Manche qui commence.
+        round: u32,
+    },
+}
+
+/// Décisions des joueurs d'une partie asynchrone, attendues auprès de l'hôte.
+///
+/// Les futurs retournés ne sont pas tenus d'être [`Send`] : une partie asynchrone est menée par une
+/// seule tâche.
+#[allow(async_fn_in_trait)]
+pub trait GameIo {
+    /// Retourne l'arrêt du compteur d'un joueur pour l'objectif décrit par `view`.
+    async fn stop_value(&mut self, player: PlayerId, view: &CounterView) -> StopDecision;
+
+    /// Retourne l'index de l'option choisie par un joueur en réponse à `prompt`.
+    async fn prompt_choice(&mut self, player: PlayerId, me: &Player, prompt: Prompt<'_>) -> usize;
+}
+
+/// Erreur de création d'une partie asynchrone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AsyncGameError {
+    /// Le mode de jeu n'est pas pris en charge par la partie asynchrone.
+    UnsupportedMode(GameMode),
+}
+
+impl fmt::Display for AsyncGameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AsyncGameError::UnsupportedMode(mode) => write!(
+                f,
+                "le mode {} n'est pas pris en charge par la partie asynchrone",
+                mode.id()
+            ),
+        }
+    }
+}
+
+impl Error for AsyncGameError {}
+
+/// Partie dont les décisions des joueurs sont attendues auprès de l'hôte (voir [`GameIo`]).
+pub struct AsyncGame<I: GameIo> {
+    /// Partie jouée, avec ses joueurs, ses règles, son rendu et ses observateurs.
+    game: Game,
+    /// Source des décisions des joueurs.
+    io: I,
+    /// Indique si la partie a été commencée (voir [`Game::start`]).
+    started: bool,
+}
+
+impl<I: GameIo> AsyncGame<I> {
+    /// Crée une partie asynchrone à partir d'une partie configurée et de la source des décisions de ses
+    /// joueurs.
+    ///
+    /// # Retour
+    ///
+    /// Retourne [`AsyncGameError::UnsupportedMode`] si la partie n'est pas en mode classique.
+    pub fn new(game: Game, io: I) -> Result<Self, AsyncGameError> {
+        if game.rules.mode != GameMode::Classic {
+            return Err(AsyncGameError::UnsupportedMode(game.rules.mode));
+        }
+        Ok(AsyncGame {
+            game,
+            io,
+            started: false,
+        })
+    }
+
+    /// Retourne la partie jouée.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Retourne la partie jouée, par exemple pour lui ajouter un observateur entre deux manches.
+    pub fn game_mut(&mut self) -> &mut Game {
+        &mut self.game
+    }
+
+    /// Retourne la source des décisions des joueurs.
+    pub fn io(&self) -> &I {
+        &self.io
+    }
+
+    /// Sépare la partie de la source des décisions de ses joueurs.
+    pub fn into_inner(self) -> (Game, I) {
+        (self.game, self.io)
+    }
+
+    /// Joue la partie jusqu'à sa fin, manche après manche.
+    ///
+    /// # Retour
+    ///
+    /// Retourne l'issue de la partie, ou une erreur.
+    pub async fn run(&mut self) -> Result<GameResult, Box<dyn Error>> {
+        loop {
+            if let Some(result) = self.play_round().await? {
+                return Ok(result);
+            }
+        }
+    }
+
+    /// Joue une manche de la partie, en la commençant au préalable si nécessaire, puis termine la
+    /// partie si un joueur a perdu toute sa vitalité.
+    ///
+    /// # Retour
+    ///
+    /// Retourne l'issue de la partie si elle est terminée, `None` si elle se poursuit, ou une erreur.
+    pub async fn play_round(&mut self) -> Result<Option<GameResult>, Box<dyn Error>> {
+        if !self.started {
+            self.game.start();
+            self.started = true;
+        }
+        if self.game.round_pause_due() {
+            let prompt = Prompt::Continue {
+                round: self.game.round,
+            };
+            for index in 0..self.game.players.len() {
+                let me = &self.game.players[index];
+                self.io.prompt_choice(PlayerId(index), me, prompt).await;
+                if let Some(result) = self.game.stopped() {
+                    return Ok(Some(result));
+                }
+            }
+        }
+        if let Some(result) = self.game.begin_round() {
+            return Ok(Some(result));
+        }
+        if self.game.rules.bans {
+            self.choose_ban().await;
+            if let Some(result) = self.game.stopped() {
+                return Ok(Some(result));
+            }
+        }
+
+        let count = self.game.round_objectives();
+        let mut scores = Vec::new();
+        for index in 0..self.game.players.len() {
+            match self.play_turn(index, count).await {
+                ControlFlow::Continue(score) => scores.push(score),
+                ControlFlow::Break(result) => return Ok(Some(result)),
+            }
+        }
+        self.game.add_total_scores(&scores);
+
+        let (winner, loser, dealt) = match self.game.resolve_scores(&scores)? {
+            RoundResolution::Drawn => {
+                if let Some(result) = self.open_shop().await {
+                    return Ok(Some(result));
+                }
+                self.game.end_round();
+                return Ok(None);
+            }
+            RoundResolution::Won {
+                winner,
+                loser,
+                dealt,
+            } => (winner, loser, dealt),
+        };
+
+        let shop = self.game.player(loser).is_alive();
+        if let Some(options) = self.game.poison_menu(winner, loser) {
+            let poison = loop {
+                let prompt = Prompt::Poison {
+                    options: &options,
+                    opponent: self.game.player(loser),
+                };
+                let choice = self
+                    .io
+                    .prompt_choice(winner, self.game.player(winner), prompt)
+                    .await;
+                if let Some(result) = self.game.stopped() {
+                    return Ok(Some(result));
+                }
+                if let PoisonChoice::Apply(poison) =
+                    self.game.poison_choice(winner, loser, &options, choice)
+                {
+                    break poison;
+                }
+            };
+            let counter = self.game.counter_menu(loser, &poison, dealt) && {
+                let prompt = Prompt::Counter {
+                    poison: &poison,
+                    extra_damage: dealt,
+                };
+                let me = self.game.player(loser);
+                self.io.prompt_choice(loser, me, prompt).await == 1
+            };
+            self.game.apply_poison(loser, poison, dealt, counter)?;
+        }
+
+        if shop && let Some(result) = self.open_shop().await {
+            return Ok(Some(result));
+        }
+        Ok(self.game.close_round())
+    }
+
+    /// Fait jouer à un joueur son tour complet, après sa prise de risque si elle est proposée.
+    ///
+    /// Retourne le score moyen du tour, ou l'issue de la partie si elle a été interrompue.
+    async fn play_turn(&mut self, index: usize, count: usize) -> ControlFlow<GameResult, u32> {
+        let player = PlayerId(index);
+        let risk = if self.game.offers_risk(count) {
+            self.game.risk_menu(index);
+            let prompt = Prompt::Risk {
+                options: &Risk::ALL,
+                opponent: &self.game.players[(index + 1) % self.game.players.len()],
+            };
+            let choice = self
+                .io
+                .prompt_choice(player, &self.game.players[index], prompt)
+                .await;
+            if let Some(result) = self.game.stopped() {
+                return ControlFlow::Break(result);
+            }
+            Some(Risk::ALL.get(choice).copied().unwrap_or_default())
+        } else {
+            None
+        };
+        let objectives = self.game.begin_turn(index, count, risk);
+        let mut stops = Vec::new();
+        for (obj_index, &objective) in objectives.iter().enumerate() {
+            let view = self.game.counter_view(index, obj_index, objective);
+            let started = self.game.now();
+            let stop = self.io.stop_value(player, &view).await;
+            let elapsed = self.game.now().saturating_sub(started);
+            if let Some(result) = self.game.stopped() {
+                // Le tour interrompu n'est ni compté ni consigné dans l'historique.
+                return ControlFlow::Break(result);
+            }
+            stops.push(
+                self.game
+                    .apply_stop(index, obj_index, objective, stop, elapsed, false, false),
+            );
+        }
+        let score = self.game.finish_turn(index, stops, risk);
+        self.game.show_turn_end(index, score);
+        ControlFlow::Continue(score)
+    }
+
+    /// Propose au joueur qui a le moins de vitalité d'interdire un poison pour la manche.
+    async fn choose_ban(&mut self) {
+        let Some(index) = self.game.ban_menu() else {
+            return;
+        };
+        let prompt = Prompt::Ban {
+            options: &PoisonType::ALL,
+            opponent: &self.game.players[1 - index],
+        };
+        let choice = self
+            .io
+            .prompt_choice(PlayerId(index), &self.game.players[index], prompt)
+            .await;
+        self.game
+            .apply_ban(index, PoisonType::ALL.get(choice).map(|_| choice));
+    }
+
+    /// Ouvre la boutique à chaque joueur, dans l'ordre, lorsqu'elle est activée.
+    ///
+    /// Retourne l'issue de la partie si elle a été interrompue pendant un achat, `None` sinon.
+    async fn open_shop(&mut self) -> Option<GameResult> {
+        if !self.game.rules.shop {
+            return None;
+        }
+        for index in 0..self.game.players.len() {
+            if !self.game.shop_menu(index) {
+                continue;
+            }
+            let prompt = Prompt::Item { items: &Item::ALL };
+            let choice = self
+                .io
+                .prompt_choice(PlayerId(index), &self.game.players[index], prompt)
+                .await;
+            if let Some(result) = self.game.stopped() {
+                return Some(result);
+            }
+            self.game
+                .buy_item(index, Item::ALL.get(choice).map(|_| choice));
+        }
+        None
+    }
+}
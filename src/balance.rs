@@ -0,0 +1,292 @@
+//! Module du rapport d'équilibrage des caractéristiques.
+//!
+//! La fonction [`sweep`] balaie une grille de vitesses et de forces ([`BalanceGrid`]) pour un joueur
+//! opposé à un adversaire de référence : pour chaque configuration, des parties silencieuses entre bots
+//! sont simulées (voir [`simulate_with_progress`]). Le [`BalanceReport`] obtenu s'affiche sous forme
+//! de tableau (taux de victoire et durée moyenne des parties) et s'exporte au format CSV.
+
+use std::error::Error;
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::controller::Difficulty;
+use crate::messages::Msg;
+use crate::player::Player;
+use crate::rules::GameRules;
+use crate::simulation::{SimulationReport, simulate_with_progress};
+use crate::tr;
+
+/// Grille des caractéristiques essayées pour le joueur évalué.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BalanceGrid {
+    /// Vitesses essayées (délai du compteur, en millisecondes).
+    pub speeds: Vec<u32>,
+    /// Forces essayées.
+    pub strengths: Vec<u32>,
+}
+
+impl Default for BalanceGrid {
+    /// Vitesses de 40 à 100 par pas de 20, forces de 25 à 75 par pas de 25.
+    fn default() -> Self {
+        BalanceGrid {
+            speeds: (40..=100).step_by(20).collect(),
+            strengths: (25..=75).step_by(25).collect(),
+        }
+    }
+}
+
+impl BalanceGrid {
+    /// Retourne le nombre de configurations de la grille.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::balance::BalanceGrid;
+    ///
+    /// assert_eq!(BalanceGrid::default().len(), 12);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.speeds.len() * self.strengths.len()
+    }
+
+    /// Indique si la grille ne contient aucune configuration.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Résultats des parties simulées pour une configuration de la grille.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BalanceCell {
+    /// Vitesse du joueur évalué.
+    pub speed: u32,
+    /// Force du joueur évalué.
+    pub strength: u32,
+    /// Résultats des parties, le joueur évalué étant le premier.
+    pub report: SimulationReport,
+}
+
+/// Rapport d'équilibrage : les résultats de chaque configuration de la grille.
+#[derive(Clone, Debug)]
+pub struct BalanceReport {
+    /// Adversaire de référence.
+    pub baseline: Player,
+    /// Nombre de parties simulées par configuration.
+    pub games: u32,
+    /// Résultats de chaque configuration, par vitesse puis par force.
+    pub cells: Vec<BalanceCell>,
+}
+
+impl BalanceReport {
+    /// Retourne le rapport au format CSV, une ligne par configuration après l'en-tête.
+    ///
+    /// Le taux de victoire est celui du joueur évalué, en pourcentage, et la durée moyenne est exprimée
+    /// en manches.
+    pub fn to_csv(&self) -> String {
+        let mut csv =
+            String::from("speed,strength,games,wins,losses,draws,win_rate,average_rounds\n");
+        for cell in &self.cells {
+            let report = &cell.report;
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{:.2},{:.2}\n",
+                cell.speed,
+                cell.strength,
+                report.games,
+                report.wins[0],
+                report.wins[1],
+                report.draws,
+                report.win_rate(0),
+                report.average_rounds()
+            ));
+        }
+        csv
+    }
+}
+
+impl fmt::Display for BalanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}",
+            tr!(
+                Msg::BalanceTitle,
+                games = self.games,
+                player = self.baseline.name,
+                speed = self.baseline.speed,
+                strength = self.baseline.strength
+            )
+        )?;
+        write!(f, "{}", tr!(Msg::BalanceColumns))?;
+        for cell in &self.cells {
+            let row = tr!(
+                Msg::BalanceRow,
+                speed = format!("{:>7}", cell.speed),
+                strength = format!("{:>5}", cell.strength),
+                rate = format!("{:>7.1}", cell.report.win_rate(0)),
+                rounds = format!("{:>5.1}", cell.report.average_rounds())
+            );
+            write!(f, "\n{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Simule des parties pour chaque configuration de la grille contre un adversaire de référence.
+///
+/// Le joueur évalué a la vitalité de la référence et les caractéristiques de la configuration ; les
+/// deux joueurs sont contrôlés par des bots de même difficulté.
+///
+/// # Arguments
+///
+/// * `grid` - Les vitesses et les forces essayées pour le joueur évalué.
+/// * `baseline` - L'adversaire de référence, identique pour toutes les configurations.
+/// * `difficulty` - La difficulté des deux bots.
+/// * `games` - Le nombre de parties simulées par configuration.
+/// * `objectifs` - Le nombre d'objectifs par tour.
+/// * `seed` - La graine rendant le rapport reproductible, le cas échéant.
+/// * `on_progress` - Reçoit le nombre de parties terminées et le nombre total de parties, après
+///   chaque partie.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::balance::{BalanceGrid, sweep};
+/// use dual_game::controller::Difficulty;
+/// use dual_game::player::Player;
+///
+/// let grid = BalanceGrid { speeds: vec![60], strengths: vec![25, 75] };
+/// let baseline = Player::new(String::from("Référence"), 30, 75, 50);
+/// let report = sweep(&grid, &baseline, Difficulty::Normal, 3, 3, Some(1), &mut |_, _| {}).unwrap();
+/// assert_eq!(report.cells.len(), 2);
+/// assert_eq!(report.cells[1].strength, 75);
+/// ```
+pub fn sweep(
+    grid: &BalanceGrid,
+    baseline: &Player,
+    difficulty: Difficulty,
+    games: u32,
+    objectifs: usize,
+    seed: Option<u64>,
+    on_progress: &mut dyn FnMut(u64, u64),
+) -> Result<BalanceReport, Box<dyn Error>> {
+    let mut rng = seed.map(StdRng::seed_from_u64);
+    let total = grid.len() as u64 * u64::from(games);
+    let mut done = 0;
+    let mut cells = Vec::with_capacity(grid.len());
+    for &speed in &grid.speeds {
+        for &strength in &grid.strengths {
+            let player = Player::builder(format!("Vitesse {speed}, force {strength}"))
+                .vitality(baseline.max_vitality())
+                .speed(speed)
+                .strength(strength)
+                .build()?;
+            let players = [player, baseline.clone()];
+            let report = simulate_with_progress(
+                &players,
+                [difficulty; 2],
+                games,
+                objectifs,
+                GameRules::default(),
+                rng.as_mut().map(|rng| rng.random()),
+                &mut |played| on_progress(done + u64::from(played), total),
+            )?;
+            done += u64::from(games);
+            cells.push(BalanceCell {
+                speed,
+                strength,
+                report,
+            });
+        }
+    }
+    Ok(BalanceReport {
+        baseline: baseline.clone(),
+        games,
+        cells,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Grille de 2 × 2 configurations, assez petite pour un test.
+    fn tiny_grid() -> BalanceGrid {
+        BalanceGrid {
+            speeds: vec![40, 100],
+            strengths: vec![25, 75],
+        }
+    }
+
+    /// Balaie la petite grille avec une graine fixe.
+    fn tiny_sweep(progress: &mut dyn FnMut(u64, u64)) -> BalanceReport {
+        let baseline = Player::new(String::from("Référence"), 30, 75, 50);
+        sweep(
+            &tiny_grid(),
+            &baseline,
+            Difficulty::Normal,
+            10,
+            3,
+            Some(42),
+            progress,
+        )
+        .unwrap()
+    }
+
+    /// Vérifie que le balayage couvre chaque configuration, est reproductible avec une graine et
+    /// signale sa progression partie par partie jusqu'au total.
+    #[test]
+    fn test_sweep_tiny_grid() {
+        let mut progress = Vec::new();
+        let report = tiny_sweep(&mut |done, total| progress.push((done, total)));
+        assert_eq!(report.cells, tiny_sweep(&mut |_, _| {}).cells);
+
+        let configurations: Vec<(u32, u32)> = report
+            .cells
+            .iter()
+            .map(|cell| (cell.speed, cell.strength))
+            .collect();
+        assert_eq!(configurations, [(40, 25), (40, 75), (100, 25), (100, 75)]);
+        for cell in &report.cells {
+            let results = &cell.report;
+            assert_eq!(results.wins[0] + results.wins[1] + results.draws, 10);
+            assert!(results.rounds >= 10);
+        }
+        assert_eq!(progress.len(), 40);
+        assert_eq!(progress.first(), Some(&(1, 40)));
+        assert_eq!(progress.last(), Some(&(40, 40)));
+        assert!(progress.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    /// Vérifie le tableau et le CSV du rapport : un en-tête, puis une ligne par configuration.
+    #[test]
+    fn test_report_table_and_csv() {
+        let report = tiny_sweep(&mut |_, _| {});
+        let table = report.to_string();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[0],
+            "Équilibrage : 10 parties par configuration contre Référence (vitesse 75, force 50)"
+        );
+        let first = &report.cells[0].report;
+        assert_eq!(
+            lines[2],
+            format!(
+                "     40     25  {:>7.1} %  {:>5.1} manches",
+                first.win_rate(0),
+                first.average_rounds()
+            )
+        );
+
+        let csv = report.to_csv();
+        let rows: Vec<Vec<&str>> = csv.lines().map(|line| line.split(',').collect()).collect();
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows[0][0], "speed");
+        assert!(rows.iter().all(|row| row.len() == 8));
+        assert_eq!(rows[4][..3], ["100", "75", "10"]);
+        let wins: u32 = rows[1][3].parse().unwrap();
+        assert_eq!(wins, first.wins[0]);
+    }
+}
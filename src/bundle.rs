@@ -0,0 +1,328 @@
+//! Module des rapports de bogue : enregistrement des saisies d'une session, puis rejeu.
+//!
+//! Avec `--record-inputs <fichier>`, chaque saisie lue dans la file du clavier
+//! ([`InputQueue`]) est horodatée par l'horloge d'un [`InputRecorder`], puis consignée avec la
+//! graine, la ligne de commande et la configuration de la partie dans un rapport de bogue
+//! ([`BugBundle`]) au format JSON. Avec `--replay-inputs <fichier>`, les saisies du rapport
+//! alimentent à nouveau la file pour reproduire la session sans terminal : seul leur ordre compte,
+//! les horodatages ne servant qu'à la lecture du rapport.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::clock::Clock;
+use crate::console::{InputEvent, InputQueue};
+use crate::predictions::write_atomic;
+
+/// Version du format des rapports de bogue écrits par l'application.
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Saisie consignée dans un rapport de bogue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedInput {
+    /// Instant de la saisie, en millisecondes depuis le début de l'enregistrement.
+    pub at_ms: u64,
+    /// Saisie lue.
+    pub event: InputEvent,
+}
+
+/// Enregistreur des saisies lues dans une [`InputQueue`] (voir [`InputQueue::with_recorder`]).
+///
+/// Les clones partagent les mêmes saisies : l'application conserve un clone pour écrire le rapport
+/// pendant que la file consigne ses saisies dans l'autre.
+#[derive(Clone)]
+pub struct InputRecorder {
+    /// Horloge horodatant les saisies.
+    clock: Arc<dyn Clock + Send + Sync>,
+    /// Instant du début de l'enregistrement.
+    origin: Duration,
+    /// Saisies consignées, dans l'ordre de leur lecture.
+    inputs: Arc<Mutex<Vec<RecordedInput>>>,
+}
+
+impl InputRecorder {
+    /// Crée un enregistrement commençant à l'instant présent de l'horloge donnée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// use dual_game::bundle::InputRecorder;
+    /// use dual_game::cancel::CancelToken;
+    /// use dual_game::clock::ManualClock;
+    /// use dual_game::console::{InputEvent, InputQueue};
+    ///
+    /// let clock = ManualClock::new();
+    /// let recorder = InputRecorder::new(Arc::new(clock.clone()));
+    /// let queue = InputQueue::from_events([InputEvent::Enter], false).with_recorder(recorder.clone());
+    /// clock.advance(Duration::from_millis(250));
+    /// queue.next_event(&CancelToken::new(), None).unwrap();
+    /// assert_eq!(recorder.inputs()[0].at_ms, 250);
+    /// ```
+    pub fn new(clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        InputRecorder {
+            origin: clock.now(),
+            clock,
+            inputs: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Consigne une saisie, horodatée à l'instant présent.
+    pub(crate) fn record(&self, event: InputEvent) {
+        let elapsed = self.clock.now().saturating_sub(self.origin);
+        let at_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        self.lock().push(RecordedInput { at_ms, event });
+    }
+
+    /// Retourne les saisies consignées, dans l'ordre de leur lecture.
+    pub fn inputs(&self) -> Vec<RecordedInput> {
+        self.lock().clone()
+    }
+
+    /// Verrouille les saisies, même si un thread a paniqué en les détenant.
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<RecordedInput>> {
+        self.inputs.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl fmt::Debug for InputRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputRecorder")
+            .field("origin", &self.origin)
+            .field("inputs", &self.lock().len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Rapport de bogue : de quoi rejouer une session à l'identique.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BugBundle {
+    /// Version du format du rapport (voir [`BUNDLE_VERSION`]).
+    pub version: u32,
+    /// Version de l'application ayant enregistré la session.
+    pub app_version: String,
+    /// Graine des objectifs et des bots de la session.
+    pub seed: u64,
+    /// Arguments de la ligne de commande, variables d'environnement `DUAL_GAME_*` comprises, sans le
+    /// nom du programme ni l'option d'enregistrement.
+    pub args: Vec<String>,
+    /// Contenu du fichier de configuration chargé, le cas échéant.
+    #[serde(default)]
+    pub config: Option<String>,
+    /// Indique si les saisies provenaient d'un terminal, ce qui détermine les questions posées.
+    pub interactive: bool,
+    /// Saisies lues pendant la session, dans l'ordre.
+    pub inputs: Vec<RecordedInput>,
+}
+
+/// Erreur survenue lors de l'enregistrement ou du chargement d'un rapport de bogue.
+#[derive(Debug)]
+pub enum BundleError {
+    /// Erreur d'entrée/sortie sur le fichier.
+    Io {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: io::Error,
+    },
+    /// Le fichier n'est pas un rapport de bogue valide.
+    Parse {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: serde_json::Error,
+    },
+    /// Le rapport a été écrit par une version plus récente de l'application.
+    UnsupportedVersion {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Version du format du rapport.
+        version: u32,
+    },
+}
+
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BundleError::Io { path, source } => {
+                write!(
+                    f,
+                    "erreur d'accès au rapport de bogue {} : {source}",
+                    path.display()
+                )
+            }
+            BundleError::Parse { path, source } => {
+                write!(f, "rapport de bogue invalide {} : {source}", path.display())
+            }
+            BundleError::UnsupportedVersion { path, version } => {
+                write!(
+                    f,
+                    "rapport de bogue {} au format {version}, plus récent que le format \
+                     {BUNDLE_VERSION} pris en charge",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl Error for BundleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BundleError::Io { source, .. } => Some(source),
+            BundleError::Parse { source, .. } => Some(source),
+            BundleError::UnsupportedVersion { .. } => None,
+        }
+    }
+}
+
+impl BugBundle {
+    /// Crée le rapport d'une session, avec les saisies consignées par l'enregistreur.
+    pub fn new(
+        seed: u64,
+        args: Vec<String>,
+        config: Option<String>,
+        interactive: bool,
+        recorder: &InputRecorder,
+    ) -> Self {
+        BugBundle {
+            version: BUNDLE_VERSION,
+            app_version: String::from(env!("CARGO_PKG_VERSION")),
+            seed,
+            args,
+            config,
+            interactive,
+            inputs: recorder.inputs(),
+        }
+    }
+
+    /// Enregistre le rapport au format JSON.
+    pub fn save(&self, path: &Path) -> Result<(), BundleError> {
+        let content = serde_json::to_string_pretty(self)
+            .expect("un rapport de bogue est toujours sérialisable");
+        write_atomic(path, &content).map_err(|source| BundleError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Charge un rapport enregistré avec [`BugBundle::save`].
+    ///
+    /// Les rapports d'une version plus récente que [`BUNDLE_VERSION`] sont refusés.
+    pub fn load(path: &Path) -> Result<Self, BundleError> {
+        let content = fs::read_to_string(path).map_err(|source| BundleError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let bundle: BugBundle =
+            serde_json::from_str(&content).map_err(|source| BundleError::Parse {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        if bundle.version > BUNDLE_VERSION {
+            return Err(BundleError::UnsupportedVersion {
+                path: path.to_path_buf(),
+                version: bundle.version,
+            });
+        }
+        Ok(bundle)
+    }
+
+    /// Retourne une file rejouant les saisies du rapport, dans l'ordre et sans attendre leurs
+    /// horodatages.
+    pub fn input_queue(&self) -> InputQueue {
+        InputQueue::from_events(
+            self.inputs.iter().map(|input| input.event),
+            self.interactive,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "cli"))]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::console::ScriptedConsole;
+    use crate::controller::{HumanController, ScriptedController, StopDecision};
+    use crate::game::{Game, GameResult};
+    use crate::output::{Output, Verbosity};
+    use crate::player::Player;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Joue une partie dont le premier joueur lit ses saisies dans la file donnée, et retourne sa
+    /// transcription.
+    fn play_with_inputs(input: InputQueue) -> String {
+        let players = vec![
+            Player::new(String::from("Alice"), 20, 75, 50),
+            Player::new(String::from("Bob"), 100, 75, 50),
+        ];
+        let mut game = Game::new(players, 2);
+        game.set_seed(11);
+        let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+        game.set_output(Output::new(
+            Box::new(Rc::clone(&console)),
+            Verbosity::Verbose,
+        ));
+        // Le compteur n'avance qu'avec l'horloge manuelle : chaque arrêt est déterministe.
+        let clock = ManualClock::new();
+        game.set_clock(Box::new(clock.clone()));
+        let alice = HumanController::default()
+            .with_input(input)
+            .with_clock(Arc::new(clock));
+        let bob = ScriptedController::new();
+        for stop in [40, 75, 10].iter().cycle().take(100) {
+            bob.push_stop(StopDecision::from_offset(*stop, 0));
+            bob.push_poison(1);
+        }
+        game.set_controller(0, Box::new(alice));
+        game.set_controller(1, Box::new(bob));
+        assert!(matches!(game.run().unwrap(), GameResult::Victory { .. }));
+        console.borrow().output.clone()
+    }
+
+    /// Vérifie qu'une partie enregistrée puis rejouée depuis son rapport de bogue donne la même
+    /// transcription, seules les saisies lues étant consignées.
+    #[test]
+    fn test_record_and_replay() {
+        let lines = ["1", "", "2", "1", "", ""].iter().cycle().take(200);
+        let events: Vec<InputEvent> = lines
+            .flat_map(|line| {
+                line.chars()
+                    .map(InputEvent::Char)
+                    .chain([InputEvent::Enter])
+            })
+            .collect();
+        let recorder = InputRecorder::new(Arc::new(ManualClock::new()));
+        let recorded = play_with_inputs(
+            InputQueue::from_events(events.clone(), false).with_recorder(recorder.clone()),
+        );
+        let inputs = recorder.inputs();
+        assert!(!inputs.is_empty() && inputs.len() < events.len());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bogue.json");
+        let bundle = BugBundle::new(
+            11,
+            vec![String::from("--name1=Alice")],
+            None,
+            false,
+            &recorder,
+        );
+        bundle.save(&path).unwrap();
+        let loaded = BugBundle::load(&path).unwrap();
+        assert_eq!(loaded, bundle);
+
+        let replayed = play_with_inputs(loaded.input_queue());
+        assert_eq!(replayed, recorded);
+    }
+}
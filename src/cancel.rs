@@ -0,0 +1,145 @@
+//! Module de gestion de l'interruption d'une partie.
+//!
+//! Un [`CancelToken`] est partagé entre le gestionnaire de Ctrl+C, la boucle de la partie, le
+//! compteur et la lecture des saisies. Il signale la demande d'interruption : chacun la vérifie à des
+//! points d'arrêt sûrs, afin de restaurer le terminal et d'afficher un bilan partiel avant de quitter.
+//! Les attentes faites par le jeton lui-même ([`CancelToken::wait_timeout`]) sont réveillées dès
+//! l'interruption, et une attente interrompue se termine par l'erreur [`Cancelled`].
+
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use crate::messages::Msg;
+use crate::tr;
+
+/// Code de sortie de l'application lorsqu'une partie est interrompue (convention 128 + SIGINT).
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Erreur d'une attente interrompue par un [`CancelToken`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", tr!(Msg::Cancelled))
+    }
+}
+
+impl Error for Cancelled {}
+
+/// État partagé par les clones d'un [`CancelToken`].
+#[derive(Debug, Default)]
+struct Shared {
+    /// Indique si l'interruption a été demandée.
+    cancelled: AtomicBool,
+    /// Verrou associé à `woken`.
+    lock: Mutex<()>,
+    /// Réveille les attentes en cours lors de l'interruption.
+    woken: Condvar,
+}
+
+/// Jeton d'annulation partagé entre plusieurs threads.
+///
+/// Les clones d'un jeton partagent le même état : annuler l'un annule tous les autres.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<Shared>);
+
+impl CancelToken {
+    /// Crée un jeton non annulé.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::cancel::CancelToken;
+    ///
+    /// let token = CancelToken::new();
+    /// let shared = token.clone();
+    /// shared.cancel();
+    /// assert!(token.is_cancelled());
+    /// ```
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    /// Demande l'interruption et réveille les attentes en cours.
+    pub fn cancel(&self) {
+        let _guard = self.0.lock.lock().unwrap_or_else(|err| err.into_inner());
+        self.0.cancelled.store(true, Ordering::SeqCst);
+        self.0.woken.notify_all();
+    }
+
+    /// Indique si l'interruption a été demandée.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Retourne [`Cancelled`] si l'interruption a été demandée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::cancel::{CancelToken, Cancelled};
+    ///
+    /// let token = CancelToken::new();
+    /// assert_eq!(token.check(), Ok(()));
+    /// token.cancel();
+    /// assert_eq!(token.check(), Err(Cancelled));
+    /// ```
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Attend la durée donnée, en s'arrêtant dès que l'interruption est demandée.
+    ///
+    /// Retourne [`Cancelled`] si l'attente a été interrompue, ou si l'interruption avait déjà été
+    /// demandée.
+    pub fn wait_timeout(&self, timeout: Duration) -> Result<(), Cancelled> {
+        let guard = self.0.lock.lock().unwrap_or_else(|err| err.into_inner());
+        let _guard = self
+            .0
+            .woken
+            .wait_timeout_while(guard, timeout, |_| !self.is_cancelled())
+            .unwrap_or_else(|err| err.into_inner());
+        self.check()
+    }
+}
+
+impl PartialEq for CancelToken {
+    /// Deux jetons sont égaux s'ils partagent le même état.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::thread;
+    use std::time::Instant;
+
+    /// Vérifie qu'une attente est réveillée dès l'interruption, sans attendre la fin du délai.
+    #[test]
+    fn test_wait_timeout() {
+        let token = CancelToken::new();
+        assert_eq!(token.wait_timeout(Duration::from_millis(10)), Ok(()));
+
+        let shared = token.clone();
+        let canceller = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            shared.cancel();
+        });
+        let started = Instant::now();
+        assert_eq!(token.wait_timeout(Duration::from_secs(10)), Err(Cancelled));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        canceller.join().unwrap();
+        assert_eq!(token.wait_timeout(Duration::from_secs(10)), Err(Cancelled));
+    }
+}
@@ -0,0 +1,214 @@
+//! Module définissant les classes de personnage prédéfinies.
+//!
+//! Ce module fournit l'énumération [`PlayerClass`] qui associe à chaque classe un ensemble de
+//! caractéristiques ([`ClassStats`]) utilisé par [`Player::from_class`](crate::player::Player::from_class).
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::messages::Msg;
+use crate::tr;
+
+/// Caractéristiques initiales associées à une classe de personnage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClassStats {
+    /// Vitalité initiale.
+    pub vitality: u32,
+    /// Vitesse (délai en millisecondes entre deux incrémentations du compteur).
+    pub speed: u32,
+    /// Force.
+    pub strength: u32,
+    /// Armure.
+    pub armor: u32,
+}
+
+/// Énumération des classes de personnage disponibles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerClass {
+    /// Caractéristiques par défaut, sans point fort ni point faible.
+    Balanced,
+    /// Compteur lent et donc plus précis, au prix d'une vitalité et d'une force réduites.
+    Sprinter,
+    /// Force élevée mais compteur plus rapide.
+    Bruiser,
+    /// Grande réserve de vitalité et armure, mais peu de force.
+    Tank,
+}
+
+impl PlayerClass {
+    /// Liste de toutes les classes disponibles.
+    pub const ALL: [PlayerClass; 4] = [
+        PlayerClass::Balanced,
+        PlayerClass::Sprinter,
+        PlayerClass::Bruiser,
+        PlayerClass::Tank,
+    ];
+
+    /// Retourne les caractéristiques initiales associées à la classe.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::class::PlayerClass;
+    ///
+    /// let stats = PlayerClass::Tank.stats();
+    /// assert!(stats.vitality > PlayerClass::Balanced.stats().vitality);
+    /// ```
+    pub fn stats(&self) -> ClassStats {
+        match self {
+            PlayerClass::Balanced => ClassStats {
+                vitality: 50,
+                speed: 75,
+                strength: 50,
+                armor: 0,
+            },
+            PlayerClass::Sprinter => ClassStats {
+                vitality: 40,
+                speed: 115,
+                strength: 47,
+                armor: 0,
+            },
+            PlayerClass::Bruiser => ClassStats {
+                vitality: 44,
+                speed: 60,
+                strength: 53,
+                armor: 0,
+            },
+            PlayerClass::Tank => ClassStats {
+                vitality: 60,
+                speed: 90,
+                strength: 47,
+                armor: 3,
+            },
+        }
+    }
+
+    /// Retourne l'identifiant de la classe utilisé en ligne de commande.
+    pub fn id(&self) -> &'static str {
+        match self {
+            PlayerClass::Balanced => "balanced",
+            PlayerClass::Sprinter => "sprinter",
+            PlayerClass::Bruiser => "bruiser",
+            PlayerClass::Tank => "tank",
+        }
+    }
+}
+
+impl fmt::Display for PlayerClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            PlayerClass::Balanced => Msg::ClassBalanced,
+            PlayerClass::Sprinter => Msg::ClassSprinter,
+            PlayerClass::Bruiser => Msg::ClassBruiser,
+            PlayerClass::Tank => Msg::ClassTank,
+        };
+        write!(f, "{}", tr!(label))
+    }
+}
+
+impl FromStr for PlayerClass {
+    type Err = UnknownClass;
+
+    /// Analyse une classe à partir de son identifiant (insensible à la casse).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s.trim().to_lowercase();
+        PlayerClass::ALL
+            .into_iter()
+            .find(|class| class.id() == id)
+            .ok_or_else(|| UnknownClass(s.to_string()))
+    }
+}
+
+/// Erreur d'un identifiant de classe inconnu.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownClass(pub String);
+
+impl fmt::Display for UnknownClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ids: Vec<&str> = PlayerClass::ALL.iter().map(|class| class.id()).collect();
+        write!(
+            f,
+            "{}",
+            tr!(Msg::UnknownClass, name = self.0, names = ids.join(", "))
+        )
+    }
+}
+
+impl Error for UnknownClass {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::RandomBot;
+    use crate::game::{Game, GameResult};
+    use crate::output::Verbosity;
+    use crate::player::Player;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Imprécision d'un joueur simulé, en millisecondes, lors de l'arrêt du compteur.
+    const REACTION_JITTER_MS: f64 = 250.0;
+
+    /// Joue une partie silencieuse entre deux bots et retourne l'index du vainqueur, le cas échéant.
+    ///
+    /// L'imprécision de chaque bot, exprimée en incrémentations, est d'autant plus faible que son
+    /// compteur est lent.
+    fn simulated_game(players: [Player; 2], rng: &mut StdRng) -> Option<usize> {
+        let sigmas = players
+            .each_ref()
+            .map(|p| REACTION_JITTER_MS / f64::from(p.speed.max(1)));
+        let mut game = Game::new(players.to_vec(), 5);
+        game.set_verbosity(Verbosity::Silent);
+        game.set_seed(rng.random());
+        for (index, sigma) in sigmas.into_iter().enumerate() {
+            let bot = RandomBot::with_sigma(StdRng::seed_from_u64(rng.random()), sigma);
+            game.set_controller(index, Box::new(bot));
+        }
+        match game.run().unwrap() {
+            GameResult::Victory { winner } | GameResult::Forfeit { winner, .. } => {
+                Some(winner.index())
+            }
+            GameResult::Draw | GameResult::Interrupted => None,
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("Tank".parse::<PlayerClass>(), Ok(PlayerClass::Tank));
+        assert_eq!(
+            " sprinter ".parse::<PlayerClass>(),
+            Ok(PlayerClass::Sprinter)
+        );
+        assert_eq!(
+            "mage".parse::<PlayerClass>(),
+            Err(UnknownClass(String::from("mage")))
+        );
+    }
+
+    /// Vérifie par simulation que chaque classe remporte une part raisonnable de ses parties
+    /// contre la classe équilibrée.
+    #[test]
+    fn test_each_class_can_beat_balanced() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for class in PlayerClass::ALL {
+            let games = 200;
+            let wins = (0..games)
+                .filter(|_| {
+                    let players = [
+                        Player::from_class(String::from("A"), class),
+                        Player::from_class(String::from("B"), PlayerClass::Balanced),
+                    ];
+                    simulated_game(players, &mut rng) == Some(0)
+                })
+                .count();
+            assert!(
+                wins >= games / 4,
+                "{class} ne gagne que {wins} parties sur {games}"
+            );
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! Module de l'horloge utilisée par le moteur.
+//!
+//! Le moteur mesure le temps pour la durée de chaque arrêt du compteur, affichée en mode détaillé,
+//! pour cadencer les animations du [`FancyRenderer`](crate::renderer::FancyRenderer), les
+//! incrémentations du compteur et les pauses du mode démonstration. Le trait [`Clock`] rend cette
+//! source injectable : [`SystemClock`] s'appuie sur [`std::time::Instant`], qui n'existe pas sur
+//! `wasm32-unknown-unknown`, tandis que [`ManualClock`] n'avance que lorsque l'hôte (une page web, un
+//! test) le lui demande, y compris pendant une attente : un test simule ainsi plusieurs secondes de
+//! compteur sans les attendre. Une [`PausableClock`] s'appuie sur l'une ou l'autre et s'arrête
+//! pendant la pause du compteur : le temps passé en pause n'est compté ni dans les incrémentations,
+//! ni dans la durée de l'arrêt.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use std::time::Instant;
+
+/// Intervalle réel entre deux vérifications d'une [`ManualClock`] pendant une attente.
+const MANUAL_POLL: Duration = Duration::from_millis(1);
+
+/// Intervalle entre deux vérifications de la reprise d'une [`PausableClock`] en pause.
+const PAUSE_POLL: Duration = Duration::from_millis(20);
+
+/// Source du temps écoulé.
+pub trait Clock {
+    /// Retourne le temps écoulé depuis une origine propre à l'horloge.
+    fn now(&self) -> Duration;
+
+    /// Attend que la durée donnée soit écoulée, par exemple entre deux images d'une animation.
+    fn sleep(&self, duration: Duration);
+
+    /// Retourne la durée réelle au bout de laquelle vérifier à nouveau si l'horloge a atteint
+    /// l'instant `deadline`, pour une attente que d'autres événements peuvent écourter.
+    ///
+    /// Par défaut, le temps restant avant `deadline` : l'horloge avance d'elle-même.
+    fn poll_delay(&self, deadline: Duration) -> Duration {
+        deadline.saturating_sub(self.now())
+    }
+}
+
+/// Horloge monotone du système, dont l'origine est sa création.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[derive(Clone, Copy, Debug)]
+pub struct SystemClock {
+    /// Instant de création de l'horloge.
+    origin: Instant,
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+impl Default for SystemClock {
+    fn default() -> Self {
+        SystemClock {
+            origin: Instant::now(),
+        }
+    }
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// Horloge avancée explicitement par l'hôte.
+///
+/// Les clones partagent le même temps : l'hôte conserve un clone pour l'avancer pendant que la partie
+/// lit l'autre, éventuellement depuis un autre thread (celui du compteur, par exemple).
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock {
+    /// Temps écoulé depuis l'origine.
+    now: Arc<Mutex<Duration>>,
+}
+
+impl ManualClock {
+    /// Crée une horloge arrêtée à son origine.
+    pub fn new() -> Self {
+        ManualClock::default()
+    }
+
+    /// Avance l'horloge de la durée donnée.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap_or_else(|err| err.into_inner()) += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// N'attend pas : avance l'horloge de la durée donnée.
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+
+    /// L'horloge n'avançant qu'à la demande de l'hôte, l'instant `deadline` est vérifié à nouveau
+    /// chaque milliseconde tant qu'il n'est pas atteint.
+    fn poll_delay(&self, deadline: Duration) -> Duration {
+        if self.now() >= deadline {
+            Duration::ZERO
+        } else {
+            MANUAL_POLL
+        }
+    }
+}
+
+/// État des pauses d'une [`PausableClock`], exprimé dans le temps de l'horloge sous-jacente.
+#[derive(Clone, Copy, Debug, Default)]
+struct PauseState {
+    /// Instant du début de la pause en cours, `None` hors d'une pause.
+    since: Option<Duration>,
+    /// Durée cumulée des pauses terminées.
+    total: Duration,
+}
+
+/// Horloge suspendue pendant les pauses, dont le temps ne compte que les périodes hors pause.
+///
+/// Les clones partagent les mêmes pauses : le thread du compteur lit l'horloge pendant que le menu de
+/// pause la suspend puis la reprend.
+#[derive(Clone)]
+pub struct PausableClock {
+    /// Horloge sous-jacente.
+    inner: Arc<dyn Clock + Send + Sync>,
+    /// Pauses de l'horloge.
+    state: Arc<Mutex<PauseState>>,
+}
+
+impl PausableClock {
+    /// Crée une horloge suspendable s'appuyant sur l'horloge donnée, hors pause.
+    pub fn new(inner: Arc<dyn Clock + Send + Sync>) -> Self {
+        PausableClock {
+            inner,
+            state: Arc::default(),
+        }
+    }
+
+    /// Retourne l'état des pauses, même si un thread a paniqué en le détenant.
+    fn state(&self) -> PauseState {
+        *self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Suspend l'horloge ; sans effet si elle est déjà en pause.
+    pub fn pause(&self) {
+        let now = self.inner.now();
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        state.since.get_or_insert(now);
+    }
+
+    /// Reprend l'horloge ; sans effet hors d'une pause.
+    pub fn resume(&self) {
+        let now = self.inner.now();
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(since) = state.since.take() {
+            state.total += now.saturating_sub(since);
+        }
+    }
+
+    /// Indique si l'horloge est en pause.
+    pub fn is_paused(&self) -> bool {
+        self.state().since.is_some()
+    }
+
+    /// Retourne la durée cumulée des pauses, y compris celle en cours.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// use dual_game::clock::{Clock, ManualClock, PausableClock};
+    ///
+    /// let clock = ManualClock::new();
+    /// let pausable = PausableClock::new(Arc::new(clock.clone()));
+    /// clock.advance(Duration::from_secs(1));
+    /// pausable.pause();
+    /// clock.advance(Duration::from_secs(30));
+    /// pausable.resume();
+    /// clock.advance(Duration::from_secs(1));
+    /// assert_eq!(pausable.now(), Duration::from_secs(2));
+    /// assert_eq!(pausable.paused(), Duration::from_secs(30));
+    /// ```
+    pub fn paused(&self) -> Duration {
+        let state = self.state();
+        let ongoing = state.since.map_or(Duration::ZERO, |since| {
+            self.inner.now().saturating_sub(since)
+        });
+        state.total + ongoing
+    }
+}
+
+impl fmt::Debug for PausableClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PausableClock")
+            .field("state", &self.state())
+            .finish()
+    }
+}
+
+impl Clock for PausableClock {
+    /// Retourne le temps de l'horloge sous-jacente moins les pauses : il n'avance pas pendant une
+    /// pause.
+    fn now(&self) -> Duration {
+        let state = self.state();
+        let now = state.since.unwrap_or_else(|| self.inner.now());
+        now.saturating_sub(state.total)
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.inner.sleep(duration);
+    }
+
+    /// Pendant une pause, l'instant `deadline` ne peut être atteint : la reprise est vérifiée
+    /// régulièrement. Hors pause, l'instant est décalé de la durée des pauses pour l'horloge
+    /// sous-jacente.
+    fn poll_delay(&self, deadline: Duration) -> Duration {
+        let state = self.state();
+        if state.since.is_some() {
+            self.inner.poll_delay(self.inner.now() + PAUSE_POLL)
+        } else {
+            self.inner.poll_delay(deadline + state.total)
+        }
+    }
+}
+
+/// Retourne l'horloge d'une nouvelle partie : celle du système lorsqu'elle existe, une horloge
+/// manuelle sur `wasm32-unknown-unknown`.
+pub(crate) fn default_clock() -> Box<dyn Clock> {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    return Box::new(SystemClock::default());
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    return Box::new(ManualClock::new());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que les clones d'une horloge manuelle partagent le temps avancé par l'hôte.
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::new();
+        let game_clock: Box<dyn Clock> = Box::new(clock.clone());
+        assert_eq!(game_clock.now(), Duration::ZERO);
+        clock.advance(Duration::from_millis(1500));
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(game_clock.now(), Duration::from_millis(1750));
+    }
+
+    /// Vérifie que le temps d'une horloge suspendable s'arrête pendant les pauses, répétées ou
+    /// redondantes, et que ses attentes tiennent compte des pauses passées.
+    #[test]
+    fn test_pausable_clock() {
+        let clock = ManualClock::new();
+        let pausable = PausableClock::new(Arc::new(clock.clone()));
+        clock.advance(Duration::from_millis(400));
+        pausable.pause();
+        pausable.pause();
+        assert!(pausable.is_paused());
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(pausable.now(), Duration::from_millis(400));
+        assert_eq!(pausable.poll_delay(Duration::from_millis(400)), MANUAL_POLL);
+        pausable.resume();
+        pausable.resume();
+        assert!(!pausable.is_paused());
+        assert_eq!(
+            pausable.poll_delay(Duration::from_millis(400)),
+            Duration::ZERO
+        );
+        assert_eq!(pausable.poll_delay(Duration::from_millis(401)), MANUAL_POLL);
+
+        clock.advance(Duration::from_millis(100));
+        pausable.pause();
+        clock.advance(Duration::from_secs(5));
+        pausable.resume();
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(pausable.now(), Duration::from_millis(600));
+        assert_eq!(pausable.paused(), Duration::from_secs(65));
+    }
+}
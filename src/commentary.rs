@@ -0,0 +1,368 @@
+//! Module des commentaires de fin de manche.
+//!
+//! À la fin de chaque manche, [`for_round`] rédige une ligne de commentaire à partir de ses chiffres
+//! réels, relevés dans l'historique de la partie ([`GameHistory`]) : la manche est d'abord rangée
+//! dans une [`Category`] (tour parfait, raté désastreux, manche nulle, remontée, victoire écrasante,
+//! manche serrée), puis l'un des modèles de phrases de cette catégorie est tiré par un générateur
+//! initialisé avec le numéro de la manche. Le commentaire d'une manche est ainsi toujours le même,
+//! et varie d'une manche à l'autre.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::messages::Msg;
+use crate::player::PlayerId;
+use crate::replay::{RoundRecord, TurnRecord};
+use crate::stats::{GameHistory, is_perfect};
+use crate::tr;
+
+/// Écart minimal entre les scores moyens d'une victoire écrasante.
+pub const BLOWOUT_GAP: u32 = 50;
+/// Écart maximal entre les scores moyens d'une manche serrée.
+pub const NAIL_BITER_GAP: u32 = 3;
+/// Nombre minimal de « miss » d'un tour désastreux.
+pub const DISASTER_MISSES: u32 = 3;
+
+/// Catégorie d'une manche, qui détermine les modèles de son commentaire.
+///
+/// Lorsque plusieurs catégories conviennent, la plus remarquable l'emporte, dans l'ordre des
+/// variantes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// Un joueur a arrêté le compteur exactement sur chacun de ses objectifs.
+    Perfect,
+    /// Un joueur a cumulé au moins [`DISASTER_MISSES`] « miss » pendant son tour.
+    Disaster,
+    /// La manche est nulle.
+    Draw,
+    /// Le vainqueur avait moins de vitalité que le perdant avant la manche.
+    Comeback,
+    /// Les scores moyens sont séparés d'au moins [`BLOWOUT_GAP`] points.
+    Blowout,
+    /// Les scores moyens sont séparés d'au plus [`NAIL_BITER_GAP`] points.
+    NailBiter,
+    /// Aucune des catégories précédentes.
+    Standard,
+}
+
+impl Category {
+    /// Retourne l'identifiant de la catégorie.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Category::Perfect => "perfect",
+            Category::Disaster => "disaster",
+            Category::Draw => "draw",
+            Category::Comeback => "comeback",
+            Category::Blowout => "blowout",
+            Category::NailBiter => "nail_biter",
+            Category::Standard => "standard",
+        }
+    }
+
+    /// Retourne les modèles de commentaire de la catégorie.
+    fn templates(&self) -> &'static [Msg] {
+        match self {
+            Category::Perfect => &[Msg::CommentaryPerfect1, Msg::CommentaryPerfect2],
+            Category::Disaster => &[Msg::CommentaryDisaster1, Msg::CommentaryDisaster2],
+            Category::Draw => &[Msg::CommentaryDraw1, Msg::CommentaryDraw2],
+            Category::Comeback => &[Msg::CommentaryComeback1, Msg::CommentaryComeback2],
+            Category::Blowout => &[Msg::CommentaryBlowout1, Msg::CommentaryBlowout2],
+            Category::NailBiter => &[Msg::CommentaryNailBiter1, Msg::CommentaryNailBiter2],
+            Category::Standard => &[Msg::CommentaryStandard1, Msg::CommentaryStandard2],
+        }
+    }
+}
+
+/// Chiffres d'une manche retenus pour son commentaire.
+struct Facts<'a> {
+    /// Tours joués pendant la manche.
+    turns: Vec<&'a TurnRecord>,
+    /// Vainqueur et perdant de la manche, `None` pour une manche nulle.
+    result: Option<(usize, usize)>,
+    /// Dégâts retirés à la vitalité du perdant.
+    dealt: u32,
+    /// Écart entre le meilleur et le moins bon score moyen.
+    gap: u32,
+}
+
+impl<'a> Facts<'a> {
+    /// Relève les chiffres de la manche dans l'historique.
+    fn new(record: &RoundRecord, history: &GameHistory<'a>) -> Self {
+        let turns: Vec<&TurnRecord> = history
+            .turns
+            .iter()
+            .filter(|turn| turn.round == record.round)
+            .collect();
+        let damage = history
+            .damage
+            .iter()
+            .find(|damage| damage.round == record.round);
+        let averages = turns.iter().map(|turn| turn.average);
+        let gap = averages.clone().max().unwrap_or(0) - averages.min().unwrap_or(0);
+        Facts {
+            turns,
+            result: damage.map(|damage| (damage.winner, damage.loser)),
+            dealt: damage.map_or(0, |damage| damage.dealt),
+            gap,
+        }
+    }
+
+    /// Retourne la catégorie de la manche.
+    fn category(&self, record: &RoundRecord) -> Category {
+        if self.perfect_turn().is_some() {
+            return Category::Perfect;
+        }
+        if self.disastrous_turn().is_some() {
+            return Category::Disaster;
+        }
+        let Some((winner, loser)) = self.result else {
+            return Category::Draw;
+        };
+        // Seul le perdant a perdu de la vitalité pendant la manche.
+        let vitality = |index: usize| {
+            record
+                .players
+                .iter()
+                .find(|snapshot| snapshot.id == PlayerId(index))
+                .map_or(0, |snapshot| snapshot.vitality)
+        };
+        if vitality(winner) < vitality(loser) + self.dealt {
+            Category::Comeback
+        } else if self.gap >= BLOWOUT_GAP {
+            Category::Blowout
+        } else if self.gap <= NAIL_BITER_GAP {
+            Category::NailBiter
+        } else {
+            Category::Standard
+        }
+    }
+
+    /// Retourne le premier tour dont chaque arrêt est parfait.
+    fn perfect_turn(&self) -> Option<&TurnRecord> {
+        self.turns
+            .iter()
+            .copied()
+            .find(|turn| !turn.stops.is_empty() && turn.stops.iter().all(is_perfect))
+    }
+
+    /// Retourne le tour qui cumule le plus de « miss », s'il en compte au moins
+    /// [`DISASTER_MISSES`].
+    fn disastrous_turn(&self) -> Option<(&TurnRecord, u32)> {
+        self.turns
+            .iter()
+            .map(|turn| (*turn, turn.stops.iter().map(|stop| stop.miss).sum::<u32>()))
+            .filter(|(_, misses)| *misses >= DISASTER_MISSES)
+            .max_by_key(|(_, misses)| *misses)
+    }
+}
+
+/// Retourne la catégorie d'une manche terminée, d'après l'historique de la partie.
+pub fn category(record: &RoundRecord, history: &GameHistory) -> Category {
+    Facts::new(record, history).category(record)
+}
+
+/// Rédige le commentaire d'une manche terminée, d'après l'historique de la partie.
+///
+/// Le modèle est tiré parmi ceux de la [`Category`] de la manche par un générateur initialisé avec
+/// le numéro de la manche.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::commentary::for_round;
+/// use dual_game::player::{Player, PlayerId};
+/// use dual_game::replay::{DrawRecord, PlayerNames, RoundRecord};
+/// use dual_game::stats::GameHistory;
+///
+/// let players = [
+///     Player::new(String::from("Alice"), 50, 50, 50),
+///     Player::new(String::from("Bob"), 50, 50, 50),
+/// ];
+/// let names = PlayerNames::from_players(&players);
+/// let draws = [DrawRecord { round: 1, score: 80, consecutive: 1 }];
+/// let record = RoundRecord {
+///     round: 1,
+///     players: vec![players[0].snapshot(PlayerId(0)), players[1].snapshot(PlayerId(1))],
+///     mutator: None,
+/// };
+/// let history = GameHistory { names: &names, turns: &[], damage: &[], draws: &draws, rounds: &[] };
+/// assert!(for_round(&record, &history).contains("Alice"));
+/// ```
+pub fn for_round(record: &RoundRecord, history: &GameHistory) -> String {
+    let facts = Facts::new(record, history);
+    let category = facts.category(record);
+    let templates = category.templates();
+    let mut rng = StdRng::seed_from_u64(u64::from(record.round));
+    let template = templates[rng.random_range(0..templates.len())];
+    let name = |index: usize| history.names.name(PlayerId(index)).unwrap_or("?");
+    let (winner, loser) = facts
+        .result
+        .map_or(("?", "?"), |(winner, loser)| (name(winner), name(loser)));
+    let player = match category {
+        Category::Perfect => facts.perfect_turn().map(|turn| name(turn.player)),
+        Category::Disaster => facts.disastrous_turn().map(|(turn, _)| name(turn.player)),
+        _ => None,
+    };
+    let misses = facts.disastrous_turn().map_or(0, |(_, misses)| misses);
+    let (first, second) = (name(0), name(1));
+    tr!(
+        template,
+        winner = winner,
+        loser = loser,
+        player = player.unwrap_or("?"),
+        first = first,
+        second = second,
+        gap = facts.gap,
+        dealt = facts.dealt,
+        misses = misses
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::Player;
+    use crate::replay::{DamageRecord, DrawRecord, PlayerNames, StopRecord};
+
+    /// Construit le tour d'un joueur à partir de ses arrêts `(objectif, compteur, miss)`.
+    fn turn(player: usize, average: u32, stops: &[(u32, u32, u32)]) -> TurnRecord {
+        TurnRecord {
+            round: 1,
+            player,
+            stops: stops
+                .iter()
+                .map(|&(objective, counter, miss)| StopRecord {
+                    objective,
+                    counter,
+                    miss,
+                    score: average,
+                    retried: false,
+                })
+                .collect(),
+            average,
+            risk: None,
+        }
+    }
+
+    /// Construit la fin de la première manche, avec la vitalité de chaque joueur.
+    fn record(vitalities: [u32; 2]) -> RoundRecord {
+        let players = vitalities
+            .iter()
+            .enumerate()
+            .map(|(index, &vitality)| {
+                Player::new(format!("Joueur {index}"), vitality, 50, 50).snapshot(PlayerId(index))
+            })
+            .collect();
+        RoundRecord {
+            round: 1,
+            players,
+            mutator: None,
+        }
+    }
+
+    /// Retourne la catégorie d'une première manche remportée par le joueur 0, ou nulle.
+    fn classify(turns: &[TurnRecord], won: Option<u32>, vitalities: [u32; 2]) -> Category {
+        let names = PlayerNames::from(vec![String::from("Alice"), String::from("Bob")]);
+        let damage: Vec<DamageRecord> = won
+            .map(|dealt| DamageRecord {
+                round: 1,
+                winner: 0,
+                loser: 1,
+                dealt,
+                time_tiebreak: false,
+                winners_curse: false,
+            })
+            .into_iter()
+            .collect();
+        let draws: Vec<DrawRecord> = match won {
+            Some(_) => Vec::new(),
+            None => vec![DrawRecord {
+                round: 1,
+                score: turns[0].average,
+                consecutive: 1,
+            }],
+        };
+        let history = GameHistory {
+            names: &names,
+            turns,
+            damage: &damage,
+            draws: &draws,
+            rounds: &[],
+        };
+        category(&record(vitalities), &history)
+    }
+
+    /// Vérifie la catégorie retenue pour des manches représentatives, et l'ordre de priorité entre
+    /// catégories.
+    #[test]
+    fn test_category() {
+        let normal = |average| turn(0, average, &[(40, 45, 0), (70, 66, 0)]);
+        let loser = |average| turn(1, average, &[(30, 20, 0), (80, 95, 0)]);
+
+        assert_eq!(
+            classify(&[normal(130), loser(70)], Some(60), [100, 30]),
+            Category::Blowout
+        );
+        assert_eq!(
+            classify(&[normal(101), loser(99)], Some(2), [50, 48]),
+            Category::NailBiter
+        );
+        assert_eq!(
+            classify(&[normal(120), loser(100)], Some(20), [50, 30]),
+            Category::Standard
+        );
+        // Alice avait 20 de vitalité contre 40 pour Bob avant la manche.
+        assert_eq!(
+            classify(&[normal(120), loser(100)], Some(20), [20, 20]),
+            Category::Comeback
+        );
+        assert_eq!(
+            classify(&[normal(100), loser(100)], None, [50, 50]),
+            Category::Draw
+        );
+        let perfect = turn(0, 150, &[(40, 40, 0), (70, 70, 0)]);
+        assert_eq!(
+            classify(&[perfect.clone(), loser(70)], Some(80), [50, 20]),
+            Category::Perfect
+        );
+        let disaster = turn(1, 20, &[(30, 20, 2), (80, 95, 1)]);
+        assert_eq!(
+            classify(&[normal(130), disaster.clone()], Some(110), [50, 20]),
+            Category::Disaster
+        );
+        assert_eq!(
+            classify(&[perfect, disaster], Some(130), [50, 20]),
+            Category::Perfect
+        );
+    }
+
+    /// Vérifie que le commentaire d'une manche est toujours le même et cite les chiffres de la
+    /// manche.
+    #[test]
+    fn test_for_round() {
+        let names = PlayerNames::from(vec![String::from("Alice"), String::from("Bob")]);
+        let turns = [
+            turn(0, 130, &[(40, 45, 0), (70, 66, 0)]),
+            turn(1, 70, &[(30, 20, 0), (80, 95, 0)]),
+        ];
+        let damage = [DamageRecord {
+            round: 1,
+            winner: 0,
+            loser: 1,
+            dealt: 60,
+            time_tiebreak: false,
+            winners_curse: false,
+        }];
+        let history = GameHistory {
+            names: &names,
+            turns: &turns,
+            damage: &damage,
+            draws: &[],
+            rounds: &[],
+        };
+        let record = record([100, 30]);
+        let text = for_round(&record, &history);
+        assert_eq!(text, for_round(&record, &history));
+        assert!(text.contains("Alice") && text.contains("60"), "{text}");
+    }
+}
@@ -0,0 +1,513 @@
+//! Module de chargement du fichier de configuration `dual_game.toml`.
+//!
+//! Le fichier de configuration fournit des valeurs par défaut pour les joueurs, les règles de la partie
+//! et l'affichage. Les options de ligne de commande l'emportent toujours sur le fichier, qui l'emporte
+//! lui-même sur les valeurs par défaut du jeu. Les clés inconnues sont signalées sans bloquer le chargement.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::class::PlayerClass;
+use crate::controller::Difficulty;
+use crate::rules::GameMode;
+use crate::style::{Color, Theme};
+
+/// Nom du fichier de configuration recherché dans le répertoire courant.
+pub const CONFIG_FILE_NAME: &str = "dual_game.toml";
+
+/// Modèle commenté écrit par `dual_game config init`.
+pub const TEMPLATE: &str = r#"# Configuration de dual_game.
+#
+# Les options de ligne de commande l'emportent sur ce fichier. Décommentez les clés à modifier.
+
+[game]
+# Nombre d'objectifs par tour.
+# objectifs = 5
+# Vitalité initiale des joueurs.
+# vitality = 50
+# Armure des joueurs.
+# armor = 0
+# Fatigue après la 5e manche.
+# fatigue = false
+# Endurance (touche s pour ralentir le compteur).
+# stamina = false
+# Boutique d'objets entre les manches.
+# shop = false
+# Mode miroir : les joueurs échangent leur vitesse et leur force.
+# mirror = false
+# Prise de risque : 3, 5 ou 7 objectifs choisis avant chaque tour.
+# risk_choice = false
+# Mutateurs : un modificateur tiré au sort au début de chaque manche.
+# mutators = false
+# Interdictions : le joueur qui a le moins de vitalité interdit un poison pour la manche.
+# bans = false
+# Rejeu : le perdant de chaque manche peut rejouer un objectif lors de la manche suivante.
+# retry = false
+# Départage au temps : une manche à égalité revient au joueur le plus rapide.
+# time_tiebreak = false
+# Poison différé : le poison ne fait effet qu'au début de la manche suivante.
+# delayed_poison = false
+# Malédiction du vainqueur : une manche gagnée de plus de 60 points ralentit le vainqueur à la suivante.
+# winners_curse = false
+# Série au meilleur des N parties.
+# best_of = 3
+# Report de vitalité du vainqueur de chaque partie de la série.
+# carry_over = false
+# Difficulté du bot contrôlant le deuxième joueur (easy, normal, hard).
+# bot = "normal"
+# Déroulement des manches (classic, duel, shared).
+# mode = "classic"
+# Vitalité perdue par point d'écart en duel.
+# duel_factor = 10
+# Anti-stagnation : manches observées avant d'imposer des dégâts minimaux (0 pour la désactiver).
+# stalemate_rounds = 5
+
+[player1]
+# name = "Alice"
+# profile = "alice"
+# class = "balanced"  # balanced, sprinter, bruiser, tank
+# color = "cyan"      # red, green, yellow, blue, magenta, cyan
+# luck = 0
+# avatar = "🦊"
+
+[player2]
+# name = "Bob"
+# profile = "bob"
+# class = "balanced"
+# color = "magenta"
+# luck = 0
+# avatar = "🐻"
+
+[ui]
+# Couleurs dans le terminal.
+# color = true
+# Affichage limité aux caractères ASCII.
+# ascii = false
+# Thème de l'affichage.
+# theme = "default"  # default, high-contrast, deuteranopia
+
+[pacing]
+# Compte à rebours de 3 secondes au lieu d'attendre ENTREE au début de chaque tour.
+# auto_continue = false
+# ENTREE demandée à chaque joueur entre deux manches.
+# pause_between_rounds = false
+# Pause en millisecondes après le résultat de chaque objectif.
+# turn_delay = 0
+
+[keys]
+# Touches du jeu : un caractère, "enter" ou "space" (remplacées par --bind action=touche).
+# stop = "enter"
+# freeze = "f"
+# stamina = "s"
+# forfeit = "q"
+# pause = "p"
+# lock1 = "a"
+# lock2 = "l"
+"#;
+
+/// Règles de la partie définies dans la section `[game]`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct GameConfig {
+    /// Nombre d'objectifs par tour.
+    pub objectifs: Option<usize>,
+    /// Vitalité initiale des joueurs.
+    pub vitality: Option<u32>,
+    /// Armure des joueurs.
+    pub armor: Option<u32>,
+    /// Active la fatigue.
+    pub fatigue: Option<bool>,
+    /// Active l'endurance.
+    pub stamina: Option<bool>,
+    /// Active la boutique d'objets.
+    pub shop: Option<bool>,
+    /// Active le mode miroir.
+    pub mirror: Option<bool>,
+    /// Active la prise de risque.
+    pub risk_choice: Option<bool>,
+    /// Active les mutateurs.
+    pub mutators: Option<bool>,
+    /// Active les interdictions de poison.
+    pub bans: Option<bool>,
+    /// Active le rejeu d'un objectif par le perdant de la manche précédente.
+    pub retry: Option<bool>,
+    /// Active le départage au temps des manches à égalité.
+    pub time_tiebreak: Option<bool>,
+    /// Active le poison différé.
+    pub delayed_poison: Option<bool>,
+    /// Active la malédiction du vainqueur.
+    pub winners_curse: Option<bool>,
+    /// Nombre de parties d'une série au meilleur des N.
+    pub best_of: Option<u32>,
+    /// Active le report de vitalité en série.
+    pub carry_over: Option<bool>,
+    /// Difficulté du bot contrôlant le deuxième joueur.
+    pub bot: Option<Difficulty>,
+    /// Déroulement des manches.
+    pub mode: Option<GameMode>,
+    /// Vitalité perdue par point d'écart en duel.
+    pub duel_factor: Option<u32>,
+    /// Nombre de manches observées par l'anti-stagnation.
+    pub stalemate_rounds: Option<u32>,
+    /// Clés non reconnues.
+    #[serde(flatten)]
+    unknown: BTreeMap<String, toml::Value>,
+}
+
+/// Valeurs par défaut d'un joueur définies dans les sections `[player1]` et `[player2]`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct PlayerConfig {
+    /// Nom du joueur.
+    pub name: Option<String>,
+    /// Profil persistant du joueur.
+    pub profile: Option<String>,
+    /// Classe du joueur.
+    pub class: Option<PlayerClass>,
+    /// Couleur du nom du joueur.
+    pub color: Option<Color>,
+    /// Chance du joueur.
+    pub luck: Option<u32>,
+    /// Avatar du joueur.
+    pub avatar: Option<String>,
+    /// Clés non reconnues.
+    #[serde(flatten)]
+    unknown: BTreeMap<String, toml::Value>,
+}
+
+/// Options d'affichage définies dans la section `[ui]`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct UiConfig {
+    /// Active les couleurs.
+    pub color: Option<bool>,
+    /// Limite l'affichage aux caractères ASCII.
+    pub ascii: Option<bool>,
+    /// Thème de l'affichage.
+    pub theme: Option<Theme>,
+    /// Clés non reconnues.
+    #[serde(flatten)]
+    unknown: BTreeMap<String, toml::Value>,
+}
+
+/// Cadence des manches définie dans la section `[pacing]` (voir [`Pacing`](crate::pacing::Pacing)).
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct PacingConfig {
+    /// Remplace l'attente d'ENTREE au début de chaque tour par un compte à rebours.
+    pub auto_continue: Option<bool>,
+    /// Demande à chaque joueur de confirmer le passage à la manche suivante.
+    pub pause_between_rounds: Option<bool>,
+    /// Pause en millisecondes après le résultat de chaque objectif.
+    pub turn_delay: Option<u64>,
+    /// Clés non reconnues.
+    #[serde(flatten)]
+    unknown: BTreeMap<String, toml::Value>,
+}
+
+/// Touches du jeu définies dans la section `[keys]`, au format de `--bind` (voir
+/// [`KeyBindings`](crate::keys::KeyBindings)).
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct KeysConfig {
+    /// Arrêt du compteur.
+    pub stop: Option<String>,
+    /// Gel du compteur.
+    pub freeze: Option<String>,
+    /// Dépense d'un point d'endurance.
+    pub stamina: Option<String>,
+    /// Abandon de la partie.
+    pub forfeit: Option<String>,
+    /// Pause du compteur.
+    pub pause: Option<String>,
+    /// Verrou du premier joueur en mode partagé.
+    pub lock1: Option<String>,
+    /// Verrou du second joueur en mode partagé.
+    pub lock2: Option<String>,
+    /// Clés non reconnues.
+    #[serde(flatten)]
+    unknown: BTreeMap<String, toml::Value>,
+}
+
+impl KeysConfig {
+    /// Retourne les touches définies, sous la forme `action=touche` acceptée par
+    /// [`KeyBindings::bind`](crate::keys::KeyBindings::bind).
+    pub fn bindings(&self) -> Vec<String> {
+        [
+            ("stop", &self.stop),
+            ("freeze", &self.freeze),
+            ("stamina", &self.stamina),
+            ("forfeit", &self.forfeit),
+            ("pause", &self.pause),
+            ("lock1", &self.lock1),
+            ("lock2", &self.lock2),
+        ]
+        .into_iter()
+        .filter_map(|(action, key)| key.as_ref().map(|key| format!("{action}={key}")))
+        .collect()
+    }
+}
+
+/// Contenu du fichier de configuration.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Règles de la partie.
+    pub game: GameConfig,
+    /// Valeurs par défaut du premier joueur.
+    pub player1: PlayerConfig,
+    /// Valeurs par défaut du deuxième joueur.
+    pub player2: PlayerConfig,
+    /// Options d'affichage.
+    pub ui: UiConfig,
+    /// Cadence des manches.
+    pub pacing: PacingConfig,
+    /// Touches du jeu.
+    pub keys: KeysConfig,
+    /// Sections non reconnues.
+    #[serde(flatten)]
+    unknown: BTreeMap<String, toml::Value>,
+}
+
+/// Erreur survenue lors du chargement ou de la création du fichier de configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// Erreur d'entrée/sortie sur le fichier de configuration.
+    Io {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: io::Error,
+    },
+    /// Le fichier de configuration n'est pas un TOML valide.
+    Parse {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: toml::de::Error,
+    },
+    /// Le fichier à créer existe déjà.
+    AlreadyExists(PathBuf),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, source } => write!(
+                f,
+                "erreur d'accès à la configuration {} : {source}",
+                path.display()
+            ),
+            ConfigError::Parse { path, source } => {
+                write!(f, "configuration invalide {} : {source}", path.display())
+            }
+            ConfigError::AlreadyExists(path) => write!(
+                f,
+                "le fichier {} existe déjà (utilisez --force pour l'écraser)",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io { source, .. } => Some(source),
+            ConfigError::Parse { source, .. } => Some(source),
+            ConfigError::AlreadyExists(_) => None,
+        }
+    }
+}
+
+impl Config {
+    /// Analyse une configuration au format TOML.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::config::Config;
+    ///
+    /// let config = Config::from_toml("[game]\nobjectifs = 3").unwrap();
+    /// assert_eq!(config.game.objectifs, Some(3));
+    /// ```
+    pub fn from_toml(text: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    /// Charge le fichier de configuration situé à `path`.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Config::from_toml(&text).map_err(|source| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Retourne les emplacements par défaut du fichier de configuration, par ordre de priorité :
+    /// `./dual_game.toml` puis `~/.config/dual_game/config.toml`.
+    pub fn default_paths() -> Vec<PathBuf> {
+        let mut paths = vec![PathBuf::from(CONFIG_FILE_NAME)];
+        if let Some(home) = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE")) {
+            paths.push(
+                Path::new(&home)
+                    .join(".config")
+                    .join("dual_game")
+                    .join("config.toml"),
+            );
+        }
+        paths
+    }
+
+    /// Recherche et charge le fichier de configuration.
+    ///
+    /// Un chemin explicite doit exister ; sinon, le premier fichier existant parmi
+    /// [`Config::default_paths`] est chargé.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le chemin et le contenu du fichier chargé, ou `None` si aucun fichier n'a été trouvé.
+    pub fn discover(explicit: Option<&Path>) -> Result<Option<(PathBuf, Self)>, ConfigError> {
+        if let Some(path) = explicit {
+            return Config::load(path).map(|config| Some((path.to_path_buf(), config)));
+        }
+        for path in Config::default_paths() {
+            if path.is_file() {
+                let config = Config::load(&path)?;
+                return Ok(Some((path, config)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Écrit le modèle commenté [`TEMPLATE`] à l'emplacement donné.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Le fichier à créer (les répertoires parents sont créés si besoin).
+    /// * `force` - Écrase le fichier s'il existe déjà.
+    pub fn write_template(path: &Path, force: bool) -> Result<(), ConfigError> {
+        if path.exists() && !force {
+            return Err(ConfigError::AlreadyExists(path.to_path_buf()));
+        }
+        let io_error = |source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        fs::write(path, TEMPLATE).map_err(io_error)
+    }
+
+    /// Retourne les clés non reconnues du fichier, par exemple `game.vitesse`.
+    pub fn unknown_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.unknown.keys().cloned().collect();
+        let sections = [
+            ("game", &self.game.unknown),
+            ("player1", &self.player1.unknown),
+            ("player2", &self.player2.unknown),
+            ("ui", &self.ui.unknown),
+            ("pacing", &self.pacing.unknown),
+            ("keys", &self.keys.unknown),
+        ];
+        for (section, unknown) in sections {
+            keys.extend(unknown.keys().map(|key| format!("{section}.{key}")));
+        }
+        keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie l'analyse d'une configuration complète et le signalement des clés inconnues.
+    #[test]
+    fn test_parse_config() {
+        let config = Config::from_toml(
+            r#"
+            theme = "sombre"
+
+            [game]
+            objectifs = 3
+            bot = "hard"
+            vitesse = 12
+
+            [player1]
+            name = "Alice"
+            class = "tank"
+            color = "green"
+
+            [ui]
+            color = false
+            theme = "deuteranopia"
+
+            [pacing]
+            auto_continue = true
+            turn_delay = 400
+
+            [keys]
+            stop = "space"
+            lock2 = "m"
+            jump = "j"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.game.objectifs, Some(3));
+        assert_eq!(config.game.bot, Some(Difficulty::Hard));
+        assert_eq!(config.player1.name.as_deref(), Some("Alice"));
+        assert_eq!(config.player1.class, Some(PlayerClass::Tank));
+        assert_eq!(config.player1.color, Some(Color::Green));
+        assert_eq!(config.player2, PlayerConfig::default());
+        assert_eq!(config.ui.color, Some(false));
+        assert_eq!(config.ui.theme, Some(Theme::Deuteranopia));
+        assert_eq!(config.pacing.auto_continue, Some(true));
+        assert_eq!(config.pacing.pause_between_rounds, None);
+        assert_eq!(config.pacing.turn_delay, Some(400));
+        assert_eq!(config.keys.bindings(), ["stop=space", "lock2=m"]);
+        assert_eq!(
+            config.unknown_keys(),
+            vec!["theme", "game.vitesse", "keys.jump"]
+        );
+    }
+
+    /// Vérifie qu'une valeur invalide est refusée avec le chemin du fichier.
+    #[test]
+    fn test_invalid_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "[game]\nbot = \"expert\"\n").unwrap();
+        let err = Config::load(&path).unwrap_err();
+        assert!(matches!(err, ConfigError::Parse { .. }));
+        assert!(err.to_string().contains("config.toml"));
+    }
+
+    /// Vérifie que le modèle écrit est une configuration valide et n'est pas écrasé par défaut.
+    #[test]
+    fn test_write_template() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dual_game").join("config.toml");
+        Config::write_template(&path, false).unwrap();
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config, Config::default());
+        assert!(matches!(
+            Config::write_template(&path, false),
+            Err(ConfigError::AlreadyExists(_))
+        ));
+        Config::write_template(&path, true).unwrap();
+        assert_eq!(
+            Config::discover(Some(&path)).unwrap().map(|(p, _)| p),
+            Some(path)
+        );
+    }
+}
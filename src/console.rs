@@ -0,0 +1,524 @@
+//! Module d'abstraction des entrées/sorties textuelles du jeu.
+//!
+//! Le trait [`Console`] permet d'injecter la source des saisies et la destination de l'affichage :
+//! `StdConsole` utilise le terminal (fonctionnalité `cli`), [`ScriptedConsole`] rejoue des saisies
+//! prédéfinies et enregistre l'affichage, ce qui rend les interactions testables, et [`NullConsole`]
+//! ignore l'affichage.
+//!
+//! L'entrée standard est lue par un seul thread, qui dépose chaque saisie dans une [`InputQueue`] où
+//! puisent le compteur, les choix et les invites ; l'attente d'une saisie peut être interrompue par un
+//! [`CancelToken`]. Les saisies lues peuvent être consignées par un [`InputRecorder`] puis rejouées
+//! dans le même ordre (voir [`InputQueue::from_events`]), pour reproduire une session.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::Read;
+#[cfg(feature = "cli")]
+use std::io::{IsTerminal, Write, stdin, stdout};
+use std::rc::Rc;
+#[cfg(feature = "cli")]
+use std::sync::OnceLock;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::bundle::InputRecorder;
+use crate::cancel::{CancelToken, Cancelled};
+#[cfg(feature = "cli")]
+use crate::style;
+
+/// Intervalle entre deux vérifications de l'interruption pendant l'attente d'une saisie.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Saisie lue au clavier, une touche à la fois.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputEvent {
+    /// Touche ENTREE, ou fin d'une ligne lue hors du mode brut.
+    Enter,
+    /// Caractère saisi.
+    Char(char),
+    /// Ctrl+C, lu en mode brut avant d'atteindre le système.
+    Interrupt,
+    /// Fin du flux ou erreur de lecture : plus aucune saisie n'arrivera.
+    Eof,
+}
+
+/// Décode les octets lus en saisies, qu'ils proviennent d'un terminal en mode brut (une touche à la
+/// fois, ENTREE valant `\r`) ou d'une lecture ligne par ligne.
+#[derive(Debug, Default)]
+struct InputParser {
+    /// Octets d'un caractère UTF-8 incomplet, en attente de la lecture suivante.
+    partial: Vec<u8>,
+    /// Indique si le dernier octet décodé de la lecture en cours est un `\r`, afin qu'un `\r\n` ne
+    /// compte que pour un seul ENTREE.
+    carriage_return: bool,
+    /// Indique si une séquence d'échappement (flèches, touches de fonction) est en cours de décodage.
+    escape: bool,
+}
+
+impl InputParser {
+    /// Décode les octets d'une lecture et ajoute les saisies obtenues.
+    fn feed(&mut self, bytes: &[u8], events: &mut Vec<InputEvent>) {
+        self.partial.extend_from_slice(bytes);
+        let (valid, rest) = match std::str::from_utf8(&self.partial) {
+            Ok(text) => (text.len(), 0),
+            Err(err) => (err.valid_up_to(), err.error_len().unwrap_or(0)),
+        };
+        let text = String::from_utf8_lossy(&self.partial[..valid]).into_owned();
+        self.partial.drain(..valid + rest);
+        for c in text.chars() {
+            let carriage_return = std::mem::take(&mut self.carriage_return);
+            if self.escape {
+                // Une séquence se termine par une lettre ou un `~`, après `[` ou `O`.
+                self.escape = !(c.is_ascii_alphabetic() && c != 'O' || c == '~');
+                continue;
+            }
+            match c {
+                '\r' => {
+                    self.carriage_return = true;
+                    events.push(InputEvent::Enter);
+                }
+                '\n' if carriage_return => {}
+                '\n' => events.push(InputEvent::Enter),
+                '\x03' => events.push(InputEvent::Interrupt),
+                '\x1b' => self.escape = true,
+                c if c.is_control() => {}
+                c => events.push(InputEvent::Char(c)),
+            }
+        }
+        self.carriage_return = false;
+    }
+}
+
+/// File des saisies d'un [`InputQueue`].
+#[derive(Debug, Default)]
+struct QueueState {
+    /// Saisies en attente, dans l'ordre de leur arrivée.
+    events: VecDeque<InputEvent>,
+}
+
+/// État partagé entre un [`InputQueue`] et son thread de lecture.
+#[derive(Debug, Default)]
+struct SharedQueue {
+    /// File des saisies.
+    state: Mutex<QueueState>,
+    /// Signalé à chaque nouvelle saisie.
+    arrived: Condvar,
+}
+
+/// File de l'entrée standard, partagée par toute l'application (voir [`InputQueue::stdin`]).
+#[cfg(feature = "cli")]
+static STDIN: OnceLock<InputQueue> = OnceLock::new();
+
+/// File des saisies du clavier, seule lectrice de l'entrée standard.
+///
+/// Un thread dédié lit l'entrée en continu et dépose chaque saisie décodée ([`InputEvent`]) dans la
+/// file ; le compteur, les choix et les invites y puisent tour à tour, de sorte qu'aucune saisie n'est
+/// lue par un autre lecteur que celui qui l'attend. Pour un terminal interactif, les saisies faites à
+/// l'avance (un deuxième ENTREE pressé juste après l'arrêt du compteur, par exemple) sont ignorées au
+/// démarrage du compteur suivant (voir [`InputQueue::skip_type_ahead`]).
+#[derive(Clone, Debug)]
+pub struct InputQueue {
+    /// File partagée avec le thread de lecture.
+    shared: Arc<SharedQueue>,
+    /// Indique si les saisies proviennent d'un utilisateur, et non d'un fichier ou d'un tube.
+    interactive: bool,
+    /// Indique si les saisies sont rejouées d'un enregistrement : déjà toutes dans la file, aucune
+    /// n'est faite à l'avance.
+    replayed: bool,
+    /// Enregistreur consignant chaque saisie retournée, le cas échéant.
+    recorder: Option<InputRecorder>,
+}
+
+impl InputQueue {
+    /// Crée une file vide, alimentée par [`InputQueue::push`].
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::cancel::CancelToken;
+    /// use dual_game::console::{InputEvent, InputQueue};
+    ///
+    /// let queue = InputQueue::new(true);
+    /// // Deux ENTREE pressés coup sur coup : le premier arrête le compteur en cours...
+    /// queue.push(InputEvent::Enter);
+    /// queue.push(InputEvent::Enter);
+    /// let cancel = CancelToken::new();
+    /// assert_eq!(queue.next_event(&cancel, None), Ok(Some(InputEvent::Enter)));
+    /// // ... et le second est ignoré au démarrage du compteur suivant.
+    /// queue.skip_type_ahead();
+    /// assert_eq!(queue.next_event(&cancel, Some(Default::default())), Ok(None));
+    /// ```
+    pub fn new(interactive: bool) -> Self {
+        InputQueue {
+            shared: Arc::new(SharedQueue::default()),
+            interactive,
+            replayed: false,
+            recorder: None,
+        }
+    }
+
+    /// Crée une file rejouant les saisies données, dans l'ordre, suivies de la fin du flux.
+    ///
+    /// `interactive` est repris de la session enregistrée, afin que l'application pose les mêmes
+    /// questions ; les saisies n'étant pas faites à l'avance, aucune n'est ignorée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::cancel::CancelToken;
+    /// use dual_game::console::{InputEvent, InputQueue};
+    ///
+    /// let queue = InputQueue::from_events([InputEvent::Char('2'), InputEvent::Enter], true);
+    /// queue.skip_type_ahead();
+    /// let cancel = CancelToken::new();
+    /// assert_eq!(queue.read_line(&cancel), Ok(Some(String::from("2"))));
+    /// assert_eq!(queue.read_line(&cancel), Ok(None));
+    /// assert!(queue.is_interactive());
+    /// ```
+    pub fn from_events(events: impl IntoIterator<Item = InputEvent>, interactive: bool) -> Self {
+        let queue = InputQueue {
+            replayed: true,
+            ..InputQueue::new(interactive)
+        };
+        Self::deliver(&queue.shared, events.into_iter().chain([InputEvent::Eof]));
+        queue
+    }
+
+    /// Consigne chaque saisie retournée par la file, et par ses clones, dans l'enregistreur donné.
+    pub fn with_recorder(mut self, recorder: InputRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Indique si les saisies proviennent d'un utilisateur, et non d'un fichier ou d'un tube.
+    pub fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    /// Crée une file alimentée par un thread lisant la source donnée jusqu'à sa fin.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    ///
+    /// use dual_game::cancel::CancelToken;
+    /// use dual_game::console::InputQueue;
+    ///
+    /// let queue = InputQueue::from_reader(Cursor::new("s\r\n2\n"), false);
+    /// let cancel = CancelToken::new();
+    /// assert_eq!(queue.read_line(&cancel), Ok(Some(String::from("s"))));
+    /// assert_eq!(queue.read_line(&cancel), Ok(Some(String::from("2"))));
+    /// assert_eq!(queue.read_line(&cancel), Ok(None));
+    /// ```
+    pub fn from_reader<R: Read + Send + 'static>(mut reader: R, interactive: bool) -> Self {
+        let queue = InputQueue::new(interactive);
+        let shared = Arc::clone(&queue.shared);
+        thread::spawn(move || {
+            let mut parser = InputParser::default();
+            let mut buffer = [0; 256];
+            let mut events = Vec::new();
+            loop {
+                match reader.read(&mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => parser.feed(&buffer[..read], &mut events),
+                }
+                Self::deliver(&shared, events.drain(..));
+            }
+            Self::deliver(&shared, [InputEvent::Eof]);
+        });
+        queue
+    }
+
+    /// Retourne la file de l'entrée standard, partagée par toute l'application.
+    ///
+    /// Le thread de lecture est lancé au premier appel, sauf si une autre file a été installée à sa
+    /// place (voir [`InputQueue::install_stdin`]).
+    #[cfg(feature = "cli")]
+    pub fn stdin() -> InputQueue {
+        STDIN
+            .get_or_init(|| InputQueue::from_reader(stdin(), stdin().is_terminal()))
+            .clone()
+    }
+
+    /// Installe la file retournée par [`InputQueue::stdin`], par exemple pour enregistrer les saisies
+    /// de l'entrée standard ou rejouer celles d'un rapport de bogue.
+    ///
+    /// Échoue en rendant la file si celle de l'entrée standard est déjà utilisée.
+    #[cfg(feature = "cli")]
+    pub fn install_stdin(queue: InputQueue) -> Result<(), InputQueue> {
+        STDIN.set(queue)
+    }
+
+    /// Ajoute une saisie à la file, comme si elle venait d'être lue.
+    pub fn push(&self, event: InputEvent) {
+        Self::deliver(&self.shared, [event]);
+    }
+
+    /// Ajoute des saisies à la file et réveille leurs lecteurs.
+    fn deliver(shared: &SharedQueue, events: impl IntoIterator<Item = InputEvent>) {
+        let mut state = lock(&shared.state);
+        let before = state.events.len();
+        state.events.extend(events);
+        if state.events.len() > before {
+            shared.arrived.notify_all();
+        }
+    }
+
+    /// Ignore les saisies faites à l'avance, avant que le joueur ne soit invité à les faire.
+    ///
+    /// Sans effet si les saisies ne proviennent pas d'un utilisateur (fichier ou tube), dont toutes les
+    /// lignes sont prévues d'avance. La fin du flux est conservée.
+    pub fn skip_type_ahead(&self) {
+        if !self.interactive || self.replayed {
+            return;
+        }
+        let mut state = lock(&self.shared.state);
+        state.events.retain(|event| *event == InputEvent::Eof);
+    }
+
+    /// Retourne la prochaine saisie, en attendant au plus `timeout` (indéfiniment avec `None`) ou
+    /// jusqu'à l'interruption.
+    ///
+    /// Retourne `Ok(None)` si aucune saisie n'est arrivée à temps, et [`Cancelled`] si l'interruption
+    /// est demandée avant. La fin du flux ([`InputEvent::Eof`]) reste en tête de la file : elle est
+    /// retournée à chaque appel suivant.
+    pub fn next_event(
+        &self,
+        cancel: &CancelToken,
+        timeout: Option<Duration>,
+    ) -> Result<Option<InputEvent>, Cancelled> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut state = lock(&self.shared.state);
+        loop {
+            cancel.check()?;
+            match state.events.front() {
+                Some(InputEvent::Eof) => return Ok(Some(InputEvent::Eof)),
+                Some(_) => {
+                    let event = state.events.pop_front();
+                    if let (Some(recorder), Some(event)) = (&self.recorder, event) {
+                        recorder.record(event);
+                    }
+                    return Ok(event);
+                }
+                None => {}
+            }
+            let wait = match deadline {
+                Some(deadline) => {
+                    let left = deadline.saturating_duration_since(Instant::now());
+                    if left.is_zero() {
+                        return Ok(None);
+                    }
+                    left.min(POLL_INTERVAL)
+                }
+                None => POLL_INTERVAL,
+            };
+            state = self
+                .shared
+                .arrived
+                .wait_timeout(state, wait)
+                .unwrap_or_else(|err| err.into_inner())
+                .0;
+        }
+    }
+
+    /// Lit une ligne, sans son retour à la ligne, en attendant au plus jusqu'à l'interruption.
+    ///
+    /// Retourne `Ok(None)` en fin de flux, et [`Cancelled`] si l'interruption est demandée avant
+    /// qu'une ligne ne soit complète ; les caractères déjà lus sont alors perdus. Un Ctrl+C lu en mode
+    /// brut annule le jeton d'interruption.
+    pub fn read_line(&self, cancel: &CancelToken) -> Result<Option<String>, Cancelled> {
+        let mut line = String::new();
+        loop {
+            match self.next_event(cancel, None)? {
+                Some(InputEvent::Enter) => return Ok(Some(line)),
+                Some(InputEvent::Char(c)) => line.push(c),
+                Some(InputEvent::Interrupt) => {
+                    cancel.cancel();
+                    return Err(Cancelled);
+                }
+                // Une dernière ligne sans retour à la ligne est retournée avant la fin du flux.
+                Some(InputEvent::Eof) | None => return Ok(Some(line).filter(|l| !l.is_empty())),
+            }
+        }
+    }
+}
+
+/// Verrouille une file, même si un thread a paniqué en la détenant.
+fn lock(state: &Mutex<QueueState>) -> MutexGuard<'_, QueueState> {
+    state.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Entrées/sorties textuelles utilisées pour dialoguer avec un joueur.
+pub trait Console {
+    /// Affiche un texte sans retour à la ligne (par exemple une invite de saisie).
+    fn print(&mut self, text: &str);
+
+    /// Lit une ligne saisie, sans son retour à la ligne.
+    ///
+    /// Retourne `None` en fin de flux ou en cas d'erreur de lecture.
+    fn read_line(&mut self) -> Option<String>;
+
+    /// Affiche une ligne de texte.
+    fn println(&mut self, line: &str) {
+        self.print(line);
+        self.print("\n");
+    }
+
+    /// Affiche une invite puis lit la réponse.
+    fn prompt(&mut self, question: &str) -> Option<String> {
+        self.print(question);
+        self.read_line()
+    }
+
+    /// Indique si un utilisateur peut répondre aux questions (terminal interactif).
+    ///
+    /// Lorsque l'entrée provient d'un fichier ou d'un tube, l'application retient des valeurs par défaut
+    /// plutôt que de poser des questions.
+    fn is_interactive(&self) -> bool;
+}
+
+/// Console utilisant l'entrée et la sortie standard.
+#[cfg(feature = "cli")]
+#[derive(Clone, Debug, Default)]
+pub struct StdConsole;
+
+#[cfg(feature = "cli")]
+impl Console for StdConsole {
+    /// Hors d'un terminal, les séquences ANSI et les retours chariot sont retirés du texte.
+    fn print(&mut self, text: &str) {
+        if style::is_tty() {
+            print!("{text}");
+        } else {
+            print!("{}", style::strip_controls(text));
+        }
+        let _ = stdout().flush();
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        InputQueue::stdin()
+            .read_line(&CancelToken::new())
+            .ok()
+            .flatten()
+    }
+
+    /// L'entrée standard est interactive si elle est reliée à un terminal, ou si les saisies rejouées
+    /// l'étaient lors de leur enregistrement.
+    fn is_interactive(&self) -> bool {
+        InputQueue::stdin().is_interactive()
+    }
+}
+
+/// Console sans affichage ni saisie, par exemple pour un serveur dont l'affichage n'est destiné
+/// qu'aux clients.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullConsole;
+
+impl Console for NullConsole {
+    fn print(&mut self, _text: &str) {}
+
+    fn read_line(&mut self) -> Option<String> {
+        None
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+/// Console rejouant des saisies prédéfinies et enregistrant l'affichage.
+#[derive(Clone, Debug, Default)]
+pub struct ScriptedConsole {
+    /// Saisies restant à rejouer.
+    inputs: VecDeque<String>,
+    /// Texte affiché depuis la création de la console.
+    pub output: String,
+    /// Simule un terminal interactif (faux par défaut, comme une entrée redirigée).
+    pub interactive: bool,
+}
+
+impl ScriptedConsole {
+    /// Crée une console rejouant les saisies données, dans l'ordre.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::console::{Console, ScriptedConsole};
+    ///
+    /// let mut console = ScriptedConsole::new(["Alice"]);
+    /// assert_eq!(console.prompt("Nom : ").as_deref(), Some("Alice"));
+    /// assert_eq!(console.read_line(), None);
+    /// assert_eq!(console.output, "Nom : ");
+    /// ```
+    pub fn new<I, S>(inputs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        ScriptedConsole {
+            inputs: inputs.into_iter().map(Into::into).collect(),
+            output: String::new(),
+            interactive: false,
+        }
+    }
+}
+
+impl Console for ScriptedConsole {
+    fn print(&mut self, text: &str) {
+        self.output.push_str(text);
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        self.inputs.pop_front()
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+}
+
+/// Console partagée, par exemple entre une partie et le test qui inspecte son affichage.
+impl<C: Console + ?Sized> Console for Rc<RefCell<C>> {
+    fn print(&mut self, text: &str) {
+        self.borrow_mut().print(text);
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        self.borrow_mut().read_line()
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.borrow().is_interactive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie le décodage des touches lues en mode brut ou ligne par ligne : un `\r\n` ne compte que
+    /// pour un ENTREE, les séquences d'échappement sont ignorées et un caractère coupé entre deux
+    /// lectures est reconstitué.
+    #[test]
+    fn test_input_parser() {
+        let mut parser = InputParser::default();
+        let mut events = Vec::new();
+        parser.feed(b"s\r\n\x1b[A\x03\xc3", &mut events);
+        parser.feed(b"\xa9\n\r", &mut events);
+        assert_eq!(
+            events,
+            [
+                InputEvent::Char('s'),
+                InputEvent::Enter,
+                InputEvent::Interrupt,
+                InputEvent::Char('é'),
+                InputEvent::Enter,
+                InputEvent::Enter,
+            ]
+        );
+    }
+}
@@ -0,0 +1,1071 @@
+//! Module définissant les contrôleurs qui prennent les décisions d'un joueur.
+//!
+//! Le trait [`Controller`] sépare les décisions (arrêt du compteur, choix du poison, contre-proposition)
+//! de la logique de la partie. Un joueur peut ainsi être piloté par un humain au clavier
+//! (`HumanController`, avec la fonctionnalité `cli`), par un bot ([`RandomBot`], [`BotController`]) ou
+//! par des décisions fournies par l'hôte du moteur ([`ScriptedController`]).
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+#[cfg(feature = "cli")]
+use std::io::{Write, stdout};
+use std::rc::Rc;
+use std::str::FromStr;
+#[cfg(feature = "cli")]
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Distribution, Normal};
+use serde::{Deserialize, Serialize};
+
+use crate::cancel::CancelToken;
+#[cfg(feature = "cli")]
+use crate::clock::Clock;
+#[cfg(feature = "cli")]
+use crate::console::InputQueue;
+#[cfg(feature = "cli")]
+use crate::counter::{Counter, CounterSession};
+use crate::items::{Item, POTION_HEAL};
+#[cfg(feature = "cli")]
+use crate::messages::Msg;
+use crate::mutators::RoundModifier;
+use crate::player::Player;
+use crate::poison::PoisonType;
+use crate::replay::StopRecord;
+use crate::risk::Risk;
+#[cfg(feature = "cli")]
+use crate::rules;
+use crate::rules::GameRules;
+#[cfg(feature = "cli")]
+use crate::scoring::ScoringConfig;
+use crate::scoring::{ScorePreview, ScoringCalculator};
+#[cfg(feature = "cli")]
+use crate::setup::parse_yes_no;
+#[cfg(feature = "cli")]
+use crate::tr;
+use crate::training::GameState;
+
+/// Écart à l'objectif au-delà duquel le [`BotController`] rejoue un objectif.
+const RETRY_GAP: u32 = 10;
+
+/// Informations mises à disposition d'un contrôleur lors de l'arrêt du compteur.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CounterView {
+    /// Objectif à atteindre.
+    pub objective: u32,
+    /// Index de l'objectif dans le tour.
+    pub index: usize,
+    /// Vitesse du joueur (délai en millisecondes entre deux incrémentations).
+    pub speed: u32,
+    /// Valeur du compteur cachée jusqu'à son arrêt, par exemple sous le brouillard (voir
+    /// [`mutators::Fog`](crate::mutators::Fog)).
+    pub hidden: bool,
+    /// Force du joueur.
+    pub strength: u32,
+    /// Endurance restante du joueur, ou `None` si la règle d'endurance est désactivée.
+    pub stamina: Option<u32>,
+    /// Ralentissement du compteur près de l'objectif, en pourcentage, `None` sans assistance.
+    pub assist: Option<u32>,
+    /// Jeton d'interruption de la partie, arrêtant le compteur sans attendre le joueur.
+    pub cancel: CancelToken,
+    /// Règles de la partie, affichables depuis le menu de pause du compteur.
+    pub rules: GameRules,
+}
+
+impl CounterView {
+    /// Retourne l'aperçu du score à afficher pendant le défilement du compteur, selon l'objectif et
+    /// la force du joueur, ou `None` si la valeur du compteur est cachée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::cancel::CancelToken;
+    /// use dual_game::controller::CounterView;
+    /// use dual_game::rules::GameRules;
+    ///
+    /// let mut view = CounterView {
+    ///     objective: 50,
+    ///     index: 0,
+    ///     speed: 75,
+    ///     hidden: false,
+    ///     strength: 30,
+    ///     stamina: None,
+    ///     assist: None,
+    ///     cancel: CancelToken::new(),
+    ///     rules: GameRules::default(),
+    /// };
+    /// assert_eq!(view.score_preview().map(|preview| preview.score(50, 0)), Some(130));
+    /// view.hidden = true;
+    /// assert_eq!(view.score_preview(), None);
+    /// ```
+    pub fn score_preview(&self) -> Option<ScorePreview> {
+        (!self.hidden).then(|| ScoringCalculator::preview(self.objective, self.strength))
+    }
+}
+
+/// Résultat de l'arrêt du compteur.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StopDecision {
+    /// Valeur du compteur au moment de l'arrêt.
+    pub counter_value: u32,
+    /// Nombre de fois où le compteur a dépassé 100 avant l'arrêt.
+    pub miss: u32,
+    /// Endurance dépensée pour ralentir le compteur.
+    pub stamina_spent: u32,
+}
+
+impl StopDecision {
+    /// Construit la décision correspondant à un arrêt décalé de `offset` incrémentations par rapport
+    /// à l'objectif, en tenant compte des tours complets du compteur.
+    ///
+    /// Le compteur démarre à 0 : un décalage qui ferait s'arrêter le compteur avant son départ est
+    /// ramené à 0.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::controller::StopDecision;
+    ///
+    /// let stop = StopDecision::from_offset(95, 10);
+    /// assert_eq!((stop.counter_value, stop.miss), (4, 1));
+    /// ```
+    pub fn from_offset(objective: u32, offset: i64) -> Self {
+        let position = (i64::from(objective) + offset).max(0) as u64;
+        StopDecision {
+            counter_value: (position % 101) as u32,
+            miss: (position / 101) as u32,
+            stamina_spent: 0,
+        }
+    }
+}
+
+/// Prise de décision pour le compte d'un joueur.
+pub trait Controller {
+    /// Arrête le compteur pour l'objectif décrit par `ctx`.
+    fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision;
+
+    /// Choisit le poison à appliquer à l'adversaire parmi `options`.
+    ///
+    /// Retourne l'index de l'option choisie ; un index hors limites fait redemander le choix, jusqu'à
+    /// ce qu'un index valide soit donné ou que la partie s'arrête (voir [`Controller::has_forfeited`]).
+    fn choose_poison(&mut self, options: &[PoisonType], opponent: &Player) -> usize;
+
+    /// Décide de contrer le poison `poison` en subissant `extra_damage` points de dégâts supplémentaires.
+    fn choose_counter(&mut self, poison: &PoisonType, extra_damage: u32, me: &Player) -> bool;
+
+    /// Choisit l'objet à acheter à la boutique parmi `items`, entre deux manches.
+    ///
+    /// Retourne l'index de l'objet choisi, ou `None` pour ne rien acheter. Par défaut, rien n'est
+    /// acheté.
+    fn choose_item(&mut self, _items: &[Item], _me: &Player) -> Option<usize> {
+        None
+    }
+
+    /// Choisit la prise de risque du tour parmi `options`, avant le tirage des objectifs.
+    ///
+    /// Retourne l'index de l'option choisie ; un index hors limites équivaut à [`Risk::Normal`]. Par
+    /// défaut, la prise de risque normale est choisie.
+    fn choose_risk(&mut self, options: &[Risk], _me: &Player, _opponent: &Player) -> usize {
+        options
+            .iter()
+            .position(|risk| *risk == Risk::Normal)
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Choisit le poison à interdire pour la manche parmi `options`, lorsque le joueur a moins de
+    /// vitalité que son adversaire.
+    ///
+    /// Retourne l'index du poison interdit, ou `None` pour n'en interdire aucun. Par défaut, aucun
+    /// poison n'est interdit.
+    fn choose_ban(
+        &mut self,
+        _options: &[PoisonType],
+        _me: &Player,
+        _opponent: &Player,
+    ) -> Option<usize> {
+        None
+    }
+
+    /// Décide de dépenser un rejeu pour rejouer aussitôt l'objectif dont l'arrêt vient d'être joué.
+    ///
+    /// Le second arrêt est retenu, même s'il est moins bon. Par défaut, l'objectif n'est pas rejoué.
+    fn choose_retry(&mut self, _stop: &StopRecord, _me: &Player) -> bool {
+        false
+    }
+
+    /// Pronostique en secret, en tant qu'arbitre, le vainqueur de la manche parmi `players` (voir
+    /// [`Game::set_referee`](crate::game::Game::set_referee)).
+    ///
+    /// Retourne l'index du joueur pronostiqué, ou `None` pour s'abstenir. Par défaut, l'arbitre
+    /// s'abstient.
+    fn choose_pick(&mut self, _players: &[Player]) -> Option<usize> {
+        None
+    }
+
+    /// Choisit, en tant qu'arbitre récompensé de sa série de pronostics justes, le mutateur imposé à
+    /// la manche parmi `options`.
+    ///
+    /// Retourne l'index du mutateur choisi, ou `None` pour n'en imposer aucun. Par défaut, aucun
+    /// mutateur n'est imposé.
+    fn choose_mutator(&mut self, _options: &[&'static dyn RoundModifier]) -> Option<usize> {
+        None
+    }
+
+    /// Prend connaissance de l'état de la partie vu par le joueur, juste avant l'arrêt du compteur,
+    /// le choix du poison ou la contre-proposition (voir [`RecordingController`]). Par défaut, l'état
+    /// est ignoré.
+    ///
+    /// [`RecordingController`]: crate::training::RecordingController
+    fn observe(&mut self, _state: &GameState) {}
+
+    /// Prend connaissance d'un message avant de poursuivre la partie.
+    fn acknowledge(&mut self, msg: &str);
+
+    /// Retourne la durée pendant laquelle le dernier compteur arrêté est resté en pause, exclue de la
+    /// durée de l'arrêt. Par défaut, aucune : seul le joueur au clavier peut mettre le compteur en
+    /// pause.
+    fn last_pause(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    /// Indique si le contrôleur affiche lui-même l'état du compteur pendant son exécution.
+    fn displays_counter(&self) -> bool {
+        false
+    }
+
+    /// Termine le tour du joueur, après son dernier objectif ou son interruption, par exemple pour
+    /// libérer le compteur qui a servi tout le tour.
+    fn end_turn(&mut self) {}
+
+    /// Associe le jeton d'interruption de la partie, qui écourte les saisies en attente.
+    fn set_cancel_token(&mut self, _cancel: CancelToken) {}
+
+    /// Indique si le joueur a abandonné la partie, par exemple après la perte de sa connexion.
+    ///
+    /// La partie se termine alors au prochain point d'arrêt sûr par [`GameResult::Forfeit`].
+    ///
+    /// [`GameResult::Forfeit`]: crate::game::GameResult::Forfeit
+    fn has_forfeited(&self) -> bool {
+        false
+    }
+}
+
+/// Retourne le contrôleur attribué à chaque joueur d'une nouvelle partie : le clavier avec la
+/// fonctionnalité `cli`, un bot de difficulté normale sans elle.
+pub(crate) fn default_controller() -> Box<dyn Controller> {
+    #[cfg(feature = "cli")]
+    return Box::new(HumanController::default());
+    #[cfg(not(feature = "cli"))]
+    return Box::new(BotController::new(Difficulty::Normal));
+}
+
+/// Réglages pour lesquels une session du compteur a été lancée : vitesse, assistance et valeur cachée.
+#[cfg(feature = "cli")]
+type CounterPace = (u32, Option<u32>, bool);
+
+/// Contrôleur d'un joueur humain utilisant le clavier.
+///
+/// Le compteur est lancé au premier objectif du tour, puis réutilisé jusqu'à la fin du tour (voir
+/// [`CounterSession`]).
+#[cfg(feature = "cli")]
+#[derive(Default)]
+pub struct HumanController {
+    /// Session du compteur du tour en cours, avec les réglages pour lesquels elle a été lancée.
+    session: Option<(CounterPace, CounterSession)>,
+    /// Jeton d'interruption de la partie, qui écourte la lecture des choix.
+    cancel: CancelToken,
+    /// Indique si le joueur a abandonné depuis le menu de pause du compteur.
+    forfeited: bool,
+    /// File des saisies du joueur, celle de l'entrée standard par défaut.
+    input: Option<InputQueue>,
+    /// Horloge cadençant le compteur, celle du système par défaut.
+    clock: Option<Arc<dyn Clock + Send + Sync>>,
+}
+
+#[cfg(feature = "cli")]
+impl fmt::Debug for HumanController {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HumanController")
+            .field("session", &self.session)
+            .field("forfeited", &self.forfeited)
+            .field("input", &self.input)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "cli")]
+impl HumanController {
+    /// Lit les saisies du joueur dans la file donnée plutôt que dans celle de l'entrée standard, par
+    /// exemple pour rejouer un rapport de bogue.
+    pub fn with_input(mut self, input: InputQueue) -> Self {
+        self.input = Some(input);
+        self
+    }
+
+    /// Cadence le compteur par l'horloge donnée plutôt que par celle du système (voir
+    /// [`Counter::with_clock`]).
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Retourne la file des saisies du joueur.
+    fn input(&self) -> InputQueue {
+        self.input.clone().unwrap_or_else(InputQueue::stdin)
+    }
+
+    /// Lit et valide un choix numérique compris entre 1 et `max`.
+    ///
+    /// Cette méthode redemande tant que l'entrée est invalide. En fin de flux ou en cas d'erreur de
+    /// lecture, la partie est interrompue ; le choix retourné, comme après une interruption, est alors
+    /// ignoré par la partie. Les saisies faites avant la question sont ignorées.
+    fn read_choice(&self, max: usize) -> usize {
+        let input = self.input();
+        input.skip_type_ahead();
+        loop {
+            print!("> ");
+            let _ = stdout().flush();
+            let Ok(Some(line)) = input.read_line(&self.cancel) else {
+                self.cancel.cancel();
+                return 1;
+            };
+            if let Ok(choice) = line.trim().parse::<usize>()
+                && (1..=max).contains(&choice)
+            {
+                return choice;
+            }
+            println!("{}", tr!(Msg::ChoiceInvalid, max = max));
+        }
+    }
+}
+
+#[cfg(feature = "cli")]
+impl Controller for HumanController {
+    /// Lance le compteur et attend que le joueur appuie sur ENTREE.
+    ///
+    /// Si la règle d'endurance est active, le joueur peut appuyer sur `s` pour ralentir le compteur ;
+    /// avec l'assistance, le compteur ralentit de lui-même près de l'objectif. La touche `p` ouvre le
+    /// menu de pause, d'où le joueur peut relire les règles de la partie ou abandonner.
+    fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+        let pace = (ctx.speed, ctx.assist, ctx.hidden);
+        if self
+            .session
+            .as_ref()
+            .is_none_or(|(session_pace, _)| *session_pace != pace)
+        {
+            let mut counter = Counter::new(ctx.speed)
+                .with_input(self.input())
+                .with_cancel(ctx.cancel.clone())
+                .with_assist(ctx.assist)
+                .with_hidden(ctx.hidden)
+                .with_rules(rules::render(&ctx.rules, &ScoringConfig::default()));
+            if let Some(clock) = &self.clock {
+                counter = counter.with_clock(Arc::clone(clock));
+            }
+            self.session = Some((pace, counter.session()));
+        }
+        let (_, session) = self.session.as_mut().unwrap();
+        session.set_preview(ctx.score_preview());
+        let stop = match ctx.stamina {
+            Some(stamina) => {
+                let mut remaining = stamina;
+                // Après une interruption, la décision est ignorée par la partie.
+                let (counter_value, miss) = session
+                    .run_with_stamina(ctx.objective, &mut remaining)
+                    .unwrap_or_default();
+                StopDecision {
+                    counter_value,
+                    miss,
+                    stamina_spent: stamina - remaining,
+                }
+            }
+            None => {
+                let (counter_value, miss) = session.run(ctx.objective).unwrap_or_default();
+                StopDecision {
+                    counter_value,
+                    miss,
+                    stamina_spent: 0,
+                }
+            }
+        };
+        self.forfeited |= session.has_forfeited();
+        stop
+    }
+
+    fn choose_poison(&mut self, options: &[PoisonType], _opponent: &Player) -> usize {
+        self.read_choice(options.len()) - 1
+    }
+
+    fn choose_counter(&mut self, _poison: &PoisonType, _extra_damage: u32, _me: &Player) -> bool {
+        self.read_choice(2) == 2
+    }
+
+    /// Le dernier choix du menu, après les objets, est de ne rien acheter.
+    fn choose_item(&mut self, items: &[Item], _me: &Player) -> Option<usize> {
+        let choice = self.read_choice(items.len() + 1) - 1;
+        (choice < items.len()).then_some(choice)
+    }
+
+    fn choose_risk(&mut self, options: &[Risk], _me: &Player, _opponent: &Player) -> usize {
+        self.read_choice(options.len()) - 1
+    }
+
+    /// Le dernier choix du menu, après les poisons, est de n'en interdire aucun.
+    fn choose_ban(
+        &mut self,
+        options: &[PoisonType],
+        _me: &Player,
+        _opponent: &Player,
+    ) -> Option<usize> {
+        let choice = self.read_choice(options.len() + 1) - 1;
+        (choice < options.len()).then_some(choice)
+    }
+
+    /// Seule une réponse affirmative rejoue l'objectif : une réponse vide, invalide ou interrompue le
+    /// conserve.
+    fn choose_retry(&mut self, _stop: &StopRecord, _me: &Player) -> bool {
+        let input = self.input();
+        input.skip_type_ahead();
+        print!("> ");
+        let _ = stdout().flush();
+        matches!(input.read_line(&self.cancel), Ok(Some(line)) if parse_yes_no(line.trim()) == Ok(true))
+    }
+
+    /// Le dernier choix du menu, après les joueurs, est de s'abstenir.
+    fn choose_pick(&mut self, players: &[Player]) -> Option<usize> {
+        let choice = self.read_choice(players.len() + 1) - 1;
+        (choice < players.len()).then_some(choice)
+    }
+
+    /// Le dernier choix du menu, après les mutateurs, est de n'en imposer aucun.
+    fn choose_mutator(&mut self, options: &[&'static dyn RoundModifier]) -> Option<usize> {
+        let choice = self.read_choice(options.len() + 1) - 1;
+        (choice < options.len()).then_some(choice)
+    }
+
+    /// Affiche le message puis attend que le joueur appuie sur ENTREE, ou l'interruption de la partie.
+    fn acknowledge(&mut self, msg: &str) {
+        println!("{msg}");
+        let input = self.input();
+        input.skip_type_ahead();
+        let _ = input.read_line(&self.cancel);
+    }
+
+    fn last_pause(&self) -> Duration {
+        self.session
+            .as_ref()
+            .map_or(Duration::ZERO, |(_, session)| session.paused())
+    }
+
+    fn displays_counter(&self) -> bool {
+        true
+    }
+
+    /// Arrête et attend le thread du compteur du tour.
+    fn end_turn(&mut self) {
+        self.session = None;
+    }
+
+    fn set_cancel_token(&mut self, cancel: CancelToken) {
+        self.cancel = cancel;
+    }
+
+    fn has_forfeited(&self) -> bool {
+        self.forfeited
+    }
+}
+
+/// Bot arrêtant le compteur selon une loi normale centrée sur l'objectif.
+#[derive(Clone, Debug)]
+pub struct RandomBot {
+    rng: StdRng,
+    stop_distribution: Normal<f64>,
+}
+
+impl RandomBot {
+    /// Écart-type par défaut, en incrémentations du compteur, autour de l'objectif.
+    pub const DEFAULT_SIGMA: f64 = 5.0;
+
+    /// Crée un bot dont l'arrêt suit une loi normale d'écart-type [`RandomBot::DEFAULT_SIGMA`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - Le générateur aléatoire utilisé pour toutes les décisions du bot.
+    pub fn new(rng: StdRng) -> Self {
+        RandomBot::with_sigma(rng, RandomBot::DEFAULT_SIGMA)
+    }
+
+    /// Crée un bot dont l'arrêt suit une loi normale de l'écart-type donné.
+    ///
+    /// Un écart-type négatif ou invalide est ramené à 0 (arrêt toujours parfait).
+    pub fn with_sigma(rng: StdRng, sigma: f64) -> Self {
+        let sigma = if sigma.is_finite() {
+            sigma.max(0.0)
+        } else {
+            0.0
+        };
+        RandomBot {
+            rng,
+            stop_distribution: Normal::new(0.0, sigma).expect("écart-type valide"),
+        }
+    }
+}
+
+impl Controller for RandomBot {
+    fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+        let offset = self.stop_distribution.sample(&mut self.rng).round() as i64;
+        StopDecision::from_offset(ctx.objective, offset)
+    }
+
+    fn choose_poison(&mut self, options: &[PoisonType], _opponent: &Player) -> usize {
+        self.rng.random_range(0..options.len().max(1))
+    }
+
+    /// Contre le poison une fois sur deux, à condition de survivre aux dégâts supplémentaires.
+    fn choose_counter(&mut self, _poison: &PoisonType, extra_damage: u32, me: &Player) -> bool {
+        me.vitality() > extra_damage && self.rng.random_bool(0.5)
+    }
+
+    fn acknowledge(&mut self, _msg: &str) {}
+}
+
+/// Niveau de difficulté d'un [`BotController`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    /// Arrêt imprécis, avec parfois un tour complet de compteur manqué.
+    Easy,
+    /// Arrêt raisonnablement précis.
+    Normal,
+    /// Arrêt très précis, sans jamais dépasser 100.
+    Hard,
+}
+
+impl Difficulty {
+    /// Liste de tous les niveaux de difficulté.
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// Retourne l'écart-type, en incrémentations du compteur, de l'arrêt autour de l'objectif.
+    pub fn sigma(&self) -> f64 {
+        match self {
+            Difficulty::Easy => 15.0,
+            Difficulty::Normal => 7.0,
+            Difficulty::Hard => 3.0,
+        }
+    }
+
+    /// Retourne la probabilité de laisser passer un tour complet du compteur avant de l'arrêter.
+    pub fn wrap_chance(&self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.1,
+            Difficulty::Normal | Difficulty::Hard => 0.0,
+        }
+    }
+
+    /// Retourne l'identifiant de la difficulté utilisé en ligne de commande.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Difficulty::Easy => "Facile",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Difficile",
+        };
+        write!(f, "{label}")
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    /// Analyse une difficulté à partir de son identifiant (insensible à la casse).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s.trim().to_lowercase();
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.id() == id)
+            .ok_or_else(|| {
+                let ids: Vec<&str> = Difficulty::ALL.iter().map(|d| d.id()).collect();
+                format!(
+                    "difficulté inconnue « {s} » (valeurs possibles : {})",
+                    ids.join(", ")
+                )
+            })
+    }
+}
+
+/// Bot dont la précision dépend d'un niveau de [`Difficulty`].
+///
+/// Le bot calcule directement la position d'arrêt du compteur, sans lancer de thread, et vise
+/// toujours la caractéristique la plus élevée de son adversaire avec son poison.
+#[derive(Clone, Debug)]
+pub struct BotController {
+    difficulty: Difficulty,
+    rng: StdRng,
+    stop_distribution: Normal<f64>,
+}
+
+impl BotController {
+    /// Crée un bot du niveau donné, initialisé à partir d'une graine aléatoire du système.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::controller::{BotController, Difficulty};
+    ///
+    /// let bot = BotController::new(Difficulty::Hard);
+    /// assert_eq!(bot.difficulty(), Difficulty::Hard);
+    /// ```
+    pub fn new(difficulty: Difficulty) -> Self {
+        BotController::with_rng(difficulty, StdRng::from_os_rng())
+    }
+
+    /// Crée un bot du niveau donné utilisant le générateur fourni, pour des parties reproductibles.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Le niveau de difficulté du bot.
+    /// * `rng` - Le générateur aléatoire utilisé pour toutes les décisions du bot.
+    pub fn with_rng(difficulty: Difficulty, rng: StdRng) -> Self {
+        BotController {
+            difficulty,
+            rng,
+            stop_distribution: Normal::new(0.0, difficulty.sigma()).expect("écart-type valide"),
+        }
+    }
+
+    /// Retourne le niveau de difficulté du bot.
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+}
+
+impl Controller for BotController {
+    fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+        let objective = i64::from(ctx.objective);
+        let mut offset = self.stop_distribution.sample(&mut self.rng).round() as i64;
+        if self.difficulty == Difficulty::Hard {
+            // Un bot difficile ne laisse jamais le compteur dépasser 100.
+            offset = (objective + offset).clamp(0, 100) - objective;
+        }
+        if self.rng.random_bool(self.difficulty.wrap_chance()) {
+            offset += 101;
+        }
+        StopDecision::from_offset(ctx.objective, offset)
+    }
+
+    /// Vise la caractéristique la plus élevée de l'adversaire.
+    fn choose_poison(&mut self, options: &[PoisonType], opponent: &Player) -> usize {
+        let target = if opponent.speed > opponent.strength {
+            PoisonType::Speed
+        } else {
+            PoisonType::Strength
+        };
+        options
+            .iter()
+            .position(|option| *option == target)
+            .unwrap_or(0)
+    }
+
+    /// Contre le poison seulement s'il reste au moins la moitié de sa vitalité après les dégâts supplémentaires.
+    fn choose_counter(&mut self, _poison: &PoisonType, extra_damage: u32, me: &Player) -> bool {
+        me.vitality() > extra_damage.saturating_mul(2)
+    }
+
+    /// Achète une potion s'il lui manque assez de vitalité, sinon un antidote, sinon un aiguisage,
+    /// pourvu qu'il en ait les moyens.
+    fn choose_item(&mut self, items: &[Item], me: &Player) -> Option<usize> {
+        let wounded = me.max_vitality() - me.vitality() >= POTION_HEAL;
+        [Item::Potion, Item::Antidote, Item::Sharpening]
+            .into_iter()
+            .filter(|item| *item != Item::Potion || wounded)
+            .find(|item| item.check(me).is_ok())
+            .and_then(|wanted| items.iter().position(|item| *item == wanted))
+    }
+
+    /// Prend le risque maximal lorsqu'il a moins de vitalité que son adversaire, sinon joue un tour
+    /// normal.
+    fn choose_risk(&mut self, options: &[Risk], me: &Player, opponent: &Player) -> usize {
+        let wanted = if me.vitality() < opponent.vitality() {
+            Risk::Risky
+        } else {
+            Risk::Normal
+        };
+        options
+            .iter()
+            .position(|risk| *risk == wanted)
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Interdit le poison qu'il choisirait lui-même contre un adversaire ayant ses caractéristiques :
+    /// celui qui vise la plus élevée de sa vitesse et de sa force.
+    fn choose_ban(
+        &mut self,
+        options: &[PoisonType],
+        me: &Player,
+        _opponent: &Player,
+    ) -> Option<usize> {
+        let feared = if me.speed > me.strength {
+            PoisonType::Speed
+        } else {
+            PoisonType::Strength
+        };
+        options.iter().position(|poison| *poison == feared)
+    }
+
+    /// Rejoue un objectif manqué d'un tour complet ou arrêté à plus de 10 de l'objectif.
+    fn choose_retry(&mut self, stop: &StopRecord, _me: &Player) -> bool {
+        stop.miss > 0 || ScoringCalculator::difference(stop.objective, stop.counter) > RETRY_GAP
+    }
+
+    fn acknowledge(&mut self, _msg: &str) {}
+}
+
+/// Contrôleur rejouant les décisions fournies par l'hôte du moteur, par exemple une page web qui
+/// mesure elle-même l'arrêt du compteur.
+///
+/// Les clones partagent les mêmes files de décisions : l'hôte conserve un clone pour les alimenter
+/// pendant que la partie utilise l'autre. Sans contre-proposition prévue, le poison est accepté ;
+/// sans achat prévu, rien n'est acheté ; sans prise de risque prévue, le tour est normal ; sans
+/// interdiction prévue, aucun poison n'est interdit ; sans rejeu prévu, l'objectif n'est pas
+/// rejoué ; sans pronostic prévu, l'arbitre s'abstient ; sans mutateur prévu, aucun n'est imposé.
+/// Un joueur à qui il ne reste aucun arrêt du compteur ou aucun choix de poison abandonne la partie.
+#[derive(Clone, Debug, Default)]
+pub struct ScriptedController {
+    /// Décisions restant à rejouer.
+    script: Rc<RefCell<Script>>,
+}
+
+/// Files de décisions d'un [`ScriptedController`].
+#[derive(Debug, Default)]
+struct Script {
+    /// Arrêts du compteur, dans l'ordre des objectifs.
+    stops: VecDeque<StopDecision>,
+    /// Index des poisons choisis.
+    poisons: VecDeque<usize>,
+    /// Réponses aux propositions de contrer le poison.
+    counters: VecDeque<bool>,
+    /// Index des objets achetés à la boutique, `None` pour ne rien acheter.
+    items: VecDeque<Option<usize>>,
+    /// Index des prises de risque choisies.
+    risks: VecDeque<usize>,
+    /// Index des poisons interdits, `None` pour n'en interdire aucun.
+    bans: VecDeque<Option<usize>>,
+    /// Réponses aux propositions de rejouer un objectif.
+    retries: VecDeque<bool>,
+    /// Pronostics de l'arbitre, `None` pour s'abstenir.
+    picks: VecDeque<Option<usize>>,
+    /// Index des mutateurs imposés par l'arbitre, `None` pour n'en imposer aucun.
+    mutators: VecDeque<Option<usize>>,
+    /// Messages dont le joueur a pris connaissance, dans l'ordre.
+    acknowledged: Vec<String>,
+    /// Indique si un arrêt ou un poison a été demandé alors que sa file était vide.
+    exhausted: bool,
+}
+
+impl ScriptedController {
+    /// Crée un contrôleur sans aucune décision prévue.
+    pub fn new() -> Self {
+        ScriptedController::default()
+    }
+
+    /// Ajoute un arrêt du compteur à rejouer.
+    pub fn push_stop(&self, stop: StopDecision) {
+        self.script.borrow_mut().stops.push_back(stop);
+    }
+
+    /// Ajoute le choix d'un poison, par son index parmi les options proposées.
+    pub fn push_poison(&self, choice: usize) {
+        self.script.borrow_mut().poisons.push_back(choice);
+    }
+
+    /// Ajoute la réponse à une proposition de contrer le poison.
+    pub fn push_counter(&self, counter: bool) {
+        self.script.borrow_mut().counters.push_back(counter);
+    }
+
+    /// Ajoute le choix d'un objet à la boutique, par son index parmi les objets proposés, ou `None`
+    /// pour ne rien acheter.
+    pub fn push_item(&self, choice: Option<usize>) {
+        self.script.borrow_mut().items.push_back(choice);
+    }
+
+    /// Ajoute le choix d'une prise de risque, par son index parmi les options proposées.
+    pub fn push_risk(&self, choice: usize) {
+        self.script.borrow_mut().risks.push_back(choice);
+    }
+
+    /// Ajoute le choix d'un poison à interdire, par son index parmi les options proposées, ou `None`
+    /// pour n'en interdire aucun.
+    pub fn push_ban(&self, choice: Option<usize>) {
+        self.script.borrow_mut().bans.push_back(choice);
+    }
+
+    /// Ajoute la réponse à une proposition de rejouer un objectif.
+    pub fn push_retry(&self, retry: bool) {
+        self.script.borrow_mut().retries.push_back(retry);
+    }
+
+    /// Ajoute le pronostic de l'arbitre, par l'index du joueur pronostiqué, ou `None` pour
+    /// s'abstenir.
+    pub fn push_pick(&self, pick: Option<usize>) {
+        self.script.borrow_mut().picks.push_back(pick);
+    }
+
+    /// Ajoute le choix du mutateur imposé par l'arbitre, par son index parmi les mutateurs proposés,
+    /// ou `None` pour n'en imposer aucun.
+    pub fn push_mutator(&self, choice: Option<usize>) {
+        self.script.borrow_mut().mutators.push_back(choice);
+    }
+
+    /// Retourne les messages dont le joueur a pris connaissance (voir [`Controller::acknowledge`]),
+    /// dans l'ordre : début de chaque tour, passage à la manche suivante…
+    pub fn acknowledged(&self) -> Vec<String> {
+        self.script.borrow().acknowledged.clone()
+    }
+
+    /// Retourne le nombre d'arrêts du compteur restant à rejouer.
+    pub fn pending_stops(&self) -> usize {
+        self.script.borrow().stops.len()
+    }
+
+    /// Oublie les décisions restant à rejouer, par exemple un poison prévu pour une manche perdue.
+    pub fn clear(&self) {
+        let mut script = self.script.borrow_mut();
+        script.stops.clear();
+        script.poisons.clear();
+        script.counters.clear();
+        script.items.clear();
+        script.risks.clear();
+        script.bans.clear();
+        script.retries.clear();
+        script.picks.clear();
+        script.mutators.clear();
+    }
+}
+
+impl Controller for ScriptedController {
+    fn stop_counter(&mut self, _ctx: &CounterView) -> StopDecision {
+        let mut script = self.script.borrow_mut();
+        script.stops.pop_front().unwrap_or_else(|| {
+            script.exhausted = true;
+            StopDecision::from_offset(0, 0)
+        })
+    }
+
+    fn choose_poison(&mut self, _options: &[PoisonType], _opponent: &Player) -> usize {
+        let mut script = self.script.borrow_mut();
+        script.poisons.pop_front().unwrap_or_else(|| {
+            script.exhausted = true;
+            usize::MAX
+        })
+    }
+
+    fn choose_counter(&mut self, _poison: &PoisonType, _extra_damage: u32, _me: &Player) -> bool {
+        self.script
+            .borrow_mut()
+            .counters
+            .pop_front()
+            .unwrap_or(false)
+    }
+
+    fn choose_item(&mut self, _items: &[Item], _me: &Player) -> Option<usize> {
+        self.script.borrow_mut().items.pop_front().flatten()
+    }
+
+    fn choose_risk(&mut self, _options: &[Risk], _me: &Player, _opponent: &Player) -> usize {
+        self.script
+            .borrow_mut()
+            .risks
+            .pop_front()
+            .unwrap_or(usize::MAX)
+    }
+
+    fn choose_ban(
+        &mut self,
+        _options: &[PoisonType],
+        _me: &Player,
+        _opponent: &Player,
+    ) -> Option<usize> {
+        self.script.borrow_mut().bans.pop_front().flatten()
+    }
+
+    fn choose_retry(&mut self, _stop: &StopRecord, _me: &Player) -> bool {
+        self.script
+            .borrow_mut()
+            .retries
+            .pop_front()
+            .unwrap_or(false)
+    }
+
+    fn choose_pick(&mut self, _players: &[Player]) -> Option<usize> {
+        self.script.borrow_mut().picks.pop_front().flatten()
+    }
+
+    fn choose_mutator(&mut self, _options: &[&'static dyn RoundModifier]) -> Option<usize> {
+        self.script.borrow_mut().mutators.pop_front().flatten()
+    }
+
+    /// Retient le message, sans attendre (voir [`ScriptedController::acknowledged`]).
+    fn acknowledge(&mut self, msg: &str) {
+        self.script
+            .borrow_mut()
+            .acknowledged
+            .push(String::from(msg));
+    }
+
+    fn has_forfeited(&self) -> bool {
+        self.script.borrow().exhausted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stop_from_offset() {
+        assert_eq!(
+            StopDecision::from_offset(50, -3),
+            StopDecision {
+                counter_value: 47,
+                miss: 0,
+                stamina_spent: 0
+            }
+        );
+        assert_eq!(StopDecision::from_offset(2, -10).counter_value, 0);
+        assert_eq!(
+            StopDecision::from_offset(100, 1),
+            StopDecision {
+                counter_value: 0,
+                miss: 1,
+                stamina_spent: 0
+            }
+        );
+    }
+
+    /// Vérifie que le bot s'arrête autour de l'objectif et que ses choix restent dans les bornes.
+    #[test]
+    fn test_random_bot_decisions() {
+        let mut bot = RandomBot::new(StdRng::seed_from_u64(7));
+        let view = CounterView {
+            objective: 50,
+            index: 0,
+            speed: 75,
+            hidden: false,
+            strength: 50,
+            stamina: None,
+            assist: None,
+            cancel: CancelToken::new(),
+            rules: GameRules::default(),
+        };
+        for _ in 0..100 {
+            let stop = bot.stop_counter(&view);
+            assert_eq!(stop.miss, 0);
+            assert!(stop.counter_value.abs_diff(50) <= 30);
+        }
+        let opponent = Player::new(String::from("Alice"), 50, 50, 50);
+        let options = [PoisonType::Speed, PoisonType::Strength];
+        assert!(bot.choose_poison(&options, &opponent) < options.len());
+        assert!(!bot.choose_counter(&PoisonType::Speed, 60, &opponent));
+    }
+
+    /// Vérifie la précision de chaque niveau et le choix du poison d'un bot.
+    #[test]
+    fn test_bot_controller_difficulty() {
+        let view = CounterView {
+            objective: 98,
+            index: 0,
+            speed: 75,
+            hidden: false,
+            strength: 50,
+            stamina: None,
+            assist: None,
+            cancel: CancelToken::new(),
+            rules: GameRules::default(),
+        };
+        let mut hard = BotController::with_rng(Difficulty::Hard, StdRng::seed_from_u64(3));
+        let mut easy = BotController::with_rng(Difficulty::Easy, StdRng::seed_from_u64(3));
+        let hard_stops: Vec<StopDecision> = (0..200).map(|_| hard.stop_counter(&view)).collect();
+        let easy_stops: Vec<StopDecision> = (0..200).map(|_| easy.stop_counter(&view)).collect();
+        assert!(hard_stops.iter().all(|stop| stop.miss == 0));
+        assert!(easy_stops.iter().any(|stop| stop.miss > 0));
+
+        let options = [PoisonType::Speed, PoisonType::Strength];
+        let opponent = Player::new(String::from("Alice"), 50, 80, 50);
+        assert_eq!(hard.choose_poison(&options, &opponent), 0);
+        let opponent = Player::new(String::from("Alice"), 50, 40, 50);
+        assert_eq!(hard.choose_poison(&options, &opponent), 1);
+        assert_eq!("HARD".parse::<Difficulty>(), Ok(Difficulty::Hard));
+        assert!("expert".parse::<Difficulty>().is_err());
+    }
+
+    /// Vérifie les achats d'un bot : une potion s'il est blessé, sinon un aiguisage, et rien sans
+    /// les moyens de payer.
+    #[test]
+    fn test_bot_shopping() {
+        let mut bot = BotController::new(Difficulty::Normal);
+        let mut me = Player::new(String::from("Bot"), 50, 75, 50);
+        assert_eq!(bot.choose_item(&Item::ALL, &me), None);
+        me.coins = 20;
+        assert_eq!(bot.choose_item(&Item::ALL, &me), Some(0));
+        me.take_damage(15);
+        assert_eq!(bot.choose_item(&Item::ALL, &me), Some(2));
+        me.coins = 15;
+        assert_eq!(bot.choose_item(&Item::ALL, &me), Some(0));
+    }
+
+    /// Vérifie la prise de risque d'un bot : risquée lorsqu'il a moins de vitalité que son
+    /// adversaire, normale sinon, et celle d'un contrôleur scripté sans choix prévu.
+    #[test]
+    fn test_bot_risk_choice() {
+        let mut bot = BotController::new(Difficulty::Normal);
+        let mut me = Player::new(String::from("Bot"), 50, 75, 50);
+        let opponent = Player::new(String::from("Alice"), 50, 75, 50);
+        assert_eq!(
+            Risk::ALL[bot.choose_risk(&Risk::ALL, &me, &opponent)],
+            Risk::Normal
+        );
+        me.take_damage(1);
+        assert_eq!(
+            Risk::ALL[bot.choose_risk(&Risk::ALL, &me, &opponent)],
+            Risk::Risky
+        );
+        assert_eq!(bot.choose_risk(&[Risk::Safe], &me, &opponent), usize::MAX);
+
+        let mut scripted = ScriptedController::new();
+        scripted.push_risk(0);
+        assert_eq!(scripted.choose_risk(&Risk::ALL, &me, &opponent), 0);
+        assert_eq!(scripted.choose_risk(&Risk::ALL, &me, &opponent), usize::MAX);
+    }
+
+    /// Vérifie qu'un menu de 4 options accepte le choix 4 et redemande après le choix 5.
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_read_choice_bounds() {
+        use crate::console::InputEvent;
+
+        let typed = |choices: &[char]| {
+            let events = choices
+                .iter()
+                .flat_map(|&choice| [InputEvent::Char(choice), InputEvent::Enter]);
+            HumanController::default().with_input(InputQueue::from_events(events, true))
+        };
+        assert_eq!(typed(&['4']).read_choice(4), 4);
+        assert_eq!(typed(&['5', '0', '3']).read_choice(4), 3);
+        // En fin de flux, après le choix refusé, le premier choix est retenu.
+        assert_eq!(typed(&['5']).read_choice(4), 1);
+        assert_eq!(typed(&['5']).read_choice(5), 5);
+    }
+}
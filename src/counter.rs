@@ -1,19 +1,456 @@
 //! Module gérant un compteur utilisé pour simuler une incrémentation avec un thread.
 //!
-//! Ce module définit la structure [`Counter`] et ses méthodes associées. Le compteur s'incrémente à une
-//! vitesse donnée et, lors de son exécution, affiche son état en continu jusqu'à ce que l'utilisateur appuie sur ENTREE.
+//! Ce module définit les bornes de vitesse du compteur et, avec la fonctionnalité `cli`, la structure
+//! `Counter` et ses méthodes associées, ainsi que la `CounterSession` dont le thread sert tous les
+//! objectifs d'un tour. Le compteur s'incrémente à une vitesse donnée et, lors de son
+//! exécution, affiche son état en continu jusqu'à ce que l'utilisateur appuie sur ENTREE ou que la
+//! partie soit interrompue (voir [`CancelToken`](crate::cancel::CancelToken)). Hors d'un terminal
+//! (voir [`style::is_tty`](crate::style::is_tty)), seul l'état final du compteur est affiché. En mode
+//! accessible (voir [`style::is_accessible`](crate::style::is_accessible)), le compteur annonce son
+//! démarrage, sa valeur à intervalle régulier et son entrée dans la zone chaude par des phrases
+//! complètes, chacune sur sa propre ligne. En mode partagé, un même compteur sert les deux joueurs,
+//! chacun verrouillant sa valeur par sa propre touche (voir [`Counter::run_shared`]). Pendant un
+//! objectif d'une `CounterSession`, la touche de pause (`p` par défaut) suspend le compteur et ouvre
+//! un menu (reprendre, afficher les règles, abandonner) ; le temps passé en pause n'est compté ni dans
+//! les incrémentations ni dans la durée de l'arrêt (voir [`PausableClock`](crate::clock::PausableClock)).
 
-use std::io::{self, stdout, Write};
-use std::sync::mpsc;
+#[cfg(feature = "cli")]
+use std::fmt::{self, Display};
+#[cfg(feature = "cli")]
+use std::io::{Write, stdout};
+#[cfg(feature = "cli")]
+use std::sync::atomic::{AtomicU32, Ordering};
+#[cfg(feature = "cli")]
+use std::sync::mpsc::{self, RecvTimeoutError};
+#[cfg(feature = "cli")]
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "cli")]
 use std::thread;
+#[cfg(feature = "cli")]
 use std::time::Duration;
 
+#[cfg(feature = "cli")]
+use crossterm::terminal;
+#[cfg(feature = "cli")]
+use unicode_width::UnicodeWidthStr;
+
+#[cfg(feature = "cli")]
+use crate::cancel::{CancelToken, Cancelled};
+#[cfg(feature = "cli")]
+use crate::clock::{Clock, PausableClock, SystemClock};
+#[cfg(feature = "cli")]
+use crate::console::{InputEvent, InputQueue};
+#[cfg(feature = "cli")]
+use crate::keys::{self, Action};
+#[cfg(feature = "cli")]
+use crate::messages::{self, Msg};
+#[cfg(feature = "cli")]
+use crate::render::fit_to_width;
+#[cfg(feature = "cli")]
+use crate::rules::{self, GameRules};
+use crate::scoring::ScoringCalculator;
+#[cfg(feature = "cli")]
+use crate::scoring::{ScorePreview, ScoringConfig};
+#[cfg(feature = "cli")]
+use crate::shared::{SHARED_MISS_CAP, SharedLocks};
+#[cfg(feature = "cli")]
+use crate::style;
+#[cfg(feature = "cli")]
+use crate::tr;
+
+/// Vitesse minimale (délai en millisecondes entre deux incrémentations) supportée par le compteur.
+pub const MIN_SPEED: u32 = 5;
+/// Vitesse maximale (délai en millisecondes entre deux incrémentations) supportée par le compteur.
+pub const MAX_SPEED: u32 = 500;
+
+/// Ralentissement, en pourcentage du délai initial, obtenu en reprenant son souffle.
+pub const BREATH_SLOWDOWN_PERCENT: u32 = 150;
+
+/// Écart maximal à l'objectif, de part et d'autre, de la zone chaude annoncée en mode accessible.
+pub const HOT_ZONE: u32 = 10;
+
+/// Écart maximal à l'objectif, de part et d'autre, dans lequel l'assistance ralentit le compteur.
+pub const ASSIST_WINDOW: u32 = 10;
+/// Ralentissement par défaut de l'assistance, en pourcentage du délai ajouté près de l'objectif.
+pub const DEFAULT_ASSIST_PERCENT: u32 = 60;
+
+/// Valeur affichée à la place du compteur lorsqu'il est caché jusqu'à son arrêt (voir
+/// [`Counter::with_hidden`]).
+pub const HIDDEN_COUNTER: &str = "??";
+
+/// Largeur ajoutée à l'état du compteur par l'aperçu du score.
+#[cfg(feature = "cli")]
+const PREVIEW_WIDTH: usize = 30;
+
+/// Intervalle entre deux vérifications de l'interruption pendant l'attente d'une touche.
+#[cfg(feature = "cli")]
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Calcule le délai entre deux incrémentations après avoir repris son souffle (+50 %).
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::counter::slowed_delay;
+///
+/// assert_eq!(slowed_delay(80), 120);
+/// ```
+pub fn slowed_delay(speed: u32) -> u32 {
+    speed.saturating_mul(BREATH_SLOWDOWN_PERCENT) / 100
+}
+
+/// Calcule le délai avant l'incrémentation suivante, ralenti par l'assistance lorsque le compteur
+/// est à [`ASSIST_WINDOW`] de l'objectif au plus.
+///
+/// # Arguments
+///
+/// * `delay` - Le délai entre deux incrémentations, en millisecondes.
+/// * `counter` - La valeur affichée par le compteur.
+/// * `objective` - L'objectif visé.
+/// * `assist` - Le pourcentage ajouté au délai près de l'objectif, `None` sans assistance.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::counter::assisted_delay;
+///
+/// assert_eq!(assisted_delay(50, 35, 45, Some(60)), 80);
+/// assert_eq!(assisted_delay(50, 34, 45, Some(60)), 50);
+/// assert_eq!(assisted_delay(50, 45, 45, None), 50);
+/// ```
+pub fn assisted_delay(delay: u32, counter: u32, objective: u32, assist: Option<u32>) -> u32 {
+    match assist {
+        Some(percent) if ScoringCalculator::difference(objective, counter) <= ASSIST_WINDOW => {
+            delay.saturating_add(delay.saturating_mul(percent) / 100)
+        }
+        _ => delay,
+    }
+}
+
+/// Incrémente le compteur, qui repart de 0 après 100 en comptant un "miss".
+#[cfg(feature = "cli")]
+fn step(counter: &mut u32, miss: &mut u32) {
+    *counter = (*counter + 1) % 101;
+    if *counter == 0 {
+        *miss += 1;
+    }
+}
+
+/// Surveille le clavier jusqu'à ce que `done` retourne `true` ou que le jeton soit annulé.
+///
+/// Une touche quelconque annule le jeton d'interruption ; les touches pressées avant l'appel sont
+/// ignorées. Si le terminal ne permet pas le mode brut, seuls `done` et le gestionnaire de Ctrl+C
+/// mettent fin à l'attente.
+#[cfg(feature = "cli")]
+pub(crate) fn watch_keys(cancel: &CancelToken, done: impl Fn() -> bool) {
+    if terminal::enable_raw_mode().is_ok() {
+        let input = InputQueue::stdin();
+        input.skip_type_ahead();
+        while !cancel.is_cancelled() && !done() {
+            match input.next_event(cancel, Some(POLL_INTERVAL)) {
+                Ok(Some(InputEvent::Eof)) | Err(_) => break,
+                Ok(Some(_)) => cancel.cancel(),
+                Ok(None) => {}
+            }
+        }
+        let _ = terminal::disable_raw_mode();
+    } else {
+        while !done() && cancel.wait_timeout(POLL_INTERVAL).is_ok() {}
+    }
+}
+
+/// Annonces du compteur pour un objectif, en mode accessible.
+///
+/// Le temps écoulé est celui de la cadence du compteur, cumulé à chaque incrémentation : la valeur est
+/// annoncée toutes les `interval`, et la zone chaude à chaque fois que le compteur y entre.
+#[cfg(feature = "cli")]
+#[derive(Clone, Debug)]
+struct Announcer {
+    /// Objectif du compteur.
+    objective: u32,
+    /// Intervalle entre deux annonces de la valeur, `None` pour n'annoncer que la zone chaude.
+    interval: Option<Duration>,
+    /// Temps écoulé depuis le démarrage du compteur.
+    elapsed: Duration,
+    /// Instant de la prochaine annonce de la valeur.
+    next: Duration,
+    /// Indique si le compteur est dans la zone chaude.
+    hot: bool,
+    /// Indique si le démarrage du compteur a été annoncé.
+    started: bool,
+}
+
+#[cfg(feature = "cli")]
+impl Announcer {
+    /// Crée les annonces d'un objectif.
+    fn new(objective: u32, interval: Option<Duration>) -> Self {
+        let next = interval.unwrap_or_default();
+        Announcer {
+            objective,
+            interval,
+            elapsed: Duration::ZERO,
+            next,
+            hot: false,
+            started: false,
+        }
+    }
+
+    /// Retourne les annonces de la valeur courante du compteur, puis avance le temps de `delay`,
+    /// l'attente avant l'incrémentation suivante.
+    fn step(&mut self, counter: u32, delay: Duration) -> Vec<String> {
+        let mut lines = Vec::new();
+        if !self.started {
+            self.started = true;
+            lines.push(tr!(Msg::CounterStarted, objective = self.objective));
+        } else if let Some(interval) = self.interval
+            && self.elapsed >= self.next
+        {
+            lines.push(tr!(Msg::CounterValue, counter = counter));
+            while self.next <= self.elapsed {
+                self.next += interval;
+            }
+        }
+        let hot = ScoringCalculator::difference(self.objective, counter) <= HOT_ZONE;
+        if hot && !self.hot {
+            lines.push(tr!(
+                Msg::CounterHotZone,
+                counter = counter,
+                objective = self.objective
+            ));
+        }
+        self.hot = hot;
+        self.elapsed += delay;
+        lines
+    }
+}
+
+/// Affichage de l'état du compteur, partagé avec son thread.
+///
+/// Dans un terminal, l'état est réécrit sur place à chaque incrémentation (retour chariot). Sinon,
+/// par exemple lorsque la sortie est redirigée vers un fichier, seul l'état final est écrit, sur sa
+/// propre ligne. En mode accessible, rien n'est réécrit sur place : l'état est annoncé par des
+/// phrases (voir [`Announcer`]).
+#[cfg(feature = "cli")]
+#[derive(Clone)]
+struct StatusLine {
+    /// Destination de l'affichage (la sortie standard par défaut).
+    out: Arc<Mutex<dyn Write + Send>>,
+    /// Indique si l'état est animé sur place.
+    tty: bool,
+    /// Intervalle entre deux annonces de la valeur en mode accessible, `None` hors de ce mode.
+    announcements: Option<Option<Duration>>,
+    /// Indique si la valeur du compteur est cachée jusqu'à son arrêt.
+    hidden: bool,
+}
+
+#[cfg(feature = "cli")]
+impl StatusLine {
+    /// Indique si l'état est animé sur place : dans un terminal, hors du mode accessible.
+    fn animated(&self) -> bool {
+        self.tty && self.announcements.is_none()
+    }
+
+    /// Crée les annonces d'un objectif en mode accessible, `None` sinon ou si la valeur est cachée.
+    fn announcer(&self, objective: u32) -> Option<Announcer> {
+        if self.hidden {
+            return None;
+        }
+        self.announcements
+            .map(|interval| Announcer::new(objective, interval))
+    }
+
+    /// Retourne la valeur du compteur à afficher : [`HIDDEN_COUNTER`] si elle est cachée.
+    fn shown<'a>(&self, counter: &'a u32) -> &'a dyn Display {
+        if self.hidden {
+            &HIDDEN_COUNTER
+        } else {
+            counter
+        }
+    }
+
+    /// Écrit les annonces de la valeur courante du compteur, chacune sur sa propre ligne, en mode
+    /// accessible.
+    fn announce(&self, announcer: &mut Option<Announcer>, counter: u32, delay: Duration) {
+        if let Some(announcer) = announcer {
+            let lines = announcer.step(counter, delay);
+            if !lines.is_empty() {
+                let mut out = self.out.lock().unwrap();
+                for line in lines {
+                    let _ = writeln!(out, "{line}");
+                }
+                let _ = out.flush();
+            }
+        }
+    }
+
+    /// Réécrit sur place l'état formaté par le message donné, suivi de l'aperçu du score `preview` s'il
+    /// est fourni, effacé sur `width` caractères ; rien hors d'un terminal.
+    ///
+    /// L'état est formaté dans le tampon du thread et n'est écrit que s'il diffère du précédent.
+    /// L'effacement et l'état sont limités à la largeur du terminal, moins une colonne : une ligne qui
+    /// passerait à la ligne suivante ne serait plus réécrite sur place.
+    fn update(
+        &self,
+        buffer: &mut StatusBuffer,
+        width: usize,
+        msg: Msg,
+        args: &[(&str, &dyn Display)],
+        preview: Option<u32>,
+    ) {
+        if !self.animated() {
+            return;
+        }
+        buffer.text.clear();
+        msg.format_into(messages::lang(), args, &mut buffer.text);
+        let mut width = width;
+        if let Some(score) = preview {
+            Msg::CounterPreview.format_into(
+                messages::lang(),
+                &[("score", &score)],
+                &mut buffer.text,
+            );
+            width += PREVIEW_WIDTH;
+        }
+        let width = match style::terminal_width() {
+            Some(columns) => {
+                let columns = columns.saturating_sub(1);
+                if buffer.text.width() > columns {
+                    buffer.text = fit_to_width(&buffer.text, columns);
+                }
+                width.min(columns)
+            }
+            None => width,
+        };
+        if buffer.text == buffer.shown {
+            return;
+        }
+        let mut out = self.out.lock().unwrap();
+        let _ = write!(out, "\r{:<width$}\r{}", "", buffer.text);
+        let _ = out.flush();
+        std::mem::swap(&mut buffer.text, &mut buffer.shown);
+    }
+
+    /// Écrit un message sur sa propre ligne, hors de l'état animé : le menu de pause, par exemple.
+    fn message(&self, text: &str) {
+        let mut out = self.out.lock().unwrap();
+        let _ = writeln!(out, "{text}");
+        let _ = out.flush();
+    }
+
+    /// Termine la ligne animée dans un terminal.
+    fn line_break(&self) {
+        if self.animated() {
+            let _ = writeln!(self.out.lock().unwrap());
+        }
+    }
+
+    /// Écrit l'état final hors d'un terminal, où il n'a pas été animé, ou l'annonce de l'arrêt du
+    /// compteur en mode accessible.
+    fn finish(&self, objective: u32, counter: u32, miss: u32, status: &str) {
+        if self.animated() {
+            return;
+        }
+        let mut out = self.out.lock().unwrap();
+        if self.announcements.is_some() {
+            let _ = writeln!(
+                out,
+                "{}",
+                tr!(
+                    Msg::CounterStopped,
+                    counter = counter,
+                    objective = objective,
+                    miss = miss
+                )
+            );
+        } else {
+            let _ = writeln!(out, "{status}");
+        }
+        let _ = out.flush();
+    }
+}
+
+/// Tampons de l'état du compteur, propres à son thread et réutilisés à chaque incrémentation.
+#[cfg(feature = "cli")]
+#[derive(Debug)]
+struct StatusBuffer {
+    /// État en cours de formatage.
+    text: String,
+    /// Dernier état écrit.
+    shown: String,
+}
+
+#[cfg(feature = "cli")]
+impl StatusBuffer {
+    /// Capacité initiale de chaque tampon, suffisante pour un état complet (séquences de couleur
+    /// comprises).
+    const CAPACITY: usize = 256;
+
+    /// Crée des tampons préalloués.
+    fn new() -> Self {
+        StatusBuffer {
+            text: String::with_capacity(Self::CAPACITY),
+            shown: String::with_capacity(Self::CAPACITY),
+        }
+    }
+}
+
+/// Souffle d'un joueur pendant l'exécution du compteur pour un objectif.
+///
+/// Le délai est partagé avec le thread du compteur : le ralentissement s'applique dès l'incrémentation
+/// suivante et jusqu'à la fin de l'objectif.
+#[cfg(feature = "cli")]
+struct Breath {
+    /// Délai courant entre deux incrémentations, en millisecondes.
+    delay: Arc<AtomicU32>,
+    /// Indique si le souffle a déjà été utilisé pour cet objectif.
+    used: bool,
+}
+
+#[cfg(feature = "cli")]
+impl Breath {
+    /// Crée le souffle d'un objectif à partir du délai initial.
+    fn new(speed: u32) -> Self {
+        Breath {
+            delay: Arc::new(AtomicU32::new(speed)),
+            used: false,
+        }
+    }
+
+    /// Dépense 1 point d'endurance pour ralentir le compteur, au plus une fois par objectif.
+    ///
+    /// Retourne `true` si le compteur a été ralenti ; l'endurance ne descend jamais en dessous de zéro.
+    fn take(&mut self, stamina: &mut u32) -> bool {
+        if self.used || *stamina == 0 {
+            return false;
+        }
+        *stamina -= 1;
+        self.used = true;
+        let delay = self.delay.load(Ordering::Relaxed);
+        self.delay.store(slowed_delay(delay), Ordering::Relaxed);
+        true
+    }
+}
+
 /// Structure gérant un compteur pour simuler la mécanique d'incrémentation via un thread.
+#[cfg(feature = "cli")]
 pub struct Counter {
     /// La vitesse détermine la pause (en millisecondes) entre chaque incrémentation.
     pub speed: u32,
+    /// Jeton d'interruption arrêtant le compteur sans attendre ENTREE.
+    cancel: CancelToken,
+    /// Affichage de l'état du compteur.
+    display: StatusLine,
+    /// File des saisies du joueur.
+    input: InputQueue,
+    /// Horloge cadençant les incrémentations.
+    clock: Arc<dyn Clock + Send + Sync>,
+    /// Ralentissement de l'assistance près de l'objectif, en pourcentage, `None` sans assistance.
+    assist: Option<u32>,
+    /// Règles affichées depuis le menu de pause, `None` pour les règles par défaut.
+    rules: Option<String>,
 }
 
+#[cfg(feature = "cli")]
 impl Counter {
     /// Crée un nouveau compteur à partir de la vitesse spécifiée.
     ///
@@ -29,7 +466,214 @@ impl Counter {
     /// let counter = Counter::new(50);
     /// ```
     pub fn new(speed: u32) -> Self {
-        Counter { speed }
+        style::refresh_terminal_width();
+        let announcements = style::is_accessible().then(style::announce_interval);
+        let display = StatusLine {
+            out: Arc::new(Mutex::new(stdout())),
+            tty: style::is_tty(),
+            announcements,
+            hidden: false,
+        };
+        Counter {
+            speed,
+            cancel: CancelToken::new(),
+            display,
+            input: InputQueue::stdin(),
+            clock: Arc::new(SystemClock::default()),
+            assist: None,
+            rules: None,
+        }
+    }
+
+    /// Cadence le compteur par l'horloge donnée plutôt que par celle du système.
+    ///
+    /// Avec une [`ManualClock`](crate::clock::ManualClock), le compteur n'avance que lorsque l'horloge
+    /// est avancée : un test simule ainsi un long objectif sans l'attendre.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Choisit d'animer l'état du compteur sur place (`true`) ou de n'afficher que son état final.
+    ///
+    /// Par défaut, le compteur est animé si la sortie standard est un terminal (voir
+    /// [`style::is_tty`]).
+    pub fn with_tty(mut self, tty: bool) -> Self {
+        self.display.tty = tty;
+        self
+    }
+
+    /// Annonce l'état du compteur par des phrases plutôt que de l'afficher : sa valeur à l'intervalle
+    /// donné et son entrée dans la zone chaude, ou seulement celle-ci avec `None`.
+    ///
+    /// Par défaut, le compteur fait ses annonces en mode accessible (voir [`style::is_accessible`]), à
+    /// l'intervalle retenu par [`style::announce_interval`].
+    pub fn with_announcements(mut self, interval: Option<Duration>) -> Self {
+        self.display.announcements = Some(interval);
+        self
+    }
+
+    /// Ralentit le compteur près de l'objectif du pourcentage donné (voir [`assisted_delay`]).
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::counter::{Counter, DEFAULT_ASSIST_PERCENT};
+    ///
+    /// let counter = Counter::new(50).with_assist(Some(DEFAULT_ASSIST_PERCENT));
+    /// ```
+    pub fn with_assist(mut self, assist: Option<u32>) -> Self {
+        self.assist = assist;
+        self
+    }
+
+    /// Cache la valeur du compteur jusqu'à son arrêt : [`HIDDEN_COUNTER`] est affiché à sa place et
+    /// aucune annonce n'est faite en mode accessible.
+    pub fn with_hidden(mut self, hidden: bool) -> Self {
+        self.display.hidden = hidden;
+        self
+    }
+
+    /// Affiche les règles données lorsque le joueur les demande depuis le menu de pause, plutôt que
+    /// les règles par défaut.
+    pub fn with_rules(mut self, rules: String) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+
+    /// Lit l'arrêt du compteur dans la file donnée plutôt que dans celle de l'entrée standard.
+    pub fn with_input(mut self, input: InputQueue) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Associe un jeton d'interruption au compteur.
+    ///
+    /// Le compteur s'arrête dès que le jeton est annulé, et un Ctrl+C lu en mode brut annule le jeton.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::cancel::CancelToken;
+    /// use dual_game::counter::Counter;
+    ///
+    /// let token = CancelToken::new();
+    /// token.cancel();
+    /// assert!(Counter::new(50).with_cancel(token).run(30).is_err());
+    /// ```
+    pub fn with_cancel(mut self, cancel: CancelToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Attend l'arrêt du compteur par le joueur.
+    ///
+    /// Les touches sont traduites par les touches du jeu ([`keys::bindings`]) : en mode brut, la touche
+    /// d'arrêt (ENTREE par défaut) arrête le compteur, Ctrl+C annule le jeton d'interruption et la
+    /// touche d'endurance (`s` par défaut) appelle `on_breath` s'il est fourni ; le jeton est vérifié
+    /// entre deux touches. Si le terminal ne permet pas le mode brut, la saisie se fait ligne par ligne
+    /// (la touche d'endurance puis ENTREE pour `on_breath`). Dans les deux cas, la touche de pause
+    /// (`p` par défaut) suspend `clock` le temps du menu de pause (voir [`Counter::pause`]).
+    ///
+    /// Retourne `true` si le joueur a abandonné depuis le menu de pause.
+    fn wait_for_stop(
+        &self,
+        clock: &PausableClock,
+        mut on_breath: Option<&mut dyn FnMut()>,
+    ) -> bool {
+        let bindings = keys::bindings();
+        let mut forfeited = false;
+        if terminal::enable_raw_mode().is_ok() {
+            loop {
+                match self.input.next_event(&self.cancel, None) {
+                    // Le mode brut intercepte Ctrl+C : l'interruption est transmise à la partie.
+                    Ok(Some(InputEvent::Interrupt)) => self.cancel.cancel(),
+                    Ok(Some(InputEvent::Eof)) | Err(_) => break,
+                    Ok(Some(event)) => match bindings.translate(event) {
+                        Some(Action::Stop) => break,
+                        Some(Action::Stamina) => {
+                            if let Some(on_breath) = on_breath.as_mut() {
+                                on_breath();
+                            }
+                        }
+                        Some(Action::Pause) if self.pause(clock, true) => {
+                            forfeited = true;
+                            break;
+                        }
+                        _ => {}
+                    },
+                    Ok(None) => {}
+                }
+            }
+            let _ = terminal::disable_raw_mode();
+            self.display.line_break();
+        } else {
+            // La lecture est abandonnée dès l'interruption, sans attendre ENTREE.
+            while let Ok(Some(line)) = self.input.read_line(&self.cancel) {
+                match bindings.translate_line(&line) {
+                    Some(Action::Pause) => {
+                        if self.pause(clock, false) {
+                            forfeited = true;
+                            break;
+                        }
+                    }
+                    Some(Action::Stamina) if let Some(on_breath) = on_breath.as_mut() => {
+                        on_breath()
+                    }
+                    _ => break,
+                }
+            }
+        }
+        forfeited
+    }
+
+    /// Suspend `clock` et affiche le menu de pause jusqu'à ce que le joueur reprenne ou abandonne.
+    ///
+    /// Le choix se fait ligne par ligne, le mode brut (`raw`) étant quitté le temps du menu : `1` ou la
+    /// touche de pause reprend le compteur, `2` affiche les règles puis le menu à nouveau, `3`
+    /// abandonne. La fin des saisies et l'interruption reprennent l'horloge, le compteur s'arrêtant
+    /// alors aussitôt.
+    ///
+    /// Retourne `true` si le joueur abandonne.
+    fn pause(&self, clock: &PausableClock, raw: bool) -> bool {
+        clock.pause();
+        if raw {
+            let _ = terminal::disable_raw_mode();
+        }
+        self.display.line_break();
+        let bindings = keys::bindings();
+        let menu = tr!(Msg::CounterPaused, key = bindings.pause);
+        let mut forfeited = false;
+        loop {
+            self.display.message(&menu);
+            let Ok(Some(line)) = self.input.read_line(&self.cancel) else {
+                break;
+            };
+            match line.trim() {
+                "1" => break,
+                "2" => match &self.rules {
+                    Some(text) => self.display.message(text),
+                    None => self.display.message(&rules::render(
+                        &GameRules::default(),
+                        &ScoringConfig::default(),
+                    )),
+                },
+                "3" => {
+                    forfeited = true;
+                    break;
+                }
+                _ if bindings.translate_line(&line) == Some(Action::Pause) => break,
+                _ => {}
+            }
+        }
+        if !forfeited {
+            self.display.message(&tr!(Msg::CounterResumed));
+        }
+        if raw {
+            let _ = terminal::enable_raw_mode();
+        }
+        clock.resume();
+        forfeited
     }
 
     /// Exécute le compteur dans un thread pour un objectif donné.
@@ -38,7 +682,9 @@ impl Counter {
     /// - Le compteur s'incrémente toutes les `speed` millisecondes.
     /// - Lorsque le compteur atteint 100, il se réinitialise et le nombre de "miss" est incrémenté.
     /// - En continu, l'état du compteur est affiché, indiquant l'objectif, le nombre de "miss" et la valeur actuelle.
-    /// - L'exécution du compteur se termine dès que l'utilisateur appuie sur ENTREE.
+    /// - L'exécution du compteur se termine dès que l'utilisateur appuie sur ENTREE, ou dès que le jeton
+    ///   d'interruption est annulé (Ctrl+C), y compris pendant la lecture d'une ligne hors du mode brut.
+    ///   Le thread du compteur est toujours attendu avant de retourner.
     ///
     /// # Arguments
     ///
@@ -49,50 +695,683 @@ impl Counter {
     /// Retourne un tuple `(counter_value, miss)` où :
     /// - `counter_value` représente la valeur finale du compteur.
     /// - `miss` correspond au nombre de fois où le compteur a atteint zéro.
-    pub fn run(&self, objectif: u32) -> (u32, u32) {
-        let (tx, rx) = mpsc::channel();
+    ///
+    /// # Erreurs
+    ///
+    /// Retourne [`Cancelled`] si le jeton d'interruption a été annulé.
+    pub fn run(&self, objectif: u32) -> Result<(u32, u32), Cancelled> {
+        self.session().run(objectif)
+    }
+
+    /// Crée une session du compteur, dont le thread sert tous les objectifs d'un tour (voir
+    /// [`CounterSession`]).
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::cancel::CancelToken;
+    /// use dual_game::counter::Counter;
+    ///
+    /// let token = CancelToken::new();
+    /// token.cancel();
+    /// let mut session = Counter::new(50).with_cancel(token).session();
+    /// for objective in [20, 40, 60] {
+    ///     assert!(session.run(objective).is_err());
+    /// }
+    /// ```
+    pub fn session(&self) -> CounterSession {
+        CounterSession::new(Counter {
+            speed: self.speed,
+            cancel: self.cancel.clone(),
+            display: self.display.clone(),
+            input: self.input.clone(),
+            clock: Arc::clone(&self.clock),
+            assist: self.assist,
+            rules: self.rules.clone(),
+        })
+    }
+
+    /// Exécute le compteur et l'arrête de lui-même après `steps` incrémentations.
+    ///
+    /// L'affichage est celui de [`Counter::run`] : l'arrêt automatique dure `steps` fois la vitesse du
+    /// compteur, ce qui permet d'animer l'arrêt d'un bot. Pendant l'exécution, une touche quelconque
+    /// annule le jeton d'interruption et arrête aussitôt le compteur.
+    ///
+    /// # Arguments
+    ///
+    /// * `objectif` - La valeur cible, affichée avec l'état du compteur.
+    /// * `steps` - Le nombre d'incrémentations avant l'arrêt (tours complets compris).
+    ///
+    /// # Retour
+    ///
+    /// Retourne un tuple `(counter_value, miss)` comme [`Counter::run`], y compris lorsque l'arrêt est
+    /// écourté par l'interruption.
+    pub fn run_until(&self, objectif: u32, steps: u32) -> (u32, u32) {
         let speed = self.speed;
+        let cancel = self.cancel.clone();
+        let display = self.display.clone();
+        let clock = Arc::clone(&self.clock);
+        let assist = self.assist;
 
-        // Lancement d'un thread pour gérer l'incrémentation du compteur.
         let handle = thread::spawn(move || {
             let mut counter: u32 = 0;
             let mut miss: u32 = 0;
+            let mut steps_done: u32 = 0;
+            let mut next = clock.now();
+            let mut announcer = display.announcer(objectif);
+            let mut buffer = StatusBuffer::new();
             loop {
-                // Terminer la boucle dès que le signal d'arrêt est reçu.
-                if rx.try_recv().is_ok() {
+                let pause =
+                    Duration::from_millis(assisted_delay(speed, counter, objectif, assist) as u64);
+                display.update(
+                    &mut buffer,
+                    50,
+                    Msg::CounterStatus,
+                    &[
+                        ("objective", &objectif),
+                        ("miss", &miss),
+                        ("counter", display.shown(&counter)),
+                    ],
+                    None,
+                );
+                if steps_done == steps || cancel.is_cancelled() {
                     return (counter, miss);
                 }
-                // Affichage de l'état du compteur.
-                print!("\r{:<50}\r→ Objectif {} : Miss = {} | Compteur = {}", "", objectif, miss, counter);
-                stdout().flush().unwrap();
+                display.announce(&mut announcer, counter, pause);
+
+                step(&mut counter, &mut miss);
+                steps_done += 1;
+                // L'attente est écourtée par l'interruption, vérifiée au début du tour de boucle.
+                next += pause;
+                while clock.now() < next && cancel.wait_timeout(clock.poll_delay(next)).is_ok() {}
+            }
+        });
 
-                // Mise à jour du compteur.
-                counter = (counter + 1) % 101; // Réinitialisation à 0 si le compteur atteint 100.
-                // Incrémentation du nombre de "miss" si le compteur est à 0.
-                if counter == 0 {
-                    miss += 1;
+        watch_keys(&self.cancel, || handle.is_finished());
+        self.display.line_break();
+        let (counter, miss) = handle.join().unwrap();
+        self.display.finish(
+            objectif,
+            counter,
+            miss,
+            &tr!(
+                Msg::CounterStatus,
+                objective = objectif,
+                miss = miss,
+                counter = counter
+            ),
+        );
+        (counter, miss)
+    }
+
+    /// Exécute le compteur en permettant au joueur de reprendre son souffle.
+    ///
+    /// Le fonctionnement est celui de [`Counter::run`], mais un appui sur `s` dépense 1 point d'endurance
+    /// pour augmenter de 50 % le délai entre deux incrémentations jusqu'à la fin de l'objectif (une fois
+    /// par objectif). L'endurance restante est affichée en continu.
+    ///
+    /// Les touches sont lues directement en mode brut ; si le terminal ne le permet pas, la saisie se fait
+    /// ligne par ligne (`s` puis ENTREE pour reprendre son souffle, ENTREE seul pour arrêter).
+    /// Comme [`Counter::run`], le compteur s'arrête dès que la partie est interrompue.
+    ///
+    /// # Arguments
+    ///
+    /// * `objectif` - La valeur cible utilisée pour le calcul du score.
+    /// * `stamina` - L'endurance du joueur, décrémentée à chaque souffle repris.
+    ///
+    /// # Retour
+    ///
+    /// Retourne un tuple `(counter_value, miss)` comme [`Counter::run`], ou [`Cancelled`] si le jeton
+    /// d'interruption a été annulé.
+    pub fn run_with_stamina(
+        &self,
+        objectif: u32,
+        stamina: &mut u32,
+    ) -> Result<(u32, u32), Cancelled> {
+        self.session().run_with_stamina(objectif, stamina)
+    }
+
+    /// Exécute le compteur partagé par deux joueurs pour un objectif (mode `shared`).
+    ///
+    /// Le compteur défile comme pour [`Counter::run`], et son état indique quels joueurs ont déjà
+    /// verrouillé leur valeur : la touche `a` verrouille celle du premier joueur, `l` celle du second,
+    /// une seule fois chacun (voir [`SharedLocks`]). Le compteur s'arrête dès que les deux joueurs ont
+    /// verrouillé, après [`SHARED_MISS_CAP`] dépassements, à la fin des saisies ou dès que la partie est
+    /// interrompue ; chaque joueur qui n'a pas verrouillé est alors signalé.
+    ///
+    /// # Arguments
+    ///
+    /// * `objectif` - L'objectif commun aux deux joueurs.
+    /// * `names` - Les noms des joueurs, dans l'ordre de leurs touches.
+    ///
+    /// # Retour
+    ///
+    /// Retourne les verrous posés par les joueurs, y compris lorsque la partie est interrompue.
+    pub fn run_shared(&self, objectif: u32, names: [&str; 2]) -> SharedLocks {
+        let state = Arc::new(Mutex::new(SharedState::default()));
+        let counting = Arc::clone(&state);
+        let cancel = self.cancel.clone();
+        let display = self.display.clone();
+        let clock = Arc::clone(&self.clock);
+        let [first, second] = names.map(String::from);
+        let pause = Duration::from_millis(self.speed as u64);
+
+        let handle = thread::spawn(move || {
+            // Les marques sont formatées une fois pour toutes : l'affichage n'alloue rien par incrémentation.
+            let (locked, waiting) = (tr!(Msg::SharedLocked), tr!(Msg::SharedWaiting));
+            let mut announcer = display.announcer(objectif);
+            let mut buffer = StatusBuffer::new();
+            let mut next = clock.now();
+            loop {
+                let state = SharedState::snapshot(&counting);
+                let SharedState {
+                    counter,
+                    miss,
+                    locks,
+                    ..
+                } = state;
+                let mark = |player| {
+                    if locks.locked(player).is_some() {
+                        &locked
+                    } else {
+                        &waiting
+                    }
+                };
+                display.update(
+                    &mut buffer,
+                    90,
+                    Msg::SharedCounterStatus,
+                    &[
+                        ("objective", &objectif),
+                        ("miss", &miss),
+                        ("counter", &counter),
+                        ("first", &first),
+                        ("first_lock", mark(0)),
+                        ("second", &second),
+                        ("second_lock", mark(1)),
+                    ],
+                    None,
+                );
+                if state.is_over() || cancel.is_cancelled() {
+                    return;
+                }
+                display.announce(&mut announcer, counter, pause);
+
+                // La valeur affichée reste celle que verrouille une touche jusqu'à l'incrémentation
+                // suivante, dont l'attente est écourtée par le dernier verrou.
+                next += pause;
+                while clock.now() < next
+                    && !SharedState::snapshot(&counting).is_over()
+                    && cancel.wait_timeout(clock.poll_delay(next)).is_ok()
+                {}
+                let mut state = counting.lock().unwrap();
+                if !state.is_over() {
+                    let SharedState { counter, miss, .. } = &mut *state;
+                    step(counter, miss);
                 }
-                thread::sleep(Duration::from_millis(speed as u64));
             }
         });
 
-        // Attente de l'appui sur ENTREE pour stopper le compteur.
-        let mut dummy = String::new();
-        let _ = io::stdin().read_line(&mut dummy);
+        // Chaque touche de verrou verrouille la valeur affichée à l'instant où elle est lue.
+        self.input.skip_type_ahead();
+        let raw = terminal::enable_raw_mode().is_ok();
+        let bindings = keys::bindings();
+        while !handle.is_finished() {
+            match self.input.next_event(&self.cancel, Some(POLL_INTERVAL)) {
+                Ok(Some(InputEvent::Interrupt)) => self.cancel.cancel(),
+                Ok(Some(InputEvent::Eof)) | Err(_) => {
+                    state.lock().unwrap().ended = true;
+                    break;
+                }
+                Ok(Some(event)) => {
+                    if let Some(Action::Lock(player)) = bindings.translate(event) {
+                        let mut state = state.lock().unwrap();
+                        let (counter, miss) = (state.counter, state.miss);
+                        state.locks.lock(player, counter, miss);
+                    }
+                }
+                Ok(None) => {}
+            }
+        }
+        if raw {
+            let _ = terminal::disable_raw_mode();
+        }
+        self.display.line_break();
+        handle.join().unwrap();
 
-        // Envoi du signal d'arrêt au thread.
-        tx.send(()).unwrap();
+        let SharedState {
+            counter,
+            miss,
+            locks,
+            ..
+        } = SharedState::snapshot(&state);
+        self.display.finish(
+            objectif,
+            counter,
+            miss,
+            &tr!(
+                Msg::CounterStatus,
+                objective = objectif,
+                miss = miss,
+                counter = counter
+            ),
+        );
+        if !self.cancel.is_cancelled() {
+            let mut out = self.display.out.lock().unwrap();
+            for (player, name) in names.iter().enumerate() {
+                if locks.locked(player).is_none() {
+                    let _ = writeln!(
+                        out,
+                        "{}",
+                        tr!(Msg::SharedNoLock, player = name, cap = SHARED_MISS_CAP)
+                    );
+                }
+            }
+            let _ = out.flush();
+        }
+        locks
+    }
+}
+
+/// État du compteur partagé, commun à son thread et à la lecture des touches.
+#[cfg(feature = "cli")]
+#[derive(Clone, Copy, Debug, Default)]
+struct SharedState {
+    /// Valeur courante du compteur.
+    counter: u32,
+    /// Nombre de dépassements du compteur.
+    miss: u32,
+    /// Verrous posés par les joueurs.
+    locks: SharedLocks,
+    /// Indique que plus aucune touche n'arrivera : le compteur s'arrête sans attendre les verrous.
+    ended: bool,
+}
 
-        let (final_counter, final_miss) = handle.join().unwrap();
-        // println!(); // Passage à la ligne après la fin du comptage.
-        (final_counter, final_miss)
+#[cfg(feature = "cli")]
+impl SharedState {
+    /// Retourne une copie de l'état, sans garder le verrou au-delà de l'appel.
+    fn snapshot(state: &Mutex<SharedState>) -> SharedState {
+        *state.lock().unwrap()
+    }
+
+    /// Indique si le compteur doit s'arrêter : les deux joueurs ont verrouillé, le compteur a dépassé
+    /// 100 [`SHARED_MISS_CAP`] fois ou plus aucune touche n'arrivera.
+    fn is_over(&self) -> bool {
+        self.ended || self.locks.is_complete() || self.miss >= SHARED_MISS_CAP
     }
 }
 
-#[cfg(test)]
+/// Ordre transmis au thread d'une [`CounterSession`].
+#[cfg(feature = "cli")]
+enum Command {
+    /// Démarre le compteur pour un objectif.
+    Start {
+        /// Objectif du compteur.
+        objective: u32,
+        /// Délai courant entre deux incrémentations, en millisecondes, ralenti par le souffle.
+        delay: Arc<AtomicU32>,
+        /// Endurance affichée avec l'état du compteur, `None` sans la règle d'endurance.
+        stamina: Option<Arc<AtomicU32>>,
+        /// Aperçu du score affiché avec l'état du compteur, `None` pour ne pas l'afficher.
+        preview: Option<ScorePreview>,
+    },
+    /// Arrête le compteur en cours, dont le thread renvoie la valeur.
+    Stop,
+}
+
+/// Session du compteur couvrant le tour d'un joueur.
+///
+/// Un seul thread est lancé pour la session : il attend l'ordre de démarrer le compteur pour un
+/// objectif, puis celui de l'arrêter, et renvoie la valeur du compteur pour chaque objectif. Chaque
+/// objectif démarre ainsi sans le délai de lancement d'un nouveau thread. Le thread est arrêté et
+/// attendu lorsque la session est libérée, y compris si le tour s'interrompt en cours de route.
+#[cfg(feature = "cli")]
+pub struct CounterSession {
+    /// Compteur dont la session reprend la vitesse, le jeton d'interruption et l'affichage.
+    counter: Counter,
+    /// Ordres transmis au thread, `None` une fois la session arrêtée.
+    commands: Option<mpsc::Sender<Command>>,
+    /// Valeurs `(counter_value, miss)` renvoyées par le thread, une par objectif.
+    results: mpsc::Receiver<(u32, u32)>,
+    /// Thread du compteur, `None` une fois attendu.
+    handle: Option<thread::JoinHandle<()>>,
+    /// Horloge du thread, suspendue pendant les pauses.
+    clock: PausableClock,
+    /// Durée des pauses pendant le dernier objectif.
+    paused: Duration,
+    /// Indique si le joueur a abandonné depuis le menu de pause.
+    forfeited: bool,
+    /// Aperçu du score des prochains objectifs, `None` pour ne pas l'afficher.
+    preview: Option<ScorePreview>,
+}
+
+#[cfg(feature = "cli")]
+impl CounterSession {
+    /// Lance le thread de la session.
+    fn new(counter: Counter) -> Self {
+        let (commands, orders) = mpsc::channel();
+        let (results, received) = mpsc::channel();
+        let cancel = counter.cancel.clone();
+        let display = counter.display.clone();
+        let clock = PausableClock::new(Arc::clone(&counter.clock));
+        let ticking = clock.clone();
+        let assist = counter.assist;
+        let handle = thread::spawn(move || {
+            // Le thread s'arrête dès que la session est libérée.
+            while let Ok(command) = orders.recv() {
+                // Un ordre d'arrêt reçu entre deux objectifs, après une interruption, est ignoré.
+                if let Command::Start {
+                    objective,
+                    delay,
+                    stamina,
+                    preview,
+                } = command
+                {
+                    // Le délai est relu à chaque incrémentation : le souffle s'applique aussitôt.
+                    let pause = |counter| {
+                        Duration::from_millis(assisted_delay(
+                            delay.load(Ordering::Relaxed),
+                            counter,
+                            objective,
+                            assist,
+                        ) as u64)
+                    };
+                    let _ = results.send(Self::count(
+                        &orders,
+                        &display,
+                        &ticking,
+                        &cancel,
+                        objective,
+                        &pause,
+                        stamina.as_deref(),
+                        preview,
+                    ));
+                }
+            }
+        });
+        CounterSession {
+            counter,
+            commands: Some(commands),
+            results: received,
+            handle: Some(handle),
+            clock,
+            paused: Duration::ZERO,
+            forfeited: false,
+            preview: None,
+        }
+    }
+
+    /// Fait tourner le compteur pour un objectif jusqu'à l'ordre d'arrêt, l'interruption de la partie
+    /// ou la libération de la session.
+    ///
+    /// Les incrémentations sont cadencées par l'horloge ; celles qui sont échues à l'arrêt sans avoir
+    /// encore été faites sont rattrapées, de sorte que la valeur retournée ne dépend que du temps
+    /// écoulé. `pause` retourne le délai de l'incrémentation partant de la valeur donnée. L'aperçu du
+    /// score n'est affiché que si l'état est animé et que la valeur du compteur n'est pas cachée.
+    #[allow(clippy::too_many_arguments)]
+    fn count(
+        orders: &mpsc::Receiver<Command>,
+        display: &StatusLine,
+        clock: &dyn Clock,
+        cancel: &CancelToken,
+        objective: u32,
+        pause: &dyn Fn(u32) -> Duration,
+        stamina: Option<&AtomicU32>,
+        preview: Option<ScorePreview>,
+    ) -> (u32, u32) {
+        let preview = preview.filter(|_| display.animated() && !display.hidden);
+        let mut counter: u32 = 0;
+        let mut miss: u32 = 0;
+        let mut announcer = display.announcer(objective);
+        let mut buffer = StatusBuffer::new();
+        let mut next = clock.now();
+        loop {
+            // Terminer la boucle dès que la partie est interrompue.
+            if cancel.is_cancelled() {
+                return (counter, miss);
+            }
+            // Affichage de l'état du compteur.
+            match stamina {
+                Some(stamina) => display.update(
+                    &mut buffer,
+                    70,
+                    Msg::CounterStatusStamina,
+                    &[
+                        ("objective", &objective),
+                        ("miss", &miss),
+                        ("counter", display.shown(&counter)),
+                        ("stamina", &stamina.load(Ordering::Relaxed)),
+                    ],
+                    preview.map(|preview| preview.score(counter, miss)),
+                ),
+                None => display.update(
+                    &mut buffer,
+                    50,
+                    Msg::CounterStatus,
+                    &[
+                        ("objective", &objective),
+                        ("miss", &miss),
+                        ("counter", display.shown(&counter)),
+                    ],
+                    preview.map(|preview| preview.score(counter, miss)),
+                ),
+            }
+            let delay = pause(counter);
+            display.announce(&mut announcer, counter, delay);
+
+            // Mise à jour du compteur.
+            step(&mut counter, &mut miss);
+            next += delay;
+            // Attente de l'incrémentation suivante, écourtée par l'ordre d'arrêt.
+            if !Self::wait_tick(orders, clock, next) {
+                loop {
+                    let delay = pause(counter);
+                    if delay.is_zero() || clock.now() < next {
+                        return (counter, miss);
+                    }
+                    step(&mut counter, &mut miss);
+                    next += delay;
+                }
+            }
+        }
+    }
+
+    /// Attend que l'horloge atteigne l'instant `next` de l'incrémentation suivante.
+    ///
+    /// Retourne `false` si l'attente est écourtée par un ordre (l'arrêt) ou par la libération de la
+    /// session.
+    fn wait_tick(orders: &mpsc::Receiver<Command>, clock: &dyn Clock, next: Duration) -> bool {
+        loop {
+            match orders.recv_timeout(clock.poll_delay(next)) {
+                Err(RecvTimeoutError::Timeout) if clock.now() >= next => return true,
+                Err(RecvTimeoutError::Timeout) => {}
+                _ => return false,
+            }
+        }
+    }
+
+    /// Démarre le compteur pour un objectif.
+    fn start(&self, objective: u32, delay: Arc<AtomicU32>, stamina: Option<Arc<AtomicU32>>) {
+        if let Some(commands) = &self.commands {
+            let _ = commands.send(Command::Start {
+                objective,
+                delay,
+                stamina,
+                preview: self.preview,
+            });
+        }
+    }
+
+    /// Affiche pendant les prochains objectifs le score qu'obtiendrait le joueur en arrêtant le
+    /// compteur sur sa valeur courante, ou ne l'affiche plus avec `None` (voir
+    /// [`CounterView::score_preview`](crate::controller::CounterView::score_preview)).
+    pub fn set_preview(&mut self, preview: Option<ScorePreview>) {
+        self.preview = preview;
+    }
+
+    /// Retourne la durée des pauses pendant le dernier objectif, exclue de ses incrémentations.
+    pub fn paused(&self) -> Duration {
+        self.paused
+    }
+
+    /// Indique si le joueur a abandonné la partie depuis le menu de pause.
+    pub fn has_forfeited(&self) -> bool {
+        self.forfeited
+    }
+
+    /// Attend l'arrêt du compteur par le joueur (voir [`Counter::wait_for_stop`]), puis retient la
+    /// durée des pauses de l'objectif et l'éventuel abandon.
+    fn wait_for_stop(&mut self, on_breath: Option<&mut dyn FnMut()>) {
+        let before = self.clock.paused();
+        self.forfeited |= self.counter.wait_for_stop(&self.clock, on_breath);
+        self.paused = self.clock.paused().saturating_sub(before);
+    }
+
+    /// Arrête le compteur en cours et retourne sa valeur, une fois son thread revenu en attente.
+    fn stop(&self) -> (u32, u32) {
+        if let Some(commands) = &self.commands {
+            let _ = commands.send(Command::Stop);
+        }
+        self.results.recv().unwrap()
+    }
+
+    /// Exécute le compteur pour un objectif, comme [`Counter::run`], sur le thread de la session.
+    ///
+    /// # Arguments
+    ///
+    /// * `objectif` - La valeur cible utilisée pour le calcul du score.
+    ///
+    /// # Retour
+    ///
+    /// Retourne un tuple `(counter_value, miss)`, ou [`Cancelled`], comme [`Counter::run`].
+    pub fn run(&mut self, objectif: u32) -> Result<(u32, u32), Cancelled> {
+        // Un ENTREE pressé en trop pour l'objectif précédent n'arrête pas celui-ci.
+        self.counter.input.skip_type_ahead();
+        self.start(objectif, Arc::new(AtomicU32::new(self.counter.speed)), None);
+        // Attente de l'appui sur ENTREE (ou de l'interruption) pour stopper le compteur.
+        self.wait_for_stop(None);
+        let (counter, miss) = self.stop();
+        self.counter.display.finish(
+            objectif,
+            counter,
+            miss,
+            &tr!(
+                Msg::CounterStatus,
+                objective = objectif,
+                miss = miss,
+                counter = counter
+            ),
+        );
+        self.counter.cancel.check()?;
+        Ok((counter, miss))
+    }
+
+    /// Exécute le compteur pour un objectif en permettant au joueur de reprendre son souffle, comme
+    /// [`Counter::run_with_stamina`], sur le thread de la session.
+    ///
+    /// # Arguments
+    ///
+    /// * `objectif` - La valeur cible utilisée pour le calcul du score.
+    /// * `stamina` - L'endurance du joueur, décrémentée à chaque souffle repris.
+    ///
+    /// # Retour
+    ///
+    /// Retourne un tuple `(counter_value, miss)`, ou [`Cancelled`], comme [`Counter::run`].
+    pub fn run_with_stamina(
+        &mut self,
+        objectif: u32,
+        stamina: &mut u32,
+    ) -> Result<(u32, u32), Cancelled> {
+        let mut breath = Breath::new(self.counter.speed);
+        let remaining = Arc::new(AtomicU32::new(*stamina));
+        self.counter.input.skip_type_ahead();
+        self.start(
+            objectif,
+            Arc::clone(&breath.delay),
+            Some(Arc::clone(&remaining)),
+        );
+        self.wait_for_stop(Some(&mut || {
+            breath.take(stamina);
+            remaining.store(*stamina, Ordering::Relaxed);
+        }));
+        let (counter, miss) = self.stop();
+        self.counter.display.finish(
+            objectif,
+            counter,
+            miss,
+            &tr!(
+                Msg::CounterStatusStamina,
+                objective = objectif,
+                miss = miss,
+                counter = counter,
+                stamina = *stamina
+            ),
+        );
+        self.counter.cancel.check()?;
+        Ok((counter, miss))
+    }
+}
+
+#[cfg(feature = "cli")]
+impl fmt::Debug for CounterSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CounterSession")
+            .field("speed", &self.counter.speed)
+            .field("running", &self.handle.is_some())
+            .finish()
+    }
+}
+
+#[cfg(feature = "cli")]
+impl Drop for CounterSession {
+    /// Arrête le thread de la session et l'attend.
+    fn drop(&mut self) {
+        self.commands = None;
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "cli"))]
 mod tests {
     use super::*;
 
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+    use std::sync::atomic::AtomicBool;
+    use std::time::Instant;
+
+    use crate::clock::ManualClock;
+
+    thread_local! {
+        /// Nombre d'allocations faites par le thread courant.
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Allocateur comptant les allocations de chaque thread, pour vérifier que le compteur n'alloue
+    /// plus rien d'une incrémentation à l'autre.
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            unsafe { System.realloc(ptr, layout, new_size) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
     /// Vérifie que la création d'un compteur avec une vitesse donnée fonctionne correctement.
     #[test]
     fn test_counter_new() {
@@ -110,7 +1389,7 @@ mod tests {
 
         // Simuler un thread séparé pour arrêter rapidement le compteur.
         let handle = thread::spawn(move || {
-            let (value, _miss) = counter.run(50); // Utilisation d'une valeur d'objectif valide
+            let (value, _miss) = counter.run(50).unwrap(); // Utilisation d'une valeur d'objectif valide
             assert!(value <= 100);
         });
 
@@ -125,4 +1404,519 @@ mod tests {
             panic!("Thread panicked: {:?}", err);
         }
     }
+
+    /// Vérifie que reprendre son souffle ralentit le compteur une seule fois par objectif
+    /// et que l'endurance ne descend pas en dessous de zéro.
+    #[test]
+    fn test_breath_slows_counter() {
+        let mut stamina = 1;
+        let mut breath = Breath::new(80);
+        assert!(breath.take(&mut stamina));
+        assert_eq!(breath.delay.load(Ordering::Relaxed), 120);
+        assert_eq!(stamina, 0);
+        assert!(!breath.take(&mut stamina));
+        assert_eq!(breath.delay.load(Ordering::Relaxed), 120);
+
+        let mut breath = Breath::new(80);
+        assert!(!breath.take(&mut stamina));
+        assert_eq!(stamina, 0);
+        assert_eq!(breath.delay.load(Ordering::Relaxed), 80);
+    }
+
+    /// Vérifie que l'arrêt automatique tient compte des tours complets du compteur.
+    #[test]
+    fn test_counter_run_until() {
+        let counter = Counter::new(1);
+        assert_eq!(counter.run_until(90, 105), (4, 1));
+        assert_eq!(counter.run_until(30, 0), (0, 0));
+    }
+
+    /// Vérifie que, hors d'un terminal, le compteur n'écrit que son état final, sans retour chariot,
+    /// alors qu'il est animé sur place dans un terminal.
+    #[test]
+    fn test_piped_counter_output() {
+        let piped = Arc::new(Mutex::new(Vec::new()));
+        let mut counter = Counter::new(1).with_tty(false);
+        counter.display.out = piped.clone();
+        assert_eq!(counter.run_until(90, 105), (4, 1));
+        let output = String::from_utf8(piped.lock().unwrap().clone()).unwrap();
+        assert!(!output.contains('\r'), "{output:?}");
+        assert_eq!(output.lines().count(), 1, "{output:?}");
+        assert!(output.ends_with("4\n"), "{output:?}");
+
+        let animated = Arc::new(Mutex::new(Vec::new()));
+        let mut counter = Counter::new(1).with_tty(true);
+        counter.display.out = animated.clone();
+        counter.run_until(90, 10);
+        assert!(animated.lock().unwrap().contains(&b'\r'));
+    }
+
+    /// Vérifie que l'affichage animé réutilise ses tampons d'une incrémentation à l'autre, sans
+    /// allocation, et n'écrit l'état que lorsqu'il change.
+    #[test]
+    fn test_status_update_allocations() {
+        let out = Arc::new(Mutex::new(Vec::with_capacity(1 << 16)));
+        let display = StatusLine {
+            out: out.clone(),
+            tty: true,
+            announcements: None,
+            hidden: false,
+        };
+        let mut buffer = StatusBuffer::new();
+        let preview = ScoringCalculator::preview(50, 50);
+        let update = |buffer: &mut StatusBuffer, counter: u32| {
+            display.update(
+                buffer,
+                50,
+                Msg::CounterStatus,
+                &[("objective", &50), ("miss", &0), ("counter", &counter)],
+                None,
+            );
+        };
+        let before = ALLOCATIONS.with(Cell::get);
+        for counter in 0..100 {
+            let args: [(&str, &dyn Display); 3] =
+                [("objective", &50), ("miss", &0), ("counter", &counter)];
+            display.update(
+                &mut buffer,
+                50,
+                Msg::CounterStatus,
+                &args,
+                Some(preview.score(counter, 0)),
+            );
+        }
+        for counter in 0..100 {
+            update(&mut buffer, counter);
+        }
+        assert_eq!(ALLOCATIONS.with(Cell::get), before);
+
+        // Un état inchangé n'est pas réécrit.
+        let written = out.lock().unwrap().len();
+        update(&mut buffer, 99);
+        assert_eq!(out.lock().unwrap().len(), written);
+        update(&mut buffer, 98);
+        assert!(out.lock().unwrap().len() > written);
+    }
+
+    /// Vérifie la cadence des annonces : le démarrage, la valeur à chaque intervalle et chaque entrée
+    /// dans la zone chaude, ou seulement cette dernière sans intervalle.
+    #[test]
+    fn test_announcer_cadence() {
+        let tick = Duration::from_millis(10);
+        let mut announcer = Announcer::new(50, Some(Duration::from_millis(200)));
+        let lines: Vec<String> = (0..=100)
+            .chain(0..=45)
+            .flat_map(|counter| announcer.step(counter, tick))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "Le compteur démarre pour l'objectif 50.",
+                "Compteur à 20.",
+                "Compteur à 40.",
+                "Zone chaude : compteur à 40, objectif 50.",
+                "Compteur à 60.",
+                "Compteur à 80.",
+                "Compteur à 100.",
+                "Compteur à 19.",
+                "Compteur à 39.",
+                "Zone chaude : compteur à 40, objectif 50.",
+            ]
+        );
+
+        let mut announcer = Announcer::new(95, None);
+        let lines: Vec<String> = (0..=100)
+            .flat_map(|counter| announcer.step(counter, tick))
+            .collect();
+        // La zone chaude tient compte du passage de 100 à 0.
+        assert_eq!(
+            lines,
+            [
+                "Le compteur démarre pour l'objectif 95.",
+                "Zone chaude : compteur à 0, objectif 95.",
+                "Zone chaude : compteur à 85, objectif 95.",
+            ]
+        );
+    }
+
+    /// Vérifie qu'en mode accessible le compteur n'écrit que des phrases complètes, chacune sur sa
+    /// propre ligne, sans retour chariot ni séquence d'échappement.
+    #[test]
+    fn test_accessible_counter_output() {
+        let spoken = Arc::new(Mutex::new(Vec::new()));
+        let mut counter = Counter::new(1).with_tty(true).with_announcements(None);
+        counter.display.out = spoken.clone();
+        assert_eq!(counter.run_until(5, 3), (3, 0));
+        let output = String::from_utf8(spoken.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            output,
+            "Le compteur démarre pour l'objectif 5.\n\
+             Zone chaude : compteur à 0, objectif 5.\n\
+             Compteur arrêté à 3 pour l'objectif 5, après 0 dépassement(s).\n"
+        );
+    }
+
+    /// Vérifie qu'une session sert plusieurs objectifs sur le même thread, avec une valeur par
+    /// objectif, et que son thread est attendu à sa libération, même au milieu d'un objectif.
+    #[test]
+    fn test_counter_session() {
+        let piped = Arc::new(Mutex::new(Vec::new()));
+        let mut counter = Counter::new(500).with_tty(false);
+        counter.display.out = piped.clone();
+        let session = counter.session();
+        drop(counter);
+        let speed = Arc::new(AtomicU32::new(500));
+        for objective in [10, 20, 30] {
+            // L'ordre d'arrêt écourte l'attente suivant la première incrémentation.
+            session.start(objective, Arc::clone(&speed), None);
+            assert_eq!(session.stop(), (1, 0));
+        }
+        session.start(40, speed, None);
+        drop(session);
+        // Le thread libéré ne détient plus l'affichage.
+        assert_eq!(Arc::strong_count(&piped), 1);
+    }
+
+    /// Vérifie qu'un compteur dont la partie est interrompue s'arrête sans attendre le joueur,
+    /// son thread étant attendu avant de retourner.
+    #[test]
+    fn test_cancelled_counter_stops() {
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let counter = Counter::new(5).with_cancel(cancel);
+        let mut stamina = 2;
+        assert_eq!(counter.run_with_stamina(40, &mut stamina), Err(Cancelled));
+        assert_eq!(stamina, 2);
+    }
+
+    /// Vérifie qu'une interruption en cours d'objectif arrête le compteur en moins de 100 ms, même
+    /// pendant l'attente d'une ligne et entre deux incrémentations lentes.
+    #[test]
+    fn test_cancel_mid_counter() {
+        let (reader, _writer) = std::io::pipe().unwrap();
+        let cancel = CancelToken::new();
+        let mut counter = Counter::new(500)
+            .with_tty(false)
+            .with_cancel(cancel.clone());
+        counter.display.out = Arc::new(Mutex::new(Vec::new()));
+        counter.input = InputQueue::from_reader(reader, false);
+
+        let canceller = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            cancel.cancel();
+            Instant::now()
+        });
+        let result = counter.run(50);
+        let returned = Instant::now();
+        let cancelled = canceller.join().unwrap();
+        assert_eq!(result, Err(Cancelled));
+        assert!(returned.duration_since(cancelled) < Duration::from_millis(100));
+    }
+
+    /// Vérifie qu'un ENTREE pressé en trop juste après l'arrêt d'un objectif n'arrête pas l'objectif
+    /// suivant dès son démarrage.
+    #[test]
+    fn test_type_ahead_ignored() {
+        let cancel = CancelToken::new();
+        let mut counter = Counter::new(5).with_tty(false).with_cancel(cancel);
+        counter.display.out = Arc::new(Mutex::new(Vec::new()));
+        let (reader, mut writer) = std::io::pipe().unwrap();
+        counter.input = InputQueue::from_reader(reader, true);
+
+        let pusher = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(30));
+            // Double ENTREE, lu d'un bloc en mode brut : le second arrive avant le démarrage de
+            // l'objectif suivant.
+            writer.write_all(b"\r\r").unwrap();
+            thread::sleep(Duration::from_millis(200));
+            writer.write_all(b"\r").unwrap();
+        });
+        let mut session = counter.session();
+        assert!(session.run(20).is_ok());
+        let second = session.run(40).unwrap();
+        pusher.join().unwrap();
+        assert!(second.0 > 10 || second.1 > 0);
+    }
+
+    /// Vérifie qu'un objectif de 10 secondes cadencé par une horloge manuelle est simulé sans être
+    /// attendu, et que sa valeur ne dépend que du temps écoulé.
+    #[test]
+    fn test_simulated_ten_second_counter() {
+        let clock = ManualClock::new();
+        let mut counter = Counter::new(50)
+            .with_tty(true)
+            .with_clock(Arc::new(clock.clone()));
+        counter.display.announcements = None;
+        let out = Arc::new(Mutex::new(Vec::new()));
+        counter.display.out = out.clone();
+        let input = InputQueue::new(false);
+        counter.input = input.clone();
+
+        let started = Instant::now();
+        let player = thread::spawn(move || {
+            // Le compteur a démarré dès qu'il a affiché son premier état.
+            while out.lock().unwrap().is_empty() {
+                thread::yield_now();
+            }
+            clock.advance(Duration::from_secs(10));
+            input.push(InputEvent::Enter);
+        });
+        // Une incrémentation au démarrage, puis une toutes les 50 ms : 201, dont un tour complet.
+        assert_eq!(counter.run(50), Ok((100, 1)));
+        player.join().unwrap();
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    /// Vérifie que l'assistance ralentit exactement les incrémentations partant d'une valeur à 10 de
+    /// l'objectif au plus, y compris de l'autre côté du passage par 100.
+    #[test]
+    fn test_assisted_delay_window() {
+        let slowed: Vec<u32> = (0..=100)
+            .filter(|&counter| assisted_delay(50, counter, 50, Some(60)) != 50)
+            .collect();
+        assert_eq!(slowed, (40..=60).collect::<Vec<u32>>());
+        assert!(
+            slowed
+                .iter()
+                .all(|&counter| assisted_delay(50, counter, 50, Some(60)) == 80)
+        );
+
+        let slowed: Vec<u32> = (0..=100)
+            .filter(|&counter| assisted_delay(50, counter, 5, Some(60)) != 50)
+            .collect();
+        assert_eq!(
+            slowed,
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 95, 96, 97, 98, 99, 100
+            ]
+        );
+        assert!((0..=100).all(|counter| assisted_delay(50, counter, 50, None) == 50));
+    }
+
+    /// Arrête un compteur cadencé par une horloge manuelle après la durée donnée.
+    fn stop_after(assist: Option<u32>, elapsed: Duration) -> (u32, u32) {
+        let clock = ManualClock::new();
+        let mut counter = Counter::new(50)
+            .with_tty(true)
+            .with_clock(Arc::new(clock.clone()))
+            .with_assist(assist);
+        counter.display.announcements = None;
+        let out = Arc::new(Mutex::new(Vec::new()));
+        counter.display.out = out.clone();
+        let input = InputQueue::new(false);
+        counter.input = input.clone();
+
+        let player = thread::spawn(move || {
+            while out.lock().unwrap().is_empty() {
+                thread::yield_now();
+            }
+            clock.advance(elapsed);
+            input.push(InputEvent::Enter);
+        });
+        let stop = counter.run(50).unwrap();
+        player.join().unwrap();
+        stop
+    }
+
+    /// Vérifie le cadencement du compteur assisté : 50 ms par incrémentation jusqu'à 40, puis 80 ms de
+    /// 40 à 61, la valeur 62 étant atteinte après 40 × 50 + 21 × 80 = 3 680 ms.
+    #[test]
+    fn test_assist_slows_counter_near_objective() {
+        assert_eq!(stop_after(Some(60), Duration::from_millis(1999)), (40, 0));
+        assert_eq!(stop_after(Some(60), Duration::from_millis(2000)), (41, 0));
+        assert_eq!(stop_after(Some(60), Duration::from_millis(2079)), (41, 0));
+        assert_eq!(stop_after(Some(60), Duration::from_millis(3679)), (61, 0));
+        assert_eq!(stop_after(Some(60), Duration::from_millis(3680)), (62, 0));
+        assert_eq!(stop_after(Some(60), Duration::from_millis(3730)), (63, 0));
+        assert_eq!(stop_after(None, Duration::from_millis(3680)), (74, 0));
+    }
+
+    /// Crée un compteur partagé cadencé par une horloge manuelle, hors d'un terminal, et sa file de
+    /// touches scriptée.
+    fn scripted_shared_counter(clock: &ManualClock) -> (Counter, InputQueue, Arc<Mutex<Vec<u8>>>) {
+        let mut counter = Counter::new(50)
+            .with_tty(false)
+            .with_clock(Arc::new(clock.clone()));
+        counter.display.announcements = None;
+        let out = Arc::new(Mutex::new(Vec::new()));
+        counter.display.out = out.clone();
+        let input = InputQueue::new(false);
+        counter.input = input.clone();
+        (counter, input, out)
+    }
+
+    /// Vérifie les verrous du compteur partagé pilotés par des touches scriptées : une seule valeur par
+    /// joueur, les autres touches ignorées, et l'arrêt dès le second verrou, à la fin des touches ou
+    /// après le nombre maximal de dépassements.
+    #[test]
+    fn test_shared_counter_locks() {
+        let clock = ManualClock::new();
+        let (counter, input, out) = scripted_shared_counter(&clock);
+        for key in ['a', 'a', 'x', 'L'] {
+            input.push(InputEvent::Char(key));
+        }
+        let locks = counter.run_shared(30, ["Alice", "Bob"]);
+        assert_eq!(
+            (locks.locked(0), locks.locked(1)),
+            (Some((0, 0)), Some((0, 0)))
+        );
+        assert_eq!(
+            String::from_utf8(out.lock().unwrap().clone()).unwrap(),
+            "→ Objectif 30 : Miss = 0 | Compteur = 0\n"
+        );
+
+        // Les touches s'arrêtent avant que Alice n'ait verrouillé : elle est comptée à l'écart maximal.
+        let (counter, input, out) = scripted_shared_counter(&clock);
+        input.push(InputEvent::Char('l'));
+        input.push(InputEvent::Eof);
+        let locks = counter.run_shared(30, ["Alice", "Bob"]);
+        assert_eq!((locks.locked(0), locks.locked(1)), (None, Some((0, 0))));
+        let output = String::from_utf8(out.lock().unwrap().clone()).unwrap();
+        assert!(
+            output.ends_with("Alice n'a pas verrouillé avant 3 miss : écart maximal.\n"),
+            "{output}"
+        );
+        assert_eq!(locks.decisions(30)[0].miss, SHARED_MISS_CAP);
+
+        // Sans aucune touche, le compteur s'arrête de lui-même après trois dépassements.
+        let (counter, _input, out) = scripted_shared_counter(&clock);
+        let done = Arc::new(AtomicBool::new(false));
+        let advancer = {
+            let done = Arc::clone(&done);
+            thread::spawn(move || {
+                while !done.load(Ordering::Relaxed) {
+                    clock.advance(Duration::from_secs(1));
+                    thread::yield_now();
+                }
+            })
+        };
+        let locks = counter.run_shared(30, ["Alice", "Bob"]);
+        done.store(true, Ordering::Relaxed);
+        advancer.join().unwrap();
+        assert_eq!(locks, SharedLocks::default());
+        let output = String::from_utf8(out.lock().unwrap().clone()).unwrap();
+        assert!(
+            output.starts_with("→ Objectif 30 : Miss = 3 | Compteur = 0\n"),
+            "{output}"
+        );
+        assert_eq!(output.matches("n'a pas verrouillé").count(), 2);
+    }
+
+    /// Ajoute une ligne saisie à la file, caractère par caractère puis ENTREE.
+    fn push_line(input: &InputQueue, line: &str) {
+        for c in line.chars() {
+            input.push(InputEvent::Char(c));
+        }
+        input.push(InputEvent::Enter);
+    }
+
+    /// Crée une session cadencée par une horloge manuelle, animée, et sa file de saisies scriptée.
+    fn scripted_session(clock: &ManualClock) -> (CounterSession, InputQueue, Arc<Mutex<Vec<u8>>>) {
+        let mut counter = Counter::new(50)
+            .with_tty(true)
+            .with_clock(Arc::new(clock.clone()))
+            .with_rules(String::from("Règles de la partie"));
+        counter.display.announcements = None;
+        let out = Arc::new(Mutex::new(Vec::new()));
+        counter.display.out = out.clone();
+        let input = InputQueue::new(false);
+        counter.input = input.clone();
+        (counter.session(), input, out)
+    }
+
+    /// Vérifie une pause pilotée par des saisies scriptées : le compteur ne s'incrémente pas pendant
+    /// 30 secondes de pause, sa valeur ne dépendant que du temps hors pause, la durée de la pause est
+    /// retenue pour l'objectif et le menu affiche les règles sur demande. L'abandon depuis le menu
+    /// arrête le compteur.
+    #[test]
+    fn test_pause_menu() {
+        let clock = ManualClock::new();
+        let (mut session, input, out) = scripted_session(&clock);
+        let pausable = session.clock.clone();
+        let player = {
+            let (clock, out) = (clock.clone(), Arc::clone(&out));
+            thread::spawn(move || {
+                while out.lock().unwrap().is_empty() {
+                    thread::yield_now();
+                }
+                clock.advance(Duration::from_millis(1000));
+                push_line(&input, "p");
+                while !pausable.is_paused() {
+                    thread::yield_now();
+                }
+                clock.advance(Duration::from_secs(30));
+                push_line(&input, "2");
+                push_line(&input, "1");
+                while pausable.is_paused() {
+                    thread::yield_now();
+                }
+                clock.advance(Duration::from_millis(1000));
+                input.push(InputEvent::Enter);
+            })
+        };
+        // 2 secondes hors pause : une incrémentation au démarrage, puis une toutes les 50 ms.
+        assert_eq!(session.run(50), Ok((41, 0)));
+        player.join().unwrap();
+        assert_eq!(session.paused(), Duration::from_secs(30));
+        assert!(!session.has_forfeited());
+        let output = String::from_utf8(out.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("⏸ Pause — 1 : reprendre"), "{output}");
+        assert!(output.contains("\nRègles de la partie\n"), "{output}");
+        assert!(output.contains("▶ Reprise du compteur."), "{output}");
+
+        // L'objectif suivant, sans pause, n'en retient aucune.
+        session.counter.input.push(InputEvent::Enter);
+        assert!(session.run(60).is_ok());
+        assert_eq!(session.paused(), Duration::ZERO);
+
+        let (mut session, input, _out) = scripted_session(&clock);
+        push_line(&input, "p");
+        push_line(&input, "3");
+        assert!(session.run(50).is_ok());
+        assert!(session.has_forfeited());
+    }
+
+    /// Vérifie que l'état animé affiche, à chaque incrémentation, le score qu'obtiendrait le joueur en
+    /// arrêtant le compteur, et que l'aperçu disparaît lorsqu'il n'est plus fourni.
+    #[test]
+    fn test_score_preview() {
+        let clock = ManualClock::new();
+        let (mut session, input, out) = scripted_session(&clock);
+        session.set_preview(Some(ScoringCalculator::preview(50, 50)));
+        let player = {
+            let (clock, out) = (clock.clone(), Arc::clone(&out));
+            thread::spawn(move || {
+                while out.lock().unwrap().is_empty() {
+                    thread::yield_now();
+                }
+                clock.advance(Duration::from_millis(2000));
+                while !String::from_utf8_lossy(&out.lock().unwrap()).contains("Compteur = 40") {
+                    thread::yield_now();
+                }
+                input.push(InputEvent::Enter);
+            })
+        };
+        assert_eq!(session.run(50), Ok((41, 0)));
+        player.join().unwrap();
+        let output = String::from_utf8(out.lock().unwrap().clone()).unwrap();
+        assert!(
+            output.contains("Compteur = 0 | si vous arrêtez ici : 70"),
+            "{output}"
+        );
+        let expected = ScoringCalculator::calculate_score(50, 40, 0, 50);
+        assert!(
+            output.contains(&format!("Compteur = 40 | si vous arrêtez ici : {expected}")),
+            "{output}"
+        );
+
+        out.lock().unwrap().clear();
+        session.set_preview(None);
+        session.counter.input.push(InputEvent::Enter);
+        assert!(session.run(60).is_ok());
+        assert!(
+            !String::from_utf8(out.lock().unwrap().clone())
+                .unwrap()
+                .contains("si vous arrêtez ici")
+        );
+    }
 }
@@ -0,0 +1,269 @@
+//! Module du défi du jour.
+//!
+//! Le défi du jour est un entraînement en solo dont les objectifs sont tirés d'une graine dérivée de
+//! la date UTC ([`DailyDate::seed`]) : tous les joueurs affrontent donc les mêmes objectifs le même
+//! jour. Les règles sont fixes ([`DailyChallenge::game`]) afin que les scores soient comparables, et
+//! le résultat se partage sous la forme d'une ligne stable ([`DailyResult::share_line`]).
+
+use std::error::Error;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::game::Game;
+use crate::player::{DEFAULT_SPEED, DEFAULT_STRENGTH, DEFAULT_VITALITY, Player};
+use crate::rules::GameRules;
+
+/// Nombre d'objectifs par essai du défi du jour.
+pub const DAILY_OBJECTIFS: usize = 5;
+/// Nombre d'essais du défi du jour.
+pub const DAILY_ROUNDS: u32 = 3;
+
+/// Date UTC d'un défi du jour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DailyDate {
+    /// Année.
+    pub year: i32,
+    /// Mois, de 1 à 12.
+    pub month: u32,
+    /// Jour du mois, à partir de 1.
+    pub day: u32,
+}
+
+impl DailyDate {
+    /// Retourne la date correspondant à un nombre de jours écoulés depuis le 1er janvier 1970.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::daily::DailyDate;
+    ///
+    /// assert_eq!(DailyDate::from_days(19875).to_string(), "2024-06-01");
+    /// ```
+    pub fn from_days(days: i64) -> Self {
+        // Conversion du calendrier grégorien proleptique, par ères de 400 ans commençant le 1er mars.
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        DailyDate { year, month, day }
+    }
+
+    /// Retourne la date UTC du jour, selon l'horloge du système.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        DailyDate::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Retourne la graine des objectifs du défi de cette date.
+    ///
+    /// La graine ne dépend que de la date : elle reste la même d'une machine et d'une version à
+    /// l'autre.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::daily::DailyDate;
+    ///
+    /// let date = DailyDate { year: 2024, month: 6, day: 1 };
+    /// assert_eq!(date.seed(), DailyDate::from_days(19875).seed());
+    /// assert_ne!(date.seed(), DailyDate::from_days(19876).seed());
+    /// ```
+    pub fn seed(&self) -> u64 {
+        // Mélange « splitmix64 » de la date au format AAAAMMJJ.
+        let stamp = i64::from(self.year) * 10_000 + i64::from(self.month * 100 + self.day);
+        let mut z = (stamp as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl fmt::Display for DailyDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Défi du jour pour une date donnée.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DailyChallenge {
+    /// Date du défi.
+    pub date: DailyDate,
+}
+
+impl DailyChallenge {
+    /// Crée le défi de la date donnée.
+    pub fn new(date: DailyDate) -> Self {
+        DailyChallenge { date }
+    }
+
+    /// Crée la partie du défi pour un joueur, avec les règles fixes du défi.
+    ///
+    /// Le joueur a les caractéristiques par défaut (difficulté normale), sans classe ni chance ; il
+    /// joue [`DAILY_OBJECTIFS`] objectifs par essai, tirés de la graine de la date, sans règle
+    /// optionnelle.
+    ///
+    /// # Erreurs
+    ///
+    /// Retourne une erreur si le nom du joueur est refusé
+    /// ([`PlayerConfigError`](crate::player::PlayerConfigError)).
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::daily::{DailyChallenge, DailyDate};
+    ///
+    /// let challenge = DailyChallenge::new(DailyDate { year: 2024, month: 6, day: 1 });
+    /// let mut first = challenge.game("Alice").unwrap();
+    /// let mut second = challenge.game("Bob").unwrap();
+    /// assert_eq!(first.generate_objectives(0), second.generate_objectives(0));
+    /// ```
+    pub fn game(&self, name: &str) -> Result<Game, Box<dyn Error>> {
+        let player = Player::builder(name)
+            .vitality(DEFAULT_VITALITY)
+            .speed(DEFAULT_SPEED)
+            .strength(DEFAULT_STRENGTH)
+            .build()?;
+        let mut game = Game::new_with_rules(vec![player], DAILY_OBJECTIFS, GameRules::default())?;
+        game.set_seed(self.date.seed());
+        Ok(game)
+    }
+
+    /// Joue les [`DAILY_ROUNDS`] essais du défi dans une partie créée par [`DailyChallenge::game`].
+    ///
+    /// # Retour
+    ///
+    /// Retourne le résultat du défi, ou `None` si les essais ont été interrompus.
+    pub fn play(&self, game: &mut Game) -> Result<Option<DailyResult>, Box<dyn Error>> {
+        let averages = game.practice(DAILY_ROUNDS)?;
+        if averages.len() < DAILY_ROUNDS as usize {
+            return Ok(None);
+        }
+        let perfects = game
+            .stats()
+            .players
+            .first()
+            .map_or(0, |player| player.perfect_stops);
+        Ok(Some(DailyResult {
+            date: self.date,
+            points: averages.iter().sum(),
+            perfects,
+        }))
+    }
+}
+
+/// Résultat d'un défi du jour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DailyResult {
+    /// Date du défi.
+    pub date: DailyDate,
+    /// Total des scores moyens des essais.
+    pub points: u32,
+    /// Nombre d'arrêts parfaits.
+    pub perfects: u32,
+}
+
+impl DailyResult {
+    /// Retourne la ligne à partager, identique quelle que soit la langue de l'affichage.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::daily::{DailyDate, DailyResult};
+    ///
+    /// let result = DailyResult {
+    ///     date: DailyDate { year: 2024, month: 6, day: 1 },
+    ///     points: 412,
+    ///     perfects: 2,
+    /// };
+    /// assert_eq!(result.share_line(), "DualGame 2024-06-01 — 412 pts, 2 perfects");
+    /// ```
+    pub fn share_line(&self) -> String {
+        format!(
+            "DualGame {} — {} pts, {} perfects",
+            self.date, self.points, self.perfects
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::console::NullConsole;
+    use crate::controller::{ScriptedController, StopDecision};
+    use crate::output::{Output, Verbosity};
+
+    /// Vérifie la conversion des jours en dates, y compris les années bissextiles et avant 1970.
+    #[test]
+    fn test_dates_from_days() {
+        let dates = [
+            (0, "1970-01-01"),
+            (-1, "1969-12-31"),
+            (59, "1970-03-01"),
+            (11_016, "2000-02-29"),
+            (19_875, "2024-06-01"),
+            (20_453, "2025-12-31"),
+        ];
+        for (days, expected) in dates {
+            assert_eq!(DailyDate::from_days(days).to_string(), expected, "{days}");
+        }
+    }
+
+    /// Vérifie que la graine est figée pour une date et distincte d'un jour à l'autre.
+    #[test]
+    fn test_seed_is_stable() {
+        let date = DailyDate {
+            year: 2024,
+            month: 6,
+            day: 1,
+        };
+        assert_eq!(date.seed(), 470_456_245_648_421_624);
+        let seeds: Vec<u64> = (19_800..19_900)
+            .map(|days| DailyDate::from_days(days).seed())
+            .collect();
+        let mut unique = seeds.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), seeds.len());
+    }
+
+    /// Joue le défi d'une date avec des arrêts scriptés : les deux premiers objectifs sont atteints
+    /// exactement, les suivants à 7 incrémentations près.
+    fn scripted_result(date: DailyDate) -> Option<DailyResult> {
+        let challenge = DailyChallenge::new(date);
+        let mut game = challenge.game("Alice").unwrap();
+        game.set_output(Output::new(Box::new(NullConsole), Verbosity::Silent));
+        game.set_clock(Box::new(ManualClock::new()));
+        let controller = ScriptedController::new();
+        let mut preview = challenge.game("Alice").unwrap();
+        let objectives: Vec<u32> = (0..DAILY_ROUNDS)
+            .flat_map(|_| preview.generate_objectives(0))
+            .collect();
+        for (index, objective) in objectives.into_iter().enumerate() {
+            let offset = if index < 2 { 0 } else { 7 };
+            controller.push_stop(StopDecision::from_offset(objective, offset));
+        }
+        game.set_controller(0, Box::new(controller));
+        challenge.play(&mut game).unwrap()
+    }
+
+    /// Vérifie que la ligne partagée est identique pour une même date et les mêmes arrêts.
+    #[test]
+    fn test_share_line_stable() {
+        let date = DailyDate::from_days(19_875);
+        let result = scripted_result(date).unwrap();
+        assert_eq!(scripted_result(date), Some(result));
+        assert_eq!(
+            result.share_line(),
+            "DualGame 2024-06-01 — 335 pts, 2 perfects"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Module du mode démonstration, où deux bots s'affrontent sans intervention.
+//!
+//! Le [`DemoBot`] décide de son arrêt comme un [`BotController`], puis anime le compteur en temps réel
+//! jusqu'à cette position ([`Counter::run_until`]). La [`PacedConsole`] ralentit l'affichage de la
+//! partie pour qu'il reste lisible. Une touche quelconque annule le jeton d'interruption partagé, ce qui
+//! arrête la démonstration à tout moment. Le compteur et les pauses sont cadencés par une horloge
+//! injectable (voir [`Clock`]).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::cancel::CancelToken;
+use crate::class::PlayerClass;
+use crate::clock::{Clock, SystemClock};
+use crate::console::{Console, StdConsole};
+use crate::controller::{BotController, Controller, CounterView, Difficulty, StopDecision};
+use crate::counter::{Counter, watch_keys};
+use crate::player::{Player, PlayerConfigError};
+use crate::poison::PoisonType;
+use crate::style::Color;
+
+/// Pause marquée après chaque ligne affichée pendant la démonstration.
+pub const DEMO_PACE: Duration = Duration::from_millis(600);
+
+/// Pause entre deux parties de démonstration.
+pub const DEMO_INTERLUDE: Duration = Duration::from_secs(4);
+
+/// Bot animant le compteur jusqu'à l'arrêt qu'il a choisi.
+#[derive(Clone)]
+pub struct DemoBot {
+    /// Bot prenant les décisions.
+    bot: BotController,
+    /// Horloge cadençant le compteur animé.
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl DemoBot {
+    /// Crée un bot de démonstration du niveau donné.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Le niveau de difficulté du bot.
+    /// * `rng` - Le générateur aléatoire utilisé pour toutes les décisions du bot.
+    pub fn new(difficulty: Difficulty, rng: StdRng) -> Self {
+        DemoBot {
+            bot: BotController::with_rng(difficulty, rng),
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// Cadence le compteur animé par l'horloge donnée plutôt que par celle du système.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl Controller for DemoBot {
+    /// Anime le compteur et l'arrête à la position choisie par le bot, le délai d'arrêt étant celui
+    /// d'un joueur appuyant à ce moment-là.
+    fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+        let target = self.bot.stop_counter(ctx);
+        let steps = target.miss * 101 + target.counter_value;
+        let (counter_value, miss) = Counter::new(ctx.speed)
+            .with_cancel(ctx.cancel.clone())
+            .with_assist(ctx.assist)
+            .with_clock(Arc::clone(&self.clock))
+            .run_until(ctx.objective, steps);
+        StopDecision {
+            counter_value,
+            miss,
+            stamina_spent: 0,
+        }
+    }
+
+    fn choose_poison(&mut self, options: &[PoisonType], opponent: &Player) -> usize {
+        self.bot.choose_poison(options, opponent)
+    }
+
+    fn choose_counter(&mut self, poison: &PoisonType, extra_damage: u32, me: &Player) -> bool {
+        self.bot.choose_counter(poison, extra_damage, me)
+    }
+
+    fn acknowledge(&mut self, _msg: &str) {}
+
+    fn displays_counter(&self) -> bool {
+        true
+    }
+}
+
+/// Console affichant la partie sur la sortie standard à un rythme ralenti.
+#[derive(Clone)]
+pub struct PacedConsole {
+    /// Pause marquée après chaque ligne.
+    pace: Duration,
+    /// Jeton annulé par une touche, qui supprime les pauses restantes.
+    cancel: CancelToken,
+    /// Horloge mesurant les pauses.
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl PacedConsole {
+    /// Crée une console marquant la pause `pace` après chaque ligne.
+    pub fn new(pace: Duration, cancel: CancelToken) -> Self {
+        PacedConsole {
+            pace,
+            cancel,
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// Mesure les pauses par l'horloge donnée plutôt que par celle du système.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl Console for PacedConsole {
+    fn print(&mut self, text: &str) {
+        StdConsole.print(text);
+    }
+
+    /// La démonstration ne lit aucune saisie.
+    fn read_line(&mut self) -> Option<String> {
+        None
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+
+    fn println(&mut self, line: &str) {
+        StdConsole.println(line);
+        pause(&*self.clock, &self.cancel, self.pace);
+    }
+}
+
+/// Attend que la durée donnée soit écoulée à l'horloge, ou qu'une touche annule le jeton.
+///
+/// Retourne `true` si l'attente s'est terminée sans interruption.
+pub fn pause(clock: &dyn Clock, cancel: &CancelToken, duration: Duration) -> bool {
+    let started = clock.now();
+    watch_keys(cancel, || clock.now().saturating_sub(started) >= duration);
+    !cancel.is_cancelled()
+}
+
+/// Tire au sort les deux bots d'une partie de démonstration : classe et difficulté de chacun.
+///
+/// # Exemples
+///
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use dual_game::demo::lineup;
+///
+/// let players = lineup(&mut StdRng::seed_from_u64(3)).unwrap();
+/// assert!(players[0].0.name.starts_with("Bot 1"));
+/// ```
+pub fn lineup(rng: &mut StdRng) -> Result<Vec<(Player, Difficulty)>, PlayerConfigError> {
+    [Color::Cyan, Color::Magenta]
+        .into_iter()
+        .enumerate()
+        .map(|(index, color)| {
+            let difficulty = Difficulty::ALL[rng.random_range(0..Difficulty::ALL.len())];
+            let class = PlayerClass::ALL[rng.random_range(0..PlayerClass::ALL.len())];
+            let player = Player::builder(format!("Bot {} ({difficulty})", index + 1))
+                .class(class)
+                .color(color)
+                .build()?;
+            Ok((player, difficulty))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::rules::GameRules;
+    use rand::SeedableRng;
+    use std::thread;
+    use std::time::Instant;
+
+    /// Vérifie que l'arrêt animé correspond à la décision du bot, tours complets compris.
+    #[test]
+    fn test_demo_bot_stops_where_bot_decides() {
+        let view = CounterView {
+            objective: 95,
+            index: 0,
+            speed: 1,
+            hidden: false,
+            strength: 50,
+            stamina: None,
+            assist: None,
+            cancel: CancelToken::new(),
+            rules: GameRules::default(),
+        };
+        let mut demo = DemoBot::new(Difficulty::Easy, StdRng::seed_from_u64(11));
+        let mut bot = BotController::with_rng(Difficulty::Easy, StdRng::seed_from_u64(11));
+        for _ in 0..5 {
+            assert_eq!(demo.stop_counter(&view), bot.stop_counter(&view));
+        }
+    }
+
+    /// Vérifie qu'une démonstration interrompue arrête aussitôt le compteur et les pauses.
+    #[test]
+    fn test_cancelled_demo_stops() {
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        assert!(!pause(
+            &SystemClock::default(),
+            &cancel,
+            Duration::from_secs(60)
+        ));
+        let view = CounterView {
+            objective: 50,
+            index: 0,
+            speed: 500,
+            hidden: false,
+            strength: 50,
+            stamina: None,
+            assist: None,
+            cancel,
+            rules: GameRules::default(),
+        };
+        let stop = DemoBot::new(Difficulty::Hard, StdRng::seed_from_u64(1)).stop_counter(&view);
+        assert_eq!((stop.counter_value, stop.miss), (0, 0));
+    }
+
+    /// Vérifie que les pauses de la démonstration suivent l'horloge injectée : une pause d'une minute
+    /// se termine dès que l'horloge l'a franchie.
+    #[test]
+    fn test_pause_follows_clock() {
+        let clock = ManualClock::new();
+        let host = clock.clone();
+        let started = Instant::now();
+        let advancer = thread::spawn(move || host.advance(Duration::from_secs(60)));
+        assert!(pause(&clock, &CancelToken::new(), Duration::from_secs(60)));
+        advancer.join().unwrap();
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
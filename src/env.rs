@@ -0,0 +1,113 @@
+//! Module d'accès aux variables d'environnement.
+//!
+//! Chaque option de la ligne de commande peut être donnée par une variable d'environnement
+//! `DUAL_GAME_<OPTION>` (par exemple `DUAL_GAME_NAME1` pour `--name1`, `DUAL_GAME_LOG_FILE` pour
+//! `--log-file`). Le trait [`Env`] abstrait la lecture des variables, afin que les tests puissent fournir
+//! leurs propres valeurs sans modifier l'environnement du processus.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::messages::Msg;
+use crate::tr;
+
+/// Préfixe des variables d'environnement reprenant les options de la ligne de commande.
+pub const ENV_PREFIX: &str = "DUAL_GAME_";
+
+/// Source de variables d'environnement.
+pub trait Env {
+    /// Retourne la valeur d'une variable, ou `None` si elle n'est pas définie ou n'est pas de l'Unicode
+    /// valide.
+    fn var(&self, name: &str) -> Option<String>;
+}
+
+/// Environnement du processus.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProcessEnv;
+
+impl Env for ProcessEnv {
+    fn var(&self, name: &str) -> Option<String> {
+        std::env::var(name).ok()
+    }
+}
+
+/// Environnement fictif, par exemple pour les tests.
+impl Env for HashMap<String, String> {
+    fn var(&self, name: &str) -> Option<String> {
+        self.get(name).cloned()
+    }
+}
+
+/// Erreur de lecture d'une variable d'environnement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvError {
+    /// La variable d'un interrupteur (option sans valeur) ne contient ni oui ni non.
+    InvalidFlag {
+        /// Nom de la variable.
+        name: String,
+        /// Valeur rejetée.
+        value: String,
+    },
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvError::InvalidFlag { name, value } => {
+                write!(
+                    f,
+                    "{}",
+                    tr!(Msg::EnvInvalidFlag, name = name, value = value)
+                )
+            }
+        }
+    }
+}
+
+impl Error for EnvError {}
+
+/// Retourne le nom de la variable d'environnement associée à une option longue.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::env::var_name;
+///
+/// assert_eq!(var_name("log-file"), "DUAL_GAME_LOG_FILE");
+/// ```
+pub fn var_name(long: &str) -> String {
+    format!("{ENV_PREFIX}{}", long.to_uppercase().replace('-', "_"))
+}
+
+/// Interprète la valeur de la variable d'un interrupteur.
+///
+/// Les valeurs `1`, `true`, `yes`, `on`, `o` et `oui` l'activent ; `0`, `false`, `no`, `off`, `n`,
+/// `non` et la valeur vide le laissent désactivé (insensible à la casse).
+pub fn parse_flag(name: &str, value: &str) -> Result<bool, EnvError> {
+    match value.trim().to_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" | "o" | "oui" => Ok(true),
+        "" | "0" | "false" | "no" | "off" | "n" | "non" => Ok(false),
+        _ => Err(EnvError::InvalidFlag {
+            name: name.to_string(),
+            value: value.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie l'interprétation des interrupteurs et le message d'une valeur invalide.
+    #[test]
+    fn test_parse_flag() {
+        assert_eq!(parse_flag("DUAL_GAME_FATIGUE", "Oui"), Ok(true));
+        assert_eq!(parse_flag("DUAL_GAME_FATIGUE", " 1 "), Ok(true));
+        assert_eq!(parse_flag("DUAL_GAME_FATIGUE", "off"), Ok(false));
+        assert_eq!(parse_flag("DUAL_GAME_FATIGUE", ""), Ok(false));
+        let err = parse_flag("DUAL_GAME_FATIGUE", "peut-être").unwrap_err();
+        assert!(err.to_string().contains("DUAL_GAME_FATIGUE"), "{err}");
+        assert!(err.to_string().contains("peut-être"), "{err}");
+    }
+}
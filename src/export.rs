@@ -0,0 +1,168 @@
+//! Module d'export du résultat d'une partie.
+//!
+//! Le [`GameExport`] résume l'issue d'une partie (joueurs, issue, vainqueur, nombre de manches) dans un
+//! schéma JSON stable, partagé par tous les destinataires externes du résultat, comme la notification
+//! HTTP (`--notify-url`) et l'export en fin de partie (`--export`), qui y joint les statistiques de la
+//! partie ([`GameStats`]).
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::GameResult;
+use crate::stats::GameStats;
+
+/// Issue d'une partie, telle qu'exportée.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    /// Un seul joueur a conservé de la vitalité.
+    Victory,
+    /// Aucun joueur n'a conservé de vitalité.
+    Draw,
+    /// Un joueur a abandonné la partie.
+    Forfeit,
+    /// La partie a été interrompue avant sa fin.
+    Interrupted,
+}
+
+impl From<GameResult> for Outcome {
+    fn from(result: GameResult) -> Self {
+        match result {
+            GameResult::Victory { .. } => Outcome::Victory,
+            GameResult::Draw => Outcome::Draw,
+            GameResult::Forfeit { .. } => Outcome::Forfeit,
+            GameResult::Interrupted => Outcome::Interrupted,
+        }
+    }
+}
+
+/// Résultat exporté d'une partie.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GameExport {
+    /// Noms des joueurs.
+    pub players: Vec<String>,
+    /// Issue de la partie.
+    pub outcome: Outcome,
+    /// Nom du vainqueur, le cas échéant.
+    pub winner: Option<String>,
+    /// Nombre de manches terminées.
+    pub rounds: u32,
+    /// Statistiques de la partie, si elles sont jointes au résultat.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<GameStats>,
+    /// Indique si la partie s'est jouée en mode miroir, les joueurs ayant échangé leur vitesse et
+    /// leur force ; absent du résultat sinon.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub mirror: bool,
+}
+
+impl GameExport {
+    /// Construit le résultat exporté d'une partie.
+    ///
+    /// # Arguments
+    ///
+    /// * `players` - Les noms des joueurs, dans l'ordre de la partie.
+    /// * `result` - L'issue de la partie.
+    /// * `rounds` - Le nombre de manches terminées.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::export::GameExport;
+    /// use dual_game::game::GameResult;
+    ///
+    /// let players = vec![String::from("Alice"), String::from("Bob")];
+    /// let export = GameExport::new(players, GameResult::Draw, 4);
+    /// assert_eq!(
+    ///     export.to_json(),
+    ///     r#"{"players":["Alice","Bob"],"outcome":"draw","winner":null,"rounds":4}"#
+    /// );
+    /// ```
+    pub fn new(players: Vec<String>, result: GameResult, rounds: u32) -> Self {
+        let winner = result
+            .winner()
+            .and_then(|id| players.get(id.index()).cloned());
+        GameExport {
+            players,
+            outcome: result.into(),
+            winner,
+            rounds,
+            stats: None,
+            mirror: false,
+        }
+    }
+
+    /// Joint les statistiques de la partie au résultat.
+    pub fn with_stats(mut self, stats: GameStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    /// Signale que la partie s'est jouée en mode miroir.
+    pub fn with_mirror(mut self, mirror: bool) -> Self {
+        self.mirror = mirror;
+        self
+    }
+
+    /// Sérialise le résultat au format JSON compact.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("un résultat exporté est toujours sérialisable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::PlayerId;
+
+    /// Vérifie le vainqueur exporté et l'aller-retour JSON du résultat.
+    #[test]
+    fn test_export_round_trip() {
+        let players = vec![String::from("Alice"), String::from("Bob")];
+        let result = GameResult::Forfeit {
+            forfeited: PlayerId(0),
+            winner: PlayerId(1),
+        };
+        let export = GameExport::new(players, result, 3);
+        assert_eq!(export.outcome, Outcome::Forfeit);
+        assert_eq!(export.winner.as_deref(), Some("Bob"));
+        let json = export.to_json();
+        assert!(json.contains(r#""outcome":"forfeit""#), "{json}");
+        assert_eq!(serde_json::from_str::<GameExport>(&json).unwrap(), export);
+    }
+
+    /// Vérifie que les statistiques exportées sont celles calculées pour la partie.
+    #[test]
+    fn test_export_stats() {
+        use crate::controller::{BotController, Difficulty};
+        use crate::game::Game;
+        use crate::output::Verbosity;
+        use crate::player::Player;
+
+        let players = vec![
+            Player::new(String::from("Alice"), 40, 75, 50),
+            Player::new(String::from("Bob"), 40, 75, 50),
+        ];
+        let mut game = Game::new(players, 3);
+        game.set_verbosity(Verbosity::Silent);
+        game.set_seed(2);
+        for index in 0..2 {
+            game.set_controller(index, Box::new(BotController::new(Difficulty::Easy)));
+        }
+        let result = game.run().unwrap();
+        let names = game.players.iter().map(|p| p.name.clone()).collect();
+        let export = GameExport::new(names, result, game.round - 1).with_stats(game.stats());
+        let json = export.to_json();
+        assert!(
+            json.contains(r#""stats":{"players":[{"name":"Alice","#),
+            "{json}"
+        );
+        let loaded: GameExport = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.stats, Some(game.stats()));
+        let dealt: u32 = game.damage.iter().map(|damage| damage.dealt).sum();
+        let stats = loaded.stats.unwrap();
+        assert_eq!(
+            stats.players[0].damage_dealt + stats.players[1].damage_dealt,
+            dealt
+        );
+    }
+}
@@ -1,261 +1,4062 @@
-//! Module définissant la logique de la partie de jeu.
-//!
-//! Ce module contient la structure [`Game`] qui gère les tours de jeu, le calcul des scores et l'application
-//! des effets de poison entre les joueurs.
-
-use std::error::Error;
-use std::io::{Write, stdin, stdout};
-
-use crate::counter::Counter;
-use crate::objectives::Objectives;
-use crate::player::Player;
-use crate::poison::{PoisonType, apply_poison};
-use crate::scoring::ScoringCalculator;
-
-/// Structure représentant une partie de jeu.
-#[derive(Clone, Debug)]
-pub struct Game {
-    /// Liste des joueurs participant à la partie.
-    pub players: Vec<Player>,
-    /// Nombre d’objectifs par tour.
-    pub objectifs_count: usize,
-    /// Numéro du tour courant.
-    pub round: u32,
-}
-
-impl Game {
-    /// Crée une nouvelle partie avec la liste de joueurs et le nombre d’objectifs par tour.
-    ///
-    /// # Arguments
-    ///
-    /// * `players` - Un vecteur contenant les joueurs.
-    /// * `objectifs_count` - Le nombre d’objectifs à générer pour chaque tour.
-    ///
-    /// # Exemples
-    ///
-    /// ```
-    /// use dual_game::game::Game;
-    /// use dual_game::player::Player;
-    ///
-    /// let players = vec![
-    ///     Player::new(String::from("Alice"), 50, 50, 50),
-    ///     Player::new(String::from("Bob"), 50, 50, 50),
-    /// ];
-    /// let game = Game::new(players, 5);
-    /// ```
-    pub fn new(players: Vec<Player>, objectifs_count: usize) -> Self {
-        Game {
-            players,
-            objectifs_count,
-            round: 1,
-        }
-    }
-
-    /// Exécute la boucle de la partie tant que tous les joueurs ont encore de la vitalité.
-    ///
-    /// Chaque tour se compose des actions suivantes :
-    /// - Affichage du numéro de tour.
-    /// - Chaque joueur joue son tour, ce qui inclut la génération d'objectifs et l'exécution d'un tour de jeu.
-    /// - Les scores sont comparés pour déterminer le gagnant du tour.
-    /// - Le joueur perdant subit une pénalité de vitalité.
-    /// - Le gagnant choisit un effet de poison à appliquer au perdant.
-    ///
-    /// # Retour
-    ///
-    /// Retourne `Ok(())` si la partie s'est terminée normalement ou une erreur dans le cas contraire.
-    pub fn run(&mut self) -> Result<(), Box<dyn Error>> {
-        println!("##### Démarrage de la partie #####");
-
-        // Boucle tant qu'aucun joueur n'a perdu toute sa vitalité.
-        while self.players.iter().all(|p| p.vitality > 0) {
-            println!("\n## Manche {} ##", self.round);
-
-            // Chaque joueur joue son tour.
-            let mut scores = Vec::new();
-            for i in 0..self.players.len() {
-                if i > 0 {
-                    println!();
-                }
-                println!(
-                    "Au tour de {} (Vitality={}, Speed={}, Strength={})",
-                    self.players[i].name,
-                    self.players[i].vitality,
-                    self.players[i].speed,
-                    self.players[i].strength
-                );
-
-                // Génération des objectifs.
-                let objectives = Objectives::generate(self.objectifs_count);
-                println!("→ Objectifs : {:?}", objectives);
-                println!("→ Appuyer sur ENTREE pour démarrer le tour..");
-                self.wait_enter()?;
-
-                // Exécution du tour et récupération du score moyen.
-                let (score, _) = self.play_turn(&objectives, &self.players[i])?;
-                println!("\n# Fin du tour #");
-                println!("→ Score moyen: {}", score);
-                scores.push(score);
-            }
-
-            // Comparaison des scores entre les joueurs.
-            if scores.len() < 2 {
-                return Err("Nombre de joueurs insuffisant pour déterminer un vainqueur.".into());
-            }
-
-            // Traitement en cas d'égalité de scores.
-            if scores[0] == scores[1] {
-                println!("\nÉgalité de scores, aucune pénalité.");
-                self.round += 1;
-                continue;
-            }
-
-            // Détermination du gagnant et du perdant.
-            let (winner_index, loser_index) = if scores[0] > scores[1] {
-                (0, 1)
-            } else {
-                (1, 0)
-            };
-
-            let diff = scores[winner_index].saturating_sub(scores[loser_index]);
-            println!(
-                "\n{} gagne la manche. {} perd {} points de vitalité.",
-                self.players[winner_index].name, self.players[loser_index].name, diff
-            );
-            self.players[loser_index].vitality =
-                self.players[loser_index].vitality.saturating_sub(diff);
-
-            // Ne pas demander le poison si le perdant n'a plus de vitalité.
-            if self.players[loser_index].vitality > 0 {
-                println!(
-                    "{} vous devez choisir quel poison appliquer à {} :",
-                    self.players[winner_index].name, self.players[loser_index].name
-                );
-                println!("→ 1: -5 speed");
-                println!("→ 2: -5 strength");
-                let poison_choice = self.get_choice()?;
-                let poison_type = match poison_choice {
-                    1 => PoisonType::Speed,
-                    2 => PoisonType::Strength,
-                    _ => {
-                        println!("Choix invalide, aucun poison appliqué.");
-                        self.round += 1;
-                        continue;
-                    }
-                };
-
-                apply_poison(&mut self.players[loser_index], poison_type)?;
-            }
-
-            println!("## FIN Manche {} ##", self.round);
-            self.round += 1;
-        }
-
-        // Affichage du vainqueur et des statistiques.
-        println!("\n##### Partie terminée #####");
-        if let Some(winner) = self.players.iter().max_by_key(|p| p.vitality) {
-            println!("Le vainqueur est {} !", winner.name);
-        }
-        println!("\nStatistiques des joueurs :");
-        for player in &self.players {
-            println!(
-                "{} - Vitality: {}, Speed: {}, Strength: {}",
-                player.name, player.vitality, player.speed, player.strength
-            );
-        }
-
-        Ok(())
-    }
-
-    /// Attend que l'utilisateur appuie sur ENTREE.
-    ///
-    /// Cette méthode lit une ligne depuis l'entrée standard et permet de faire une pause dans le déroulement du tour.
-    ///
-    /// # Retour
-    ///
-    /// Retourne `Ok(())` si la lecture s'est déroulée sans problème.
-    fn wait_enter(&self) -> Result<(), Box<dyn Error>> {
-        let mut input = String::new();
-        stdin().read_line(&mut input)?;
-        Ok(())
-    }
-
-    /// Lit et valide le choix numérique de l'utilisateur.
-    ///
-    /// Cette méthode demande à l'utilisateur de saisir 1 ou 2 et continue de redemander en cas d'entrée invalide.
-    ///
-    /// # Retour
-    ///
-    /// Retourne le choix de l'utilisateur sous forme de `u32`.
-    fn get_choice(&self) -> Result<u32, Box<dyn Error>> {
-        loop {
-            print!("> ");
-            stdout().flush()?;
-            let mut input = String::new();
-            stdin().read_line(&mut input)?;
-            let trimmed = input.trim();
-            if let Ok(choice) = trimmed.parse::<u32>() {
-                if choice == 1 || choice == 2 {
-                    return Ok(choice);
-                }
-            }
-            println!("Entrée invalide, veuillez entrer 1 ou 2.");
-        }
-    }
-
-    /// Exécute le tour d’un joueur en traitant chacun des objectifs.
-    ///
-    /// Pour chaque objectif, un compteur est lancé et la méthode [`Counter::run`] est appelée pour simuler
-    /// le comportement du compteur. Le score est ensuite calculé en fonction de la valeur du compteur,
-    /// du nombre de "miss" et de la force du joueur.
-    ///
-    /// # Arguments
-    ///
-    /// * `objectives` - Une référence vers un vecteur d'objectifs numériques.
-    /// * `player` - Le joueur dont le tour est en cours.
-    ///
-    /// # Retour
-    ///
-    /// Retourne un tuple `(score_moyen, scores_détaillés)` :
-    /// - `score_moyen` est le score moyen obtenu lors du tour.
-    /// - `scores_détaillés` est un vecteur contenant les scores de chaque objectif.
-    pub fn play_turn(
-        &self,
-        objectives: &[u32],
-        player: &Player,
-    ) -> Result<(u32, Vec<u32>), Box<dyn Error>> {
-        let mut scores = Vec::new();
-
-        // Pour chaque objectif, on simule l'arrêt d'un compteur.
-        for obj in objectives.iter() {
-            // Instanciation d'un compteur utilisant la vitesse du joueur.
-            let counter = Counter::new(player.speed);
-            // Simulation du comportement du compteur.
-            let (counter_value, miss) = counter.run(*obj);
-
-            let score =
-                ScoringCalculator::calculate_score(*obj, counter_value, miss, player.strength);
-            // println!("⟹ Counter value = {}, Miss = {} => Score = {}", counter_value, miss, score);
-            scores.push(score);
-        }
-        let average = ScoringCalculator::calculate_average(&scores);
-        Ok((average, scores))
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::player::Player;
-
-    /// Vérifie que la création d'une nouvelle partie avec deux joueurs et un nombre d'objectifs donné fonctionne.
-    #[test]
-    fn test_game_new() {
-        let players = vec![
-            Player::new(String::from("Michel"), 50, 50, 50),
-            Player::new(String::from("Jacque"), 50, 50, 50),
-        ];
-        let game = Game::new(players, 5);
-        assert_eq!(game.players.len(), 2);
-        assert_eq!(game.objectifs_count, 5);
-    }
-}
+//! Module définissant la logique de la partie de jeu.
+//!
+//! Ce module contient la structure [`Game`] qui gère les tours de jeu, le calcul des scores et l'application
+//! des effets de poison entre les joueurs. Les décisions de chaque joueur sont déléguées à son
+//! [`Controller`].
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::ops::ControlFlow;
+use std::time::Duration;
+
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+use crate::cancel::CancelToken;
+use crate::clock::{Clock, default_clock};
+use crate::commentary;
+use crate::controller::{Controller, CounterView, StopDecision, default_controller};
+use crate::counter::MIN_SPEED;
+use crate::export::Outcome;
+use crate::ghost::Ghost;
+use crate::history::{HistoryEntry, HistorySink};
+use crate::items::{Item, ShopOutcome, buy, coins_earned};
+use crate::keys;
+use crate::messages::Msg;
+use crate::mutators::{self, RoundModifier};
+use crate::objectives::Objectives;
+use crate::observer::{GameEvent, GameObserver};
+use crate::output::{Output, Verbosity};
+use crate::pacing::{AUTO_CONTINUE_SECONDS, Pacing};
+use crate::player::{DEFAULT_STAMINA, DamageOutcome, Player, PlayerId, PlayerSnapshot};
+use crate::poison::{PoisonOutcome, PoisonType, allowed_poisons, resolve_poison};
+use crate::referee::Referee;
+use crate::renderer::{
+    DuelPointView, EndView, ObjectiveView, PlainRenderer, Renderer, RoundOutcome, ScoreboardView,
+    SoundCue, TurnView,
+};
+use crate::replay::{
+    DamageRecord, DrawRecord, PlayerNames, ReplayHeader, RoundRecord, StopRecord, TurnRecord,
+};
+use crate::risk::Risk;
+use crate::rules::{
+    GameMode, GameRules, STALEMATE_DAMAGE_PER_ROUND, STALEMATE_MIN_DAMAGE, STALEMATE_RELIEF_DAMAGE,
+    SetupError, TIME_TIEBREAK_DAMAGE, WINNERS_CURSE_MARGIN, WINNERS_CURSE_SPEED, mirror,
+};
+use crate::saves::{self, SAVE_VERSION, SaveState, SaveStore, SavedPlayer};
+use crate::scoring::{ScoringCalculator, ScoringConfig};
+use crate::shared::{SharedCounter, SharedLocks, SharedView};
+use crate::stats::{GameHistory, GameStats, StatsAccumulator};
+use crate::style;
+use crate::tr;
+use crate::training::{GameState, PlayerState};
+
+/// Affiche une ligne via la sortie de la partie, si son niveau de détail est retenu.
+///
+/// Le texte n'est formaté que si la ligne est effectivement affichée.
+macro_rules! say {
+    ($game:expr, $level:expr) => {
+        $game.output.line($level, "")
+    };
+    ($game:expr, $level:expr, $($arg:tt)*) => {
+        if $game.output.shows($level) {
+            $game.output.line($level, &format!($($arg)*));
+        }
+    };
+}
+
+/// Intervalle minimal entre deux signaux de dépassement du compteur, pour que les dépassements
+/// rapprochés (ceux d'un bot, par exemple) ne fassent pas sonner le terminal en continu.
+const MISS_CUE_COOLDOWN: Duration = Duration::from_millis(500);
+
+/// Nombre de manches nulles consécutives après lequel les manches deviennent décisives : chacune se
+/// joue sur un seul objectif, jusqu'à ce qu'un joueur la remporte.
+pub const TIEBREAKER_AFTER_DRAWS: u32 = 3;
+
+/// Issue d'une partie terminée.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameResult {
+    /// Un seul joueur a conservé de la vitalité.
+    Victory {
+        /// Le vainqueur.
+        winner: PlayerId,
+    },
+    /// Aucun joueur n'a conservé de vitalité.
+    Draw,
+    /// Un joueur a abandonné la partie.
+    Forfeit {
+        /// Le joueur ayant abandonné.
+        forfeited: PlayerId,
+        /// Son adversaire, déclaré vainqueur.
+        winner: PlayerId,
+    },
+    /// La partie a été interrompue (Ctrl+C) avant sa fin.
+    Interrupted,
+}
+
+impl GameResult {
+    /// Retourne le vainqueur de la partie, le cas échéant.
+    pub fn winner(&self) -> Option<PlayerId> {
+        match self {
+            GameResult::Victory { winner } | GameResult::Forfeit { winner, .. } => Some(*winner),
+            GameResult::Draw | GameResult::Interrupted => None,
+        }
+    }
+
+    /// Retourne le nom du vainqueur de la partie, le cas échéant.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::game::GameResult;
+    /// use dual_game::player::PlayerId;
+    /// use dual_game::replay::PlayerNames;
+    ///
+    /// let names = PlayerNames::from(vec![String::from("Alice"), String::from("Bob")]);
+    /// let result = GameResult::Victory { winner: PlayerId(1) };
+    /// assert_eq!(result.winner_name(&names), Some("Bob"));
+    /// assert_eq!(GameResult::Draw.winner_name(&names), None);
+    /// ```
+    pub fn winner_name<'a>(&self, names: &'a PlayerNames) -> Option<&'a str> {
+        self.winner().and_then(|id| names.name(id))
+    }
+}
+
+/// Signaux sonores d'une partie (`--sound`).
+#[derive(Clone, Copy, Debug, Default)]
+struct Sound {
+    /// Indique si les signaux sont émis.
+    enabled: bool,
+    /// Instant du dernier signal de dépassement émis.
+    last_miss: Option<Duration>,
+}
+
+impl Sound {
+    /// Indique si le signal doit être émis à l'instant donné et retient l'instant des dépassements.
+    ///
+    /// Un dépassement survenant moins de [`MISS_CUE_COOLDOWN`] après le précédent est ignoré.
+    fn allows(&mut self, cue: SoundCue, now: Duration) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        if cue != SoundCue::Miss {
+            return true;
+        }
+        if self
+            .last_miss
+            .is_some_and(|last| now.saturating_sub(last) < MISS_CUE_COOLDOWN)
+        {
+            return false;
+        }
+        self.last_miss = Some(now);
+        true
+    }
+}
+
+/// Issue d'une manche, une fois les scores des joueurs comparés.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RoundResolution {
+    /// Les scores sont égaux : la manche est nulle.
+    Drawn,
+    /// Un joueur a remporté la manche et infligé des dégâts à son adversaire.
+    Won {
+        /// Vainqueur de la manche.
+        winner: PlayerId,
+        /// Perdant de la manche.
+        loser: PlayerId,
+        /// Dégâts de vitalité infligés au perdant.
+        dealt: u32,
+    },
+}
+
+/// Suite donnée à la réponse du vainqueur d'une manche au menu des poisons.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum PoisonChoice {
+    /// Le poison choisi est appliqué au perdant.
+    Apply(PoisonType),
+    /// La réponse est hors du menu : le menu est affiché de nouveau.
+    Retry,
+}
+
+/// Structure représentant une partie de jeu.
+pub struct Game {
+    /// Liste des joueurs participant à la partie.
+    pub players: Vec<Player>,
+    /// Nombre d’objectifs par tour.
+    pub objectifs_count: usize,
+    /// Numéro du tour courant.
+    pub round: u32,
+    /// Somme des scores moyens de chaque joueur sur l'ensemble des manches jouées.
+    pub total_scores: Vec<u32>,
+    /// Règles optionnelles de la partie.
+    pub rules: GameRules,
+    /// Noms des joueurs, en-tête de l'historique retenu au début de la partie.
+    pub names: PlayerNames,
+    /// Tours joués depuis le début de la partie, dans l'ordre.
+    pub history: Vec<TurnRecord>,
+    /// État des joueurs à la fin de chaque manche, dans l'ordre.
+    pub rounds: Vec<RoundRecord>,
+    /// Manches nulles, dans l'ordre.
+    pub draws: Vec<DrawRecord>,
+    /// Nombre de manches nulles depuis la dernière manche remportée.
+    consecutive_draws: u32,
+    /// Indique si l'anti-stagnation impose des dégâts minimaux aux manches perdues (voir
+    /// [`GameRules::stalemate_rounds`]).
+    stalemate: bool,
+    /// Durée cumulée des arrêts du compteur de chaque joueur pendant la manche en cours, pour le
+    /// départage au temps ([`GameRules::time_tiebreak`]).
+    round_times: Vec<Duration>,
+    /// « Miss » cumulés par chaque joueur pendant la manche en cours.
+    round_misses: Vec<u32>,
+    /// Nombre de manches retenues dans `history`, `damage`, `draws` et `rounds`, sans limite par
+    /// défaut.
+    history_limit: Option<usize>,
+    /// Destination des enregistrements de l'historique, transmis à la fin de chaque manche.
+    history_sink: Option<Box<dyn HistorySink>>,
+    /// Compteurs des statistiques, alimentés par les enregistrements transmis.
+    totals: StatsAccumulator,
+    /// Nombre de tours, de dégâts, de manches nulles et de manches en tête de l'historique déjà
+    /// transmis.
+    committed: (usize, usize, usize, usize),
+    /// Dégâts infligés à la fin de chaque manche gagnée, dans l'ordre.
+    pub damage: Vec<DamageRecord>,
+    /// Générateur des objectifs.
+    rng: StdRng,
+    /// Graine du générateur des objectifs, si elle a été fixée.
+    seed: Option<u64>,
+    /// Instantané de chaque joueur au début de la partie, retenu dans l'en-tête de l'historique.
+    start: Vec<PlayerSnapshot>,
+    /// Jeton d'interruption vérifié entre les tours et transmis au compteur.
+    cancel: CancelToken,
+    /// Destination de l'affichage du déroulement de la partie.
+    output: Output,
+    /// Rendu des moments affichés de la partie.
+    renderer: Box<dyn Renderer>,
+    /// Observateurs notifiés de chaque événement de la partie.
+    observers: Vec<Box<dyn GameObserver>>,
+    /// Contrôleur prenant les décisions de chaque joueur, dans le même ordre que `players`.
+    controllers: Vec<Box<dyn Controller>>,
+    /// Horloge mesurant la durée de chaque arrêt du compteur.
+    clock: Box<dyn Clock>,
+    /// Signaux sonores transmis au rendu.
+    sound: Sound,
+    /// Cadence des manches : début des tours, pause entre les manches et après chaque objectif.
+    pacing: Pacing,
+    /// Compteur commun aux joueurs en mode partagé, `None` pour que chaque contrôleur arrête le
+    /// compteur à son tour.
+    shared_counter: Option<Box<dyn SharedCounter>>,
+    /// Entraînement précédent affiché en regard de l'entraînement en cours (voir
+    /// [`Game::set_ghost`]).
+    ghost: Option<Ghost>,
+    /// Mutateur de la manche en cours, tiré au début de chaque manche lorsque les mutateurs sont
+    /// activés ([`GameRules::mutators`]).
+    modifier: Option<&'static dyn RoundModifier>,
+    /// Poison interdit au vainqueur de la manche en cours, choisi au début de la manche lorsque les
+    /// interdictions sont activées ([`GameRules::bans`]).
+    ban: Option<PoisonType>,
+    /// Poison différé choisi à la manche précédente et sa victime, appliqué au début de la manche
+    /// suivante ([`GameRules::delayed_poison`]).
+    pending_poison: Option<(PlayerId, PoisonType)>,
+    /// Joueur frappé par la malédiction du vainqueur et manche pendant laquelle il est ralenti
+    /// ([`GameRules::winners_curse`]).
+    winners_curse: Option<(PlayerId, u32)>,
+    /// Emplacement sauvegardé à la fin de chaque manche, le cas échéant (voir
+    /// [`Game::set_autosave`]).
+    autosave: Option<(SaveStore, String)>,
+    /// Arbitre pronostiquant le vainqueur de chaque manche, avec son contrôleur (voir
+    /// [`Game::set_referee`]).
+    referee: Option<(Referee, Box<dyn Controller>)>,
+}
+
+impl fmt::Debug for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Game")
+            .field("players", &self.players)
+            .field("objectifs_count", &self.objectifs_count)
+            .field("round", &self.round)
+            .field("total_scores", &self.total_scores)
+            .field("rules", &self.rules)
+            .field("output", &self.output)
+            .field("seed", &self.seed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Game {
+    /// Crée une nouvelle partie avec la liste de joueurs et le nombre d’objectifs par tour.
+    ///
+    /// Chaque joueur est initialement contrôlé au clavier (`HumanController`), ou par un bot de
+    /// difficulté normale sans la fonctionnalité `cli` ; voir [`Game::set_controller`] pour changer le
+    /// contrôleur d'un joueur.
+    ///
+    /// # Arguments
+    ///
+    /// * `players` - Un vecteur contenant les joueurs.
+    /// * `objectifs_count` - Le nombre d’objectifs à générer pour chaque tour.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::game::Game;
+    /// use dual_game::player::Player;
+    ///
+    /// let players = vec![
+    ///     Player::new(String::from("Alice"), 50, 50, 50),
+    ///     Player::new(String::from("Bob"), 50, 50, 50),
+    /// ];
+    /// let game = Game::new(players, 5);
+    /// ```
+    ///
+    /// Un nombre d'objectifs supérieur à
+    /// [`DEFAULT_MAX_OBJECTIVES`](crate::rules::DEFAULT_MAX_OBJECTIVES) est ramené à cette limite,
+    /// avec un avertissement dans le journal ; [`Game::new_with_rules`] le refuse plutôt, et accepte
+    /// une limite relevée par [`GameRules::max_objectives`].
+    pub fn new(players: Vec<Player>, objectifs_count: usize) -> Self {
+        let rules = GameRules::default();
+        if let Err(error) = rules.check_objectives(objectifs_count) {
+            log::warn!(
+                "{error}, nombre d'objectifs ramené à {}",
+                rules.max_objectives
+            );
+        }
+        Game::unchecked(players, objectifs_count.min(rules.max_objectives))
+    }
+
+    /// Crée une nouvelle partie sans vérifier le nombre d'objectifs par tour.
+    fn unchecked(players: Vec<Player>, objectifs_count: usize) -> Self {
+        Game {
+            total_scores: vec![0; players.len()],
+            round_times: vec![Duration::ZERO; players.len()],
+            round_misses: vec![0; players.len()],
+            controllers: players.iter().map(|_| default_controller()).collect(),
+            names: PlayerNames::from_players(&players),
+            totals: StatsAccumulator::new(players.len()),
+            players,
+            objectifs_count,
+            round: 1,
+            rules: GameRules::default(),
+            history: Vec::new(),
+            rounds: Vec::new(),
+            draws: Vec::new(),
+            consecutive_draws: 0,
+            stalemate: false,
+            history_limit: None,
+            history_sink: None,
+            committed: (0, 0, 0, 0),
+            damage: Vec::new(),
+            rng: StdRng::from_os_rng(),
+            seed: None,
+            start: Vec::new(),
+            cancel: CancelToken::new(),
+            output: Output::default(),
+            renderer: Box::new(PlainRenderer),
+            observers: Vec::new(),
+            clock: default_clock(),
+            sound: Sound::default(),
+            pacing: Pacing::default(),
+            shared_counter: None,
+            ghost: None,
+            modifier: None,
+            ban: None,
+            pending_poison: None,
+            winners_curse: None,
+            autosave: None,
+            referee: None,
+        }
+    }
+
+    /// Recrée une partie sauvegardée, qui reprendra à sa prochaine manche.
+    ///
+    /// Les règles sont reprises telles quelles : en mode miroir, les caractéristiques sauvegardées
+    /// sont déjà échangées. L'historique des manches jouées avant la sauvegarde n'est pas restauré.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::game::Game;
+    /// use dual_game::player::Player;
+    ///
+    /// let players = vec![
+    ///     Player::new(String::from("Alice"), 50, 50, 50),
+    ///     Player::new(String::from("Bob"), 50, 50, 50),
+    /// ];
+    /// let mut state = Game::new(players, 3).save_state();
+    /// state.round = 4;
+    /// let game = Game::from_save(&state);
+    /// assert_eq!((game.round, game.players[1].name.as_str()), (4, "Bob"));
+    /// ```
+    pub fn from_save(state: &SaveState) -> Self {
+        let players = state.players.iter().map(SavedPlayer::to_player).collect();
+        // La limite d'objectifs a été vérifiée avec les règles sauvegardées.
+        let mut game = Game::unchecked(players, state.objectifs);
+        game.rules = state.rules;
+        game.round = state.round;
+        if state.total_scores.len() == game.players.len() {
+            game.total_scores = state.total_scores.clone();
+        }
+        game.consecutive_draws = state.consecutive_draws;
+        game.pending_poison = state
+            .pending_poison
+            .clone()
+            .filter(|(index, _)| *index < game.players.len())
+            .map(|(index, poison)| (PlayerId(index), poison));
+        game.winners_curse = state
+            .winners_curse
+            .filter(|index| *index < game.players.len())
+            .map(|index| (PlayerId(index), state.round));
+        if !state.crate_version.is_empty() && state.crate_version != env!("CARGO_PKG_VERSION") {
+            log::warn!(
+                "sauvegarde écrite par la version {} du jeu, reprise avec la version {}",
+                state.crate_version,
+                env!("CARGO_PKG_VERSION")
+            );
+        }
+        game
+    }
+
+    /// Retourne l'état de la partie entre deux manches, tel qu'il est sauvegardé.
+    pub fn save_state(&self) -> SaveState {
+        SaveState {
+            version: SAVE_VERSION,
+            saved_at: saves::now_secs(),
+            round: self.round,
+            objectifs: self.objectifs_count,
+            rules: self.rules,
+            players: self.players.iter().map(SavedPlayer::from).collect(),
+            total_scores: self.total_scores.clone(),
+            consecutive_draws: self.consecutive_draws,
+            crate_version: String::from(env!("CARGO_PKG_VERSION")),
+            scoring: ScoringConfig::default(),
+            seed: self.seed,
+            pending_poison: self
+                .pending_poison
+                .clone()
+                .map(|(player, poison)| (player.index(), poison)),
+            winners_curse: self.cursed_player().map(|player| player.index()),
+        }
+    }
+
+    /// Retourne l'en-tête de l'historique de la partie : ses joueurs, ses paramètres, la version de
+    /// l'application et les caractéristiques des joueurs au début de la partie.
+    pub fn replay_header(&self) -> ReplayHeader {
+        ReplayHeader {
+            crate_version: Some(String::from(env!("CARGO_PKG_VERSION"))),
+            scoring: Some(ScoringConfig::default()),
+            rules: Some(self.rules),
+            start: self.start.clone(),
+            ..ReplayHeader::new(self.names.clone(), self.objectifs_count, self.seed)
+        }
+    }
+
+    /// Sauvegarde la partie dans l'emplacement donné à la fin de chaque manche, tant qu'elle se
+    /// poursuit, en remplaçant la sauvegarde précédente.
+    pub fn set_autosave(&mut self, store: SaveStore, slot: impl Into<String>) {
+        self.autosave = Some((store, slot.into()));
+    }
+
+    /// Crée une nouvelle partie avec ses règles optionnelles.
+    ///
+    /// En mode miroir ([`GameRules::mirror`]), la vitesse et la force des deux joueurs sont échangées
+    /// dès la création (voir [`rules::mirror`](crate::rules::mirror)) : l'aperçu du duel, la partie et
+    /// son résultat portent les caractéristiques échangées.
+    ///
+    /// # Erreurs
+    ///
+    /// Retourne [`SetupError::Mirror`] si le mode miroir est demandé sans exactement deux joueurs, et
+    /// [`SetupError::TooManyObjectives`] si `objectifs_count` dépasse [`GameRules::max_objectives`].
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::game::Game;
+    /// use dual_game::player::Player;
+    /// use dual_game::rules::GameRules;
+    ///
+    /// let players = vec![
+    ///     Player::new(String::from("Alice"), 50, 40, 70),
+    ///     Player::new(String::from("Bob"), 50, 90, 30),
+    /// ];
+    /// let rules = GameRules { mirror: true, ..GameRules::default() };
+    /// let game = Game::new_with_rules(players, 5, rules).unwrap();
+    /// assert_eq!(game.players[0].speed, 90);
+    /// ```
+    pub fn new_with_rules(
+        players: Vec<Player>,
+        objectifs_count: usize,
+        rules: GameRules,
+    ) -> Result<Self, SetupError> {
+        rules.check_objectives(objectifs_count)?;
+        let players = if rules.mirror {
+            mirror(players)?
+        } else {
+            players
+        };
+        let mut game = Game::unchecked(players, objectifs_count);
+        game.rules = rules;
+        Ok(game)
+    }
+
+    /// Remplace la destination de l'affichage de la partie (la sortie standard par défaut).
+    pub fn set_output(&mut self, output: Output) {
+        self.output = output;
+    }
+
+    /// Remplace le rendu du déroulement de la partie (l'affichage textuel habituel par défaut).
+    pub fn set_renderer(&mut self, renderer: Box<dyn Renderer>) {
+        self.renderer = renderer;
+    }
+
+    /// Ajoute un observateur notifié de chaque événement de la partie, indépendamment du niveau de
+    /// détail de l'affichage.
+    pub fn add_observer(&mut self, observer: Box<dyn GameObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// Notifie un événement à tous les observateurs.
+    fn notify(&mut self, event: GameEvent) {
+        for observer in &mut self.observers {
+            observer.on_event(&event);
+        }
+    }
+
+    /// Modifie le niveau de détail de l'affichage de la partie.
+    ///
+    /// [`Verbosity::Silent`] désactive tout affichage, par exemple pour les parties simulées.
+    pub fn set_verbosity(&mut self, verbosity: Verbosity) {
+        self.output.set_verbosity(verbosity);
+    }
+
+    /// Retourne le niveau de détail de l'affichage de la partie.
+    pub fn verbosity(&self) -> Verbosity {
+        self.output.verbosity()
+    }
+
+    /// Associe un jeton d'interruption à la partie.
+    ///
+    /// Lorsque le jeton est annulé, le compteur en cours s'arrête et la partie se termine au prochain
+    /// point d'arrêt sûr (début de manche, avant ou après un tour) avec [`GameResult::Interrupted`],
+    /// après avoir affiché un bilan partiel.
+    ///
+    /// Le jeton est transmis aux contrôleurs des joueurs, dont les saisies en attente sont écourtées.
+    pub fn set_cancel_token(&mut self, cancel: CancelToken) {
+        for controller in &mut self.controllers {
+            controller.set_cancel_token(cancel.clone());
+        }
+        self.cancel = cancel;
+    }
+
+    /// Remplace l'horloge mesurant la durée des arrêts du compteur (celle du système par défaut).
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Installe le compteur commun aux joueurs en mode partagé (voir [`GameMode::Shared`]).
+    ///
+    /// Sans compteur partagé, par exemple lorsqu'un bot joue, chaque contrôleur arrête à son tour le
+    /// compteur sur l'objectif commun.
+    pub fn set_shared_counter(&mut self, counter: Box<dyn SharedCounter>) {
+        self.shared_counter = Some(counter);
+    }
+
+    /// Active ou désactive les signaux sonores (désactivés par défaut).
+    ///
+    /// Le rendu reçoit alors un [`SoundCue`] à chaque dépassement du compteur (au plus un par
+    /// demi-seconde, selon l'horloge de la partie), à chaque arrêt parfait et à la fin de la partie.
+    pub fn set_sound(&mut self, enabled: bool) {
+        self.sound.enabled = enabled;
+    }
+
+    /// Change la cadence des manches (voir [`Pacing`]) : par défaut, chaque tour attend ENTREE et
+    /// les manches s'enchaînent sans pause.
+    pub fn set_pacing(&mut self, pacing: Pacing) {
+        self.pacing = pacing;
+    }
+
+    /// Limite l'historique retenu en mémoire aux dernières manches (au moins une).
+    ///
+    /// Les enregistrements plus anciens sont retirés de `history`, `damage`, `draws` et `rounds` à la fin de
+    /// chaque manche, après avoir été transmis à l'historique de la partie (voir
+    /// [`Game::set_history_sink`]) ; les statistiques restent calculées sur toute la partie.
+    pub fn set_history_limit(&mut self, rounds: usize) {
+        self.history_limit = Some(rounds.max(1));
+    }
+
+    /// Transmet chaque enregistrement de l'historique à la destination donnée, à la fin de chaque
+    /// manche puis à la fin de la partie.
+    pub fn set_history_sink(&mut self, sink: Box<dyn HistorySink>) {
+        self.history_sink = Some(sink);
+    }
+
+    /// Affiche, pendant l'entraînement (voir [`Game::practice`]), l'arrêt du fantôme sur chaque
+    /// objectif et la comparaison des moyennes des essais.
+    pub fn set_ghost(&mut self, ghost: Ghost) {
+        self.ghost = Some(ghost);
+    }
+
+    /// Confie à un spectateur le rôle d'arbitre : au début de chaque manche, il pronostique en
+    /// secret le vainqueur, et impose un mutateur de son choix à la manche qui suit une série de
+    /// [`REFEREE_STREAK`](crate::referee::REFEREE_STREAK) pronostics justes.
+    pub fn set_referee(&mut self, name: String, controller: Box<dyn Controller>) {
+        self.referee = Some((Referee::new(name), controller));
+    }
+
+    /// Retourne l'arbitre de la partie, le cas échéant.
+    pub fn referee(&self) -> Option<&Referee> {
+        self.referee.as_ref().map(|(referee, _)| referee)
+    }
+
+    /// Fixe la graine du générateur des objectifs, rendant leur tirage reproductible.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::game::Game;
+    /// use dual_game::player::Player;
+    ///
+    /// let players = vec![Player::new(String::from("Alice"), 50, 50, 50)];
+    /// let mut first = Game::new(players.clone(), 5);
+    /// let mut second = Game::new(players, 5);
+    /// first.set_seed(42);
+    /// second.set_seed(42);
+    /// assert_eq!(first.generate_objectives(0), second.generate_objectives(0));
+    /// assert_eq!(first.seed(), Some(42));
+    /// ```
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+        self.seed = Some(seed);
+    }
+
+    /// Retourne la graine du générateur des objectifs, si elle a été fixée.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Tire les objectifs du prochain tour d'un joueur, en tenant compte de sa chance.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - L'index du joueur dans `players`.
+    pub fn generate_objectives(&mut self, index: usize) -> Vec<u32> {
+        self.draw_objectives(index, self.objectifs_count)
+    }
+
+    /// Tire le nombre donné d'objectifs pour le prochain tour d'un joueur, en tenant compte de sa
+    /// chance.
+    fn draw_objectives(&mut self, index: usize, count: usize) -> Vec<u32> {
+        if log::log_enabled!(log::Level::Debug) {
+            // Prochaine valeur du générateur, sans l'avancer : elle identifie son état.
+            let rng_state = self.rng.clone().next_u64();
+            log::debug!(
+                "tirage des objectifs player={} round={} count={count} luck={} seed={:?} \
+                 rng_state={rng_state:016x}",
+                self.players[index].name,
+                self.round,
+                self.players[index].luck,
+                self.seed
+            );
+        }
+        let objectives = Objectives::generate_lucky(count, self.players[index].luck, &mut self.rng);
+        let objectives = self.apply_modifier(objectives);
+        log::debug!(
+            "objectifs tirés player={} objectives={objectives:?}",
+            self.players[index].name
+        );
+        self.notify(GameEvent::ObjectivesDrawn {
+            player: self.players[index].name.clone(),
+            objectives: objectives.clone(),
+        });
+        objectives
+    }
+
+    /// Remplace le contrôleur d'un joueur.
+    ///
+    /// Le contrôleur reçoit le jeton d'interruption de la partie (voir [`Game::set_cancel_token`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - L'index du joueur dans `players`.
+    /// * `controller` - Le nouveau contrôleur du joueur.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::controller::RandomBot;
+    /// use dual_game::game::Game;
+    /// use dual_game::player::Player;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let players = vec![
+    ///     Player::new(String::from("Alice"), 50, 50, 50),
+    ///     Player::new(String::from("Bot"), 50, 50, 50),
+    /// ];
+    /// let mut game = Game::new(players, 5);
+    /// game.set_controller(1, Box::new(RandomBot::new(StdRng::seed_from_u64(1))));
+    /// ```
+    pub fn set_controller(&mut self, index: usize, mut controller: Box<dyn Controller>) {
+        controller.set_cancel_token(self.cancel.clone());
+        self.controllers[index] = controller;
+    }
+
+    /// Exécute la boucle de la partie tant que tous les joueurs ont encore de la vitalité.
+    ///
+    /// Chaque tour se compose des actions suivantes :
+    /// - Affichage du numéro de tour.
+    /// - Chaque joueur joue son tour, ce qui inclut la génération d'objectifs et l'exécution d'un tour de jeu.
+    /// - Les scores sont comparés pour déterminer le gagnant du tour.
+    /// - Le joueur perdant subit une pénalité de vitalité ; en cas d'égalité, la manche est nulle et
+    ///   aucun joueur n'est pénalisé. Après [`TIEBREAKER_AFTER_DRAWS`] manches nulles consécutives,
+    ///   chaque manche se joue sur un seul objectif jusqu'à ce qu'un joueur la remporte.
+    /// - Le gagnant choisit un effet de poison à appliquer au perdant.
+    ///
+    /// # Retour
+    ///
+    /// Retourne l'issue de la partie ([`GameResult`]) si elle s'est terminée normalement ou une erreur
+    /// dans le cas contraire.
+    pub fn run(&mut self) -> Result<GameResult, Box<dyn Error>> {
+        self.start();
+        loop {
+            if let Some(result) = self.play_round()? {
+                return Ok(result);
+            }
+        }
+    }
+
+    /// Commence la partie : annonce les joueurs, recharge leur endurance et affiche leur comparatif.
+    ///
+    /// [`Game::run`] commence la partie avant d'enchaîner les manches ; un hôte qui appelle lui-même
+    /// [`Game::play_round`] doit d'abord appeler cette méthode.
+    pub fn start(&mut self) {
+        self.names = PlayerNames::from_players(&self.players);
+        self.start = self.snapshots();
+        self.record(&[HistoryEntry::Header(self.replay_header())]);
+        if self.rules.mirror {
+            self.renderer.mirror_notice(&mut self.output, &self.players);
+        }
+        self.renderer.game_start(&mut self.output, &self.players);
+        self.notify(GameEvent::GameStarted {
+            players: self.names.iter().map(String::from).collect(),
+            objectifs: self.objectifs_count,
+        });
+
+        // L'endurance est rechargée au début de chaque partie, et non entre les manches ni à la
+        // reprise d'une partie sauvegardée.
+        if self.round > 1 {
+            return;
+        }
+        let stamina = if self.rules.stamina {
+            DEFAULT_STAMINA
+        } else {
+            0
+        };
+        for player in &mut self.players {
+            player.stamina = stamina;
+            player.retries = 0;
+        }
+    }
+
+    /// Joue une manche de la partie, puis termine la partie si un joueur a perdu toute sa vitalité.
+    ///
+    /// [`Game::run`] enchaîne les manches jusqu'à la fin de la partie ; un hôte qui rythme lui-même la
+    /// partie (une page web, par exemple) peut appeler cette méthode à chaque manche, après avoir
+    /// fourni les décisions des joueurs à leurs contrôleurs (voir [`ScriptedController`]).
+    ///
+    /// # Retour
+    ///
+    /// Retourne l'issue de la partie si elle est terminée, `None` si elle se poursuit, ou une erreur.
+    ///
+    /// [`ScriptedController`]: crate::controller::ScriptedController
+    pub fn play_round(&mut self) -> Result<Option<GameResult>, Box<dyn Error>> {
+        if self.round_pause_due() {
+            for i in 0..self.players.len() {
+                self.controllers[i].acknowledge(&tr!(
+                    Msg::PressEnterRound,
+                    player = self.players[i].name,
+                    round = self.round
+                ));
+                if let Some(result) = self.stopped() {
+                    return Ok(Some(result));
+                }
+            }
+        }
+        if let Some(result) = self.begin_round() {
+            return Ok(Some(result));
+        }
+        if self.rules.bans {
+            self.choose_ban();
+            if let Some(result) = self.stopped() {
+                return Ok(Some(result));
+            }
+        }
+
+        // En mode classique, chaque joueur joue son tour ; en duel et en mode partagé, les objectifs
+        // communs sont joués un à un.
+        let count = self.round_objectives();
+        let scores = match self.rules.mode {
+            GameMode::Classic => self.play_turns(count)?,
+            GameMode::Duel => self.play_duel(count)?,
+            GameMode::Shared => self.play_shared(count)?,
+        };
+        let scores = match scores {
+            ControlFlow::Continue(scores) => scores,
+            ControlFlow::Break(result) => return Ok(Some(result)),
+        };
+
+        let (winner, loser, dealt) = match self.resolve_scores(&scores)? {
+            RoundResolution::Drawn => {
+                if let Some(result) = self.open_shop() {
+                    return Ok(Some(result));
+                }
+                self.end_round();
+                return Ok(None);
+            }
+            RoundResolution::Won {
+                winner,
+                loser,
+                dealt,
+            } => (winner, loser, dealt),
+        };
+
+        // La boutique n'ouvre pas si le perdant n'a plus de vitalité.
+        let shop = self.player(loser).is_alive();
+        if let Some(options) = self.poison_menu(winner, loser) {
+            let poison_type = loop {
+                let state = self.state(winner.index());
+                self.controllers[winner.index()].observe(&state);
+                let choice = self.controllers[winner.index()]
+                    .choose_poison(&options, &self.players[loser.index()]);
+                if let Some(result) = self.stopped() {
+                    return Ok(Some(result));
+                }
+                // Le menu est redemandé jusqu'à une réponse valide ; seul l'arrêt de la partie
+                // (annulation ou abandon d'un contrôleur) l'interrompt.
+                if let PoisonChoice::Apply(poison_type) =
+                    self.poison_choice(winner, loser, &options, choice)
+                {
+                    break poison_type;
+                }
+            };
+
+            // Le perdant peut contrer le poison une fois par partie en doublant ses dégâts.
+            let counter = self.offer_counter(loser, &poison_type, dealt);
+            self.apply_poison(loser, poison_type, dealt, counter)?;
+        }
+
+        // Entre deux manches, chaque joueur peut dépenser ses pièces à la boutique.
+        if shop && let Some(result) = self.open_shop() {
+            return Ok(Some(result));
+        }
+        Ok(self.close_round())
+    }
+
+    /// Indique si chaque joueur doit confirmer le passage à la manche qui commence : la cadence
+    /// marque une pause entre les manches ([`Pacing::pause_between_rounds`]), une manche a déjà été
+    /// jouée et la partie se poursuit.
+    pub(crate) fn round_pause_due(&self) -> bool {
+        self.pacing.pause_between_rounds
+            && self.round > 1
+            && self.players.iter().all(Player::is_alive)
+    }
+
+    /// Commence une manche : la notifie, affiche le tableau des scores et l'en-tête de la manche, puis
+    /// tire son mutateur lorsque les mutateurs sont activés.
+    ///
+    /// # Retour
+    ///
+    /// Retourne l'issue de la partie si elle est déjà terminée, interrompue ou abandonnée, `None`
+    /// sinon.
+    pub(crate) fn begin_round(&mut self) -> Option<GameResult> {
+        if !self.players.iter().all(Player::is_alive) {
+            return Some(self.finish());
+        }
+        if let Some(result) = self.stopped() {
+            return Some(result);
+        }
+        self.notify(GameEvent::RoundStarted {
+            round: self.round,
+            players: self.snapshots(),
+        });
+        self.round_times = vec![Duration::ZERO; self.players.len()];
+        self.round_misses = vec![0; self.players.len()];
+        // Le terminal a pu être redimensionné depuis la manche précédente.
+        style::refresh_terminal_width();
+        self.show_scoreboard(self.round, false);
+        self.renderer
+            .round_header(&mut self.output, self.round, &self.players);
+        if self.rules.shop {
+            self.renderer.coin_balances(&mut self.output, &self.players);
+        }
+        // Le mutateur imposé par l'arbitre remplace celui qui aurait été tiré au sort.
+        let imposed = self.referee_mutator();
+        let modifier =
+            imposed.or_else(|| self.rules.mutators.then(|| mutators::draw(&mut self.rng)));
+        if let Some(modifier) = modifier {
+            self.modifier = Some(modifier);
+            self.renderer.round_modifier(&mut self.output, modifier);
+        }
+        self.release_poison();
+        self.check_stalemate();
+        self.referee_pick();
+        None
+    }
+
+    /// Active l'anti-stagnation lorsque les dernières manches ont infligé trop peu de dégâts, et la
+    /// lève après une manche aux dégâts suffisants (voir [`GameRules::stalemate_rounds`]).
+    ///
+    /// Les dégâts sont relevés dans l'historique des manches : une manche nulle n'en a infligé aucun.
+    /// Avec un historique limité ([`Game::set_history_limit`]), seules les manches retenues sont
+    /// observées.
+    fn check_stalemate(&mut self) {
+        let window = match self.history_limit {
+            Some(limit) => self.rules.stalemate_rounds.min(limit as u32),
+            None => self.rules.stalemate_rounds,
+        };
+        let played = self.round - 1;
+        if window == 0 || played < window {
+            return;
+        }
+        let dealt_since = |round: u32| -> u32 {
+            self.damage
+                .iter()
+                .filter(|record| record.round >= round)
+                .map(|record| record.dealt)
+                .sum()
+        };
+        if self.stalemate {
+            let dealt = dealt_since(played);
+            if dealt >= STALEMATE_RELIEF_DAMAGE {
+                self.stalemate = false;
+                log::info!("anti-stagnation levée round={} dealt={dealt}", self.round);
+                self.renderer.stalemate_off(&mut self.output, dealt);
+            }
+            return;
+        }
+        let dealt = dealt_since(self.round - window);
+        let threshold = window.saturating_mul(STALEMATE_DAMAGE_PER_ROUND);
+        if dealt < threshold {
+            self.stalemate = true;
+            log::info!(
+                "anti-stagnation activée round={} rounds={window} dealt={dealt} threshold={threshold}",
+                self.round
+            );
+            self.renderer
+                .stalemate_on(&mut self.output, window, threshold);
+        }
+    }
+
+    /// Propose le menu des mutateurs à l'arbitre dont la série de pronostics justes est complète.
+    ///
+    /// Retourne le mutateur imposé à la manche, ou `None` si l'arbitre n'en impose aucun.
+    fn referee_mutator(&mut self) -> Option<&'static dyn RoundModifier> {
+        let (referee, controller) = self.referee.as_mut()?;
+        if !referee.take_reward() {
+            return None;
+        }
+        let level = Self::controller_level(controller.as_ref());
+        self.renderer
+            .referee_reward(&mut self.output, level, &referee.name, &mutators::POOL);
+        let choice = controller.choose_mutator(&mutators::POOL)?;
+        mutators::POOL.get(choice).copied()
+    }
+
+    /// Fait détourner le regard des joueurs, puis recueille le pronostic secret de l'arbitre.
+    fn referee_pick(&mut self) {
+        let Some((referee, controller)) = self.referee.as_mut() else {
+            return;
+        };
+        let names: Vec<String> = self.players.iter().map(Player::colored_name).collect();
+        controller.acknowledge(&tr!(
+            Msg::RefereeLookAway,
+            players = names.join(", "),
+            referee = referee.name
+        ));
+        let level = Self::controller_level(controller.as_ref());
+        self.renderer
+            .referee_pick(&mut self.output, level, &referee.name, &self.players);
+        let pick = controller
+            .choose_pick(&self.players)
+            .filter(|&pick| pick < self.players.len());
+        referee.pick(pick);
+    }
+
+    /// Confronte le pronostic de l'arbitre au résultat de la manche, et en annonce l'issue.
+    fn settle_referee(&mut self, resolution: &RoundResolution) {
+        let Some((referee, _)) = self.referee.as_mut() else {
+            return;
+        };
+        let winner = match resolution {
+            RoundResolution::Drawn => None,
+            RoundResolution::Won { winner, .. } => Some(winner.index()),
+        };
+        if let Some(verdict) = referee.settle(winner) {
+            self.renderer.referee_verdict(
+                &mut self.output,
+                &referee.name,
+                &self.players[verdict.pick],
+                &verdict,
+            );
+        }
+    }
+
+    /// Retourne le nombre d'objectifs de la manche : après plusieurs manches nulles consécutives, la
+    /// manche décisive se joue sur un seul objectif.
+    pub(crate) fn round_objectives(&self) -> usize {
+        if self.consecutive_draws >= TIEBREAKER_AFTER_DRAWS {
+            1
+        } else {
+            self.objectifs_count
+        }
+    }
+
+    /// Compare les scores de la manche : une égalité rend la manche nulle, sans pénalité ; sinon, le
+    /// perdant subit les dégâts de la manche. Le résultat est affiché et notifié.
+    ///
+    /// Avec le départage au temps, une égalité revient au joueur dont les arrêts ont duré le moins
+    /// longtemps, pour des dégâts fixes ; la manche ne reste nulle qu'à durées égales.
+    ///
+    /// Les pièces gagnées à la manche sont versées lorsque la boutique est activée.
+    ///
+    /// Le pronostic de l'arbitre, s'il y en a un, est ensuite confronté au résultat.
+    pub(crate) fn resolve_scores(
+        &mut self,
+        scores: &[u32],
+    ) -> Result<RoundResolution, Box<dyn Error>> {
+        let resolution = self.compare_scores(scores)?;
+        self.settle_referee(&resolution);
+        Ok(resolution)
+    }
+
+    /// Compare les scores de la manche et applique ses dégâts (voir [`Game::resolve_scores`]).
+    fn compare_scores(&mut self, scores: &[u32]) -> Result<RoundResolution, Box<dyn Error>> {
+        if scores.len() < 2 {
+            return Err(tr!(Msg::NotEnoughPlayers).into());
+        }
+        if self.rules.shop {
+            for (player, score) in self.players.iter_mut().zip(scores) {
+                player.coins = player.coins.saturating_add(coins_earned(*score));
+            }
+        }
+
+        // Égalité de scores départagée au temps : le joueur le plus rapide l'emporte.
+        let tiebreak = scores[0] == scores[1] && self.rules.time_tiebreak;
+        if tiebreak && self.round_times[0] != self.round_times[1] {
+            self.consecutive_draws = 0;
+            let (winner, loser) = if self.round_times[0] < self.round_times[1] {
+                (PlayerId(0), PlayerId(1))
+            } else {
+                (PlayerId(1), PlayerId(0))
+            };
+            log::debug!(
+                "départage au temps score={} times_ms={:?} damage={TIME_TIEBREAK_DAMAGE}",
+                scores[0],
+                self.round_times
+                    .iter()
+                    .map(Duration::as_millis)
+                    .collect::<Vec<_>>()
+            );
+            let damage = self
+                .player_mut(loser)
+                .take_piercing_damage(TIME_TIEBREAK_DAMAGE);
+            return Ok(self.award_round(winner, loser, damage, true));
+        }
+
+        // Traitement en cas d'égalité de scores : la manche est nulle, sans pénalité.
+        if scores[0] == scores[1] {
+            self.consecutive_draws += 1;
+            let draw = DrawRecord {
+                round: self.round,
+                score: scores[0],
+                consecutive: self.consecutive_draws,
+            };
+            self.draws.push(draw);
+            log::info!(
+                "manche nulle round={} score={} consecutive={}",
+                draw.round,
+                draw.score,
+                draw.consecutive
+            );
+            let outcome = RoundOutcome::Draw {
+                score: draw.score,
+                consecutive: draw.consecutive,
+                tiebreaker_next: draw.consecutive >= TIEBREAKER_AFTER_DRAWS,
+            };
+            self.renderer.round_result(&mut self.output, &outcome);
+            self.notify(GameEvent::RoundTied {
+                score: draw.score,
+                consecutive: draw.consecutive,
+            });
+            return Ok(RoundResolution::Drawn);
+        }
+        self.consecutive_draws = 0;
+
+        // Détermination du gagnant et du perdant.
+        let (winner, loser) = if scores[0] > scores[1] {
+            (PlayerId(0), PlayerId(1))
+        } else {
+            (PlayerId(1), PlayerId(0))
+        };
+
+        let mut diff = self
+            .rules
+            .round_damage(scores[winner.index()], scores[loser.index()]);
+        let base = diff;
+        if let Some(modifier) = self.modifier {
+            diff = modifier.damage(diff);
+        }
+        let mut damage = self.player_mut(loser).take_damage(diff);
+        // L'anti-stagnation complète les dégâts jusqu'au minimum, sans tenir compte de l'armure.
+        if self.stalemate && damage.dealt < STALEMATE_MIN_DAMAGE {
+            let extra = self
+                .player_mut(loser)
+                .take_piercing_damage(STALEMATE_MIN_DAMAGE - damage.dealt);
+            damage.dealt += extra.dealt;
+            damage.fatal |= extra.fatal;
+        }
+        log::debug!(
+            "calcul des dégâts winner_score={} loser_score={} mode={:?} base={base} mutator={} \
+             damage={diff} armor={} stalemate={} dealt={} absorbed={}",
+            scores[winner.index()],
+            scores[loser.index()],
+            self.rules.mode,
+            self.modifier.map_or("none", |modifier| modifier.id()),
+            self.player(loser).armor,
+            self.stalemate,
+            damage.dealt,
+            damage.absorbed
+        );
+        let resolution = self.award_round(winner, loser, damage, false);
+        let margin = scores[winner.index()] - scores[loser.index()];
+        if self.rules.winners_curse && margin > WINNERS_CURSE_MARGIN {
+            self.curse_winner(winner, margin);
+        }
+        Ok(resolution)
+    }
+
+    /// Frappe le vainqueur de la manche de la malédiction du vainqueur
+    /// ([`GameRules::winners_curse`]) pour la manche suivante : la retient, la consigne avec les
+    /// dégâts de la manche, l'annonce aux joueurs et la notifie.
+    fn curse_winner(&mut self, winner: PlayerId, margin: u32) {
+        log::info!(
+            "malédiction du vainqueur round={} player={} margin={margin} speed={WINNERS_CURSE_SPEED}",
+            self.round,
+            self.player(winner).name
+        );
+        self.winners_curse = Some((winner, self.round + 1));
+        if let Some(record) = self.damage.last_mut() {
+            record.winners_curse = true;
+        }
+        self.renderer
+            .winners_curse(&mut self.output, &self.players[winner.index()], margin);
+        self.notify(GameEvent::WinnersCurse {
+            player: self.player(winner).name.clone(),
+            margin,
+            speed: WINNERS_CURSE_SPEED,
+            round: self.round + 1,
+        });
+    }
+
+    /// Retourne le joueur frappé par la malédiction du vainqueur pendant la manche en cours : le
+    /// vainqueur de la manche précédente, s'il l'a remportée avec trop d'écart. La malédiction ne
+    /// dure qu'une manche.
+    fn cursed_player(&self) -> Option<PlayerId> {
+        self.winners_curse
+            .filter(|(_, round)| *round == self.round)
+            .map(|(player, _)| player)
+    }
+
+    /// Retourne la vitesse d'un joueur pendant la manche en cours, avant le mutateur : sa vitesse,
+    /// réduite de [`WINNERS_CURSE_SPEED`] points s'il est frappé par la malédiction du vainqueur.
+    fn round_speed(&self, index: usize) -> u32 {
+        let speed = self.players[index].speed;
+        if self.cursed_player() == Some(PlayerId(index)) {
+            speed.saturating_sub(WINNERS_CURSE_SPEED).max(MIN_SPEED)
+        } else {
+            speed
+        }
+    }
+
+    /// Retourne la vitesse effective du compteur d'un joueur pendant la manche en cours : sa vitesse
+    /// (voir [`Game::round_speed`]) modifiée par le mutateur de la manche.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - L'index du joueur dans `players`.
+    pub fn effective_speed(&self, index: usize) -> u32 {
+        let speed = self.round_speed(index);
+        self.modifier
+            .map_or(speed, |modifier| modifier.speed(speed))
+    }
+
+    /// Consigne, notifie et affiche les dégâts infligés au perdant d'une manche remportée.
+    fn award_round(
+        &mut self,
+        winner: PlayerId,
+        loser: PlayerId,
+        damage: DamageOutcome,
+        time_tiebreak: bool,
+    ) -> RoundResolution {
+        // Le perdant gagne un rejeu, sans en cumuler plus d'un.
+        if self.rules.retry {
+            self.player_mut(loser).retries = 1;
+        }
+        let dealt = damage.dealt;
+        log::info!(
+            "manche remportée round={} winner={} loser={} dealt={dealt} vitality={} \
+             time_tiebreak={time_tiebreak}",
+            self.round,
+            self.player(winner).name,
+            self.player(loser).name,
+            self.player(loser).vitality()
+        );
+        self.damage.push(DamageRecord {
+            round: self.round,
+            winner: winner.index(),
+            loser: loser.index(),
+            dealt,
+            time_tiebreak,
+            winners_curse: false,
+        });
+        self.notify(GameEvent::DamageDealt {
+            winner: self.player(winner).name.clone(),
+            loser: self.player(loser).name.clone(),
+            damage: dealt,
+            absorbed: damage.absorbed,
+            vitality: self.player(loser).vitality(),
+        });
+        let outcome = RoundOutcome::Won {
+            winner: &self.players[winner.index()],
+            loser: &self.players[loser.index()],
+            damage,
+            time_tiebreak,
+        };
+        self.renderer.round_result(&mut self.output, &outcome);
+        RoundResolution::Won {
+            winner,
+            loser,
+            dealt,
+        }
+    }
+
+    /// Affiche au vainqueur de la manche le menu des poisons à appliquer au perdant.
+    ///
+    /// Le poison interdit pour la manche n'est pas proposé ; s'il n'en reste aucun, l'absence de
+    /// poison est annoncée.
+    ///
+    /// # Retour
+    ///
+    /// Retourne les poisons proposés, ou `None` si le perdant n'a plus de vitalité ou si aucun poison
+    /// ne peut être proposé.
+    pub(crate) fn poison_menu(
+        &mut self,
+        winner: PlayerId,
+        loser: PlayerId,
+    ) -> Option<Vec<PoisonType>> {
+        if !self.player(loser).is_alive() {
+            return None;
+        }
+        let options = allowed_poisons(self.ban.as_slice());
+        if options.is_empty() {
+            self.renderer
+                .poison_unavailable(&mut self.output, &self.players[loser.index()]);
+            return None;
+        }
+        self.show_poison_menu(winner, loser, &options);
+        Some(options)
+    }
+
+    /// Affiche au vainqueur de la manche les poisons proposés au perdant.
+    fn show_poison_menu(&mut self, winner: PlayerId, loser: PlayerId, options: &[PoisonType]) {
+        let level = self.prompt_level(winner.index());
+        self.renderer.poison_menu(
+            &mut self.output,
+            level,
+            &self.players[winner.index()],
+            &self.players[loser.index()],
+            options,
+        );
+    }
+
+    /// Retient la réponse du vainqueur au menu des poisons.
+    ///
+    /// Les mêmes `options` ont servi à afficher le menu : une réponse qui n'en désigne aucune affiche
+    /// de nouveau le menu pour une nouvelle tentative.
+    pub(crate) fn poison_choice(
+        &mut self,
+        winner: PlayerId,
+        loser: PlayerId,
+        options: &[PoisonType],
+        choice: usize,
+    ) -> PoisonChoice {
+        match options.get(choice) {
+            Some(poison) => PoisonChoice::Apply(poison.clone()),
+            None => {
+                self.renderer
+                    .invalid_choice(&mut self.output, options.len());
+                self.show_poison_menu(winner, loser, options);
+                PoisonChoice::Retry
+            }
+        }
+    }
+
+    /// Applique au perdant le poison choisi, ou les dégâts supplémentaires s'il le contre, puis
+    /// notifie et affiche le résultat.
+    ///
+    /// Comme le poison, les dégâts supplémentaires ignorent l'armure. Avec le poison différé
+    /// ([`GameRules::delayed_poison`]), un poison accepté est seulement annoncé : il fait effet au
+    /// début de la manche suivante, alors qu'un poison contré est résolu aussitôt.
+    pub(crate) fn apply_poison(
+        &mut self,
+        loser: PlayerId,
+        poison_type: PoisonType,
+        dealt: u32,
+        counter: bool,
+    ) -> Result<(), Box<dyn Error>> {
+        if self.rules.delayed_poison && !counter {
+            log::debug!(
+                "poison différé target={} poison={poison_type:?} round={}",
+                self.player(loser).name,
+                self.round
+            );
+            self.renderer.poison_delayed(
+                &mut self.output,
+                &self.players[loser.index()],
+                &poison_type,
+            );
+            self.pending_poison = Some((loser, poison_type));
+            return Ok(());
+        }
+        let outcome = resolve_poison(self.player_mut(loser), poison_type, dealt, counter)?;
+        if let PoisonOutcome::Countered { extra_damage } = outcome
+            && let Some(record) = self.damage.last_mut()
+        {
+            record.dealt += extra_damage;
+        }
+        self.show_poison(loser, outcome);
+        Ok(())
+    }
+
+    /// Applique le poison différé en attente au début de la manche, après avoir averti sa victime.
+    fn release_poison(&mut self) {
+        let Some((target, poison)) = self.pending_poison.take() else {
+            return;
+        };
+        self.renderer
+            .poison_warning(&mut self.output, &self.players[target.index()], &poison);
+        // Le poison a été accepté à la manche précédente : il ne peut plus être contré.
+        self.player_mut(target).apply_poison(poison.clone());
+        self.show_poison(target, PoisonOutcome::Applied(poison));
+    }
+
+    /// Notifie et affiche l'effet du poison finalement subi par un joueur.
+    fn show_poison(&mut self, loser: PlayerId, outcome: PoisonOutcome) {
+        let player = self.player(loser).name.clone();
+        self.notify(match &outcome {
+            PoisonOutcome::Applied(poison) => GameEvent::PoisonApplied {
+                player,
+                poison: poison.clone(),
+            },
+            PoisonOutcome::Countered { extra_damage } => GameEvent::PoisonCountered {
+                player,
+                extra_damage: *extra_damage,
+                vitality: self.player(loser).vitality(),
+            },
+        });
+        self.renderer
+            .poison_result(&mut self.output, &self.players[loser.index()], &outcome);
+    }
+
+    /// Termine une manche remportée, puis la partie si un joueur a perdu toute sa vitalité.
+    ///
+    /// # Retour
+    ///
+    /// Retourne l'issue de la partie si elle est terminée, `None` si elle se poursuit.
+    pub(crate) fn close_round(&mut self) -> Option<GameResult> {
+        self.renderer.round_end(&mut self.output, self.round);
+        self.end_round();
+        if !self.players.iter().all(Player::is_alive) {
+            return Some(self.finish());
+        }
+        None
+    }
+
+    /// Ouvre la boutique à chaque joueur, dans l'ordre, lorsqu'elle est activée.
+    ///
+    /// Un joueur qui ne peut acheter aucun objet n'est pas sollicité.
+    ///
+    /// # Retour
+    ///
+    /// Retourne l'issue de la partie si elle a été interrompue pendant un achat, `None` sinon.
+    fn open_shop(&mut self) -> Option<GameResult> {
+        if !self.rules.shop {
+            return None;
+        }
+        for index in 0..self.players.len() {
+            if !self.shop_menu(index) {
+                continue;
+            }
+            let choice = self.controllers[index].choose_item(&Item::ALL, &self.players[index]);
+            if let Some(result) = self.stopped() {
+                return Some(result);
+            }
+            self.buy_item(index, choice);
+        }
+        None
+    }
+
+    /// Affiche à un joueur le menu de la boutique, s'il peut y acheter au moins un objet.
+    ///
+    /// Retourne `true` si le menu a été affiché et que le joueur doit choisir un objet.
+    pub(crate) fn shop_menu(&mut self, index: usize) -> bool {
+        let player = &self.players[index];
+        if Item::ALL.iter().all(|item| item.check(player).is_err()) {
+            return false;
+        }
+        let level = self.prompt_level(index);
+        self.renderer
+            .shop_menu(&mut self.output, level, player, &Item::ALL);
+        true
+    }
+
+    /// Achète pour un joueur l'objet choisi à la boutique (`None` pour ne rien acheter), puis affiche
+    /// le résultat de l'achat.
+    pub(crate) fn buy_item(&mut self, index: usize, choice: Option<usize>) {
+        let outcome = match choice.and_then(|choice| Item::ALL.get(choice)) {
+            Some(&item) => match buy(&mut self.players[index], item) {
+                Ok(()) => ShopOutcome::Bought(item),
+                Err(err) => ShopOutcome::Refused(item, err),
+            },
+            None => ShopOutcome::Skipped,
+        };
+        self.renderer
+            .shop_result(&mut self.output, &self.players[index], &outcome);
+    }
+
+    /// Fait jouer à chaque joueur son tour complet, sur `count` objectifs tirés pour lui.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le score moyen de chaque joueur, ou l'issue de la partie si elle a été interrompue.
+    fn play_turns(
+        &mut self,
+        count: usize,
+    ) -> Result<ControlFlow<GameResult, Vec<u32>>, Box<dyn Error>> {
+        let mut scores = Vec::with_capacity(self.players.len());
+        for i in 0..self.players.len() {
+            // Prise de risque, hors manche décisive, puis génération des objectifs.
+            let risk = if self.offers_risk(count) {
+                let risk = self.choose_risk(i);
+                if let Some(result) = self.stopped() {
+                    return Ok(ControlFlow::Break(result));
+                }
+                Some(risk)
+            } else {
+                None
+            };
+            let objectives = self.begin_turn(i, count, risk);
+            if self.pacing.auto_continue {
+                self.count_down(i);
+            } else {
+                self.controllers[i].acknowledge(&tr!(Msg::PressEnterTurn));
+            }
+            if let Some(result) = self.stopped() {
+                return Ok(ControlFlow::Break(result));
+            }
+
+            // Exécution du tour et récupération du score moyen.
+            let (score, _) = self.play_risked_turn(i, &objectives, risk)?;
+            if let Some(result) = self.stopped() {
+                return Ok(ControlFlow::Break(result));
+            }
+            self.show_turn_end(i, score);
+            scores.push(score);
+        }
+        self.add_total_scores(&scores);
+        Ok(ControlFlow::Continue(scores))
+    }
+
+    /// Affiche le compte à rebours du tour d'un joueur, seconde après seconde selon l'horloge de la
+    /// partie, jusqu'à son début ou l'interruption de la partie.
+    fn count_down(&mut self, index: usize) {
+        for seconds in (1..=AUTO_CONTINUE_SECONDS).rev() {
+            if self.cancel.is_cancelled() {
+                return;
+            }
+            self.renderer
+                .turn_countdown(&mut self.output, &self.players[index], seconds);
+            self.clock.sleep(Duration::from_secs(1));
+        }
+    }
+
+    /// Marque la pause prévue par la cadence après le résultat d'un objectif, s'il y en a une.
+    fn breathe(&self) {
+        if !self.pacing.turn_delay.is_zero() {
+            self.clock.sleep(self.pacing.turn_delay);
+        }
+    }
+
+    /// Indique si les joueurs choisissent leur prise de risque avant leur tour de `count` objectifs :
+    /// la règle doit être activée, et la manche ne pas être décisive.
+    pub(crate) fn offers_risk(&self, count: usize) -> bool {
+        self.rules.risk_choice && count == self.objectifs_count
+    }
+
+    /// Commence le tour d'un joueur : tire ses objectifs, autant que l'exige sa prise de risque s'il en
+    /// a choisi une, puis les affiche.
+    ///
+    /// Retourne les objectifs du tour.
+    pub(crate) fn begin_turn(
+        &mut self,
+        index: usize,
+        count: usize,
+        risk: Option<Risk>,
+    ) -> Vec<u32> {
+        let count = risk.map_or(count, |risk| risk.objectives());
+        let objectives = self.draw_objectives(index, count);
+        let turn = TurnView {
+            index,
+            player: &self.players[index],
+            fatigue: self.rules.fatigue_penalty(self.round),
+            objectives: &objectives,
+            prompt: self.prompt_level(index),
+        };
+        self.renderer.turn_start(&mut self.output, &turn);
+        objectives
+    }
+
+    /// Affiche la fin du tour d'un joueur avec son score moyen.
+    pub(crate) fn show_turn_end(&mut self, index: usize, score: u32) {
+        self.renderer
+            .turn_end(&mut self.output, &self.players[index], score);
+    }
+
+    /// Ajoute les scores de la manche aux scores cumulés des joueurs.
+    pub(crate) fn add_total_scores(&mut self, scores: &[u32]) {
+        for (total, score) in self.total_scores.iter_mut().zip(scores) {
+            *total += score;
+        }
+    }
+
+    /// Propose au joueur qui a le moins de vitalité d'interdire un poison pour la manche, et annonce
+    /// l'interdiction. Personne n'est sollicité lorsque les vitalités sont égales.
+    fn choose_ban(&mut self) {
+        let Some(index) = self.ban_menu() else {
+            return;
+        };
+        let player = &self.players[index];
+        let opponent = &self.players[1 - index];
+        let choice = self.controllers[index].choose_ban(&PoisonType::ALL, player, opponent);
+        self.apply_ban(index, choice);
+    }
+
+    /// Affiche le menu d'interdiction au joueur qui a le moins de vitalité, après avoir levé
+    /// l'interdiction de la manche précédente.
+    ///
+    /// Retourne l'index du joueur sollicité, ou `None` lorsque les vitalités sont égales.
+    pub(crate) fn ban_menu(&mut self) -> Option<usize> {
+        self.ban = None;
+        let [first, second] = &self.players[..] else {
+            return None;
+        };
+        let index = match first.vitality().cmp(&second.vitality()) {
+            Ordering::Less => 0,
+            Ordering::Greater => 1,
+            Ordering::Equal => return None,
+        };
+        let level = self.prompt_level(index);
+        self.renderer.ban_menu(
+            &mut self.output,
+            level,
+            &self.players[index],
+            &PoisonType::ALL,
+        );
+        Some(index)
+    }
+
+    /// Retient le poison interdit pour la manche par un joueur (`None` pour n'en interdire aucun), et
+    /// annonce l'interdiction.
+    pub(crate) fn apply_ban(&mut self, index: usize, choice: Option<usize>) {
+        self.ban = choice.and_then(|choice| PoisonType::ALL.get(choice).cloned());
+        if let Some(poison) = &self.ban {
+            self.renderer
+                .round_ban(&mut self.output, &self.players[index], poison);
+        }
+    }
+
+    /// Propose à un joueur de choisir sa prise de risque avant son tour.
+    ///
+    /// Un choix hors limites équivaut à [`Risk::Normal`].
+    fn choose_risk(&mut self, index: usize) -> Risk {
+        self.risk_menu(index);
+        let player = &self.players[index];
+        let opponent = &self.players[(index + 1) % self.players.len()];
+        let choice = self.controllers[index].choose_risk(&Risk::ALL, player, opponent);
+        Risk::ALL.get(choice).copied().unwrap_or_default()
+    }
+
+    /// Affiche à un joueur le menu des prises de risque.
+    pub(crate) fn risk_menu(&mut self, index: usize) {
+        let level = self.prompt_level(index);
+        self.renderer
+            .risk_menu(&mut self.output, level, &self.players[index], &Risk::ALL);
+    }
+
+    /// Fait disputer aux joueurs `count` objectifs communs, un à un : chaque joueur arrête le compteur
+    /// à son tour, et le meilleur score remporte le point de l'objectif.
+    ///
+    /// Les objectifs sont tirés sans tenir compte de la chance, commune aux deux joueurs. Les arrêts de
+    /// chaque joueur forment son tour dans l'historique, avec leur score moyen.
+    ///
+    /// # Retour
+    ///
+    /// Retourne les points de chaque joueur, ou l'issue de la partie si elle a été interrompue.
+    fn play_duel(
+        &mut self,
+        count: usize,
+    ) -> Result<ControlFlow<GameResult, Vec<u32>>, Box<dyn Error>> {
+        let objectives = self.draw_common_objectives(count);
+        let mut points = vec![0; self.players.len()];
+        let mut stops = vec![Vec::new(); self.players.len()];
+        for (obj_index, &objective) in objectives.iter().enumerate() {
+            self.renderer.duel_objective(
+                &mut self.output,
+                obj_index + 1,
+                objectives.len(),
+                objective,
+            );
+            let mut scores = Vec::new();
+            for (i, turn) in stops.iter_mut().enumerate() {
+                self.controllers[i].acknowledge(&tr!(
+                    Msg::PressEnterDuel,
+                    player = self.players[i].name,
+                    objective = objective
+                ));
+                if let Some(result) = self.stopped() {
+                    return Ok(ControlFlow::Break(result));
+                }
+                let stop = self.play_single_objective(i, obj_index, objective);
+                self.controllers[i].end_turn();
+                if let Some(result) = self.stopped() {
+                    return Ok(ControlFlow::Break(result));
+                }
+                if let Some(stop) = stop {
+                    scores.push(stop.score);
+                    turn.push(stop);
+                }
+            }
+
+            // Le meilleur score remporte le point ; à égalité, personne ne le marque.
+            let best = scores.iter().copied().max().unwrap_or(0);
+            let leaders: Vec<usize> = (0..scores.len()).filter(|&i| scores[i] == best).collect();
+            let winner = match leaders[..] {
+                [winner] => {
+                    points[winner] += 1;
+                    Some(&self.players[winner])
+                }
+                _ => None,
+            };
+            let view = DuelPointView {
+                winner,
+                points: &points,
+            };
+            self.renderer.duel_point(&mut self.output, &view);
+        }
+
+        self.end_common_turns(stops);
+        Ok(ControlFlow::Continue(points))
+    }
+
+    /// Fait jouer aux joueurs `count` objectifs communs sur le compteur partagé, chacun verrouillant sa
+    /// valeur sur le même compteur (voir [`Game::set_shared_counter`]).
+    ///
+    /// Sans compteur partagé, chaque contrôleur arrête à son tour le compteur sur l'objectif commun.
+    /// Les arrêts de chaque joueur forment son tour dans l'historique, et les scores moyens sont
+    /// comparés comme en mode classique.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le score moyen de chaque joueur, ou l'issue de la partie si elle a été interrompue.
+    fn play_shared(
+        &mut self,
+        count: usize,
+    ) -> Result<ControlFlow<GameResult, Vec<u32>>, Box<dyn Error>> {
+        if self.players.len() != 2 {
+            return Err(tr!(Msg::NotEnoughPlayers).into());
+        }
+        let mut speed = (self.round_speed(0) + self.round_speed(1)) / 2;
+        if let Some(modifier) = self.modifier {
+            speed = modifier.speed(speed);
+        }
+        let objectives = self.draw_common_objectives(count);
+        let mut stops = vec![Vec::new(); self.players.len()];
+        for (obj_index, &objective) in objectives.iter().enumerate() {
+            self.renderer.duel_objective(
+                &mut self.output,
+                obj_index + 1,
+                objectives.len(),
+                objective,
+            );
+            if self.shared_counter.is_none() {
+                for (i, turn) in stops.iter_mut().enumerate() {
+                    let stop = self.play_single_objective(i, obj_index, objective);
+                    self.controllers[i].end_turn();
+                    if let Some(result) = self.stopped() {
+                        return Ok(ControlFlow::Break(result));
+                    }
+                    turn.extend(stop);
+                }
+                continue;
+            }
+
+            let names = [self.players[0].name.as_str(), self.players[1].name.as_str()];
+            let [first_key, second_key] = keys::bindings().locks;
+            self.controllers[0].acknowledge(&tr!(
+                Msg::PressEnterShared,
+                first = names[0],
+                first_key = first_key,
+                second = names[1],
+                second_key = second_key
+            ));
+            if let Some(result) = self.stopped() {
+                return Ok(ControlFlow::Break(result));
+            }
+            let view = SharedView {
+                objective,
+                speed,
+                names: [self.players[0].name.as_str(), self.players[1].name.as_str()],
+                cancel: self.cancel.clone(),
+            };
+            let started = self.clock.now();
+            let locks = match self.shared_counter.as_mut() {
+                Some(shared) => shared.run(&view),
+                None => SharedLocks::default(),
+            };
+            let elapsed = self.clock.now().saturating_sub(started);
+            if let Some(result) = self.stopped() {
+                return Ok(ControlFlow::Break(result));
+            }
+            for (i, stop) in locks.decisions(objective).into_iter().enumerate() {
+                stops[i].push(self.score_stop(i, objective, stop, elapsed, false, false));
+            }
+            self.breathe();
+        }
+
+        let averages = self.end_common_turns(stops);
+        for (player, average) in self.players.iter().zip(&averages) {
+            self.renderer.turn_end(&mut self.output, player, *average);
+        }
+        Ok(ControlFlow::Continue(averages))
+    }
+
+    /// Tire les objectifs communs aux joueurs, sans tenir compte de leur chance, et les notifie pour
+    /// chaque joueur.
+    fn draw_common_objectives(&mut self, count: usize) -> Vec<u32> {
+        let objectives = Objectives::generate_lucky(count, 0, &mut self.rng);
+        let objectives = self.apply_modifier(objectives);
+        for player in &self.players {
+            let event = GameEvent::ObjectivesDrawn {
+                player: player.name.clone(),
+                objectives: objectives.clone(),
+            };
+            for observer in &mut self.observers {
+                observer.on_event(&event);
+            }
+        }
+        objectives
+    }
+
+    /// Transforme les objectifs tirés par le mutateur de la manche, s'il y en a un.
+    fn apply_modifier(&self, objectives: Vec<u32>) -> Vec<u32> {
+        match self.modifier {
+            Some(modifier) => objectives
+                .into_iter()
+                .map(|objective| modifier.objective(objective))
+                .collect(),
+            None => objectives,
+        }
+    }
+
+    /// Consigne le tour de chaque joueur formé de ses arrêts sur les objectifs communs, et ajoute son
+    /// score moyen au total.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le score moyen de chaque joueur.
+    fn end_common_turns(&mut self, stops: Vec<Vec<StopRecord>>) -> Vec<u32> {
+        let mut averages = Vec::with_capacity(stops.len());
+        for (i, stops) in stops.into_iter().enumerate() {
+            let scores: Vec<u32> = stops.iter().map(|stop| stop.score).collect();
+            let average = ScoringCalculator::calculate_average(&scores);
+            self.end_turn_record(i, stops, average, None);
+            self.total_scores[i] += average;
+            averages.push(average);
+        }
+        averages
+    }
+
+    /// Retourne l'instantané des caractéristiques de chaque joueur.
+    fn snapshots(&self) -> Vec<PlayerSnapshot> {
+        self.players
+            .iter()
+            .enumerate()
+            .map(|(index, player)| player.snapshot(PlayerId(index)))
+            .collect()
+    }
+
+    /// Retient l'état des joueurs à la fin de la manche, la commente, la notifie et passe à la
+    /// suivante.
+    pub(crate) fn end_round(&mut self) {
+        let record = RoundRecord {
+            round: self.round,
+            players: self.snapshots(),
+            mutator: self
+                .modifier
+                .take()
+                .map(|modifier| modifier.id().to_string()),
+        };
+        let history = self.retained_history();
+        let category = commentary::category(&record, &history);
+        let text = commentary::for_round(&record, &history);
+        self.renderer
+            .round_commentary(&mut self.output, category, &text);
+        self.rounds.push(record);
+        self.ban = None;
+        self.commit();
+        if let Some(limit) = self.history_limit {
+            self.forget_before(self.round.saturating_sub(limit as u32 - 1));
+        }
+        self.notify(GameEvent::RoundEnded { round: self.round });
+        self.round += 1;
+        if let Some((store, slot)) = &self.autosave
+            && self.players.iter().all(Player::is_alive)
+            && let Err(err) = store.save(slot, &self.save_state())
+        {
+            log::warn!("sauvegarde automatique de la partie impossible : {err}");
+        }
+    }
+
+    /// Ajoute aux compteurs des statistiques les enregistrements de l'historique qui n'y figurent pas
+    /// encore, et les transmet à l'historique de la partie.
+    fn commit(&mut self) {
+        let (turns, damage, draws, rounds) = self.committed;
+        self.totals.add_turns(&self.history[turns..]);
+        self.totals.add_damage(&self.damage[damage..]);
+        self.totals.add_draws(&self.draws[draws..]);
+        if self.history_sink.is_some() {
+            let entries: Vec<HistoryEntry> = self.history[turns..]
+                .iter()
+                .cloned()
+                .map(HistoryEntry::Turn)
+                .chain(
+                    self.damage[damage..]
+                        .iter()
+                        .copied()
+                        .map(HistoryEntry::Damage),
+                )
+                .chain(self.draws[draws..].iter().copied().map(HistoryEntry::Draw))
+                .chain(
+                    self.rounds[rounds..]
+                        .iter()
+                        .cloned()
+                        .map(HistoryEntry::Round),
+                )
+                .collect();
+            self.record(&entries);
+        }
+        self.committed = (
+            self.history.len(),
+            self.damage.len(),
+            self.draws.len(),
+            self.rounds.len(),
+        );
+    }
+
+    /// Transmet des enregistrements à l'historique de la partie, s'il y en a un, puis les rend
+    /// durables.
+    fn record(&mut self, entries: &[HistoryEntry]) {
+        let Some(sink) = &mut self.history_sink else {
+            return;
+        };
+        let written = entries.iter().try_for_each(|entry| sink.write(entry));
+        if let Err(err) = written.and_then(|()| sink.flush()) {
+            log::warn!("écriture de l'historique de la partie impossible : {err}");
+        }
+    }
+
+    /// Retire de la mémoire les enregistrements transmis des manches antérieures à celle donnée.
+    fn forget_before(&mut self, round: u32) {
+        let turns = self.history.partition_point(|turn| turn.round < round);
+        let damage = self.damage.partition_point(|record| record.round < round);
+        let draws = self.draws.partition_point(|record| record.round < round);
+        let rounds = self.rounds.partition_point(|record| record.round < round);
+        self.history.drain(..turns);
+        self.damage.drain(..damage);
+        self.draws.drain(..draws);
+        self.rounds.drain(..rounds);
+        let (committed_turns, committed_damage, committed_draws, committed_rounds) = self.committed;
+        self.committed = (
+            committed_turns - turns,
+            committed_damage - damage,
+            committed_draws - draws,
+            committed_rounds - rounds,
+        );
+    }
+
+    /// Affiche le vainqueur et les statistiques de fin de partie, et notifie sa fin.
+    fn finish(&mut self) -> GameResult {
+        let result = match self.winner() {
+            Some(winner) => GameResult::Victory { winner },
+            None => GameResult::Draw,
+        };
+        self.end(result)
+    }
+
+    /// Exécute une séance d'entraînement en solo pour le premier joueur.
+    ///
+    /// Chaque essai tire de nouveaux objectifs et les fait jouer au joueur, sans adversaire ni dégâts.
+    /// Les essais sont transmis à l'historique de la partie (voir [`Game::set_history_sink`]) au fil de
+    /// l'entraînement, et comparés à ceux du fantôme s'il y en a un (voir [`Game::set_ghost`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `turns` - Le nombre d'essais.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le score moyen de chaque essai.
+    pub fn practice(&mut self, turns: u32) -> Result<Vec<u32>, Box<dyn Error>> {
+        say!(self, Verbosity::Normal, "{}", tr!(Msg::PracticeStart));
+        self.start = self.snapshots();
+        self.record(&[HistoryEntry::Header(self.replay_header())]);
+        self.players[0].stamina = if self.rules.stamina {
+            DEFAULT_STAMINA
+        } else {
+            0
+        };
+
+        let mut averages = Vec::new();
+        for turn in 1..=turns {
+            if self.cancel.is_cancelled() {
+                say!(
+                    self,
+                    Verbosity::Quiet,
+                    "\n{}",
+                    tr!(Msg::PracticeInterrupted)
+                );
+                break;
+            }
+            self.round = turn;
+            say!(
+                self,
+                Verbosity::Normal,
+                "\n{}",
+                tr!(Msg::PracticeTry, turn = turn, turns = turns)
+            );
+            let objectives = self.generate_objectives(0);
+            let level = self.prompt_level(0);
+            say!(
+                self,
+                level,
+                "{}",
+                tr!(Msg::Objectives, objectives = format!("{objectives:?}"))
+            );
+            self.controllers[0].acknowledge(&tr!(Msg::PressEnterTry));
+            let (score, _) = self.play_turn(0, &objectives)?;
+            if self.cancel.is_cancelled() {
+                say!(
+                    self,
+                    Verbosity::Quiet,
+                    "\n{}",
+                    tr!(Msg::PracticeInterrupted)
+                );
+                break;
+            }
+            say!(
+                self,
+                Verbosity::Quiet,
+                "\n{}",
+                tr!(Msg::AverageScore, score = score)
+            );
+            if self.history_sink.is_some()
+                && let Some(turn) = self.history.last()
+            {
+                self.record(&[HistoryEntry::Turn(turn.clone())]);
+            }
+            averages.push(score);
+            if let Some(ghost) = self
+                .ghost
+                .as_ref()
+                .and_then(|g| g.running_average(averages.len()))
+            {
+                say!(
+                    self,
+                    Verbosity::Normal,
+                    "{}",
+                    tr!(
+                        Msg::GhostAverages,
+                        average = ScoringCalculator::calculate_average(&averages),
+                        ghost = ghost
+                    )
+                );
+            }
+        }
+        let outcome = if self.cancel.is_cancelled() {
+            Outcome::Interrupted
+        } else {
+            Outcome::Draw
+        };
+        self.record(&[HistoryEntry::End {
+            outcome,
+            winner: None,
+        }]);
+
+        if let Some(best) = averages.iter().max() {
+            say!(self, Verbosity::Normal, "\n{}", tr!(Msg::PracticeOver));
+            say!(
+                self,
+                Verbosity::Quiet,
+                "{}",
+                tr!(
+                    Msg::PracticeSummary,
+                    best = best,
+                    average = ScoringCalculator::calculate_average(&averages)
+                )
+            );
+        }
+        if let Some(margin) = self.ghost.as_ref().and_then(|g| g.margin(&averages)) {
+            let points = margin.unsigned_abs();
+            let line = match margin {
+                0 => tr!(Msg::GhostTied),
+                1.. => tr!(Msg::GhostAhead, points = points),
+                _ => tr!(Msg::GhostBehind, points = points),
+            };
+            say!(self, Verbosity::Quiet, "{}", line);
+        }
+        Ok(averages)
+    }
+
+    /// Termine la partie si elle a été interrompue ou si un joueur a abandonné.
+    ///
+    /// Retourne l'issue de la partie ainsi terminée, ou `None` si elle peut se poursuivre.
+    pub(crate) fn stopped(&mut self) -> Option<GameResult> {
+        if self.cancel.is_cancelled() {
+            return Some(self.interrupt());
+        }
+        let forfeited = self.controllers.iter().position(|c| c.has_forfeited())?;
+        Some(self.forfeit(PlayerId(forfeited)))
+    }
+
+    /// Déclare l'abandon d'un joueur, son adversaire remportant la partie.
+    fn forfeit(&mut self, forfeited: PlayerId) -> GameResult {
+        let winner = PlayerId((forfeited.index() + 1) % self.players.len());
+        self.end(GameResult::Forfeit { forfeited, winner })
+    }
+
+    /// Affiche le bilan partiel d'une partie interrompue.
+    ///
+    /// Seules les manches terminées sont comptées dans les scores cumulés.
+    fn interrupt(&mut self) -> GameResult {
+        self.end(GameResult::Interrupted)
+    }
+
+    /// Affiche la fin de la partie, puis ses statistiques si elle n'a pas été interrompue, et la notifie
+    /// aux observateurs.
+    fn end(&mut self, result: GameResult) -> GameResult {
+        // Un poison différé en attente n'a plus de manche où faire effet.
+        self.pending_poison = None;
+        self.commit();
+        self.record(&[HistoryEntry::End {
+            outcome: Outcome::from(result),
+            winner: result.winner().map(|id| id.index()),
+        }]);
+        if result != GameResult::Interrupted {
+            self.show_scoreboard(self.round - 1, true);
+        }
+        let end = EndView {
+            result,
+            players: &self.players,
+            total_scores: &self.total_scores,
+            rounds: self.round - 1,
+        };
+        self.renderer.game_end(&mut self.output, &end);
+        if result != GameResult::Interrupted {
+            let stats = self.stats();
+            self.renderer.game_stats(&mut self.output, &stats);
+        }
+        if result != GameResult::Interrupted
+            && self.sound.allows(SoundCue::GameOver, Duration::ZERO)
+        {
+            self.renderer
+                .sound_cue(&mut self.output, SoundCue::GameOver);
+        }
+        self.notify(GameEvent::GameEnded {
+            result,
+            winner: result.winner_name(&self.names).map(String::from),
+            rounds: self.round - 1,
+        });
+        result
+    }
+
+    /// Transmet au rendu le tableau des scores : manches remportées, score moyen du dernier tour et
+    /// vitalité de chaque joueur, tirés de l'historique de la partie et des compteurs des manches qui n'y
+    /// sont plus retenues.
+    fn show_scoreboard(&mut self, round: u32, finished: bool) {
+        let indexes = 0..self.players.len();
+        let wins = indexes
+            .clone()
+            .map(|index| {
+                let uncommitted = self.damage[self.committed.1..]
+                    .iter()
+                    .filter(|damage| damage.winner == index)
+                    .count() as u32;
+                self.totals.wins(index) + uncommitted
+            })
+            .collect();
+        let averages = indexes
+            .map(|index| {
+                let last = self.history.iter().rev().find(|turn| turn.player == index);
+                last.map(|turn| turn.average)
+            })
+            .collect();
+        let view = ScoreboardView {
+            round,
+            players: &self.players,
+            wins,
+            averages,
+            finished,
+        };
+        self.renderer.scoreboard(&mut self.output, &view);
+    }
+
+    /// Retourne le niveau de détail des lignes destinées à un joueur (objectifs, menus).
+    ///
+    /// Un joueur humain en a besoin pour jouer, même avec [`Verbosity::Quiet`] ; pour un bot, elles ne
+    /// sont affichées qu'à partir de [`Verbosity::Normal`].
+    fn prompt_level(&self, index: usize) -> Verbosity {
+        Self::controller_level(self.controllers[index].as_ref())
+    }
+
+    /// Retourne le niveau de détail des lignes destinées à un participant selon son contrôleur (voir
+    /// [`Game::prompt_level`]).
+    fn controller_level(controller: &dyn Controller) -> Verbosity {
+        if controller.displays_counter() {
+            Verbosity::Quiet
+        } else {
+            Verbosity::Normal
+        }
+    }
+
+    /// Retourne la force effective d'un joueur pour la manche en cours.
+    ///
+    /// La force effective tient compte de la fatigue lorsque [`GameRules::fatigue`] est activée,
+    /// sans jamais descendre en dessous de zéro.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - L'index du joueur dans `players`.
+    pub fn effective_strength(&self, index: usize) -> u32 {
+        self.players[index].effective_strength(self.rules.fatigue_penalty(self.round))
+    }
+
+    /// Retourne l'état de la partie vu par un joueur, hors arrêt du compteur : celui décrit à son
+    /// contrôleur avant le choix du poison ou la contre-proposition (voir [`Controller::observe`]).
+    pub fn state(&self, index: usize) -> GameState {
+        let player_state = |i: usize| {
+            PlayerState::new(
+                &self.players[i],
+                self.effective_speed(i),
+                self.effective_strength(i),
+            )
+        };
+        GameState {
+            round: self.round,
+            me: player_state(index),
+            opponent: (self.players.len() == 2).then(|| player_state(1 - index)),
+            mutator: self.modifier.map(|modifier| modifier.id().to_string()),
+            stalemate: self.stalemate,
+            objective: None,
+            objective_index: None,
+            misses: self.round_misses[index],
+        }
+    }
+
+    /// Retourne le joueur correspondant à l'identifiant donné.
+    pub fn player(&self, id: PlayerId) -> &Player {
+        &self.players[id.index()]
+    }
+
+    /// Retourne le joueur correspondant à l'identifiant donné, de manière mutable.
+    pub fn player_mut(&mut self, id: PlayerId) -> &mut Player {
+        &mut self.players[id.index()]
+    }
+
+    /// Retourne l'historique de la partie retenu en mémoire (voir [`Game::set_history_limit`]).
+    pub fn retained_history(&self) -> GameHistory<'_> {
+        GameHistory {
+            names: &self.names,
+            turns: &self.history,
+            damage: &self.damage,
+            draws: &self.draws,
+            rounds: &self.rounds,
+        }
+    }
+
+    /// Calcule les statistiques de la partie à partir de son historique (voir
+    /// [`summarize`](crate::stats::summarize)), y compris les manches qui ne sont plus retenues en
+    /// mémoire.
+    pub fn stats(&self) -> GameStats {
+        let (turns, damage, draws, _) = self.committed;
+        let mut totals = self.totals.clone();
+        totals.add_turns(&self.history[turns..]);
+        totals.add_damage(&self.damage[damage..]);
+        totals.add_draws(&self.draws[draws..]);
+        totals.finish(&self.names)
+    }
+
+    /// Retourne l'identifiant du vainqueur si la partie est terminée.
+    ///
+    /// La partie est terminée lorsqu'un seul joueur conserve de la vitalité.
+    pub fn winner(&self) -> Option<PlayerId> {
+        let mut alive = self
+            .players
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.is_alive());
+        match (alive.next(), alive.next()) {
+            (Some((index, _)), None) => Some(PlayerId(index)),
+            _ => None,
+        }
+    }
+
+    /// Propose au perdant de contrer le poison choisi en subissant le double des dégâts de la manche.
+    ///
+    /// # Arguments
+    ///
+    /// * `loser` - L'identifiant du joueur perdant.
+    /// * `poison_type` - Le poison choisi par le gagnant.
+    /// * `diff` - Les dégâts de vitalité déjà infligés lors de la manche.
+    ///
+    /// # Retour
+    ///
+    /// Retourne `true` si le perdant choisit de contrer le poison.
+    fn offer_counter(&mut self, loser: PlayerId, poison_type: &PoisonType, diff: u32) -> bool {
+        if !self.counter_menu(loser, poison_type, diff) {
+            return false;
+        }
+        let state = self.state(loser.index());
+        self.controllers[loser.index()].observe(&state);
+        self.controllers[loser.index()].choose_counter(
+            poison_type,
+            diff,
+            &self.players[loser.index()],
+        )
+    }
+
+    /// Affiche au perdant le menu de la contre-proposition au poison, s'il lui en reste une.
+    ///
+    /// Retourne `true` si le menu a été affiché et que le perdant doit choisir de contrer ou non.
+    pub(crate) fn counter_menu(
+        &mut self,
+        loser: PlayerId,
+        poison_type: &PoisonType,
+        diff: u32,
+    ) -> bool {
+        if self.player(loser).counters_remaining == 0 {
+            return false;
+        }
+        let level = self.prompt_level(loser.index());
+        self.renderer.counter_menu(
+            &mut self.output,
+            level,
+            &self.players[loser.index()],
+            poison_type,
+            diff,
+        );
+        true
+    }
+
+    /// Exécute le tour d’un joueur en traitant chacun des objectifs.
+    ///
+    /// Pour chaque objectif, le contrôleur du joueur arrête le compteur (via une [`CounterSession`]
+    /// lancée pour tout le tour d'un joueur humain). Le score est ensuite calculé en fonction de la
+    /// valeur du compteur, du nombre de "miss" et de la force effective du joueur (voir
+    /// [`Game::effective_strength`]). Le tour du contrôleur est terminé à la fin du tour, même
+    /// interrompu (voir [`Controller::end_turn`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - L'index du joueur dont le tour est en cours.
+    /// * `objectives` - Les objectifs numériques du tour.
+    ///
+    /// # Retour
+    ///
+    /// Retourne un tuple `(score_moyen, scores_détaillés)` :
+    /// - `score_moyen` est le score moyen obtenu lors du tour.
+    /// - `scores_détaillés` est un vecteur contenant les scores de chaque objectif.
+    ///
+    /// [`CounterSession`]: crate::counter::CounterSession
+    pub fn play_turn(
+        &mut self,
+        index: usize,
+        objectives: &[u32],
+    ) -> Result<(u32, Vec<u32>), Box<dyn Error>> {
+        self.play_risked_turn(index, objectives, None)
+    }
+
+    /// Exécute le tour d'un joueur (voir [`Game::play_turn`]), en appliquant au score moyen le
+    /// multiplicateur de la prise de risque choisie, s'il y en a une.
+    fn play_risked_turn(
+        &mut self,
+        index: usize,
+        objectives: &[u32],
+        risk: Option<Risk>,
+    ) -> Result<(u32, Vec<u32>), Box<dyn Error>> {
+        let result = self.play_objectives(index, objectives, risk);
+        self.controllers[index].end_turn();
+        result
+    }
+
+    /// Traite chacun des objectifs du tour d'un joueur (voir [`Game::play_turn`]).
+    fn play_objectives(
+        &mut self,
+        index: usize,
+        objectives: &[u32],
+        risk: Option<Risk>,
+    ) -> Result<(u32, Vec<u32>), Box<dyn Error>> {
+        let mut scores = Vec::with_capacity(objectives.len());
+        let mut stops = Vec::with_capacity(objectives.len());
+
+        // Pour chaque objectif, le contrôleur arrête le compteur.
+        for (obj_index, obj) in objectives.iter().enumerate() {
+            let Some(record) = self.play_single_objective(index, obj_index, *obj) else {
+                // Le tour interrompu n'est ni compté ni consigné dans l'historique.
+                return Ok((0, scores));
+            };
+            scores.push(record.score);
+            stops.push(record);
+        }
+        let average = self.finish_turn(index, stops, risk);
+        Ok((average, scores))
+    }
+
+    /// Termine le tour complet d'un joueur : calcule son score moyen, multiplié par sa prise de risque
+    /// s'il en a choisi une, puis notifie la fin du tour et le consigne dans l'historique.
+    ///
+    /// Retourne le score moyen du tour.
+    pub(crate) fn finish_turn(
+        &mut self,
+        index: usize,
+        stops: Vec<StopRecord>,
+        risk: Option<Risk>,
+    ) -> u32 {
+        let scores: Vec<u32> = stops.iter().map(|stop| stop.score).collect();
+        let average = ScoringCalculator::calculate_average(&scores);
+        let average = risk.map_or(average, |risk| risk.apply(average));
+        self.end_turn_record(index, stops, average, risk);
+        average
+    }
+
+    /// Fait arrêter le compteur sur un objectif par le contrôleur d'un joueur, puis affiche et notifie
+    /// le résultat.
+    ///
+    /// Le tour du contrôleur n'est pas terminé : l'appelant le termine une fois ses objectifs joués
+    /// (voir [`Controller::end_turn`]), après chaque objectif en mode duel.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - L'index du joueur qui arrête le compteur.
+    /// * `obj_index` - La position de l'objectif dans le tour du joueur.
+    /// * `objective` - L'objectif visé.
+    ///
+    /// # Retour
+    ///
+    /// Retourne l'arrêt du compteur et son score, ou `None` si la partie a été interrompue ou si le
+    /// joueur a abandonné.
+    ///
+    /// Avec la règle du rejeu, un joueur qui a un rejeu en réserve peut le dépenser pour rejouer
+    /// aussitôt l'objectif : le second arrêt est retenu, même s'il est moins bon.
+    pub fn play_single_objective(
+        &mut self,
+        index: usize,
+        obj_index: usize,
+        objective: u32,
+    ) -> Option<StopRecord> {
+        let record = self.run_counter(index, obj_index, objective, false)?;
+        if !self.retry_menu(index, &record)
+            || !self.controllers[index].choose_retry(&record, &self.players[index])
+            || self.cancel.is_cancelled()
+        {
+            return Some(record);
+        }
+        self.players[index].retries -= 1;
+        self.run_counter(index, obj_index, objective, true)
+    }
+
+    /// Fait arrêter le compteur une fois sur un objectif (voir [`Game::play_single_objective`]) ;
+    /// `retried` indique qu'il s'agit du rejeu de l'objectif.
+    fn run_counter(
+        &mut self,
+        index: usize,
+        obj_index: usize,
+        objective: u32,
+        retried: bool,
+    ) -> Option<StopRecord> {
+        let view = self.counter_view(index, obj_index, objective);
+        let state = GameState {
+            objective: Some(objective),
+            objective_index: Some(obj_index),
+            ..self.state(index)
+        };
+        let controller = &mut self.controllers[index];
+        controller.observe(&state);
+        let started = self.clock.now();
+        let stop = controller.stop_counter(&view);
+        // Le temps passé dans le menu de pause n'est pas compté dans la durée de l'arrêt.
+        let elapsed = self
+            .clock
+            .now()
+            .saturating_sub(started)
+            .saturating_sub(controller.last_pause());
+        if self.cancel.is_cancelled() || controller.has_forfeited() {
+            return None;
+        }
+        let counter_shown = controller.displays_counter();
+        let record = self.apply_stop(
+            index,
+            obj_index,
+            objective,
+            stop,
+            elapsed,
+            counter_shown,
+            retried,
+        );
+        self.breathe();
+        Some(record)
+    }
+
+    /// Propose à un joueur de rejouer l'objectif qu'il vient de jouer, s'il a un rejeu en réserve et
+    /// que la règle du rejeu est active.
+    ///
+    /// Retourne `true` si la proposition a été affichée et que le joueur doit y répondre.
+    pub(crate) fn retry_menu(&mut self, index: usize, stop: &StopRecord) -> bool {
+        if !self.rules.retry || self.players[index].retries == 0 {
+            return false;
+        }
+        let level = self.prompt_level(index);
+        self.renderer
+            .retry_menu(&mut self.output, level, &self.players[index], stop);
+        true
+    }
+
+    /// Décrit au joueur qui va arrêter le compteur l'objectif visé et le compteur de la manche : sa
+    /// vitesse et son éventuel brouillard selon le mutateur, la force effective et l'endurance du
+    /// joueur.
+    pub(crate) fn counter_view(
+        &self,
+        index: usize,
+        obj_index: usize,
+        objective: u32,
+    ) -> CounterView {
+        CounterView {
+            objective,
+            index: obj_index,
+            speed: self.effective_speed(index),
+            hidden: self
+                .modifier
+                .is_some_and(|modifier| modifier.hides_counter()),
+            strength: self.effective_strength(index),
+            stamina: self.rules.stamina.then_some(self.players[index].stamina),
+            assist: self.players[index].assist,
+            cancel: self.cancel.clone(),
+            rules: self.rules,
+        }
+    }
+
+    /// Applique l'arrêt du compteur d'un joueur : dépense l'endurance utilisée, calcule le score puis
+    /// l'affiche et le notifie, avec l'arrêt du fantôme s'il y en a un.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - L'index du joueur qui a arrêté le compteur.
+    /// * `obj_index` - La position de l'objectif dans le tour du joueur.
+    /// * `objective` - L'objectif visé.
+    /// * `stop` - La valeur du compteur et le nombre de « miss » à l'arrêt.
+    /// * `elapsed` - La durée de l'arrêt.
+    /// * `counter_shown` - Indique si le compteur a été affiché pendant son animation.
+    /// * `retried` - Indique si l'arrêt est celui du rejeu de l'objectif.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn apply_stop(
+        &mut self,
+        index: usize,
+        obj_index: usize,
+        objective: u32,
+        stop: StopDecision,
+        elapsed: Duration,
+        counter_shown: bool,
+        retried: bool,
+    ) -> StopRecord {
+        self.players[index].spend_stamina(stop.stamina_spent);
+        let record = self.score_stop(index, objective, stop, elapsed, counter_shown, retried);
+        self.show_ghost_stop(obj_index);
+        record
+    }
+
+    /// Retourne l'instant présent selon l'horloge de la partie, qui mesure la durée des arrêts.
+    #[cfg(feature = "async")]
+    pub(crate) fn now(&self) -> Duration {
+        self.clock.now()
+    }
+
+    /// Affiche l'arrêt du fantôme sur l'objectif à la même position de l'essai en cours, s'il y en a
+    /// un.
+    fn show_ghost_stop(&mut self, obj_index: usize) {
+        let try_index = self.round.saturating_sub(1) as usize;
+        let Some(ghost) = &self.ghost else {
+            return;
+        };
+        let (Some(stop), Some(diff)) = (
+            ghost.stop(try_index, obj_index),
+            ghost.difference(try_index, obj_index),
+        ) else {
+            return;
+        };
+        let line = tr!(Msg::GhostStop, counter = stop.counter, diff = diff);
+        say!(self, Verbosity::Normal, "{}", line);
+    }
+
+    /// Calcule le score d'un arrêt du compteur, puis l'affiche et le notifie.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - L'index du joueur qui a arrêté le compteur.
+    /// * `objective` - L'objectif visé.
+    /// * `stop` - La valeur du compteur et le nombre de « miss » à l'arrêt.
+    /// * `elapsed` - La durée de l'arrêt.
+    /// * `counter_shown` - Indique si le compteur a été affiché pendant son animation.
+    /// * `retried` - Indique si l'arrêt est celui du rejeu de l'objectif.
+    fn score_stop(
+        &mut self,
+        index: usize,
+        objective: u32,
+        stop: StopDecision,
+        elapsed: Duration,
+        counter_shown: bool,
+        retried: bool,
+    ) -> StopRecord {
+        self.round_times[index] += elapsed;
+        self.round_misses[index] += stop.miss;
+        let strength = self.effective_strength(index);
+        let score =
+            ScoringCalculator::calculate_score(objective, stop.counter_value, stop.miss, strength);
+        log::debug!(
+            "arrêt du compteur player={} objective={objective} counter={} miss={} strength={strength} \
+             score={score} elapsed_ms={} retried={retried}",
+            self.players[index].name,
+            stop.counter_value,
+            stop.miss,
+            elapsed.as_millis()
+        );
+        let record = StopRecord {
+            objective,
+            counter: stop.counter_value,
+            miss: stop.miss,
+            score,
+            retried,
+        };
+        let view = ObjectiveView {
+            player: &self.players[index],
+            strength,
+            stop: record,
+            elapsed,
+            counter_shown,
+            assisted: self.players[index].assist.is_some(),
+        };
+        self.renderer.objective_result(&mut self.output, &view);
+        if let Some(cue) = SoundCue::for_stop(&record)
+            && self.sound.allows(cue, self.clock.now())
+        {
+            self.renderer.sound_cue(&mut self.output, cue);
+        }
+        self.notify(GameEvent::CounterStopped {
+            player: self.players[index].name.clone(),
+            stop: record,
+        });
+        record
+    }
+
+    /// Notifie la fin du tour d'un joueur et le consigne dans l'historique.
+    fn end_turn_record(
+        &mut self,
+        index: usize,
+        stops: Vec<StopRecord>,
+        average: u32,
+        risk: Option<Risk>,
+    ) {
+        self.notify(GameEvent::TurnEnded {
+            player: self.players[index].name.clone(),
+            average,
+        });
+        self.history.push(TurnRecord {
+            round: self.round,
+            player: index,
+            stops,
+            average,
+            risk,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::console::ScriptedConsole;
+    use crate::controller::{ScriptedController, StopDecision};
+    use crate::player::Player;
+
+    /// Crée une partie de deux objectifs par tour entre Alice et Bob (vitalité 1000, vitesse 75,
+    /// force 50), pilotés par des contrôleurs scriptés, avec une horloge manuelle et une console
+    /// qui enregistre l'affichage.
+    ///
+    /// La partie n'est pas démarrée, afin que le test puisse encore ajuster les joueurs.
+    fn scripted_game(
+        rules: GameRules,
+    ) -> (Game, Rc<RefCell<ScriptedConsole>>, [ScriptedController; 2]) {
+        let players = vec![
+            Player::new(String::from("Alice"), 1000, 75, 50),
+            Player::new(String::from("Bob"), 1000, 75, 50),
+        ];
+        let mut game = Game::new(players, 2);
+        game.rules = rules;
+        let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+        game.set_output(Output::new(
+            Box::new(Rc::clone(&console)),
+            Verbosity::Normal,
+        ));
+        game.set_clock(Box::new(ManualClock::new()));
+        let controllers = [ScriptedController::new(), ScriptedController::new()];
+        for (index, controller) in controllers.iter().enumerate() {
+            game.set_controller(index, Box::new(controller.clone()));
+        }
+        (game, console, controllers)
+    }
+
+    /// Vérifie que la création d'une nouvelle partie avec deux joueurs et un nombre d'objectifs donné fonctionne.
+    #[test]
+    fn test_game_new() {
+        let players = vec![
+            Player::new(String::from("Michel"), 50, 50, 50),
+            Player::new(String::from("Jacque"), 50, 50, 50),
+        ];
+        let game = Game::new(players, 5);
+        assert_eq!(game.players.len(), 2);
+        assert_eq!(game.objectifs_count, 5);
+    }
+
+    /// Vérifie que le vainqueur n'est désigné qu'une fois un seul joueur encore en vie.
+    #[test]
+    fn test_game_winner() {
+        let players = vec![
+            Player::new(String::from("Michel"), 50, 50, 50),
+            Player::new(String::from("Jacque"), 50, 50, 50),
+        ];
+        let mut game = Game::new(players, 5);
+        assert_eq!(game.winner(), None);
+        game.players[0].vitality = 0;
+        assert_eq!(game.winner(), Some(PlayerId(1)));
+    }
+
+    /// Vérifie que la fatigue réduit temporairement la force effective, en se cumulant au poison.
+    #[test]
+    fn test_fatigue_effective_strength() {
+        let players = vec![
+            Player::new(String::from("Michel"), 50, 50, 50),
+            Player::new(String::from("Jacque"), 50, 50, 6),
+        ];
+        let mut game = Game::new(players, 5);
+        game.rules.fatigue = true;
+        game.round = 5;
+        assert_eq!(game.effective_strength(0), 50);
+        game.round = 6;
+        assert_eq!(game.effective_strength(0), 48);
+        game.players[0].apply_poison(PoisonType::Strength);
+        assert_eq!(game.effective_strength(0), 43);
+        assert_eq!(game.players[0].strength, 45);
+        game.round = 20;
+        assert_eq!(game.effective_strength(0), 15);
+        assert_eq!(game.effective_strength(1), 0);
+    }
+
+    /// Vérifie qu'une partie entre deux bots se déroule sans aucune intervention.
+    #[test]
+    fn test_bot_game_runs_unattended() {
+        use crate::controller::RandomBot;
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let players = vec![
+            Player::new(String::from("Bot 1"), 30, 75, 50),
+            Player::new(String::from("Bot 2"), 30, 75, 50),
+        ];
+        let mut game = Game::new(players, 3);
+        for index in 0..2 {
+            let rng = StdRng::seed_from_u64(index as u64);
+            game.set_controller(index, Box::new(RandomBot::new(rng)));
+        }
+        game.run().unwrap();
+        assert!(game.winner().is_some());
+        assert!(game.round > 1);
+        // Chaque manche retient l'état des joueurs, et le nom du vainqueur se retrouve par l'en-tête.
+        assert_eq!(game.rounds.len(), game.round as usize - 1);
+        let last = game.rounds.last().unwrap();
+        assert!(last.players.iter().any(|snapshot| snapshot.vitality == 0));
+        let winner = GameResult::Victory {
+            winner: game.winner().unwrap(),
+        };
+        let name = game.player(game.winner().unwrap()).name.as_str();
+        assert_eq!(winner.winner_name(&game.names), Some(name));
+    }
+
+    /// Vérifie qu'un entraînement joue le nombre d'essais demandé et les consigne dans l'historique.
+    #[test]
+    fn test_practice() {
+        use crate::controller::RandomBot;
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let players = vec![Player::new(String::from("Michel"), 50, 75, 50)];
+        let mut game = Game::new(players, 4);
+        game.set_verbosity(Verbosity::Silent);
+        game.set_controller(0, Box::new(RandomBot::new(StdRng::seed_from_u64(1))));
+        let averages = game.practice(3).unwrap();
+        assert_eq!(averages.len(), 3);
+        assert_eq!(game.history.len(), 3);
+        assert!(game.history.iter().all(|turn| turn.stops.len() == 4));
+        assert_eq!(game.players[0].vitality(), 50);
+    }
+
+    /// Vérifie qu'un entraînement scripté affiche l'arrêt du fantôme du fichier d'exemple après
+    /// chaque objectif qu'il a joué, compare les moyennes et que les essais enregistrés forment à leur
+    /// tour un fantôme.
+    #[test]
+    fn test_practice_ghost() {
+        use std::path::Path;
+
+        use crate::history::MemorySink;
+        use crate::replay::Replay;
+
+        let players = vec![Player::new(String::from("Alice"), 50, 75, 50)];
+        let mut game = Game::new(players, 3);
+        game.set_seed(7);
+        let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+        game.set_output(Output::new(
+            Box::new(Rc::clone(&console)),
+            Verbosity::Normal,
+        ));
+        game.set_clock(Box::new(ManualClock::new()));
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/ghost.jsonl");
+        let ghost = Ghost::load(&path).unwrap();
+        game.set_ghost(ghost.clone());
+        let sink = Rc::new(RefCell::new(MemorySink::default()));
+        game.set_history_sink(Box::new(Rc::clone(&sink)));
+        let alice = ScriptedController::new();
+        game.set_controller(0, Box::new(alice.clone()));
+        for _ in 0..9 {
+            alice.push_stop(StopDecision::from_offset(50, 0));
+        }
+
+        let averages = game.practice(3).unwrap();
+        assert_eq!(averages.len(), 3);
+        let output = &console.borrow().output;
+        assert_eq!(output.matches("👻 fantôme : ").count(), 6);
+        assert!(output.contains("👻 fantôme : 43 (diff 1)\n"));
+        assert!(output.contains("👻 fantôme : 77 (diff 8)\n"));
+        let first = format!("Moyenne des essais : {} | fantôme : 124\n", averages[0]);
+        assert!(output.contains(&first));
+        let margin = ghost.margin(&averages).unwrap();
+        let summary = match margin {
+            0 => String::from("Égalité parfaite avec le fantôme."),
+            1.. => format!("Vous battez le fantôme de {margin} points."),
+            _ => format!("Le fantôme vous bat de {} points.", -margin),
+        };
+        assert!(output.ends_with(&format!("{summary}\n")));
+
+        let replay = Replay::from_entries(sink.borrow().entries.clone()).unwrap();
+        assert!(!replay.unfinished);
+        let recorded = Ghost::from_replay(&replay);
+        assert_eq!(recorded.tries(), 3);
+        assert_eq!(recorded.margin(&averages), Some(0));
+    }
+
+    /// Vérifie qu'une partie interrompue en cours de manche s'arrête au point d'arrêt suivant, sans
+    /// compter la manche en cours, et que le tour interrompu est tout de même terminé.
+    #[test]
+    fn test_game_interrupted() {
+        use crate::controller::RandomBot;
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+        use std::cell::Cell;
+
+        /// Bot annulant la partie lors de son n-ième arrêt du compteur.
+        struct CancellingBot {
+            bot: RandomBot,
+            cancel: CancelToken,
+            stops_left: u32,
+            ended_turns: Rc<Cell<u32>>,
+        }
+
+        impl Controller for CancellingBot {
+            fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+                if self.stops_left == 0 {
+                    self.cancel.cancel();
+                }
+                self.stops_left = self.stops_left.saturating_sub(1);
+                self.bot.stop_counter(ctx)
+            }
+
+            fn choose_poison(&mut self, options: &[PoisonType], opponent: &Player) -> usize {
+                self.bot.choose_poison(options, opponent)
+            }
+
+            fn choose_counter(&mut self, poison: &PoisonType, extra: u32, me: &Player) -> bool {
+                self.bot.choose_counter(poison, extra, me)
+            }
+
+            fn acknowledge(&mut self, _msg: &str) {}
+
+            fn end_turn(&mut self) {
+                self.ended_turns.set(self.ended_turns.get() + 1);
+            }
+        }
+
+        let players = vec![
+            Player::new(String::from("Michel"), 500, 75, 50),
+            Player::new(String::from("Jacque"), 500, 75, 50),
+        ];
+        let mut game = Game::new(players, 2);
+        let cancel = CancelToken::new();
+        let ended_turns = Rc::new(Cell::new(0));
+        game.set_cancel_token(cancel.clone());
+        game.set_controller(0, Box::new(RandomBot::new(StdRng::seed_from_u64(1))));
+        game.set_controller(
+            1,
+            Box::new(CancellingBot {
+                bot: RandomBot::new(StdRng::seed_from_u64(2)),
+                cancel,
+                stops_left: 3,
+                ended_turns: Rc::clone(&ended_turns),
+            }),
+        );
+
+        assert_eq!(game.run().unwrap(), GameResult::Interrupted);
+        assert_eq!(game.round, 2);
+        // Manche 1 complète, puis seul le tour de Michel en manche 2.
+        assert_eq!(game.history.len(), 3);
+        let totals: u32 = game.history[..2].iter().map(|turn| turn.average).sum();
+        assert_eq!(game.total_scores.iter().sum::<u32>(), totals);
+        assert_eq!(GameResult::Interrupted.winner(), None);
+        // Le tour de Jacque en manche 1, puis son tour interrompu en manche 2.
+        assert_eq!(ended_turns.get(), 2);
+    }
+
+    /// Vérifie, en capturant l'affichage, les lignes retenues à chaque niveau de détail.
+    #[test]
+    fn test_verbosity_levels() {
+        use crate::controller::RandomBot;
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let play = |verbosity: Verbosity| {
+            let players = vec![
+                Player::new(String::from("Bot 1"), 20, 75, 50),
+                Player::new(String::from("Bot 2"), 20, 75, 50),
+            ];
+            let mut game = Game::new(players, 3);
+            game.set_seed(3);
+            for index in 0..2 {
+                let rng = StdRng::seed_from_u64(index as u64);
+                game.set_controller(index, Box::new(RandomBot::new(rng)));
+            }
+            let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+            game.set_output(Output::new(Box::new(Rc::clone(&console)), verbosity));
+            game.run().unwrap();
+            console.borrow().output.clone()
+        };
+
+        let silent = play(Verbosity::Silent);
+        assert!(silent.is_empty(), "{silent}");
+
+        let quiet = play(Verbosity::Quiet);
+        assert!(quiet.contains("gagne la manche"), "{quiet}");
+        assert!(quiet.contains("Le vainqueur est"), "{quiet}");
+        assert!(!quiet.contains("## Manche 1 ##"), "{quiet}");
+        assert!(!quiet.contains("→ Objectifs"), "{quiet}");
+
+        let normal = play(Verbosity::Normal);
+        assert!(normal.contains("##### Démarrage de la partie #####"));
+        assert!(normal.contains("## Manche 1 ##"));
+        assert!(normal.contains("→ Objectifs"));
+        assert!(!normal.contains("↳ Objectif"), "{normal}");
+
+        let verbose = play(Verbosity::Verbose);
+        assert!(verbose.contains("↳ Objectif"), "{verbose}");
+        assert!(verbose.contains("écart"));
+        assert!(verbose.lines().count() > normal.lines().count());
+    }
+
+    /// Vérifie sur des parties simulées qu'un bot difficile bat largement un bot facile.
+    #[test]
+    fn test_hard_bot_beats_easy_bot() {
+        use crate::controller::{BotController, Difficulty};
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let games = 40;
+        let mut hard_wins = 0;
+        for seed in 0..games {
+            // Les bots alternent de place pour ne pas avantager le premier joueur.
+            let hard_index = (seed % 2) as usize;
+            let players = vec![
+                Player::new(String::from("Bot 1"), 50, 75, 50),
+                Player::new(String::from("Bot 2"), 50, 75, 50),
+            ];
+            let mut game = Game::new(players, 5);
+            for index in 0..2 {
+                let difficulty = if index == hard_index {
+                    Difficulty::Hard
+                } else {
+                    Difficulty::Easy
+                };
+                let rng = StdRng::seed_from_u64(seed * 2 + index as u64);
+                game.set_controller(index, Box::new(BotController::with_rng(difficulty, rng)));
+            }
+            game.run().unwrap();
+            if game.winner() == Some(PlayerId(hard_index)) {
+                hard_wins += 1;
+            }
+        }
+        assert!(hard_wins * 4 >= games * 3, "{hard_wins}/{games}");
+    }
+
+    /// Vérifie qu'un hôte peut rythmer la partie manche par manche en fournissant les arrêts du
+    /// compteur, et qu'un joueur sans arrêt prévu abandonne.
+    #[test]
+    fn test_play_round_scripted() {
+        let (mut game, _console, [alice, bob]) = scripted_game(GameRules::default());
+        game.players[1].strength = 0;
+        game.start();
+
+        for _ in 0..2 {
+            alice.push_stop(StopDecision::from_offset(50, 0));
+            bob.push_stop(StopDecision::from_offset(50, 303));
+        }
+        alice.push_poison(0);
+        bob.push_counter(false);
+        assert_eq!(game.play_round().unwrap(), None);
+        assert_eq!(alice.pending_stops(), 0);
+        assert_eq!(game.players[0].vitality(), 1000);
+        assert!(game.players[1].vitality() < 1000);
+        assert_eq!(game.history.len(), 2);
+
+        assert_eq!(
+            game.play_round().unwrap(),
+            Some(GameResult::Forfeit {
+                forfeited: PlayerId(0),
+                winner: PlayerId(1),
+            })
+        );
+    }
+
+    /// Vérifie la boutique : chaque joueur gagne des pièces selon son score moyen, puis achète un objet
+    /// après le poison, l'achat étant refusé faute de pièces.
+    #[test]
+    fn test_shop_round() {
+        let rules = GameRules {
+            shop: true,
+            ..GameRules::default()
+        };
+        let (mut game, console, [alice, bob]) = scripted_game(rules);
+        game.players[0].strength = 200;
+        game.players[1].strength = 0;
+        game.players[1].coins = 10;
+        game.start();
+
+        for _ in 0..2 {
+            alice.push_stop(StopDecision::from_offset(50, 0));
+            bob.push_stop(StopDecision::from_offset(50, 303));
+        }
+        alice.push_poison(0);
+        bob.push_counter(false);
+        alice.push_item(Some(0));
+        bob.push_item(Some(2));
+        assert_eq!(game.play_round().unwrap(), None);
+
+        let earned: Vec<u32> = game
+            .history
+            .iter()
+            .map(|turn| coins_earned(turn.average))
+            .collect();
+        assert!(earned[0] >= 20);
+        assert_eq!(game.players[0].coins, earned[0] - 15);
+        assert_eq!(game.players[0].strength, 205);
+        assert_eq!(game.players[1].coins, 10 + earned[1]);
+        assert_eq!(game.players[1].poisons, [PoisonType::Speed]);
+
+        let output = &console.borrow().output;
+        assert!(output.contains("Pièces : Alice 0 · Bob 10\n"));
+        assert!(output.contains("achète Aiguisage (+5 strength)"));
+        assert!(output.contains("ne peut pas acheter Potion : 20 pièces nécessaires"));
+    }
+
+    /// Vérifie que la prise de risque choisie fixe le nombre d'objectifs du tour, multiplie le score
+    /// moyen et est consignée dans l'historique ; un choix hors limites donne un tour normal.
+    #[test]
+    fn test_risk_choice_round() {
+        let rules = GameRules {
+            risk_choice: true,
+            ..GameRules::default()
+        };
+        let (mut game, console, [alice, bob]) = scripted_game(rules);
+        game.objectifs_count = 5;
+        game.start();
+
+        alice.push_risk(0);
+        bob.push_risk(2);
+        for _ in 0..7 {
+            alice.push_stop(StopDecision::from_offset(50, 0));
+            bob.push_stop(StopDecision::from_offset(50, 0));
+        }
+        alice.push_poison(0);
+        bob.push_poison(0);
+        assert_eq!(game.play_round().unwrap(), None);
+        assert_eq!(alice.pending_stops(), 4);
+        assert_eq!(bob.pending_stops(), 0);
+
+        let turns: Vec<(usize, Option<Risk>)> = game
+            .history
+            .iter()
+            .map(|turn| (turn.stops.len(), turn.risk))
+            .collect();
+        assert_eq!(turns, [(3, Some(Risk::Safe)), (7, Some(Risk::Risky))]);
+        for turn in &game.history {
+            let scores: Vec<u32> = turn.stops.iter().map(|stop| stop.score).collect();
+            let average = ScoringCalculator::calculate_average(&scores);
+            assert_eq!(turn.average, turn.risk.unwrap().apply(average));
+        }
+        assert!(
+            console
+                .borrow()
+                .output
+                .contains("→ 3: Risqué — 7 objectifs, moyenne à 115 %")
+        );
+
+        alice.clear();
+        bob.clear();
+        alice.push_risk(usize::MAX);
+        for _ in 0..5 {
+            alice.push_stop(StopDecision::from_offset(50, 0));
+            bob.push_stop(StopDecision::from_offset(50, 0));
+        }
+        alice.push_poison(0);
+        bob.push_poison(0);
+        assert_eq!(game.play_round().unwrap(), None);
+        assert_eq!(game.history[2].stops.len(), 5);
+        assert_eq!(game.history[2].risk, Some(Risk::Normal));
+        assert_eq!(game.history[3].risk, Some(Risk::Normal));
+    }
+
+    /// Vérifie qu'une manche en duel alterne les arrêts des joueurs sur des objectifs communs, attribue
+    /// un point par objectif au meilleur score et inflige l'écart de points multiplié par le facteur.
+    #[test]
+    fn test_duel_round() {
+        let rules = GameRules {
+            mode: GameMode::Duel,
+            ..GameRules::default()
+        };
+        let (mut game, console, [alice, bob]) = scripted_game(rules);
+        game.objectifs_count = 3;
+        game.set_seed(7);
+        game.start();
+
+        // Sans dépassement, Alice marque au moins sa force ; après mille dépassements, 0 point.
+        let scoring = StopDecision {
+            counter_value: 0,
+            miss: 0,
+            stamina_spent: 0,
+        };
+        let hopeless = StopDecision::from_offset(50, 101 * 1000);
+        for (alice_stop, bob_stop) in [
+            (scoring, hopeless),
+            (hopeless, hopeless),
+            (scoring, hopeless),
+        ] {
+            alice.push_stop(alice_stop);
+            bob.push_stop(bob_stop);
+        }
+        alice.push_poison(0);
+        bob.push_counter(false);
+        assert_eq!(game.play_round().unwrap(), None);
+
+        // Deux points d'écart, à 10 points de vitalité chacun.
+        assert_eq!(game.players[0].vitality(), 1000);
+        assert_eq!(game.players[1].vitality(), 980);
+        assert_eq!(game.history.len(), 2);
+        let [alice_turn, bob_turn] = [&game.history[0], &game.history[1]];
+        assert_eq!(alice_turn.stops.len(), 3);
+        for (alice_stop, bob_stop) in alice_turn.stops.iter().zip(&bob_turn.stops) {
+            assert_eq!(alice_stop.objective, bob_stop.objective);
+        }
+        assert_eq!(bob_turn.average, 0);
+
+        let output = console.borrow().output.clone();
+        assert!(output.contains("⚔ Objectif 1/3 : "));
+        assert!(output.contains("Point pour Alice (1 - 0)."));
+        assert!(output.contains("Scores égaux, aucun point (1 - 0)."));
+        assert!(output.contains("Point pour Alice (2 - 0)."));
+        assert!(output.contains("Bob perd 20 points de vitalité"));
+    }
+
+    /// Vérifie une manche en mode partagé : chaque verrou est compté sur l'objectif commun, un joueur
+    /// qui n'a pas verrouillé l'est à l'écart maximal, et les scores moyens sont comparés.
+    #[test]
+    fn test_shared_round() {
+        use crate::console::NullConsole;
+        use crate::shared::{SHARED_MISS_CAP, SharedLocks, SharedView};
+
+        /// Compteur partagé sur lequel seul le premier joueur verrouille, exactement sur l'objectif.
+        struct FirstLocksOnly;
+
+        impl SharedCounter for FirstLocksOnly {
+            fn run(&mut self, view: &SharedView) -> SharedLocks {
+                assert_eq!(view.names, ["Alice", "Bob"]);
+                assert_eq!(view.speed, 70);
+                let mut locks = SharedLocks::default();
+                locks.press('a', view.objective, 0);
+                locks
+            }
+        }
+
+        let players = vec![
+            Player::new(String::from("Alice"), 1000, 60, 50),
+            Player::new(String::from("Bob"), 1000, 80, 50),
+        ];
+        let mut game = Game::new(players, 2);
+        game.rules.mode = GameMode::Shared;
+        game.set_output(Output::new(Box::new(NullConsole), Verbosity::Silent));
+        game.set_clock(Box::new(ManualClock::new()));
+        game.set_shared_counter(Box::new(FirstLocksOnly));
+        let alice = ScriptedController::new();
+        let bob = ScriptedController::new();
+        game.set_controller(0, Box::new(alice.clone()));
+        game.set_controller(1, Box::new(bob.clone()));
+        game.start();
+
+        alice.push_poison(0);
+        bob.push_counter(false);
+        assert_eq!(game.play_round().unwrap(), None);
+
+        let [alice_turn, bob_turn] = [&game.history[0], &game.history[1]];
+        assert_eq!(alice_turn.stops.len(), 2);
+        for (alice_stop, bob_stop) in alice_turn.stops.iter().zip(&bob_turn.stops) {
+            assert_eq!(alice_stop.counter, alice_stop.objective);
+            assert_eq!(bob_stop.objective, alice_stop.objective);
+            assert_eq!(
+                ScoringCalculator::difference(bob_stop.objective, bob_stop.counter),
+                50
+            );
+            assert_eq!(bob_stop.miss, SHARED_MISS_CAP);
+        }
+        let diff = alice_turn.average - bob_turn.average;
+        assert_eq!(game.players[1].vitality(), 1000 - diff);
+    }
+
+    /// Vérifie qu'une suite de manches nulles est enregistrée dans l'historique, les statistiques et la
+    /// rediffusion, puis mène à des manches décisives sur un seul objectif jusqu'à ce qu'un joueur en
+    /// remporte une.
+    #[test]
+    fn test_draws_lead_to_tiebreaker() {
+        use crate::history::MemorySink;
+        use crate::replay::Replay;
+
+        let (mut game, console, [alice, bob]) = scripted_game(GameRules::default());
+        game.set_output(Output::new(Box::new(Rc::clone(&console)), Verbosity::Quiet));
+        let sink = Rc::new(RefCell::new(MemorySink::default()));
+        game.set_history_sink(Box::new(Rc::clone(&sink)));
+        game.start();
+
+        // Après mille dépassements, chaque objectif rapporte 0 point : toutes les manches sont nulles.
+        let hopeless = StopDecision::from_offset(50, 101 * 1000);
+        for round in 1..=TIEBREAKER_AFTER_DRAWS + 1 {
+            let count = if round > TIEBREAKER_AFTER_DRAWS { 1 } else { 2 };
+            for _ in 0..count {
+                alice.push_stop(hopeless);
+                bob.push_stop(hopeless);
+            }
+            assert_eq!(game.play_round().unwrap(), None);
+        }
+        assert_eq!(alice.pending_stops(), 0);
+        let stops: Vec<usize> = game.history.iter().map(|turn| turn.stops.len()).collect();
+        assert_eq!(stops, [2, 2, 2, 2, 2, 2, 1, 1]);
+        let consecutive: Vec<u32> = game.draws.iter().map(|draw| draw.consecutive).collect();
+        assert_eq!(consecutive, [1, 2, 3, 4]);
+        assert!(game.players.iter().all(|player| player.vitality() == 1000));
+        let output = console.borrow().output.clone();
+        assert_eq!(output.matches(&tr!(Msg::ScoreTie)).count(), 4);
+        assert!(output.contains(&tr!(Msg::TiebreakerNext, draws = 3)));
+
+        // Alice remporte la manche décisive ; la manche suivante reprend ses deux objectifs.
+        alice.push_stop(StopDecision::from_offset(50, 0));
+        bob.push_stop(hopeless);
+        alice.push_poison(0);
+        bob.push_counter(false);
+        assert_eq!(game.play_round().unwrap(), None);
+        assert!(game.players[1].vitality() < 1000);
+        for _ in 0..2 {
+            alice.push_stop(hopeless);
+            bob.push_stop(hopeless);
+        }
+        assert_eq!(game.play_round().unwrap(), None);
+        assert_eq!(game.history.last().unwrap().stops.len(), 2);
+        assert_eq!(game.draws.last().unwrap().consecutive, 1);
+
+        let stats = game.stats();
+        assert!(stats.players.iter().all(|player| player.draws == 5));
+        let replay = Replay::from_game(&game);
+        assert_eq!(replay.draws, game.draws);
+        assert!(replay.to_string().contains("Égalité à 0, aucune pénalité"));
+        let entries = sink.borrow().entries.clone();
+        assert_eq!(Replay::from_entries(entries).unwrap().draws, game.draws);
+    }
+
+    /// Vérifie que l'assistance d'un joueur parvient à son compteur et signale ses résultats : chaque
+    /// objectif dans le rendu JSON, et son score moyen « (assisté) » dans le rendu habituel.
+    #[test]
+    fn test_assist_flag_in_results() {
+        use crate::renderer::JsonRenderer;
+
+        let play = |json: bool| {
+            let (mut game, console, [alice, bob]) = scripted_game(GameRules::default());
+            game.players[0].assist = Some(60);
+            if json {
+                game.set_renderer(Box::new(JsonRenderer));
+            }
+            game.start();
+            for _ in 0..2 {
+                alice.push_stop(StopDecision::from_offset(50, 0));
+                bob.push_stop(StopDecision::from_offset(50, 20));
+            }
+            // Le vainqueur de la manche, quel qu'il soit, choisit le premier poison.
+            alice.push_poison(0);
+            bob.push_poison(0);
+            assert_eq!(game.play_round().unwrap(), None);
+            console.borrow().output.clone()
+        };
+
+        let assisted: Vec<(String, bool)> = play(true)
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .filter(|event| event["event"] == "objective")
+            .map(|event| {
+                let player = event["player"].as_str().unwrap().to_string();
+                (player, event["assisted"].as_bool().unwrap())
+            })
+            .collect();
+        assert_eq!(
+            assisted,
+            [
+                (String::from("Alice"), true),
+                (String::from("Alice"), true),
+                (String::from("Bob"), false),
+                (String::from("Bob"), false),
+            ]
+        );
+
+        let output = play(false);
+        let tagged: Vec<&str> = output
+            .lines()
+            .filter(|line| line.contains("(assisté)"))
+            .collect();
+        assert_eq!(tagged.len(), 1);
+        assert!(tagged[0].starts_with("→ Score moyen: "));
+    }
+
+    /// Vérifie qu'une partie en mode miroir est créée avec les caractéristiques échangées, annoncées
+    /// avant un aperçu du duel qui les reflète, et qu'elle exige deux joueurs.
+    #[test]
+    fn test_mirror_game() {
+        use crate::render;
+
+        let players = vec![
+            Player::new(String::from("Alice"), 50, 40, 70),
+            Player::new(String::from("Bob"), 60, 90, 30),
+        ];
+        let rules = GameRules {
+            mirror: true,
+            ..GameRules::default()
+        };
+        let mut game = Game::new_with_rules(players.clone(), 3, rules).unwrap();
+        assert_eq!(game.rules, rules);
+        let stats: Vec<(u32, u32, u32)> = game
+            .players
+            .iter()
+            .map(|player| (player.vitality(), player.speed, player.strength))
+            .collect();
+        assert_eq!(stats, [(50, 90, 30), (60, 40, 70)]);
+
+        let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+        game.set_output(Output::new(
+            Box::new(Rc::clone(&console)),
+            Verbosity::Normal,
+        ));
+        game.start();
+        let output = console.borrow().output.clone();
+        let notice = output
+            .find("Mode miroir : Alice et Bob échangent leur vitesse et leur force")
+            .unwrap();
+        let preview = output
+            .find(&render::matchup(&game.players[0], &game.players[1]))
+            .unwrap();
+        assert!(notice < preview);
+
+        let plain = Game::new_with_rules(players.clone(), 3, GameRules::default()).unwrap();
+        assert_eq!(plain.players[0].speed, 40);
+        let solo = vec![players[0].clone()];
+        let mismatch = SetupError::Mirror(crate::rules::MirrorError::PlayerCount(1));
+        assert_eq!(Game::new_with_rules(solo, 3, rules).err(), Some(mismatch));
+    }
+
+    /// Vérifie la limite du nombre d'objectifs par tour, relevée par les règles.
+    #[test]
+    fn test_objectives_limit() {
+        use crate::rules::DEFAULT_MAX_OBJECTIVES;
+
+        let players = vec![
+            Player::new(String::from("Alice"), 50, 50, 50),
+            Player::new(String::from("Bob"), 50, 50, 50),
+        ];
+        let count = DEFAULT_MAX_OBJECTIVES + 1;
+        assert_eq!(
+            Game::new_with_rules(players.clone(), count, GameRules::default()).err(),
+            Some(SetupError::TooManyObjectives {
+                count,
+                limit: DEFAULT_MAX_OBJECTIVES
+            })
+        );
+        let rules = GameRules {
+            max_objectives: count,
+            ..GameRules::default()
+        };
+        let game = Game::new_with_rules(players.clone(), count, rules).unwrap();
+        assert_eq!(game.objectifs_count, count);
+        // Une sauvegarde reprend avec sa limite relevée.
+        assert_eq!(Game::from_save(&game.save_state()).objectifs_count, count);
+        // Sans règles, le nombre d'objectifs est ramené à la limite par défaut.
+        assert_eq!(
+            Game::new(players, count).objectifs_count,
+            DEFAULT_MAX_OBJECTIVES
+        );
+    }
+
+    /// Vérifie les signaux sonores transmis au rendu : arrêts parfaits, dépassements espacés d'au
+    /// moins [`MISS_CUE_COOLDOWN`] et fin de partie.
+    #[test]
+    fn test_sound_cues() {
+        use crate::renderer::JsonRenderer;
+
+        // Une partie jumelle, de même graine, révèle le premier objectif d'Alice.
+        let (mut twin, _, _) = scripted_game(GameRules::default());
+        twin.set_seed(5);
+        let objective = twin.generate_objectives(0)[0];
+
+        let (mut game, console, [alice, bob]) = scripted_game(GameRules::default());
+        game.set_seed(5);
+        game.set_output(Output::new(Box::new(Rc::clone(&console)), Verbosity::Quiet));
+        game.set_renderer(Box::new(JsonRenderer));
+        let clock = ManualClock::new();
+        game.set_clock(Box::new(clock.clone()));
+        game.set_sound(true);
+        game.start();
+
+        // Les dépassements de Bob suivent de trop près celui d'Alice.
+        alice.push_stop(StopDecision::from_offset(objective, 0));
+        alice.push_stop(StopDecision::from_offset(50, 101));
+        bob.push_stop(StopDecision::from_offset(50, 202));
+        bob.push_stop(StopDecision::from_offset(50, 202));
+        alice.push_poison(0);
+        bob.push_counter(false);
+        assert_eq!(game.play_round().unwrap(), None);
+
+        // Une seconde plus tard, le dépassement d'Alice sonne, puis elle abandonne.
+        clock.advance(Duration::from_secs(1));
+        alice.push_stop(StopDecision::from_offset(50, 303));
+        assert!(game.play_round().unwrap().is_some());
+
+        let cues: Vec<String> = console
+            .borrow()
+            .output
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .filter(|event| event["event"] == "sound")
+            .map(|event| event["cue"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(cues, ["perfect", "miss", "miss", "game_over"]);
+
+        // Sans `set_sound`, aucun signal n'est émis.
+        let mut sound = Sound::default();
+        assert!(!sound.allows(SoundCue::Perfect, Duration::ZERO));
+    }
+
+    /// Vérifie que la durée d'un arrêt exclut le temps que le contrôleur a passé en pause.
+    #[test]
+    fn test_pause_excluded_from_elapsed() {
+        use crate::poison::PoisonType;
+        use crate::renderer::JsonRenderer;
+
+        /// Joueur restant 7 secondes sur l'objectif, dont 5 en pause.
+        struct PausingController(ManualClock);
+
+        impl Controller for PausingController {
+            fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+                self.0.advance(Duration::from_secs(7));
+                StopDecision::from_offset(ctx.objective, 0)
+            }
+
+            fn choose_poison(&mut self, _options: &[PoisonType], _opponent: &Player) -> usize {
+                0
+            }
+
+            fn choose_counter(&mut self, _poison: &PoisonType, _extra: u32, _me: &Player) -> bool {
+                false
+            }
+
+            fn last_pause(&self) -> Duration {
+                Duration::from_secs(5)
+            }
+
+            fn acknowledge(&mut self, _msg: &str) {}
+        }
+
+        let players = vec![
+            Player::new(String::from("Alice"), 1000, 75, 50),
+            Player::new(String::from("Bob"), 1000, 75, 50),
+        ];
+        let mut game = Game::new(players, 1);
+        let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+        game.set_output(Output::new(Box::new(Rc::clone(&console)), Verbosity::Quiet));
+        game.set_renderer(Box::new(JsonRenderer));
+        let clock = ManualClock::new();
+        game.set_clock(Box::new(clock.clone()));
+        game.set_controller(0, Box::new(PausingController(clock)));
+
+        let stop = game.play_single_objective(0, 0, 40).unwrap();
+        assert_eq!(stop.counter, 40);
+        let event: serde_json::Value =
+            serde_json::from_str(console.borrow().output.lines().next().unwrap()).unwrap();
+        assert_eq!(event["event"], "objective");
+        assert_eq!(event["elapsed_ms"], 2000);
+    }
+
+    /// Vérifie que, mutateurs activés, un mutateur est tiré et annoncé au début de chaque manche puis
+    /// consigné avec elle.
+    #[test]
+    fn test_round_mutators_drawn() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        use crate::controller::RandomBot;
+        use crate::renderer::JsonRenderer;
+
+        let players = vec![
+            Player::new(String::from("Alice"), 1000, 75, 50),
+            Player::new(String::from("Bob"), 1000, 75, 50),
+        ];
+        let mut game = Game::new(players, 3);
+        game.rules.mutators = true;
+        game.set_seed(11);
+        let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+        game.set_output(Output::new(Box::new(Rc::clone(&console)), Verbosity::Quiet));
+        game.set_renderer(Box::new(JsonRenderer));
+        game.set_clock(Box::new(ManualClock::new()));
+        for index in 0..2 {
+            let rng = StdRng::seed_from_u64(index as u64);
+            game.set_controller(index, Box::new(RandomBot::new(rng)));
+        }
+        game.start();
+        for _ in 0..4 {
+            assert_eq!(game.play_round().unwrap(), None);
+        }
+
+        let recorded: Vec<&str> = game
+            .rounds
+            .iter()
+            .map(|record| record.mutator.as_deref().unwrap())
+            .collect();
+        assert_eq!(recorded.len(), 4);
+        assert!(recorded.iter().all(|id| mutators::find(id).is_some()));
+        let announced: Vec<String> = console
+            .borrow()
+            .output
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .filter(|event| event["event"] == "mutator")
+            .map(|event| event["id"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(announced, recorded);
+    }
+
+    /// Vérifie les effets du mutateur de la manche : objectifs ramenés sous la gravité, compteur caché
+    /// et accéléré, dégâts doublés par le pactole ; le mutateur ne dure que la manche.
+    #[test]
+    fn test_round_modifier_effects() {
+        use crate::console::NullConsole;
+        use crate::mutators::{Fog, Gravity, Jackpot, Storm};
+
+        /// Contrôleur retenant la vitesse et l'affichage de chaque compteur lancé.
+        struct Watcher(Rc<RefCell<Vec<(u32, bool)>>>);
+
+        impl Controller for Watcher {
+            fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+                self.0.borrow_mut().push((ctx.speed, ctx.hidden));
+                StopDecision::from_offset(ctx.objective, 0)
+            }
+
+            fn choose_poison(&mut self, _options: &[PoisonType], _opponent: &Player) -> usize {
+                0
+            }
+
+            fn choose_counter(&mut self, _poison: &PoisonType, _extra: u32, _me: &Player) -> bool {
+                false
+            }
+
+            fn acknowledge(&mut self, _msg: &str) {}
+        }
+
+        let players = vec![
+            Player::new(String::from("Alice"), 1000, 60, 50),
+            Player::new(String::from("Bob"), 1000, 60, 50),
+        ];
+        let mut game = Game::new(players, 5);
+        game.set_seed(3);
+        game.set_output(Output::new(Box::new(NullConsole), Verbosity::Silent));
+        game.set_clock(Box::new(ManualClock::new()));
+
+        game.modifier = Some(&Gravity);
+        for _ in 0..20 {
+            let objectives = game.generate_objectives(0);
+            assert!(
+                objectives
+                    .iter()
+                    .all(|&objective| objective <= Gravity::MAX_OBJECTIVE)
+            );
+        }
+
+        let views = Rc::new(RefCell::new(Vec::new()));
+        game.set_controller(0, Box::new(Watcher(Rc::clone(&views))));
+        game.modifier = Some(&Fog);
+        game.play_single_objective(0, 0, 40).unwrap();
+        game.modifier = Some(&Storm);
+        game.play_single_objective(0, 0, 40).unwrap();
+        game.modifier = None;
+        game.play_single_objective(0, 0, 40).unwrap();
+        assert_eq!(*views.borrow(), [(60, true), (50, false), (60, false)]);
+
+        let alice = ScriptedController::new();
+        let bob = ScriptedController::new();
+        game.set_controller(0, Box::new(alice.clone()));
+        game.set_controller(1, Box::new(bob.clone()));
+        game.start();
+        for _ in 0..5 {
+            alice.push_stop(StopDecision::from_offset(50, 0));
+            bob.push_stop(StopDecision::from_offset(50, 30));
+        }
+        alice.push_poison(0);
+        bob.push_counter(false);
+        game.modifier = Some(&Jackpot);
+        assert_eq!(game.play_round().unwrap(), None);
+
+        let [first, second] = &game.history[..] else {
+            panic!("deux tours attendus");
+        };
+        let base = game.rules.round_damage(first.average, second.average);
+        assert_eq!(game.damage[0].dealt, base * 2);
+        assert_eq!(game.rounds[0].mutator.as_deref(), Some("jackpot"));
+        assert!(game.modifier.is_none());
+    }
+
+    /// Vérifie les interdictions : le joueur qui a le moins de vitalité interdit un poison, retiré du
+    /// menu du vainqueur, et personne n'est sollicité lorsque les vitalités sont égales.
+    #[test]
+    fn test_ban_round() {
+        let rules = GameRules {
+            bans: true,
+            ..GameRules::default()
+        };
+        let (mut game, console, [alice, bob]) = scripted_game(rules);
+        game.start();
+
+        // Vitalités égales : aucune interdiction n'est proposée.
+        bob.push_ban(Some(1));
+        for _ in 0..2 {
+            alice.push_stop(StopDecision::from_offset(50, 0));
+            bob.push_stop(StopDecision::from_offset(50, 303));
+        }
+        alice.push_poison(0);
+        assert_eq!(game.play_round().unwrap(), None);
+        assert_eq!(game.players[1].poisons, [PoisonType::Speed]);
+        assert!(!console.borrow().output.contains("quel poison interdire"));
+
+        // Bob, moins vital, interdit la vitesse : le premier choix d'Alice est alors la force.
+        bob.clear();
+        bob.push_ban(Some(0));
+        for _ in 0..2 {
+            alice.push_stop(StopDecision::from_offset(50, 0));
+            bob.push_stop(StopDecision::from_offset(50, 303));
+        }
+        alice.push_poison(0);
+        assert_eq!(game.play_round().unwrap(), None);
+        assert_eq!(
+            game.players[1].poisons,
+            [PoisonType::Speed, PoisonType::Strength]
+        );
+        assert!(game.ban.is_none());
+        let output = &console.borrow().output;
+        assert!(output.contains("Bob, quel poison interdire pour cette manche ?"));
+        assert!(output.contains("🚫 Bob interdit le poison -5 speed pour cette manche."));
+        assert!(output.contains("→ 1: -5 strength\n"));
+    }
+
+    /// Vérifie la règle du rejeu : le perdant d'une manche gagne un rejeu, qu'il dépense pour
+    /// rejouer un objectif dont le second arrêt est retenu, même moins bon.
+    #[test]
+    fn test_retry_round() {
+        let rules = GameRules {
+            retry: true,
+            ..GameRules::default()
+        };
+        let (mut game, console, [alice, bob]) = scripted_game(rules);
+        game.set_seed(5);
+        game.start();
+
+        // Sans rejeu en réserve, aucune proposition n'est faite.
+        bob.push_retry(true);
+        for _ in 0..2 {
+            alice.push_stop(StopDecision::from_offset(50, 0));
+            bob.push_stop(StopDecision::from_offset(50, 303));
+        }
+        alice.push_poison(0);
+        assert_eq!(game.play_round().unwrap(), None);
+        assert_eq!(game.players[1].retries, 1);
+        assert_eq!(game.players[0].retries, 0);
+        assert!(!console.borrow().output.contains("rejouer cet objectif"));
+
+        // Bob dépense son rejeu sur le premier objectif : le second arrêt, moins bon, est retenu.
+        bob.clear();
+        bob.push_retry(true);
+        alice.push_stop(StopDecision::from_offset(50, 0));
+        alice.push_stop(StopDecision::from_offset(50, 0));
+        bob.push_stop(StopDecision::from_offset(50, 10));
+        bob.push_stop(StopDecision::from_offset(50, 400));
+        bob.push_stop(StopDecision::from_offset(50, 0));
+        alice.push_poison(0);
+        assert_eq!(game.play_round().unwrap(), None);
+        assert_eq!(bob.pending_stops(), 0);
+        let turn = game.history.last().unwrap();
+        assert_eq!(turn.player, 1);
+        assert!(turn.stops[0].retried);
+        assert!(!turn.stops[1].retried);
+        assert_eq!(turn.stops.len(), 2);
+        // Bob a perdu la manche et regagne un rejeu, sans en cumuler plus d'un.
+        assert_eq!(game.players[1].retries, 1);
+        let output = &console.borrow().output;
+        assert!(output.contains("↺ Bob, rejouer cet objectif"));
+        assert!(output.contains("↺ Objectif rejoué"));
+    }
+
+    /// Vérifie l'anti-stagnation : des manches à 1 point de dégâts l'activent, les manches perdues
+    /// coûtent alors au moins 5 points malgré l'armure, et une manche de gros dégâts la lève.
+    #[test]
+    fn test_stalemate_escalation() {
+        let (mut game, console, [alice, bob]) = scripted_game(GameRules::default());
+        game.set_seed(5);
+        // L'armure de Bob réduit chaque défaite au point de dégâts minimal.
+        game.players[1].armor = 1000;
+        game.start();
+        let play_round = |game: &mut Game| {
+            for _ in 0..2 {
+                alice.push_stop(StopDecision::from_offset(50, 0));
+                bob.push_stop(StopDecision::from_offset(50, 303));
+            }
+            alice.push_poison(0);
+            assert_eq!(game.play_round().unwrap(), None);
+            game.damage.last().unwrap().dealt
+        };
+
+        for _ in 0..5 {
+            assert_eq!(play_round(&mut game), 1);
+        }
+        assert!(!console.borrow().output.contains("Anti-stagnation"));
+        assert_eq!(play_round(&mut game), STALEMATE_MIN_DAMAGE);
+        assert!(console.borrow().output.contains(
+            "Anti-stagnation activée : moins de 10 points de dégâts en 5 manches, chaque manche \
+             perdue coûte désormais au moins 5 points de vitalité."
+        ));
+
+        // Sans armure, Bob perd une manche aux gros dégâts, qui lève l'anti-stagnation.
+        game.players[1].armor = 0;
+        let dealt = play_round(&mut game);
+        assert!(dealt >= STALEMATE_RELIEF_DAMAGE);
+        game.players[1].armor = 1000;
+        assert_eq!(play_round(&mut game), 1);
+        let lifted = tr!(Msg::StalemateOff, dealt = dealt);
+        assert!(console.borrow().output.contains(&lifted));
+        assert!(!game.stalemate);
+    }
+
+    /// Vérifie qu'après trois pronostics justes d'affilée, l'arbitre impose le mutateur choisi à la
+    /// manche suivante, un pronostic manqué remettant sa série à zéro.
+    #[test]
+    fn test_referee_mutator() {
+        let (mut game, console, [alice, bob]) = scripted_game(GameRules::default());
+        game.set_seed(5);
+        let referee = ScriptedController::new();
+        game.set_referee(String::from("Chloé"), Box::new(referee.clone()));
+        game.start();
+
+        // Alice remporte chaque manche ; l'arbitre se trompe une fois, puis voit juste trois fois.
+        referee.push_pick(Some(1));
+        for _ in 0..3 {
+            referee.push_pick(Some(0));
+        }
+        referee.push_mutator(Some(2));
+        referee.push_pick(None);
+        for _ in 0..5 {
+            for _ in 0..2 {
+                alice.push_stop(StopDecision::from_offset(50, 0));
+                bob.push_stop(StopDecision::from_offset(50, 303));
+            }
+            alice.push_poison(0);
+            assert_eq!(game.play_round().unwrap(), None);
+        }
+        let mutators: Vec<Option<&str>> = game
+            .rounds
+            .iter()
+            .map(|round| round.mutator.as_deref())
+            .collect();
+        assert_eq!(
+            mutators,
+            [None, None, None, None, Some(mutators::POOL[2].id())]
+        );
+        assert_eq!(game.referee().unwrap().streak(), 0);
+        let output = &console.borrow().output;
+        assert!(output.contains("Chloé avait pronostiqué Bob : pronostic manqué."));
+        assert!(output.contains("pronostic juste (3/3 d'affilée)"));
+        assert!(output.contains("Chloé a vu juste 3 fois d'affilée"));
+    }
+
+    #[test]
+    fn test_time_tiebreak() {
+        use crate::poison::PoisonType;
+
+        /// Joueur atteignant chaque objectif après une durée fixe.
+        struct TimedController(ManualClock, Duration);
+
+        impl Controller for TimedController {
+            fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+                self.0.advance(self.1);
+                StopDecision::from_offset(ctx.objective, 0)
+            }
+
+            fn choose_poison(&mut self, _options: &[PoisonType], _opponent: &Player) -> usize {
+                0
+            }
+
+            fn choose_counter(&mut self, _poison: &PoisonType, _extra: u32, _me: &Player) -> bool {
+                false
+            }
+
+            fn acknowledge(&mut self, _msg: &str) {}
+        }
+
+        let game_with = |alice: u64, bob: u64| {
+            let players = vec![
+                Player::new(String::from("Alice"), 1000, 75, 50),
+                Player::new(String::from("Bob"), 1000, 75, 50),
+            ];
+            let mut game = Game::new(players, 2);
+            game.rules.time_tiebreak = true;
+            game.set_output(Output::new(
+                Box::new(ScriptedConsole::default()),
+                Verbosity::Quiet,
+            ));
+            let clock = ManualClock::new();
+            game.set_clock(Box::new(clock.clone()));
+            let durations = [alice, bob].map(Duration::from_millis);
+            for (index, duration) in durations.into_iter().enumerate() {
+                game.set_controller(index, Box::new(TimedController(clock.clone(), duration)));
+            }
+            game.start();
+            game
+        };
+
+        // Scores égaux : Bob, plus rapide, remporte la manche pour des dégâts fixes.
+        let mut game = game_with(1500, 900);
+        assert_eq!(game.play_round().unwrap(), None);
+        assert!(game.draws.is_empty());
+        let damage = game.damage.last().unwrap();
+        assert_eq!((damage.winner, damage.loser), (1, 0));
+        assert_eq!(damage.dealt, TIME_TIEBREAK_DAMAGE);
+        assert!(damage.time_tiebreak);
+
+        // À durées égales, la manche reste nulle.
+        let mut game = game_with(1000, 1000);
+        assert_eq!(game.play_round().unwrap(), None);
+        assert!(game.damage.is_empty());
+        assert_eq!(game.draws.len(), 1);
+    }
+
+    /// Observateur retenant les événements de la partie.
+    impl GameObserver for Vec<GameEvent> {
+        fn on_event(&mut self, event: &GameEvent) {
+            self.push(event.clone());
+        }
+    }
+
+    /// Vérifie la malédiction du vainqueur : une manche gagnée de 61 points ralentit le vainqueur
+    /// pendant la manche suivante seulement, une manche gagnée de 60 points ne le ralentit pas. Elle
+    /// est notifiée aux observateurs et conservée dans les sauvegardes.
+    #[test]
+    fn test_winners_curse() {
+        let new_game = || {
+            let rules = GameRules {
+                winners_curse: true,
+                ..GameRules::default()
+            };
+            let (mut game, console, _) = scripted_game(rules);
+            game.set_output(Output::new(
+                Box::new(Rc::clone(&console)),
+                Verbosity::Normal,
+            ));
+            game.start();
+            (game, console)
+        };
+        let speeds = |game: &Game| {
+            let alice = game.counter_view(0, 0, 50).speed;
+            (alice, game.state(0).me.speed, game.effective_speed(1))
+        };
+
+        // 60 points d'écart : le vainqueur n'est pas maudit.
+        let (mut game, console) = new_game();
+        game.resolve_scores(&[130, 70]).unwrap();
+        assert!(!game.damage[0].winners_curse);
+        game.end_round();
+        assert_eq!(speeds(&game), (75, 75, 75));
+        assert!(!console.borrow().output.contains("Excès de confiance"));
+
+        // 61 points d'écart : le vainqueur est ralenti pendant la manche suivante.
+        let (mut game, console) = new_game();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        game.add_observer(Box::new(Rc::clone(&events)));
+        game.resolve_scores(&[131, 70]).unwrap();
+        assert!(game.damage[0].winners_curse);
+        assert!(console.borrow().output.contains(
+            "😬 Excès de confiance : Alice l'emporte de 61 points et perd 3 points de vitesse \
+             pendant la manche suivante."
+        ));
+        assert!(events.borrow().contains(&GameEvent::WinnersCurse {
+            player: String::from("Alice"),
+            margin: 61,
+            speed: WINNERS_CURSE_SPEED,
+            round: 2,
+        }));
+        assert_eq!(speeds(&game), (75, 75, 75));
+        game.end_round();
+        assert_eq!(speeds(&game), (72, 72, 75));
+
+        // Une partie sauvegardée entre les deux manches reprend avec la malédiction.
+        let state = game.save_state();
+        assert_eq!(state.winners_curse, Some(0));
+        assert_eq!(speeds(&Game::from_save(&state)), (72, 72, 75));
+
+        // La malédiction expire à la fin de la manche, même sans nouveau vainqueur.
+        game.resolve_scores(&[70, 70]).unwrap();
+        game.end_round();
+        assert_eq!(speeds(&game), (75, 75, 75));
+        assert_eq!(game.save_state().winners_curse, None);
+
+        // Sans la règle, aucun écart ne maudit le vainqueur.
+        let (mut game, _) = new_game();
+        game.rules.winners_curse = false;
+        game.resolve_scores(&[150, 0]).unwrap();
+        game.end_round();
+        assert!(!game.damage[0].winners_curse);
+        assert_eq!(speeds(&game), (75, 75, 75));
+    }
+
+    /// Vérifie le poison différé : choisi à la fin de la première manche, il n'est appliqué qu'au
+    /// début de la suivante, après l'avertissement de sa victime, et il est abandonné lorsque la
+    /// partie s'arrête avant.
+    #[test]
+    fn test_delayed_poison() {
+        let new_game = || {
+            let rules = GameRules {
+                delayed_poison: true,
+                ..GameRules::default()
+            };
+            let (mut game, console, [alice, bob]) = scripted_game(rules);
+            game.start();
+            for _ in 0..2 {
+                alice.push_stop(StopDecision::from_offset(50, 0));
+                bob.push_stop(StopDecision::from_offset(50, 303));
+            }
+            alice.push_poison(0);
+            bob.push_counter(false);
+            (game, console)
+        };
+
+        // Le poison choisi par Alice est seulement annoncé à la fin de la première manche.
+        let (mut game, console) = new_game();
+        assert_eq!(game.play_round().unwrap(), None);
+        assert!(game.players[1].poisons.is_empty());
+        assert_eq!(game.pending_poison, Some((PlayerId(1), PoisonType::Speed)));
+        assert_eq!(
+            game.save_state().pending_poison,
+            Some((1, PoisonType::Speed))
+        );
+
+        // Il fait effet au début de la deuxième manche, avant les objectifs.
+        assert_eq!(game.begin_round(), None);
+        assert_eq!(game.players[1].poisons, [PoisonType::Speed]);
+        assert_eq!(game.pending_poison, None);
+        let output = console.borrow().output.clone();
+        let delayed = output
+            .find("fera effet au début de la manche suivante")
+            .unwrap();
+        let header = output.find("## Manche 2 ##").unwrap();
+        let warning = output
+            .find("⚠ Bob : le poison de vitesse fera effet ce tour.")
+            .unwrap();
+        assert!(delayed < header && header < warning, "{output}");
+
+        // Une partie interrompue avant la manche suivante abandonne le poison en attente.
+        let (mut game, _) = new_game();
+        let cancel = CancelToken::new();
+        game.set_cancel_token(cancel.clone());
+        assert_eq!(game.play_round().unwrap(), None);
+        cancel.cancel();
+        assert_eq!(game.play_round().unwrap(), Some(GameResult::Interrupted));
+        assert!(game.players[1].poisons.is_empty());
+        assert_eq!(game.pending_poison, None);
+    }
+
+    /// Vérifie le choix du poison hors du menu : le menu est redemandé jusqu'à un choix valide, et
+    /// seul l'arrêt de la partie interrompt la question.
+    #[test]
+    fn test_invalid_poison_choice() {
+        let play_round = |choices: &[usize]| {
+            let (mut game, console, [alice, bob]) = scripted_game(GameRules::default());
+            game.start();
+            for _ in 0..2 {
+                alice.push_stop(StopDecision::from_offset(50, 0));
+                bob.push_stop(StopDecision::from_offset(50, 303));
+            }
+            for &choice in choices {
+                alice.push_poison(choice);
+            }
+            bob.push_counter(false);
+            let result = game.play_round().unwrap();
+            let output = console.borrow().output.clone();
+            let menus = output.matches("vous devez choisir quel poison").count();
+            let retries = output
+                .matches("Choix invalide, veuillez choisir un poison entre 1 et 2.")
+                .count();
+            (result, game.players[1].poisons.clone(), menus, retries)
+        };
+
+        let (result, poisons, menus, retries) = play_round(&[5, 1]);
+        assert_eq!(result, None);
+        assert_eq!(poisons, [PoisonType::Strength]);
+        assert_eq!((menus, retries), (2, 1));
+
+        // Les réponses invalides ne lassent pas la partie : le menu est redemandé à chacune.
+        let (result, poisons, menus, retries) = play_round(&[5, 2, 7, 9, 0]);
+        assert_eq!(result, None);
+        assert_eq!(poisons, [PoisonType::Speed]);
+        assert_eq!((menus, retries), (5, 4));
+
+        // Un contrôleur qui ne répond plus abandonne la partie, sans qu'aucun poison soit retenu.
+        let (result, poisons, menus, retries) = play_round(&[5, 2]);
+        assert_eq!(
+            result,
+            Some(GameResult::Forfeit {
+                forfeited: PlayerId(0),
+                winner: PlayerId(1),
+            })
+        );
+        assert!(poisons.is_empty());
+        assert_eq!((menus, retries), (3, 2));
+    }
+
+    /// Vérifie la cadence des manches : par défaut, chaque tour attend ENTREE ; avec la
+    /// continuation automatique, un compte à rebours le remplace, une pause suit chaque objectif et
+    /// chaque joueur confirme le passage à la manche suivante.
+    #[test]
+    fn test_pacing() {
+        let play_two_rounds = |pacing: Pacing| {
+            let (mut game, console, [alice, bob]) = scripted_game(GameRules::default());
+            game.set_pacing(pacing);
+            let clock = ManualClock::new();
+            game.set_clock(Box::new(clock.clone()));
+            game.start();
+            for _ in 0..2 {
+                for _ in 0..2 {
+                    alice.push_stop(StopDecision::from_offset(50, 0));
+                    bob.push_stop(StopDecision::from_offset(50, 303));
+                }
+                alice.push_poison(0);
+                assert_eq!(game.play_round().unwrap(), None);
+            }
+            let output = console.borrow().output.clone();
+            (
+                clock.now(),
+                output,
+                alice.acknowledged(),
+                bob.acknowledged(),
+            )
+        };
+
+        // Par défaut, chaque tour attend ENTREE et les manches s'enchaînent.
+        let (elapsed, output, alice, _) = play_two_rounds(Pacing::default());
+        assert_eq!(elapsed, Duration::ZERO);
+        assert_eq!(alice, vec![tr!(Msg::PressEnterTurn); 2]);
+        assert!(!output.contains("commence dans"));
+
+        // Compte à rebours de 3 secondes pour chacun des 4 tours, pause de 250 ms après chacun des
+        // 8 objectifs, et confirmation de chaque joueur avant la deuxième manche seulement.
+        let pacing = Pacing {
+            auto_continue: true,
+            pause_between_rounds: true,
+            turn_delay: Duration::from_millis(250),
+        };
+        let (elapsed, output, alice, bob) = play_two_rounds(pacing);
+        assert_eq!(
+            elapsed,
+            Duration::from_secs(4 * 3) + Duration::from_millis(8 * 250)
+        );
+        assert_eq!(
+            alice,
+            ["→ Alice, appuyer sur ENTREE pour passer à la manche 2.."]
+        );
+        assert_eq!(
+            bob,
+            ["→ Bob, appuyer sur ENTREE pour passer à la manche 2.."]
+        );
+        assert_eq!(
+            output.matches("→ Le tour de Bob commence dans 3..").count(),
+            2
+        );
+        assert_eq!(output.matches("commence dans 1..").count(), 4);
+    }
+}
@@ -0,0 +1,198 @@
+//! Module du journal de partie.
+//!
+//! Le [`GameLog`] est un [`GameObserver`] qui consigne chaque événement de la partie dans un fichier
+//! texte, précédé de son horodatage UTC. Les lignes sont mises en tampon et écrites sur disque à la
+//! fin de chaque manche et de chaque partie.
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::observer::{GameEvent, GameObserver};
+
+/// Erreur survenue lors de la création du journal de partie.
+#[derive(Debug)]
+pub enum GameLogError {
+    /// Le dossier devant contenir le journal n'existe pas.
+    MissingDirectory {
+        /// Chemin du journal demandé.
+        path: PathBuf,
+    },
+    /// Erreur d'entrée/sortie lors de la création du fichier.
+    Io {
+        /// Chemin du journal demandé.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for GameLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameLogError::MissingDirectory { path } => write!(
+                f,
+                "impossible de créer le journal {} : le dossier {} n'existe pas",
+                path.display(),
+                path.parent().unwrap_or(Path::new("")).display()
+            ),
+            GameLogError::Io { path, source } => {
+                write!(
+                    f,
+                    "impossible de créer le journal {} : {source}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl Error for GameLogError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GameLogError::MissingDirectory { .. } => None,
+            GameLogError::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Journal consignant les événements des parties dans un fichier.
+#[derive(Debug)]
+pub struct GameLog {
+    /// Fichier du journal, écrit par blocs.
+    writer: BufWriter<File>,
+}
+
+impl GameLog {
+    /// Crée (ou remplace) le fichier du journal.
+    ///
+    /// # Retour
+    ///
+    /// Retourne [`GameLogError::MissingDirectory`] si le dossier du fichier n'existe pas, afin de
+    /// signaler l'erreur avant le début de la partie.
+    pub fn create(path: &Path) -> Result<Self, GameLogError> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+            && !parent.is_dir()
+        {
+            return Err(GameLogError::MissingDirectory {
+                path: path.to_path_buf(),
+            });
+        }
+        let file = File::create(path).map_err(|source| GameLogError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(GameLog {
+            writer: BufWriter::new(file),
+        })
+    }
+}
+
+impl GameObserver for GameLog {
+    fn on_event(&mut self, event: &GameEvent) {
+        let written = writeln!(self.writer, "[{}] {event}", timestamp(SystemTime::now()));
+        let flushed = match event {
+            GameEvent::RoundEnded { .. } | GameEvent::GameEnded { .. } => self.writer.flush(),
+            _ => Ok(()),
+        };
+        if let Err(err) = written.and(flushed) {
+            log::warn!("écriture du journal de partie impossible : {err}");
+        }
+    }
+}
+
+/// Formate un instant en UTC au format ISO 8601, à la milliseconde près.
+///
+/// # Exemples
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use dual_game::game_log::timestamp;
+///
+/// let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+/// assert_eq!(timestamp(time), "2023-11-14T22:13:20.123Z");
+/// ```
+pub fn timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+    // Conversion d'un nombre de jours depuis 1970 en date civile (algorithme de H. Hinnant).
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::{BotController, Difficulty};
+    use crate::game::Game;
+    use crate::output::Verbosity;
+    use crate::player::Player;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use std::fs;
+
+    /// Vérifie que le journal consigne chaque étape d'une partie, horodatée.
+    #[test]
+    fn test_game_log_records_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("partie.log");
+        let players = vec![
+            Player::new(String::from("Alice"), 20, 75, 50),
+            Player::new(String::from("Bob"), 20, 75, 50),
+        ];
+        let mut game = Game::new(players, 3);
+        game.set_verbosity(Verbosity::Silent);
+        game.set_seed(5);
+        for index in 0..2 {
+            let rng = StdRng::seed_from_u64(index as u64);
+            game.set_controller(
+                index,
+                Box::new(BotController::with_rng(Difficulty::Normal, rng)),
+            );
+        }
+        game.add_observer(Box::new(GameLog::create(&path).unwrap()));
+        game.run().unwrap();
+
+        let log = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = log.lines().collect();
+        assert!(lines.iter().all(|line| line.starts_with('[')));
+        assert!(lines[0].ends_with("Début de partie : Alice contre Bob (3 objectifs par tour)"));
+        assert!(log.contains("] Manche 1\n"));
+        assert!(log.contains("] Objectifs de Alice : ["));
+        let stops = lines.iter().filter(|l| l.contains("] Arrêt de ")).count();
+        assert_eq!(stops, game.history.len() * 3);
+        assert!(log.contains("perd "));
+        assert!(lines.last().unwrap().contains("Fin de partie après"));
+    }
+
+    /// Vérifie qu'un journal dans un dossier inexistant est refusé avec un message explicite.
+    #[test]
+    fn test_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("absent").join("partie.log");
+        let err = GameLog::create(&path).unwrap_err();
+        assert!(matches!(err, GameLogError::MissingDirectory { .. }));
+        assert!(err.to_string().contains("n'existe pas"), "{err}");
+    }
+}
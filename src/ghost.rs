@@ -0,0 +1,134 @@
+//! Module du fantôme de l'entraînement.
+//!
+//! Un entraînement enregistré (`dual_game practice --record <fichier>`) peut servir de [`Ghost`] à
+//! un entraînement suivant (`--ghost <fichier>`) : après chaque arrêt du compteur, la position où le
+//! fantôme s'était arrêté sur le même objectif est affichée, puis les moyennes des essais sont
+//! comparées. Les arrêts sont appariés par leur position dans l'essai, et non par la valeur de
+//! l'objectif, chaque essai tirant de nouveaux objectifs.
+
+use std::path::Path;
+
+use crate::replay::{Replay, ReplayError, StopRecord, TurnRecord};
+use crate::scoring::ScoringCalculator;
+
+/// Essais d'un entraînement précédent, rejoués en regard de l'entraînement en cours.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ghost {
+    /// Essais du fantôme, dans l'ordre.
+    tries: Vec<TurnRecord>,
+}
+
+impl Ghost {
+    /// Retient les essais du premier joueur d'une rediffusion, dans l'ordre.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::ghost::Ghost;
+    /// use dual_game::replay::{PlayerNames, Replay, ReplayHeader, StopRecord, TurnRecord};
+    ///
+    /// let replay = Replay {
+    ///     header: ReplayHeader::new(PlayerNames::from(vec![String::from("Alice")]), 1, None),
+    ///     turns: vec![TurnRecord {
+    ///         round: 1,
+    ///         player: 0,
+    ///         stops: vec![StopRecord { objective: 44, counter: 43, miss: 0, score: 130, retried: false }],
+    ///         average: 130,
+    ///         risk: None,
+    ///     }],
+    ///     rounds: Vec::new(),
+    ///     draws: Vec::new(),
+    ///     winner: None,
+    ///     unfinished: false,
+    /// };
+    /// let ghost = Ghost::from_replay(&replay);
+    /// assert_eq!(ghost.tries(), 1);
+    /// assert_eq!(ghost.stop(0, 0).map(|stop| stop.counter), Some(43));
+    /// assert_eq!(ghost.stop(1, 0), None);
+    /// ```
+    pub fn from_replay(replay: &Replay) -> Self {
+        Ghost {
+            tries: replay
+                .turns
+                .iter()
+                .filter(|turn| turn.player == 0)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Charge le fantôme d'un entraînement enregistré, au format de [`Replay::load`].
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        Replay::load(path).map(|replay| Ghost::from_replay(&replay))
+    }
+
+    /// Retourne le nombre d'essais du fantôme.
+    pub fn tries(&self) -> usize {
+        self.tries.len()
+    }
+
+    /// Retourne l'arrêt du fantôme sur l'objectif à la position `obj_index` de l'essai `try_index`
+    /// (tous deux comptés à partir de 0), s'il l'a joué.
+    pub fn stop(&self, try_index: usize, obj_index: usize) -> Option<&StopRecord> {
+        self.tries.get(try_index)?.stops.get(obj_index)
+    }
+
+    /// Retourne l'écart entre l'arrêt du fantôme et son objectif, à la même position que
+    /// [`Ghost::stop`].
+    pub fn difference(&self, try_index: usize, obj_index: usize) -> Option<u32> {
+        self.stop(try_index, obj_index)
+            .map(|stop| ScoringCalculator::difference(stop.objective, stop.counter))
+    }
+
+    /// Retourne la moyenne des scores moyens des `tries` premiers essais du fantôme, ou `None` s'il
+    /// n'en a joué aucun.
+    pub fn running_average(&self, tries: usize) -> Option<u32> {
+        let averages: Vec<u32> = self
+            .tries
+            .iter()
+            .take(tries)
+            .map(|turn| turn.average)
+            .collect();
+        (!averages.is_empty()).then(|| ScoringCalculator::calculate_average(&averages))
+    }
+
+    /// Compare les scores moyens des essais en cours à ceux du fantôme, sur les essais joués par les
+    /// deux.
+    ///
+    /// # Retour
+    ///
+    /// Retourne l'avance de l'entraînement en cours sur le fantôme, en points de moyenne (négative
+    /// s'il est en retard), ou `None` si aucun essai ne peut être comparé.
+    pub fn margin(&self, averages: &[u32]) -> Option<i64> {
+        let tries = averages.len().min(self.tries.len());
+        let ghost = self.running_average(tries)?;
+        let own = ScoringCalculator::calculate_average(&averages[..tries]);
+        Some(i64::from(own) - i64::from(ghost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie le chargement du fantôme depuis le fichier d'exemple, l'appariement des arrêts par
+    /// position et la comparaison des moyennes sur les essais communs.
+    #[test]
+    fn test_ghost_fixture() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/ghost.jsonl");
+        let ghost = Ghost::load(&path).unwrap();
+        assert_eq!(ghost.tries(), 2);
+        assert_eq!(ghost.stop(0, 1).map(|stop| stop.counter), Some(43));
+        assert_eq!(ghost.difference(0, 1), Some(1));
+        assert_eq!(ghost.difference(1, 2), Some(8));
+        assert_eq!(ghost.stop(0, 3), None);
+        assert_eq!(ghost.stop(2, 0), None);
+
+        assert_eq!(ghost.running_average(1), Some(124));
+        assert_eq!(ghost.running_average(5), Some(111));
+        assert_eq!(ghost.margin(&[136]), Some(12));
+        assert_eq!(ghost.margin(&[90, 110, 150]), Some(-11));
+        assert_eq!(ghost.margin(&[]), None);
+        assert_eq!(Ghost::default().margin(&[100]), None);
+    }
+}
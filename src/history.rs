@@ -0,0 +1,272 @@
+//! Module de l'historique des parties au fil de l'eau.
+//!
+//! Une partie peut confier chaque enregistrement de son historique ([`HistoryEntry`]) à un
+//! [`HistorySink`] à la fin de chaque manche, plutôt que de tout conserver jusqu'à sa fin : associé à
+//! une limite du nombre de manches retenues en mémoire (voir
+//! [`Game::set_history_limit`](crate::game::Game::set_history_limit)), l'historique d'une longue
+//! session occupe une mémoire bornée. [`JsonlSink`] écrit les enregistrements dans un fichier JSON
+//! Lines, relu par [`Replay::load`](crate::replay::Replay::load), et [`MemorySink`] les conserve.
+
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::export::Outcome;
+use crate::replay::{DamageRecord, DrawRecord, ReplayError, ReplayHeader, RoundRecord, TurnRecord};
+
+/// Nombre de manches retenues en mémoire par les parties de l'application, suffisant pour le tableau
+/// des scores.
+pub const DEFAULT_RETAINED_ROUNDS: usize = 20;
+
+/// Enregistrement de l'historique d'une partie, une ligne d'un fichier JSON Lines.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HistoryEntry {
+    /// En-tête de la partie, écrit à son début.
+    Header(ReplayHeader),
+    /// Tour joué par un joueur.
+    Turn(TurnRecord),
+    /// Dégâts subis par le perdant d'une manche.
+    Damage(DamageRecord),
+    /// État des joueurs à la fin d'une manche.
+    Round(RoundRecord),
+    /// Manche nulle, sans dégâts.
+    Draw(DrawRecord),
+    /// Fin de la partie, absente si elle s'est arrêtée brutalement.
+    End {
+        /// Issue de la partie.
+        outcome: Outcome,
+        /// Index du vainqueur, le cas échéant.
+        winner: Option<usize>,
+    },
+}
+
+/// Destination des enregistrements de l'historique d'une partie.
+///
+/// La partie transmet les enregistrements de chaque manche à sa fin, puis appelle
+/// [`HistorySink::flush`] : une partie arrêtée brutalement ne perd que sa manche en cours.
+pub trait HistorySink {
+    /// Reçoit un enregistrement, dans l'ordre de la partie.
+    fn write(&mut self, entry: &HistoryEntry) -> io::Result<()>;
+
+    /// Rend durables les enregistrements reçus depuis le dernier appel.
+    fn flush(&mut self) -> io::Result<()>;
+}
+
+/// Historique partagé, par exemple entre une partie et le test qui inspecte ses enregistrements.
+impl<S: HistorySink + ?Sized> HistorySink for Rc<RefCell<S>> {
+    fn write(&mut self, entry: &HistoryEntry) -> io::Result<()> {
+        self.borrow_mut().write(entry)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.borrow_mut().flush()
+    }
+}
+
+/// Historique conservant chaque enregistrement en mémoire.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemorySink {
+    /// Enregistrements reçus, dans l'ordre.
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl HistorySink for MemorySink {
+    fn write(&mut self, entry: &HistoryEntry) -> io::Result<()> {
+        self.entries.push(entry.clone());
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Historique écrit au format JSON Lines, un enregistrement par ligne, sans jamais réécrire le début
+/// du fichier.
+///
+/// Les lignes sont mises en tampon et écrites d'un bloc à chaque [`HistorySink::flush`] : un fichier
+/// interrompu par un arrêt brutal ne contient que des lignes complètes.
+#[derive(Debug)]
+pub struct JsonlSink<W: Write> {
+    /// Destination des lignes.
+    writer: W,
+    /// Lignes en attente d'écriture.
+    pending: String,
+}
+
+impl JsonlSink<File> {
+    /// Crée (ou remplace) le fichier de l'historique.
+    pub fn create(path: &Path) -> Result<Self, ReplayError> {
+        let file = File::create(path).map_err(|source| ReplayError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(JsonlSink::new(file))
+    }
+}
+
+impl<W: Write> JsonlSink<W> {
+    /// Crée un historique écrivant ses lignes dans la destination donnée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::history::{HistoryEntry, HistorySink, JsonlSink};
+    /// use dual_game::replay::{PlayerNames, ReplayHeader};
+    ///
+    /// let mut sink = JsonlSink::new(Vec::new());
+    /// let names = PlayerNames::from(vec![String::from("Alice")]);
+    /// let header = HistoryEntry::Header(ReplayHeader::new(names, 3, None));
+    /// sink.write(&header).unwrap();
+    /// assert!(sink.get_ref().is_empty());
+    /// sink.flush().unwrap();
+    /// let text = String::from_utf8(sink.into_inner()).unwrap();
+    /// assert_eq!(
+    ///     text,
+    ///     "{\"kind\":\"header\",\"players\":[\"Alice\"],\"objectifs\":3,\"seed\":null,\"version\":5}\n"
+    /// );
+    /// ```
+    pub fn new(writer: W) -> Self {
+        JsonlSink {
+            writer,
+            pending: String::new(),
+        }
+    }
+
+    /// Retourne la destination des lignes.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Retourne la destination des lignes, sans écrire les lignes en attente.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> HistorySink for JsonlSink<W> {
+    fn write(&mut self, entry: &HistoryEntry) -> io::Result<()> {
+        let line = serde_json::to_string(entry)?;
+        self.pending.push_str(&line);
+        self.pending.push('\n');
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.pending.is_empty() {
+            self.writer.write_all(self.pending.as_bytes())?;
+            self.pending.clear();
+        }
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::RandomBot;
+    use crate::game::{Game, GameResult};
+    use crate::output::Verbosity;
+    use crate::player::Player;
+    use crate::replay::Replay;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    /// Prépare une partie silencieuse et reproductible entre deux bots.
+    fn bot_game(vitality: u32) -> Game {
+        let players = vec![
+            Player::new(String::from("Alice"), vitality, 75, 50),
+            Player::new(String::from("Bob"), vitality, 75, 50),
+        ];
+        let mut game = Game::new(players, 3);
+        game.set_verbosity(Verbosity::Silent);
+        game.set_seed(159);
+        for index in 0..2 {
+            let rng = StdRng::seed_from_u64(index as u64);
+            game.set_controller(index, Box::new(RandomBot::new(rng)));
+        }
+        game
+    }
+
+    /// Vérifie qu'une partie de 1000 manches ne retient en mémoire que ses dernières manches, tout en
+    /// transmettant chacune à l'historique et en calculant les mêmes statistiques qu'une partie
+    /// conservant tout.
+    #[test]
+    fn test_bounded_history() {
+        let sink = Rc::new(RefCell::new(MemorySink::default()));
+        let mut game = bot_game(1_000_000);
+        game.set_history_limit(10);
+        game.set_history_sink(Box::new(Rc::clone(&sink)));
+        let mut twin = bot_game(1_000_000);
+
+        game.start();
+        twin.start();
+        for _ in 0..1000 {
+            assert_eq!(game.play_round().unwrap(), None);
+            assert_eq!(twin.play_round().unwrap(), None);
+            assert!(game.history.len() <= 2 * 10);
+            assert!(game.damage.len() <= 10);
+            assert!(game.rounds.len() <= 10);
+        }
+        assert_eq!(game.rounds.first().map(|round| round.round), Some(991));
+        assert_eq!(twin.history.len(), 2000);
+        assert_eq!(game.stats(), twin.stats());
+
+        let entries = &sink.borrow().entries;
+        let count = |kind: fn(&HistoryEntry) -> bool| entries.iter().filter(|e| kind(e)).count();
+        assert!(matches!(entries[0], HistoryEntry::Header(_)));
+        assert_eq!(count(|entry| matches!(entry, HistoryEntry::Turn(_))), 2000);
+        assert_eq!(count(|entry| matches!(entry, HistoryEntry::Round(_))), 1000);
+        assert_eq!(
+            count(|entry| matches!(entry, HistoryEntry::Damage(_))),
+            twin.damage.len()
+        );
+    }
+
+    /// Vérifie qu'une partie écrite au fil de l'eau se relit comme sa rediffusion, y compris lorsqu'elle
+    /// s'est arrêtée brutalement.
+    #[test]
+    fn test_jsonl_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("partie.jsonl");
+        let mut game = bot_game(20);
+        game.set_history_limit(1);
+        game.set_history_sink(Box::new(JsonlSink::create(&path).unwrap()));
+        let result = game.run().unwrap();
+        assert!(matches!(result, GameResult::Victory { .. }));
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.ends_with('\n'));
+        for line in content.lines() {
+            serde_json::from_str::<HistoryEntry>(line).unwrap();
+        }
+        let replay = Replay::load(&path).unwrap();
+        assert_eq!(
+            replay.header.players.name(crate::player::PlayerId(0)),
+            Some("Alice")
+        );
+        assert_eq!(replay.header.seed, Some(159));
+        assert_eq!(replay.turns.len(), 2 * (game.round as usize - 1));
+        assert_eq!(replay.rounds.len(), game.round as usize - 1);
+        assert_eq!(replay.winner, game.winner().map(|id| id.index()));
+        assert!(!replay.unfinished);
+        assert!(game.history.len() <= 2);
+
+        // Une partie abandonnée sans se terminer laisse un fichier valide, arrêté à sa dernière manche.
+        let mut game = bot_game(1_000);
+        game.set_history_sink(Box::new(JsonlSink::create(&path).unwrap()));
+        game.start();
+        game.play_round().unwrap();
+        game.play_round().unwrap();
+        drop(game);
+        let replay = Replay::load(&path).unwrap();
+        assert_eq!((replay.turns.len(), replay.rounds.len()), (4, 2));
+        assert!(replay.unfinished);
+        assert!(replay.to_string().ends_with("Partie inachevée"));
+    }
+}
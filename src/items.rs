@@ -0,0 +1,248 @@
+//! Module de la boutique d'objets ouverte entre les manches.
+//!
+//! Lorsque la boutique est activée ([`GameRules::shop`](crate::rules::GameRules::shop)), chaque
+//! joueur gagne des pièces à chaque manche ([`coins_earned`]) et peut acheter un [`Item`] avant la
+//! manche suivante. Les effets des objets durent jusqu'à la fin de la partie ; ils passent par les
+//! caractéristiques du joueur, [`Player::heal`] et [`Player::cure_poison`]. L'achat est résolu par
+//! [`buy`], qui vérifie que le joueur peut se l'offrir.
+
+use std::error::Error;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::counter::MIN_SPEED;
+use crate::messages::Msg;
+use crate::player::Player;
+use crate::tr;
+
+/// Score moyen d'une manche rapportant une pièce.
+pub const COIN_DIVISOR: u32 = 10;
+/// Force gagnée avec un aiguisage.
+pub const SHARPENING_BONUS: u32 = 5;
+/// Délai du compteur, en millisecondes, retiré par des chaussures.
+pub const SHOES_DELAY: u32 = 10;
+/// Vitalité rendue par une potion.
+pub const POTION_HEAL: u32 = 10;
+
+/// Objets vendus par la boutique.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Item {
+    /// Aiguisage : force augmentée pour le reste de la partie.
+    Sharpening,
+    /// Chaussures : délai du compteur réduit pour le reste de la partie.
+    Shoes,
+    /// Potion : vitalité rendue, sans dépasser la vitalité maximale.
+    Potion,
+    /// Antidote : annule le dernier poison subi.
+    Antidote,
+}
+
+impl Item {
+    /// Objets proposés par la boutique, dans l'ordre du menu.
+    pub const ALL: [Item; 4] = [Item::Sharpening, Item::Shoes, Item::Potion, Item::Antidote];
+
+    /// Retourne le nom de l'objet, tel qu'affiché dans la boutique.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Item::Sharpening => "Aiguisage",
+            Item::Shoes => "Chaussures",
+            Item::Potion => "Potion",
+            Item::Antidote => "Antidote",
+        }
+    }
+
+    /// Retourne le libellé de l'effet de l'objet, tel qu'affiché dans la boutique.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Item::Sharpening => "+5 strength",
+            Item::Shoes => "-10 ms",
+            Item::Potion => "+10 vitality",
+            Item::Antidote => "-1 poison",
+        }
+    }
+
+    /// Retourne le prix de l'objet, en pièces.
+    pub fn price(&self) -> u32 {
+        match self {
+            Item::Sharpening | Item::Shoes => 15,
+            Item::Potion => 20,
+            Item::Antidote => 10,
+        }
+    }
+
+    /// Vérifie que le joueur peut acheter l'objet : il doit en avoir les moyens et, pour un antidote,
+    /// avoir subi un poison.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::items::{Item, ShopError};
+    /// use dual_game::player::Player;
+    ///
+    /// let mut player = Player::new(String::from("Alice"), 50, 75, 50);
+    /// player.coins = 12;
+    /// assert!(Item::Antidote.check(&player).is_err());
+    /// assert_eq!(
+    ///     Item::Potion.check(&player),
+    ///     Err(ShopError::NotEnoughCoins { price: 20, coins: 12 })
+    /// );
+    /// ```
+    pub fn check(&self, player: &Player) -> Result<(), ShopError> {
+        if player.coins < self.price() {
+            return Err(ShopError::NotEnoughCoins {
+                price: self.price(),
+                coins: player.coins,
+            });
+        }
+        if *self == Item::Antidote && player.poisons.is_empty() {
+            return Err(ShopError::NothingToCure);
+        }
+        Ok(())
+    }
+
+    /// Applique l'effet de l'objet au joueur, sans le faire payer.
+    ///
+    /// Le délai du compteur ne descend pas sous [`MIN_SPEED`], et un antidote sans poison à annuler
+    /// est sans effet.
+    pub fn apply(&self, player: &mut Player) {
+        match self {
+            Item::Sharpening => player.strength = player.strength.saturating_add(SHARPENING_BONUS),
+            Item::Shoes => {
+                player.speed = player.speed.saturating_sub(SHOES_DELAY).max(MIN_SPEED);
+            }
+            Item::Potion => {
+                player.heal(POTION_HEAL);
+            }
+            Item::Antidote => {
+                player.cure_poison();
+            }
+        }
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Raison pour laquelle un achat est refusé.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShopError {
+    /// Le joueur n'a pas assez de pièces.
+    NotEnoughCoins {
+        /// Prix de l'objet.
+        price: u32,
+        /// Pièces du joueur.
+        coins: u32,
+    },
+    /// Le joueur n'a subi aucun poison à annuler.
+    NothingToCure,
+}
+
+impl fmt::Display for ShopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShopError::NotEnoughCoins { price, coins } => write!(
+                f,
+                "{}",
+                tr!(Msg::ShopNotEnoughCoins, price = price, coins = coins)
+            ),
+            ShopError::NothingToCure => write!(f, "{}", tr!(Msg::ShopNothingToCure)),
+        }
+    }
+}
+
+impl Error for ShopError {}
+
+/// Issue du passage d'un joueur à la boutique.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShopOutcome {
+    /// L'objet a été acheté et son effet appliqué.
+    Bought(Item),
+    /// L'achat a été refusé.
+    Refused(Item, ShopError),
+    /// Le joueur n'a rien acheté.
+    Skipped,
+}
+
+/// Retourne les pièces gagnées pour un score moyen de manche.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::items::coins_earned;
+///
+/// assert_eq!(coins_earned(87), 8);
+/// ```
+pub fn coins_earned(average: u32) -> u32 {
+    average / COIN_DIVISOR
+}
+
+/// Achète un objet pour le joueur : ses pièces sont débitées et l'effet de l'objet appliqué.
+///
+/// # Retour
+///
+/// * `Ok(())` si l'achat a eu lieu.
+/// * `Err(ShopError)` si le joueur ne peut pas acheter l'objet (voir [`Item::check`]) ; il n'est
+///   alors pas modifié.
+pub fn buy(player: &mut Player, item: Item) -> Result<(), ShopError> {
+    item.check(player)?;
+    player.coins -= item.price();
+    item.apply(player);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poison::PoisonType;
+
+    /// Vérifie qu'un achat n'a lieu que si le joueur en a les moyens, au centime près, et qu'un achat
+    /// refusé ne modifie pas le joueur.
+    #[test]
+    fn test_buy_affordability() {
+        let mut player = Player::new(String::from("Alice"), 50, 75, 50);
+        player.coins = 14;
+        assert_eq!(
+            buy(&mut player, Item::Sharpening),
+            Err(ShopError::NotEnoughCoins {
+                price: 15,
+                coins: 14
+            })
+        );
+        assert_eq!((player.coins, player.strength), (14, 50));
+
+        player.coins = 15;
+        assert_eq!(buy(&mut player, Item::Sharpening), Ok(()));
+        assert_eq!((player.coins, player.strength), (0, 55));
+    }
+
+    /// Vérifie l'effet de chaque objet : force, délai du compteur borné par [`MIN_SPEED`], vitalité
+    /// bornée par son maximum et poison annulé.
+    #[test]
+    fn test_item_effects() {
+        let mut player = Player::new(String::from("Alice"), 50, 12, 50);
+        player.coins = 100;
+        buy(&mut player, Item::Shoes).unwrap();
+        assert_eq!(player.speed, MIN_SPEED);
+
+        player.take_damage(4);
+        buy(&mut player, Item::Potion).unwrap();
+        assert_eq!(player.vitality(), 50);
+
+        assert_eq!(
+            buy(&mut player, Item::Antidote),
+            Err(ShopError::NothingToCure)
+        );
+        player.apply_poison(PoisonType::Strength);
+        player.apply_poison(PoisonType::Speed);
+        player.speed = 30;
+        buy(&mut player, Item::Antidote).unwrap();
+        assert_eq!((player.speed, player.strength), (35, 45));
+        assert_eq!(player.poisons, [PoisonType::Strength]);
+        assert_eq!(player.coins, 100 - 15 - 20 - 10);
+    }
+}
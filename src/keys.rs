@@ -0,0 +1,440 @@
+//! Module des touches du clavier attribuées aux actions du jeu.
+//!
+//! Les [`KeyBindings`] associent une touche à chaque [`Action`] : arrêter le compteur, dépenser un
+//! point d'endurance, mettre le compteur en pause, verrouiller le compteur partagé pour chaque
+//! joueur, ainsi que le gel et l'abandon, réservés aux commandes à venir mais déjà validés contre les
+//! conflits. Les touches se
+//! définissent dans la section `[keys]` du fichier de configuration ou avec `--bind action=touche`,
+//! puis sont validées au démarrage ([`KeyBindings::validate`]) et retenues pour toute l'application
+//! ([`set_bindings`]).
+//!
+//! Le compteur ne compare jamais les saisies à des touches fixes : il traduit chaque [`InputEvent`]
+//! en action par [`KeyBindings::translate`].
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use crate::console::InputEvent;
+use crate::messages::Msg;
+use crate::shared::SHARED_KEYS;
+use crate::tr;
+
+/// Touches retenues pour toute l'application.
+static BINDINGS: RwLock<KeyBindings> = RwLock::new(KeyBindings::DEFAULT);
+
+/// Touche du clavier, les lettres étant insensibles à la casse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// Touche ENTREE.
+    Enter,
+    /// Caractère saisi, en minuscule pour une lettre.
+    Char(char),
+}
+
+impl Key {
+    /// Retourne la touche d'un caractère, une lettre étant ramenée en minuscule.
+    pub fn char(c: char) -> Self {
+        Key::Char(c.to_ascii_lowercase())
+    }
+}
+
+impl FromStr for Key {
+    type Err = KeyBindingError;
+
+    /// Analyse une touche : `enter`, `space` (ou `entree`, `espace`), ou un caractère unique.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::keys::Key;
+    ///
+    /// assert_eq!("space".parse(), Ok(Key::Char(' ')));
+    /// assert_eq!("S".parse(), Ok(Key::Char('s')));
+    /// assert_eq!("Enter".parse(), Ok(Key::Enter));
+    /// assert!("ab".parse::<Key>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "enter" | "entree" | "entrée" => return Ok(Key::Enter),
+            "space" | "espace" => return Ok(Key::Char(' ')),
+            _ => {}
+        }
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if !c.is_control() && !c.is_whitespace() => Ok(Key::char(c)),
+            _ => Err(KeyBindingError::InvalidKey(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Enter => write!(f, "enter"),
+            Key::Char(' ') => write!(f, "space"),
+            Key::Char(c) => write!(f, "{c}"),
+        }
+    }
+}
+
+/// Action du jeu déclenchée par une touche.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Arrête le compteur.
+    Stop,
+    /// Gèle le compteur (réservée).
+    Freeze,
+    /// Dépense un point d'endurance pour ralentir le compteur.
+    Stamina,
+    /// Abandonne la partie (réservée).
+    Forfeit,
+    /// Met le compteur en pause et ouvre le menu de pause.
+    Pause,
+    /// Verrouille le compteur partagé pour le joueur d'index donné.
+    Lock(usize),
+}
+
+impl Action {
+    /// Actions configurables, dans l'ordre de la configuration.
+    pub const ALL: [Action; 7] = [
+        Action::Stop,
+        Action::Freeze,
+        Action::Stamina,
+        Action::Forfeit,
+        Action::Pause,
+        Action::Lock(0),
+        Action::Lock(1),
+    ];
+
+    /// Retourne l'identifiant de l'action, tel qu'utilisé par `--bind` et la section `[keys]`.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Action::Stop => "stop",
+            Action::Freeze => "freeze",
+            Action::Stamina => "stamina",
+            Action::Forfeit => "forfeit",
+            Action::Pause => "pause",
+            Action::Lock(0) => "lock1",
+            Action::Lock(_) => "lock2",
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = KeyBindingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Action::ALL
+            .into_iter()
+            .find(|action| action.id().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| KeyBindingError::UnknownAction(s.to_string()))
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+/// Touches attribuées aux actions du jeu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyBindings {
+    /// Arrêt du compteur.
+    pub stop: Key,
+    /// Gel du compteur.
+    pub freeze: Key,
+    /// Dépense d'un point d'endurance.
+    pub stamina: Key,
+    /// Abandon de la partie.
+    pub forfeit: Key,
+    /// Pause du compteur.
+    pub pause: Key,
+    /// Verrou du compteur partagé, dans l'ordre des joueurs.
+    pub locks: [Key; 2],
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings::DEFAULT
+    }
+}
+
+/// Erreur dans la définition des touches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyBindingError {
+    /// Une liaison `--bind` n'a pas la forme `action=touche`.
+    Syntax(String),
+    /// L'action n'existe pas.
+    UnknownAction(String),
+    /// La touche n'est ni un caractère unique, ni `enter`, ni `space`.
+    InvalidKey(String),
+    /// Plusieurs actions partagent la même touche.
+    Conflict {
+        /// Touche attribuée plusieurs fois.
+        key: Key,
+        /// Actions qui se la disputent, dans l'ordre de [`Action::ALL`].
+        actions: Vec<Action>,
+    },
+}
+
+impl fmt::Display for KeyBindingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            KeyBindingError::Syntax(spec) => tr!(Msg::KeyBindSyntax, spec = spec),
+            KeyBindingError::UnknownAction(action) => {
+                let ids: Vec<&str> = Action::ALL.iter().map(Action::id).collect();
+                tr!(
+                    Msg::KeyUnknownAction,
+                    action = action,
+                    actions = ids.join(", ")
+                )
+            }
+            KeyBindingError::InvalidKey(key) => tr!(Msg::KeyInvalid, key = key),
+            KeyBindingError::Conflict { key, actions } => {
+                let ids: Vec<&str> = actions.iter().map(Action::id).collect();
+                tr!(Msg::KeyConflict, key = key, actions = ids.join(", "))
+            }
+        };
+        write!(f, "{message}")
+    }
+}
+
+impl Error for KeyBindingError {}
+
+impl KeyBindings {
+    /// Touches par défaut : ENTREE pour arrêter, `f` pour geler, `s` pour l'endurance, `q` pour
+    /// abandonner, `p` pour la pause, et les touches [`SHARED_KEYS`] pour les verrous.
+    pub const DEFAULT: KeyBindings = KeyBindings {
+        stop: Key::Enter,
+        freeze: Key::Char('f'),
+        stamina: Key::Char('s'),
+        forfeit: Key::Char('q'),
+        pause: Key::Char('p'),
+        locks: [Key::Char(SHARED_KEYS[0]), Key::Char(SHARED_KEYS[1])],
+    };
+
+    /// Retourne la touche attribuée à une action.
+    pub fn key(&self, action: Action) -> Key {
+        match action {
+            Action::Stop => self.stop,
+            Action::Freeze => self.freeze,
+            Action::Stamina => self.stamina,
+            Action::Forfeit => self.forfeit,
+            Action::Pause => self.pause,
+            Action::Lock(player) => self.locks[player.min(1)],
+        }
+    }
+
+    /// Attribue une touche à une action.
+    pub fn set(&mut self, action: Action, key: Key) {
+        match action {
+            Action::Stop => self.stop = key,
+            Action::Freeze => self.freeze = key,
+            Action::Stamina => self.stamina = key,
+            Action::Forfeit => self.forfeit = key,
+            Action::Pause => self.pause = key,
+            Action::Lock(player) => self.locks[player.min(1)] = key,
+        }
+    }
+
+    /// Applique une liaison de la forme `action=touche`, par exemple `stop=space`.
+    ///
+    /// La liaison n'est pas validée contre les autres touches : voir [`KeyBindings::validate`].
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::keys::{Action, Key, KeyBindings};
+    ///
+    /// let mut bindings = KeyBindings::default();
+    /// bindings.bind("stop=space").unwrap();
+    /// assert_eq!(bindings.key(Action::Stop), Key::Char(' '));
+    /// assert!(bindings.bind("stop").is_err());
+    /// assert!(bindings.bind("jump=j").is_err());
+    /// ```
+    pub fn bind(&mut self, spec: &str) -> Result<(), KeyBindingError> {
+        let (action, key) = spec
+            .split_once('=')
+            .ok_or_else(|| KeyBindingError::Syntax(spec.to_string()))?;
+        self.set(action.parse()?, key.trim().parse()?);
+        Ok(())
+    }
+
+    /// Vérifie qu'aucune touche n'est attribuée à plusieurs actions.
+    ///
+    /// # Erreurs
+    ///
+    /// Retourne [`KeyBindingError::Conflict`] pour la première touche partagée, avec toutes les
+    /// actions qui se la disputent.
+    pub fn validate(&self) -> Result<(), KeyBindingError> {
+        for (index, action) in Action::ALL.iter().enumerate() {
+            let key = self.key(*action);
+            let clash: Vec<Action> = Action::ALL[index..]
+                .iter()
+                .copied()
+                .filter(|other| self.key(*other) == key)
+                .collect();
+            if clash.len() > 1 {
+                return Err(KeyBindingError::Conflict {
+                    key,
+                    actions: clash,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Retourne l'action déclenchée par une touche, `None` si elle n'est attribuée à aucune.
+    pub fn action(&self, key: Key) -> Option<Action> {
+        Action::ALL
+            .into_iter()
+            .find(|action| self.key(*action) == key)
+    }
+
+    /// Traduit une saisie du clavier en action du jeu.
+    ///
+    /// Ctrl+C et la fin du flux ne sont pas des touches configurables : ils ne donnent aucune action
+    /// et restent traités par l'appelant.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::console::InputEvent;
+    /// use dual_game::keys::{Action, KeyBindings};
+    ///
+    /// let bindings = KeyBindings::default();
+    /// assert_eq!(bindings.translate(InputEvent::Enter), Some(Action::Stop));
+    /// assert_eq!(bindings.translate(InputEvent::Char('L')), Some(Action::Lock(1)));
+    /// assert_eq!(bindings.translate(InputEvent::Char('x')), None);
+    /// ```
+    pub fn translate(&self, event: InputEvent) -> Option<Action> {
+        match event {
+            InputEvent::Enter => self.action(Key::Enter),
+            InputEvent::Char(c) => self.action(Key::char(c)),
+            InputEvent::Interrupt | InputEvent::Eof => None,
+        }
+    }
+
+    /// Traduit une ligne saisie hors du mode brut en action du jeu : une ligne vide vaut ENTREE, une
+    /// ligne d'un seul caractère vaut cette touche, les espaces autour étant ignorés.
+    pub fn translate_line(&self, line: &str) -> Option<Action> {
+        let mut chars = line.trim().chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => self.translate(InputEvent::Enter),
+            (Some(c), None) => self.translate(InputEvent::Char(c)),
+            _ => None,
+        }
+    }
+}
+
+/// Retient les touches de toute l'application, une fois validées.
+pub fn set_bindings(bindings: KeyBindings) {
+    *BINDINGS.write().unwrap_or_else(|err| err.into_inner()) = bindings;
+}
+
+/// Retourne les touches retenues, celles par défaut si aucune n'a été fixée.
+pub fn bindings() -> KeyBindings {
+    *BINDINGS.read().unwrap_or_else(|err| err.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie la détection des conflits : la touche partagée et toutes les actions qui se la
+    /// disputent sont signalées, les lettres étant comparées sans tenir compte de la casse.
+    #[test]
+    fn test_conflicts() {
+        assert_eq!(KeyBindings::default().validate(), Ok(()));
+
+        let mut bindings = KeyBindings::default();
+        bindings.bind("lock1=S").unwrap();
+        let err = bindings.validate().unwrap_err();
+        assert_eq!(
+            err,
+            KeyBindingError::Conflict {
+                key: Key::Char('s'),
+                actions: vec![Action::Stamina, Action::Lock(0)],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "touche « s » attribuée à plusieurs actions : stamina, lock1"
+        );
+
+        bindings.bind("lock1=a").unwrap();
+        bindings.bind("freeze=enter").unwrap();
+        bindings.bind("forfeit=enter").unwrap();
+        assert_eq!(
+            bindings.validate().unwrap_err().to_string(),
+            "touche « enter » attribuée à plusieurs actions : stop, freeze, forfeit"
+        );
+    }
+
+    /// Vérifie la traduction des saisies par un jeu de touches personnalisé, en mode brut comme ligne
+    /// par ligne.
+    #[test]
+    fn test_custom_translation() {
+        let mut bindings = KeyBindings::default();
+        for spec in [
+            "stop=space",
+            "stamina=Enter",
+            "lock1=q",
+            "forfeit=x",
+            "lock2 = 2",
+        ] {
+            bindings.bind(spec).unwrap();
+        }
+        assert_eq!(bindings.validate(), Ok(()));
+
+        let events = [
+            InputEvent::Char(' '),
+            InputEvent::Enter,
+            InputEvent::Char('Q'),
+            InputEvent::Char('2'),
+            InputEvent::Char('a'),
+            InputEvent::Char('s'),
+            InputEvent::Interrupt,
+            InputEvent::Eof,
+        ];
+        let actions: Vec<Option<Action>> = events.map(|event| bindings.translate(event)).into();
+        assert_eq!(
+            actions,
+            [
+                Some(Action::Stop),
+                Some(Action::Stamina),
+                Some(Action::Lock(0)),
+                Some(Action::Lock(1)),
+                None,
+                None,
+                None,
+                None,
+            ]
+        );
+        assert_eq!(bindings.translate_line("\n"), Some(Action::Stamina));
+        assert_eq!(bindings.translate_line(" q "), Some(Action::Lock(0)));
+        assert_eq!(bindings.translate_line("qq"), None);
+    }
+
+    /// Vérifie les erreurs des liaisons mal formées.
+    #[test]
+    fn test_bind_errors() {
+        let mut bindings = KeyBindings::default();
+        assert_eq!(
+            bindings.bind("stop"),
+            Err(KeyBindingError::Syntax(String::from("stop")))
+        );
+        assert_eq!(
+            bindings.bind("jump=j").unwrap_err().to_string(),
+            "action inconnue « jump » (actions : stop, freeze, stamina, forfeit, pause, lock1, lock2)"
+        );
+        assert_eq!(
+            bindings.bind("stop=ctrl").unwrap_err(),
+            KeyBindingError::InvalidKey(String::from("ctrl"))
+        );
+        assert_eq!(bindings, KeyBindings::default());
+    }
+}
@@ -1,7 +1,69 @@
-// Modules du projet.
-pub mod game;
-pub mod player;
-pub mod counter;
-pub mod objectives;
-pub mod scoring;
-pub mod poison;
+// Modules du projet.
+pub mod game;
+pub mod player;
+pub mod counter;
+pub mod objectives;
+pub mod scoring;
+pub mod poison;
+
+pub mod achievements;
+#[cfg(feature = "async")]
+pub mod async_game;
+pub mod balance;
+pub mod bundle;
+pub mod cancel;
+pub mod class;
+pub mod clock;
+pub mod commentary;
+pub mod config;
+pub mod console;
+pub mod controller;
+pub mod daily;
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub mod demo;
+#[doc(hidden)]
+pub mod env;
+pub mod export;
+pub mod game_log;
+pub mod ghost;
+pub mod history;
+pub mod items;
+pub mod keys;
+pub mod messages;
+pub mod mutators;
+pub mod net;
+#[cfg(feature = "http")]
+pub mod notifier;
+pub mod observer;
+pub mod output;
+pub mod pacing;
+#[doc(hidden)]
+pub mod panel;
+pub mod predictions;
+pub mod prelude;
+pub mod profile;
+pub mod protocol;
+pub mod referee;
+#[doc(hidden)]
+pub mod rematch;
+#[doc(hidden)]
+pub mod render;
+pub mod renderer;
+pub mod replay;
+pub mod risk;
+pub mod rules;
+pub mod saves;
+pub mod series;
+pub mod session;
+#[doc(hidden)]
+pub mod setup;
+pub mod shared;
+pub mod simulation;
+pub mod stats;
+pub mod style;
+pub mod training;
+#[cfg(feature = "tui")]
+pub mod tui;
+#[cfg(feature = "ws")]
+pub mod ws;
@@ -1,70 +1,3018 @@
 //! Point d'entrée de l'application.
 //!
-//! Ce module configure et lance la boucle principale du jeu en analysant les arguments en ligne de commande
-//! et en créant les joueurs et la partie de jeu correspondante.
+//! Ce module analyse les arguments en ligne de commande et aiguille chaque sous-commande (`play`,
+//! `practice`, `simulate`, `balance`, `replay`, `stats`, `rules`, `presets`, `demo`, `host`, `join`,
+//! `completions`, `man`, `config`) vers
+//! le point d'entrée correspondant de la bibliothèque. Sans sous-commande, `play` est exécutée pour
+//! rester compatible avec les anciennes invocations. Avec `--protocol`, `play` est pilotée par une
+//! interface externe au lieu du terminal ; avec `--tui`, elle s'affiche en plein écran. La
+//! complétion du shell et la page de manuel sont générées à partir des mêmes définitions ([`Cli`]), et
+//! restent donc à jour d'elles-mêmes.
 
+use std::cell::RefCell;
 use std::error::Error;
-use std::io::{stdin, stdout, Write};
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
-use clap::Parser;
-use dual_game::game::Game;
-use dual_game::player::Player;
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{Arg, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use dual_game::achievements;
+use dual_game::balance::{self, BalanceGrid};
+use dual_game::bundle::{BugBundle, InputRecorder};
+use dual_game::cancel::{CancelToken, INTERRUPTED_EXIT_CODE};
+use dual_game::class::PlayerClass;
+use dual_game::clock::SystemClock;
+use dual_game::config::{CONFIG_FILE_NAME, Config};
+use dual_game::console::InputQueue;
+use dual_game::controller::HumanController;
+use dual_game::daily::{DailyChallenge, DailyDate};
+use dual_game::demo::{self, DEMO_INTERLUDE, DEMO_PACE, DemoBot, PacedConsole};
+use dual_game::env::{self, Env, ProcessEnv};
+use dual_game::export::GameExport;
+use dual_game::game_log::GameLog;
+use dual_game::ghost::Ghost;
+use dual_game::history::{DEFAULT_RETAINED_ROUNDS, JsonlSink};
+use dual_game::keys::{self, KeyBindingError, KeyBindings};
+use dual_game::messages::{self, Lang, Msg};
+use dual_game::net::{
+    self, Connection, DEFAULT_PORT, NetObserver, REPLY_TIMEOUT, RemoteConsole, RemoteController,
+};
+#[cfg(feature = "http")]
+use dual_game::notifier::NotifierObserver;
+use dual_game::pacing::Pacing;
+use dual_game::panel::{self, PanelMode};
+use dual_game::player::{
+    DEFAULT_SPEED, DEFAULT_STRENGTH, DEFAULT_VITALITY, MAX_STRENGTH, PlayerConfigError,
+    default_name, disambiguate_names,
+};
+use dual_game::predictions::{DEFAULT_BETTING_WINDOW, PredictionsFile};
+use dual_game::prelude::*;
+use dual_game::profile::{
+    Profile, ProfileStore, ProgressLoss, XP_PER_LEVEL, confirm_permadeath, leaderboard,
+    level_strength_bonus,
+};
+use dual_game::protocol::{ProtocolController, ProtocolIo, ProtocolObserver};
+use dual_game::rematch::{RematchDecision, rematch_menu};
+use dual_game::renderer::FancyRenderer;
+use dual_game::replay::{Replay, VerificationResult};
+use dual_game::rules::{self, DEFAULT_DUEL_FACTOR, Preset};
+use dual_game::saves::{SaveState, SaveStore, SavedPlayer, list_slots};
+use dual_game::series::Series;
+use dual_game::session::Session;
+use dual_game::setup::{DEFAULT_OBJECTIFS, SetupChoices, edit, wizard};
+use dual_game::shared::TerminalSharedCounter;
+use dual_game::simulation;
+use dual_game::stats::ObjectiveHeatmap;
+use dual_game::style::{self, Color, Theme};
+use dual_game::tr;
+#[cfg(feature = "tui")]
+use dual_game::tui::{SharedTui, Tui, TuiConsole, TuiController, TuiObserver};
+#[cfg(feature = "ws")]
+use dual_game::ws::{self, DEFAULT_WS_PORT};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
-/// Structure gérant les arguments en ligne de commande.
+/// Texte affiché à la fin de l'aide, décrivant les variables d'environnement.
+const ENV_HELP: &str = "Chaque option peut aussi être donnée par une variable d'environnement \
+DUAL_GAME_<OPTION> (ex. DUAL_GAME_NAME1, DUAL_GAME_LOG_FILE=partie.log, DUAL_GAME_FATIGUE=1).
+Priorité : ligne de commande > environnement > fichier de configuration > valeurs par défaut.";
+
+/// Arguments en ligne de commande de l'application.
+///
+/// Les options absentes de la ligne de commande peuvent être données par des variables
+/// d'environnement (voir [`Cli::expand_env`]).
+#[derive(Parser)]
+#[command(
+    author,
+    version,
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true,
+    after_help = ENV_HELP
+)]
+struct Cli {
+    #[command(flatten)]
+    common: CommonArgs,
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Options de `play`, acceptées sans sous-commande.
+    #[command(flatten, next_help_heading = "Options de play")]
+    play: PlayArgs,
+}
+
+impl Cli {
+    /// Analyse la ligne de commande, complétée par les variables d'environnement `DUAL_GAME_*` (voir
+    /// [`Cli::expand_env`]).
+    #[cfg(test)]
+    fn parse_with_env<I, T>(argv: I, env: &dyn Env) -> Result<Cli, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        Cli::try_parse_from(Cli::expand_env(argv, env)?)
+    }
+
+    /// Complète la ligne de commande par les variables d'environnement `DUAL_GAME_*` et retourne les
+    /// arguments obtenus, tels qu'ils seront analysés et consignés dans un rapport de bogue.
+    ///
+    /// Chaque option de la sous-commande exécutée (options communes comprises) absente de la ligne de
+    /// commande est reprise de sa variable, si elle est définie et non vide. Une option en conflit avec
+    /// une option donnée en ligne de commande n'est pas reprise : la ligne de commande l'emporte.
+    fn expand_env<I, T>(argv: I, env: &dyn Env) -> Result<Vec<OsString>, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let mut argv: Vec<OsString> = argv.into_iter().map(Into::into).collect();
+        let mut command = Cli::command();
+        command.build();
+        let mut matches = command.clone().try_get_matches_from(&argv)?;
+        let mut scope = &command;
+        while let Some((name, sub_matches)) = matches.subcommand() {
+            scope = scope
+                .find_subcommand(name)
+                .expect("sous-commande analysée par clap");
+            matches = sub_matches.clone();
+        }
+
+        let given: Vec<&Arg> = scope
+            .get_arguments()
+            .filter(|arg| {
+                matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine)
+            })
+            .collect();
+        for arg in scope.get_arguments() {
+            let Some(long) = arg.get_long() else {
+                continue;
+            };
+            let conflicts = scope.get_arg_conflicts_with(arg);
+            let blocked = given.iter().any(|other| {
+                other.get_id() == arg.get_id()
+                    || conflicts.iter().any(|c| c.get_id() == other.get_id())
+                    || scope
+                        .get_arg_conflicts_with(other)
+                        .iter()
+                        .any(|c| c.get_id() == arg.get_id())
+            });
+            if blocked || matches!(long, "help" | "version") {
+                continue;
+            }
+            let name = env::var_name(long);
+            let Some(value) = env.var(&name).filter(|value| !value.is_empty()) else {
+                continue;
+            };
+            if arg.get_action().takes_values() {
+                argv.push(format!("--{long}={value}").into());
+            } else if env::parse_flag(&name, &value)
+                .map_err(|err| clap::Error::raw(ErrorKind::InvalidValue, format!("{err}\n")))?
+            {
+                argv.push(format!("--{long}").into());
+            }
+        }
+        Ok(argv)
+    }
+
+    /// Sépare les options communes de la sous-commande à exécuter, `play` par défaut.
+    fn into_parts(self) -> (CommonArgs, Command) {
+        (
+            self.common,
+            self.command.unwrap_or(Command::Play(Box::new(self.play))),
+        )
+    }
+}
+
+/// Options communes à toutes les sous-commandes.
+///
+/// - `--config` : Fichier de configuration (défaut: `./dual_game.toml` puis `~/.config/dual_game/config.toml`).
+/// - `--seed` : Graine rendant le tirage des objectifs et les décisions des bots reproductibles.
+/// - `--ascii` : Limite l'affichage aux caractères ASCII (avatars masqués).
+/// - `--no-color` : Désactive les couleurs (également désactivées si `NO_COLOR` est définie).
+/// - `--theme` : Thème de l'affichage : `default`, `high-contrast` ou `deuteranopia`.
+/// - `--force-tty`, `--no-tty` : Traite la sortie standard comme un terminal ou non (défaut: détecté).
+/// - `--accessible` : Affichage adapté aux lecteurs d'écran, en phrases complètes et sans retour chariot.
+/// - `--announce-interval` : Intervalle en millisecondes des annonces du compteur en mode accessible.
+/// - `--lang` : Langue de l'affichage, `fr` ou `en` (défaut: d'après `LANG`, sinon `fr`).
+/// - `--bind` : Attribue une touche à une action, par exemple `stop=space` (répétable).
+/// - `--quiet`, `--verbose` : Niveau de détail de l'affichage des parties.
+#[derive(clap::Args)]
+#[command(next_help_heading = "Options communes")]
+struct CommonArgs {
+    /// Fichier de configuration (défaut: ./dual_game.toml puis ~/.config/dual_game/config.toml)
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    /// Graine rendant le tirage des objectifs et les décisions des bots reproductibles
+    #[arg(long, global = true)]
+    seed: Option<u64>,
+    /// Limite l'affichage aux caractères ASCII pour les terminaux rudimentaires (avatars masqués)
+    #[arg(long, global = true)]
+    ascii: bool,
+    /// Désactive les couleurs (également désactivées si la variable d'environnement NO_COLOR est définie)
+    #[arg(long, global = true)]
+    no_color: bool,
+    /// Thème de l'affichage : default, high-contrast (gras et vidéo inverse au lieu des couleurs) ou deuteranopia (défaut: default)
+    #[arg(long, global = true)]
+    theme: Option<Theme>,
+    /// Anime le compteur et garde les couleurs même si la sortie standard n'est pas un terminal
+    #[arg(long, global = true, conflicts_with_all = ["no_tty", "accessible"])]
+    force_tty: bool,
+    /// N'affiche que la valeur finale du compteur, sans couleurs ni retours chariot, comme lorsque la sortie standard est redirigée
+    #[arg(long, global = true)]
+    no_tty: bool,
+    /// Affichage adapté aux lecteurs d'écran : phrases complètes, ni couleurs, ni décorations, ni retours chariot, et compteur annoncé à intervalles réguliers
+    #[arg(long, global = true)]
+    accessible: bool,
+    /// Intervalle en millisecondes entre deux annonces du compteur en mode accessible, 0 pour n'annoncer que l'entrée dans la zone chaude
+    #[arg(long, global = true, value_name = "MS", default_value_t = 1000)]
+    announce_interval: u64,
+    /// Langue de l'affichage : fr ou en (défaut: d'après la variable d'environnement LANG, sinon fr)
+    #[arg(long, global = true)]
+    lang: Option<Lang>,
+    /// Attribue une touche à une action (stop, freeze, stamina, forfeit, pause, lock1, lock2), par exemple stop=space ; répétable
+    #[arg(long, global = true, value_name = "ACTION=TOUCHE")]
+    bind: Vec<String>,
+    /// N'affiche que les résultats des manches et le vainqueur (ainsi que les invites des joueurs humains)
+    #[arg(long, short, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    /// Détaille chaque objectif (écart, score) et la durée de chaque arrêt du compteur
+    #[arg(long, short, global = true)]
+    verbose: bool,
+}
+
+impl CommonArgs {
+    /// Retourne le niveau de détail de l'affichage demandé par `--quiet` ou `--verbose`.
+    fn verbosity(&self) -> Verbosity {
+        if self.quiet {
+            Verbosity::Quiet
+        } else if self.verbose {
+            Verbosity::Verbose
+        } else {
+            Verbosity::Normal
+        }
+    }
+}
+
+/// Options de la sous-commande `play`, qui lance une ou plusieurs parties entre deux joueurs.
 ///
 /// Les paramètres suivants sont disponibles :
-/// - `--name1` : Nom du premier joueur.
-/// - `--name2` : Nom du deuxième joueur.
+/// - `--name1` : Nom du premier joueur (défaut: « Joueur 1 », ou l'assistant dans un terminal).
+/// - `--name2` : Nom du deuxième joueur (défaut: « Joueur 2 », ou l'assistant dans un terminal).
+/// - `--player1`, `--player2` : Raccourci `nom:vitalité:vitesse:force` définissant un joueur complet.
 /// - `--vitality` : Vitalité initiale des joueurs (défaut: 50).
+/// - `--speed1`, `--speed2` : Vitesse des joueurs, en millisecondes par incrémentation (défaut: 75).
+/// - `--strength1`, `--strength2` : Force des joueurs (défaut: 50).
 /// - `--objectifs` : Nombre d’objectifs par tour (défaut: 5).
-#[derive(Parser)]
-#[command(author, version, about, long_about = None)]
-struct Args {
-    /// Nom du premier joueur
+/// - `--armor` : Armure des joueurs (défaut: 0).
+/// - `--class1`, `--class2` : Classe prédéfinie des joueurs, remplaçant les caractéristiques individuelles.
+/// - `--profile1`, `--profile2` : Profils persistants des joueurs, remplaçant `--name1`/`--name2`.
+/// - `--luck1`, `--luck2` : Chance des joueurs (0 à 20), rapprochant leurs objectifs de 50 (défaut: 0).
+/// - `--color1`, `--color2` : Couleur du nom des joueurs (défaut: `cyan` et `magenta`).
+/// - `--avatar1`, `--avatar2` : Avatar (emoji ou caractère unique) affiché devant le nom des joueurs.
+/// - `--assist1`, `--assist2` : Assistance ralentissant le compteur près de l'objectif, en pourcentage (défaut: 60).
+/// - `--fatigue` : Active la fatigue, réduisant la force effective des joueurs après la 5e manche.
+/// - `--stamina` : Active l'endurance, permettant de ralentir le compteur avec la touche `s`.
+/// - `--shop` : Active la boutique, où les pièces gagnées à chaque manche s'échangent contre des objets.
+/// - `--mirror` : Mode miroir, où les joueurs échangent leur vitesse et leur force avant la première manche.
+/// - `--risk-choice` : Prise de risque, où chaque joueur choisit 3, 5 ou 7 objectifs avant son tour, pour une moyenne multipliée par 0,9, 1 ou 1,15.
+/// - `--mutators` : Mutateurs, où un modificateur tiré au sort au début de chaque manche s'applique aux deux joueurs pour la manche.
+/// - `--bans` : Interdictions, où le joueur qui a le moins de vitalité interdit un poison au début de chaque manche.
+/// - `--retry` : Rejeu, où le perdant d'une manche peut rejouer une fois un objectif lors d'une manche suivante.
+/// - `--time-tiebreak` : Départage au temps, où une manche à égalité revient au joueur dont les arrêts ont été les plus rapides.
+/// - `--preset` : Préréglage des règles (`classic`, `blitz`, `marathon` ou `party`), dont chaque valeur cède aux options données.
+/// - `--best-of` : Série au meilleur des N parties, enchaînées jusqu'à ce qu'un joueur en remporte la majorité.
+/// - `--carry-over` : Avec `--best-of`, le vainqueur d'une partie commence la suivante avec 25 % de sa vitalité restante en bonus (au plus 15).
+/// - `--bot` : Le deuxième joueur est contrôlé par un bot (`easy`, `normal` ou `hard`, défaut: `normal`).
+/// - `--mode` : Déroulement des manches, `classic`, `duel` (objectifs disputés un à un) ou `shared` (compteur commun, touches `a` et `l`) ; défaut: `classic`.
+/// - `--duel-factor` : Vitalité perdue par point d'écart en duel (défaut: 10).
+/// - `--record` : Enregistre la rediffusion de chaque partie dans le fichier donné, au fil de la partie.
+/// - `--log-file` : Consigne chaque événement des parties, horodaté, dans le fichier donné.
+/// - `--record-inputs` : Enregistre les saisies de la session dans un rapport de bogue, avec la graine et la configuration.
+/// - `--replay-inputs` : Rejoue sans terminal la session d'un rapport de bogue.
+/// - `--notify-url` : Envoie le résultat de chaque partie en JSON à l'URL donnée (fonctionnalité `http`).
+/// - `--rules` : Affiche les règles du jeu, selon les options et la configuration, puis quitte.
+///
+/// Les options non renseignées sont reprises du fichier de configuration, puis des valeurs par défaut.
+#[derive(clap::Args, Clone, Default)]
+struct PlayArgs {
+    /// Nom du premier joueur (défaut: assistant dans un terminal, sinon « Joueur 1 »)
+    #[arg(long, conflicts_with = "profile1")]
+    name1: Option<String>,
+    /// Nom du deuxième joueur (défaut: « Bot » avec --bot, assistant dans un terminal, sinon « Joueur 2 »)
+    #[arg(long, conflicts_with = "profile2")]
+    name2: Option<String>,
+    /// Premier joueur complet, au format nom:vitalité:vitesse:force (ex. « Alice:50:75:50 »)
+    #[arg(long, conflicts_with_all = ["name1", "profile1", "speed1", "strength1"])]
+    player1: Option<PlayerSpec>,
+    /// Deuxième joueur complet, au format nom:vitalité:vitesse:force (ex. « Bob:60:90:40 »)
+    #[arg(long, conflicts_with_all = ["name2", "profile2", "speed2", "strength2"])]
+    player2: Option<PlayerSpec>,
+    /// Vitalité initiale des joueurs (défaut: 50)
     #[arg(long)]
-    name1: String,
-    /// Nom du deuxième joueur
+    vitality: Option<u32>,
+    /// Vitesse du premier joueur, en millisecondes par incrémentation du compteur (défaut: 75)
     #[arg(long)]
-    name2: String,
-    /// Vitalité initiale des joueurs (défaut: 50)
-    #[arg(long, default_value_t = 50)]
+    speed1: Option<u32>,
+    /// Vitesse du deuxième joueur, en millisecondes par incrémentation du compteur (défaut: 75)
+    #[arg(long)]
+    speed2: Option<u32>,
+    /// Force du premier joueur (défaut: 50)
+    #[arg(long)]
+    strength1: Option<u32>,
+    /// Force du deuxième joueur (défaut: 50)
+    #[arg(long)]
+    strength2: Option<u32>,
+    /// Nombre d’objectifs par tour (défaut: 5)
+    #[arg(long)]
+    objectifs: Option<usize>,
+    /// Armure des joueurs, réduisant les dégâts subis par manche perdue (défaut: 0)
+    #[arg(long)]
+    armor: Option<u32>,
+    /// Classe du premier joueur (balanced, sprinter, bruiser, tank)
+    #[arg(long)]
+    class1: Option<PlayerClass>,
+    /// Classe du deuxième joueur (balanced, sprinter, bruiser, tank)
+    #[arg(long)]
+    class2: Option<PlayerClass>,
+    /// Profil du premier joueur, chargé depuis ~/.dual_game/profiles (créé s'il n'existe pas)
+    #[arg(long)]
+    profile1: Option<String>,
+    /// Profil du deuxième joueur, chargé depuis ~/.dual_game/profiles (créé s'il n'existe pas)
+    #[arg(long)]
+    profile2: Option<String>,
+    /// Chance du premier joueur, de 0 à 20 : ses objectifs se rapprochent de 50 (défaut: 0)
+    #[arg(long)]
+    luck1: Option<u32>,
+    /// Chance du deuxième joueur, de 0 à 20 : ses objectifs se rapprochent de 50 (défaut: 0)
+    #[arg(long)]
+    luck2: Option<u32>,
+    /// Couleur du nom du premier joueur (red, green, yellow, blue, magenta, cyan ; défaut: cyan)
+    #[arg(long)]
+    color1: Option<Color>,
+    /// Couleur du nom du deuxième joueur (red, green, yellow, blue, magenta, cyan ; défaut: magenta)
+    #[arg(long)]
+    color2: Option<Color>,
+    /// Avatar du premier joueur (emoji ou caractère unique), affiché devant son nom
+    #[arg(long)]
+    avatar1: Option<String>,
+    /// Avatar du deuxième joueur (emoji ou caractère unique), affiché devant son nom
+    #[arg(long)]
+    avatar2: Option<String>,
+    /// Assistance du premier joueur : à 10 de l'objectif au plus, le compteur ralentit du pourcentage donné (défaut: 60), et ses scores sont signalés « (assisté) »
+    #[arg(long, num_args = 0..=1, default_missing_value = "60")]
+    assist1: Option<u32>,
+    /// Assistance du deuxième joueur : à 10 de l'objectif au plus, le compteur ralentit du pourcentage donné (défaut: 60), et ses scores sont signalés « (assisté) »
+    #[arg(long, num_args = 0..=1, default_missing_value = "60")]
+    assist2: Option<u32>,
+    /// Active la fatigue : après la 5e manche, chaque manche retire 2 points de force effective aux joueurs
+    #[arg(long)]
+    fatigue: bool,
+    /// Active l'endurance : 3 points par partie, chacun ralentissant le compteur de 50 % pour un objectif (touche s)
+    #[arg(long)]
+    stamina: bool,
+    /// Active la boutique : chaque manche rapporte le score moyen divisé par 10 en pièces, dépensées entre les manches en objets
+    #[arg(long)]
+    shop: bool,
+    /// Mode miroir : avant la première manche, les deux joueurs échangent leur vitesse et leur force (vitalité inchangée)
+    #[arg(long)]
+    mirror: bool,
+    /// Prise de risque : avant chaque tour, choix entre 3 objectifs (moyenne × 0,9), 5 ou 7 (moyenne × 1,15)
+    #[arg(long)]
+    risk_choice: bool,
+    /// Mutateurs : au début de chaque manche, un modificateur tiré au sort (Brouillard, Tempête, Gravité, Pactole) s'applique aux deux joueurs
+    #[arg(long)]
+    mutators: bool,
+    /// Interdictions : au début de chaque manche, le joueur qui a le moins de vitalité peut interdire un poison, que le vainqueur de la manche ne pourra pas choisir
+    #[arg(long)]
+    bans: bool,
+    /// Rejeu : le perdant d'une manche gagne un rejeu (un seul en réserve), qui lui permet de rejouer aussitôt un objectif lors d'une manche suivante ; le second arrêt est retenu, même s'il est moins bon
+    #[arg(long)]
+    retry: bool,
+    /// Départage au temps : une manche à égalité de scores revient au joueur dont les arrêts du compteur ont duré le moins longtemps au total, pour 3 points de dégâts ; à durées égales, la manche reste nulle
+    #[arg(long)]
+    time_tiebreak: bool,
+    /// Poison différé : le poison choisi par le vainqueur d'une manche ne fait effet qu'au début de la manche suivante, annoncé à sa victime ; il est perdu si la partie se termine avant
+    #[arg(long)]
+    delayed_poison: bool,
+    /// Malédiction du vainqueur : le joueur qui remporte une manche avec plus de 60 points d'écart de score perd 3 points de vitesse effective (compteur plus rapide) pendant la manche suivante
+    #[arg(long)]
+    winners_curse: bool,
+    /// Préréglage des règles, dont chaque valeur cède aux options données : classic (règles par défaut), blitz (3 objectifs, compteur rapide, fatigue), marathon (8 objectifs, endurance) ou party (mutateurs, boutique) ; voir `dual_game presets`
+    #[arg(long)]
+    preset: Option<Preset>,
+    /// Série au meilleur des N parties : les parties s'enchaînent jusqu'à ce qu'un joueur en remporte la majorité, les matchs nuls étant rejoués
+    #[arg(long)]
+    best_of: Option<u32>,
+    /// Report de vitalité en série : le vainqueur d'une partie commence la suivante avec 25 % de sa vitalité restante en bonus, au plus 15
+    #[arg(long, requires = "best_of")]
+    carry_over: bool,
+    /// Confie le deuxième joueur à un bot de la difficulté donnée (easy, normal, hard ; défaut: normal)
+    #[arg(long, num_args = 0..=1, default_missing_value = "normal")]
+    bot: Option<Difficulty>,
+    /// Confie à ce spectateur le rôle d'arbitre : avant chaque manche, il pronostique en secret le vainqueur pendant que les joueurs détournent le regard ; après 3 pronostics justes d'affilée, il impose un mutateur de son choix à la manche suivante
+    #[arg(long, value_name = "NOM", conflicts_with = "protocol")]
+    referee: Option<String>,
+    /// Déroulement des manches : classic, duel où les joueurs visent tour à tour chaque objectif pour un point, ou shared où un seul compteur défile pour les deux joueurs (touches a et l) (défaut: classic)
+    #[arg(long)]
+    mode: Option<GameMode>,
+    /// Vitalité perdue par le perdant d'une manche en duel, par point d'écart (défaut: 10)
+    #[arg(long)]
+    duel_factor: Option<u32>,
+    /// Anti-stagnation : nombre de manches observées (0 pour la désactiver) ; si elles ont infligé moins de 2 points de dégâts par manche, chaque manche perdue coûte au moins 5 points de vitalité jusqu'à une manche de 10 points de dégâts ou plus (défaut: 5)
+    #[arg(long, value_name = "MANCHES")]
+    stalemate_rounds: Option<u32>,
+    /// Enregistre la rediffusion de chaque partie dans ce fichier, au fil de la partie (relue avec `dual_game replay`)
+    #[arg(long)]
+    record: Option<PathBuf>,
+    /// Exporte le résultat et les statistiques de chaque partie dans ce fichier (JSON)
+    #[arg(long)]
+    export: Option<PathBuf>,
+    /// Consigne chaque événement des parties, horodaté, dans ce fichier
+    #[arg(long)]
+    log_file: Option<PathBuf>,
+    /// Enregistre chaque saisie, horodatée, avec la graine, la ligne de commande et la configuration dans ce rapport de bogue (JSON), rejoué avec --replay-inputs
+    #[arg(long, value_name = "FICHIER", conflicts_with = "replay_inputs")]
+    record_inputs: Option<PathBuf>,
+    /// Rejoue sans terminal la session d'un rapport de bogue écrit avec --record-inputs : sa ligne de commande, sa graine, sa configuration et ses saisies, dans l'ordre (les autres options sont ignorées)
+    #[arg(long, value_name = "FICHIER")]
+    replay_inputs: Option<PathBuf>,
+    /// Écrit avant chaque manche un instantané JSON (manche, caractéristiques des joueurs) dans ce fichier pour les pronostics des spectateurs, puis y ajoute l'issue de la manche
+    #[arg(long)]
+    predictions_file: Option<PathBuf>,
+    /// Sauvegarde la partie à la fin de chaque manche dans cet emplacement de ~/.dual_game/saves, en remplaçant la sauvegarde précédente
+    #[arg(long)]
+    autosave: Option<String>,
+    /// Reprend la partie sauvegardée dans cet emplacement, avec ses joueurs, ses règles et son nombre d'objectifs (voir `dual_game saves list`)
+    #[arg(long, conflicts_with_all = ["profile1", "profile2", "protocol"])]
+    load_slot: Option<String>,
+    /// Durée en secondes de la fenêtre de paris, pendant laquelle la partie attend après l'écriture du fichier de pronostics (défaut: 10)
+    #[arg(long, requires = "predictions_file")]
+    betting_window: Option<u64>,
+    /// Envoie le résultat de chaque partie en JSON à cette URL (requête POST)
+    #[cfg(feature = "http")]
+    #[arg(long)]
+    notify_url: Option<String>,
+    /// Joue une seule partie pilotée par une interface externe : commandes sur l'entrée standard, événements sur la sortie standard
+    #[arg(long, conflicts_with_all = ["profile1", "profile2"])]
+    protocol: bool,
+    /// Affiche les parties dans une interface plein écran du terminal
+    #[cfg(feature = "tui")]
+    #[arg(long, conflicts_with = "protocol")]
+    tui: bool,
+    /// Rendu du déroulement des parties (plain, fancy, json, accessible, plain-ascii ; défaut: plain)
+    #[arg(long, default_value_t)]
+    render: RenderStyle,
+    /// Rendu ASCII à mots-clés anglais stables (ROUND 3 START, DAMAGE Bob 12) pour les chaînes de journalisation, sans couleurs, emoji ni retours chariot, quelle que soit la langue (raccourci de `--render plain-ascii`)
+    #[arg(long, conflicts_with = "render")]
+    plain_ascii: bool,
+    /// Désactive les animations du rendu fancy (barre de vie qui se vide, poison qui clignote), également désactivées si la sortie standard n'est pas un terminal
+    #[arg(long)]
+    no_animations: bool,
+    /// Fait sonner le terminal à chaque dépassement du compteur, arrêt parfait et fin de partie
+    #[arg(long)]
+    sound: bool,
+    /// Remplace l'attente d'ENTREE au début de chaque tour par un compte à rebours de 3 secondes
+    #[arg(long)]
+    auto_continue: bool,
+    /// Demande à chaque joueur d'appuyer sur ENTREE avant de passer à la manche suivante
+    #[arg(long)]
+    pause_between_rounds: bool,
+    /// Pause en millisecondes après le résultat de chaque objectif (défaut: 0)
+    #[arg(long, value_name = "MS")]
+    turn_delay: Option<u64>,
+    /// Affiche les règles du jeu, selon les options et la configuration, puis quitte
+    #[arg(long)]
+    rules: bool,
+}
+
+impl PlayArgs {
+    /// Retourne la cadence des manches demandée.
+    fn pacing(&self) -> Pacing {
+        Pacing {
+            auto_continue: self.auto_continue,
+            pause_between_rounds: self.pause_between_rounds,
+            turn_delay: Duration::from_millis(self.turn_delay.unwrap_or(0)),
+        }
+    }
+
+    /// Retourne les règles optionnelles demandées.
+    fn game_rules(&self) -> GameRules {
+        GameRules {
+            fatigue: self.fatigue,
+            stamina: self.stamina,
+            mode: self.mode.unwrap_or_default(),
+            duel_factor: self.duel_factor.unwrap_or(DEFAULT_DUEL_FACTOR),
+            shop: self.shop,
+            mirror: self.mirror,
+            risk_choice: self.risk_choice,
+            mutators: self.mutators,
+            bans: self.bans,
+            retry: self.retry,
+            time_tiebreak: self.time_tiebreak,
+            stalemate_rounds: self
+                .stalemate_rounds
+                .unwrap_or(rules::DEFAULT_STALEMATE_ROUNDS),
+            max_objectives: rules::DEFAULT_MAX_OBJECTIVES,
+            delayed_poison: self.delayed_poison,
+            winners_curse: self.winners_curse,
+        }
+    }
+
+    /// Indique si les parties sont affichées dans l'interface plein écran du terminal (`--tui`).
+    fn full_screen(&self) -> bool {
+        #[cfg(feature = "tui")]
+        return self.tui;
+        #[cfg(not(feature = "tui"))]
+        false
+    }
+
+    /// Crée le rendu demandé, remplacé par le rendu accessible avec `--accessible` sauf s'il s'agit du
+    /// rendu ASCII destiné aux journaux. Le rendu fancy est animé sauf avec `--no-animations` ou hors
+    /// d'un terminal, et son tableau des scores est épinglé en haut du terminal lorsque celui-ci le
+    /// permet, hors de l'interface plein écran.
+    fn renderer(&self) -> Box<dyn Renderer> {
+        if self.render == RenderStyle::PlainAscii {
+            return self.render.renderer();
+        }
+        if style::is_accessible() {
+            return RenderStyle::Accessible.renderer();
+        }
+        if self.render != RenderStyle::Fancy {
+            return self.render.renderer();
+        }
+        let renderer = if !self.no_animations && style::is_tty() {
+            FancyRenderer::animated(Box::new(SystemClock::default()))
+        } else {
+            FancyRenderer::default()
+        };
+        let panel = if self.full_screen() {
+            PanelMode::Reprint
+        } else {
+            PanelMode::detect(&ProcessEnv, style::is_tty(), panel::terminal_rows())
+        };
+        Box::new(renderer.with_panel(panel))
+    }
+
+    /// Complète les options non renseignées avec le préréglage `--preset`, avant la fusion de la
+    /// configuration : les options données l'emportent sur le préréglage, qui l'emporte sur le
+    /// fichier de configuration.
+    fn apply_preset(&mut self) {
+        let Some(preset) = self.preset else {
+            return;
+        };
+        let rules = preset.rules;
+        self.objectifs = self.objectifs.or(preset.objectives);
+        self.speed1 = self.speed1.or(preset.speed);
+        self.speed2 = self.speed2.or(preset.speed);
+        self.fatigue |= rules.fatigue;
+        self.stamina |= rules.stamina;
+        self.shop |= rules.shop;
+        self.mirror |= rules.mirror;
+        self.risk_choice |= rules.risk_choice;
+        self.mutators |= rules.mutators;
+        self.bans |= rules.bans;
+        self.retry |= rules.retry;
+        self.time_tiebreak |= rules.time_tiebreak;
+        self.delayed_poison |= rules.delayed_poison;
+        self.winners_curse |= rules.winners_curse;
+        self.mode = self.mode.or(Some(rules.mode));
+        self.duel_factor = self.duel_factor.or(Some(rules.duel_factor));
+        self.stalemate_rounds = self.stalemate_rounds.or(Some(rules.stalemate_rounds));
+    }
+
+    /// Reporte le nom des raccourcis `--player1`/`--player2` sur `--name1`/`--name2`, avant la fusion
+    /// de la configuration.
+    fn apply_player_specs(&mut self) {
+        if let Some(spec) = &self.player1 {
+            self.name1 = Some(spec.name.clone());
+        }
+        if let Some(spec) = &self.player2 {
+            self.name2 = Some(spec.name.clone());
+        }
+    }
+}
+
+/// Joueur complet décrit par le raccourci `nom:vitalité:vitesse:force`.
+#[derive(Clone, Debug, PartialEq)]
+struct PlayerSpec {
+    /// Nom du joueur.
+    name: String,
+    /// Vitalité initiale.
     vitality: u32,
+    /// Vitesse du compteur.
+    speed: u32,
+    /// Force.
+    strength: u32,
+}
+
+impl FromStr for PlayerSpec {
+    type Err = String;
+
+    /// Analyse un raccourci `nom:vitalité:vitesse:force` ; les erreurs nomment le champ fautif.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split(':').collect();
+        let [name, vitality, speed, strength] = fields[..] else {
+            return Err(tr!(Msg::SpecFieldCount, count = fields.len(), spec = s));
+        };
+        let number = |field: Msg, value: &str| {
+            value
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| tr!(Msg::SpecInvalidField, field = tr!(field), value = value))
+        };
+        if name.trim().is_empty() {
+            return Err(tr!(Msg::SpecEmptyName));
+        }
+        Ok(PlayerSpec {
+            name: name.to_string(),
+            vitality: number(Msg::FieldVitality, vitality)?,
+            speed: number(Msg::FieldSpeed, speed)?,
+            strength: number(Msg::FieldStrength, strength)?,
+        })
+    }
+}
+
+/// Options de la sous-commande `practice`, un entraînement en solo sans adversaire.
+#[derive(clap::Args)]
+struct PracticeArgs {
+    /// Nom du joueur
+    #[arg(long, default_value = "Joueur")]
+    name: String,
+    /// Classe du joueur (balanced, sprinter, bruiser, tank)
+    #[arg(long)]
+    class: Option<PlayerClass>,
+    /// Vitesse du joueur, en millisecondes par incrémentation du compteur (défaut: 75)
+    #[arg(long)]
+    speed: Option<u32>,
+    /// Force du joueur (défaut: 50)
+    #[arg(long)]
+    strength: Option<u32>,
+    /// Chance du joueur, de 0 à 20 (défaut: 0)
+    #[arg(long)]
+    luck: Option<u32>,
+    /// Nombre d'essais
+    #[arg(long, default_value_t = 3)]
+    turns: u32,
+    /// Nombre d’objectifs par essai
+    #[arg(long, default_value_t = DEFAULT_OBJECTIFS)]
+    objectifs: usize,
+    /// Active l'endurance (touche s pour ralentir le compteur)
+    #[arg(long)]
+    stamina: bool,
+    /// Enregistre les essais dans ce fichier, au fil de l'entraînement (réutilisable avec `--ghost`)
+    #[arg(long)]
+    record: Option<PathBuf>,
+    /// Affronte le fantôme d'un entraînement enregistré avec `--record`
+    #[arg(long)]
+    ghost: Option<PathBuf>,
+}
+
+/// Options de la sous-commande `daily`, le défi du jour.
+#[derive(clap::Args)]
+struct DailyArgs {
+    /// Nom du joueur
+    #[arg(long, default_value = "Joueur")]
+    name: String,
+    /// Profil où conserver le meilleur score du jour (défaut: le nom du joueur)
+    #[arg(long)]
+    profile: Option<String>,
+}
+
+/// Options de la sous-commande `simulate`, qui enchaîne des parties entre deux bots.
+#[derive(clap::Args)]
+struct SimulateArgs {
+    /// Nombre de parties à simuler
+    #[arg(long, default_value_t = 100)]
+    games: u32,
+    /// Difficulté du premier bot (easy, normal, hard)
+    #[arg(long, default_value = "normal")]
+    bot1: Difficulty,
+    /// Difficulté du deuxième bot (easy, normal, hard)
+    #[arg(long, default_value = "normal")]
+    bot2: Difficulty,
+    /// Classe du premier bot (balanced, sprinter, bruiser, tank)
+    #[arg(long)]
+    class1: Option<PlayerClass>,
+    /// Classe du deuxième bot (balanced, sprinter, bruiser, tank)
+    #[arg(long)]
+    class2: Option<PlayerClass>,
+    /// Vitalité initiale des bots sans classe (défaut: 50)
+    #[arg(long)]
+    vitality: Option<u32>,
     /// Nombre d’objectifs par tour (défaut: 5)
-    #[arg(long, default_value_t = 5)]
+    #[arg(long)]
+    objectifs: Option<usize>,
+    /// Active la fatigue
+    #[arg(long)]
+    fatigue: bool,
+}
+
+impl SimulateArgs {
+    /// Complète les options non renseignées en ligne de commande avec la section `[game]` du fichier
+    /// de configuration.
+    fn apply_config(&mut self, config: &Config) {
+        let game = &config.game;
+        self.objectifs = self.objectifs.or(game.objectifs);
+        self.vitality = self.vitality.or(game.vitality);
+        self.fatigue |= game.fatigue.unwrap_or(false);
+    }
+}
+
+/// Options de la sous-commande `balance`, qui mesure l'effet de la vitesse et de la force sur les
+/// victoires.
+#[derive(clap::Args)]
+struct BalanceArgs {
+    /// Nombre de parties simulées par configuration
+    #[arg(long, default_value_t = 1000)]
+    games: u32,
+    /// Difficulté des deux bots (easy, normal, hard)
+    #[arg(long, default_value = "normal")]
+    bot: Difficulty,
+    /// Vitalité initiale des deux joueurs (défaut: 50)
+    #[arg(long)]
+    vitality: Option<u32>,
+    /// Nombre d’objectifs par tour (défaut: 5)
+    #[arg(long)]
+    objectifs: Option<usize>,
+    /// Fichier CSV où écrire le rapport, en plus du tableau affiché
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+impl BalanceArgs {
+    /// Complète les options non renseignées en ligne de commande avec la section `[game]` du fichier
+    /// de configuration.
+    fn apply_config(&mut self, config: &Config) {
+        self.objectifs = self.objectifs.or(config.game.objectifs);
+        self.vitality = self.vitality.or(config.game.vitality);
+    }
+}
+
+/// Options de la sous-commande `stats`.
+#[derive(clap::Args)]
+struct StatsArgs {
+    /// Répertoire de rediffusions dont afficher la précision par tranche d'objectifs, plutôt que le
+    /// classement des profils
+    #[arg(long, value_name = "DIR")]
+    replays: Option<PathBuf>,
+}
+
+/// Sous-commandes de l'application.
+#[derive(Subcommand)]
+enum Command {
+    /// Joue une ou plusieurs parties entre deux joueurs (sous-commande par défaut)
+    Play(Box<PlayArgs>),
+    /// Entraînement en solo, sans adversaire ni dégâts
+    Practice(PracticeArgs),
+    /// Défi du jour : les mêmes objectifs pour tous, tirés de la date, et un résultat à partager
+    Daily(DailyArgs),
+    /// Simule des parties entre deux bots et affiche leurs taux de victoire
+    Simulate(SimulateArgs),
+    /// Simule des parties pour une grille de vitesses et de forces contre un adversaire de référence
+    Balance(BalanceArgs),
+    /// Retrace une partie enregistrée avec `play --record`
+    Replay {
+        /// Fichier de rediffusion
+        file: PathBuf,
+        /// Relit la rediffusion même si sa première manche ne concorde pas avec son barème
+        #[arg(long)]
+        force: bool,
+    },
+    /// Affiche le classement des profils, ou la précision par objectif de parties enregistrées
+    Stats(StatsArgs),
+    /// Affiche les règles du jeu, selon la configuration
+    Rules,
+    /// Affiche les préréglages de règles disponibles pour `play --preset`
+    Presets,
+    /// Démonstration : deux bots s'affrontent en boucle jusqu'à l'appui sur une touche
+    Demo,
+    /// Héberge une partie en réseau et attend qu'un joueur distant la rejoigne avec `join`
+    Host {
+        /// Port d'écoute
+        #[arg(long, default_value_t = DEFAULT_PORT)]
+        port: u16,
+        /// Nom du joueur de cette machine (défaut: « Joueur 1 »)
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Rejoint la partie en réseau d'un hôte, le compteur tournant sur cette machine
+    Join {
+        /// Adresse de l'hôte, au format hôte:port (ex. 192.168.1.10:4000)
+        addr: String,
+        /// Nom du joueur de cette machine (défaut: « Joueur 2 »)
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Serveur WebSocket pour les clients web, qui jouent contre un bot
+    #[cfg(feature = "ws")]
+    Serve {
+        /// Port d'écoute
+        #[arg(long, default_value_t = DEFAULT_WS_PORT)]
+        port: u16,
+    },
+    /// Écrit sur la sortie standard le script de complétion de la ligne de commande pour un shell
+    Completions {
+        /// Shell ciblé (bash, zsh, fish, powershell, elvish)
+        shell: Shell,
+    },
+    /// Écrit sur la sortie standard la page de manuel (format roff)
+    Man,
+    /// Gestion des profils de joueurs (`play --profile1`, `play --profile2`)
+    Profile {
+        #[command(subcommand)]
+        action: ProfileAction,
+    },
+    /// Gestion des sauvegardes de partie (`play --autosave`, `play --load-slot`)
+    Saves {
+        #[command(subcommand)]
+        action: SavesAction,
+    },
+    /// Gestion du fichier de configuration
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+/// Actions sur le fichier de configuration.
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Écrit un fichier de configuration commenté
+    Init {
+        /// Emplacement du fichier à créer
+        #[arg(long, default_value = CONFIG_FILE_NAME)]
+        path: PathBuf,
+        /// Écrase le fichier s'il existe déjà
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Actions sur les profils de joueurs.
+#[derive(Subcommand)]
+enum ProfileAction {
+    /// Active le mode hardcore d'un profil : une défaite efface son expérience, son niveau et ses
+    /// succès
+    SetHardcore {
+        /// Nom du profil
+        name: String,
+        /// Désactive le mode hardcore au lieu de l'activer
+        #[arg(long)]
+        off: bool,
+    },
+}
+
+/// Actions sur les sauvegardes de partie.
+#[derive(Subcommand)]
+enum SavesAction {
+    /// Affiche les emplacements de sauvegarde, avec leur manche, leurs joueurs et leur date
+    List,
+}
+
+/// Complète les arguments non renseignés en ligne de commande avec le fichier de configuration.
+///
+/// Les options de ligne de commande et les variables d'environnement l'emportent toujours sur le
+/// fichier. Le nom et le profil d'un joueur ne sont repris du fichier que si aucun des deux n'a été
+/// donné en ligne de commande ou par l'environnement.
+fn apply_config(args: &mut PlayArgs, config: Config) {
+    let game = config.game;
+    args.objectifs = args.objectifs.or(game.objectifs);
+    args.vitality = args.vitality.or(game.vitality);
+    args.armor = args.armor.or(game.armor);
+    args.fatigue |= game.fatigue.unwrap_or(false);
+    args.stamina |= game.stamina.unwrap_or(false);
+    args.shop |= game.shop.unwrap_or(false);
+    args.mirror |= game.mirror.unwrap_or(false);
+    args.risk_choice |= game.risk_choice.unwrap_or(false);
+    args.mutators |= game.mutators.unwrap_or(false);
+    args.bans |= game.bans.unwrap_or(false);
+    args.retry |= game.retry.unwrap_or(false);
+    args.time_tiebreak |= game.time_tiebreak.unwrap_or(false);
+    args.delayed_poison |= game.delayed_poison.unwrap_or(false);
+    args.winners_curse |= game.winners_curse.unwrap_or(false);
+    args.best_of = args.best_of.or(game.best_of);
+    args.carry_over |= game.carry_over.unwrap_or(false);
+    args.bot = args.bot.or(game.bot);
+    args.mode = args.mode.or(game.mode);
+    args.duel_factor = args.duel_factor.or(game.duel_factor);
+    args.stalemate_rounds = args.stalemate_rounds.or(game.stalemate_rounds);
+
+    let pacing = config.pacing;
+    args.auto_continue |= pacing.auto_continue.unwrap_or(false);
+    args.pause_between_rounds |= pacing.pause_between_rounds.unwrap_or(false);
+    args.turn_delay = args.turn_delay.or(pacing.turn_delay);
+
+    let (p1, p2) = (config.player1, config.player2);
+    if args.name1.is_none() && args.profile1.is_none() {
+        args.name1 = p1.name;
+        args.profile1 = p1.profile;
+    }
+    if args.name2.is_none() && args.profile2.is_none() {
+        args.name2 = p2.name;
+        args.profile2 = p2.profile;
+    }
+    args.class1 = args.class1.or(p1.class);
+    args.class2 = args.class2.or(p2.class);
+    args.color1 = args.color1.or(p1.color);
+    args.color2 = args.color2.or(p2.color);
+    args.luck1 = args.luck1.or(p1.luck);
+    args.luck2 = args.luck2.or(p2.luck);
+    args.avatar1 = args.avatar1.take().or(p1.avatar);
+    args.avatar2 = args.avatar2.take().or(p2.avatar);
+}
+
+/// Configuration d'une session de jeu résolue à partir des arguments et des profils.
+struct Setup {
+    /// Joueurs validés.
+    players: Vec<Player>,
+    /// Profils associés à chaque joueur, le cas échéant.
+    profiles: Vec<Option<Profile>>,
+    /// Gestionnaire des profils, présent si au moins un profil est utilisé.
+    store: Option<ProfileStore>,
+    /// Nombre d’objectifs par tour.
     objectifs: usize,
+    /// Règles optionnelles de la partie.
+    rules: GameRules,
+    /// Difficulté du bot contrôlant le deuxième joueur, le cas échéant.
+    bot: Option<Difficulty>,
+}
+
+/// Construit un joueur à partir de son profil éventuel et des options de ligne de commande.
+///
+/// Les options explicites l'emportent sur le profil, et une classe remplace les caractéristiques individuelles.
+///
+/// # Arguments
+///
+/// * `index` - L'index du joueur (0 pour le premier joueur, 1 pour le deuxième).
+/// * `args` - Les arguments de ligne de commande.
+/// * `profile` - Le profil du joueur, le cas échéant.
+fn build_player(
+    index: usize,
+    args: &PlayArgs,
+    profile: Option<&Profile>,
+) -> Result<Player, PlayerConfigError> {
+    let (spec, speed, strength) = match index {
+        0 => (&args.player1, args.speed1, args.strength1),
+        _ => (&args.player2, args.speed2, args.strength2),
+    };
+    let (name, class, color, luck, avatar) = match index {
+        0 => (
+            args.name1.clone(),
+            args.class1,
+            args.color1.unwrap_or(Color::Cyan),
+            args.luck1,
+            &args.avatar1,
+        ),
+        _ if args.bot.is_some() => (
+            args.name2.clone().or(Some(String::from("Bot"))),
+            args.class2,
+            args.color2.unwrap_or(Color::Magenta),
+            args.luck2,
+            &args.avatar2,
+        ),
+        _ => (
+            args.name2.clone(),
+            args.class2,
+            args.color2.unwrap_or(Color::Magenta),
+            args.luck2,
+            &args.avatar2,
+        ),
+    };
+    let mut builder = match profile {
+        Some(profile) => profile.player_builder(),
+        None => Player::builder(name.unwrap_or_else(|| default_name(index)))
+            .vitality(DEFAULT_VITALITY)
+            .speed(DEFAULT_SPEED)
+            .strength(DEFAULT_STRENGTH),
+    };
+    if let Some(vitality) = spec.as_ref().map(|spec| spec.vitality).or(args.vitality) {
+        builder = builder.vitality(vitality);
+    }
+    if let Some(speed) = spec.as_ref().map(|spec| spec.speed).or(speed) {
+        builder = builder.speed(speed);
+    }
+    if let Some(strength) = spec.as_ref().map(|spec| spec.strength).or(strength) {
+        builder = builder.strength(strength);
+    }
+    if let Some(armor) = args.armor {
+        builder = builder.armor(armor);
+    }
+    if let Some(class) = class {
+        builder = builder.class(class);
+    }
+    if let Some(luck) = luck {
+        builder = builder.luck(luck);
+    }
+    if let Some(avatar) = avatar {
+        builder = builder.avatar(avatar.as_str());
+    }
+    if let Some(percent) = [args.assist1, args.assist2][index] {
+        builder = builder.assist(percent);
+    }
+    builder.color(color).build()
+}
+
+/// Indique si l'assistant interactif doit configurer la partie.
+///
+/// L'assistant n'est lancé que si un joueur n'est nommé ni par son nom, ni par son profil, ni par
+/// `--bot` pour le deuxième, et que la console est interactive. Sinon (entrée redirigée, intégration
+/// continue), les joueurs non nommés reçoivent leur nom par défaut sans question.
+fn uses_wizard(args: &PlayArgs, console: &dyn Console) -> bool {
+    let unnamed = (args.name1.is_none() && args.profile1.is_none())
+        || (args.name2.is_none() && args.profile2.is_none() && args.bot.is_none());
+    unnamed && console.is_interactive()
+}
+
+/// Charge les profils demandés et construit les joueurs.
+///
+/// Un joueur nommé ni par son nom, ni par son profil, ni par `--bot` pour le deuxième reçoit son nom
+/// par défaut ([`default_name`]) ; les homonymes éventuels sont ensuite distingués.
+fn setup(args: &PlayArgs) -> Result<Setup, Box<dyn Error>> {
+    let store = if args.profile1.is_some() || args.profile2.is_some() {
+        Some(ProfileStore::default_location()?)
+    } else {
+        None
+    };
+    let mut profiles = Vec::new();
+    for name in [&args.profile1, &args.profile2] {
+        profiles.push(match (name, &store) {
+            (Some(name), Some(store)) => Some(store.load_or_create(name)?),
+            _ => None,
+        });
+    }
+
+    let mut players = vec![
+        build_player(0, args, profiles[0].as_ref())?,
+        build_player(1, args, profiles[1].as_ref())?,
+    ];
+    for name in disambiguate_names(&mut players) {
+        println!("{}", tr!(Msg::DuplicateName, name = name));
+    }
+    let objectifs = args
+        .objectifs
+        .or_else(|| profiles.iter().flatten().find_map(|p| p.options.objectifs))
+        .unwrap_or(5);
+
+    Ok(Setup {
+        players,
+        profiles,
+        store,
+        objectifs,
+        rules: args.game_rules(),
+        bot: args.bot,
+    })
+}
+
+/// Configure la session à partir d'une partie sauvegardée.
+///
+/// Les revanches opposent les joueurs de la sauvegarde avec leur vitalité pleine et sans leurs poisons ;
+/// en mode miroir, leurs caractéristiques sont rétablies avant l'échange propre à chaque partie.
+fn resume_setup(args: &PlayArgs, save: &SaveState) -> Result<Setup, Box<dyn Error>> {
+    let mut players: Vec<Player> = save
+        .players
+        .iter()
+        .map(SavedPlayer::to_fresh_player)
+        .collect();
+    if save.rules.mirror {
+        players = rules::mirror(players)?;
+    }
+    Ok(Setup {
+        profiles: vec![None; players.len()],
+        players,
+        store: None,
+        objectifs: save.objectifs,
+        rules: save.rules,
+        bot: args.bot,
+    })
+}
+
+/// Configure la partie avec l'assistant interactif lorsque les joueurs ne sont pas nommés.
+///
+/// Les noms connus, ou à défaut les noms par défaut, sont proposés, et les règles et couleurs données en ligne de commande
+/// sont conservées.
+fn wizard_setup(args: &PlayArgs) -> Result<Setup, Box<dyn Error>> {
+    let names = [
+        args.name1
+            .clone()
+            .or_else(|| args.profile1.clone())
+            .or_else(|| Some(default_name(0))),
+        args.name2
+            .clone()
+            .or_else(|| args.profile2.clone())
+            .or_else(|| args.bot.map(|_| String::from("Bot")))
+            .or_else(|| Some(default_name(1))),
+    ];
+    let Some(SetupChoices {
+        mut players,
+        rules,
+        objectifs,
+    }) = wizard(&mut StdConsole, names)
+    else {
+        return Err(tr!(Msg::SetupInterrupted).into());
+    };
+    let colors = [
+        args.color1.unwrap_or(Color::Cyan),
+        args.color2.unwrap_or(Color::Magenta),
+    ];
+    let assists = [args.assist1, args.assist2];
+    for ((player, color), assist) in players.iter_mut().zip(colors).zip(assists) {
+        player.color = Some(color);
+        player.assist = assist;
+    }
+    for name in disambiguate_names(&mut players) {
+        println!("{}", tr!(Msg::DuplicateName, name = name));
+    }
+    Ok(Setup {
+        players,
+        profiles: vec![None, None],
+        store: None,
+        objectifs,
+        rules: GameRules {
+            fatigue: rules.fatigue || args.fatigue,
+            stamina: rules.stamina || args.stamina,
+            ..args.game_rules()
+        },
+        bot: args.bot,
+    })
+}
+
+/// Retourne les touches du jeu : celles par défaut, remplacées par la section `[keys]` du fichier de
+/// configuration puis par les options `--bind`, et validées contre les conflits.
+fn key_bindings(config: Option<&Config>, binds: &[String]) -> Result<KeyBindings, KeyBindingError> {
+    let mut bindings = KeyBindings::default();
+    let from_config = config
+        .map(|config| config.keys.bindings())
+        .unwrap_or_default();
+    for spec in from_config.iter().chain(binds) {
+        bindings.bind(spec)?;
+    }
+    bindings.validate()?;
+    Ok(bindings)
+}
+
+/// Affiche une erreur de configuration et quitte l'application avec le code 2.
+fn exit_config_error(err: impl Display) -> ! {
+    eprintln!("{}", tr!(Msg::ConfigError, error = err));
+    std::process::exit(2);
+}
+
+/// Enregistrement des saisies de la session en cours (`--record-inputs`).
+static RECORDING: OnceLock<Recording> = OnceLock::new();
+
+/// Enregistrement des saisies d'une session, écrit dans un rapport de bogue à sa fin.
+struct Recording {
+    /// Chemin du rapport de bogue.
+    path: PathBuf,
+    /// Rapport de bogue, sans ses saisies.
+    bundle: BugBundle,
+    /// Enregistreur des saisies lues dans la file de l'entrée standard.
+    recorder: InputRecorder,
+}
+
+impl Recording {
+    /// Commence l'enregistrement des saisies de l'entrée standard, avant toute lecture.
+    ///
+    /// Une graine est tirée si aucune n'est donnée, afin que le rapport puisse rejouer la session.
+    fn start(path: PathBuf, argv: &[OsString], common: &mut CommonArgs, config: Option<String>) {
+        let seed = *common.seed.get_or_insert_with(rand::random);
+        let recorder = InputRecorder::new(Arc::new(SystemClock::default()));
+        let interactive = io::stdin().is_terminal();
+        let input =
+            InputQueue::from_reader(io::stdin(), interactive).with_recorder(recorder.clone());
+        let _ = InputQueue::install_stdin(input);
+        let bundle = BugBundle::new(seed, recorded_args(argv), config, interactive, &recorder);
+        let _ = RECORDING.set(Recording {
+            path,
+            bundle,
+            recorder,
+        });
+    }
+
+    /// Écrit le rapport de bogue de la session enregistrée, le cas échéant.
+    fn save() {
+        let Some(recording) = RECORDING.get() else {
+            return;
+        };
+        let bundle = BugBundle {
+            inputs: recording.recorder.inputs(),
+            ..recording.bundle.clone()
+        };
+        match bundle.save(&recording.path) {
+            Ok(()) => eprintln!(
+                "{}",
+                tr!(
+                    Msg::BundleWritten,
+                    path = recording.path.display(),
+                    count = bundle.inputs.len()
+                )
+            ),
+            Err(err) => eprintln!("{}", tr!(Msg::Error, error = err)),
+        }
+    }
+}
+
+/// Retourne les arguments consignés dans un rapport de bogue : ceux de la ligne de commande, sans le
+/// nom du programme ni l'option d'enregistrement.
+fn recorded_args(argv: &[OsString]) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut value = false;
+    for arg in argv.iter().skip(1).map(|arg| arg.to_string_lossy()) {
+        if std::mem::take(&mut value) || arg.starts_with("--record-inputs=") {
+            continue;
+        }
+        if arg == "--record-inputs" {
+            value = true;
+            continue;
+        }
+        args.push(arg.into_owned());
+    }
+    args
+}
+
+/// Sous-commande `play` : crée les joueurs et lance une boucle de parties.
+///
+/// À la fin de chaque partie, l'utilisateur peut relancer une partie avec les mêmes réglages ou en
+/// échangeant les places, modifier les réglages avec l'assistant, afficher le bilan ou quitter.
+///
+/// # Arguments
+///
+/// * `args` - Les options de la sous-commande.
+/// * `config` - Le fichier de configuration chargé, le cas échéant.
+/// * `seed` - La graine des objectifs et des bots, le cas échéant.
+/// * `verbosity` - Le niveau de détail de l'affichage des parties.
+/// * `cancel` - Le jeton annulé par Ctrl+C.
+fn play(
+    mut args: PlayArgs,
+    config: Option<Config>,
+    seed: Option<u64>,
+    verbosity: Verbosity,
+    cancel: CancelToken,
+) -> Result<(), Box<dyn Error>> {
+    // Fusion du préréglage et du fichier de configuration : ligne de commande > préréglage > fichier
+    // > valeurs par défaut.
+    args.apply_player_specs();
+    args.apply_preset();
+    if let Some(config) = config {
+        apply_config(&mut args, config);
+    }
+    // Le rendu ASCII est destiné aux journaux : le reste de l'affichage perd aussi ses couleurs et
+    // ses symboles.
+    if args.plain_ascii {
+        args.render = RenderStyle::PlainAscii;
+    }
+    if args.render == RenderStyle::PlainAscii {
+        style::set_enabled(false);
+        style::set_ascii(true);
+    }
+    if args.rules {
+        println!(
+            "{}",
+            rules::render(&args.game_rules(), &ScoringConfig::default())
+        );
+        return Ok(());
+    }
+    if args.protocol {
+        return protocol(&args, seed, cancel);
+    }
+
+    // Le journal est créé avant la configuration des joueurs, afin de signaler au plus tôt un chemin
+    // invalide. Il est partagé par toutes les parties de la session.
+    let log = match &args.log_file {
+        Some(path) => Some(Rc::new(RefCell::new(GameLog::create(path)?))),
+        None => None,
+    };
+    let predictions = match &args.predictions_file {
+        Some(path) => {
+            let window = args.betting_window.unwrap_or(DEFAULT_BETTING_WINDOW);
+            let predictions = PredictionsFile::create(
+                path,
+                Duration::from_secs(window),
+                Box::new(SystemClock::default()),
+            )?;
+            Some(Rc::new(RefCell::new(predictions)))
+        }
+        None => None,
+    };
+    #[cfg(feature = "http")]
+    let notifier = args
+        .notify_url
+        .as_ref()
+        .map(|url| Rc::new(RefCell::new(NotifierObserver::new(url.clone()))));
+
+    // Création et validation des joueurs avec les paramètres et profils fournis, ou avec l'assistant
+    // interactif si un joueur n'est pas nommé et que l'entrée est un terminal.
+    // Une partie sauvegardée reprend ses propres joueurs et règles.
+    let saves = if args.autosave.is_some() || args.load_slot.is_some() {
+        Some(SaveStore::default_location()?)
+    } else {
+        None
+    };
+    let mut resume = match (&args.load_slot, &saves) {
+        (Some(slot), Some(saves)) => Some((slot.clone(), saves.load(slot)?)),
+        _ => None,
+    };
+    let setup = if let Some((_, save)) = &resume {
+        resume_setup(&args, save)
+    } else if uses_wizard(&args, &StdConsole) {
+        wizard_setup(&args)
+    } else {
+        setup(&args)
+    };
+    let Setup {
+        mut players,
+        mut profiles,
+        store,
+        mut objectifs,
+        mut rules,
+        bot,
+    } = setup.unwrap_or_else(|err| exit_config_error(err));
+
+    // Les règles sont proposées avant la toute première partie, si un joueur peut y répondre.
+    if StdConsole.is_interactive()
+        && let Some(marker) = rules::first_run_marker()
+    {
+        rules::offer(&mut StdConsole, &marker, &rules, &ScoringConfig::default());
+    }
+
+    // Bilan des parties de la session, affiché avant chaque proposition de revanche.
+    let mut session = Session::new(&players);
+    // Place du joueur contrôlé par le bot, qui change lorsque les joueurs échangent leurs places.
+    let mut bot_index = 1;
+    // Série au meilleur des N parties en cours, recommencée après chaque proposition de revanche.
+    let mut series = args
+        .best_of
+        .map(|best_of| Series::new(best_of, &players, args.carry_over));
+
+    // Boucle principale pour jouer plusieurs parties.
+    loop {
+        // En mode miroir, les caractéristiques sont échangées pour cette partie seulement : les
+        // joueurs de la session conservent les leurs d'une revanche à l'autre. Il en va de même du
+        // bonus de vitalité reporté d'une partie à l'autre d'une série.
+        let mut game_players = players.clone();
+        if let Some(series) = &series {
+            series.apply(&mut game_players);
+        }
+        let mut game = match resume.take() {
+            Some((slot, save)) => {
+                println!("{}", tr!(Msg::SaveResumed, slot = slot, round = save.round));
+                Game::from_save(&save)
+            }
+            None => Game::new_with_rules(game_players, objectifs, rules)?,
+        };
+        if let (Some(slot), Some(saves)) = (&args.autosave, &saves) {
+            game.set_autosave(saves.clone(), slot.clone());
+        }
+        if let Some(name) = &args.referee {
+            game.set_referee(name.clone(), Box::new(HumanController::default()));
+        }
+        for (player, profile) in game.players.iter().zip(&profiles) {
+            if let Some(profile) = profile.as_ref().filter(|profile| profile.hardcore) {
+                let warning = tr!(
+                    Msg::HardcoreWarning,
+                    player = player.colored_name(),
+                    level = profile.level,
+                    xp = profile.xp,
+                    achievements = profile.achievements.len()
+                );
+                println!("{}", style::paint_role(&warning, style::Role::Alert));
+            }
+        }
+        if let Some(series) = &series {
+            for (player, &bonus) in game.players.iter().zip(series.bonuses()) {
+                if bonus > 0 {
+                    println!(
+                        "{}",
+                        tr!(
+                            Msg::SeriesStart,
+                            player = player.colored_name(),
+                            vitality = player.vitality(),
+                            bonus = bonus
+                        )
+                    );
+                }
+            }
+        }
+        game.set_verbosity(verbosity);
+        game.set_renderer(args.renderer());
+        game.set_sound(args.sound);
+        game.set_pacing(args.pacing());
+        // Chaque revanche dérive sa propre graine, afin que la série entière soit reproductible.
+        let game_seed = seed.map(|seed| seed.wrapping_add(u64::from(session.games())));
+        if let Some(game_seed) = game_seed {
+            game.set_seed(game_seed);
+        }
+        if let Some(difficulty) = bot {
+            let bot = match game_seed {
+                Some(game_seed) => {
+                    BotController::with_rng(difficulty, StdRng::seed_from_u64(game_seed))
+                }
+                None => BotController::new(difficulty),
+            };
+            game.set_controller(bot_index, Box::new(bot));
+        } else if rules.mode == GameMode::Shared && !args.full_screen() {
+            // Les deux joueurs partagent le clavier : chacun verrouille le compteur par sa touche.
+            game.set_shared_counter(Box::new(TerminalSharedCounter));
+        }
+        game.set_cancel_token(cancel.clone());
+        // Les manches anciennes d'une longue session ne sont retenues que par la rediffusion.
+        game.set_history_limit(DEFAULT_RETAINED_ROUNDS);
+        if let Some(path) = &args.record {
+            match JsonlSink::create(path) {
+                Ok(sink) => game.set_history_sink(Box::new(sink)),
+                Err(err) => eprintln!("{}", tr!(Msg::ReplaySaveFailed, error = err)),
+            }
+        }
+        if let Some(log) = &log {
+            game.add_observer(Box::new(Rc::clone(log)));
+        }
+        if let Some(predictions) = &predictions {
+            game.add_observer(Box::new(Rc::clone(predictions)));
+        }
+        #[cfg(feature = "http")]
+        if let Some(notifier) = &notifier {
+            game.add_observer(Box::new(Rc::clone(notifier)));
+        }
+        #[cfg(feature = "tui")]
+        let tui = if args.tui {
+            Some(tui_game(
+                &mut game,
+                bot.map(|_| bot_index),
+                verbosity,
+                &cancel,
+            )?)
+        } else {
+            None
+        };
+        let result = game.run()?;
+        // L'écran est quitté avant le bilan et la revanche, affichés dans le terminal habituel.
+        #[cfg(feature = "tui")]
+        if let Some(tui) = tui {
+            let mut tui = tui.borrow_mut();
+            if result != GameResult::Interrupted {
+                let message = match result.winner() {
+                    Some(winner) => tr!(Msg::Winner, winner = game.player(winner).name),
+                    None => tr!(Msg::Draw),
+                };
+                tui.pause(&message);
+            }
+            tui.leave();
+        }
+        if result == GameResult::Interrupted {
+            println!("\n{session}");
+            Recording::save();
+            std::process::exit(INTERRUPTED_EXIT_CODE);
+        }
+        session.record(&result);
+        if let Some(path) = &args.export {
+            let names = game.players.iter().map(|p| p.name.clone()).collect();
+            let export = GameExport::new(names, result, game.round - 1)
+                .with_stats(game.stats())
+                .with_mirror(game.rules.mirror);
+            if let Err(err) = fs::write(path, export.to_json()) {
+                eprintln!("{}", tr!(Msg::ExportSaveFailed, error = err));
+            }
+        }
+
+        // Mise à jour du bilan et de l'expérience des profils.
+        if let Some(store) = &store {
+            for (index, profile) in profiles.iter_mut().enumerate() {
+                if let Some(profile) = profile {
+                    match result.winner() {
+                        Some(winner) => profile.record_game(index == winner.index()),
+                        None => profile.record_draw(),
+                    }
+                    let progress = profile.gain_xp(game.total_scores[index]);
+                    if progress.strength_gained > 0 {
+                        println!(
+                            "{}",
+                            tr!(
+                                Msg::XpLevelUp,
+                                player = players[index].colored_name(),
+                                xp = progress.xp_gained,
+                                level = progress.level,
+                                strength = progress.strength_gained
+                            )
+                        );
+                        players[index].strength =
+                            (players[index].strength + progress.strength_gained).min(MAX_STRENGTH);
+                    } else {
+                        println!(
+                            "{}",
+                            tr!(
+                                Msg::XpProgress,
+                                player = players[index].colored_name(),
+                                xp = progress.xp_gained,
+                                level = progress.level,
+                                progress = profile.xp,
+                                needed = XP_PER_LEVEL
+                            )
+                        );
+                    }
+                    let history = game.retained_history();
+                    let earned = achievements::earned(&history, &result, PlayerId(index));
+                    for id in profile.unlock(&earned) {
+                        println!(
+                            "{}",
+                            tr!(
+                                Msg::AchievementUnlocked,
+                                player = players[index].colored_name(),
+                                name = achievements::name(id).unwrap_or(id)
+                            )
+                        );
+                    }
+                    if let Some(loss) = permadeath(&mut StdConsole, profile, &result, index) {
+                        players[index].strength = players[index]
+                            .strength
+                            .saturating_sub(level_strength_bonus(loss.level));
+                    }
+                    if let Err(err) = store.save(profile) {
+                        eprintln!("{}", tr!(Msg::ProfileSaveFailed, error = err));
+                    }
+                }
+            }
+        }
+
+        // Les parties d'une série s'enchaînent sans proposition de revanche jusqu'à son vainqueur.
+        if let Some(series) = &mut series {
+            series.record(&result, &game.players);
+            println!("\n{series}");
+            if !series.is_over() {
+                continue;
+            }
+        }
+
+        println!("\n{session}");
+        match rematch_menu(&mut StdConsole, &session) {
+            RematchDecision::Same => {}
+            RematchDecision::Swap => {
+                // Le bot, les profils et le bilan suivent leur joueur à sa nouvelle place.
+                players.swap(0, 1);
+                profiles.swap(0, 1);
+                session.swap_sides();
+                bot_index = 1 - bot_index;
+            }
+            RematchDecision::Edit => {
+                let current = SetupChoices {
+                    players: players.clone(),
+                    rules,
+                    objectifs,
+                };
+                let Some(choices) = edit(&mut StdConsole, &current) else {
+                    return Ok(());
+                };
+                let renamed = choices
+                    .players
+                    .iter()
+                    .zip(&players)
+                    .any(|(edited, player)| edited.name != player.name);
+                let colors: Vec<_> = players.iter().map(|player| player.color).collect();
+                players = choices.players;
+                for (player, color) in players.iter_mut().zip(colors) {
+                    player.color = color;
+                }
+                for name in disambiguate_names(&mut players) {
+                    println!("{}", tr!(Msg::DuplicateName, name = name));
+                }
+                // Les joueurs sont reconstruits par l'assistant : leurs profils ne sont plus suivis.
+                profiles = vec![None, None];
+                objectifs = choices.objectifs;
+                rules = choices.rules;
+                if renamed {
+                    session = Session::new(&players);
+                }
+            }
+            RematchDecision::Quit => return Ok(()),
+        }
+        series = args
+            .best_of
+            .map(|best_of| Series::new(best_of, &players, args.carry_over));
+    }
+}
+
+/// Option `--tui` de `play` : affiche la partie dans l'interface plein écran du terminal.
+///
+/// L'affichage de la partie est recopié dans le journal de l'écran, et les joueurs humains jouent au
+/// clavier dans l'écran. Retourne l'écran, à quitter une fois la partie terminée.
+///
+/// # Arguments
+///
+/// * `game` - La partie à afficher.
+/// * `bot_index` - La place du joueur contrôlé par le bot, le cas échéant.
+/// * `verbosity` - Le niveau de détail du journal.
+/// * `cancel` - Le jeton d'interruption, annulé par Ctrl+C dans l'écran.
+#[cfg(feature = "tui")]
+fn tui_game(
+    game: &mut Game,
+    bot_index: Option<usize>,
+    verbosity: Verbosity,
+    cancel: &CancelToken,
+) -> Result<SharedTui, Box<dyn Error>> {
+    let tui = Tui::enter(&game.players, cancel.clone())?;
+    game.set_output(Output::new(
+        Box::new(TuiConsole::new(Rc::clone(&tui))),
+        verbosity,
+    ));
+    for index in 0..game.players.len() {
+        if Some(index) != bot_index {
+            game.set_controller(index, Box::new(TuiController::new(Rc::clone(&tui))));
+        }
+    }
+    game.add_observer(Box::new(TuiObserver::new(Rc::clone(&tui))));
+    Ok(tui)
+}
+
+/// Option `--protocol` de `play` : joue une partie pilotée par une interface externe.
+///
+/// Les commandes de l'interface sont lues sur l'entrée standard et les événements de la partie écrits
+/// sur la sortie standard, sans aucun autre affichage (voir [`dual_game::protocol`]). Le deuxième
+/// joueur peut être confié à un bot avec `--bot`.
+fn protocol(args: &PlayArgs, seed: Option<u64>, cancel: CancelToken) -> Result<(), Box<dyn Error>> {
+    let mut players = vec![
+        build_player(0, args, None).unwrap_or_else(|err| exit_config_error(err)),
+        build_player(1, args, None).unwrap_or_else(|err| exit_config_error(err)),
+    ];
+    // Les joueurs étant désignés par leur index, les homonymes sont renommés sans avertissement.
+    disambiguate_names(&mut players);
+    let objectifs = args.objectifs.unwrap_or(DEFAULT_OBJECTIFS);
+    let mut game = Game::new_with_rules(players, objectifs, args.game_rules())?;
+    game.set_output(Output::new(Box::new(NullConsole), Verbosity::Silent));
+    if let Some(seed) = seed {
+        game.set_seed(seed);
+    }
+    game.set_cancel_token(cancel);
+
+    let io = ProtocolIo::shared(io::stdin().lock(), io::stdout().lock());
+    for index in 0..2 {
+        let controller: Box<dyn Controller> = match args.bot {
+            Some(difficulty) if index == 1 => Box::new(match seed {
+                Some(seed) => BotController::with_rng(difficulty, StdRng::seed_from_u64(seed)),
+                None => BotController::new(difficulty),
+            }),
+            _ => Box::new(ProtocolController::new(index, Rc::clone(&io))),
+        };
+        game.set_controller(index, controller);
+    }
+    game.add_observer(Box::new(ProtocolObserver::new(io)));
+    if game.run()? == GameResult::Interrupted {
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    }
+    Ok(())
+}
+
+/// Installe le gestionnaire de Ctrl+C et retourne le jeton qu'il annule.
+///
+/// Un premier Ctrl+C demande l'interruption : la partie s'arrête au prochain point d'arrêt sûr et
+/// affiche un bilan partiel. Un second Ctrl+C quitte immédiatement, après avoir restauré le terminal.
+fn install_interrupt_handler() -> CancelToken {
+    let cancel = CancelToken::new();
+    let handler = cancel.clone();
+    let installed = ctrlc::set_handler(move || {
+        if handler.is_cancelled() {
+            let _ = crossterm::terminal::disable_raw_mode();
+            std::process::exit(INTERRUPTED_EXIT_CODE);
+        }
+        handler.cancel();
+        eprintln!("\n{}", tr!(Msg::InterruptRequested));
+    });
+    if let Err(err) = installed {
+        eprintln!("{}", tr!(Msg::InterruptUnavailable, error = err));
+    }
+    cancel
+}
+
+/// Sous-commande `practice` : entraînement en solo, sans adversaire ni dégâts.
+fn practice(
+    args: PracticeArgs,
+    seed: Option<u64>,
+    verbosity: Verbosity,
+    cancel: CancelToken,
+) -> Result<(), Box<dyn Error>> {
+    let mut builder = Player::builder(args.name)
+        .vitality(DEFAULT_VITALITY)
+        .speed(args.speed.unwrap_or(DEFAULT_SPEED))
+        .strength(args.strength.unwrap_or(DEFAULT_STRENGTH))
+        .color(Color::Cyan);
+    if let Some(class) = args.class {
+        builder = builder.class(class);
+    }
+    if let Some(luck) = args.luck {
+        builder = builder.luck(luck);
+    }
+    let player = builder.build().unwrap_or_else(|err| exit_config_error(err));
+
+    let rules = GameRules {
+        stamina: args.stamina,
+        ..GameRules::default()
+    };
+    let mut game = Game::new_with_rules(vec![player], args.objectifs, rules)?;
+    game.set_verbosity(verbosity);
+    if style::is_accessible() {
+        game.set_renderer(RenderStyle::Accessible.renderer());
+    }
+    if let Some(seed) = seed {
+        game.set_seed(seed);
+    }
+    if let Some(path) = &args.ghost {
+        game.set_ghost(Ghost::load(path)?);
+    }
+    if let Some(path) = &args.record {
+        match JsonlSink::create(path) {
+            Ok(sink) => game.set_history_sink(Box::new(sink)),
+            Err(err) => eprintln!("{}", tr!(Msg::ReplaySaveFailed, error = err)),
+        }
+    }
+    game.set_cancel_token(cancel.clone());
+    game.practice(args.turns)?;
+    if cancel.is_cancelled() {
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    }
+    Ok(())
+}
+
+/// Sous-commande `daily` : défi du jour, dont le meilleur score est conservé dans le profil du
+/// joueur.
+fn daily(args: DailyArgs, verbosity: Verbosity, cancel: CancelToken) -> Result<(), Box<dyn Error>> {
+    let challenge = DailyChallenge::new(DailyDate::today());
+    let date = challenge.date.to_string();
+    let store = ProfileStore::default_location()?;
+    let mut profile = store.load_or_create(args.profile.as_deref().unwrap_or(&args.name))?;
+    println!("{}", tr!(Msg::DailyTitle, date = date));
+    if let Some(best) = profile.daily_best(&date) {
+        println!("{}", tr!(Msg::DailyAlreadyPlayed, best = best));
+    }
+
+    let mut game = challenge
+        .game(&args.name)
+        .unwrap_or_else(|err| exit_config_error(err));
+    game.set_verbosity(verbosity);
+    if style::is_accessible() {
+        game.set_renderer(RenderStyle::Accessible.renderer());
+    }
+    game.set_cancel_token(cancel.clone());
+    let Some(result) = challenge.play(&mut game)? else {
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    };
+    println!("\n{}", result.share_line());
+    let best = profile.record_daily(&date, result.points);
+    store.save(&profile)?;
+    println!("{}", tr!(Msg::DailyBest, best = best));
+    Ok(())
+}
+
+/// Sous-commande `simulate` : parties entre deux bots et taux de victoire de chacun.
+///
+/// Les options non renseignées sont reprises du fichier de configuration, puis des valeurs par défaut.
+fn simulate(
+    mut args: SimulateArgs,
+    config: Option<&Config>,
+    seed: Option<u64>,
+) -> Result<(), Box<dyn Error>> {
+    if let Some(config) = config {
+        args.apply_config(config);
+    }
+    let build = |index: usize, difficulty: Difficulty, class: Option<PlayerClass>| {
+        let builder = Player::builder(format!("Bot {} ({difficulty})", index + 1))
+            .vitality(args.vitality.unwrap_or(DEFAULT_VITALITY))
+            .speed(DEFAULT_SPEED)
+            .strength(DEFAULT_STRENGTH);
+        match class {
+            Some(class) => builder.class(class),
+            None => builder,
+        }
+        .build()
+        .unwrap_or_else(|err| exit_config_error(err))
+    };
+    let players = [
+        build(0, args.bot1, args.class1),
+        build(1, args.bot2, args.class2),
+    ];
+    let rules = GameRules {
+        fatigue: args.fatigue,
+        ..GameRules::default()
+    };
+    let report = simulation::simulate(
+        &players,
+        [args.bot1, args.bot2],
+        args.games,
+        args.objectifs.unwrap_or(DEFAULT_OBJECTIFS),
+        rules,
+        seed,
+    )?;
+    println!("{report}");
+    Ok(())
+}
+
+/// Sous-commande `balance` : taux de victoire et durée des parties pour chaque configuration de la
+/// grille d'équilibrage, contre un adversaire de référence aux caractéristiques par défaut.
+///
+/// La progression est affichée sur place sur la sortie d'erreur, pour ne pas se mêler au tableau. Les
+/// options non renseignées sont reprises du fichier de configuration, puis des valeurs par défaut.
+fn balance(
+    mut args: BalanceArgs,
+    config: Option<&Config>,
+    seed: Option<u64>,
+) -> Result<(), Box<dyn Error>> {
+    if let Some(config) = config {
+        args.apply_config(config);
+    }
+    let baseline = Player::builder("Référence")
+        .vitality(args.vitality.unwrap_or(DEFAULT_VITALITY))
+        .speed(DEFAULT_SPEED)
+        .strength(DEFAULT_STRENGTH)
+        .build()
+        .unwrap_or_else(|err| exit_config_error(err));
+    let mut shown = None;
+    let report = balance::sweep(
+        &BalanceGrid::default(),
+        &baseline,
+        args.bot,
+        args.games,
+        args.objectifs.unwrap_or(DEFAULT_OBJECTIFS),
+        seed,
+        &mut |done, total| {
+            let percent = done * 100 / total;
+            if shown != Some(percent) {
+                shown = Some(percent);
+                eprint!("\r{}", tr!(Msg::BalanceProgress, percent = percent));
+            }
+        },
+    )?;
+    if shown.is_some() {
+        eprintln!();
+    }
+    println!("{report}");
+    if let Some(path) = &args.out
+        && let Err(err) = fs::write(path, report.to_csv())
+    {
+        eprintln!("{}", tr!(Msg::BalanceSaveFailed, error = err));
+    }
+    Ok(())
+}
+
+/// Sous-commande `demo` : parties entre deux bots tirés au sort, animées en temps réel.
+///
+/// Les parties s'enchaînent jusqu'à ce qu'une touche (ou Ctrl+C) annule le jeton d'interruption, ce qui
+/// arrête aussitôt le compteur et les pauses ; le terminal est restauré à chaque attente.
+fn demo(
+    seed: Option<u64>,
+    verbosity: Verbosity,
+    cancel: CancelToken,
+) -> Result<(), Box<dyn Error>> {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    };
+    while !cancel.is_cancelled() {
+        let lineup = demo::lineup(&mut rng)?;
+        println!(
+            "\n{}",
+            tr!(
+                Msg::DemoStart,
+                player1 = lineup[0].0.colored_name(),
+                player2 = lineup[1].0.colored_name()
+            )
+        );
+        let (players, difficulties): (Vec<Player>, Vec<Difficulty>) = lineup.into_iter().unzip();
+        let mut game = Game::new_with_rules(players, DEFAULT_OBJECTIFS, GameRules::default())?;
+        let console = PacedConsole::new(DEMO_PACE, cancel.clone());
+        game.set_output(Output::new(Box::new(console), verbosity));
+        game.set_seed(rng.random());
+        for (index, difficulty) in difficulties.into_iter().enumerate() {
+            let bot_rng = StdRng::seed_from_u64(rng.random());
+            game.set_controller(index, Box::new(DemoBot::new(difficulty, bot_rng)));
+        }
+        game.set_cancel_token(cancel.clone());
+        game.run()?;
+
+        if !cancel.is_cancelled() {
+            println!("\n{}", tr!(Msg::DemoNextGame));
+            demo::pause(&SystemClock::default(), &cancel, DEMO_INTERLUDE);
+        }
+    }
+    Ok(())
+}
+
+/// Sous-commande `host` : partie en réseau exécutée sur cette machine, contre un joueur distant.
+///
+/// L'hôte fait autorité sur les scores et les dégâts ; le joueur distant ne transmet que ses arrêts du
+/// compteur et ses choix. S'il se déconnecte, il abandonne la partie.
+fn host(
+    port: u16,
+    name: Option<String>,
+    seed: Option<u64>,
+    verbosity: Verbosity,
+    cancel: CancelToken,
+) -> Result<(), Box<dyn Error>> {
+    let name = name.unwrap_or_else(|| default_name(0));
+    let listener = TcpListener::bind(("0.0.0.0", port))?;
+    println!(
+        "{}",
+        tr!(Msg::NetWaiting, port = listener.local_addr()?.port())
+    );
+    let (stream, address) = listener.accept()?;
+    stream.set_read_timeout(Some(REPLY_TIMEOUT))?;
+    let connection = Rc::new(RefCell::new(Connection::new(stream)));
+    let remote = connection.borrow_mut().accept(&name)?;
+
+    let mut players = vec![
+        Player::builder(name).color(Color::Cyan).build()?,
+        Player::builder(remote).color(Color::Magenta).build()?,
+    ];
+    for name in disambiguate_names(&mut players) {
+        println!("{}", tr!(Msg::DuplicateName, name = name));
+    }
+    println!(
+        "{}",
+        tr!(
+            Msg::NetConnected,
+            player = players[1].colored_name(),
+            address = address
+        )
+    );
+
+    let mut game = Game::new_with_rules(players, DEFAULT_OBJECTIFS, GameRules::default())?;
+    let console = RemoteConsole::new(Box::new(StdConsole), Rc::clone(&connection));
+    game.set_output(Output::new(Box::new(console), verbosity));
+    if let Some(seed) = seed {
+        game.set_seed(seed);
+    }
+    game.set_controller(1, Box::new(RemoteController::new(Rc::clone(&connection))));
+    game.add_observer(Box::new(NetObserver::new(connection)));
+    game.set_cancel_token(cancel);
+    if game.run()? == GameResult::Interrupted {
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    }
+    Ok(())
+}
+
+/// Sous-commande `join` : rejoint la partie d'un hôte, en jouant au clavier sur cette machine.
+fn join(addr: &str, name: Option<String>, cancel: CancelToken) -> Result<(), Box<dyn Error>> {
+    let name = name.unwrap_or_else(|| default_name(1));
+    let mut connection = Connection::new(TcpStream::connect(addr)?);
+    let opponent = connection.join(&name)?;
+    println!("{}", tr!(Msg::NetJoined, player = opponent));
+    let result = net::run_client(
+        &mut connection,
+        &mut HumanController::default(),
+        &mut StdConsole,
+        &cancel,
+    )?;
+    if result.is_none() {
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    }
+    Ok(())
+}
+
+/// Sous-commande `serve` : serveur WebSocket, chaque client web jouant ses parties contre un bot.
+#[cfg(feature = "ws")]
+fn serve(port: u16, seed: Option<u64>) -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind(("0.0.0.0", port))?;
+    println!(
+        "{}",
+        tr!(Msg::WsListening, port = listener.local_addr()?.port())
+    );
+    ws::serve(listener, seed)?;
+    Ok(())
+}
+
+/// Sous-commande `rules` : règles du jeu, avec les règles optionnelles du fichier de configuration.
+fn show_rules(config: Option<Config>) {
+    let mut args = PlayArgs::default();
+    if let Some(config) = config {
+        apply_config(&mut args, config);
+    }
+    println!(
+        "{}",
+        rules::render(&args.game_rules(), &ScoringConfig::default())
+    );
+}
+
+/// Sous-commande `completions` : script de complétion du shell donné, tiré des définitions de [`Cli`].
+fn completions(shell: Shell, out: &mut dyn Write) {
+    clap_complete::generate(shell, &mut Cli::command(), env!("CARGO_PKG_NAME"), out);
+}
+
+/// Sous-commande `man` : page de manuel au format roff, tirée des définitions de [`Cli`].
+fn man_page(out: &mut dyn Write) -> io::Result<()> {
+    clap_mangen::Man::new(Cli::command()).render(out)
+}
+
+/// Sous-commande `replay` : retrace une partie enregistrée, après avoir vérifié sa première manche.
+///
+/// Une rediffusion dont la première manche ne concorde pas avec son barème et ses règles est
+/// refusée, sauf avec `--force`.
+fn replay(file: &Path, force: bool) -> Result<(), Box<dyn Error>> {
+    let replay = Replay::load(file)?;
+    if let VerificationResult::Diverged(divergence) = replay.verify() {
+        if !force {
+            let version = replay.header.crate_version.as_deref().unwrap_or("?");
+            return Err(tr!(
+                Msg::ReplayDiverged,
+                version = version,
+                divergence = divergence
+            )
+            .into());
+        }
+        eprintln!("{}", tr!(Msg::ReplayForced, divergence = divergence));
+    }
+    println!("{replay}");
+    Ok(())
+}
+
+/// Efface la progression d'un profil hardcore dont le joueur a perdu la partie, après confirmation
+/// (voir [`confirm_permadeath`]). Un match nul ou une victoire laissent le profil intact.
+///
+/// Retourne la progression effacée, le cas échéant.
+fn permadeath(
+    console: &mut dyn Console,
+    profile: &mut Profile,
+    result: &GameResult,
+    index: usize,
+) -> Option<ProgressLoss> {
+    let lost = result
+        .winner()
+        .is_some_and(|winner| winner.index() != index);
+    if !profile.hardcore || !lost {
+        return None;
+    }
+    confirm_permadeath(console, profile)
+}
+
+/// Sous-commande `profile set-hardcore` : active ou désactive le mode hardcore d'un profil, créé au
+/// besoin.
+fn set_hardcore(name: &str, off: bool) -> Result<(), Box<dyn Error>> {
+    let store = ProfileStore::default_location()?;
+    let mut profile = store.load_or_create(name)?;
+    profile.hardcore = !off;
+    store.save(&profile)?;
+    let message = if off {
+        Msg::HardcoreDisabled
+    } else {
+        Msg::HardcoreEnabled
+    };
+    println!("{}", tr!(message, profile = profile.name));
+    Ok(())
+}
+
+/// Sous-commande `saves list` : emplacements de sauvegarde, triés par nom.
+fn list_saves() -> Result<(), Box<dyn Error>> {
+    let saves = SaveStore::default_location()?.list()?;
+    println!("{}", list_slots(&saves));
+    Ok(())
+}
+
+/// Sous-commande `stats` : classement des profils enregistrés, ou carte de précision par objectif des
+/// rediffusions d'un répertoire.
+///
+/// Une rediffusion illisible est signalée puis ignorée, sans empêcher l'analyse des autres.
+fn stats(args: StatsArgs) -> Result<(), Box<dyn Error>> {
+    if let Some(dir) = &args.replays {
+        let mut heatmap = ObjectiveHeatmap::default();
+        for replay in Replay::load_dir(dir)? {
+            match replay {
+                Ok(replay) => heatmap.add_replay(&replay),
+                Err(err) => eprintln!("{}", tr!(Msg::ReplaySkipped, error = err)),
+            }
+        }
+        println!("{heatmap}");
+        return Ok(());
+    }
+    let profiles = ProfileStore::default_location()?.list()?;
+    println!("{}", leaderboard(&profiles));
+    Ok(())
 }
 
 /// Fonction principale de l'application.
 ///
-/// Initialise le logger, parse les arguments, crée les joueurs et lance une boucle de parties.
-/// L'utilisateur peut choisir de relancer une partie ou de quitter l'application.
+/// Initialise le logger, parse les arguments, charge la configuration puis exécute la sous-commande
+/// demandée (`play` par défaut).
 fn main() -> Result<(), Box<dyn Error>> {
     // Initialisation du logger (log, env_logger)
     env_logger::init();
 
-    // Parse des arguments en ligne de commande.
-    let args: Args = Args::parse();
+    // Parse des arguments en ligne de commande, dont les erreurs suivent déjà la langue de `LANG`.
+    messages::set_lang(Lang::from_env());
+    let argv = Cli::expand_env(std::env::args_os(), &ProcessEnv).unwrap_or_else(|err| err.exit());
+    let (mut common, mut command) = Cli::try_parse_from(&argv)
+        .unwrap_or_else(|err| err.exit())
+        .into_parts();
 
-    // Création des joueurs avec les paramètres fournis.
-    let player1 = Player::new(args.name1, args.vitality, 75, 50);
-    let player2 = Player::new(args.name2, args.vitality, 75, 50);
+    // Le rejeu d'un rapport de bogue reprend sa ligne de commande, sa graine et ses saisies, sans
+    // terminal.
+    let bundle = match &command {
+        Command::Play(args) => args
+            .replay_inputs
+            .as_deref()
+            .map(|path| BugBundle::load(path).unwrap_or_else(|err| exit_config_error(err))),
+        _ => None,
+    };
+    if let Some(bundle) = &bundle {
+        let argv = std::iter::once(String::from("dual_game")).chain(bundle.args.iter().cloned());
+        (common, command) = Cli::try_parse_from(argv)
+            .unwrap_or_else(|err| err.exit())
+            .into_parts();
+        common.seed = Some(bundle.seed);
+        common.force_tty = false;
+        common.no_tty = true;
+        let _ = InputQueue::install_stdin(bundle.input_queue());
+    }
+    if let Some(lang) = common.lang {
+        messages::set_lang(lang);
+    }
 
-    // Boucle principale pour jouer plusieurs parties.
-    loop {
-        let mut game = Game::new(vec![player1.clone(), player2.clone()], args.objectifs);
-        game.run()?;
+    // La complétion et la page de manuel sont générées sans charger la configuration.
+    match &command {
+        Command::Completions { shell } => {
+            completions(*shell, &mut io::stdout());
+            return Ok(());
+        }
+        Command::Man => return Ok(man_page(&mut io::stdout())?),
+        _ => {}
+    }
 
-        println!("\n🔄 Relancer une partie ? [Y/N]");
-        loop {
-            print!("> ");
-            stdout().flush()?;
-            let mut input = String::new();
-            stdin().read_line(&mut input)?;
-            match input.trim().to_uppercase().as_str() {
-                "Y" => break,
-                "N" => return Ok(()),
-                _ => println!("Entrée invalide, veuillez entrer Y ou N."),
+    if let Command::Config {
+        action: ConfigAction::Init { path, force },
+    } = &command
+    {
+        if let Err(err) = Config::write_template(path, *force) {
+            eprintln!("{}", tr!(Msg::Error, error = err));
+            std::process::exit(1);
+        }
+        println!("{}", tr!(Msg::ConfigWritten, path = path.display()));
+        return Ok(());
+    }
+
+    let mut config_text = None;
+    let config = match bundle.as_ref().map(|bundle| bundle.config.as_deref()) {
+        Some(text) => {
+            text.map(|text| Config::from_toml(text).unwrap_or_else(|err| exit_config_error(err)))
+        }
+        None => match Config::discover(common.config.as_deref()) {
+            Ok(Some((path, config))) => {
+                for key in config.unknown_keys() {
+                    eprintln!(
+                        "{}",
+                        tr!(Msg::UnknownConfigKey, key = key, path = path.display())
+                    );
+                }
+                config_text = fs::read_to_string(&path).ok();
+                Some(config)
             }
+            Ok(None) => None,
+            Err(err) => exit_config_error(err),
+        },
+    };
+    if let Command::Play(args) = &command
+        && let Some(path) = &args.record_inputs
+    {
+        Recording::start(path.clone(), &argv, &mut common, config_text);
+    }
+    if let Some(ui) = config.as_ref().map(|config| &config.ui) {
+        common.no_color |= ui.color == Some(false);
+        common.ascii |= ui.ascii.unwrap_or(false);
+        common.theme = common.theme.or(ui.theme);
+    }
+    // Hors d'un terminal, le compteur n'est pas animé et l'affichage perd ses séquences ANSI.
+    // Le mode accessible écrit chaque annonce sur sa propre ligne, comme hors d'un terminal.
+    let tty = style::tty_requested(common.force_tty, common.no_tty || common.accessible);
+    style::set_tty(tty);
+    style::refresh_terminal_width();
+    style::set_enabled(tty && style::colors_requested(common.no_color));
+    style::set_ascii(common.ascii || common.accessible);
+    style::set_accessible(common.accessible);
+    style::set_announce_interval(
+        (common.announce_interval > 0).then(|| Duration::from_millis(common.announce_interval)),
+    );
+    style::set_theme(common.theme.unwrap_or_default());
+    match key_bindings(config.as_ref(), &common.bind) {
+        Ok(bindings) => keys::set_bindings(bindings),
+        Err(err) => exit_config_error(err),
+    }
+
+    let result = match command {
+        Command::Play(args) => play(
+            *args,
+            config,
+            common.seed,
+            common.verbosity(),
+            install_interrupt_handler(),
+        ),
+        Command::Practice(args) => practice(
+            args,
+            common.seed,
+            common.verbosity(),
+            install_interrupt_handler(),
+        ),
+        Command::Daily(args) => daily(args, common.verbosity(), install_interrupt_handler()),
+        Command::Simulate(args) => simulate(args, config.as_ref(), common.seed),
+        Command::Balance(args) => balance(args, config.as_ref(), common.seed),
+        Command::Replay { file, force } => replay(&file, force),
+        Command::Stats(args) => stats(args),
+        Command::Profile {
+            action: ProfileAction::SetHardcore { name, off },
+        } => set_hardcore(&name, off),
+        Command::Saves {
+            action: SavesAction::List,
+        } => list_saves(),
+        Command::Rules => {
+            show_rules(config);
+            Ok(())
         }
+        Command::Presets => {
+            println!("{}", rules::list_presets());
+            Ok(())
+        }
+        Command::Demo => demo(common.seed, common.verbosity(), install_interrupt_handler()),
+        Command::Host { port, name } => host(
+            port,
+            name,
+            common.seed,
+            common.verbosity(),
+            install_interrupt_handler(),
+        ),
+        Command::Join { addr, name } => join(&addr, name, install_interrupt_handler()),
+        #[cfg(feature = "ws")]
+        Command::Serve { port } => serve(port, common.seed),
+        Command::Completions { .. } | Command::Man | Command::Config { .. } => Ok(()),
+    };
+    Recording::save();
+    if let Err(err) = result {
+        eprintln!("{}", tr!(Msg::Error, error = err));
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dual_game::console::ScriptedConsole;
+    use std::collections::HashMap;
+
+    /// Analyse une ligne de commande et retourne les options de `play`.
+    fn play_args<const N: usize>(argv: [&str; N]) -> PlayArgs {
+        match Cli::try_parse_from(argv).unwrap().into_parts().1 {
+            Command::Play(args) => *args,
+            _ => panic!("sous-commande play attendue"),
+        }
+    }
+
+    /// Vérifie l'analyse des options de vitesse et de force de chaque joueur.
+    #[test]
+    fn test_speed_strength_flags() {
+        let args = play_args([
+            "dual_game",
+            "--name1",
+            "Alice",
+            "--name2",
+            "Bob",
+            "--speed1",
+            "120",
+            "--strength2",
+            "80",
+        ]);
+        let alice = build_player(0, &args, None).unwrap();
+        let bob = build_player(1, &args, None).unwrap();
+        assert_eq!((alice.speed, alice.strength), (120, DEFAULT_STRENGTH));
+        assert_eq!((bob.speed, bob.strength), (DEFAULT_SPEED, 80));
+
+        let args = play_args(["dual_game", "--name1", "Alice", "--strength1", "500"]);
+        assert!(matches!(
+            build_player(0, &args, None),
+            Err(PlayerConfigError::StrengthTooHigh(500))
+        ));
+    }
+
+    /// Vérifie l'option d'export du résultat et des statistiques des parties.
+    #[test]
+    fn test_export_flag() {
+        assert_eq!(play_args(["dual_game"]).export, None);
+        let args = play_args(["dual_game", "--export", "partie.json"]);
+        assert_eq!(args.export.as_deref(), Some(Path::new("partie.json")));
+    }
+
+    /// Vérifie les options d'enregistrement et de rejeu des saisies, exclusives l'une de l'autre, et
+    /// les arguments consignés dans le rapport de bogue.
+    #[test]
+    fn test_input_bundle_flags() {
+        let args = play_args(["dual_game", "--record-inputs", "bogue.json"]);
+        assert_eq!(args.record_inputs.as_deref(), Some(Path::new("bogue.json")));
+        let args = play_args(["dual_game", "--replay-inputs", "bogue.json"]);
+        assert_eq!(args.replay_inputs.as_deref(), Some(Path::new("bogue.json")));
+        assert!(
+            Cli::try_parse_from([
+                "dual_game",
+                "--record-inputs",
+                "a.json",
+                "--replay-inputs",
+                "b.json"
+            ])
+            .is_err()
+        );
+
+        let argv: Vec<OsString> = [
+            "dual_game",
+            "--record-inputs",
+            "bogue.json",
+            "--name1",
+            "Alice",
+            "--record-inputs=autre.json",
+            "--fatigue",
+        ]
+        .map(OsString::from)
+        .to_vec();
+        assert_eq!(recorded_args(&argv), ["--name1", "Alice", "--fatigue"]);
+    }
+
+    /// Vérifie le choix du rendu des parties, `plain` par défaut.
+    #[test]
+    fn test_render_flag() {
+        assert_eq!(play_args(["dual_game"]).render, RenderStyle::Plain);
+        let args = play_args(["dual_game", "--render", "json"]);
+        assert_eq!(args.render, RenderStyle::Json);
+        assert!(Cli::try_parse_from(["dual_game", "--render", "html"]).is_err());
+        assert!(!args.no_animations && !args.sound);
+        let args = play_args(["dual_game", "--render", "plain-ascii"]);
+        assert_eq!(args.render, RenderStyle::PlainAscii);
+        assert!(play_args(["dual_game", "--plain-ascii"]).plain_ascii);
+        assert!(Cli::try_parse_from(["dual_game", "--plain-ascii", "--render", "json"]).is_err());
+        let args = play_args([
+            "dual_game",
+            "--render",
+            "fancy",
+            "--no-animations",
+            "--sound",
+        ]);
+        assert!(args.no_animations && args.sound);
+    }
+
+    /// Vérifie le choix du mode duel et de son facteur de dégâts, repris de la configuration à défaut.
+    #[test]
+    fn test_mode_flag() {
+        assert_eq!(play_args(["dual_game"]).game_rules(), GameRules::default());
+        let args = play_args(["dual_game", "--mode", "duel", "--duel-factor", "15"]);
+        let rules = args.game_rules();
+        assert_eq!((rules.mode, rules.duel_factor), (GameMode::Duel, 15));
+        assert!(Cli::try_parse_from(["dual_game", "--mode", "survie"]).is_err());
+        let args = play_args(["dual_game", "--mode", "SHARED"]);
+        assert_eq!(args.game_rules().mode, GameMode::Shared);
+
+        let mut args = play_args(["dual_game", "--duel-factor", "5"]);
+        let config: Config = toml::from_str("[game]\nmode = \"duel\"\nduel_factor = 20").unwrap();
+        apply_config(&mut args, config);
+        let rules = args.game_rules();
+        assert_eq!((rules.mode, rules.duel_factor), (GameMode::Duel, 5));
+    }
+
+    /// Vérifie la priorité du préréglage : les options données l'emportent sur lui, et lui sur le
+    /// fichier de configuration.
+    #[test]
+    fn test_preset_precedence() {
+        let mut args = play_args(["dual_game", "--preset", "blitz"]);
+        args.apply_preset();
+        assert_eq!(
+            (args.objectifs, args.speed1, args.speed2),
+            (Some(3), Some(50), Some(50))
+        );
+        assert!(args.game_rules().fatigue);
+
+        let mut args = play_args([
+            "dual_game",
+            "--preset",
+            "blitz",
+            "--objectifs",
+            "7",
+            "--speed2",
+            "90",
+            "--mode",
+            "duel",
+        ]);
+        args.apply_preset();
+        let config: Config =
+            toml::from_str("[game]\nobjectifs = 9\nmode = \"shared\"\nshop = true").unwrap();
+        apply_config(&mut args, config);
+        assert_eq!(
+            (args.objectifs, args.speed1, args.speed2),
+            (Some(7), Some(50), Some(90))
+        );
+        let rules = args.game_rules();
+        assert_eq!(rules.mode, GameMode::Duel);
+        assert!(rules.fatigue && rules.shop);
+
+        let mut args = play_args(["dual_game", "--preset", "marathon"]);
+        args.apply_preset();
+        apply_config(&mut args, toml::from_str("[game]\nobjectifs = 4").unwrap());
+        assert_eq!(args.objectifs, Some(8));
+
+        assert!(Cli::try_parse_from(["dual_game", "--preset", "hardcore"]).is_err());
+        let (_, command) = Cli::try_parse_from(["dual_game", "presets"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(command, Command::Presets));
+        let help = Cli::command().render_long_help().to_string();
+        assert!(help.contains("--preset"), "{help}");
+    }
+
+    /// Vérifie que `simulate` et `balance` reprennent la section `[game]` du fichier de configuration
+    /// pour les options absentes de la ligne de commande.
+    #[test]
+    fn test_simulate_config_precedence() {
+        let config: Config =
+            toml::from_str("[game]\nobjectifs = 4\nvitality = 30\nfatigue = true").unwrap();
+        let parse = |argv: &[&str]| Cli::try_parse_from(argv).unwrap().into_parts().1;
+
+        let command = parse(&["dual_game", "simulate", "--objectifs", "7"]);
+        let Command::Simulate(mut args) = command else {
+            panic!("sous-commande simulate attendue");
+        };
+        args.apply_config(&config);
+        assert_eq!((args.objectifs, args.vitality), (Some(7), Some(30)));
+        assert!(args.fatigue);
+
+        let command = parse(&["dual_game", "balance", "--vitality", "60"]);
+        let Command::Balance(mut args) = command else {
+            panic!("sous-commande balance attendue");
+        };
+        assert_eq!(args.objectifs, None);
+        args.apply_config(&config);
+        assert_eq!((args.objectifs, args.vitality), (Some(4), Some(60)));
+    }
+
+    /// Vérifie que la boutique est activée par --shop ou par le fichier de configuration.
+    #[test]
+    fn test_shop_flag() {
+        assert!(play_args(["dual_game", "--shop"]).game_rules().shop);
+        let mut args = play_args(["dual_game"]);
+        let config: Config = toml::from_str("[game]\nshop = true").unwrap();
+        apply_config(&mut args, config);
+        assert!(args.game_rules().shop);
+    }
+
+    /// Vérifie que l'assistance de chaque joueur est activée séparément, au pourcentage par défaut
+    /// ou à celui donné.
+    #[test]
+    fn test_assist_flags() {
+        let args = play_args(["dual_game", "--assist1", "--name2", "Bob"]);
+        assert_eq!(build_player(0, &args, None).unwrap().assist, Some(60));
+        assert_eq!(build_player(1, &args, None).unwrap().assist, None);
+
+        let args = play_args(["dual_game", "--assist2", "25"]);
+        assert_eq!(build_player(0, &args, None).unwrap().assist, None);
+        assert_eq!(build_player(1, &args, None).unwrap().assist, Some(25));
+    }
+
+    /// Vérifie que le mode miroir est activé par son option comme par le fichier de configuration.
+    #[test]
+    fn test_mirror_flag() {
+        assert!(play_args(["dual_game", "--mirror"]).game_rules().mirror);
+        let mut args = play_args(["dual_game"]);
+        assert!(!args.game_rules().mirror);
+        let config: Config = toml::from_str("[game]\nmirror = true").unwrap();
+        apply_config(&mut args, config);
+        assert!(args.game_rules().mirror);
+    }
+
+    /// Vérifie que --bind l'emporte sur la section [keys] du fichier de configuration et qu'un conflit
+    /// entre les touches est refusé au démarrage.
+    #[test]
+    fn test_bind_flag() {
+        let (common, _) = Cli::try_parse_from([
+            "dual_game",
+            "practice",
+            "--bind",
+            "stop=space",
+            "--bind",
+            "lock1=z",
+        ])
+        .unwrap()
+        .into_parts();
+        assert_eq!(common.bind, ["stop=space", "lock1=z"]);
+
+        let config: Config = toml::from_str("[keys]\nstop = \"x\"\nstamina = \"b\"").unwrap();
+        let bindings = key_bindings(Some(&config), &common.bind).unwrap();
+        assert_eq!(bindings.stop, keys::Key::Char(' '));
+        assert_eq!(bindings.stamina, keys::Key::Char('b'));
+        assert_eq!(bindings.locks, [keys::Key::Char('z'), keys::Key::Char('l')]);
+
+        let err = key_bindings(None, &[String::from("freeze=s")]).unwrap_err();
+        assert!(matches!(err, KeyBindingError::Conflict { .. }));
+        assert!(key_bindings(None, &[String::from("stop")]).is_err());
+    }
+
+    /// Vérifie que la prise de risque est activée par son option comme par le fichier de
+    /// configuration.
+    #[test]
+    fn test_risk_choice_flag() {
+        assert!(
+            play_args(["dual_game", "--risk-choice"])
+                .game_rules()
+                .risk_choice
+        );
+        let mut args = play_args(["dual_game"]);
+        assert!(!args.game_rules().risk_choice);
+        let config: Config = toml::from_str("[game]\nrisk_choice = true").unwrap();
+        apply_config(&mut args, config);
+        assert!(args.game_rules().risk_choice);
+    }
+
+    /// Vérifie que les mutateurs sont activés par leur option comme par le fichier de configuration.
+    #[test]
+    fn test_mutators_flag() {
+        assert!(play_args(["dual_game", "--mutators"]).game_rules().mutators);
+        let mut args = play_args(["dual_game"]);
+        assert!(!args.game_rules().mutators);
+        let config: Config = toml::from_str("[game]\nmutators = true").unwrap();
+        apply_config(&mut args, config);
+        assert!(args.game_rules().mutators);
+    }
+
+    /// Vérifie que les interdictions sont activées par leur option comme par le fichier de
+    /// configuration.
+    #[test]
+    fn test_bans_flag() {
+        assert!(play_args(["dual_game", "--bans"]).game_rules().bans);
+        let mut args = play_args(["dual_game"]);
+        assert!(!args.game_rules().bans);
+        let config: Config = toml::from_str("[game]\nbans = true").unwrap();
+        apply_config(&mut args, config);
+        assert!(args.game_rules().bans);
+    }
+
+    /// Vérifie les options de série : le report exige `--best-of`, et le fichier de configuration
+    /// complète la ligne de commande sans l'écraser.
+    #[test]
+    fn test_best_of_flags() {
+        let args = play_args(["dual_game", "--best-of", "5", "--carry-over"]);
+        assert_eq!((args.best_of, args.carry_over), (Some(5), true));
+        assert!(Cli::try_parse_from(["dual_game", "--carry-over"]).is_err());
+
+        let mut args = play_args(["dual_game", "--best-of", "7"]);
+        let config: Config = toml::from_str("[game]\nbest_of = 3\ncarry_over = true").unwrap();
+        apply_config(&mut args, config);
+        assert_eq!((args.best_of, args.carry_over), (Some(7), true));
+    }
+
+    /// Vérifie les options de sauvegarde et la sous-commande `saves list`.
+    #[test]
+    fn test_save_slot_flags() {
+        let args = play_args(["dual_game", "--autosave", "slot1", "--load-slot", "slot1"]);
+        assert_eq!(
+            (args.autosave.as_deref(), args.load_slot.as_deref()),
+            (Some("slot1"), Some("slot1"))
+        );
+        assert!(
+            Cli::try_parse_from(["dual_game", "--load-slot", "slot1", "--profile1", "alice"])
+                .is_err()
+        );
+        let (_, command) = Cli::try_parse_from(["dual_game", "saves", "list"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(
+            command,
+            Command::Saves {
+                action: SavesAction::List
+            }
+        ));
+    }
+
+    /// Vérifie que seule une défaite efface la progression d'un profil hardcore, et la sous-commande
+    /// qui active le mode hardcore.
+    #[test]
+    fn test_permadeath_only_on_defeat() {
+        let mut profile = Profile::new("alice");
+        profile.hardcore = true;
+        profile.level = 4;
+        let outcomes = [
+            GameResult::Draw,
+            GameResult::Interrupted,
+            GameResult::Victory {
+                winner: PlayerId(0),
+            },
+        ];
+        for result in outcomes {
+            let mut console = ScriptedConsole::new(["o"]);
+            assert_eq!(permadeath(&mut console, &mut profile, &result, 0), None);
+            assert!(console.output.is_empty());
+        }
+        assert_eq!(profile.level, 4);
+
+        let defeat = GameResult::Forfeit {
+            forfeited: PlayerId(0),
+            winner: PlayerId(1),
+        };
+        let mut console = ScriptedConsole::new(["o"]);
+        let loss = permadeath(&mut console, &mut profile, &defeat, 0).unwrap();
+        assert_eq!((loss.level, profile.level), (4, 0));
+
+        profile.hardcore = false;
+        let mut console = ScriptedConsole::new(["o"]);
+        assert_eq!(permadeath(&mut console, &mut profile, &defeat, 0), None);
+
+        let (_, command) = Cli::try_parse_from(["dual_game", "profile", "set-hardcore", "alice"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(
+            command,
+            Command::Profile {
+                action: ProfileAction::SetHardcore { name, off: false }
+            } if name == "alice"
+        ));
+    }
+
+    /// Vérifie les options du fichier de pronostics : la fenêtre de paris exige le fichier.
+    #[test]
+    fn test_predictions_flags() {
+        let args = play_args([
+            "dual_game",
+            "--predictions-file",
+            "pronostics.json",
+            "--betting-window",
+            "30",
+        ]);
+        assert_eq!(
+            (args.predictions_file, args.betting_window),
+            (Some(PathBuf::from("pronostics.json")), Some(30))
+        );
+        assert!(Cli::try_parse_from(["dual_game", "--betting-window", "30"]).is_err());
+    }
+
+    /// Vérifie le raccourci --player1 et les messages d'erreur nommant le champ fautif.
+    #[test]
+    fn test_player_shorthand() {
+        let mut args = play_args([
+            "dual_game",
+            "--player1",
+            "Alice:40:90:60",
+            "--vitality",
+            "70",
+        ]);
+        assert_eq!(
+            args.player1,
+            Some(PlayerSpec {
+                name: String::from("Alice"),
+                vitality: 40,
+                speed: 90,
+                strength: 60
+            })
+        );
+        args.apply_player_specs();
+        assert_eq!(args.name1.as_deref(), Some("Alice"));
+        let alice = build_player(0, &args, None).unwrap();
+        assert_eq!(
+            (alice.vitality(), alice.speed, alice.strength),
+            (40, 90, 60)
+        );
+
+        let err = Cli::try_parse_from(["dual_game", "--player1", "Alice:50:vite:50"])
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("champ « vitesse » invalide"), "{err}");
+        let err = Cli::try_parse_from(["dual_game", "--player2", "Bob:50:75"])
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(
+            err.contains("format attendu nom:vitalité:vitesse:force"),
+            "{err}"
+        );
+        assert!(
+            Cli::try_parse_from(["dual_game", "--player1", "A:1:75:50", "--speed1", "80"]).is_err()
+        );
+    }
+
+    /// Vérifie les options forçant ou désactivant le traitement de la sortie comme un terminal.
+    #[test]
+    fn test_tty_flags() {
+        let (common, _) = Cli::try_parse_from(["dual_game", "demo", "--no-tty"])
+            .unwrap()
+            .into_parts();
+        assert!(common.no_tty && !common.force_tty);
+        assert!(style::tty_requested(true, false));
+        assert!(!style::tty_requested(false, true));
+        assert!(Cli::try_parse_from(["dual_game", "--force-tty", "--no-tty"]).is_err());
+    }
+
+    /// Vérifie les options du mode accessible et l'intervalle par défaut de ses annonces.
+    #[test]
+    fn test_accessible_flags() {
+        let (common, _) = Cli::try_parse_from(["dual_game", "--accessible"])
+            .unwrap()
+            .into_parts();
+        assert!(common.accessible);
+        assert_eq!(common.announce_interval, 1000);
+
+        let (common, command) = Cli::try_parse_from([
+            "dual_game",
+            "practice",
+            "--accessible",
+            "--announce-interval",
+            "0",
+        ])
+        .unwrap()
+        .into_parts();
+        assert!(common.accessible && common.announce_interval == 0);
+        assert!(matches!(command, Command::Practice(_)));
+        assert!(Cli::try_parse_from(["dual_game", "--accessible", "--force-tty"]).is_err());
+    }
+
+    /// Vérifie l'aiguillage des sous-commandes, `play` restant la sous-commande par défaut.
+    #[test]
+    fn test_subcommands() {
+        let (common, command) = Cli::try_parse_from(["dual_game", "--name1", "A", "--seed", "4"])
+            .unwrap()
+            .into_parts();
+        assert_eq!(common.seed, Some(4));
+        assert!(matches!(command, Command::Play(args) if args.name1.as_deref() == Some("A")));
+
+        let (common, command) =
+            Cli::try_parse_from(["dual_game", "play", "--name1", "A", "--bot", "--no-color"])
+                .unwrap()
+                .into_parts();
+        assert!(common.no_color);
+        assert!(matches!(command, Command::Play(args) if args.bot == Some(Difficulty::Normal)));
+
+        let (common, _) = Cli::try_parse_from(["dual_game", "--theme", "high-contrast"])
+            .unwrap()
+            .into_parts();
+        assert_eq!(common.theme, Some(Theme::HighContrast));
+        assert!(Cli::try_parse_from(["dual_game", "--theme", "sepia"]).is_err());
+
+        let (_, command) =
+            Cli::try_parse_from(["dual_game", "practice", "--turns", "5", "--class", "tank"])
+                .unwrap()
+                .into_parts();
+        assert!(matches!(
+            command,
+            Command::Practice(args) if args.turns == 5 && args.class == Some(PlayerClass::Tank)
+        ));
+        let (_, command) = Cli::try_parse_from([
+            "dual_game",
+            "practice",
+            "--record",
+            "essais.jsonl",
+            "--ghost",
+            "record.jsonl",
+        ])
+        .unwrap()
+        .into_parts();
+        assert!(matches!(
+            command,
+            Command::Practice(args) if args.record.as_deref() == Some(Path::new("essais.jsonl"))
+                && args.ghost.as_deref() == Some(Path::new("record.jsonl"))
+        ));
+
+        let (_, command) = Cli::try_parse_from(["dual_game", "daily", "--profile", "alice"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(
+            command,
+            Command::Daily(args) if args.name == "Joueur" && args.profile.as_deref() == Some("alice")
+        ));
+
+        let (common, command) = Cli::try_parse_from([
+            "dual_game",
+            "simulate",
+            "--games",
+            "10",
+            "--bot1",
+            "hard",
+            "--seed",
+            "1",
+        ])
+        .unwrap()
+        .into_parts();
+        assert_eq!(common.seed, Some(1));
+        assert!(matches!(
+            command,
+            Command::Simulate(args)
+                if args.games == 10 && args.bot1 == Difficulty::Hard && args.bot2 == Difficulty::Normal
+        ));
+
+        let (common, command) = Cli::try_parse_from([
+            "dual_game",
+            "balance",
+            "--games",
+            "5000",
+            "--seed",
+            "42",
+            "--out",
+            "equilibrage.csv",
+        ])
+        .unwrap()
+        .into_parts();
+        assert_eq!(common.seed, Some(42));
+        assert!(matches!(
+            command,
+            Command::Balance(args)
+                if args.games == 5000
+                    && args.bot == Difficulty::Normal
+                    && args.out.as_deref() == Some(Path::new("equilibrage.csv"))
+        ));
+
+        let (_, command) = Cli::try_parse_from(["dual_game", "replay", "partie.json"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(
+            command,
+            Command::Replay { file, force: false } if file == Path::new("partie.json")
+        ));
+
+        let (_, command) = Cli::try_parse_from(["dual_game", "replay", "partie.json", "--force"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(command, Command::Replay { force: true, .. }));
+
+        let (_, command) = Cli::try_parse_from(["dual_game", "stats"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(command, Command::Stats(args) if args.replays.is_none()));
+
+        let (_, command) = Cli::try_parse_from(["dual_game", "stats", "--replays", "parties"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(
+            command,
+            Command::Stats(args) if args.replays.as_deref() == Some(Path::new("parties"))
+        ));
+
+        let (common, command) = Cli::try_parse_from(["dual_game", "demo", "--seed", "2"])
+            .unwrap()
+            .into_parts();
+        assert_eq!(common.seed, Some(2));
+        assert!(matches!(command, Command::Demo));
+
+        let (_, command) = Cli::try_parse_from(["dual_game", "rules"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(command, Command::Rules));
+
+        let (_, command) = Cli::try_parse_from(["dual_game", "host"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(
+            command,
+            Command::Host {
+                port: DEFAULT_PORT,
+                name: None
+            }
+        ));
+        let (_, command) =
+            Cli::try_parse_from(["dual_game", "join", "10.0.0.2:4000", "--name", "Bob"])
+                .unwrap()
+                .into_parts();
+        assert!(matches!(
+            command,
+            Command::Join { addr, name: Some(name) } if addr == "10.0.0.2:4000" && name == "Bob"
+        ));
+        assert!(
+            play_args(["dual_game", "--rules", "--fatigue"])
+                .game_rules()
+                .fatigue
+        );
+
+        // Les options de play ne se mélangent pas aux autres sous-commandes.
+        assert!(Cli::try_parse_from(["dual_game", "--name1", "A", "stats"]).is_err());
+        assert!(Cli::try_parse_from(["dual_game", "replay"]).is_err());
+    }
+
+    /// Vérifie le choix entre l'assistant et les noms par défaut selon la console, ainsi que la
+    /// distinction d'un nom donné identique à un nom par défaut.
+    #[test]
+    fn test_default_names() {
+        let mut terminal = ScriptedConsole::default();
+        terminal.interactive = true;
+        let piped = ScriptedConsole::default();
+
+        let args = play_args(["dual_game", "--name2", "Bob"]);
+        assert!(uses_wizard(&args, &terminal));
+        assert!(!uses_wizard(&args, &piped));
+        let args = play_args(["dual_game", "--name1", "Alice", "--bot"]);
+        assert!(!uses_wizard(&args, &terminal));
+
+        let players = setup(&play_args(["dual_game"])).unwrap().players;
+        assert_eq!(players[0].name, "Joueur 1");
+        assert_eq!(players[1].name, "Joueur 2");
+        let players = setup(&play_args(["dual_game", "--name2", "Joueur 1"]))
+            .unwrap()
+            .players;
+        assert_eq!(players[0].name, "Joueur 1 (1)");
+        assert_eq!(players[1].name, "Joueur 1 (2)");
+    }
+
+    /// Vérifie qu'une rediffusion dont le barème ne concorde pas avec ses scores n'est relue qu'avec
+    /// `--force`.
+    #[test]
+    fn test_replay_force() {
+        let file =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/mismatched_scoring.json");
+        let err = replay(&file, false).unwrap_err().to_string();
+        assert!(err.contains("version 0.1.0"), "{err}");
+        assert!(
+            err.contains("score enregistré 150, score recalculé 170"),
+            "{err}"
+        );
+        assert!(replay(&file, true).is_ok());
+    }
+
+    /// Vérifie que la complétion de chaque shell et la page de manuel reprennent les vraies options.
+    #[test]
+    fn test_completions_and_man_page() {
+        for shell in [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Elvish,
+        ] {
+            let mut out = Vec::new();
+            completions(shell, &mut out);
+            let script = String::from_utf8(out).unwrap();
+            for flag in ["name1", "log-file", "objectifs", "games", "lang"] {
+                assert!(script.contains(flag), "{shell} : option {flag} absente");
+            }
+        }
+
+        let mut out = Vec::new();
+        man_page(&mut out).unwrap();
+        let page = String::from_utf8(out).unwrap();
+        assert!(page.starts_with(".ie"), "{page}");
+        assert!(page.contains(".TH dual_game"));
+        assert!(page.contains("name1") && page.contains("simulate"));
+
+        let (_, command) = Cli::try_parse_from(["dual_game", "completions", "fish"])
+            .unwrap()
+            .into_parts();
+        assert!(matches!(
+            command,
+            Command::Completions { shell: Shell::Fish }
+        ));
+    }
+
+    /// Crée un environnement fictif à partir de paires nom/valeur.
+    fn mock_env<const N: usize>(vars: [(&str, &str); N]) -> HashMap<String, String> {
+        vars.into_iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    /// Vérifie que les variables d'environnement complètent la ligne de commande sans l'emporter sur elle.
+    #[test]
+    fn test_env_fallbacks() {
+        let env = mock_env([
+            ("DUAL_GAME_SEED", "7"),
+            ("DUAL_GAME_NAME1", "Alice"),
+            ("DUAL_GAME_PLAYER2", "Bob:60:90:40"),
+            ("DUAL_GAME_FATIGUE", "oui"),
+            ("DUAL_GAME_STAMINA", "0"),
+            ("DUAL_GAME_LOG_FILE", "partie.log"),
+            ("DUAL_GAME_VERBOSE", "1"),
+        ]);
+        let (common, command) = Cli::parse_with_env(["dual_game", "--seed", "3"], &env)
+            .unwrap()
+            .into_parts();
+        assert_eq!(common.seed, Some(3));
+        assert!(common.verbose);
+        let Command::Play(args) = command else {
+            panic!("sous-commande play attendue");
+        };
+        assert_eq!(args.name1.as_deref(), Some("Alice"));
+        assert_eq!(args.player2.map(|spec| spec.speed), Some(90));
+        assert!(args.fatigue && !args.stamina);
+        assert_eq!(args.log_file, Some(PathBuf::from("partie.log")));
+
+        // Une variable en conflit avec la ligne de commande est ignorée, dans les deux sens.
+        let (common, command) =
+            Cli::parse_with_env(["dual_game", "play", "--profile1", "alice", "-q"], &env)
+                .unwrap()
+                .into_parts();
+        assert!(common.quiet && !common.verbose);
+        assert!(matches!(command, Command::Play(args) if args.name1.is_none()));
+
+        // Les variables s'appliquent aussi aux autres sous-commandes.
+        let env = mock_env([("DUAL_GAME_GAMES", "12"), ("DUAL_GAME_BOT1", "hard")]);
+        let (_, command) = Cli::parse_with_env(["dual_game", "simulate", "--games", "3"], &env)
+            .unwrap()
+            .into_parts();
+        assert!(matches!(
+            command,
+            Command::Simulate(args) if args.games == 3 && args.bot1 == Difficulty::Hard
+        ));
+
+        let env = mock_env([("DUAL_GAME_ASCII", "peut-être")]);
+        let err = Cli::parse_with_env(["dual_game", "stats"], &env)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("DUAL_GAME_ASCII"), "{err}");
+        let env = mock_env([("DUAL_GAME_SEED", "abc")]);
+        assert!(Cli::parse_with_env(["dual_game", "stats"], &env).is_err());
     }
 }
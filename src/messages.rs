@@ -0,0 +1,1939 @@
+//! Module des messages affichés à l'utilisateur.
+//!
+//! Chaque message est identifié par une clé [`Msg`] et traduit dans chaque [`Lang`] par un gabarit
+//! dont les paramètres sont nommés entre accolades (`{player}`, `{round}`…). La langue est choisie
+//! globalement avec l'option `--lang` ou, à défaut, d'après la variable d'environnement `LANG` ; le
+//! français reste la langue par défaut.
+//!
+//! La macro [`tr!`](crate::tr) formate un message dans la langue courante :
+//!
+//! ```
+//! use dual_game::messages::{Lang, Msg};
+//! use dual_game::tr;
+//!
+//! assert_eq!(tr!(Msg::RoundStart, round = 3), "## Manche 3 ##");
+//! assert_eq!(Msg::RoundStart.format(Lang::En, &[("round", &3)]), "## Round 3 ##");
+//! ```
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fmt::{self, Write};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Langue courante de l'affichage, stockée sous forme d'index dans [`Lang::ALL`].
+static LANG: AtomicU8 = AtomicU8::new(0);
+
+/// Langues disponibles pour l'affichage.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Lang {
+    #[default]
+    Fr,
+    En,
+}
+
+impl Lang {
+    /// Liste de toutes les langues disponibles.
+    pub const ALL: [Lang; 2] = [Lang::Fr, Lang::En];
+
+    /// Retourne l'identifiant de la langue utilisé en ligne de commande.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Lang::Fr => "fr",
+            Lang::En => "en",
+        }
+    }
+
+    /// Déduit la langue d'une valeur de locale POSIX (`en_US.UTF-8`, `fr_FR`…).
+    ///
+    /// # Retour
+    ///
+    /// Retourne `None` si la locale ne correspond à aucune langue disponible.
+    pub fn from_locale(locale: &str) -> Option<Lang> {
+        let code = locale
+            .split(['_', '.', '@', '-'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        Lang::ALL.into_iter().find(|lang| lang.id() == code)
+    }
+
+    /// Retourne la langue indiquée par la variable d'environnement `LANG`, le français à défaut.
+    pub fn from_env() -> Lang {
+        env::var("LANG")
+            .ok()
+            .and_then(|locale| Lang::from_locale(&locale))
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Lang {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+impl FromStr for Lang {
+    type Err = String;
+
+    /// Analyse une langue à partir de son identifiant (insensible à la casse).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s.trim().to_lowercase();
+        Lang::ALL
+            .into_iter()
+            .find(|lang| lang.id() == id)
+            .ok_or_else(|| {
+                let ids: Vec<&str> = Lang::ALL.iter().map(|l| l.id()).collect();
+                format!(
+                    "langue inconnue « {s} » (valeurs possibles : {})",
+                    ids.join(", ")
+                )
+            })
+    }
+}
+
+/// Choisit la langue de l'ensemble de l'affichage.
+pub fn set_lang(lang: Lang) {
+    let index = Lang::ALL.iter().position(|l| *l == lang).unwrap_or(0);
+    LANG.store(index as u8, Ordering::Relaxed);
+}
+
+/// Retourne la langue courante de l'affichage.
+pub fn lang() -> Lang {
+    Lang::ALL
+        .get(usize::from(LANG.load(Ordering::Relaxed)))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Clés des messages affichés à l'utilisateur.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Msg {
+    // Déroulement de la partie (`game`).
+    GameStart,
+    RoundStart,
+    TurnOf,
+    TurnOfFatigued,
+    Objectives,
+    PressEnterTurn,
+    TurnCountdown,
+    PressEnterRound,
+    TurnEnd,
+    AverageScore,
+    AssistedTag,
+    NotEnoughPlayers,
+    ScoreTie,
+    TiebreakerNext,
+    DuelObjective,
+    PressEnterDuel,
+    DuelPointWon,
+    DuelPointTied,
+    PressEnterShared,
+    ArmorDetail,
+    RoundWon,
+    TimeTiebreak,
+    ChoosePoison,
+    PoisonChoiceRetry,
+    PoisonApplied,
+    PoisonCountered,
+    PoisonDelayed,
+    PoisonWarning,
+    WinnersCurse,
+    CoinBalances,
+    ShopMenu,
+    ShopItem,
+    ShopSkip,
+    ShopBought,
+    ShopRefused,
+    MirrorNotice,
+    RiskMenu,
+    RiskOption,
+    BanMenu,
+    BanOption,
+    BanNone,
+    BanNotice,
+    PoisonAllBanned,
+    MutatorAnnounce,
+    MutatorFog,
+    MutatorStorm,
+    MutatorGravity,
+    MutatorJackpot,
+    RoundEnd,
+    GameOver,
+    Winner,
+    Draw,
+    PlayerStats,
+    CounterOffer,
+    AcceptPoison,
+    CounterPoison,
+    PracticeStart,
+    PracticeTry,
+    PressEnterTry,
+    PracticeInterrupted,
+    PracticeOver,
+    PracticeSummary,
+    GhostStop,
+    GhostAverages,
+    GhostAhead,
+    GhostBehind,
+    GhostTied,
+    DailyTitle,
+    DailyAlreadyPlayed,
+    DailyBest,
+    GameInterrupted,
+    PlayerForfeited,
+    RoundsCompleted,
+    TotalScores,
+    ObjectiveDetail,
+    ObjectiveRetried,
+    RetryMenu,
+    StalemateOn,
+    StalemateOff,
+    CommentaryPerfect1,
+    CommentaryPerfect2,
+    CommentaryDisaster1,
+    CommentaryDisaster2,
+    CommentaryDraw1,
+    CommentaryDraw2,
+    CommentaryComeback1,
+    CommentaryComeback2,
+    CommentaryBlowout1,
+    CommentaryBlowout2,
+    CommentaryNailBiter1,
+    CommentaryNailBiter2,
+    CommentaryStandard1,
+    CommentaryStandard2,
+    RefereeLookAway,
+    RefereeMenu,
+    RefereeOption,
+    RefereeAbstain,
+    RefereeRight,
+    RefereeWrong,
+    RefereeReward,
+    RefereeMutatorOption,
+    RefereeMutatorNone,
+    // Compteur (`counter`).
+    CounterStatus,
+    CounterStatusStamina,
+    CounterPreview,
+    SharedCounterStatus,
+    SharedLocked,
+    SharedWaiting,
+    SharedNoLock,
+    // Validation des joueurs (`player`).
+    EmptyName,
+    NameTooLong,
+    InvalidVitality,
+    SpeedOutOfRange,
+    StrengthTooHigh,
+    InvalidAvatar,
+    LuckTooHigh,
+    ShopNotEnoughCoins,
+    ShopNothingToCure,
+    MirrorPlayerCount,
+    TooManyObjectives,
+    DefaultPlayerName,
+    // Règles (`rules`).
+    RulesTitle,
+    RulesCounter,
+    RulesScoring,
+    RulesTierSingle,
+    RulesTierRange,
+    RulesTierBeyond,
+    RulesMiss,
+    RulesDamage,
+    RulesPoison,
+    RulesWin,
+    RulesFatigueOn,
+    RulesFatigueOff,
+    RulesStaminaOn,
+    RulesStaminaOff,
+    RulesDuel,
+    RulesShared,
+    RulesShop,
+    RulesMirror,
+    RulesRisk,
+    RulesPause,
+    RulesMutators,
+    RulesBans,
+    RulesRetry,
+    RulesTimeTiebreak,
+    RulesStalemate,
+    RulesDelayedPoison,
+    RulesWinnersCurse,
+    RulesOffer,
+    PresetsTitle,
+    PresetEntry,
+    PresetClassic,
+    PresetBlitz,
+    PresetMarathon,
+    PresetParty,
+    UnknownPreset,
+    ClassBalanced,
+    ClassSprinter,
+    ClassBruiser,
+    ClassTank,
+    UnknownClass,
+    // Application (`main`, `rematch`, `demo`, `env`).
+    SpecFieldCount,
+    SpecInvalidField,
+    SpecEmptyName,
+    FieldVitality,
+    FieldSpeed,
+    FieldStrength,
+    DuplicateName,
+    SetupInterrupted,
+    SetupTitle,
+    SetupInvalidInput,
+    SetupYesNoError,
+    SetupYes,
+    SetupNo,
+    SetupYesKey,
+    SetupNoKey,
+    SetupPlayerName,
+    SetupPlayerClass,
+    SetupNoClass,
+    SetupVitality,
+    SetupPositiveNumber,
+    SetupObjectives,
+    SetupFatigue,
+    SetupStamina,
+    SetupSummary,
+    SetupSummaryPlayer,
+    SetupSummaryRules,
+    ConfigError,
+    ConfigWritten,
+    BundleWritten,
+    UnknownConfigKey,
+    KeyBindSyntax,
+    KeyUnknownAction,
+    KeyInvalid,
+    KeyConflict,
+    ReplaySaveFailed,
+    NotifyFailed,
+    ProfileSaveFailed,
+    HardcoreWarning,
+    HardcoreLoss,
+    HardcoreConfirm,
+    HardcoreReset,
+    HardcoreKept,
+    HardcoreEnabled,
+    HardcoreDisabled,
+    XpLevelUp,
+    XpProgress,
+    AchievementUnlocked,
+    RematchPrompt,
+    RematchSame,
+    RematchSwap,
+    RematchEdit,
+    RematchStats,
+    RematchQuit,
+    RematchInvalid,
+    ChoiceInvalid,
+    SessionScore,
+    SessionDraw,
+    SessionDraws,
+    SessionForfeit,
+    SessionForfeits,
+    SeriesStart,
+    SeriesScore,
+    SeriesGameWon,
+    SeriesGameDrawn,
+    SeriesCarriedOver,
+    SeriesWinner,
+    SaveResumed,
+    SaveSlot,
+    SavesEmpty,
+    DemoStart,
+    DemoNextGame,
+    EnvInvalidFlag,
+    NetWaiting,
+    NetConnected,
+    NetJoined,
+    NetDisconnected,
+    NetIo,
+    NetTooLarge,
+    NetInvalidMessage,
+    NetUnexpected,
+    NetIncompatible,
+    NetRejected,
+    WsListening,
+    ProtocolUnknownCommand,
+    ProtocolMissingArgument,
+    ProtocolInvalidArgument,
+    ProtocolExtraArgument,
+    ProtocolUnexpected,
+    TuiTooSmall,
+    TuiLogTitle,
+    TuiPoisonTitle,
+    TuiWinnersCurse,
+    TuiStopHint,
+    TuiBreathHint,
+    TuiChoiceHint,
+    TuiContinueHint,
+    PoisonedStat,
+    StatsTitle,
+    StatsPrecision,
+    StatsObjectives,
+    StatsDamage,
+    StatsDraws,
+    StatsRounds,
+    StatsErrors,
+    StatsHighlight,
+    StatsMvp,
+    ExportSaveFailed,
+    SimulationTitle,
+    SimulationWins,
+    SimulationDraws,
+    SimulationLength,
+    BalanceTitle,
+    BalanceColumns,
+    BalanceRow,
+    BalanceProgress,
+    BalanceSaveFailed,
+    ReplaySkipped,
+    ReplayDiverged,
+    ReplayForced,
+    ScoreboardTitle,
+    ScoreboardRecord,
+    ScoreboardRow,
+    CounterStarted,
+    CounterValue,
+    CounterHotZone,
+    CounterStopped,
+    CounterPaused,
+    CounterResumed,
+    SpokenGameStart,
+    SpokenPlayer,
+    SpokenRoundStart,
+    SpokenVitality,
+    SpokenTurn,
+    SpokenFatigue,
+    SpokenObjectives,
+    SpokenStop,
+    SpokenScore,
+    SpokenTurnEnd,
+    SpokenOption,
+    SpokenAccept,
+    SpokenPoison,
+    SpokenCounter,
+    SpokenRoundEnd,
+    SpokenGameOver,
+    SpokenInterrupted,
+    SpokenForfeit,
+    SpokenTotal,
+    SpokenStats,
+    SpokenDraws,
+    SpokenRounds,
+    SpokenErrors,
+    SpokenHighlight,
+    SpokenMvp,
+    Cancelled,
+    InterruptRequested,
+    InterruptUnavailable,
+    Error,
+}
+
+impl Msg {
+    /// Liste de toutes les clés de messages.
+    pub const ALL: [Msg; 318] = [
+        Msg::GameStart,
+        Msg::RoundStart,
+        Msg::TurnOf,
+        Msg::TurnOfFatigued,
+        Msg::Objectives,
+        Msg::PressEnterTurn,
+        Msg::TurnCountdown,
+        Msg::PressEnterRound,
+        Msg::TurnEnd,
+        Msg::AverageScore,
+        Msg::AssistedTag,
+        Msg::NotEnoughPlayers,
+        Msg::ScoreTie,
+        Msg::TiebreakerNext,
+        Msg::DuelObjective,
+        Msg::PressEnterDuel,
+        Msg::DuelPointWon,
+        Msg::DuelPointTied,
+        Msg::PressEnterShared,
+        Msg::ArmorDetail,
+        Msg::RoundWon,
+        Msg::TimeTiebreak,
+        Msg::ChoosePoison,
+        Msg::PoisonChoiceRetry,
+        Msg::PoisonApplied,
+        Msg::PoisonCountered,
+        Msg::PoisonDelayed,
+        Msg::PoisonWarning,
+        Msg::WinnersCurse,
+        Msg::CoinBalances,
+        Msg::ShopMenu,
+        Msg::ShopItem,
+        Msg::ShopSkip,
+        Msg::ShopBought,
+        Msg::ShopRefused,
+        Msg::MirrorNotice,
+        Msg::RiskMenu,
+        Msg::RiskOption,
+        Msg::BanMenu,
+        Msg::BanOption,
+        Msg::BanNone,
+        Msg::BanNotice,
+        Msg::PoisonAllBanned,
+        Msg::MutatorAnnounce,
+        Msg::MutatorFog,
+        Msg::MutatorStorm,
+        Msg::MutatorGravity,
+        Msg::MutatorJackpot,
+        Msg::RoundEnd,
+        Msg::GameOver,
+        Msg::Winner,
+        Msg::Draw,
+        Msg::PlayerStats,
+        Msg::CounterOffer,
+        Msg::AcceptPoison,
+        Msg::CounterPoison,
+        Msg::PracticeStart,
+        Msg::PracticeTry,
+        Msg::PressEnterTry,
+        Msg::PracticeInterrupted,
+        Msg::PracticeOver,
+        Msg::PracticeSummary,
+        Msg::GhostStop,
+        Msg::GhostAverages,
+        Msg::GhostAhead,
+        Msg::GhostBehind,
+        Msg::GhostTied,
+        Msg::DailyTitle,
+        Msg::DailyAlreadyPlayed,
+        Msg::DailyBest,
+        Msg::GameInterrupted,
+        Msg::PlayerForfeited,
+        Msg::RoundsCompleted,
+        Msg::TotalScores,
+        Msg::ObjectiveDetail,
+        Msg::ObjectiveRetried,
+        Msg::RetryMenu,
+        Msg::StalemateOn,
+        Msg::StalemateOff,
+        Msg::CommentaryPerfect1,
+        Msg::CommentaryPerfect2,
+        Msg::CommentaryDisaster1,
+        Msg::CommentaryDisaster2,
+        Msg::CommentaryDraw1,
+        Msg::CommentaryDraw2,
+        Msg::CommentaryComeback1,
+        Msg::CommentaryComeback2,
+        Msg::CommentaryBlowout1,
+        Msg::CommentaryBlowout2,
+        Msg::CommentaryNailBiter1,
+        Msg::CommentaryNailBiter2,
+        Msg::CommentaryStandard1,
+        Msg::CommentaryStandard2,
+        Msg::RefereeLookAway,
+        Msg::RefereeMenu,
+        Msg::RefereeOption,
+        Msg::RefereeAbstain,
+        Msg::RefereeRight,
+        Msg::RefereeWrong,
+        Msg::RefereeReward,
+        Msg::RefereeMutatorOption,
+        Msg::RefereeMutatorNone,
+        Msg::CounterStatus,
+        Msg::CounterStatusStamina,
+        Msg::CounterPreview,
+        Msg::SharedCounterStatus,
+        Msg::SharedLocked,
+        Msg::SharedWaiting,
+        Msg::SharedNoLock,
+        Msg::EmptyName,
+        Msg::NameTooLong,
+        Msg::InvalidVitality,
+        Msg::SpeedOutOfRange,
+        Msg::StrengthTooHigh,
+        Msg::InvalidAvatar,
+        Msg::LuckTooHigh,
+        Msg::ShopNotEnoughCoins,
+        Msg::ShopNothingToCure,
+        Msg::MirrorPlayerCount,
+        Msg::TooManyObjectives,
+        Msg::DefaultPlayerName,
+        Msg::RulesTitle,
+        Msg::RulesCounter,
+        Msg::RulesScoring,
+        Msg::RulesTierSingle,
+        Msg::RulesTierRange,
+        Msg::RulesTierBeyond,
+        Msg::RulesMiss,
+        Msg::RulesDamage,
+        Msg::RulesPoison,
+        Msg::RulesWin,
+        Msg::RulesFatigueOn,
+        Msg::RulesFatigueOff,
+        Msg::RulesStaminaOn,
+        Msg::RulesStaminaOff,
+        Msg::RulesDuel,
+        Msg::RulesShared,
+        Msg::RulesShop,
+        Msg::RulesMirror,
+        Msg::RulesRisk,
+        Msg::RulesPause,
+        Msg::RulesMutators,
+        Msg::RulesBans,
+        Msg::RulesRetry,
+        Msg::RulesTimeTiebreak,
+        Msg::RulesStalemate,
+        Msg::RulesDelayedPoison,
+        Msg::RulesWinnersCurse,
+        Msg::RulesOffer,
+        Msg::PresetsTitle,
+        Msg::PresetEntry,
+        Msg::PresetClassic,
+        Msg::PresetBlitz,
+        Msg::PresetMarathon,
+        Msg::PresetParty,
+        Msg::UnknownPreset,
+        Msg::ClassBalanced,
+        Msg::ClassSprinter,
+        Msg::ClassBruiser,
+        Msg::ClassTank,
+        Msg::UnknownClass,
+        Msg::SpecFieldCount,
+        Msg::SpecInvalidField,
+        Msg::SpecEmptyName,
+        Msg::FieldVitality,
+        Msg::FieldSpeed,
+        Msg::FieldStrength,
+        Msg::DuplicateName,
+        Msg::SetupInterrupted,
+        Msg::SetupTitle,
+        Msg::SetupInvalidInput,
+        Msg::SetupYesNoError,
+        Msg::SetupYes,
+        Msg::SetupNo,
+        Msg::SetupYesKey,
+        Msg::SetupNoKey,
+        Msg::SetupPlayerName,
+        Msg::SetupPlayerClass,
+        Msg::SetupNoClass,
+        Msg::SetupVitality,
+        Msg::SetupPositiveNumber,
+        Msg::SetupObjectives,
+        Msg::SetupFatigue,
+        Msg::SetupStamina,
+        Msg::SetupSummary,
+        Msg::SetupSummaryPlayer,
+        Msg::SetupSummaryRules,
+        Msg::ConfigError,
+        Msg::ConfigWritten,
+        Msg::BundleWritten,
+        Msg::UnknownConfigKey,
+        Msg::KeyBindSyntax,
+        Msg::KeyUnknownAction,
+        Msg::KeyInvalid,
+        Msg::KeyConflict,
+        Msg::ReplaySaveFailed,
+        Msg::NotifyFailed,
+        Msg::ProfileSaveFailed,
+        Msg::HardcoreWarning,
+        Msg::HardcoreLoss,
+        Msg::HardcoreConfirm,
+        Msg::HardcoreReset,
+        Msg::HardcoreKept,
+        Msg::HardcoreEnabled,
+        Msg::HardcoreDisabled,
+        Msg::XpLevelUp,
+        Msg::XpProgress,
+        Msg::AchievementUnlocked,
+        Msg::RematchPrompt,
+        Msg::RematchSame,
+        Msg::RematchSwap,
+        Msg::RematchEdit,
+        Msg::RematchStats,
+        Msg::RematchQuit,
+        Msg::RematchInvalid,
+        Msg::ChoiceInvalid,
+        Msg::SessionScore,
+        Msg::SessionDraw,
+        Msg::SessionDraws,
+        Msg::SessionForfeit,
+        Msg::SessionForfeits,
+        Msg::SeriesStart,
+        Msg::SeriesScore,
+        Msg::SeriesGameWon,
+        Msg::SeriesGameDrawn,
+        Msg::SeriesCarriedOver,
+        Msg::SeriesWinner,
+        Msg::SaveResumed,
+        Msg::SaveSlot,
+        Msg::SavesEmpty,
+        Msg::DemoStart,
+        Msg::DemoNextGame,
+        Msg::EnvInvalidFlag,
+        Msg::NetWaiting,
+        Msg::NetConnected,
+        Msg::NetJoined,
+        Msg::NetDisconnected,
+        Msg::NetIo,
+        Msg::NetTooLarge,
+        Msg::NetInvalidMessage,
+        Msg::NetUnexpected,
+        Msg::NetIncompatible,
+        Msg::NetRejected,
+        Msg::WsListening,
+        Msg::ProtocolUnknownCommand,
+        Msg::ProtocolMissingArgument,
+        Msg::ProtocolInvalidArgument,
+        Msg::ProtocolExtraArgument,
+        Msg::ProtocolUnexpected,
+        Msg::TuiTooSmall,
+        Msg::TuiLogTitle,
+        Msg::TuiPoisonTitle,
+        Msg::TuiWinnersCurse,
+        Msg::TuiStopHint,
+        Msg::TuiBreathHint,
+        Msg::TuiChoiceHint,
+        Msg::TuiContinueHint,
+        Msg::PoisonedStat,
+        Msg::StatsTitle,
+        Msg::StatsPrecision,
+        Msg::StatsObjectives,
+        Msg::StatsDamage,
+        Msg::StatsDraws,
+        Msg::StatsRounds,
+        Msg::StatsErrors,
+        Msg::StatsHighlight,
+        Msg::StatsMvp,
+        Msg::ExportSaveFailed,
+        Msg::SimulationTitle,
+        Msg::SimulationWins,
+        Msg::SimulationDraws,
+        Msg::SimulationLength,
+        Msg::BalanceTitle,
+        Msg::BalanceColumns,
+        Msg::BalanceRow,
+        Msg::BalanceProgress,
+        Msg::BalanceSaveFailed,
+        Msg::ReplaySkipped,
+        Msg::ReplayDiverged,
+        Msg::ReplayForced,
+        Msg::ScoreboardTitle,
+        Msg::ScoreboardRecord,
+        Msg::ScoreboardRow,
+        Msg::CounterStarted,
+        Msg::CounterValue,
+        Msg::CounterHotZone,
+        Msg::CounterStopped,
+        Msg::CounterPaused,
+        Msg::CounterResumed,
+        Msg::SpokenGameStart,
+        Msg::SpokenPlayer,
+        Msg::SpokenRoundStart,
+        Msg::SpokenVitality,
+        Msg::SpokenTurn,
+        Msg::SpokenFatigue,
+        Msg::SpokenObjectives,
+        Msg::SpokenStop,
+        Msg::SpokenScore,
+        Msg::SpokenTurnEnd,
+        Msg::SpokenOption,
+        Msg::SpokenAccept,
+        Msg::SpokenPoison,
+        Msg::SpokenCounter,
+        Msg::SpokenRoundEnd,
+        Msg::SpokenGameOver,
+        Msg::SpokenInterrupted,
+        Msg::SpokenForfeit,
+        Msg::SpokenTotal,
+        Msg::SpokenStats,
+        Msg::SpokenDraws,
+        Msg::SpokenRounds,
+        Msg::SpokenErrors,
+        Msg::SpokenHighlight,
+        Msg::SpokenMvp,
+        Msg::Cancelled,
+        Msg::InterruptRequested,
+        Msg::InterruptUnavailable,
+        Msg::Error,
+    ];
+
+    /// Retourne le gabarit du message dans la langue donnée.
+    pub fn template(self, lang: Lang) -> &'static str {
+        match lang {
+            Lang::Fr => fr(self),
+            Lang::En => en(self),
+        }
+    }
+
+    /// Formate le message dans la langue donnée en remplaçant ses paramètres nommés.
+    ///
+    /// Un paramètre absent de `args` est laissé tel quel dans le texte.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - La langue du message.
+    /// * `args` - Les valeurs des paramètres, associées à leur nom.
+    pub fn format(self, lang: Lang, args: &[(&str, &dyn fmt::Display)]) -> String {
+        let mut text = String::new();
+        self.format_into(lang, args, &mut text);
+        text
+    }
+
+    /// Formate le message comme [`Msg::format`], à la suite du texte donné.
+    ///
+    /// Le texte peut ainsi être réutilisé d'un formatage à l'autre, sans nouvelle allocation une fois
+    /// sa capacité suffisante.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::messages::{Lang, Msg};
+    ///
+    /// let mut text = String::from("> ");
+    /// Msg::PracticeTry.format_into(Lang::Fr, &[("turn", &1), ("turns", &3)], &mut text);
+    /// assert_eq!(text, "> ## Essai 1/3 ##");
+    /// ```
+    pub fn format_into(self, lang: Lang, args: &[(&str, &dyn fmt::Display)], text: &mut String) {
+        let mut rest = self.template(lang);
+        while let Some(start) = rest.find('{') {
+            text.push_str(&rest[..start]);
+            let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+                break;
+            };
+            let name = &rest[start + 1..end];
+            match args.iter().find(|(key, _)| *key == name) {
+                Some((_, value)) => {
+                    let _ = write!(text, "{value}");
+                }
+                None => text.push_str(&rest[start..=end]),
+            }
+            rest = &rest[end + 1..];
+        }
+        text.push_str(rest);
+    }
+
+    /// Retourne les noms des paramètres du message dans la langue donnée.
+    pub fn placeholders(self, lang: Lang) -> BTreeSet<&'static str> {
+        self.template(lang)
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+}
+
+/// Formate un message dans la langue courante.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::messages::Msg;
+/// use dual_game::tr;
+///
+/// assert_eq!(
+///     tr!(Msg::PracticeTry, turn = 1, turns = 3),
+///     "## Essai 1/3 ##"
+/// );
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($msg:expr) => {
+        $msg.format($crate::messages::lang(), &[])
+    };
+    ($msg:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $msg.format(
+            $crate::messages::lang(),
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
+
+/// Catalogue français (langue par défaut).
+fn fr(msg: Msg) -> &'static str {
+    match msg {
+        Msg::GameStart => "##### Démarrage de la partie #####",
+        Msg::RoundStart => "## Manche {round} ##",
+        Msg::TurnOf => "Au tour de {player}",
+        Msg::TurnOfFatigued => "Au tour de {player} (fatigue -{fatigue})",
+        Msg::Objectives => "→ Objectifs : {objectives}",
+        Msg::PressEnterTurn => "→ Appuyer sur ENTREE pour démarrer le tour..",
+        Msg::TurnCountdown => "→ Le tour de {player} commence dans {seconds}..",
+        Msg::PressEnterRound => "→ {player}, appuyer sur ENTREE pour passer à la manche {round}..",
+        Msg::TurnEnd => "# Fin du tour #",
+        Msg::AverageScore => "→ Score moyen: {score}",
+        Msg::AssistedTag => "(assisté)",
+        Msg::NotEnoughPlayers => "Nombre de joueurs insuffisant pour déterminer un vainqueur.",
+        Msg::ScoreTie => "Égalité de scores, aucune pénalité.",
+        Msg::TiebreakerNext => {
+            "{draws} manches nulles de suite : la manche suivante est décisive et se joue sur un seul objectif."
+        }
+        Msg::DuelObjective => "⚔ Objectif {number}/{total} : {objective}",
+        Msg::PressEnterDuel => "→ {player}, appuyer sur ENTREE pour viser {objective}..",
+        Msg::DuelPointWon => "Point pour {player} ({points}).",
+        Msg::DuelPointTied => "Scores égaux, aucun point ({points}).",
+        Msg::PressEnterShared => {
+            "→ {first} verrouille avec {first_key}, {second} avec {second_key}. Appuyer sur ENTREE pour lancer le compteur.."
+        }
+        Msg::ArmorDetail => " ({raw} - {absorbed} armure)",
+        Msg::RoundWon => {
+            "{winner} gagne la manche. {loser} perd {damage} points de vitalité{detail}."
+        }
+        Msg::TimeTiebreak => "Égalité de scores, départagée au temps.",
+        Msg::ChoosePoison => "{winner} vous devez choisir quel poison appliquer à {loser} :",
+        Msg::PoisonChoiceRetry => "Choix invalide, veuillez choisir un poison entre 1 et {max}.",
+        Msg::PoisonApplied => "Poison appliqué à {player} : {poison}.",
+        Msg::PoisonCountered => {
+            "{player} contre le poison et perd {damage} points de vitalité supplémentaires (vitalité restante : {vitality})."
+        }
+        Msg::PoisonDelayed => {
+            "Le poison choisi pour {player} ({poison}) fera effet au début de la manche suivante."
+        }
+        Msg::PoisonWarning => "⚠ {player} : le poison de {field} fera effet ce tour.",
+        Msg::WinnersCurse => {
+            "😬 Excès de confiance : {player} l'emporte de {margin} points et perd {speed} points de \
+             vitesse pendant la manche suivante."
+        }
+        Msg::CoinBalances => "Pièces : {balances}",
+        Msg::ShopMenu => "{player}, boutique ({coins} pièces) :",
+        Msg::ShopItem => "→ {number}: {item} ({effect}) — {price} pièces",
+        Msg::ShopSkip => "→ {number}: ne rien acheter",
+        Msg::ShopBought => "{player} achète {item} ({effect}), il lui reste {coins} pièces.",
+        Msg::ShopRefused => "{player} ne peut pas acheter {item} : {reason}.",
+        Msg::MirrorNotice => {
+            "🪞 Mode miroir : {first} et {second} échangent leur vitesse et leur force pour la partie."
+        }
+        Msg::RiskMenu => "{player}, choisissez votre prise de risque :",
+        Msg::RiskOption => "→ {number}: {risk} — {objectives} objectifs, moyenne à {percent} %",
+        Msg::BanMenu => "{player}, quel poison interdire pour cette manche ?",
+        Msg::BanOption => "→ {number}: interdire {poison}",
+        Msg::BanNone => "→ {number}: n'interdire aucun poison",
+        Msg::BanNotice => "🚫 {player} interdit le poison {poison} pour cette manche.",
+        Msg::PoisonAllBanned => "Aucun poison ne peut être infligé à {player} cette manche.",
+        Msg::MutatorAnnounce => "🎲 Mutateur de la manche : {name} — {description}",
+        Msg::MutatorFog => "le compteur reste caché jusqu'à son arrêt",
+        Msg::MutatorStorm => "le compteur défile {percent} % plus vite",
+        Msg::MutatorGravity => "les objectifs sont compris entre 0 et {max}",
+        Msg::MutatorJackpot => "les dégâts de la manche sont doublés",
+        Msg::RoundEnd => "## FIN Manche {round} ##",
+        Msg::GameOver => "##### Partie terminée #####",
+        Msg::Winner => "Le vainqueur est {winner} !",
+        Msg::Draw => "Match nul !",
+        Msg::PlayerStats => "Statistiques des joueurs :",
+        Msg::CounterOffer => {
+            "{player}, vous pouvez contrer ce poison ({remaining} contre-proposition restante) :"
+        }
+        Msg::AcceptPoison => "→ 1: accepter le poison ({poison})",
+        Msg::CounterPoison => {
+            "→ 2: contrer et perdre {damage} points de vitalité supplémentaires (vitalité {vitality} → {remaining})"
+        }
+        Msg::PracticeStart => "##### Entraînement #####",
+        Msg::PracticeTry => "## Essai {turn}/{turns} ##",
+        Msg::PressEnterTry => "→ Appuyer sur ENTREE pour démarrer l'essai..",
+        Msg::PracticeInterrupted => "Entraînement interrompu.",
+        Msg::PracticeOver => "##### Entraînement terminé #####",
+        Msg::PracticeSummary => "Meilleur essai : {best} | Moyenne des essais : {average}",
+        Msg::GhostStop => "👻 fantôme : {counter} (diff {diff})",
+        Msg::GhostAverages => "Moyenne des essais : {average} | fantôme : {ghost}",
+        Msg::GhostAhead => "Vous battez le fantôme de {points} points.",
+        Msg::GhostBehind => "Le fantôme vous bat de {points} points.",
+        Msg::GhostTied => "Égalité parfaite avec le fantôme.",
+        Msg::DailyTitle => "##### Défi du jour {date} #####",
+        Msg::DailyAlreadyPlayed => "Défi du jour déjà joué, meilleur score : {best}",
+        Msg::DailyBest => "Meilleur score du jour : {best}",
+        Msg::GameInterrupted => "##### Partie interrompue #####",
+        Msg::PlayerForfeited => "##### {player} a quitté la partie et déclare forfait #####",
+        Msg::RoundsCompleted => "Manches terminées : {rounds}",
+        Msg::TotalScores => "Scores cumulés :",
+        Msg::ObjectiveDetail => {
+            "  ↳ Objectif {objective} : compteur {counter}, écart {gap}, miss {miss}, score {score} ({elapsed} s)"
+        }
+        Msg::ObjectiveRetried => "  ↺ Objectif rejoué : le score {score} est retenu",
+        Msg::RetryMenu => "↺ {player}, rejouer cet objectif ({objective}, score {score}) ? [o/N]",
+        Msg::StalemateOn => {
+            "Anti-stagnation activée : moins de {threshold} points de dégâts en {rounds} manches, \
+             chaque manche perdue coûte désormais au moins {damage} points de vitalité."
+        }
+        Msg::StalemateOff => {
+            "Anti-stagnation levée : la dernière manche a infligé {dealt} points de dégâts."
+        }
+        Msg::CommentaryPerfect1 => {
+            "« Un sans-faute pour {player}, chaque objectif touché en plein cœur ! »"
+        }
+        Msg::CommentaryPerfect2 => {
+            "« Précision d'horloger : {player} n'a pas laissé un seul point d'écart. »"
+        }
+        Msg::CommentaryDisaster1 => {
+            "« {misses} miss pour {player} : le compteur a fait des tours de piste. »"
+        }
+        Msg::CommentaryDisaster2 => "« Manche à oublier pour {player}, trahi par {misses} miss. »",
+        Msg::CommentaryDraw1 => "« Rien ne sépare {first} et {second} : tout est à refaire. »",
+        Msg::CommentaryDraw2 => {
+            "« Match nul entre {first} et {second}, les compteurs se neutralisent. »"
+        }
+        Msg::CommentaryComeback1 => {
+            "« {winner} refuse de céder et reprend du terrain sur {loser} ! »"
+        }
+        Msg::CommentaryComeback2 => {
+            "« Dos au mur, {winner} fait plier {loser}. La remontée est lancée ! »"
+        }
+        Msg::CommentaryBlowout1 => {
+            "« Écrasante victoire de {winner} : {gap} points d'écart, {loser} encaisse {dealt} \
+             dégâts. »"
+        }
+        Msg::CommentaryBlowout2 => {
+            "« Démonstration de {winner}, qui surclasse {loser} de {gap} points. »"
+        }
+        Msg::CommentaryNailBiter1 => {
+            "« Au couteau ! {winner} l'emporte avec {gap} point(s) d'avance. »"
+        }
+        Msg::CommentaryNailBiter2 => {
+            "« Un souffle d'écart : {winner} arrache la manche de {gap} point(s). »"
+        }
+        Msg::CommentaryStandard1 => {
+            "« {winner} prend la manche et inflige {dealt} dégâts à {loser}. »"
+        }
+        Msg::CommentaryStandard2 => {
+            "« Manche maîtrisée par {winner} ; {loser} perd {dealt} points de vitalité. »"
+        }
+        Msg::RefereeLookAway => {
+            "{players} : détournez le regard, {referee} pronostique en secret le vainqueur de la \
+             manche. ENTRÉE pour continuer."
+        }
+        Msg::RefereeMenu => "{referee}, qui remportera la manche ?",
+        Msg::RefereeOption => "→ {number}: {player}",
+        Msg::RefereeAbstain => "→ {number}: s'abstenir",
+        Msg::RefereeRight => {
+            "{referee} avait pronostiqué {player} : pronostic juste ({streak}/{needed} d'affilée)."
+        }
+        Msg::RefereeWrong => "{referee} avait pronostiqué {player} : pronostic manqué.",
+        Msg::RefereeReward => {
+            "{referee} a vu juste {needed} fois d'affilée : quel mutateur imposer à cette manche ?"
+        }
+        Msg::RefereeMutatorOption => "→ {number}: {name} ({description})",
+        Msg::RefereeMutatorNone => "→ {number}: n'imposer aucun mutateur",
+        Msg::CounterStatus => "→ Objectif {objective} : Miss = {miss} | Compteur = {counter}",
+        Msg::CounterStatusStamina => {
+            "→ Objectif {objective} : Miss = {miss} | Compteur = {counter} | Souffle (s) = {stamina}"
+        }
+        Msg::CounterPreview => " | si vous arrêtez ici : {score}",
+        Msg::SharedCounterStatus => {
+            "→ Objectif {objective} : Miss = {miss} | Compteur = {counter} | {first} (a) {first_lock} | \
+             {second} (l) {second_lock}"
+        }
+        Msg::SharedLocked => "verrouillé",
+        Msg::SharedWaiting => "en attente",
+        Msg::SharedNoLock => "{player} n'a pas verrouillé avant {cap} miss : écart maximal.",
+        Msg::EmptyName => "le nom du joueur ne peut pas être vide",
+        Msg::NameTooLong => "nom trop long (« {name} ») : il ne peut pas dépasser {max} caractères",
+        Msg::InvalidVitality => "vitalité invalide ({value}) : elle doit être d'au moins 1",
+        Msg::SpeedOutOfRange => {
+            "vitesse invalide ({value}) : elle doit être comprise entre {min} et {max}"
+        }
+        Msg::StrengthTooHigh => "force invalide ({value}) : elle ne peut pas dépasser {max}",
+        Msg::InvalidAvatar => {
+            "avatar invalide (« {avatar} ») : il doit s'agir d'un emoji ou d'un caractère unique"
+        }
+        Msg::LuckTooHigh => "chance invalide ({value}) : elle ne peut pas dépasser {max}",
+        Msg::ShopNotEnoughCoins => "{price} pièces nécessaires, {coins} disponibles",
+        Msg::ShopNothingToCure => "aucun poison à annuler",
+        Msg::MirrorPlayerCount => "le mode miroir se joue à 2 joueurs ({count} joueur(s) donné(s))",
+        Msg::TooManyObjectives => "{count} objectifs par tour dépassent la limite de {limit}",
+        Msg::DefaultPlayerName => "Joueur {number}",
+        Msg::RulesTitle => "📜 Règles du jeu",
+        Msg::RulesCounter => {
+            "À son tour, chaque joueur arrête un compteur qui défile de 0 à 100 en visant au plus près \
+             chacun de ses objectifs. La vitesse est le délai en millisecondes entre deux \
+             incrémentations : plus elle est élevée, plus le compteur est lent."
+        }
+        Msg::RulesScoring => {
+            "Score d'un objectif selon l'écart entre le compteur et l'objectif (le compteur reboucle de 100 à 0) :"
+        }
+        Msg::RulesTierSingle => "  • écart de {difference} : {base} + force",
+        Msg::RulesTierRange => "  • écart de {low} à {high} : {base} + force",
+        Msg::RulesTierBeyond => "  • écart supérieur à {difference} : force seule",
+        Msg::RulesMiss => {
+            "Chaque « miss » (passage du compteur par 100) divise le score de l'objectif : score / \
+             (miss + 1). Le score du tour est la moyenne de ses objectifs, arrondie au supérieur."
+        }
+        Msg::RulesDamage => {
+            "Le perdant de la manche perd en vitalité l'écart entre les scores moyens, diminué de son \
+             armure (au moins 1 point). En cas d'égalité, personne ne perd de vitalité."
+        }
+        Msg::RulesPoison => {
+            "S'il reste en vie, le perdant est ensuite empoisonné par le gagnant : {amount} points de \
+             vitesse ou de force en moins. Il peut contrer le poison {counters} fois par partie en \
+             subissant à nouveau les dégâts de la manche, sans armure."
+        }
+        Msg::RulesWin => {
+            "La partie se termine lorsqu'un joueur n'a plus de vitalité : son adversaire l'emporte."
+        }
+        Msg::RulesFatigueOn => {
+            "Fatigue : après la manche {start}, chaque manche retire {per_round} points de force \
+             effective à chaque joueur."
+        }
+        Msg::RulesFatigueOff => "Fatigue : désactivée (--fatigue).",
+        Msg::RulesStaminaOn => {
+            "Endurance : chaque joueur dispose de {stamina} points par partie ; la touche {key} en dépense \
+             un pour ralentir le compteur de {slowdown} % pendant un objectif."
+        }
+        Msg::RulesStaminaOff => "Endurance : désactivée (--stamina).",
+        Msg::RulesDuel => {
+            "Mode duel : les joueurs visent tour à tour chaque objectif ; le meilleur score remporte un \
+             point, et le perdant de la manche perd {factor} points de vitalité par point d'écart."
+        }
+        Msg::RulesShared => {
+            "Mode partagé : un seul compteur défile pour les deux joueurs, qui verrouillent chacun leur \
+             valeur une fois par objectif (touches {first_key} et {second_key}) ; sans verrou après {cap} miss, l'écart est \
+             maximal."
+        }
+        Msg::RulesShop => {
+            "Boutique : chaque manche rapporte le score moyen divisé par {divisor} en pièces ; entre \
+             les manches, chaque joueur peut acheter un objet."
+        }
+        Msg::RulesMirror => {
+            "Miroir : avant la première manche, les joueurs échangent leur vitesse et leur force ; la \
+             vitalité ne change pas."
+        }
+        Msg::RulesRisk => {
+            "Prise de risque : avant son tour, chaque joueur choisit 3 objectifs (moyenne × 0,9), 5 \
+             (moyenne inchangée) ou 7 (moyenne × 1,15)."
+        }
+        Msg::RulesPause => {
+            "Pause : pendant le compteur, la touche {key} ouvre un menu pour reprendre, relire les \
+             règles ou abandonner ; le temps passé en pause n'est pas compté."
+        }
+        Msg::RulesMutators => {
+            "Mutateurs : au début de chaque manche, un mutateur tiré au sort s'applique aux deux \
+             joueurs pour la manche : Brouillard (compteur caché), Tempête (compteur plus rapide), \
+             Gravité (objectifs de 0 à 30) ou Pactole (dégâts doublés)."
+        }
+        Msg::RulesBans => {
+            "Interdictions : au début de chaque manche, le joueur qui a le moins de vitalité peut \
+             interdire un poison, que le vainqueur de la manche ne pourra pas choisir."
+        }
+        Msg::RulesRetry => {
+            "Rejeu : le perdant d'une manche gagne un rejeu (un seul en réserve). Après l'arrêt d'un \
+             objectif, il peut le dépenser pour rejouer aussitôt cet objectif : le second arrêt est \
+             retenu, même s'il est moins bon."
+        }
+        Msg::RulesTimeTiebreak => {
+            "Départage au temps : une manche à égalité de scores revient au joueur dont les arrêts \
+             ont été les plus rapides, et le perdant perd {damage} points de vitalité. À durées \
+             égales, la manche reste nulle."
+        }
+        Msg::RulesStalemate => {
+            "Anti-stagnation : si les {rounds} dernières manches ont infligé moins de {threshold} \
+             points de dégâts en tout, chaque manche perdue coûte au moins {damage} points de \
+             vitalité, jusqu'à une manche de {relief} points de dégâts ou plus."
+        }
+        Msg::RulesDelayedPoison => {
+            "Poison différé : le poison choisi par le vainqueur d'une manche ne fait effet qu'au \
+             début de la manche suivante, annoncé à sa victime. Il est perdu si la partie se \
+             termine avant."
+        }
+        Msg::RulesWinnersCurse => {
+            "Malédiction du vainqueur : le joueur qui remporte une manche avec plus de {margin} \
+             points d'écart perd {speed} points de vitesse (compteur plus rapide) pendant la manche \
+             suivante."
+        }
+        Msg::RulesOffer => "Première partie : afficher les règles du jeu ? (o/n)",
+        Msg::PresetsTitle => "Préréglages (--preset) :",
+        Msg::PresetEntry => "  {name} : {description}",
+        Msg::PresetClassic => "règles par défaut",
+        Msg::PresetBlitz => "3 objectifs, compteur rapide (vitesse 50) et fatigue",
+        Msg::PresetMarathon => "8 objectifs et endurance",
+        Msg::PresetParty => "mutateurs et boutique",
+        Msg::UnknownPreset => "préréglage inconnu « {name} » (valeurs possibles : {names})",
+        Msg::ClassBalanced => "Équilibré",
+        Msg::ClassSprinter => "Sprinteur",
+        Msg::ClassBruiser => "Cogneur",
+        Msg::ClassTank => "Tank",
+        Msg::UnknownClass => "classe inconnue « {name} » (valeurs possibles : {names})",
+        Msg::SpecFieldCount => {
+            "format attendu nom:vitalité:vitesse:force ({count} champ(s) trouvé(s) dans « {spec} »)"
+        }
+        Msg::SpecInvalidField => {
+            "champ « {field} » invalide (« {value} ») : nombre entier positif attendu"
+        }
+        Msg::SpecEmptyName => "champ « nom » vide",
+        Msg::FieldVitality => "vitalité",
+        Msg::FieldSpeed => "vitesse",
+        Msg::FieldStrength => "force",
+        Msg::DuplicateName => {
+            "Note : plusieurs joueurs s'appellent « {name} », un numéro est ajouté à leur nom."
+        }
+        Msg::SetupInterrupted => "configuration interrompue",
+        Msg::SetupTitle => {
+            "Configuration de la partie (Entrée pour garder la valeur entre crochets)"
+        }
+        Msg::SetupInvalidInput => "  Saisie invalide : {error}",
+        Msg::SetupYesNoError => "répondez o (oui) ou n (non)",
+        Msg::SetupYes => "oui",
+        Msg::SetupNo => "non",
+        Msg::SetupYesKey => "o",
+        Msg::SetupNoKey => "n",
+        Msg::SetupPlayerName => "Nom du joueur {number}",
+        Msg::SetupPlayerClass => "Classe de {name} ({classes})",
+        Msg::SetupNoClass => "aucune",
+        Msg::SetupVitality => "Vitalité initiale des joueurs sans classe",
+        Msg::SetupPositiveNumber => "entrez un nombre entier d'au moins 1",
+        Msg::SetupObjectives => "Nombre d'objectifs par tour",
+        Msg::SetupFatigue => "Activer la fatigue (o/n)",
+        Msg::SetupStamina => "Activer l'endurance (o/n)",
+        Msg::SetupSummary => "\nConfiguration choisie :",
+        Msg::SetupSummaryPlayer => "  Joueur {number} : {player}",
+        Msg::SetupSummaryRules => {
+            "  Objectifs par tour : {objectifs} | Fatigue : {fatigue} | Endurance : {stamina}"
+        }
+        Msg::ConfigError => "Erreur de configuration : {error}",
+        Msg::ConfigWritten => "Fichier de configuration écrit : {path}",
+        Msg::BundleWritten => "Rapport de bogue écrit : {path} ({count} saisies)",
+        Msg::UnknownConfigKey => "Avertissement : clé inconnue « {key} » ignorée dans {path}",
+        Msg::KeyBindSyntax => {
+            "liaison de touche invalide « {spec} » (format action=touche, par exemple stop=space)"
+        }
+        Msg::KeyUnknownAction => "action inconnue « {action} » (actions : {actions})",
+        Msg::KeyInvalid => "touche invalide « {key} » (un caractère, enter ou space)",
+        Msg::KeyConflict => "touche « {key} » attribuée à plusieurs actions : {actions}",
+        Msg::ReplaySaveFailed => "Impossible d'enregistrer la rediffusion : {error}",
+        Msg::NotifyFailed => "Avertissement : impossible de notifier le résultat à {url} : {error}",
+        Msg::ProfileSaveFailed => "Impossible d'enregistrer le profil : {error}",
+        Msg::HardcoreWarning => {
+            "☠ {player} joue en mode hardcore : une défaite effacera son niveau {level}, ses {xp} XP et ses {achievements} succès."
+        }
+        Msg::HardcoreLoss => {
+            "☠ {player} a perdu en mode hardcore. Progression effacée : niveau {level}, {xp} XP, succès : {achievements}."
+        }
+        Msg::HardcoreConfirm => {
+            "Confirmer l'effacement ? Sinon, le profil quitte le mode hardcore et conserve sa progression. [o/N] "
+        }
+        Msg::HardcoreReset => "Progression de {player} effacée.",
+        Msg::HardcoreKept => "{player} quitte le mode hardcore et conserve sa progression.",
+        Msg::HardcoreEnabled => {
+            "Le profil {profile} est désormais en mode hardcore : une défaite effacera son expérience, son niveau et ses succès."
+        }
+        Msg::HardcoreDisabled => "Le profil {profile} n'est plus en mode hardcore.",
+        Msg::XpLevelUp => {
+            "{player} gagne {xp} XP — niveau {level}, +{strength} force au prochain match"
+        }
+        Msg::XpProgress => "{player} gagne {xp} XP — niveau {level} ({progress}/{needed} XP)",
+        Msg::AchievementUnlocked => "🏆 {player} débloque le succès « {name} »",
+        Msg::RematchPrompt => "🔄 Que souhaitez-vous faire ?",
+        Msg::RematchSame => "→ 1: revanche avec les mêmes réglages",
+        Msg::RematchSwap => "→ 2: revanche en échangeant les places",
+        Msg::RematchEdit => "→ 3: modifier les réglages",
+        Msg::RematchStats => "→ 4: afficher le bilan de la session",
+        Msg::RematchQuit => "→ 5: quitter",
+        Msg::RematchInvalid => "Entrée invalide, veuillez entrer un nombre entre 1 et 5.",
+        Msg::ChoiceInvalid => "Entrée invalide, veuillez entrer un nombre entre 1 et {max}.",
+        Msg::SessionScore => "Score de la soirée — {scores}",
+        Msg::SessionDraw => "{count} nul",
+        Msg::SessionDraws => "{count} nuls",
+        Msg::SessionForfeit => "{count} abandon",
+        Msg::SessionForfeits => "{count} abandons",
+        Msg::SeriesStart => "{player} commence avec {vitality} vitalité (+{bonus} report)",
+        Msg::SeriesScore => "Série au meilleur des {best_of} — {score}",
+        Msg::SeriesGameWon => "Partie {number} : {winner}",
+        Msg::SeriesGameDrawn => "Partie {number} : match nul",
+        Msg::SeriesCarriedOver => "{player} +{bonus} report",
+        Msg::SeriesWinner => "🏆 Vainqueur de la série : {winner}",
+        Msg::SaveResumed => "💾 Reprise de la sauvegarde « {slot} » à la manche {round}.",
+        Msg::SaveSlot => "{slot} — manche {round} — {players} — {time}",
+        Msg::SavesEmpty => "Aucune sauvegarde.",
+        Msg::DemoStart => {
+            "🎬 Démonstration : {player1} contre {player2}. Appuyez sur une touche pour quitter."
+        }
+        Msg::DemoNextGame => "Prochaine partie de démonstration…",
+        Msg::EnvInvalidFlag => {
+            "variable {name} invalide : « {value} » (valeurs possibles : 1, 0, true, false, oui, non)"
+        }
+        Msg::NetWaiting => "En attente d'un adversaire sur le port {port}…",
+        Msg::NetConnected => "{player} a rejoint la partie depuis {address}.",
+        Msg::NetJoined => "Connecté à la partie de {player}.",
+        Msg::NetDisconnected => "connexion perdue avec l'autre joueur",
+        Msg::NetIo => "erreur réseau : {error}",
+        Msg::NetTooLarge => "message réseau trop long ({len} octets)",
+        Msg::NetInvalidMessage => "message réseau invalide : {error}",
+        Msg::NetUnexpected => "message réseau inattendu : {message}",
+        Msg::NetIncompatible => {
+            "versions du protocole incompatibles : le client prend en charge les versions {min} à {max}, l'hôte la version {version}"
+        }
+        Msg::NetRejected => "connexion refusée par l'hôte : {reason}",
+        Msg::WsListening => {
+            "Serveur WebSocket à l'écoute sur le port {port} (Ctrl+C pour arrêter)."
+        }
+        Msg::ProtocolUnknownCommand => "commande inconnue : {command}",
+        Msg::ProtocolMissingArgument => "argument manquant pour {command}",
+        Msg::ProtocolInvalidArgument => "argument invalide pour {command} : {value}",
+        Msg::ProtocolExtraArgument => "trop d'arguments pour {command}",
+        Msg::ProtocolUnexpected => "commande {command} inattendue, {expected} attendu",
+        Msg::TuiTooSmall => {
+            "Terminal trop petit : agrandissez-le à au moins {width} × {height} caractères."
+        }
+        Msg::TuiLogTitle => "Déroulement",
+        Msg::TuiPoisonTitle => "Poison à appliquer à {opponent}",
+        Msg::TuiWinnersCurse => "maudit : -{speed} vitesse",
+        Msg::TuiStopHint => "ENTREE ou ESPACE : arrêter le compteur · Ctrl+C : interrompre",
+        Msg::TuiBreathHint => " · s : reprendre son souffle ({stamina})",
+        Msg::TuiChoiceHint => "1 à {max} : choisir · Ctrl+C : interrompre",
+        Msg::TuiContinueHint => "ENTREE : continuer · Ctrl+C : interrompre",
+        Msg::PoisonedStat => "{field} de {player} : {value}",
+        Msg::StatsTitle => "Analyse de la partie :",
+        Msg::StatsPrecision => {
+            "→ {player} : écart moyen {diff}, {perfect} arrêt(s) parfait(s), {misses} dépassement(s)"
+        }
+        Msg::StatsObjectives => {
+            "  meilleur objectif : {best} (score {best_score}), pire : {worst} (score {worst_score})"
+        }
+        Msg::StatsDamage => "  dégâts infligés : {dealt}, subis : {taken}",
+        Msg::StatsDraws => "  manches nulles : {draws}",
+        Msg::StatsRounds => "  score moyen par manche : {sparkline} ({scores})",
+        Msg::StatsErrors => {
+            "  arrêts trop tôt : {early}, pile : {exact}, trop tard : {late} (écart signé) :"
+        }
+        Msg::StatsHighlight => {
+            "  objectif phare : manche {round}, objectif {objective} arrêté sur {counter}, score \
+             {score} (rediffusion : tour {turn}, objectif {index})"
+        }
+        Msg::StatsMvp => {
+            "★ Objectif MVP : {player}, manche {round}, objectif {objective} arrêté sur {counter}, \
+             score {score} (rediffusion : tour {turn}, objectif {index})"
+        }
+        Msg::ExportSaveFailed => "Impossible d'exporter le résultat de la partie : {error}",
+        Msg::SimulationTitle => "Simulation de {games} parties",
+        Msg::SimulationWins => "  {player} : {wins} victoires ({rate} %)",
+        Msg::SimulationDraws => "  Matchs nuls : {draws}",
+        Msg::SimulationLength => "  Durée moyenne : {rounds} manches",
+        Msg::BalanceTitle => {
+            "Équilibrage : {games} parties par configuration contre {player} (vitesse {speed}, force \
+             {strength})"
+        }
+        Msg::BalanceColumns => "Vitesse  Force  Victoires  Durée moyenne",
+        Msg::BalanceRow => "{speed}  {strength}  {rate} %  {rounds} manches",
+        Msg::BalanceProgress => "Équilibrage : {percent} %",
+        Msg::BalanceSaveFailed => "Impossible d'enregistrer le rapport d'équilibrage : {error}",
+        Msg::ReplaySkipped => "Rediffusion ignorée : {error}",
+        Msg::ReplayDiverged => {
+            "La rediffusion (version {version}) ne concorde pas avec son barème et ses règles \
+             ({divergence}) : relancez avec --force pour la relire tout de même"
+        }
+        Msg::ReplayForced => "Rediffusion relue malgré une divergence ({divergence})",
+        Msg::ScoreboardTitle => "Tableau des scores (manche {round})",
+        Msg::ScoreboardRecord => "victoires : {wins}  moyenne : {average}",
+        Msg::ScoreboardRow => "{player}  {record}  vitalité : {vitality}/{max}",
+        Msg::CounterStarted => "Le compteur démarre pour l'objectif {objective}.",
+        Msg::CounterValue => "Compteur à {counter}.",
+        Msg::CounterHotZone => "Zone chaude : compteur à {counter}, objectif {objective}.",
+        Msg::CounterStopped => {
+            "Compteur arrêté à {counter} pour l'objectif {objective}, après {miss} dépassement(s)."
+        }
+        Msg::CounterPaused => {
+            "⏸ Pause — 1 : reprendre · 2 : afficher les règles · 3 : abandonner ({key} : reprendre)"
+        }
+        Msg::CounterResumed => "▶ Reprise du compteur.",
+        Msg::SpokenGameStart => "La partie commence.",
+        Msg::SpokenPlayer => {
+            "{player} a {vitality} points de vitalité sur {max}, une vitesse de {speed}, une force de {strength} et une armure de {armor}."
+        }
+        Msg::SpokenRoundStart => "La manche {round} commence.",
+        Msg::SpokenVitality => "{player} a {vitality} points de vitalité sur {max}.",
+        Msg::SpokenTurn => "C'est au tour de {player}.",
+        Msg::SpokenFatigue => "La fatigue réduit la force de {player} de {fatigue}.",
+        Msg::SpokenObjectives => "Objectifs de ce tour : {objectives}.",
+        Msg::SpokenStop => {
+            "Objectif {objective} : compteur arrêté à {counter}, écart de {gap}, {miss} dépassement(s), score de {score}."
+        }
+        Msg::SpokenScore => "Objectif {objective} : écart de {gap}, score de {score}.",
+        Msg::SpokenTurnEnd => "Fin du tour de {player}, avec un score moyen de {score}.",
+        Msg::SpokenOption => "Tapez {number} pour {option}.",
+        Msg::SpokenAccept => "accepter le poison, {poison}",
+        Msg::SpokenPoison => "une perte de {amount} points de {field}",
+        Msg::SpokenCounter => {
+            "contrer et perdre {damage} points de vitalité supplémentaires, pour en garder {remaining}"
+        }
+        Msg::SpokenRoundEnd => "La manche {round} est terminée.",
+        Msg::SpokenGameOver => "La partie est terminée.",
+        Msg::SpokenInterrupted => {
+            "La partie a été interrompue après {rounds} manche(s) terminée(s)."
+        }
+        Msg::SpokenForfeit => "{player} a quitté la partie et déclare forfait.",
+        Msg::SpokenTotal => "Score cumulé de {player} : {total}.",
+        Msg::SpokenStats => {
+            "{player} : écart moyen de {diff}, {perfect} arrêt(s) parfait(s), {misses} dépassement(s), {dealt} points de dégâts infligés et {taken} subis."
+        }
+        Msg::SpokenRounds => "Scores moyens de {player} par manche : {scores}.",
+        Msg::SpokenErrors => {
+            "{player} a arrêté le compteur {early} fois trop tôt, {exact} fois pile et {late} fois trop tard."
+        }
+        Msg::SpokenDraws => "{player} a fait match nul dans {draws} manche(s).",
+        Msg::SpokenHighlight => {
+            "Objectif phare de {player} : manche {round}, objectif {objective}, arrêt sur \
+             {counter}, score {score}."
+        }
+        Msg::SpokenMvp => {
+            "Objectif MVP de la partie : {player}, manche {round}, objectif {objective}, arrêt sur \
+             {counter}, score {score}."
+        }
+        Msg::Cancelled => "partie interrompue",
+        Msg::InterruptRequested => {
+            "Interruption demandée : la partie s'arrête et affiche son bilan (Ctrl+C à nouveau pour quitter immédiatement)."
+        }
+        Msg::InterruptUnavailable => "Avertissement : impossible d'intercepter Ctrl+C : {error}",
+        Msg::Error => "Erreur : {error}",
+    }
+}
+
+/// Catalogue anglais.
+fn en(msg: Msg) -> &'static str {
+    match msg {
+        Msg::GameStart => "##### Game start #####",
+        Msg::RoundStart => "## Round {round} ##",
+        Msg::TurnOf => "{player}'s turn",
+        Msg::TurnOfFatigued => "{player}'s turn (fatigue -{fatigue})",
+        Msg::Objectives => "→ Objectives: {objectives}",
+        Msg::PressEnterTurn => "→ Press ENTER to start the turn..",
+        Msg::TurnCountdown => "→ {player}'s turn starts in {seconds}..",
+        Msg::PressEnterRound => "→ {player}, press ENTER to go on to round {round}..",
+        Msg::TurnEnd => "# End of turn #",
+        Msg::AverageScore => "→ Average score: {score}",
+        Msg::AssistedTag => "(assisted)",
+        Msg::NotEnoughPlayers => "Not enough players to determine a winner.",
+        Msg::ScoreTie => "Tied scores, no penalty.",
+        Msg::TiebreakerNext => {
+            "{draws} drawn rounds in a row: the next round is a tiebreaker played on a single objective."
+        }
+        Msg::DuelObjective => "⚔ Objective {number}/{total}: {objective}",
+        Msg::PressEnterDuel => "→ {player}, press ENTER to aim at {objective}..",
+        Msg::DuelPointWon => "Point for {player} ({points}).",
+        Msg::DuelPointTied => "Tied scores, no point ({points}).",
+        Msg::PressEnterShared => {
+            "→ {first} locks with {first_key}, {second} with {second_key}. Press ENTER to start the counter.."
+        }
+        Msg::ArmorDetail => " ({raw} - {absorbed} armor)",
+        Msg::RoundWon => "{winner} wins the round. {loser} loses {damage} vitality points{detail}.",
+        Msg::TimeTiebreak => "Tied scores, broken on time.",
+        Msg::ChoosePoison => "{winner}, choose which poison to apply to {loser}:",
+        Msg::PoisonChoiceRetry => "Invalid choice, please choose a poison between 1 and {max}.",
+        Msg::PoisonApplied => "Poison applied to {player}: {poison}.",
+        Msg::PoisonCountered => {
+            "{player} counters the poison and loses {damage} extra vitality points (remaining vitality: {vitality})."
+        }
+        Msg::PoisonDelayed => {
+            "The poison chosen for {player} ({poison}) takes effect at the start of the next round."
+        }
+        Msg::PoisonWarning => "⚠ {player}: the {field} poison takes effect this round.",
+        Msg::WinnersCurse => {
+            "😬 Overconfidence: {player} wins by {margin} points and loses {speed} speed points \
+             during the next round."
+        }
+        Msg::CoinBalances => "Coins: {balances}",
+        Msg::ShopMenu => "{player}, shop ({coins} coins):",
+        Msg::ShopItem => "→ {number}: {item} ({effect}) — {price} coins",
+        Msg::ShopSkip => "→ {number}: buy nothing",
+        Msg::ShopBought => "{player} buys {item} ({effect}), {coins} coins left.",
+        Msg::ShopRefused => "{player} cannot buy {item}: {reason}.",
+        Msg::MirrorNotice => {
+            "🪞 Mirror mode: {first} and {second} swap their speed and strength for this game."
+        }
+        Msg::RiskMenu => "{player}, choose your risk:",
+        Msg::RiskOption => "→ {number}: {risk} — {objectives} objectives, average at {percent}%",
+        Msg::BanMenu => "{player}, which poison do you ban for this round?",
+        Msg::BanOption => "→ {number}: ban {poison}",
+        Msg::BanNone => "→ {number}: ban no poison",
+        Msg::BanNotice => "🚫 {player} bans the {poison} poison for this round.",
+        Msg::PoisonAllBanned => "No poison can be inflicted on {player} this round.",
+        Msg::MutatorAnnounce => "🎲 Round mutator: {name} — {description}",
+        Msg::MutatorFog => "the counter stays hidden until it stops",
+        Msg::MutatorStorm => "the counter runs {percent}% faster",
+        Msg::MutatorGravity => "objectives range from 0 to {max}",
+        Msg::MutatorJackpot => "the round's damage is doubled",
+        Msg::RoundEnd => "## END of round {round} ##",
+        Msg::GameOver => "##### Game over #####",
+        Msg::Winner => "The winner is {winner}!",
+        Msg::Draw => "Draw!",
+        Msg::PlayerStats => "Player statistics:",
+        Msg::CounterOffer => {
+            "{player}, you can counter this poison ({remaining} counter remaining):"
+        }
+        Msg::AcceptPoison => "→ 1: accept the poison ({poison})",
+        Msg::CounterPoison => {
+            "→ 2: counter and lose {damage} extra vitality points (vitality {vitality} → {remaining})"
+        }
+        Msg::PracticeStart => "##### Practice #####",
+        Msg::PracticeTry => "## Try {turn}/{turns} ##",
+        Msg::PressEnterTry => "→ Press ENTER to start the try..",
+        Msg::PracticeInterrupted => "Practice interrupted.",
+        Msg::PracticeOver => "##### Practice over #####",
+        Msg::PracticeSummary => "Best try: {best} | Average of tries: {average}",
+        Msg::GhostStop => "👻 ghost: {counter} (diff {diff})",
+        Msg::GhostAverages => "Average of tries: {average} | ghost: {ghost}",
+        Msg::GhostAhead => "You beat the ghost by {points} points.",
+        Msg::GhostBehind => "The ghost beats you by {points} points.",
+        Msg::GhostTied => "Dead heat with the ghost.",
+        Msg::DailyTitle => "##### Daily challenge {date} #####",
+        Msg::DailyAlreadyPlayed => "Daily challenge already played, best score: {best}",
+        Msg::DailyBest => "Best score of the day: {best}",
+        Msg::GameInterrupted => "##### Game interrupted #####",
+        Msg::PlayerForfeited => "##### {player} left the game and forfeits #####",
+        Msg::RoundsCompleted => "Rounds completed: {rounds}",
+        Msg::TotalScores => "Total scores:",
+        Msg::ObjectiveDetail => {
+            "  ↳ Objective {objective}: counter {counter}, gap {gap}, miss {miss}, score {score} ({elapsed} s)"
+        }
+        Msg::ObjectiveRetried => "  ↺ Objective replayed: score {score} is kept",
+        Msg::RetryMenu => "↺ {player}, replay this objective ({objective}, score {score})? [y/N]",
+        Msg::StalemateOn => {
+            "Anti-stalling on: less than {threshold} damage in {rounds} rounds, each lost round now \
+             costs at least {damage} vitality points."
+        }
+        Msg::StalemateOff => "Anti-stalling off: the last round dealt {dealt} damage.",
+        Msg::CommentaryPerfect1 => {
+            "“A flawless turn from {player}, every objective hit dead center!”"
+        }
+        Msg::CommentaryPerfect2 => {
+            "“Clockwork precision: {player} did not miss by a single point.”"
+        }
+        Msg::CommentaryDisaster1 => {
+            "“{misses} misses for {player}: the counter went for a few laps.”"
+        }
+        Msg::CommentaryDisaster2 => "“A round to forget for {player}, undone by {misses} misses.”",
+        Msg::CommentaryDraw1 => "“Nothing separates {first} and {second}: back to square one.”",
+        Msg::CommentaryDraw2 => "“A draw between {first} and {second}, the counters cancel out.”",
+        Msg::CommentaryComeback1 => "“{winner} refuses to give in and claws back against {loser}!”",
+        Msg::CommentaryComeback2 => {
+            "“Back to the wall, {winner} beats {loser}. The comeback is on!”"
+        }
+        Msg::CommentaryBlowout1 => {
+            "“A crushing win for {winner}: {gap} points ahead, {loser} takes {dealt} damage.”"
+        }
+        Msg::CommentaryBlowout2 => {
+            "“A masterclass from {winner}, outscoring {loser} by {gap} points.”"
+        }
+        Msg::CommentaryNailBiter1 => "“Razor-thin! {winner} wins by {gap} point(s).”",
+        Msg::CommentaryNailBiter2 => {
+            "“By a whisker: {winner} snatches the round by {gap} point(s).”"
+        }
+        Msg::CommentaryStandard1 => {
+            "“{winner} takes the round and deals {dealt} damage to {loser}.”"
+        }
+        Msg::CommentaryStandard2 => {
+            "“A controlled round from {winner}; {loser} loses {dealt} vitality.”"
+        }
+        Msg::RefereeLookAway => {
+            "{players}: look away, {referee} secretly picks the winner of the round. Press ENTER to \
+             continue."
+        }
+        Msg::RefereeMenu => "{referee}, who will win the round?",
+        Msg::RefereeOption => "→ {number}: {player}",
+        Msg::RefereeAbstain => "→ {number}: abstain",
+        Msg::RefereeRight => "{referee} picked {player}: right call ({streak}/{needed} in a row).",
+        Msg::RefereeWrong => "{referee} picked {player}: wrong call.",
+        Msg::RefereeReward => {
+            "{referee} called it right {needed} times in a row: which mutator for this round?"
+        }
+        Msg::RefereeMutatorOption => "→ {number}: {name} ({description})",
+        Msg::RefereeMutatorNone => "→ {number}: impose no mutator",
+        Msg::CounterStatus => "→ Objective {objective}: Miss = {miss} | Counter = {counter}",
+        Msg::CounterStatusStamina => {
+            "→ Objective {objective}: Miss = {miss} | Counter = {counter} | Breath (s) = {stamina}"
+        }
+        Msg::CounterPreview => " | if you stop here: {score}",
+        Msg::SharedCounterStatus => {
+            "→ Objective {objective}: Miss = {miss} | Counter = {counter} | {first} (a) {first_lock} | \
+             {second} (l) {second_lock}"
+        }
+        Msg::SharedLocked => "locked",
+        Msg::SharedWaiting => "waiting",
+        Msg::SharedNoLock => "{player} did not lock before {cap} misses: maximum difference.",
+        Msg::EmptyName => "the player name cannot be empty",
+        Msg::NameTooLong => "name too long (\"{name}\"): it cannot exceed {max} characters",
+        Msg::InvalidVitality => "invalid vitality ({value}): it must be at least 1",
+        Msg::SpeedOutOfRange => "invalid speed ({value}): it must be between {min} and {max}",
+        Msg::StrengthTooHigh => "invalid strength ({value}): it cannot exceed {max}",
+        Msg::InvalidAvatar => {
+            "invalid avatar (\"{avatar}\"): it must be an emoji or a single character"
+        }
+        Msg::LuckTooHigh => "invalid luck ({value}): it cannot exceed {max}",
+        Msg::ShopNotEnoughCoins => "{price} coins needed, {coins} available",
+        Msg::ShopNothingToCure => "no poison to cure",
+        Msg::MirrorPlayerCount => "mirror mode needs 2 players ({count} player(s) given)",
+        Msg::TooManyObjectives => "{count} objectives per turn exceed the limit of {limit}",
+        Msg::DefaultPlayerName => "Player {number}",
+        Msg::RulesTitle => "📜 Game rules",
+        Msg::RulesCounter => {
+            "On their turn, each player stops a counter running from 0 to 100, aiming as close as \
+             possible to each of their objectives. Speed is the delay in milliseconds between two \
+             increments: the higher it is, the slower the counter."
+        }
+        Msg::RulesScoring => {
+            "Score of an objective by gap between the counter and the objective (the counter wraps from 100 to 0):"
+        }
+        Msg::RulesTierSingle => "  • gap of {difference}: {base} + strength",
+        Msg::RulesTierRange => "  • gap of {low} to {high}: {base} + strength",
+        Msg::RulesTierBeyond => "  • gap above {difference}: strength only",
+        Msg::RulesMiss => {
+            "Each \"miss\" (the counter passing 100) divides the objective's score: score / \
+             (miss + 1). The turn score is the average of its objectives, rounded up."
+        }
+        Msg::RulesDamage => {
+            "The loser of the round loses vitality equal to the gap between average scores, minus \
+             their armor (at least 1 point). On a tie, nobody loses vitality."
+        }
+        Msg::RulesPoison => {
+            "If still alive, the loser is then poisoned by the winner: {amount} points of speed or \
+             strength lost. They may counter the poison {counters} time(s) per game by taking the \
+             round's damage again, ignoring armor."
+        }
+        Msg::RulesWin => "The game ends when a player has no vitality left: their opponent wins.",
+        Msg::RulesFatigueOn => {
+            "Fatigue: after round {start}, each round removes {per_round} points of effective \
+             strength from each player."
+        }
+        Msg::RulesFatigueOff => "Fatigue: disabled (--fatigue).",
+        Msg::RulesStaminaOn => {
+            "Stamina: each player has {stamina} points per game; the {key} key spends one to slow the \
+             counter by {slowdown}% during an objective."
+        }
+        Msg::RulesStaminaOff => "Stamina: disabled (--stamina).",
+        Msg::RulesDuel => {
+            "Duel mode: players take turns aiming at each objective; the best score wins a point, and \
+             the round loser loses {factor} vitality points per point of difference."
+        }
+        Msg::RulesShared => {
+            "Shared mode: a single counter runs for both players, who each lock their value once per \
+             objective (keys {first_key} and {second_key}); without a lock after {cap} misses, the difference is maximal."
+        }
+        Msg::RulesShop => {
+            "Shop: each round earns the average score divided by {divisor} in coins; between rounds, \
+             each player may buy one item."
+        }
+        Msg::RulesMirror => {
+            "Mirror: before the first round, the players swap their speed and strength; vitality is \
+             unchanged."
+        }
+        Msg::RulesRisk => {
+            "Risk choice: before their turn, each player picks 3 objectives (average × 0.9), 5 \
+             (average unchanged) or 7 (average × 1.15)."
+        }
+        Msg::RulesPause => {
+            "Pause: during the counter, the {key} key opens a menu to resume, read the rules again \
+             or forfeit; time spent paused is not counted."
+        }
+        Msg::RulesMutators => {
+            "Mutators: at the start of each round, a mutator drawn at random applies to both players \
+             for the round: Brouillard (hidden counter), Tempête (faster counter), Gravité \
+             (objectives from 0 to 30) or Pactole (doubled damage)."
+        }
+        Msg::RulesBans => {
+            "Bans: at the start of each round, the player with less vitality may ban one poison, \
+             which the winner of the round cannot choose."
+        }
+        Msg::RulesRetry => {
+            "Retry: the loser of a round earns a retry (at most one banked). After stopping on an \
+             objective, they may spend it to replay that objective at once: the second stop is kept, \
+             even if it is worse."
+        }
+        Msg::RulesTimeTiebreak => {
+            "Time tiebreak: a round with tied scores goes to the player whose stops were the \
+             fastest, and the loser loses {damage} vitality points. With equal times, the round \
+             stays a draw."
+        }
+        Msg::RulesStalemate => {
+            "Anti-stalling: if the last {rounds} rounds dealt less than {threshold} damage in \
+             total, each lost round costs at least {damage} vitality points, until a round deals \
+             {relief} damage or more."
+        }
+        Msg::RulesDelayedPoison => {
+            "Delayed poison: the poison chosen by a round's winner only takes effect at the start \
+             of the next round, announced to its victim. It is discarded if the game ends first."
+        }
+        Msg::RulesWinnersCurse => {
+            "Winner's curse: a player who wins a round by more than {margin} points loses {speed} \
+             speed points (faster counter) during the next round."
+        }
+        Msg::RulesOffer => "First game: show the game rules? (y/n)",
+        Msg::PresetsTitle => "Presets (--preset):",
+        Msg::PresetEntry => "  {name}: {description}",
+        Msg::PresetClassic => "default rules",
+        Msg::PresetBlitz => "3 objectives, fast counter (speed 50) and fatigue",
+        Msg::PresetMarathon => "8 objectives and stamina",
+        Msg::PresetParty => "mutators and shop",
+        Msg::UnknownPreset => "unknown preset \"{name}\" (possible values: {names})",
+        Msg::ClassBalanced => "Balanced",
+        Msg::ClassSprinter => "Sprinter",
+        Msg::ClassBruiser => "Bruiser",
+        Msg::ClassTank => "Tank",
+        Msg::UnknownClass => "unknown class \"{name}\" (possible values: {names})",
+        Msg::SpecFieldCount => {
+            "expected format name:vitality:speed:strength ({count} field(s) found in \"{spec}\")"
+        }
+        Msg::SpecInvalidField => {
+            "invalid \"{field}\" field (\"{value}\"): positive integer expected"
+        }
+        Msg::SpecEmptyName => "empty \"name\" field",
+        Msg::FieldVitality => "vitality",
+        Msg::FieldSpeed => "speed",
+        Msg::FieldStrength => "strength",
+        Msg::DuplicateName => {
+            "Note: several players are named \"{name}\", a number is appended to their name."
+        }
+        Msg::SetupInterrupted => "setup interrupted",
+        Msg::SetupTitle => "Game setup (press Enter to keep the value in brackets)",
+        Msg::SetupInvalidInput => "  Invalid input: {error}",
+        Msg::SetupYesNoError => "answer y (yes) or n (no)",
+        Msg::SetupYes => "yes",
+        Msg::SetupNo => "no",
+        Msg::SetupYesKey => "y",
+        Msg::SetupNoKey => "n",
+        Msg::SetupPlayerName => "Player {number} name",
+        Msg::SetupPlayerClass => "Class of {name} ({classes})",
+        Msg::SetupNoClass => "none",
+        Msg::SetupVitality => "Starting vitality of players without a class",
+        Msg::SetupPositiveNumber => "enter a whole number of at least 1",
+        Msg::SetupObjectives => "Objectives per turn",
+        Msg::SetupFatigue => "Enable fatigue (y/n)",
+        Msg::SetupStamina => "Enable stamina (y/n)",
+        Msg::SetupSummary => "\nChosen setup:",
+        Msg::SetupSummaryPlayer => "  Player {number}: {player}",
+        Msg::SetupSummaryRules => {
+            "  Objectives per turn: {objectifs} | Fatigue: {fatigue} | Stamina: {stamina}"
+        }
+        Msg::ConfigError => "Configuration error: {error}",
+        Msg::ConfigWritten => "Configuration file written: {path}",
+        Msg::BundleWritten => "Bug report written: {path} ({count} inputs)",
+        Msg::UnknownConfigKey => "Warning: unknown key \"{key}\" ignored in {path}",
+        Msg::KeyBindSyntax => "invalid key binding \"{spec}\" (format action=key, e.g. stop=space)",
+        Msg::KeyUnknownAction => "unknown action \"{action}\" (actions: {actions})",
+        Msg::KeyInvalid => "invalid key \"{key}\" (a single character, enter or space)",
+        Msg::KeyConflict => "key \"{key}\" bound to several actions: {actions}",
+        Msg::ReplaySaveFailed => "Could not save the replay: {error}",
+        Msg::NotifyFailed => "Warning: could not send the result to {url}: {error}",
+        Msg::ProfileSaveFailed => "Could not save the profile: {error}",
+        Msg::HardcoreWarning => {
+            "☠ {player} plays in hardcore mode: a defeat will wipe level {level}, {xp} XP and {achievements} achievements."
+        }
+        Msg::HardcoreLoss => {
+            "☠ {player} lost in hardcore mode. Progress to be wiped: level {level}, {xp} XP, achievements: {achievements}."
+        }
+        Msg::HardcoreConfirm => {
+            "Confirm the wipe? Otherwise the profile leaves hardcore mode and keeps its progress. [y/N] "
+        }
+        Msg::HardcoreReset => "{player}'s progress has been wiped.",
+        Msg::HardcoreKept => "{player} leaves hardcore mode and keeps their progress.",
+        Msg::HardcoreEnabled => {
+            "Profile {profile} is now in hardcore mode: a defeat will wipe its experience, level and achievements."
+        }
+        Msg::HardcoreDisabled => "Profile {profile} is no longer in hardcore mode.",
+        Msg::XpLevelUp => "{player} gains {xp} XP — level {level}, +{strength} strength next match",
+        Msg::XpProgress => "{player} gains {xp} XP — level {level} ({progress}/{needed} XP)",
+        Msg::AchievementUnlocked => "🏆 {player} unlocks the achievement “{name}”",
+        Msg::RematchPrompt => "🔄 What would you like to do?",
+        Msg::RematchSame => "→ 1: rematch with the same settings",
+        Msg::RematchSwap => "→ 2: rematch with sides swapped",
+        Msg::RematchEdit => "→ 3: edit settings",
+        Msg::RematchStats => "→ 4: show session stats",
+        Msg::RematchQuit => "→ 5: quit",
+        Msg::RematchInvalid => "Invalid input, please enter a number between 1 and 5.",
+        Msg::ChoiceInvalid => "Invalid input, please enter a number between 1 and {max}.",
+        Msg::SessionScore => "Tonight's score — {scores}",
+        Msg::SessionDraw => "{count} draw",
+        Msg::SessionDraws => "{count} draws",
+        Msg::SessionForfeit => "{count} forfeit",
+        Msg::SessionForfeits => "{count} forfeits",
+        Msg::SeriesStart => "{player} starts with {vitality} vitality (+{bonus} carry-over)",
+        Msg::SeriesScore => "Best of {best_of} series — {score}",
+        Msg::SeriesGameWon => "Game {number}: {winner}",
+        Msg::SeriesGameDrawn => "Game {number}: draw",
+        Msg::SeriesCarriedOver => "{player} +{bonus} carry-over",
+        Msg::SeriesWinner => "🏆 Series winner: {winner}",
+        Msg::SaveResumed => "💾 Resuming save \"{slot}\" at round {round}.",
+        Msg::SaveSlot => "{slot} — round {round} — {players} — {time}",
+        Msg::SavesEmpty => "No saves.",
+        Msg::DemoStart => "🎬 Demo: {player1} vs {player2}. Press any key to quit.",
+        Msg::DemoNextGame => "Next demo game…",
+        Msg::EnvInvalidFlag => {
+            "invalid variable {name}: \"{value}\" (possible values: 1, 0, true, false, yes, no)"
+        }
+        Msg::NetWaiting => "Waiting for an opponent on port {port}…",
+        Msg::NetConnected => "{player} joined the game from {address}.",
+        Msg::NetJoined => "Connected to {player}'s game.",
+        Msg::NetDisconnected => "connection to the other player lost",
+        Msg::NetIo => "network error: {error}",
+        Msg::NetTooLarge => "network message too long ({len} bytes)",
+        Msg::NetInvalidMessage => "invalid network message: {error}",
+        Msg::NetUnexpected => "unexpected network message: {message}",
+        Msg::NetIncompatible => {
+            "incompatible protocol versions: the client supports versions {min} to {max}, the host version {version}"
+        }
+        Msg::NetRejected => "connection refused by the host: {reason}",
+        Msg::WsListening => "WebSocket server listening on port {port} (Ctrl+C to stop).",
+        Msg::ProtocolUnknownCommand => "unknown command: {command}",
+        Msg::ProtocolMissingArgument => "missing argument for {command}",
+        Msg::ProtocolInvalidArgument => "invalid argument for {command}: {value}",
+        Msg::ProtocolExtraArgument => "too many arguments for {command}",
+        Msg::ProtocolUnexpected => "unexpected command {command}, expected {expected}",
+        Msg::TuiTooSmall => {
+            "Terminal too small: resize it to at least {width} × {height} characters."
+        }
+        Msg::TuiLogTitle => "Game log",
+        Msg::TuiPoisonTitle => "Poison to apply to {opponent}",
+        Msg::TuiWinnersCurse => "cursed: -{speed} speed",
+        Msg::TuiStopHint => "ENTER or SPACE: stop the counter · Ctrl+C: interrupt",
+        Msg::TuiBreathHint => " · s: catch your breath ({stamina})",
+        Msg::TuiChoiceHint => "1 to {max}: choose · Ctrl+C: interrupt",
+        Msg::TuiContinueHint => "ENTER: continue · Ctrl+C: interrupt",
+        Msg::PoisonedStat => "{player}'s {field}: {value}",
+        Msg::StatsTitle => "Game analysis:",
+        Msg::StatsPrecision => {
+            "→ {player}: average gap {diff}, {perfect} perfect stop(s), {misses} miss(es)"
+        }
+        Msg::StatsObjectives => {
+            "  best objective: {best} (score {best_score}), worst: {worst} (score {worst_score})"
+        }
+        Msg::StatsDamage => "  damage dealt: {dealt}, taken: {taken}",
+        Msg::StatsDraws => "  drawn rounds: {draws}",
+        Msg::StatsRounds => "  average score per round: {sparkline} ({scores})",
+        Msg::StatsErrors => {
+            "  stops too early: {early}, exact: {exact}, too late: {late} (signed error):"
+        }
+        Msg::StatsHighlight => {
+            "  best objective: round {round}, target {objective} stopped at {counter}, score \
+             {score} (replay: turn {turn}, objective {index})"
+        }
+        Msg::StatsMvp => {
+            "★ MVP objective: {player}, round {round}, target {objective} stopped at {counter}, \
+             score {score} (replay: turn {turn}, objective {index})"
+        }
+        Msg::ExportSaveFailed => "Could not export the game result: {error}",
+        Msg::SimulationTitle => "Simulation of {games} games",
+        Msg::SimulationWins => "  {player}: {wins} wins ({rate} %)",
+        Msg::SimulationDraws => "  Draws: {draws}",
+        Msg::SimulationLength => "  Average length: {rounds} rounds",
+        Msg::BalanceTitle => {
+            "Balancing: {games} games per configuration against {player} (speed {speed}, strength \
+             {strength})"
+        }
+        Msg::BalanceColumns => "  Speed  Power       Wins  Average length",
+        Msg::BalanceRow => "{speed}  {strength}  {rate} %  {rounds} rounds",
+        Msg::BalanceProgress => "Balancing: {percent} %",
+        Msg::BalanceSaveFailed => "Could not save the balance report: {error}",
+        Msg::ReplaySkipped => "Skipped replay: {error}",
+        Msg::ReplayDiverged => {
+            "The replay (version {version}) does not match its scoring and rules \
+             ({divergence}): run again with --force to play it back anyway"
+        }
+        Msg::ReplayForced => "Replay played back despite a divergence ({divergence})",
+        Msg::ScoreboardTitle => "Scoreboard (round {round})",
+        Msg::ScoreboardRecord => "wins: {wins}  average: {average}",
+        Msg::ScoreboardRow => "{player}  {record}  vitality: {vitality}/{max}",
+        Msg::CounterStarted => "The counter starts for objective {objective}.",
+        Msg::CounterValue => "Counter at {counter}.",
+        Msg::CounterHotZone => "Hot zone: counter at {counter}, objective {objective}.",
+        Msg::CounterStopped => {
+            "Counter stopped at {counter} for objective {objective}, after {miss} miss(es)."
+        }
+        Msg::CounterPaused => {
+            "⏸ Paused — 1: resume · 2: show the rules · 3: forfeit ({key}: resume)"
+        }
+        Msg::CounterResumed => "▶ Counter resumed.",
+        Msg::SpokenGameStart => "The game begins.",
+        Msg::SpokenPlayer => {
+            "{player} has {vitality} of {max} vitality points, a speed of {speed}, a strength of {strength} and an armor of {armor}."
+        }
+        Msg::SpokenRoundStart => "Round {round} begins.",
+        Msg::SpokenVitality => "{player} has {vitality} of {max} vitality points.",
+        Msg::SpokenTurn => "It is {player}'s turn.",
+        Msg::SpokenFatigue => "Fatigue reduces {player}'s strength by {fatigue}.",
+        Msg::SpokenObjectives => "Objectives for this turn: {objectives}.",
+        Msg::SpokenStop => {
+            "Objective {objective}: counter stopped at {counter}, gap of {gap}, {miss} miss(es), score of {score}."
+        }
+        Msg::SpokenScore => "Objective {objective}: gap of {gap}, score of {score}.",
+        Msg::SpokenTurnEnd => "End of {player}'s turn, with an average score of {score}.",
+        Msg::SpokenOption => "Type {number} to {option}.",
+        Msg::SpokenAccept => "accept the poison, {poison}",
+        Msg::SpokenPoison => "a loss of {amount} {field}",
+        Msg::SpokenCounter => {
+            "counter and lose {damage} extra vitality points, keeping {remaining}"
+        }
+        Msg::SpokenRoundEnd => "Round {round} is over.",
+        Msg::SpokenGameOver => "The game is over.",
+        Msg::SpokenInterrupted => "The game was interrupted after {rounds} completed round(s).",
+        Msg::SpokenForfeit => "{player} left the game and forfeits.",
+        Msg::SpokenTotal => "{player}'s total score: {total}.",
+        Msg::SpokenStats => {
+            "{player}: average gap of {diff}, {perfect} perfect stop(s), {misses} miss(es), {dealt} damage points dealt and {taken} taken."
+        }
+        Msg::SpokenRounds => "{player}'s average score per round: {scores}.",
+        Msg::SpokenErrors => {
+            "{player} stopped the counter too early {early} times, exactly {exact} times and too late {late} times."
+        }
+        Msg::SpokenDraws => "{player} drew {draws} round(s).",
+        Msg::SpokenHighlight => {
+            "Best objective of {player}: round {round}, target {objective}, stopped at {counter}, \
+             score {score}."
+        }
+        Msg::SpokenMvp => {
+            "MVP objective of the game: {player}, round {round}, target {objective}, stopped at \
+             {counter}, score {score}."
+        }
+        Msg::Cancelled => "game interrupted",
+        Msg::InterruptRequested => {
+            "Interruption requested: the game stops and shows its summary (Ctrl+C again to quit immediately)."
+        }
+        Msg::InterruptUnavailable => "Warning: cannot intercept Ctrl+C: {error}",
+        Msg::Error => "Error: {error}",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que chaque clé est traduite dans toutes les langues, avec les mêmes paramètres.
+    #[test]
+    fn test_catalogs_complete() {
+        let keys: BTreeSet<String> = Msg::ALL.iter().map(|msg| format!("{msg:?}")).collect();
+        assert_eq!(keys.len(), Msg::ALL.len(), "clé en double dans Msg::ALL");
+        for msg in Msg::ALL {
+            let expected = msg.placeholders(Lang::Fr);
+            for lang in Lang::ALL {
+                assert!(!msg.template(lang).is_empty(), "{msg:?} vide en {lang}");
+                assert_eq!(
+                    msg.placeholders(lang),
+                    expected,
+                    "paramètres de {msg:?} différents en {lang}"
+                );
+            }
+        }
+    }
+
+    /// Vérifie le remplacement des paramètres nommés et la sélection de la langue.
+    #[test]
+    fn test_format_and_lang() {
+        let text = Msg::RoundWon.format(
+            Lang::Fr,
+            &[
+                ("winner", &"Alice"),
+                ("loser", &"Bob"),
+                ("damage", &12),
+                ("detail", &""),
+            ],
+        );
+        assert_eq!(
+            text,
+            "Alice gagne la manche. Bob perd 12 points de vitalité."
+        );
+        assert_eq!(
+            Msg::TurnOf.format(Lang::En, &[("player", &"Bob")]),
+            "Bob's turn"
+        );
+        assert_eq!(Msg::TurnOf.format(Lang::En, &[]), "{player}'s turn");
+
+        assert_eq!("EN".parse::<Lang>(), Ok(Lang::En));
+        assert!("de".parse::<Lang>().unwrap_err().contains("fr, en"));
+        assert_eq!(Lang::from_locale("en_US.UTF-8"), Some(Lang::En));
+        assert_eq!(Lang::from_locale("fr_FR"), Some(Lang::Fr));
+        assert_eq!(Lang::from_locale("C.UTF-8"), None);
+    }
+}
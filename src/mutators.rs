@@ -0,0 +1,231 @@
+//! Module des mutateurs, modificateurs tirés au sort au début de chaque manche.
+//!
+//! Lorsque les mutateurs sont activés ([`GameRules::mutators`](crate::rules::GameRules::mutators)),
+//! un [`RoundModifier`] est tiré parmi [`POOL`] par le générateur de la partie au début de chaque
+//! manche, annoncé, puis appliqué aux deux joueurs pour cette manche seulement. Chaque mutateur agit
+//! par des points d'ancrage de la partie : le tirage des objectifs ([`RoundModifier::objective`]), le
+//! lancement du compteur ([`RoundModifier::speed`], [`RoundModifier::hides_counter`]) et les dégâts
+//! de la manche ([`RoundModifier::damage`]). Son identifiant est consigné avec la manche dans
+//! l'historique ([`RoundRecord::mutator`](crate::replay::RoundRecord::mutator)).
+
+use rand::Rng;
+
+use crate::counter::MIN_SPEED;
+use crate::messages::Msg;
+use crate::tr;
+
+/// Modificateur appliqué aux deux joueurs pendant une manche.
+///
+/// Par défaut, chaque point d'ancrage laisse la partie inchangée : un mutateur ne redéfinit que ceux
+/// sur lesquels il agit.
+pub trait RoundModifier: Sync {
+    /// Retourne l'identifiant du mutateur, consigné dans l'historique.
+    fn id(&self) -> &'static str;
+
+    /// Retourne le nom du mutateur, tel qu'annoncé au début de la manche.
+    fn name(&self) -> &'static str;
+
+    /// Retourne la description de l'effet du mutateur.
+    fn description(&self) -> String;
+
+    /// Transforme un objectif tiré pour la manche.
+    fn objective(&self, objective: u32) -> u32 {
+        objective
+    }
+
+    /// Transforme la vitesse du compteur (délai en millisecondes entre deux incrémentations).
+    fn speed(&self, speed: u32) -> u32 {
+        speed
+    }
+
+    /// Indique si la valeur du compteur est cachée jusqu'à son arrêt.
+    fn hides_counter(&self) -> bool {
+        false
+    }
+
+    /// Transforme les dégâts infligés au perdant de la manche, avant son armure.
+    fn damage(&self, damage: u32) -> u32 {
+        damage
+    }
+}
+
+/// Brouillard : le compteur est caché jusqu'à son arrêt.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Fog;
+
+impl RoundModifier for Fog {
+    fn id(&self) -> &'static str {
+        "fog"
+    }
+
+    fn name(&self) -> &'static str {
+        "Brouillard"
+    }
+
+    fn description(&self) -> String {
+        tr!(Msg::MutatorFog)
+    }
+
+    fn hides_counter(&self) -> bool {
+        true
+    }
+}
+
+/// Tempête : le compteur défile 20 % plus vite.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Storm;
+
+impl Storm {
+    /// Accélération du compteur, en pourcentage.
+    pub const SPEEDUP_PERCENT: u32 = 20;
+}
+
+impl RoundModifier for Storm {
+    fn id(&self) -> &'static str {
+        "storm"
+    }
+
+    fn name(&self) -> &'static str {
+        "Tempête"
+    }
+
+    fn description(&self) -> String {
+        tr!(Msg::MutatorStorm, percent = Storm::SPEEDUP_PERCENT)
+    }
+
+    /// Divise le délai entre deux incrémentations par 1,2, sans descendre sous [`MIN_SPEED`].
+    fn speed(&self, speed: u32) -> u32 {
+        (speed.saturating_mul(100) / (100 + Storm::SPEEDUP_PERCENT)).max(MIN_SPEED)
+    }
+}
+
+/// Gravité : les objectifs sont ramenés entre 0 et 30.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Gravity;
+
+impl Gravity {
+    /// Objectif maximal sous la gravité.
+    pub const MAX_OBJECTIVE: u32 = 30;
+}
+
+impl RoundModifier for Gravity {
+    fn id(&self) -> &'static str {
+        "gravity"
+    }
+
+    fn name(&self) -> &'static str {
+        "Gravité"
+    }
+
+    fn description(&self) -> String {
+        tr!(Msg::MutatorGravity, max = Gravity::MAX_OBJECTIVE)
+    }
+
+    /// Ramène proportionnellement l'objectif de 0–100 à 0–30, arrondi à l'entier le plus proche : la
+    /// chance des joueurs garde son effet.
+    fn objective(&self, objective: u32) -> u32 {
+        (objective.min(100) * Gravity::MAX_OBJECTIVE + 50) / 100
+    }
+}
+
+/// Pactole : les dégâts de la manche sont doublés.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Jackpot;
+
+impl RoundModifier for Jackpot {
+    fn id(&self) -> &'static str {
+        "jackpot"
+    }
+
+    fn name(&self) -> &'static str {
+        "Pactole"
+    }
+
+    fn description(&self) -> String {
+        tr!(Msg::MutatorJackpot)
+    }
+
+    fn damage(&self, damage: u32) -> u32 {
+        damage.saturating_mul(2)
+    }
+}
+
+/// Mutateurs tirés au sort, chacun avec la même probabilité.
+pub static POOL: [&dyn RoundModifier; 4] = [&Fog, &Storm, &Gravity, &Jackpot];
+
+/// Tire un mutateur de [`POOL`] avec le générateur donné.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::mutators;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let first = mutators::draw(&mut StdRng::seed_from_u64(7));
+/// let second = mutators::draw(&mut StdRng::seed_from_u64(7));
+/// assert_eq!(first.id(), second.id());
+/// ```
+pub fn draw<R: Rng + ?Sized>(rng: &mut R) -> &'static dyn RoundModifier {
+    POOL[rng.random_range(0..POOL.len())]
+}
+
+/// Retourne le mutateur d'identifiant donné, par exemple pour relire l'historique.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::mutators;
+///
+/// assert_eq!(mutators::find("fog").map(|m| m.name()), Some("Brouillard"));
+/// assert!(mutators::find("meteor").is_none());
+/// ```
+pub fn find(id: &str) -> Option<&'static dyn RoundModifier> {
+    POOL.iter().copied().find(|modifier| modifier.id() == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    /// Vérifie l'effet de chaque mutateur sur les objectifs, la vitesse, l'affichage du compteur et
+    /// les dégâts, les autres points d'ancrage restant inchangés.
+    #[test]
+    fn test_modifier_effects() {
+        assert!(Fog.hides_counter());
+        assert_eq!(
+            (Fog.objective(77), Fog.speed(60), Fog.damage(9)),
+            (77, 60, 9)
+        );
+
+        assert_eq!(Storm.speed(60), 50);
+        assert_eq!(Storm.speed(100), 83);
+        assert_eq!(Storm.speed(MIN_SPEED), MIN_SPEED);
+        assert!(!Storm.hides_counter());
+
+        let objectives: Vec<u32> = [0, 1, 2, 50, 98, 100, 250]
+            .into_iter()
+            .map(|objective| Gravity.objective(objective))
+            .collect();
+        assert_eq!(objectives, [0, 0, 1, 15, 29, 30, 30]);
+        assert_eq!(Gravity.damage(9), 9);
+
+        assert_eq!(Jackpot.damage(9), 18);
+        assert_eq!(Jackpot.damage(u32::MAX), u32::MAX);
+        assert_eq!(Jackpot.objective(42), 42);
+    }
+
+    /// Vérifie que le tirage couvre tout le répertoire et que chaque identifiant est unique.
+    #[test]
+    fn test_draw_covers_pool() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut drawn: Vec<&str> = (0..200).map(|_| draw(&mut rng).id()).collect();
+        drawn.sort_unstable();
+        drawn.dedup();
+        assert_eq!(drawn, ["fog", "gravity", "jackpot", "storm"]);
+        assert!(POOL.iter().all(|modifier| find(modifier.id()).is_some()));
+    }
+}
@@ -0,0 +1,1024 @@
+//! Module du jeu en réseau entre deux machines.
+//!
+//! L'hôte (`dual_game host`) exécute la partie et fait autorité sur les scores et les dégâts ; le joueur
+//! distant (`dual_game join`) n'est qu'un terminal. Les deux machines échangent des [`Message`] JSON
+//! préfixés par leur longueur (4 octets, gros-boutiste). Le compteur du joueur distant tourne sur sa
+//! propre machine : seule la valeur d'arrêt est transmise à l'hôte.
+//!
+//! À l'ouverture de la connexion, le client annonce les versions du protocole qu'il prend en charge
+//! ([`Message::Hello`]) et l'hôte retient la plus récente version commune ([`negotiate`]).
+//!
+//! Côté hôte, le [`RemoteController`] relaie les décisions au joueur distant, le [`NetObserver`] lui
+//! transmet les événements de la partie et la [`RemoteConsole`] lui recopie l'affichage. Une connexion
+//! perdue fait abandonner le joueur distant ([`GameResult::Forfeit`]) au lieu de bloquer la partie.
+//! Côté client, [`run_client`] répond aux demandes de l'hôte avec un [`Controller`] local.
+//!
+//! [`GameResult::Forfeit`]: crate::game::GameResult::Forfeit
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::rc::Rc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cancel::CancelToken;
+use crate::console::Console;
+use crate::controller::{Controller, CounterView, Difficulty, StopDecision};
+use crate::export::GameExport;
+use crate::messages::Msg;
+use crate::observer::{GameEvent, GameObserver};
+use crate::player::Player;
+use crate::poison::PoisonType;
+use crate::rules::GameRules;
+use crate::tr;
+
+/// Version du protocole parlée par cette version du jeu.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Plus ancienne version du protocole encore prise en charge.
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+
+/// Port d'écoute par défaut de l'hôte.
+pub const DEFAULT_PORT: u16 = 4000;
+
+/// Taille maximale d'un message, en octets.
+pub const MAX_MESSAGE_LEN: usize = 1 << 20;
+
+/// Délai maximal d'attente d'une réponse du joueur distant, au-delà duquel il est considéré comme
+/// déconnecté.
+pub const REPLY_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Caractéristiques d'un joueur transmises au joueur distant pour ses décisions.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerView {
+    /// Nom du joueur.
+    pub name: String,
+    /// Vitalité actuelle.
+    pub vitality: u32,
+    /// Vitesse.
+    pub speed: u32,
+    /// Force.
+    pub strength: u32,
+    /// Contre-propositions au poison encore disponibles.
+    pub counters_remaining: u32,
+}
+
+impl From<&Player> for PlayerView {
+    fn from(player: &Player) -> Self {
+        PlayerView {
+            name: player.name.clone(),
+            vitality: player.vitality(),
+            speed: player.speed,
+            strength: player.strength,
+            counters_remaining: player.counters_remaining,
+        }
+    }
+}
+
+impl PlayerView {
+    /// Reconstruit un joueur portant ces caractéristiques.
+    pub fn to_player(&self) -> Player {
+        let mut player = Player::new(self.name.clone(), self.vitality, self.speed, self.strength);
+        player.counters_remaining = self.counters_remaining;
+        player
+    }
+}
+
+/// Message échangé entre l'hôte et le joueur distant.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Message {
+    /// Client → hôte : ouverture de la connexion.
+    Hello {
+        /// Plus ancienne version du protocole prise en charge par le client.
+        min_version: u32,
+        /// Plus récente version du protocole prise en charge par le client.
+        max_version: u32,
+        /// Nom du joueur distant.
+        name: String,
+    },
+    /// Hôte → client : connexion acceptée.
+    Welcome {
+        /// Version du protocole retenue.
+        version: u32,
+        /// Nom du joueur de l'hôte.
+        opponent: String,
+    },
+    /// Client → serveur WebSocket : lance une partie contre un bot.
+    StartGame {
+        /// Difficulté du bot adverse.
+        difficulty: Difficulty,
+    },
+    /// Hôte → client : connexion refusée.
+    Rejected {
+        /// Motif du refus.
+        reason: String,
+    },
+    /// Hôte → client : ligne de l'affichage de la partie.
+    Line {
+        /// Texte de la ligne.
+        text: String,
+    },
+    /// Hôte → client : objectifs du tour d'un joueur.
+    Objectives {
+        /// Nom du joueur.
+        player: String,
+        /// Objectifs du tour.
+        objectives: Vec<u32>,
+    },
+    /// Hôte → client : demande d'arrêt du compteur pour un objectif.
+    PromptStop {
+        /// Objectif à atteindre.
+        objective: u32,
+        /// Index de l'objectif dans le tour.
+        index: usize,
+        /// Vitesse du joueur distant.
+        speed: u32,
+        /// Force effective du joueur distant.
+        strength: u32,
+        /// Endurance restante, ou `None` si la règle d'endurance est désactivée.
+        stamina: Option<u32>,
+    },
+    /// Client → hôte : arrêt du compteur, mesuré sur la machine du client.
+    StopResult {
+        /// Valeur du compteur au moment de l'arrêt.
+        counter_value: u32,
+        /// Nombre de tours complets du compteur.
+        miss: u32,
+        /// Endurance dépensée.
+        stamina_spent: u32,
+    },
+    /// Hôte → client : demande du poison à appliquer à l'adversaire.
+    PromptPoison {
+        /// Poisons proposés.
+        options: Vec<PoisonType>,
+        /// Adversaire, perdant de la manche.
+        opponent: PlayerView,
+    },
+    /// Client → hôte : poison choisi.
+    PoisonChoice {
+        /// Index du poison choisi parmi les options.
+        choice: usize,
+    },
+    /// Hôte → client : proposition de contrer le poison.
+    PromptCounter {
+        /// Poison choisi par l'adversaire.
+        poison: PoisonType,
+        /// Dégâts supplémentaires subis en cas de contre.
+        extra_damage: u32,
+        /// Joueur distant, perdant de la manche.
+        me: PlayerView,
+    },
+    /// Client → hôte : décision de contrer le poison.
+    CounterChoice {
+        /// Vrai si le joueur distant contre le poison.
+        counter: bool,
+    },
+    /// Hôte → client : message à valider avant de poursuivre.
+    Acknowledge {
+        /// Texte du message.
+        text: String,
+    },
+    /// Client → hôte : message validé.
+    Acknowledged,
+    /// Hôte → client : résultat d'une manche.
+    RoundResult {
+        /// Numéro de la manche.
+        round: u32,
+        /// Vainqueur de la manche, ou `None` en cas d'égalité.
+        winner: Option<String>,
+        /// Dégâts infligés au perdant.
+        damage: u32,
+    },
+    /// Hôte → client : malédiction du vainqueur d'une manche, qui le ralentit pendant la suivante.
+    WinnersCurse {
+        /// Joueur maudit.
+        player: String,
+        /// Écart de score moyen avec lequel il a remporté la manche.
+        margin: u32,
+        /// Points de vitesse effective perdus.
+        speed: u32,
+        /// Manche pendant laquelle il est ralenti.
+        round: u32,
+    },
+    /// Hôte → client : fin de la partie.
+    GameOver {
+        /// Résultat de la partie.
+        result: GameExport,
+    },
+}
+
+impl Message {
+    /// Retourne le type du message, tel qu'il apparaît dans le JSON.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Message::Hello { .. } => "hello",
+            Message::Welcome { .. } => "welcome",
+            Message::StartGame { .. } => "start_game",
+            Message::Rejected { .. } => "rejected",
+            Message::Line { .. } => "line",
+            Message::Objectives { .. } => "objectives",
+            Message::PromptStop { .. } => "prompt_stop",
+            Message::StopResult { .. } => "stop_result",
+            Message::PromptPoison { .. } => "prompt_poison",
+            Message::PoisonChoice { .. } => "poison_choice",
+            Message::PromptCounter { .. } => "prompt_counter",
+            Message::CounterChoice { .. } => "counter_choice",
+            Message::Acknowledge { .. } => "acknowledge",
+            Message::Acknowledged => "acknowledged",
+            Message::RoundResult { .. } => "round_result",
+            Message::WinnersCurse { .. } => "winners_curse",
+            Message::GameOver { .. } => "game_over",
+        }
+    }
+}
+
+/// Erreur survenue lors d'un échange réseau.
+#[derive(Debug)]
+pub enum NetError {
+    /// L'autre machine a fermé la connexion.
+    Disconnected,
+    /// Erreur d'entrée/sortie sur la connexion.
+    Io(io::Error),
+    /// Le message annoncé dépasse [`MAX_MESSAGE_LEN`].
+    TooLarge {
+        /// Taille annoncée, en octets.
+        len: usize,
+    },
+    /// Le message reçu n'est pas un [`Message`] valide.
+    InvalidMessage(serde_json::Error),
+    /// Le message reçu ne correspond pas à l'étape de l'échange.
+    Unexpected {
+        /// Type du message reçu.
+        message: &'static str,
+    },
+    /// Aucune version du protocole n'est commune au client et à l'hôte.
+    Incompatible {
+        /// Plus ancienne version prise en charge par le client.
+        min_version: u32,
+        /// Plus récente version prise en charge par le client.
+        max_version: u32,
+    },
+    /// L'hôte a refusé la connexion.
+    Rejected {
+        /// Motif du refus.
+        reason: String,
+    },
+}
+
+impl NetError {
+    /// Construit l'erreur signalant un message reçu hors de propos.
+    pub fn unexpected(message: &Message) -> Self {
+        NetError::Unexpected {
+            message: message.kind(),
+        }
+    }
+}
+
+impl fmt::Display for NetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            NetError::Disconnected => tr!(Msg::NetDisconnected),
+            NetError::Io(source) => tr!(Msg::NetIo, error = source),
+            NetError::TooLarge { len } => tr!(Msg::NetTooLarge, len = len),
+            NetError::InvalidMessage(source) => tr!(Msg::NetInvalidMessage, error = source),
+            NetError::Unexpected { message } => tr!(Msg::NetUnexpected, message = message),
+            NetError::Incompatible {
+                min_version,
+                max_version,
+            } => tr!(
+                Msg::NetIncompatible,
+                min = min_version,
+                max = max_version,
+                version = PROTOCOL_VERSION
+            ),
+            NetError::Rejected { reason } => tr!(Msg::NetRejected, reason = reason),
+        };
+        write!(f, "{text}")
+    }
+}
+
+impl Error for NetError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NetError::Io(source) => Some(source),
+            NetError::InvalidMessage(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NetError {
+    /// Une fin de flux ou une connexion rompue signifie que l'autre machine s'est déconnectée.
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::UnexpectedEof
+            | io::ErrorKind::BrokenPipe
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted => NetError::Disconnected,
+            _ => NetError::Io(err),
+        }
+    }
+}
+
+/// Écrit un message préfixé par sa longueur.
+pub fn write_message<W: Write>(writer: &mut W, message: &Message) -> Result<(), NetError> {
+    let body = serde_json::to_vec(message).expect("un message est toujours sérialisable");
+    let len = u32::try_from(body.len()).map_err(|_| NetError::TooLarge { len: body.len() })?;
+    writer.write_all(&len.to_be_bytes())?;
+    writer.write_all(&body)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Lit un message préfixé par sa longueur.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::net::{Message, read_message, write_message};
+///
+/// let mut buffer = Vec::new();
+/// write_message(&mut buffer, &Message::Acknowledged).unwrap();
+/// assert_eq!(read_message(&mut buffer.as_slice()).unwrap(), Message::Acknowledged);
+/// ```
+pub fn read_message<R: Read>(reader: &mut R) -> Result<Message, NetError> {
+    let mut len = [0; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_MESSAGE_LEN {
+        return Err(NetError::TooLarge { len });
+    }
+    let mut body = vec![0; len];
+    reader.read_exact(&mut body)?;
+    serde_json::from_slice(&body).map_err(NetError::InvalidMessage)
+}
+
+/// Canal transportant les [`Message`] entre deux machines.
+///
+/// Tout flux d'octets (connexion TCP, flux en mémoire) est un canal dont les messages sont préfixés par
+/// leur longueur ; le serveur WebSocket (fonctionnalité `ws`) transporte les mêmes messages dans des
+/// trames texte.
+pub trait Transport {
+    /// Envoie un message.
+    fn send_message(&mut self, message: &Message) -> Result<(), NetError>;
+
+    /// Attend le prochain message.
+    fn receive_message(&mut self) -> Result<Message, NetError>;
+}
+
+impl<S: Read + Write> Transport for S {
+    fn send_message(&mut self, message: &Message) -> Result<(), NetError> {
+        write_message(self, message)
+    }
+
+    fn receive_message(&mut self) -> Result<Message, NetError> {
+        read_message(self)
+    }
+}
+
+/// Retient la plus récente version du protocole commune à l'hôte et au client.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::net::{PROTOCOL_VERSION, negotiate};
+///
+/// assert_eq!(negotiate(1, PROTOCOL_VERSION + 3), Some(PROTOCOL_VERSION));
+/// assert_eq!(negotiate(PROTOCOL_VERSION + 1, PROTOCOL_VERSION + 3), None);
+/// ```
+pub fn negotiate(min_version: u32, max_version: u32) -> Option<u32> {
+    let version = max_version.min(PROTOCOL_VERSION);
+    (version >= min_version.max(MIN_PROTOCOL_VERSION)).then_some(version)
+}
+
+/// Connexion avec l'autre machine.
+///
+/// Après une erreur, la connexion est considérée comme fermée : les envois et lectures suivants
+/// échouent aussitôt avec [`NetError::Disconnected`].
+#[derive(Debug)]
+pub struct Connection<S> {
+    /// Canal de la connexion.
+    stream: S,
+    /// Vrai après une erreur ou une fermeture.
+    closed: bool,
+}
+
+impl<S: Transport> Connection<S> {
+    /// Crée une connexion sur le canal donné.
+    pub fn new(stream: S) -> Self {
+        Connection {
+            stream,
+            closed: false,
+        }
+    }
+
+    /// Indique si la connexion est fermée.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Ferme la connexion, par exemple après un message hors de propos.
+    pub fn close(&mut self) {
+        self.closed = true;
+    }
+
+    /// Envoie un message.
+    pub fn send(&mut self, message: &Message) -> Result<(), NetError> {
+        if self.closed {
+            return Err(NetError::Disconnected);
+        }
+        self.stream
+            .send_message(message)
+            .inspect_err(|_| self.closed = true)
+    }
+
+    /// Attend le prochain message.
+    pub fn receive(&mut self) -> Result<Message, NetError> {
+        if self.closed {
+            return Err(NetError::Disconnected);
+        }
+        self.stream
+            .receive_message()
+            .inspect_err(|_| self.closed = true)
+    }
+
+    /// Envoie un message puis attend la réponse.
+    pub fn request(&mut self, message: &Message) -> Result<Message, NetError> {
+        self.send(message)?;
+        self.receive()
+    }
+
+    /// Accepte un joueur distant : négocie la version du protocole et retourne son nom.
+    ///
+    /// # Arguments
+    ///
+    /// * `host_name` - Le nom du joueur de l'hôte, annoncé au joueur distant.
+    pub fn accept(&mut self, host_name: &str) -> Result<String, NetError> {
+        match self.receive()? {
+            Message::Hello {
+                min_version,
+                max_version,
+                name,
+            } => match negotiate(min_version, max_version) {
+                Some(version) => {
+                    self.send(&Message::Welcome {
+                        version,
+                        opponent: host_name.to_string(),
+                    })?;
+                    Ok(name)
+                }
+                None => {
+                    let err = NetError::Incompatible {
+                        min_version,
+                        max_version,
+                    };
+                    let _ = self.send(&Message::Rejected {
+                        reason: err.to_string(),
+                    });
+                    self.close();
+                    Err(err)
+                }
+            },
+            other => {
+                self.close();
+                Err(NetError::unexpected(&other))
+            }
+        }
+    }
+
+    /// Rejoint la partie d'un hôte et retourne le nom de son joueur.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Le nom du joueur distant, annoncé à l'hôte.
+    pub fn join(&mut self, name: &str) -> Result<String, NetError> {
+        self.send(&Message::Hello {
+            min_version: MIN_PROTOCOL_VERSION,
+            max_version: PROTOCOL_VERSION,
+            name: name.to_string(),
+        })?;
+        match self.receive()? {
+            Message::Welcome { opponent, .. } => Ok(opponent),
+            Message::Rejected { reason } => {
+                self.close();
+                Err(NetError::Rejected { reason })
+            }
+            other => {
+                self.close();
+                Err(NetError::unexpected(&other))
+            }
+        }
+    }
+}
+
+/// Connexion partagée par le contrôleur, l'observateur et la console de l'hôte.
+pub type SharedConnection<S> = Rc<RefCell<Connection<S>>>;
+
+/// Contrôleur relayant les décisions au joueur distant.
+///
+/// Si la connexion est perdue ou si le joueur distant répond hors de propos, la connexion est fermée,
+/// les décisions suivantes sont neutres et le joueur distant abandonne la partie.
+#[derive(Debug)]
+pub struct RemoteController<S> {
+    /// Connexion avec le joueur distant.
+    connection: SharedConnection<S>,
+}
+
+impl<S: Transport> RemoteController<S> {
+    /// Crée un contrôleur relayant les décisions sur la connexion donnée.
+    pub fn new(connection: SharedConnection<S>) -> Self {
+        RemoteController { connection }
+    }
+
+    /// Envoie une demande au joueur distant et interprète sa réponse avec `parse`.
+    ///
+    /// Retourne `None`, après avoir fermé la connexion, si l'échange échoue ou si la réponse ne
+    /// convient pas.
+    fn ask<T>(
+        &mut self,
+        message: &Message,
+        parse: impl FnOnce(&Message) -> Option<T>,
+    ) -> Option<T> {
+        let mut connection = self.connection.borrow_mut();
+        let outcome = connection
+            .request(message)
+            .and_then(|reply| parse(&reply).ok_or_else(|| NetError::unexpected(&reply)));
+        match outcome {
+            Ok(value) => Some(value),
+            Err(err) => {
+                log::warn!("{err}");
+                connection.close();
+                None
+            }
+        }
+    }
+}
+
+impl<S: Transport> Controller for RemoteController<S> {
+    /// Le compteur tourne sur la machine du joueur distant ; l'hôte borne les valeurs reçues.
+    fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+        let request = Message::PromptStop {
+            objective: ctx.objective,
+            index: ctx.index,
+            speed: ctx.speed,
+            strength: ctx.strength,
+            stamina: ctx.stamina,
+        };
+        let stop = self.ask(&request, |reply| match *reply {
+            Message::StopResult {
+                counter_value,
+                miss,
+                stamina_spent,
+            } => Some(StopDecision {
+                counter_value: counter_value.min(100),
+                miss,
+                stamina_spent: stamina_spent.min(ctx.stamina.unwrap_or(0)),
+            }),
+            _ => None,
+        });
+        stop.unwrap_or(StopDecision {
+            counter_value: 0,
+            miss: 0,
+            stamina_spent: 0,
+        })
+    }
+
+    fn choose_poison(&mut self, options: &[PoisonType], opponent: &Player) -> usize {
+        let request = Message::PromptPoison {
+            options: options.to_vec(),
+            opponent: opponent.into(),
+        };
+        self.ask(&request, |reply| match *reply {
+            Message::PoisonChoice { choice } => Some(choice),
+            _ => None,
+        })
+        .unwrap_or(usize::MAX)
+    }
+
+    fn choose_counter(&mut self, poison: &PoisonType, extra_damage: u32, me: &Player) -> bool {
+        let request = Message::PromptCounter {
+            poison: poison.clone(),
+            extra_damage,
+            me: me.into(),
+        };
+        self.ask(&request, |reply| match *reply {
+            Message::CounterChoice { counter } => Some(counter),
+            _ => None,
+        })
+        .unwrap_or(false)
+    }
+
+    fn acknowledge(&mut self, msg: &str) {
+        let request = Message::Acknowledge {
+            text: msg.to_string(),
+        };
+        self.ask(&request, |reply| {
+            matches!(reply, Message::Acknowledged).then_some(())
+        });
+    }
+
+    /// Le joueur distant voit son compteur et les invites qui lui sont destinées.
+    fn displays_counter(&self) -> bool {
+        true
+    }
+
+    fn has_forfeited(&self) -> bool {
+        self.connection.borrow().is_closed()
+    }
+}
+
+/// Observateur transmettant les événements de la partie au joueur distant.
+#[derive(Debug)]
+pub struct NetObserver<S> {
+    /// Connexion avec le joueur distant.
+    connection: SharedConnection<S>,
+    /// Noms des joueurs de la partie en cours.
+    players: Vec<String>,
+    /// Vainqueur et dégâts de la manche en cours, une fois les scores comparés.
+    round_winner: Option<(String, u32)>,
+}
+
+impl<S: Transport> NetObserver<S> {
+    /// Crée un observateur transmettant les événements sur la connexion donnée.
+    pub fn new(connection: SharedConnection<S>) -> Self {
+        NetObserver {
+            connection,
+            players: Vec::new(),
+            round_winner: None,
+        }
+    }
+
+    /// Envoie un message ; un échec ferme la connexion, ce que la partie traite comme un abandon.
+    fn send(&self, message: &Message) {
+        if let Err(err) = self.connection.borrow_mut().send(message) {
+            log::warn!("{err}");
+        }
+    }
+}
+
+impl<S: Transport> GameObserver for NetObserver<S> {
+    fn on_event(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::GameStarted { players, .. } => self.players = players.clone(),
+            GameEvent::ObjectivesDrawn { player, objectives } => {
+                self.send(&Message::Objectives {
+                    player: player.clone(),
+                    objectives: objectives.clone(),
+                });
+            }
+            GameEvent::DamageDealt { winner, damage, .. } => {
+                self.round_winner = Some((winner.clone(), *damage));
+            }
+            GameEvent::RoundEnded { round } => {
+                let (winner, damage) = match self.round_winner.take() {
+                    Some((winner, damage)) => (Some(winner), damage),
+                    None => (None, 0),
+                };
+                self.send(&Message::RoundResult {
+                    round: *round,
+                    winner,
+                    damage,
+                });
+            }
+            GameEvent::WinnersCurse {
+                player,
+                margin,
+                speed,
+                round,
+            } => {
+                self.send(&Message::WinnersCurse {
+                    player: player.clone(),
+                    margin: *margin,
+                    speed: *speed,
+                    round: *round,
+                });
+            }
+            GameEvent::GameEnded { result, rounds, .. } => {
+                let result = GameExport::new(self.players.clone(), *result, *rounds);
+                self.send(&Message::GameOver { result });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Console de l'hôte recopiant chaque ligne affichée au joueur distant.
+pub struct RemoteConsole<S> {
+    /// Console locale de l'hôte.
+    local: Box<dyn Console>,
+    /// Connexion avec le joueur distant.
+    connection: SharedConnection<S>,
+}
+
+impl<S: Transport> RemoteConsole<S> {
+    /// Crée une console affichant sur `local` et recopiant les lignes sur la connexion.
+    pub fn new(local: Box<dyn Console>, connection: SharedConnection<S>) -> Self {
+        RemoteConsole { local, connection }
+    }
+}
+
+impl<S: Transport> Console for RemoteConsole<S> {
+    fn print(&mut self, text: &str) {
+        self.local.print(text);
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        self.local.read_line()
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.local.is_interactive()
+    }
+
+    fn println(&mut self, line: &str) {
+        self.local.println(line);
+        let message = Message::Line {
+            text: line.to_string(),
+        };
+        if let Err(err) = self.connection.borrow_mut().send(&message) {
+            log::warn!("{err}");
+        }
+    }
+}
+
+/// Joue la partie d'un hôte en tant que joueur distant, jusqu'à sa fin.
+///
+/// Les lignes affichées par l'hôte sont recopiées sur `console` et chaque demande de l'hôte est
+/// confiée à `controller`, dont le compteur tourne sur cette machine.
+///
+/// # Arguments
+///
+/// * `connection` - La connexion, une fois la partie rejointe ([`Connection::join`]).
+/// * `controller` - Le contrôleur du joueur distant.
+/// * `console` - La console recevant l'affichage de la partie.
+/// * `cancel` - Le jeton d'interruption ; une fois annulé, la connexion est abandonnée.
+///
+/// # Retour
+///
+/// Retourne le résultat de la partie, ou `None` si elle a été interrompue sur cette machine.
+pub fn run_client<S: Transport>(
+    connection: &mut Connection<S>,
+    controller: &mut dyn Controller,
+    console: &mut dyn Console,
+    cancel: &CancelToken,
+) -> Result<Option<GameExport>, NetError> {
+    controller.set_cancel_token(cancel.clone());
+    loop {
+        if cancel.is_cancelled() {
+            connection.close();
+            return Ok(None);
+        }
+        let reply = match connection.receive()? {
+            Message::Line { text } => {
+                console.println(&text);
+                continue;
+            }
+            Message::Objectives { .. } | Message::RoundResult { .. } => {
+                // Le tour précédent du joueur est terminé, s'il en avait un.
+                controller.end_turn();
+                continue;
+            }
+            // La malédiction est déjà annoncée par les lignes de l'affichage.
+            Message::WinnersCurse { .. } => continue,
+            Message::PromptStop {
+                objective,
+                index,
+                speed,
+                strength,
+                stamina,
+            } => {
+                let view = CounterView {
+                    objective,
+                    index,
+                    speed,
+                    hidden: false,
+                    strength,
+                    stamina,
+                    assist: None,
+                    cancel: cancel.clone(),
+                    // Les règles de l'hôte ne sont pas transmises : le menu de pause affiche
+                    // celles par défaut.
+                    rules: GameRules::default(),
+                };
+                let stop = controller.stop_counter(&view);
+                Message::StopResult {
+                    counter_value: stop.counter_value,
+                    miss: stop.miss,
+                    stamina_spent: stop.stamina_spent,
+                }
+            }
+            Message::PromptPoison { options, opponent } => Message::PoisonChoice {
+                choice: controller.choose_poison(&options, &opponent.to_player()),
+            },
+            Message::PromptCounter {
+                poison,
+                extra_damage,
+                me,
+            } => Message::CounterChoice {
+                counter: controller.choose_counter(&poison, extra_damage, &me.to_player()),
+            },
+            Message::Acknowledge { text } => {
+                controller.acknowledge(&text);
+                Message::Acknowledged
+            }
+            Message::GameOver { result } => return Ok(Some(result)),
+            other => {
+                connection.close();
+                return Err(NetError::unexpected(&other));
+            }
+        };
+        if cancel.is_cancelled() {
+            connection.close();
+            return Ok(None);
+        }
+        connection.send(&reply)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::{Receiver, Sender, channel};
+    use std::thread;
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use crate::console::ScriptedConsole;
+    use crate::controller::RandomBot;
+    use crate::export::Outcome;
+    use crate::game::{Game, GameResult};
+    use crate::output::{Output, Verbosity};
+    use crate::player::PlayerId;
+
+    /// Extrémité d'un flux bidirectionnel en mémoire.
+    struct Pipe {
+        tx: Sender<Vec<u8>>,
+        rx: Receiver<Vec<u8>>,
+        pending: Vec<u8>,
+    }
+
+    impl Read for Pipe {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.pending.is_empty() {
+                match self.rx.recv() {
+                    Ok(bytes) => self.pending = bytes,
+                    Err(_) => return Ok(0),
+                }
+            }
+            let len = buf.len().min(self.pending.len());
+            buf[..len].copy_from_slice(&self.pending[..len]);
+            self.pending.drain(..len);
+            Ok(len)
+        }
+    }
+
+    impl Write for Pipe {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.tx
+                .send(buf.to_vec())
+                .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Crée les deux extrémités d'un flux bidirectionnel en mémoire.
+    fn duplex() -> (Pipe, Pipe) {
+        let (host_tx, client_rx) = channel();
+        let (client_tx, host_rx) = channel();
+        let host = Pipe {
+            tx: host_tx,
+            rx: host_rx,
+            pending: Vec::new(),
+        };
+        let client = Pipe {
+            tx: client_tx,
+            rx: client_rx,
+            pending: Vec::new(),
+        };
+        (host, client)
+    }
+
+    /// Prépare côté hôte une partie contre le joueur distant, dont l'affichage est capturé.
+    fn host_game(stream: Pipe) -> Game {
+        let connection = Rc::new(RefCell::new(Connection::new(stream)));
+        let remote = connection.borrow_mut().accept("Alice").unwrap();
+        let players = vec![
+            Player::new(String::from("Alice"), 30, 75, 50),
+            Player::new(remote, 30, 75, 50),
+        ];
+        let mut game = Game::new(players, 3);
+        game.set_seed(7);
+        let console =
+            RemoteConsole::new(Box::new(ScriptedConsole::default()), Rc::clone(&connection));
+        game.set_output(Output::new(Box::new(console), Verbosity::Normal));
+        game.set_controller(0, Box::new(RandomBot::new(StdRng::seed_from_u64(1))));
+        game.set_controller(1, Box::new(RemoteController::new(Rc::clone(&connection))));
+        game.add_observer(Box::new(NetObserver::new(connection)));
+        game
+    }
+
+    /// Vérifie le cadrage des messages et le refus d'un message trop long.
+    #[test]
+    fn test_framing() {
+        let message = Message::PromptPoison {
+            options: vec![PoisonType::Speed, PoisonType::Strength],
+            opponent: PlayerView::from(&Player::new(String::from("Bob"), 40, 75, 50)),
+        };
+        let mut buffer = Vec::new();
+        write_message(&mut buffer, &message).unwrap();
+        assert_eq!(buffer[..4], ((buffer.len() - 4) as u32).to_be_bytes());
+        assert!(String::from_utf8_lossy(&buffer[4..]).contains(r#""type":"prompt_poison""#));
+        assert_eq!(read_message(&mut buffer.as_slice()).unwrap(), message);
+
+        let oversized = (MAX_MESSAGE_LEN as u32 + 1).to_be_bytes();
+        assert!(matches!(
+            read_message(&mut oversized.as_slice()),
+            Err(NetError::TooLarge { .. })
+        ));
+        assert!(matches!(
+            read_message(&mut [0u8, 0].as_slice()),
+            Err(NetError::Disconnected)
+        ));
+    }
+
+    /// Vérifie le refus d'un client ne parlant aucune version commune du protocole.
+    #[test]
+    fn test_version_negotiation() {
+        let (host, client) = duplex();
+        let client = thread::spawn(move || {
+            let mut connection = Connection::new(client);
+            connection
+                .send(&Message::Hello {
+                    min_version: PROTOCOL_VERSION + 1,
+                    max_version: PROTOCOL_VERSION + 2,
+                    name: String::from("Bob"),
+                })
+                .unwrap();
+            connection.receive().unwrap()
+        });
+        let err = Connection::new(host).accept("Alice").unwrap_err();
+        assert!(matches!(err, NetError::Incompatible { .. }));
+        assert!(matches!(client.join().unwrap(), Message::Rejected { .. }));
+    }
+
+    /// Joue une partie complète entre l'hôte et un joueur distant piloté par un bot.
+    #[test]
+    fn test_remote_game() {
+        let (host, client) = duplex();
+        let client = thread::spawn(move || {
+            let mut connection = Connection::new(client);
+            assert_eq!(connection.join("Bob").unwrap(), "Alice");
+            let mut bot = RandomBot::new(StdRng::seed_from_u64(2));
+            let mut console = ScriptedConsole::default();
+            let result =
+                run_client(&mut connection, &mut bot, &mut console, &CancelToken::new()).unwrap();
+            (result, console.output)
+        });
+        let mut game = host_game(host);
+        let result = game.run().unwrap();
+        let (export, output) = client.join().unwrap();
+        let export = export.unwrap();
+        assert_eq!(export.players, ["Alice", "Bob"]);
+        assert_eq!(export.outcome, Outcome::from(result));
+        assert_eq!(export.rounds, game.round - 1);
+        assert!(output.contains("Manche 1"), "{output}");
+        assert!(game.history.iter().any(|turn| turn.player == 1));
+    }
+
+    /// Vérifie qu'une déconnexion en cours de manche fait abandonner le joueur distant.
+    #[test]
+    fn test_disconnect_forfeits() {
+        let (host, client) = duplex();
+        let client = thread::spawn(move || {
+            let mut connection = Connection::new(client);
+            connection.join("Bob").unwrap();
+            // Le client se déconnecte au premier arrêt du compteur demandé.
+            loop {
+                match connection.receive().unwrap() {
+                    Message::Acknowledge { .. } => connection.send(&Message::Acknowledged).unwrap(),
+                    Message::PromptStop { .. } => break,
+                    _ => {}
+                }
+            }
+        });
+        let mut game = host_game(host);
+        let outcome = game.run().unwrap();
+        client.join().unwrap();
+        assert_eq!(
+            outcome,
+            GameResult::Forfeit {
+                forfeited: PlayerId(1),
+                winner: PlayerId(0),
+            }
+        );
+    }
+}
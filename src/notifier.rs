@@ -0,0 +1,148 @@
+//! Module de notification du résultat des parties à un service externe.
+//!
+//! Le [`NotifierObserver`] est un [`GameObserver`] qui envoie, à la fin de chaque partie, le résultat
+//! exporté ([`GameExport`]) en JSON par une requête HTTP `POST`. Un échec (service injoignable, délai
+//! dépassé, statut d'erreur) n'interrompt jamais la partie : il est seulement signalé par un
+//! avertissement. Ce module n'est compilé qu'avec la fonctionnalité cargo `http`.
+
+use std::time::Duration;
+
+use ureq::Agent;
+
+use crate::export::GameExport;
+use crate::messages::Msg;
+use crate::observer::{GameEvent, GameObserver};
+use crate::tr;
+
+/// Délai maximal d'une notification, connexion comprise.
+pub const NOTIFY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Observateur envoyant le résultat de chaque partie à une URL.
+#[derive(Debug)]
+pub struct NotifierObserver {
+    /// URL recevant les résultats.
+    url: String,
+    /// Client HTTP, limité à [`NOTIFY_TIMEOUT`].
+    agent: Agent,
+    /// Noms des joueurs de la partie en cours.
+    players: Vec<String>,
+}
+
+impl NotifierObserver {
+    /// Crée un observateur notifiant l'URL donnée.
+    pub fn new(url: impl Into<String>) -> Self {
+        let agent = Agent::config_builder()
+            .timeout_global(Some(NOTIFY_TIMEOUT))
+            .build()
+            .new_agent();
+        NotifierObserver {
+            url: url.into(),
+            agent,
+            players: Vec::new(),
+        }
+    }
+
+    /// Envoie un résultat à l'URL de l'observateur.
+    ///
+    /// Retourne une erreur si le service est injoignable, ne répond pas à temps ou répond par un
+    /// statut d'erreur.
+    pub fn send(&self, export: &GameExport) -> Result<(), ureq::Error> {
+        self.agent
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .send(export.to_json())?;
+        Ok(())
+    }
+}
+
+impl GameObserver for NotifierObserver {
+    fn on_event(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::GameStarted { players, .. } => self.players = players.clone(),
+            GameEvent::GameEnded { result, rounds, .. } => {
+                let export = GameExport::new(self.players.clone(), *result, *rounds);
+                if let Err(err) = self.send(&export) {
+                    eprintln!("{}", tr!(Msg::NotifyFailed, url = self.url, error = err));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use crate::game::GameResult;
+    use crate::player::PlayerId;
+
+    /// Démarre un serveur local acceptant une requête, et retourne son URL et le corps reçu.
+    fn mock_server() -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/results", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    length = value.trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                .unwrap();
+            String::from_utf8(body).unwrap()
+        });
+        (url, handle)
+    }
+
+    /// Vérifie que la fin de partie envoie le résultat exporté au serveur.
+    #[test]
+    fn test_notifier_posts_result() {
+        let (url, server) = mock_server();
+        let mut notifier = NotifierObserver::new(url);
+        notifier.on_event(&GameEvent::GameStarted {
+            players: vec![String::from("Alice"), String::from("Bob")],
+            objectifs: 5,
+        });
+        notifier.on_event(&GameEvent::GameEnded {
+            result: GameResult::Victory {
+                winner: PlayerId(0),
+            },
+            winner: Some(String::from("Alice")),
+            rounds: 6,
+        });
+        let body = server.join().unwrap();
+        let export: GameExport = serde_json::from_str(&body).unwrap();
+        assert_eq!(export.winner.as_deref(), Some("Alice"));
+        assert_eq!(export.rounds, 6);
+    }
+
+    /// Vérifie qu'un service injoignable produit une erreur sans bloquer la partie.
+    #[test]
+    fn test_notifier_unreachable() {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let notifier = NotifierObserver::new(format!("http://127.0.0.1:{port}/"));
+        let export = GameExport::new(vec![String::from("Alice")], GameResult::Draw, 1);
+        assert!(notifier.send(&export).is_err());
+    }
+}
@@ -34,7 +34,43 @@ impl Objectives {
         let mut rng = rand::rng();
         (0..n).map(|_| rng.random_range(0..=100)).collect()
     }
-    
+
+    /// Génère un vecteur d'objectifs favorisés par la chance du joueur.
+    ///
+    /// Chaque objectif est tiré `1 + luck / 5` fois et la valeur la plus proche de 50 est conservée :
+    /// plus la chance est élevée, plus les objectifs se concentrent dans la zone confortable du milieu.
+    /// Avec une chance nulle, le tirage est identique à [`Objectives::generate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Le nombre d'objectifs à générer.
+    /// * `luck` - La chance du joueur (entre 0 et 20).
+    /// * `rng` - Le générateur aléatoire à utiliser.
+    ///
+    /// # Retour
+    ///
+    /// Retourne un vecteur de `u32` contenant les objectifs générés.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::objectives::Objectives;
+    ///
+    /// let objs = Objectives::generate_lucky(5, 20, &mut rand::rng());
+    /// assert_eq!(objs.len(), 5);
+    /// ```
+    pub fn generate_lucky<R: Rng + ?Sized>(n: usize, luck: u32, rng: &mut R) -> Vec<u32> {
+        let draws = 1 + luck / 5;
+        (0..n)
+            .map(|_| {
+                (0..draws)
+                    .map(|_| rng.random_range(0..=100))
+                    .min_by_key(|value: &u32| value.abs_diff(50))
+                    .unwrap_or(50)
+            })
+            .collect()
+    }
+
     /// Génère une `HashMap` associant une touche à un objectif.
     ///
     /// Chaque clé est une lettre aléatoire et la valeur correspondante est un objectif aléatoire entre 0 et 100.
@@ -67,6 +103,8 @@ impl Objectives {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
 
     /// Vérifie que la génération d'un vecteur d'objectifs fonctionne correctement.
     #[test]
@@ -85,4 +123,21 @@ mod tests {
         // Le nombre d'éléments peut être inférieur à 5 si des clés se chevauchent.
         assert!(map.len() <= 5);
     }
+
+    /// Vérifie sur de nombreux tirages qu'une chance élevée rapproche les objectifs de 50.
+    #[test]
+    fn test_generate_lucky_bias() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mean_distance = |luck: u32, rng: &mut StdRng| {
+            let objs = Objectives::generate_lucky(5000, luck, rng);
+            assert!(objs.iter().all(|&obj| obj <= 100));
+            objs.iter().map(|obj| obj.abs_diff(50)).sum::<u32>() as f64 / objs.len() as f64
+        };
+        let unlucky = mean_distance(0, &mut rng);
+        let lucky = mean_distance(10, &mut rng);
+        let luckiest = mean_distance(20, &mut rng);
+        assert!((unlucky - 25.0).abs() < 2.0, "{unlucky}");
+        assert!(lucky < unlucky - 5.0, "{lucky} / {unlucky}");
+        assert!(luckiest < lucky, "{luckiest} / {lucky}");
+    }
 }
@@ -0,0 +1,203 @@
+//! Module des événements de la partie et de leurs observateurs.
+//!
+//! La partie signale chacune de ses étapes (début de manche, objectifs, arrêts du compteur, dégâts,
+//! poison, résultat) sous forme de [`GameEvent`] aux [`GameObserver`] qui lui sont associés, par
+//! exemple le journal de partie ([`GameLog`](crate::game_log::GameLog)). Les observateurs reçoivent
+//! tous les événements, quel que soit le niveau de détail de l'affichage.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::game::GameResult;
+use crate::player::PlayerSnapshot;
+use crate::poison::PoisonType;
+use crate::replay::StopRecord;
+
+/// Étape du déroulement d'une partie.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameEvent {
+    /// La partie commence.
+    GameStarted {
+        /// Noms des joueurs.
+        players: Vec<String>,
+        /// Nombre d'objectifs par tour.
+        objectifs: usize,
+    },
+    /// Une manche commence.
+    RoundStarted {
+        /// Numéro de la manche.
+        round: u32,
+        /// Caractéristiques des joueurs au début de la manche.
+        players: Vec<PlayerSnapshot>,
+    },
+    /// Les objectifs du tour d'un joueur ont été tirés.
+    ObjectivesDrawn {
+        /// Nom du joueur.
+        player: String,
+        /// Objectifs du tour.
+        objectives: Vec<u32>,
+    },
+    /// Un joueur a arrêté le compteur sur un objectif.
+    CounterStopped {
+        /// Nom du joueur.
+        player: String,
+        /// Détail de l'arrêt.
+        stop: StopRecord,
+    },
+    /// Un joueur a terminé son tour.
+    TurnEnded {
+        /// Nom du joueur.
+        player: String,
+        /// Score moyen du tour.
+        average: u32,
+    },
+    /// La manche se termine sur une égalité de scores : elle est nulle.
+    RoundTied {
+        /// Score moyen commun aux joueurs.
+        score: u32,
+        /// Nombre de manches nulles consécutives, celle-ci comprise.
+        consecutive: u32,
+    },
+    /// Le perdant de la manche a subi des dégâts.
+    DamageDealt {
+        /// Nom du gagnant de la manche.
+        winner: String,
+        /// Nom du perdant de la manche.
+        loser: String,
+        /// Dégâts retirés à la vitalité.
+        damage: u32,
+        /// Dégâts absorbés par l'armure.
+        absorbed: u32,
+        /// Vitalité restante du perdant.
+        vitality: u32,
+    },
+    /// Le gagnant de la manche est frappé par la malédiction du vainqueur.
+    WinnersCurse {
+        /// Nom du joueur maudit.
+        player: String,
+        /// Écart de score moyen avec lequel il a remporté la manche.
+        margin: u32,
+        /// Points de vitesse effective perdus.
+        speed: u32,
+        /// Manche pendant laquelle il est ralenti.
+        round: u32,
+    },
+    /// Un poison a été appliqué au perdant.
+    PoisonApplied {
+        /// Nom du joueur empoisonné.
+        player: String,
+        /// Poison appliqué.
+        poison: PoisonType,
+    },
+    /// Le perdant a contré le poison.
+    PoisonCountered {
+        /// Nom du joueur.
+        player: String,
+        /// Dégâts supplémentaires subis.
+        extra_damage: u32,
+        /// Vitalité restante du joueur.
+        vitality: u32,
+    },
+    /// Une manche se termine.
+    RoundEnded {
+        /// Numéro de la manche.
+        round: u32,
+    },
+    /// La partie se termine.
+    GameEnded {
+        /// Issue de la partie.
+        result: GameResult,
+        /// Nom du vainqueur, le cas échéant.
+        winner: Option<String>,
+        /// Nombre de manches terminées.
+        rounds: u32,
+    },
+}
+
+impl fmt::Display for GameEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameEvent::GameStarted { players, objectifs } => write!(
+                f,
+                "Début de partie : {} ({objectifs} objectifs par tour)",
+                players.join(" contre ")
+            ),
+            GameEvent::RoundStarted { round, .. } => write!(f, "Manche {round}"),
+            GameEvent::ObjectivesDrawn { player, objectives } => {
+                write!(f, "Objectifs de {player} : {objectives:?}")
+            }
+            GameEvent::CounterStopped { player, stop } => write!(
+                f,
+                "Arrêt de {player} : objectif {}, compteur {}, miss {}, score {}",
+                stop.objective, stop.counter, stop.miss, stop.score
+            ),
+            GameEvent::TurnEnded { player, average } => {
+                write!(f, "Fin du tour de {player} : score moyen {average}")
+            }
+            GameEvent::RoundTied { score, consecutive } => write!(
+                f,
+                "Égalité à {score}, aucune pénalité ({consecutive} manche(s) nulle(s) de suite)"
+            ),
+            GameEvent::DamageDealt {
+                winner,
+                loser,
+                damage,
+                absorbed,
+                vitality,
+            } => write!(
+                f,
+                "{winner} gagne la manche : {loser} perd {damage} points de vitalité \
+                 ({absorbed} absorbés par l'armure, vitalité restante {vitality})"
+            ),
+            GameEvent::WinnersCurse {
+                player,
+                margin,
+                speed,
+                round,
+            } => write!(
+                f,
+                "Malédiction du vainqueur : {player} l'emporte de {margin} points et perd {speed} \
+                 points de vitesse pendant la manche {round}"
+            ),
+            GameEvent::PoisonApplied { player, poison } => {
+                write!(f, "Poison appliqué à {player} : {}", poison.label())
+            }
+            GameEvent::PoisonCountered {
+                player,
+                extra_damage,
+                vitality,
+            } => write!(
+                f,
+                "{player} contre le poison : {extra_damage} points de vitalité supplémentaires \
+                 (vitalité restante {vitality})"
+            ),
+            GameEvent::RoundEnded { round } => write!(f, "Fin de la manche {round}"),
+            GameEvent::GameEnded {
+                result,
+                winner,
+                rounds,
+            } => {
+                let outcome = match (result, winner) {
+                    (GameResult::Interrupted, _) => String::from("partie interrompue"),
+                    (_, Some(winner)) => format!("vainqueur {winner}"),
+                    (_, None) => String::from("match nul"),
+                };
+                write!(f, "Fin de partie après {rounds} manche(s) : {outcome}")
+            }
+        }
+    }
+}
+
+/// Observateur des événements d'une partie.
+pub trait GameObserver {
+    /// Reçoit un événement de la partie, dans l'ordre du déroulement.
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+/// Observateur partagé, par exemple entre les parties successives d'une session.
+impl<O: GameObserver + ?Sized> GameObserver for Rc<RefCell<O>> {
+    fn on_event(&mut self, event: &GameEvent) {
+        self.borrow_mut().on_event(event);
+    }
+}
@@ -0,0 +1,122 @@
+//! Module de gestion du niveau de détail de l'affichage.
+//!
+//! Chaque ligne affichée par la partie est associée au niveau de [`Verbosity`] à partir duquel elle
+//! apparaît. L'[`Output`] filtre les lignes selon le niveau choisi (`--quiet`, `--verbose`) avant de
+//! les transmettre à sa [`Console`], ce qui permet aussi de capturer l'affichage dans les tests.
+
+use std::fmt;
+
+use crate::console::Console;
+#[cfg(not(feature = "cli"))]
+use crate::console::NullConsole;
+#[cfg(feature = "cli")]
+use crate::console::StdConsole;
+
+/// Niveaux de détail de l'affichage, du plus discret au plus bavard.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Verbosity {
+    /// Aucun affichage (parties simulées).
+    Silent,
+    /// Résultats des manches et vainqueur uniquement, en plus des invites destinées aux joueurs humains.
+    Quiet,
+    /// Affichage habituel de la partie.
+    #[default]
+    Normal,
+    /// Détail de chaque objectif (écart, score) et durée de chaque arrêt du compteur.
+    Verbose,
+}
+
+/// Destination de l'affichage d'une partie, filtrée selon un niveau de détail.
+pub struct Output {
+    /// Console recevant les lignes affichées.
+    console: Box<dyn Console>,
+    /// Niveau de détail retenu.
+    verbosity: Verbosity,
+}
+
+impl Output {
+    /// Crée une sortie écrivant dans la console donnée.
+    ///
+    /// # Arguments
+    ///
+    /// * `console` - La console recevant les lignes affichées.
+    /// * `verbosity` - Le niveau de détail retenu.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// use dual_game::console::ScriptedConsole;
+    /// use dual_game::output::{Output, Verbosity};
+    ///
+    /// let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+    /// let mut output = Output::new(Box::new(Rc::clone(&console)), Verbosity::Quiet);
+    /// output.line(Verbosity::Quiet, "Alice gagne la manche.");
+    /// output.line(Verbosity::Normal, "## Manche 1 ##");
+    /// assert_eq!(console.borrow().output, "Alice gagne la manche.\n");
+    /// ```
+    pub fn new(console: Box<dyn Console>, verbosity: Verbosity) -> Self {
+        Output { console, verbosity }
+    }
+
+    /// Crée une sortie écrivant sur la sortie standard.
+    #[cfg(feature = "cli")]
+    pub fn stdout(verbosity: Verbosity) -> Self {
+        Output::new(Box::new(StdConsole), verbosity)
+    }
+
+    /// Retourne le niveau de détail retenu.
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    /// Modifie le niveau de détail retenu.
+    pub fn set_verbosity(&mut self, verbosity: Verbosity) {
+        self.verbosity = verbosity;
+    }
+
+    /// Indique si les lignes du niveau donné sont affichées.
+    pub fn shows(&self, level: Verbosity) -> bool {
+        self.verbosity != Verbosity::Silent && level <= self.verbosity
+    }
+
+    /// Affiche une ligne si son niveau est retenu.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Le niveau à partir duquel la ligne apparaît.
+    /// * `line` - Le texte de la ligne, sans retour à la ligne.
+    pub fn line(&mut self, level: Verbosity, line: &str) {
+        if self.shows(level) {
+            self.console.println(line);
+        }
+    }
+
+    /// Affiche un texte sans retour à la ligne si son niveau est retenu, par exemple une image
+    /// d'animation remplaçant la précédente sur la même ligne.
+    pub fn print(&mut self, level: Verbosity, text: &str) {
+        if self.shows(level) {
+            self.console.print(text);
+        }
+    }
+}
+
+impl Default for Output {
+    /// Sortie standard avec la fonctionnalité `cli` ; sans elle, l'affichage est ignoré.
+    fn default() -> Self {
+        #[cfg(feature = "cli")]
+        return Output::stdout(Verbosity::default());
+        #[cfg(not(feature = "cli"))]
+        return Output::new(Box::new(NullConsole), Verbosity::default());
+    }
+}
+
+impl fmt::Debug for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Output")
+            .field("verbosity", &self.verbosity)
+            .finish_non_exhaustive()
+    }
+}
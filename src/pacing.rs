@@ -0,0 +1,26 @@
+//! Module de la cadence des manches.
+//!
+//! Par défaut, chaque tour commence lorsque le joueur appuie sur ENTREE, les objectifs s'enchaînent
+//! sans attente et les manches se suivent sans interruption. Une [`Pacing`] change ce rythme
+//! (voir [`Game::set_pacing`](crate::game::Game::set_pacing)) : un compte à rebours remplace l'attente
+//! du début de tour, une confirmation sépare les manches et une pause suit le résultat de chaque
+//! objectif. Les attentes passent par l'horloge de la partie et les confirmations par les contrôleurs
+//! des joueurs : une partie scriptée rejoue ainsi n'importe quelle cadence sans attendre.
+
+use std::time::Duration;
+
+/// Durée, en secondes, du compte à rebours qui remplace l'attente du début de tour.
+pub const AUTO_CONTINUE_SECONDS: u32 = 3;
+
+/// Cadence des manches d'une partie.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Pacing {
+    /// Remplace l'attente d'ENTREE au début de chaque tour par un compte à rebours de
+    /// [`AUTO_CONTINUE_SECONDS`] secondes (`--auto-continue`).
+    pub auto_continue: bool,
+    /// Demande à chaque joueur de confirmer le passage à la manche suivante
+    /// (`--pause-between-rounds`).
+    pub pause_between_rounds: bool,
+    /// Pause marquée après le résultat de chaque objectif (`--turn-delay`), aucune par défaut.
+    pub turn_delay: Duration,
+}
@@ -0,0 +1,218 @@
+//! Module du panneau épinglé en haut du terminal.
+//!
+//! Le [`FancyRenderer`](crate::renderer::FancyRenderer) y affiche le tableau des scores, mis à jour à
+//! chaque manche sans défiler avec le reste de la partie. Dans un terminal capable (voir
+//! [`PanelMode::detect`]), le [`Panel`] réserve les premières lignes de l'écran en limitant la zone de
+//! défilement à celles du dessous, puis redessine ses lignes en place à l'aide de séquences
+//! d'échappement ANSI. Sinon, il se contente de réimprimer ses lignes à la suite de la partie.
+
+use crate::env::Env;
+
+/// Nombre minimal de lignes du terminal pour y épingler un panneau.
+pub const MIN_PINNED_ROWS: usize = 16;
+
+/// Manière d'afficher le panneau.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum PanelMode {
+    /// Les lignes sont épinglées en haut d'un terminal du nombre de lignes donné.
+    Pinned {
+        /// Nombre de lignes du terminal.
+        rows: usize,
+    },
+    /// Les lignes sont réimprimées à chaque mise à jour.
+    #[default]
+    Reprint,
+}
+
+impl PanelMode {
+    /// Choisit la manière d'afficher le panneau selon le terminal.
+    ///
+    /// Le panneau n'est épinglé que si la sortie est un terminal, que la variable `TERM` le décrit
+    /// (elle est définie et ne vaut pas `dumb`) et qu'il compte au moins [`MIN_PINNED_ROWS`] lignes.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - Les variables d'environnement.
+    /// * `tty` - Indique si la sortie standard est un terminal.
+    /// * `rows` - Le nombre de lignes du terminal, s'il est connu.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use dual_game::panel::PanelMode;
+    ///
+    /// let env = HashMap::from([(String::from("TERM"), String::from("xterm-256color"))]);
+    /// assert_eq!(PanelMode::detect(&env, true, Some(40)), PanelMode::Pinned { rows: 40 });
+    /// assert_eq!(PanelMode::detect(&env, false, Some(40)), PanelMode::Reprint);
+    /// ```
+    pub fn detect(env: &dyn Env, tty: bool, rows: Option<usize>) -> PanelMode {
+        let capable = env
+            .var("TERM")
+            .is_some_and(|term| !term.is_empty() && term != "dumb");
+        match rows {
+            Some(rows) if tty && capable && rows >= MIN_PINNED_ROWS => PanelMode::Pinned { rows },
+            _ => PanelMode::Reprint,
+        }
+    }
+}
+
+/// Retourne le nombre de lignes du terminal, s'il est connu.
+#[cfg(feature = "cli")]
+pub fn terminal_rows() -> Option<usize> {
+    crossterm::terminal::size()
+        .ok()
+        .map(|(_, rows)| usize::from(rows))
+        .filter(|&rows| rows > 0)
+}
+
+/// Panneau de quelques lignes, redessiné à chaque mise à jour.
+///
+/// Les méthodes retournent le texte à écrire sur la sortie, séquences d'échappement comprises, sans
+/// l'écrire elles-mêmes.
+#[derive(Debug, Default)]
+pub struct Panel {
+    /// Manière d'afficher le panneau.
+    mode: PanelMode,
+    /// Nombre de lignes réservées en haut du terminal, 0 tant qu'aucune ne l'est.
+    reserved: usize,
+}
+
+impl Panel {
+    /// Crée un panneau affiché de la manière donnée.
+    pub fn new(mode: PanelMode) -> Self {
+        Panel { mode, reserved: 0 }
+    }
+
+    /// Retourne la manière d'afficher le panneau.
+    pub fn mode(&self) -> PanelMode {
+        self.mode
+    }
+
+    /// Retourne le texte dessinant les lignes données.
+    ///
+    /// Épinglé, le premier dessin réserve autant de lignes en haut du terminal : les lignes suivantes
+    /// défilent en dessous. Les dessins suivants réutilisent cette réservation, leurs lignes en trop
+    /// étant ignorées. Le curseur est rétabli à sa position après chaque dessin.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::panel::{Panel, PanelMode};
+    ///
+    /// let mut panel = Panel::new(PanelMode::Reprint);
+    /// let lines = [String::from("Manche 1"), String::from("Alice 50/50")];
+    /// assert_eq!(panel.draw(&lines), "Manche 1\nAlice 50/50\n");
+    /// ```
+    pub fn draw(&mut self, lines: &[String]) -> String {
+        let PanelMode::Pinned { rows } = self.mode else {
+            return lines.iter().map(|line| format!("{line}\n")).collect();
+        };
+        let mut text = String::new();
+        if self.reserved == 0 {
+            self.reserved = lines.len().min(rows.saturating_sub(1));
+            // Les lignes vides poussent l'affichage existant vers le haut avant la réservation ; le
+            // curseur revient ensuite en bas de la zone de défilement.
+            text.push_str(&"\n".repeat(self.reserved));
+            text.push_str(&format!("\x1b[{};{rows}r\x1b[{rows};1H", self.reserved + 1));
+        }
+        text.push_str("\x1b7");
+        for (index, line) in lines.iter().take(self.reserved).enumerate() {
+            text.push_str(&format!("\x1b[{};1H\x1b[2K{line}", index + 1));
+        }
+        text.push_str("\x1b8");
+        text
+    }
+
+    /// Retourne le texte libérant les lignes réservées, vide si aucune ne l'est : la zone de
+    /// défilement couvre de nouveau tout le terminal et le curseur passe à sa dernière ligne.
+    pub fn release(&mut self) -> String {
+        match self.mode {
+            PanelMode::Pinned { rows } if self.reserved > 0 => {
+                self.reserved = 0;
+                format!("\x1b[r\x1b[{rows};1H")
+            }
+            _ => String::new(),
+        }
+    }
+}
+
+impl Drop for Panel {
+    /// Libère les lignes encore réservées, par exemple si la partie s'est arrêtée sur une erreur, pour
+    /// ne pas laisser le terminal avec une zone de défilement réduite.
+    fn drop(&mut self) {
+        let release = self.release();
+        if !release.is_empty() {
+            print!("{release}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    /// Vérifie le choix de la manière d'afficher le panneau selon le terminal.
+    #[test]
+    fn test_detect() {
+        let term = |value: &str| HashMap::from([(String::from("TERM"), value.to_string())]);
+        let xterm = term("xterm");
+        assert_eq!(
+            PanelMode::detect(&xterm, true, Some(24)),
+            PanelMode::Pinned { rows: 24 }
+        );
+        assert_eq!(PanelMode::detect(&xterm, true, None), PanelMode::Reprint);
+        assert_eq!(
+            PanelMode::detect(&xterm, true, Some(MIN_PINNED_ROWS - 1)),
+            PanelMode::Reprint
+        );
+        assert_eq!(
+            PanelMode::detect(&term("dumb"), true, Some(24)),
+            PanelMode::Reprint
+        );
+        let unset: HashMap<String, String> = HashMap::new();
+        assert_eq!(
+            PanelMode::detect(&unset, true, Some(24)),
+            PanelMode::Reprint
+        );
+    }
+
+    /// Vérifie les séquences d'échappement d'un panneau épinglé : réservation au premier dessin,
+    /// redessin en place ensuite, puis libération.
+    #[test]
+    fn test_pinned_sequences() {
+        let mut panel = Panel::new(PanelMode::Pinned { rows: 24 });
+        let lines = [String::from("Manche 1"), String::from("Alice")];
+        assert_eq!(
+            panel.draw(&lines),
+            "\n\n\x1b[3;24r\x1b[24;1H\x1b7\x1b[1;1H\x1b[2KManche 1\x1b[2;1H\x1b[2KAlice\x1b8"
+        );
+
+        // Les lignes en trop sont ignorées une fois la réservation faite.
+        let lines = [
+            String::from("Manche 2"),
+            String::from("Bob"),
+            String::from("Carol"),
+        ];
+        assert_eq!(
+            panel.draw(&lines),
+            "\x1b7\x1b[1;1H\x1b[2KManche 2\x1b[2;1H\x1b[2KBob\x1b8"
+        );
+
+        assert_eq!(panel.release(), "\x1b[r\x1b[24;1H");
+        assert_eq!(panel.release(), "");
+    }
+
+    /// Vérifie qu'un panneau réimprimé n'écrit aucune séquence d'échappement.
+    #[test]
+    fn test_reprint() {
+        let mut panel = Panel::default();
+        let lines = [String::from("Manche 1"), String::from("Alice")];
+        assert_eq!(panel.draw(&lines), "Manche 1\nAlice\n");
+        assert_eq!(panel.draw(&lines), "Manche 1\nAlice\n");
+        assert_eq!(panel.release(), "");
+    }
+}
@@ -1,109 +1,941 @@
-//! Module définissant la structure et les comportements d'un joueur.
-//!
-//! Ce module fournit la structure [`Player`] ainsi que ses méthodes pour créer un joueur,
-//! afficher ses statistiques et appliquer un effet de poison.
-
-use crate::poison::PoisonType;
-
-/// Représente un joueur avec ses caractéristiques.
-#[derive(Clone, Debug)]
-pub struct Player {
-    /// Nom du joueur.
-    pub name: String,
-    /// Vitalité du joueur.
-    pub vitality: u32,
-    /// Vitesse du joueur.
-    pub speed: u32,
-    /// Force du joueur.
-    pub strength: u32,
-}
-
-impl Player {
-    /// Crée un nouveau joueur.
-    ///
-    /// # Arguments
-    ///
-    /// * `name` - Le nom du joueur.
-    /// * `vitality` - La vitalité initiale du joueur.
-    /// * `speed` - La vitesse du joueur.
-    /// * `strength` - La force du joueur.
-    ///
-    /// # Exemples
-    ///
-    /// ```
-    /// use dual_game::player::Player;
-    ///
-    /// let player = Player::new(String::from("Alice"), 50, 50, 50);
-    /// ```
-    pub fn new(name: String, vitality: u32, speed: u32, strength: u32) -> Self {
-        Player {
-            name,
-            vitality,
-            speed,
-            strength,
-        }
-    }
-
-    /// Affiche les caractéristiques du joueur.
-    ///
-    /// Cette méthode affiche le nom du joueur et ses statistiques (vitality, speed, strength).
-    pub fn display_stats(&self) {
-        println!(
-            "{} (Vitality={}, Speed={}, Strength={})",
-            self.name, self.vitality, self.speed, self.strength
-        );
-    }
-
-    /// Applique un effet de poison sur le joueur.
-    ///
-    /// En fonction du type de poison, la vitesse ou la force du joueur est réduite de 5 points,
-    /// sans descendre en dessous de zéro.
-    ///
-    /// # Arguments
-    ///
-    /// * `poison` - Le type de poison à appliquer.
-    pub fn apply_poison(&mut self, poison: PoisonType) {
-        match poison {
-            PoisonType::Speed => {
-                if self.speed >= 5 {
-                    self.speed -= 5;
-                } else {
-                    self.speed = 0;
-                }
-            }
-            PoisonType::Strength => {
-                if self.strength >= 5 {
-                    self.strength -= 5;
-                } else {
-                    self.strength = 0;
-                }
-            }
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_player_creation() {
-        let player = Player::new(String::from("Test"), 50, 50, 50);
-        assert_eq!(player.name, "Test");
-        assert_eq!(player.vitality, 50);
-    }
-
-    #[test]
-    fn test_poison_application_speed() {
-        let mut player = Player::new(String::from("Test"), 50, 50, 50);
-        player.apply_poison(PoisonType::Speed);
-        assert_eq!(player.speed, 45);
-    }
-
-    #[test]
-    fn test_poison_application_strength() {
-        let mut player = Player::new(String::from("Test"), 50, 50, 50);
-        player.apply_poison(PoisonType::Strength);
-        assert_eq!(player.strength, 45);
-    }
-}
+//! Module définissant la structure et les comportements d'un joueur.
+//!
+//! Ce module fournit la structure [`Player`] ainsi que ses méthodes pour créer un joueur,
+//! afficher ses statistiques et appliquer un effet de poison. La construction recommandée passe
+//! par [`PlayerBuilder`], qui valide les caractéristiques et retourne une [`PlayerConfigError`].
+
+use std::error::Error;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::class::PlayerClass;
+use crate::counter::{MAX_SPEED, MIN_SPEED};
+use crate::messages::Msg;
+use crate::poison::{POISON_AMOUNT, PoisonType};
+use crate::style::{self, Color};
+use crate::tr;
+use unicode_width::UnicodeWidthStr;
+
+/// Nombre de contre-propositions au poison dont dispose un joueur en début de partie.
+pub const DEFAULT_COUNTERS: u32 = 1;
+/// Vitalité par défaut d'un joueur.
+pub const DEFAULT_VITALITY: u32 = 50;
+/// Vitesse par défaut d'un joueur.
+pub const DEFAULT_SPEED: u32 = 75;
+/// Force par défaut d'un joueur.
+pub const DEFAULT_STRENGTH: u32 = 50;
+/// Force maximale acceptée à la création d'un joueur.
+pub const MAX_STRENGTH: u32 = 200;
+
+/// Issue de dégâts infligés à un joueur.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DamageOutcome {
+    /// Dégâts bruts, avant armure.
+    pub raw: u32,
+    /// Dégâts absorbés par l'armure.
+    pub absorbed: u32,
+    /// Dégâts effectivement retirés à la vitalité.
+    pub dealt: u32,
+    /// Indique si ces dégâts ont éliminé le joueur.
+    pub fatal: bool,
+}
+
+/// Endurance par défaut de chaque joueur au début d'une partie, lorsque la règle d'endurance est active.
+pub const DEFAULT_STAMINA: u32 = 3;
+/// Chance maximale d'un joueur.
+pub const MAX_LUCK: u32 = 20;
+/// Longueur maximale, en caractères, du nom d'un joueur.
+pub const MAX_NAME_LEN: usize = 24;
+
+/// Identifiant d'un joueur au sein d'une partie, correspondant à sa position dans la liste des joueurs.
+///
+/// La logique de la partie désigne les joueurs par leur identifiant et jamais par leur nom.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PlayerId(pub usize);
+
+impl PlayerId {
+    /// Retourne la position du joueur dans la liste des joueurs.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Caractéristiques d'un joueur à un instant de la partie, sans son nom.
+///
+/// Contrairement à un clone de [`Player`], un instantané ne copie aucune chaîne : l'historique des
+/// manches en conserve un par joueur et par manche, le nom étant retrouvé à partir de l'identifiant
+/// (voir [`PlayerNames`](crate::replay::PlayerNames)).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerSnapshot {
+    /// Identifiant du joueur.
+    pub id: PlayerId,
+    /// Vitalité du joueur.
+    pub vitality: u32,
+    /// Vitalité maximale du joueur.
+    pub max_vitality: u32,
+    /// Vitesse du joueur.
+    pub speed: u32,
+    /// Force du joueur.
+    pub strength: u32,
+    /// Nombre de poisons subis et non annulés, absent des rediffusions antérieures.
+    #[serde(default)]
+    pub poisons: u32,
+}
+
+/// Représente un joueur avec ses caractéristiques.
+#[derive(Clone, Debug)]
+pub struct Player {
+    /// Nom du joueur.
+    pub name: String,
+    /// Vitalité du joueur, modifiée uniquement via [`Player::take_damage`] et [`Player::heal`]
+    /// en dehors de la crate.
+    pub(crate) vitality: u32,
+    /// Vitalité maximale du joueur, égale à sa vitalité initiale.
+    pub(crate) max_vitality: u32,
+    /// Vitesse du joueur.
+    pub speed: u32,
+    /// Force du joueur.
+    pub strength: u32,
+    /// Nombre de contre-propositions au poison encore disponibles pour la partie.
+    pub counters_remaining: u32,
+    /// Classe de personnage choisie, le cas échéant.
+    pub class: Option<PlayerClass>,
+    /// Armure réduisant d'autant les dégâts de vitalité subis lors d'une manche perdue.
+    pub armor: u32,
+    /// Couleur du nom du joueur dans le terminal, le cas échéant.
+    pub color: Option<Color>,
+    /// Chance du joueur (0 à [`MAX_LUCK`]), rapprochant ses objectifs de 50
+    /// (voir [`Objectives::generate_lucky`](crate::objectives::Objectives::generate_lucky)).
+    pub luck: u32,
+    /// Endurance restante pour la partie en cours (voir [`Player::spend_stamina`]).
+    pub stamina: u32,
+    /// Avatar (emoji ou caractère unique) affiché devant le nom du joueur, le cas échéant.
+    pub avatar: Option<String>,
+    /// Pièces du joueur, dépensées à la boutique entre les manches (voir [`items`](crate::items)).
+    pub coins: u32,
+    /// Poisons subis pendant la partie, du plus ancien au plus récent.
+    pub poisons: Vec<PoisonType>,
+    /// Ralentissement du compteur près de l'objectif, en pourcentage, `None` sans assistance (voir
+    /// [`assisted_delay`](crate::counter::assisted_delay)).
+    pub assist: Option<u32>,
+    /// Rejeux d'objectif en réserve, gagnés en perdant une manche (voir
+    /// [`GameRules::retry`](crate::rules::GameRules::retry)).
+    pub retries: u32,
+}
+
+impl Player {
+    /// Crée un nouveau joueur.
+    ///
+    /// Les caractéristiques ne sont pas validées : préférez [`Player::builder`], qui nomme chaque
+    /// caractéristique et vérifie leurs bornes.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Le nom du joueur.
+    /// * `vitality` - La vitalité initiale du joueur.
+    /// * `speed` - La vitesse du joueur.
+    /// * `strength` - La force du joueur.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::player::Player;
+    ///
+    /// let player = Player::new(String::from("Alice"), 50, 50, 50);
+    /// ```
+    pub fn new(name: String, vitality: u32, speed: u32, strength: u32) -> Self {
+        Player {
+            name,
+            vitality,
+            max_vitality: vitality,
+            speed,
+            strength,
+            counters_remaining: DEFAULT_COUNTERS,
+            class: None,
+            armor: 0,
+            color: None,
+            luck: 0,
+            stamina: 0,
+            avatar: None,
+            coins: 0,
+            poisons: Vec::new(),
+            assist: None,
+            retries: 0,
+        }
+    }
+
+    /// Crée un nouveau joueur à partir des caractéristiques d'une classe prédéfinie.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Le nom du joueur.
+    /// * `class` - La classe dont les caractéristiques sont reprises.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::class::PlayerClass;
+    /// use dual_game::player::Player;
+    ///
+    /// let player = Player::from_class(String::from("Alice"), PlayerClass::Tank);
+    /// assert_eq!(player.vitality(), PlayerClass::Tank.stats().vitality);
+    /// ```
+    pub fn from_class(name: String, class: PlayerClass) -> Self {
+        let stats = class.stats();
+        Player {
+            class: Some(class),
+            armor: stats.armor,
+            ..Player::new(name, stats.vitality, stats.speed, stats.strength)
+        }
+    }
+
+    /// Retourne un [`PlayerBuilder`] pour créer un joueur avec validation des caractéristiques.
+    ///
+    /// Les caractéristiques non renseignées prennent leur valeur par défaut
+    /// ([`DEFAULT_VITALITY`], [`DEFAULT_SPEED`], [`DEFAULT_STRENGTH`]).
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::player::Player;
+    ///
+    /// let player = Player::builder("Alice").vitality(50).speed(75).strength(50).build().unwrap();
+    /// assert_eq!(player.speed, 75);
+    /// ```
+    pub fn builder(name: impl Into<String>) -> PlayerBuilder {
+        PlayerBuilder {
+            name: name.into(),
+            vitality: DEFAULT_VITALITY,
+            speed: DEFAULT_SPEED,
+            strength: DEFAULT_STRENGTH,
+            strength_bonus: 0,
+            armor: 0,
+            class: None,
+            color: None,
+            luck: 0,
+            avatar: None,
+            assist: None,
+        }
+    }
+
+    /// Affiche les caractéristiques du joueur.
+    ///
+    /// Cette méthode affiche le nom du joueur et ses statistiques (vitality, speed, strength).
+    #[cfg(feature = "cli")]
+    #[deprecated(note = "utiliser le formatage `{}` via l'implémentation de `Display`")]
+    pub fn display_stats(&self) {
+        println!("{self}");
+    }
+
+    /// Retourne la vitalité actuelle du joueur.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::player::Player;
+    ///
+    /// let player = Player::new(String::from("Alice"), 50, 50, 50);
+    /// assert_eq!(player.vitality(), 50);
+    /// ```
+    pub fn vitality(&self) -> u32 {
+        self.vitality
+    }
+
+    /// Retourne le nom du joueur précédé de son avatar, coloré selon sa couleur si les couleurs sont
+    /// activées. L'avatar est omis si l'affichage est limité aux caractères ASCII.
+    pub fn colored_name(&self) -> String {
+        self.styled_name(style::is_enabled(), style::is_ascii())
+    }
+
+    /// Retourne le nom du joueur précédé de son avatar, par exemple `🦊 Alice`.
+    ///
+    /// # Arguments
+    ///
+    /// * `colored` - Indique si le nom doit être coloré selon la couleur du joueur.
+    /// * `ascii` - Indique si l'avatar doit être omis pour se limiter aux caractères ASCII.
+    pub fn styled_name(&self, colored: bool, ascii: bool) -> String {
+        let name = style::paint_with(&self.name, self.color, colored);
+        match &self.avatar {
+            Some(avatar) if !ascii => format!("{avatar} {name}"),
+            _ => name,
+        }
+    }
+
+    /// Retourne la largeur d'affichage, en colonnes de terminal, du nom retourné par
+    /// [`Player::styled_name`] (les caractères larges comme les emojis occupent deux colonnes).
+    pub fn name_width(&self, ascii: bool) -> usize {
+        let name = self.name.width();
+        match &self.avatar {
+            Some(avatar) if !ascii => avatar.width() + 1 + name,
+            _ => name,
+        }
+    }
+
+    /// Dépense de l'endurance, sans descendre en dessous de zéro.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - L'endurance à dépenser.
+    ///
+    /// # Retour
+    ///
+    /// Retourne l'endurance effectivement dépensée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::player::Player;
+    ///
+    /// let mut player = Player::new(String::from("Alice"), 50, 50, 50);
+    /// player.stamina = 1;
+    /// assert_eq!(player.spend_stamina(2), 1);
+    /// assert_eq!(player.stamina, 0);
+    /// ```
+    pub fn spend_stamina(&mut self, amount: u32) -> u32 {
+        let spent = amount.min(self.stamina);
+        self.stamina -= spent;
+        spent
+    }
+
+    /// Retourne la vitalité maximale du joueur.
+    pub fn max_vitality(&self) -> u32 {
+        self.max_vitality
+    }
+
+    /// Retourne un instantané des caractéristiques du joueur d'identifiant donné.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::player::{Player, PlayerId};
+    ///
+    /// let mut player = Player::new(String::from("Alice"), 50, 75, 60);
+    /// player.take_damage(20);
+    /// let snapshot = player.snapshot(PlayerId(0));
+    /// assert_eq!((snapshot.vitality, snapshot.max_vitality), (30, 50));
+    /// assert_eq!((snapshot.speed, snapshot.strength), (75, 60));
+    /// ```
+    pub fn snapshot(&self, id: PlayerId) -> PlayerSnapshot {
+        PlayerSnapshot {
+            id,
+            vitality: self.vitality,
+            max_vitality: self.max_vitality,
+            speed: self.speed,
+            strength: self.strength,
+            poisons: self.poisons.len() as u32,
+        }
+    }
+
+    /// Indique si le joueur a encore de la vitalité.
+    pub fn is_alive(&self) -> bool {
+        self.vitality > 0
+    }
+
+    /// Retourne la force effective du joueur après application d'une pénalité temporaire.
+    ///
+    /// La force effective est celle utilisée pour le calcul des scores ; elle ne descend jamais en
+    /// dessous de zéro et la force du joueur n'est pas modifiée.
+    ///
+    /// # Arguments
+    ///
+    /// * `penalty` - La force retirée temporairement (par exemple la fatigue).
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::player::Player;
+    ///
+    /// let player = Player::new(String::from("Alice"), 50, 50, 3);
+    /// assert_eq!(player.effective_strength(2), 1);
+    /// assert_eq!(player.effective_strength(10), 0);
+    /// ```
+    pub fn effective_strength(&self, penalty: u32) -> u32 {
+        self.strength.saturating_sub(penalty)
+    }
+
+    /// Inflige des dégâts de vitalité au joueur après réduction par son armure.
+    ///
+    /// L'armure réduit les dégâts d'une valeur fixe, mais des dégâts non nuls infligent toujours
+    /// au moins 1 point. La vitalité ne descend jamais en dessous de zéro.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Les dégâts bruts, avant armure.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le détail des dégâts subis.
+    pub fn take_damage(&mut self, amount: u32) -> DamageOutcome {
+        let reduced = if amount == 0 {
+            0
+        } else {
+            amount.saturating_sub(self.armor).max(1)
+        };
+        DamageOutcome {
+            raw: amount,
+            absorbed: amount - reduced,
+            ..self.take_piercing_damage(reduced)
+        }
+    }
+
+    /// Inflige des dégâts de vitalité au joueur en ignorant son armure.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Les dégâts à infliger.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le détail des dégâts subis.
+    pub fn take_piercing_damage(&mut self, amount: u32) -> DamageOutcome {
+        let dealt = amount.min(self.vitality);
+        self.vitality -= dealt;
+        DamageOutcome {
+            raw: amount,
+            absorbed: 0,
+            dealt,
+            fatal: dealt > 0 && self.vitality == 0,
+        }
+    }
+
+    /// Rend de la vitalité au joueur, sans dépasser sa vitalité maximale.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - La vitalité à rendre.
+    ///
+    /// # Retour
+    ///
+    /// Retourne la vitalité effectivement rendue.
+    pub fn heal(&mut self, amount: u32) -> u32 {
+        let healed = amount.min(self.max_vitality.saturating_sub(self.vitality));
+        self.vitality += healed;
+        healed
+    }
+
+    /// Applique un effet de poison sur le joueur.
+    ///
+    /// En fonction du type de poison, la vitesse ou la force du joueur est réduite de [`POISON_AMOUNT`] points,
+    /// sans descendre en dessous de zéro. Le poison est retenu dans [`Player::poisons`].
+    ///
+    /// # Arguments
+    ///
+    /// * `poison` - Le type de poison à appliquer.
+    pub fn apply_poison(&mut self, poison: PoisonType) {
+        match poison {
+            PoisonType::Speed => self.speed = self.speed.saturating_sub(POISON_AMOUNT),
+            PoisonType::Strength => self.strength = self.strength.saturating_sub(POISON_AMOUNT),
+        }
+        self.poisons.push(poison);
+    }
+
+    /// Annule le dernier poison subi, en rendant au joueur les [`POISON_AMOUNT`] points retirés.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le poison annulé, ou `None` si le joueur n'a subi aucun poison.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::player::Player;
+    /// use dual_game::poison::PoisonType;
+    ///
+    /// let mut player = Player::new(String::from("Alice"), 50, 75, 50);
+    /// player.apply_poison(PoisonType::Strength);
+    /// assert_eq!(player.cure_poison(), Some(PoisonType::Strength));
+    /// assert_eq!(player.strength, 50);
+    /// assert_eq!(player.cure_poison(), None);
+    /// ```
+    pub fn cure_poison(&mut self) -> Option<PoisonType> {
+        let poison = self.poisons.pop()?;
+        match poison {
+            PoisonType::Speed => self.speed = self.speed.saturating_add(POISON_AMOUNT),
+            PoisonType::Strength => self.strength = self.strength.saturating_add(POISON_AMOUNT),
+        }
+        Some(poison)
+    }
+}
+
+/// Indique si un avatar est un emoji ou un caractère unique : il doit occuper au plus deux colonnes
+/// de terminal et compter au plus quatre caractères (emoji composés d'un sélecteur de variante, etc.).
+fn is_valid_avatar(avatar: &str) -> bool {
+    let chars = avatar.chars().count();
+    (1..=4).contains(&chars) && (1..=2).contains(&avatar.width())
+}
+
+/// Retourne le nom par défaut du joueur d'index donné (« Joueur 1 », « Joueur 2 »…), dans la langue de
+/// l'affichage.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::player::default_name;
+///
+/// assert_eq!(default_name(1), "Joueur 2");
+/// ```
+pub fn default_name(index: usize) -> String {
+    tr!(Msg::DefaultPlayerName, number = index + 1)
+}
+
+/// Distingue les joueurs portant le même nom en leur ajoutant un suffixe numéroté.
+///
+/// Par exemple, deux joueurs nommés « Alex » deviennent « Alex (1) » et « Alex (2) ».
+///
+/// # Arguments
+///
+/// * `players` - Les joueurs à renommer si nécessaire.
+///
+/// # Retour
+///
+/// Retourne la liste des noms en double qui ont été modifiés.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::player::{Player, disambiguate_names};
+///
+/// let mut players = vec![
+///     Player::new(String::from("Alex"), 50, 50, 50),
+///     Player::new(String::from("Alex"), 50, 50, 50),
+/// ];
+/// assert_eq!(disambiguate_names(&mut players), vec![String::from("Alex")]);
+/// assert_eq!(players[1].name, "Alex (2)");
+/// ```
+pub fn disambiguate_names(players: &mut [Player]) -> Vec<String> {
+    let mut duplicates: Vec<String> = Vec::new();
+    for (i, player) in players.iter().enumerate() {
+        if !duplicates.contains(&player.name)
+            && players[i + 1..]
+                .iter()
+                .any(|other| other.name == player.name)
+        {
+            duplicates.push(player.name.clone());
+        }
+    }
+    for name in &duplicates {
+        let homonyms = players.iter_mut().filter(|player| player.name == *name);
+        for (n, player) in homonyms.enumerate() {
+            player.name = format!("{name} ({})", n + 1);
+        }
+    }
+    duplicates
+}
+
+impl fmt::Display for Player {
+    /// Formate le nom du joueur, sa classe éventuelle et ses statistiques,
+    /// par exemple `Alice [Tank] (Vitality=70, Speed=90, Strength=47, Armor=3)`.
+    /// L'armure, la chance et l'endurance ne sont affichées que si elles sont non nulles.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.colored_name())?;
+        if let Some(class) = self.class {
+            write!(f, " [{class}]")?;
+        }
+        write!(
+            f,
+            " (Vitality={}, Speed={}, Strength={}",
+            self.vitality, self.speed, self.strength
+        )?;
+        if self.armor > 0 {
+            write!(f, ", Armor={}", self.armor)?;
+        }
+        if self.luck > 0 {
+            write!(f, ", Luck={}", self.luck)?;
+        }
+        if self.stamina > 0 {
+            write!(f, ", Stamina={}", self.stamina)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Erreur de configuration détectée lors de la construction d'un joueur.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlayerConfigError {
+    /// Le nom du joueur est vide.
+    EmptyName,
+    /// Le nom du joueur dépasse [`MAX_NAME_LEN`] caractères.
+    NameTooLong(String),
+    /// La vitalité initiale doit être d'au moins 1.
+    InvalidVitality(u32),
+    /// La vitesse est hors de l'intervalle supporté par le compteur.
+    SpeedOutOfRange(u32),
+    /// La force dépasse [`MAX_STRENGTH`].
+    StrengthTooHigh(u32),
+    /// La chance dépasse [`MAX_LUCK`].
+    LuckTooHigh(u32),
+    /// L'avatar n'est pas un emoji ou un caractère unique.
+    InvalidAvatar(String),
+}
+
+impl fmt::Display for PlayerConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlayerConfigError::EmptyName => write!(f, "{}", tr!(Msg::EmptyName)),
+            PlayerConfigError::NameTooLong(name) => write!(
+                f,
+                "{}",
+                tr!(Msg::NameTooLong, name = name, max = MAX_NAME_LEN)
+            ),
+            PlayerConfigError::InvalidVitality(v) => {
+                write!(f, "{}", tr!(Msg::InvalidVitality, value = v))
+            }
+            PlayerConfigError::SpeedOutOfRange(v) => write!(
+                f,
+                "{}",
+                tr!(
+                    Msg::SpeedOutOfRange,
+                    value = v,
+                    min = MIN_SPEED,
+                    max = MAX_SPEED
+                )
+            ),
+            PlayerConfigError::StrengthTooHigh(v) => {
+                write!(
+                    f,
+                    "{}",
+                    tr!(Msg::StrengthTooHigh, value = v, max = MAX_STRENGTH)
+                )
+            }
+            PlayerConfigError::InvalidAvatar(avatar) => {
+                write!(f, "{}", tr!(Msg::InvalidAvatar, avatar = avatar))
+            }
+            PlayerConfigError::LuckTooHigh(v) => {
+                write!(f, "{}", tr!(Msg::LuckTooHigh, value = v, max = MAX_LUCK))
+            }
+        }
+    }
+}
+
+impl Error for PlayerConfigError {}
+
+/// Constructeur de [`Player`] nommant chaque caractéristique et validant leurs bornes.
+#[derive(Clone, Debug)]
+pub struct PlayerBuilder {
+    name: String,
+    vitality: u32,
+    speed: u32,
+    strength: u32,
+    strength_bonus: u32,
+    armor: u32,
+    class: Option<PlayerClass>,
+    color: Option<Color>,
+    luck: u32,
+    avatar: Option<String>,
+    assist: Option<u32>,
+}
+
+impl PlayerBuilder {
+    /// Définit la vitalité initiale du joueur.
+    pub fn vitality(mut self, vitality: u32) -> Self {
+        self.vitality = vitality;
+        self
+    }
+
+    /// Définit la vitesse du joueur (délai en millisecondes entre deux incrémentations du compteur).
+    pub fn speed(mut self, speed: u32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Définit la force du joueur.
+    pub fn strength(mut self, strength: u32) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    /// Définit l'armure du joueur.
+    pub fn armor(mut self, armor: u32) -> Self {
+        self.armor = armor;
+        self
+    }
+
+    /// Définit un bonus de force (progression du profil) ajouté à la force de base, y compris
+    /// celle d'une classe. La force obtenue est plafonnée à [`MAX_STRENGTH`].
+    pub fn strength_bonus(mut self, bonus: u32) -> Self {
+        self.strength_bonus = bonus;
+        self
+    }
+
+    /// Applique les caractéristiques d'une classe prédéfinie, en remplaçant celles déjà définies.
+    pub fn class(mut self, class: PlayerClass) -> Self {
+        let stats = class.stats();
+        self.vitality = stats.vitality;
+        self.speed = stats.speed;
+        self.strength = stats.strength;
+        self.armor = stats.armor;
+        self.class = Some(class);
+        self
+    }
+
+    /// Définit la chance du joueur.
+    pub fn luck(mut self, luck: u32) -> Self {
+        self.luck = luck;
+        self
+    }
+
+    /// Définit l'avatar (emoji ou caractère unique) affiché devant le nom du joueur.
+    pub fn avatar(mut self, avatar: impl Into<String>) -> Self {
+        self.avatar = Some(avatar.into());
+        self
+    }
+
+    /// Active l'assistance, ralentissant le compteur du pourcentage donné près de l'objectif.
+    pub fn assist(mut self, percent: u32) -> Self {
+        self.assist = Some(percent);
+        self
+    }
+
+    /// Définit la couleur du nom du joueur.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Valide les caractéristiques et construit le joueur.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le [`Player`] construit ou la première [`PlayerConfigError`] rencontrée.
+    pub fn build(self) -> Result<Player, PlayerConfigError> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(PlayerConfigError::EmptyName);
+        }
+        if name.chars().count() > MAX_NAME_LEN {
+            return Err(PlayerConfigError::NameTooLong(name.to_string()));
+        }
+        if self.vitality < 1 {
+            return Err(PlayerConfigError::InvalidVitality(self.vitality));
+        }
+        if !(MIN_SPEED..=MAX_SPEED).contains(&self.speed) {
+            return Err(PlayerConfigError::SpeedOutOfRange(self.speed));
+        }
+        if self.strength > MAX_STRENGTH {
+            return Err(PlayerConfigError::StrengthTooHigh(self.strength));
+        }
+        if self.luck > MAX_LUCK {
+            return Err(PlayerConfigError::LuckTooHigh(self.luck));
+        }
+        let avatar = self.avatar.map(|avatar| avatar.trim().to_string());
+        if let Some(avatar) = &avatar
+            && !is_valid_avatar(avatar)
+        {
+            return Err(PlayerConfigError::InvalidAvatar(avatar.clone()));
+        }
+        let strength = self
+            .strength
+            .saturating_add(self.strength_bonus)
+            .min(MAX_STRENGTH);
+        Ok(Player {
+            class: self.class,
+            armor: self.armor,
+            color: self.color,
+            luck: self.luck,
+            avatar,
+            assist: self.assist,
+            ..Player::new(name.to_string(), self.vitality, self.speed, strength)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_player_creation() {
+        let player = Player::new(String::from("Test"), 50, 50, 50);
+        assert_eq!(player.name, "Test");
+        assert_eq!(player.vitality, 50);
+        assert_eq!(player.counters_remaining, DEFAULT_COUNTERS);
+    }
+
+    #[test]
+    fn test_player_display() {
+        let player = Player::new(String::from("Alice"), 50, 75, 50);
+        assert_eq!(
+            player.to_string(),
+            "Alice (Vitality=50, Speed=75, Strength=50)"
+        );
+        let tank = Player::from_class(String::from("Bob"), PlayerClass::Tank);
+        assert_eq!(
+            tank.to_string(),
+            "Bob [Tank] (Vitality=60, Speed=90, Strength=47, Armor=3)"
+        );
+        let lucky = Player::builder("Eve").luck(12).build().unwrap();
+        assert_eq!(
+            lucky.to_string(),
+            "Eve (Vitality=50, Speed=75, Strength=50, Luck=12)"
+        );
+    }
+
+    #[test]
+    fn test_builder_defaults_and_named_stats() {
+        let player = Player::builder("Alice").speed(90).build().unwrap();
+        assert_eq!(player.name, "Alice");
+        assert_eq!(player.vitality, DEFAULT_VITALITY);
+        assert_eq!(player.speed, 90);
+        assert_eq!(player.strength, DEFAULT_STRENGTH);
+    }
+
+    #[test]
+    fn test_builder_class_overrides_stats() {
+        let player = Player::builder("Alice")
+            .vitality(10)
+            .class(PlayerClass::Tank)
+            .build()
+            .unwrap();
+        assert_eq!(player.vitality, PlayerClass::Tank.stats().vitality);
+        assert_eq!(player.class, Some(PlayerClass::Tank));
+
+        let boosted = Player::builder("Alice")
+            .strength_bonus(4)
+            .class(PlayerClass::Tank)
+            .build()
+            .unwrap();
+        assert_eq!(boosted.strength, PlayerClass::Tank.stats().strength + 4);
+
+        // Un bonus démesuré est plafonné, sans dépassement de capacité.
+        let capped = Player::builder("Alice")
+            .strength_bonus(u32::MAX)
+            .build()
+            .unwrap();
+        assert_eq!(capped.strength, MAX_STRENGTH);
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        assert_eq!(
+            Player::builder("  ").build().unwrap_err(),
+            PlayerConfigError::EmptyName
+        );
+        assert_eq!(
+            Player::builder("").build().unwrap_err(),
+            PlayerConfigError::EmptyName
+        );
+        assert_eq!(
+            Player::builder("Abcdefghijklmnopqrstuvwxyz")
+                .build()
+                .unwrap_err(),
+            PlayerConfigError::NameTooLong(String::from("Abcdefghijklmnopqrstuvwxyz"))
+        );
+        assert_eq!(Player::builder("  Alice ").build().unwrap().name, "Alice");
+        assert_eq!(
+            Player::builder("Alice").vitality(0).build().unwrap_err(),
+            PlayerConfigError::InvalidVitality(0)
+        );
+        assert_eq!(
+            Player::builder("Alice")
+                .speed(MAX_SPEED + 1)
+                .build()
+                .unwrap_err(),
+            PlayerConfigError::SpeedOutOfRange(MAX_SPEED + 1)
+        );
+        assert_eq!(
+            Player::builder("Alice").strength(201).build().unwrap_err(),
+            PlayerConfigError::StrengthTooHigh(201)
+        );
+        assert_eq!(
+            Player::builder("Alice").luck(21).build().unwrap_err(),
+            PlayerConfigError::LuckTooHigh(21)
+        );
+    }
+
+    /// Vérifie la validation de l'avatar et son affichage devant le nom.
+    #[test]
+    fn test_avatar() {
+        let fox = Player::builder("Alice").avatar("🦊").build().unwrap();
+        assert_eq!(fox.styled_name(false, false), "🦊 Alice");
+        assert_eq!(fox.name_width(false), 8);
+        assert_eq!(fox.styled_name(false, true), "Alice");
+        assert_eq!(fox.name_width(true), 5);
+        assert!(Player::builder("Alice").avatar("A").build().is_ok());
+        assert_eq!(
+            Player::builder("Alice").avatar("ABC").build().unwrap_err(),
+            PlayerConfigError::InvalidAvatar(String::from("ABC"))
+        );
+        assert!(Player::builder("Alice").avatar(" ").build().is_err());
+    }
+
+    /// Vérifie que seuls les noms en double reçoivent un suffixe.
+    #[test]
+    fn test_disambiguate_names() {
+        let mut players = vec![
+            Player::new(String::from("Alex"), 50, 50, 50),
+            Player::new(String::from("Alex"), 50, 50, 50),
+        ];
+        assert_eq!(disambiguate_names(&mut players), vec![String::from("Alex")]);
+        assert_eq!(players[0].name, "Alex (1)");
+        assert_eq!(players[1].name, "Alex (2)");
+
+        let mut players = vec![
+            Player::new(String::from("Alex"), 50, 50, 50),
+            Player::new(String::from("Sam"), 50, 50, 50),
+        ];
+        assert!(disambiguate_names(&mut players).is_empty());
+        assert_eq!(players[0].name, "Alex");
+        assert_eq!(players[1].name, "Sam");
+    }
+
+    #[test]
+    fn test_take_damage_with_armor() {
+        let mut player = Player::new(String::from("Test"), 50, 50, 50);
+        assert_eq!(player.take_damage(18).dealt, 18);
+        assert_eq!(player.vitality(), 32);
+
+        player.armor = 6;
+        assert_eq!(
+            player.take_damage(18),
+            DamageOutcome {
+                raw: 18,
+                absorbed: 6,
+                dealt: 12,
+                fatal: false
+            }
+        );
+        assert_eq!(player.take_damage(6).dealt, 1);
+        assert_eq!(player.take_damage(4).dealt, 1);
+        assert_eq!(player.take_damage(0).dealt, 0);
+        assert_eq!(player.vitality(), 18);
+
+        let outcome = player.take_damage(100);
+        assert_eq!((outcome.dealt, outcome.fatal), (18, true));
+        assert!(!player.is_alive());
+    }
+
+    #[test]
+    fn test_piercing_damage_ignores_armor() {
+        let mut player = Player::new(String::from("Test"), 20, 50, 50);
+        player.armor = 10;
+        assert_eq!(player.take_piercing_damage(8).dealt, 8);
+        assert_eq!(player.vitality(), 12);
+        assert!(player.is_alive());
+    }
+
+    #[test]
+    fn test_heal_clamps_to_max_vitality() {
+        let mut player = Player::new(String::from("Test"), 50, 50, 50);
+        assert_eq!(player.heal(10), 0);
+        player.take_damage(15);
+        assert_eq!(player.heal(10), 10);
+        assert_eq!(player.heal(10), 5);
+        assert_eq!(player.vitality(), player.max_vitality());
+    }
+
+    #[test]
+    fn test_poison_application_speed() {
+        let mut player = Player::new(String::from("Test"), 50, 50, 50);
+        player.apply_poison(PoisonType::Speed);
+        assert_eq!(player.speed, 45);
+    }
+
+    #[test]
+    fn test_poison_application_strength() {
+        let mut player = Player::new(String::from("Test"), 50, 50, 50);
+        player.apply_poison(PoisonType::Strength);
+        assert_eq!(player.strength, 45);
+    }
+}
@@ -1,12 +1,19 @@
 //! Module gérant l'application des effets de poison sur les joueurs.
 //!
 //! Ce module définit l'énumération [`PoisonType`] qui énumère les différents types de poison,
-//! ainsi qu'une fonction pour appliquer l'effet correspondant sur un joueur.
+//! ainsi qu'une fonction pour appliquer l'effet correspondant sur un joueur. Le perdant d'une manche
+//! peut également contrer le poison une fois par partie via [`resolve_poison`].
+
+use serde::{Deserialize, Serialize};
 
 use crate::player::Player;
 
+/// Points de vitesse ou de force retirés par un poison.
+pub const POISON_AMOUNT: u32 = 5;
+
 /// Énumération des types de poison pouvant être appliqués.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum PoisonType {
     /// Poison affectant la vitesse.
     Speed,
@@ -14,6 +21,48 @@ pub enum PoisonType {
     Strength,
 }
 
+impl PoisonType {
+    /// Poisons proposés au gagnant d'une manche, dans l'ordre du menu.
+    pub const ALL: [PoisonType; 2] = [PoisonType::Speed, PoisonType::Strength];
+
+    /// Retourne le libellé de l'effet du poison, tel qu'affiché dans le menu.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PoisonType::Speed => "-5 speed",
+            PoisonType::Strength => "-5 strength",
+        }
+    }
+}
+
+/// Retourne les poisons proposés au gagnant d'une manche, dans l'ordre du menu, privés de ceux qui
+/// sont exclus (par exemple le poison interdit pour la manche avec `--bans`).
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::poison::{PoisonType, allowed_poisons};
+///
+/// assert_eq!(allowed_poisons(&[PoisonType::Speed]), [PoisonType::Strength]);
+/// ```
+pub fn allowed_poisons(excluded: &[PoisonType]) -> Vec<PoisonType> {
+    PoisonType::ALL
+        .into_iter()
+        .filter(|poison| !excluded.contains(poison))
+        .collect()
+}
+
+/// Issue de la résolution d'un poison choisi par le gagnant d'une manche.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PoisonOutcome {
+    /// Le poison a été appliqué au perdant.
+    Applied(PoisonType),
+    /// Le perdant a contré le poison en subissant des dégâts de vitalité supplémentaires.
+    Countered {
+        /// Dégâts supplémentaires effectivement retirés à la vitalité.
+        extra_damage: u32,
+    },
+}
+
 /// Applique l’effet de poison sur le joueur cible.
 /// Le poison modifie soit la vitesse, soit la force du joueur.
 ///
@@ -31,6 +80,55 @@ pub fn apply_poison(target: &mut Player, poison_type: PoisonType) -> Result<(),
     Ok(())
 }
 
+/// Résout le poison choisi par le gagnant en tenant compte d'une éventuelle contre-proposition du perdant.
+///
+/// Si le perdant contre, il consomme une de ses contre-propositions et subit à nouveau `damage` points
+/// de dégâts de vitalité (soit le double des dégâts de la manche) au lieu du poison. Comme le poison,
+/// ces dégâts ignorent l'armure.
+///
+/// # Arguments
+///
+/// * `target` - Le joueur perdant de la manche.
+/// * `poison_type` - Le poison choisi par le gagnant.
+/// * `damage` - Les dégâts de vitalité déjà infligés lors de la manche.
+/// * `counter` - Indique si le perdant choisit de contrer le poison.
+///
+/// # Retour
+///
+/// * `Ok(PoisonOutcome)` décrivant l'effet finalement appliqué.
+/// * `Err(String)` si le perdant tente de contrer sans contre-proposition restante.
+pub fn resolve_poison(
+    target: &mut Player,
+    poison_type: PoisonType,
+    damage: u32,
+    counter: bool,
+) -> Result<PoisonOutcome, String> {
+    log::debug!(
+        "résolution du poison target={} poison={poison_type:?} damage={damage} counter={counter} \
+         counters_remaining={}",
+        target.name,
+        target.counters_remaining
+    );
+    if !counter {
+        apply_poison(target, poison_type.clone())?;
+        return Ok(PoisonOutcome::Applied(poison_type));
+    }
+    if target.counters_remaining == 0 {
+        return Err(format!(
+            "{} n'a plus de contre-proposition disponible.",
+            target.name
+        ));
+    }
+    target.counters_remaining -= 1;
+    let extra_damage = target.take_piercing_damage(damage).dealt;
+    log::debug!(
+        "poison contré target={} extra_damage={extra_damage} vitality={}",
+        target.name,
+        target.vitality()
+    );
+    Ok(PoisonOutcome::Countered { extra_damage })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,4 +151,44 @@ mod tests {
         apply_poison(&mut player, PoisonType::Strength).unwrap();
         assert_eq!(player.strength, 45);
     }
+
+    /// Vérifie le menu des poisons privé des poisons exclus, jusqu'au menu vide.
+    #[test]
+    fn test_allowed_poisons() {
+        assert_eq!(allowed_poisons(&[]), PoisonType::ALL);
+        assert_eq!(
+            allowed_poisons(&[PoisonType::Strength]),
+            [PoisonType::Speed]
+        );
+        assert!(allowed_poisons(&PoisonType::ALL).is_empty());
+    }
+
+    #[test]
+    fn test_resolve_poison_counter_used() {
+        let mut player = Player::new(String::from("Test"), 30, 50, 50);
+        let outcome = resolve_poison(&mut player, PoisonType::Speed, 12, true).unwrap();
+        assert_eq!(outcome, PoisonOutcome::Countered { extra_damage: 12 });
+        assert_eq!(player.vitality, 18);
+        assert_eq!(player.speed, 50);
+        assert_eq!(player.counters_remaining, 0);
+    }
+
+    #[test]
+    fn test_resolve_poison_counter_refused() {
+        let mut player = Player::new(String::from("Test"), 30, 50, 50);
+        let outcome = resolve_poison(&mut player, PoisonType::Strength, 12, false).unwrap();
+        assert_eq!(outcome, PoisonOutcome::Applied(PoisonType::Strength));
+        assert_eq!(player.vitality, 30);
+        assert_eq!(player.strength, 45);
+        assert_eq!(player.counters_remaining, 1);
+    }
+
+    #[test]
+    fn test_resolve_poison_no_counter_left() {
+        let mut player = Player::new(String::from("Test"), 30, 50, 50);
+        player.counters_remaining = 0;
+        assert!(resolve_poison(&mut player, PoisonType::Speed, 12, true).is_err());
+        assert_eq!(player.vitality, 30);
+        assert_eq!(player.speed, 50);
+    }
 }
@@ -0,0 +1,420 @@
+//! Module du fichier de pronostics destiné aux spectateurs d'un stream.
+//!
+//! Le [`PredictionsFile`] est un [`GameObserver`] qui écrit, au début de chaque manche, un instantané
+//! JSON de la manche (son numéro et les caractéristiques des deux joueurs) dans un fichier, puis
+//! suspend la partie pendant la fenêtre de paris. Un bot externe qui surveille le fichier peut ainsi
+//! ouvrir les pronostics des spectateurs. À la fin de la manche, son issue réelle est ajoutée au
+//! fichier, sur une seconde ligne.
+//!
+//! Chaque écriture passe par un fichier temporaire renommé ensuite à la place du fichier : le bot ne
+//! lit jamais un fichier à moitié écrit.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::clock::Clock;
+use crate::observer::{GameEvent, GameObserver};
+use crate::player::PlayerSnapshot;
+
+/// Durée par défaut de la fenêtre de paris, en secondes.
+pub const DEFAULT_BETTING_WINDOW: u64 = 10;
+
+/// Erreur survenue lors de la création du fichier de pronostics.
+#[derive(Debug)]
+pub enum PredictionsError {
+    /// Le dossier devant contenir le fichier n'existe pas.
+    MissingDirectory {
+        /// Chemin du fichier demandé.
+        path: PathBuf,
+    },
+}
+
+impl fmt::Display for PredictionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PredictionsError::MissingDirectory { path } => write!(
+                f,
+                "impossible de créer le fichier de pronostics {} : le dossier {} n'existe pas",
+                path.display(),
+                path.parent().unwrap_or(Path::new("")).display()
+            ),
+        }
+    }
+}
+
+impl Error for PredictionsError {}
+
+/// Caractéristiques d'un joueur au début d'une manche, telles qu'écrites dans le fichier.
+#[derive(Debug, Serialize)]
+struct PlayerStats<'a> {
+    /// Nom du joueur.
+    name: &'a str,
+    /// Vitalité du joueur.
+    vitality: u32,
+    /// Vitalité maximale du joueur.
+    max_vitality: u32,
+    /// Vitesse du joueur.
+    speed: u32,
+    /// Force du joueur.
+    strength: u32,
+    /// Nombre de poisons subis.
+    poisons: u32,
+}
+
+/// Ligne du fichier de pronostics.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Entry<'a> {
+    /// Instantané écrit au début de la manche, avant la fenêtre de paris.
+    Round {
+        /// Numéro de la manche.
+        round: u32,
+        /// Durée de la fenêtre de paris, en secondes.
+        window: u64,
+        /// Caractéristiques des joueurs.
+        players: Vec<PlayerStats<'a>>,
+    },
+    /// Issue de la manche, ajoutée à sa fin.
+    Outcome {
+        /// Numéro de la manche.
+        round: u32,
+        /// Nom du gagnant de la manche, `null` pour une manche nulle.
+        winner: Option<&'a str>,
+        /// Vitalité perdue par le perdant, poison compris.
+        damage: u32,
+        /// Vitalité de chaque joueur à la fin de la manche.
+        vitality: &'a [u32],
+    },
+}
+
+impl Entry<'_> {
+    /// Sérialise la ligne en JSON, sans retour à la ligne final.
+    fn to_line(&self) -> String {
+        serde_json::to_string(self).expect("une ligne de pronostics est toujours sérialisable")
+    }
+}
+
+/// Observateur écrivant l'instantané et l'issue de chaque manche dans le fichier de pronostics.
+pub struct PredictionsFile {
+    /// Chemin du fichier de pronostics.
+    path: PathBuf,
+    /// Durée de la fenêtre de paris.
+    window: Duration,
+    /// Horloge mesurant la fenêtre de paris.
+    clock: Box<dyn Clock>,
+    /// Noms des joueurs de la partie en cours.
+    players: Vec<String>,
+    /// Contenu courant du fichier.
+    content: String,
+    /// Gagnant de la manche en cours, le cas échéant.
+    winner: Option<usize>,
+    /// Vitalité perdue pendant la manche en cours.
+    damage: u32,
+    /// Vitalité de chaque joueur, tenue à jour pendant la manche.
+    vitality: Vec<u32>,
+}
+
+impl PredictionsFile {
+    /// Prépare le fichier de pronostics, sans l'écrire avant la première manche.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Le chemin du fichier de pronostics.
+    /// * `window` - La durée de la fenêtre de paris, pendant laquelle la partie est suspendue.
+    /// * `clock` - L'horloge mesurant la fenêtre de paris.
+    ///
+    /// # Retour
+    ///
+    /// Retourne [`PredictionsError::MissingDirectory`] si le dossier du fichier n'existe pas, afin de
+    /// signaler l'erreur avant le début de la partie.
+    pub fn create(
+        path: &Path,
+        window: Duration,
+        clock: Box<dyn Clock>,
+    ) -> Result<Self, PredictionsError> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+            && !parent.is_dir()
+        {
+            return Err(PredictionsError::MissingDirectory {
+                path: path.to_path_buf(),
+            });
+        }
+        Ok(PredictionsFile {
+            path: path.to_path_buf(),
+            window,
+            clock,
+            players: Vec::new(),
+            content: String::new(),
+            winner: None,
+            damage: 0,
+            vitality: Vec::new(),
+        })
+    }
+
+    /// Retourne l'index du joueur nommé `name`.
+    fn index(&self, name: &str) -> Option<usize> {
+        self.players.iter().position(|p| p == name)
+    }
+
+    /// Ajoute une ligne au contenu du fichier, puis remplace le fichier par son nouveau contenu.
+    fn append(&mut self, line: String) {
+        self.content.push_str(&line);
+        self.content.push('\n');
+        if let Err(err) = write_atomic(&self.path, &self.content) {
+            log::warn!("écriture du fichier de pronostics impossible : {err}");
+        }
+    }
+
+    /// Écrit l'instantané du début de la manche, puis attend la fin de la fenêtre de paris.
+    fn start_round(&mut self, round: u32, snapshots: &[PlayerSnapshot]) {
+        self.content.clear();
+        self.winner = None;
+        self.damage = 0;
+        self.vitality = snapshots.iter().map(|s| s.vitality).collect();
+        let players = snapshots
+            .iter()
+            .map(|s| PlayerStats {
+                name: self.players.get(s.id.index()).map_or("", String::as_str),
+                vitality: s.vitality,
+                max_vitality: s.max_vitality,
+                speed: s.speed,
+                strength: s.strength,
+                poisons: s.poisons,
+            })
+            .collect();
+        let entry = Entry::Round {
+            round,
+            window: self.window.as_secs(),
+            players,
+        };
+        let line = entry.to_line();
+        self.append(line);
+        self.clock.sleep(self.window);
+    }
+}
+
+impl fmt::Debug for PredictionsFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PredictionsFile")
+            .field("path", &self.path)
+            .field("window", &self.window)
+            .field("players", &self.players)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GameObserver for PredictionsFile {
+    fn on_event(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::GameStarted { players, .. } => self.players = players.clone(),
+            GameEvent::RoundStarted { round, players } => self.start_round(*round, players),
+            GameEvent::DamageDealt {
+                winner,
+                loser,
+                damage,
+                vitality,
+                ..
+            } => {
+                self.winner = self.index(winner);
+                self.damage += damage;
+                if let Some(slot) = self.index(loser).and_then(|i| self.vitality.get_mut(i)) {
+                    *slot = *vitality;
+                }
+            }
+            GameEvent::PoisonCountered {
+                player,
+                extra_damage,
+                vitality,
+            } => {
+                self.damage += extra_damage;
+                if let Some(slot) = self.index(player).and_then(|i| self.vitality.get_mut(i)) {
+                    *slot = *vitality;
+                }
+            }
+            GameEvent::RoundEnded { round } => {
+                let line = Entry::Outcome {
+                    round: *round,
+                    winner: self.winner.map(|index| self.players[index].as_str()),
+                    damage: self.damage,
+                    vitality: &self.vitality,
+                }
+                .to_line();
+                self.append(line);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Remplace le contenu d'un fichier de façon atomique : le contenu est écrit dans un fichier
+/// temporaire du même dossier, renommé ensuite à la place du fichier.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::predictions::write_atomic;
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// let path = dir.path().join("pronostics.json");
+/// write_atomic(&path, "{}\n").unwrap();
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "{}\n");
+/// assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+/// ```
+pub fn write_atomic(path: &Path, content: &str) -> io::Result<()> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+    fs::write(&temp, content)?;
+    fs::rename(&temp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::controller::{ScriptedController, StopDecision};
+    use crate::game::Game;
+    use crate::output::Verbosity;
+    use crate::player::Player;
+    use serde_json::{Value, json};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Observateur relisant le fichier de pronostics au début et à la fin de chaque manche.
+    struct Watcher {
+        /// Chemin du fichier de pronostics.
+        path: PathBuf,
+        /// Contenus successifs du fichier, chaque ligne décodée.
+        seen: Rc<RefCell<Vec<Vec<Value>>>>,
+    }
+
+    impl GameObserver for Watcher {
+        fn on_event(&mut self, event: &GameEvent) {
+            if let GameEvent::RoundStarted { .. } | GameEvent::RoundEnded { .. } = event {
+                let content = fs::read_to_string(&self.path).unwrap();
+                let lines = content
+                    .lines()
+                    .map(|line| serde_json::from_str(line).unwrap())
+                    .collect();
+                self.seen.borrow_mut().push(lines);
+            }
+        }
+    }
+
+    /// Vérifie la suite des contenus du fichier sur deux manches scriptées : l'instantané seul
+    /// pendant la fenêtre de paris, puis suivi de l'issue de la manche, sans fichier temporaire
+    /// restant.
+    #[test]
+    fn test_predictions_file_sequence() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pronostics.json");
+        let players = vec![
+            Player::new(String::from("Alice"), 1000, 75, 50),
+            Player::new(String::from("Bob"), 1000, 60, 40),
+        ];
+        let mut game = Game::new(players, 1);
+        game.set_verbosity(Verbosity::Silent);
+        game.set_clock(Box::new(ManualClock::new()));
+        let window = ManualClock::new();
+        let predictions = PredictionsFile::create(
+            &path,
+            Duration::from_secs(DEFAULT_BETTING_WINDOW),
+            Box::new(window.clone()),
+        )
+        .unwrap();
+        game.add_observer(Box::new(predictions));
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        game.add_observer(Box::new(Watcher {
+            path: path.clone(),
+            seen: Rc::clone(&seen),
+        }));
+        let alice = ScriptedController::new();
+        let bob = ScriptedController::new();
+        game.set_controller(0, Box::new(alice.clone()));
+        game.set_controller(1, Box::new(bob.clone()));
+        game.start();
+
+        alice.push_stop(StopDecision::from_offset(50, 0));
+        bob.push_stop(StopDecision::from_offset(50, 303));
+        alice.push_poison(0);
+        assert_eq!(game.play_round().unwrap(), None);
+        let bob_vitality = game.players[1].vitality();
+        alice.push_stop(StopDecision::from_offset(50, 0));
+        bob.push_stop(StopDecision::from_offset(50, 303));
+        alice.push_poison(0);
+        assert_eq!(game.play_round().unwrap(), None);
+        let last_vitality = game.players[1].vitality();
+
+        assert_eq!(
+            window.now(),
+            Duration::from_secs(2 * DEFAULT_BETTING_WINDOW)
+        );
+        let first = json!({
+            "event": "round",
+            "round": 1,
+            "window": 10,
+            "players": [
+                {"name": "Alice", "vitality": 1000, "max_vitality": 1000, "speed": 75,
+                 "strength": 50, "poisons": 0},
+                {"name": "Bob", "vitality": 1000, "max_vitality": 1000, "speed": 60,
+                 "strength": 40, "poisons": 0},
+            ],
+        });
+        let second = json!({
+            "event": "round",
+            "round": 2,
+            "window": 10,
+            "players": [
+                {"name": "Alice", "vitality": 1000, "max_vitality": 1000, "speed": 75,
+                 "strength": 50, "poisons": 0},
+                {"name": "Bob", "vitality": bob_vitality, "max_vitality": 1000, "speed": 55,
+                 "strength": 40, "poisons": 1},
+            ],
+        });
+        assert_eq!(
+            *seen.borrow(),
+            [
+                vec![first.clone()],
+                vec![
+                    first,
+                    json!({
+                        "event": "outcome",
+                        "round": 1,
+                        "winner": "Alice",
+                        "damage": 1000 - bob_vitality,
+                        "vitality": [1000, bob_vitality],
+                    }),
+                ],
+                vec![second.clone()],
+                vec![
+                    second,
+                    json!({
+                        "event": "outcome",
+                        "round": 2,
+                        "winner": "Alice",
+                        "damage": bob_vitality - last_vitality,
+                        "vitality": [1000, last_vitality],
+                    }),
+                ],
+            ]
+        );
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    /// Vérifie qu'un fichier de pronostics dans un dossier inexistant est refusé.
+    #[test]
+    fn test_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("absent").join("pronostics.json");
+        let err = PredictionsFile::create(&path, Duration::ZERO, Box::new(ManualClock::new()))
+            .unwrap_err();
+        assert!(err.to_string().contains("n'existe pas"), "{err}");
+    }
+}
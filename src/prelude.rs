@@ -0,0 +1,31 @@
+//! Prélude de la bibliothèque : les éléments de l'API publique stable, importables d'un coup.
+//!
+//! ```
+//! use dual_game::prelude::*;
+//! ```
+//!
+//! Le prélude couvre ce qu'il faut pour créer, piloter et observer une partie sans l'application en
+//! ligne de commande : la partie et ses joueurs, les règles et le barème, les contrôleurs, la console
+//! et la sortie, le rendu, les observateurs et les horloges. Le score d'un objectif se calcule avec
+//! [`ScoringCalculator::calculate_score`] (barème standard) ou [`ScoringConfig::score`] (barème
+//! personnalisé).
+//!
+//! Les autres modules publics restent accessibles par leur chemin ; ceux qui ne servent qu'à
+//! l'application en ligne de commande sont masqués de la documentation et peuvent changer sans
+//! préavis.
+
+pub use crate::clock::{Clock, ManualClock, SystemClock};
+#[cfg(feature = "cli")]
+pub use crate::console::StdConsole;
+pub use crate::console::{Console, NullConsole, ScriptedConsole};
+pub use crate::controller::{
+    BotController, Controller, CounterView, Difficulty, ScriptedController, StopDecision,
+};
+pub use crate::game::{Game, GameResult};
+pub use crate::observer::{GameEvent, GameObserver};
+pub use crate::output::{Output, Verbosity};
+pub use crate::player::{Player, PlayerId};
+pub use crate::poison::PoisonType;
+pub use crate::renderer::{RenderStyle, Renderer};
+pub use crate::rules::{GameMode, GameRules};
+pub use crate::scoring::{ScoringCalculator, ScoringConfig};
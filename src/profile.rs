@@ -0,0 +1,812 @@
+//! Module gérant les profils de joueurs persistés sur disque.
+//!
+//! Un profil conserve les caractéristiques d'un joueur, sa classe, son bilan de victoires et de défaites,
+//! son expérience ainsi que ses options préférées. Les profils sont stockés au format JSON dans
+//! `~/.dual_game/profiles/<nom>.json` et manipulés via [`ProfileStore`].
+//!
+//! Un profil en mode hardcore ([`Profile::hardcore`]) perd son expérience, son niveau et ses succès
+//! lorsqu'il perd une partie, après une confirmation qui détaille la progression effacée
+//! ([`confirm_permadeath`]).
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::achievements;
+use crate::class::PlayerClass;
+use crate::console::Console;
+use crate::messages::Msg;
+use crate::player::{
+    DEFAULT_SPEED, DEFAULT_STRENGTH, DEFAULT_VITALITY, Player, PlayerBuilder, PlayerConfigError,
+};
+use crate::predictions::write_atomic;
+use crate::setup::parse_yes_no;
+use crate::tr;
+
+/// Expérience nécessaire pour gagner un niveau.
+pub const XP_PER_LEVEL: u32 = 500;
+/// Force supplémentaire accordée par niveau.
+pub const STRENGTH_PER_LEVEL: u32 = 2;
+/// Bonus de force maximal accordé par les niveaux.
+pub const MAX_LEVEL_STRENGTH_BONUS: u32 = 20;
+
+/// Calcule le niveau et l'expérience restante après un gain d'expérience.
+///
+/// L'expérience excédentaire est reportée sur le niveau suivant, et un gain important peut faire
+/// franchir plusieurs niveaux d'un coup.
+///
+/// # Arguments
+///
+/// * `level` - Le niveau actuel.
+/// * `xp` - L'expérience accumulée dans le niveau actuel.
+/// * `gained` - L'expérience gagnée.
+///
+/// # Retour
+///
+/// Retourne le tuple `(niveau, expérience)` mis à jour.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::profile::level_up;
+///
+/// assert_eq!(level_up(1, 400, 1200), (4, 100));
+/// ```
+pub fn level_up(level: u32, xp: u32, gained: u32) -> (u32, u32) {
+    let total = u64::from(xp) + u64::from(gained);
+    let per_level = u64::from(XP_PER_LEVEL);
+    let levels = (total / per_level) as u32;
+    (level.saturating_add(levels), (total % per_level) as u32)
+}
+
+/// Bonus de force accordé par un niveau donné, plafonné à [`MAX_LEVEL_STRENGTH_BONUS`].
+pub fn level_strength_bonus(level: u32) -> u32 {
+    level
+        .saturating_mul(STRENGTH_PER_LEVEL)
+        .min(MAX_LEVEL_STRENGTH_BONUS)
+}
+
+/// Progression obtenue par un profil à l'issue d'une partie.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LevelProgress {
+    /// Expérience gagnée pendant la partie.
+    pub xp_gained: u32,
+    /// Nombre de niveaux gagnés.
+    pub levels_gained: u32,
+    /// Niveau atteint.
+    pub level: u32,
+    /// Force supplémentaire obtenue pour les prochaines parties.
+    pub strength_gained: u32,
+}
+
+/// Options de partie préférées d'un joueur.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfileOptions {
+    /// Nombre d'objectifs par tour préféré.
+    pub objectifs: Option<usize>,
+}
+
+/// Meilleur score d'un profil au défi du jour (voir [`daily`](crate::daily)).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DailyBest {
+    /// Date du défi, au format `AAAA-MM-JJ`.
+    pub date: String,
+    /// Meilleur total obtenu ce jour-là.
+    pub points: u32,
+}
+
+/// Profil persistant d'un joueur.
+///
+/// Les champs absents du fichier prennent leur valeur par défaut et les champs inconnus sont ignorés,
+/// afin qu'un profil écrit par une version plus récente reste lisible.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    /// Nom du profil, identique au nom de son fichier et utilisé comme nom du joueur.
+    pub name: String,
+    /// Vitalité initiale.
+    pub vitality: u32,
+    /// Vitesse.
+    pub speed: u32,
+    /// Force.
+    pub strength: u32,
+    /// Armure.
+    pub armor: u32,
+    /// Classe de personnage, qui remplace les caractéristiques individuelles si elle est définie.
+    pub class: Option<PlayerClass>,
+    /// Nombre total de parties gagnées.
+    pub wins: u32,
+    /// Nombre total de parties perdues.
+    pub losses: u32,
+    /// Nombre total de matchs nuls.
+    pub draws: u32,
+    /// Expérience accumulée dans le niveau actuel.
+    pub xp: u32,
+    /// Niveau du joueur, chaque niveau accordant un bonus de force.
+    pub level: u32,
+    /// Options de partie préférées.
+    pub options: ProfileOptions,
+    /// Meilleur score au dernier défi du jour joué, le cas échéant.
+    pub daily: Option<DailyBest>,
+    /// Identifiants des succès débloqués, dans l'ordre où ils l'ont été (voir
+    /// [`achievements`](crate::achievements)).
+    pub achievements: Vec<String>,
+    /// Mode hardcore : une défaite efface l'expérience, le niveau et les succès du profil.
+    pub hardcore: bool,
+}
+
+/// Progression d'un profil effacée par une défaite en mode hardcore.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgressLoss {
+    /// Niveau perdu.
+    pub level: u32,
+    /// Expérience accumulée dans ce niveau.
+    pub xp: u32,
+    /// Identifiants des succès perdus.
+    pub achievements: Vec<String>,
+}
+
+impl ProgressLoss {
+    /// Retourne les noms des succès perdus, séparés par des virgules, ou `-` s'il n'y en a aucun.
+    pub fn achievement_names(&self) -> String {
+        if self.achievements.is_empty() {
+            return String::from("-");
+        }
+        let names: Vec<&str> = self
+            .achievements
+            .iter()
+            .map(|id| achievements::name(id).unwrap_or(id))
+            .collect();
+        names.join(", ")
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Profile {
+            name: String::new(),
+            vitality: DEFAULT_VITALITY,
+            speed: DEFAULT_SPEED,
+            strength: DEFAULT_STRENGTH,
+            armor: 0,
+            class: None,
+            wins: 0,
+            losses: 0,
+            draws: 0,
+            xp: 0,
+            level: 0,
+            options: ProfileOptions::default(),
+            daily: None,
+            achievements: Vec::new(),
+            hardcore: false,
+        }
+    }
+}
+
+impl Profile {
+    /// Crée un profil avec les caractéristiques par défaut.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Le nom du profil.
+    pub fn new(name: impl Into<String>) -> Self {
+        Profile {
+            name: name.into(),
+            ..Profile::default()
+        }
+    }
+
+    /// Retourne un [`PlayerBuilder`] initialisé avec les caractéristiques du profil,
+    /// y compris le bonus de force lié à son niveau.
+    pub fn player_builder(&self) -> PlayerBuilder {
+        let builder = Player::builder(self.name.clone())
+            .vitality(self.vitality)
+            .speed(self.speed)
+            .strength(self.strength)
+            .armor(self.armor)
+            .strength_bonus(level_strength_bonus(self.level));
+        match self.class {
+            Some(class) => builder.class(class),
+            None => builder,
+        }
+    }
+
+    /// Construit le joueur correspondant au profil.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le [`Player`] ou une [`PlayerConfigError`] si les caractéristiques du profil sont invalides.
+    pub fn to_player(&self) -> Result<Player, PlayerConfigError> {
+        self.player_builder().build()
+    }
+
+    /// Enregistre l'issue d'une partie dans le bilan du profil.
+    ///
+    /// # Arguments
+    ///
+    /// * `won` - `true` si le joueur a gagné la partie.
+    pub fn record_game(&mut self, won: bool) {
+        if won {
+            self.wins += 1;
+        } else {
+            self.losses += 1;
+        }
+    }
+
+    /// Enregistre un match nul dans le bilan du profil.
+    pub fn record_draw(&mut self) {
+        self.draws += 1;
+    }
+
+    /// Retourne le meilleur score du profil au défi du jour de la date donnée, `None` s'il ne l'a pas
+    /// encore joué.
+    pub fn daily_best(&self, date: &str) -> Option<u32> {
+        self.daily
+            .as_ref()
+            .filter(|best| best.date == date)
+            .map(|best| best.points)
+    }
+
+    /// Enregistre le total obtenu au défi du jour de la date donnée.
+    ///
+    /// Seul le meilleur score du dernier jour joué est conservé.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le meilleur score du profil pour cette date.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::profile::Profile;
+    ///
+    /// let mut profile = Profile::new("alice");
+    /// assert_eq!(profile.record_daily("2024-06-01", 412), 412);
+    /// assert_eq!(profile.record_daily("2024-06-01", 380), 412);
+    /// assert_eq!(profile.record_daily("2024-06-02", 300), 300);
+    /// ```
+    pub fn record_daily(&mut self, date: &str, points: u32) -> u32 {
+        let best = self
+            .daily_best(date)
+            .map_or(points, |best| best.max(points));
+        self.daily = Some(DailyBest {
+            date: date.to_string(),
+            points: best,
+        });
+        best
+    }
+
+    /// Enregistre les succès obtenus lors d'une partie.
+    ///
+    /// # Retour
+    ///
+    /// Retourne les identifiants des succès qui n'étaient pas encore débloqués, dans l'ordre donné.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::profile::Profile;
+    ///
+    /// let mut profile = Profile::new("alice");
+    /// assert_eq!(profile.unlock(&["first_win"]), ["first_win"]);
+    /// assert_eq!(profile.unlock(&["first_win", "marathon"]), ["marathon"]);
+    /// assert_eq!(profile.achievements, ["first_win", "marathon"]);
+    /// ```
+    pub fn unlock<'a>(&mut self, earned: &[&'a str]) -> Vec<&'a str> {
+        let mut unlocked = Vec::new();
+        for &id in earned {
+            if !self.achievements.iter().any(|known| known == id) {
+                self.achievements.push(id.to_string());
+                unlocked.push(id);
+            }
+        }
+        unlocked
+    }
+
+    /// Ajoute l'expérience gagnée lors d'une partie et calcule les niveaux obtenus.
+    ///
+    /// Le bonus de force des nouveaux niveaux ne s'applique qu'aux parties créées ensuite.
+    ///
+    /// # Arguments
+    ///
+    /// * `xp` - L'expérience gagnée, égale au total des scores de manche du joueur.
+    pub fn gain_xp(&mut self, xp: u32) -> LevelProgress {
+        let previous_level = self.level;
+        (self.level, self.xp) = level_up(self.level, self.xp, xp);
+        LevelProgress {
+            xp_gained: xp,
+            levels_gained: self.level - previous_level,
+            level: self.level,
+            strength_gained: level_strength_bonus(self.level)
+                - level_strength_bonus(previous_level),
+        }
+    }
+
+    /// Retourne la progression qu'une défaite en mode hardcore effacerait.
+    pub fn progress(&self) -> ProgressLoss {
+        ProgressLoss {
+            level: self.level,
+            xp: self.xp,
+            achievements: self.achievements.clone(),
+        }
+    }
+
+    /// Efface l'expérience, le niveau et les succès du profil, en conservant son bilan, ses
+    /// caractéristiques et ses options.
+    ///
+    /// # Retour
+    ///
+    /// Retourne la progression effacée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::profile::Profile;
+    ///
+    /// let mut profile = Profile::new("alice");
+    /// profile.gain_xp(1200);
+    /// profile.unlock(&["first_win"]);
+    /// profile.record_game(true);
+    /// let loss = profile.reset_progress();
+    /// assert_eq!((loss.level, loss.xp, loss.achievements.len()), (2, 200, 1));
+    /// assert_eq!((profile.level, profile.xp, profile.wins), (0, 0, 1));
+    /// assert!(profile.achievements.is_empty());
+    /// ```
+    pub fn reset_progress(&mut self) -> ProgressLoss {
+        let loss = self.progress();
+        self.level = 0;
+        self.xp = 0;
+        self.achievements.clear();
+        loss
+    }
+}
+
+/// Demande confirmation avant d'effacer la progression d'un profil hardcore qui vient de perdre une
+/// partie, en détaillant ce qui sera perdu.
+///
+/// Seul un « oui » explicite efface la progression ; toute autre réponse, y compris la fin du flux
+/// d'entrée, la conserve mais fait quitter le mode hardcore au profil, qu'une défaite ne peut laisser
+/// intact.
+///
+/// # Retour
+///
+/// Retourne la progression effacée, ou `None` si le profil a quitté le mode hardcore.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::console::ScriptedConsole;
+/// use dual_game::profile::{Profile, confirm_permadeath};
+///
+/// let mut profile = Profile::new("alice");
+/// profile.hardcore = true;
+/// profile.level = 3;
+/// let mut console = ScriptedConsole::new(["o"]);
+/// assert_eq!(confirm_permadeath(&mut console, &mut profile).unwrap().level, 3);
+/// assert_eq!(profile.level, 0);
+/// assert!(profile.hardcore);
+/// ```
+pub fn confirm_permadeath(
+    console: &mut dyn Console,
+    profile: &mut Profile,
+) -> Option<ProgressLoss> {
+    let loss = profile.progress();
+    console.println(&tr!(
+        Msg::HardcoreLoss,
+        player = profile.name,
+        level = loss.level,
+        xp = loss.xp,
+        achievements = loss.achievement_names()
+    ));
+    let confirmed = console
+        .prompt(&tr!(Msg::HardcoreConfirm))
+        .is_some_and(|answer| parse_yes_no(answer.trim()) == Ok(true));
+    if confirmed {
+        console.println(&tr!(Msg::HardcoreReset, player = profile.name));
+        Some(profile.reset_progress())
+    } else {
+        profile.hardcore = false;
+        console.println(&tr!(Msg::HardcoreKept, player = profile.name));
+        None
+    }
+}
+
+/// Erreur survenant lors du chargement ou de l'enregistrement d'un profil.
+#[derive(Debug)]
+pub enum ProfileError {
+    /// Le nom de profil contient des caractères non autorisés.
+    InvalidName(String),
+    /// Le répertoire personnel de l'utilisateur est introuvable.
+    NoHomeDir,
+    /// Erreur d'entrée/sortie sur le fichier de profil.
+    Io {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: io::Error,
+    },
+    /// Le fichier de profil n'est pas un JSON valide.
+    Corrupt {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileError::InvalidName(name) => write!(
+                f,
+                "nom de profil invalide « {name} » : seuls les lettres, chiffres, '-' et '_' sont autorisés"
+            ),
+            ProfileError::NoHomeDir => write!(f, "répertoire personnel introuvable"),
+            ProfileError::Io { path, source } => {
+                write!(f, "erreur d'accès au profil {} : {source}", path.display())
+            }
+            ProfileError::Corrupt { path, source } => {
+                write!(f, "profil corrompu {} : {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for ProfileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ProfileError::Io { source, .. } => Some(source),
+            ProfileError::Corrupt { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Répertoire contenant les fichiers de profils.
+#[derive(Clone, Debug)]
+pub struct ProfileStore {
+    dir: PathBuf,
+}
+
+impl ProfileStore {
+    /// Crée un gestionnaire de profils stockés dans le répertoire donné.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        ProfileStore { dir: dir.into() }
+    }
+
+    /// Crée un gestionnaire de profils stockés dans `~/.dual_game/profiles`.
+    pub fn default_location() -> Result<Self, ProfileError> {
+        let home = std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .ok_or(ProfileError::NoHomeDir)?;
+        Ok(ProfileStore::new(
+            Path::new(&home).join(".dual_game").join("profiles"),
+        ))
+    }
+
+    /// Retourne le chemin du fichier associé à un nom de profil.
+    pub fn path_for(&self, name: &str) -> Result<PathBuf, ProfileError> {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(ProfileError::InvalidName(name.to_string()));
+        }
+        Ok(self.dir.join(format!("{name}.json")))
+    }
+
+    /// Charge un profil, ou le crée avec les valeurs par défaut s'il n'existe pas encore.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le profil chargé, ou une [`ProfileError`] si le fichier est illisible ou corrompu.
+    pub fn load_or_create(&self, name: &str) -> Result<Profile, ProfileError> {
+        let path = self.path_for(name)?;
+        match fs::read_to_string(&path) {
+            Ok(content) => {
+                let mut profile: Profile = serde_json::from_str(&content)
+                    .map_err(|source| ProfileError::Corrupt { path, source })?;
+                // Le nom du fichier fait foi, afin que le profil soit réenregistré au même endroit.
+                profile.name = name.to_string();
+                Ok(profile)
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let profile = Profile::new(name);
+                self.save(&profile)?;
+                Ok(profile)
+            }
+            Err(source) => Err(ProfileError::Io { path, source }),
+        }
+    }
+
+    /// Enregistre un profil sur disque, en créant le répertoire si nécessaire et en remplaçant
+    /// atomiquement le fichier existant.
+    pub fn save(&self, profile: &Profile) -> Result<(), ProfileError> {
+        let path = self.path_for(&profile.name)?;
+        let io_err = |source| ProfileError::Io {
+            path: path.clone(),
+            source,
+        };
+        fs::create_dir_all(&self.dir).map_err(io_err)?;
+        let content =
+            serde_json::to_string_pretty(profile).expect("un profil est toujours sérialisable");
+        // Le remplacement atomique préserve l'ancien profil si l'écriture est interrompue.
+        write_atomic(&path, &content).map_err(io_err)
+    }
+
+    /// Charge tous les profils enregistrés, triés par nom.
+    ///
+    /// # Retour
+    ///
+    /// Retourne une liste vide si le répertoire des profils n'existe pas encore.
+    pub fn list(&self) -> Result<Vec<Profile>, ProfileError> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => {
+                return Err(ProfileError::Io {
+                    path: self.dir.clone(),
+                    source,
+                });
+            }
+        };
+        let mut names = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|source| ProfileError::Io {
+                    path: self.dir.clone(),
+                    source,
+                })?
+                .path();
+            if path.extension().is_some_and(|ext| ext == "json")
+                && let Some(name) = path.file_stem().and_then(|stem| stem.to_str())
+                && self.path_for(name).is_ok()
+            {
+                names.push(name.to_string());
+            }
+        }
+        names.sort();
+        names.iter().map(|name| self.load_or_create(name)).collect()
+    }
+}
+
+/// Formate le classement des profils, du plus grand nombre de victoires au plus petit.
+///
+/// À nombre de victoires égal, le profil ayant le moins de défaites est classé en premier.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::profile::{Profile, leaderboard};
+///
+/// let mut alice = Profile::new("alice");
+/// alice.wins = 3;
+/// let bob = Profile::new("bob");
+/// assert!(leaderboard(&[bob, alice]).lines().nth(1).unwrap().contains("alice"));
+/// ```
+pub fn leaderboard(profiles: &[Profile]) -> String {
+    if profiles.is_empty() {
+        return String::from("Aucun profil enregistré.");
+    }
+    let mut ranked: Vec<&Profile> = profiles.iter().collect();
+    ranked.sort_by(|a, b| {
+        b.wins
+            .cmp(&a.wins)
+            .then(a.losses.cmp(&b.losses))
+            .then(a.name.cmp(&b.name))
+    });
+    let width = ranked
+        .iter()
+        .map(|p| p.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut lines = vec![String::from("Classement des profils")];
+    for (rank, profile) in ranked.iter().enumerate() {
+        lines.push(format!(
+            "{:>2}. {:<width$}  {:>3} V  {:>3} N  {:>3} D  niveau {}",
+            rank + 1,
+            profile.name,
+            profile.wins,
+            profile.draws,
+            profile.losses,
+            profile.level
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_or_create_and_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProfileStore::new(dir.path());
+
+        let mut profile = store.load_or_create("alice").unwrap();
+        assert_eq!(profile, Profile::new("alice"));
+        assert!(dir.path().join("alice.json").exists());
+
+        profile.record_game(true);
+        profile.record_draw();
+        profile.class = Some(PlayerClass::Tank);
+        store.save(&profile).unwrap();
+
+        let reloaded = store.load_or_create("alice").unwrap();
+        assert_eq!((reloaded.wins, reloaded.draws, reloaded.losses), (1, 1, 0));
+        assert_eq!(reloaded.to_player().unwrap().class, Some(PlayerClass::Tank));
+    }
+
+    /// Vérifie que le meilleur score du défi du jour est conservé dans le fichier du profil, et
+    /// oublié le jour suivant.
+    #[test]
+    fn test_daily_best_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProfileStore::new(dir.path());
+        let mut profile = store.load_or_create("alice").unwrap();
+        assert_eq!(profile.daily_best("2024-06-01"), None);
+        profile.record_daily("2024-06-01", 412);
+        store.save(&profile).unwrap();
+
+        let mut reloaded = store.load_or_create("alice").unwrap();
+        assert_eq!(reloaded.daily_best("2024-06-01"), Some(412));
+        assert_eq!(reloaded.daily_best("2024-06-02"), None);
+        assert_eq!(reloaded.record_daily("2024-06-01", 450), 450);
+    }
+
+    /// Vérifie que les succès débloqués sont persistés et ne sont annoncés qu'une fois.
+    #[test]
+    fn test_achievements_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProfileStore::new(dir.path());
+        let mut profile = store.load_or_create("alice").unwrap();
+        assert!(profile.achievements.is_empty());
+        assert_eq!(
+            profile.unlock(&["first_win", "blitz"]),
+            ["first_win", "blitz"]
+        );
+        store.save(&profile).unwrap();
+
+        let mut reloaded = store.load_or_create("alice").unwrap();
+        assert_eq!(reloaded.achievements, ["first_win", "blitz"]);
+        assert!(reloaded.unlock(&["blitz"]).is_empty());
+    }
+
+    /// Vérifie la confirmation de l'effacement d'un profil hardcore : la progression perdue est
+    /// détaillée, un « oui » l'efface, tout autre réponse la conserve en quittant le mode hardcore.
+    #[test]
+    fn test_permadeath_confirmation() {
+        use crate::console::ScriptedConsole;
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProfileStore::new(dir.path());
+        let mut hardcore = Profile::new("alice");
+        hardcore.hardcore = true;
+        hardcore.gain_xp(1200);
+        hardcore.unlock(&["first_win"]);
+        hardcore.record_game(true);
+
+        let mut profile = hardcore.clone();
+        let mut console = ScriptedConsole::new(["oui"]);
+        let loss = confirm_permadeath(&mut console, &mut profile).unwrap();
+        assert_eq!(
+            loss,
+            ProgressLoss {
+                level: 2,
+                xp: 200,
+                achievements: vec![String::from("first_win")],
+            }
+        );
+        assert!(
+            console
+                .output
+                .contains("niveau 2, 200 XP, succès : Première victoire")
+        );
+        assert!(console.output.contains("Progression de alice effacée."));
+        store.save(&profile).unwrap();
+        let reloaded = store.load_or_create("alice").unwrap();
+        assert_eq!((reloaded.level, reloaded.xp, reloaded.wins), (0, 0, 1));
+        assert!(reloaded.achievements.is_empty() && reloaded.hardcore);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        for answers in [vec!["n"], vec![]] {
+            let mut profile = hardcore.clone();
+            let mut console = ScriptedConsole::new(answers);
+            assert_eq!(confirm_permadeath(&mut console, &mut profile), None);
+            assert!(!profile.hardcore);
+            assert_eq!(profile.progress(), hardcore.progress());
+            assert!(console.output.contains("alice quitte le mode hardcore"));
+        }
+    }
+
+    #[test]
+    fn test_level_up_threshold() {
+        assert_eq!(level_up(0, 0, XP_PER_LEVEL - 1), (0, XP_PER_LEVEL - 1));
+        assert_eq!(level_up(0, 0, XP_PER_LEVEL), (1, 0));
+        assert_eq!(level_up(2, 450, 60), (3, 10));
+    }
+
+    #[test]
+    fn test_gain_xp_multi_level_and_cap() {
+        let mut profile = Profile::new("alice");
+        let progress = profile.gain_xp(1200);
+        assert_eq!(progress.levels_gained, 2);
+        assert_eq!(progress.strength_gained, 2 * STRENGTH_PER_LEVEL);
+        assert_eq!((profile.level, profile.xp), (2, 200));
+
+        profile.level = 9;
+        let progress = profile.gain_xp(XP_PER_LEVEL * 3);
+        assert_eq!(progress.level, 12);
+        assert_eq!(level_strength_bonus(12), MAX_LEVEL_STRENGTH_BONUS);
+        assert_eq!(progress.strength_gained, MAX_LEVEL_STRENGTH_BONUS - 18);
+        assert_eq!(
+            profile.to_player().unwrap().strength,
+            DEFAULT_STRENGTH + MAX_LEVEL_STRENGTH_BONUS
+        );
+    }
+
+    #[test]
+    fn test_unknown_fields_are_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProfileStore::new(dir.path());
+        fs::write(
+            dir.path().join("bob.json"),
+            r#"{"name": "Bob", "wins": 3, "future_field": {"x": 1}}"#,
+        )
+        .unwrap();
+
+        let profile = store.load_or_create("bob").unwrap();
+        assert_eq!(profile.name, "bob");
+        assert_eq!(profile.wins, 3);
+        assert_eq!(profile.vitality, DEFAULT_VITALITY);
+    }
+
+    #[test]
+    fn test_corrupt_profile_and_invalid_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProfileStore::new(dir.path());
+        fs::write(dir.path().join("carol.json"), "{ pas du json").unwrap();
+
+        let err = store.load_or_create("carol").unwrap_err();
+        assert!(matches!(err, ProfileError::Corrupt { .. }));
+        assert!(err.to_string().contains("profil corrompu"));
+        assert!(matches!(
+            store.load_or_create("../evil"),
+            Err(ProfileError::InvalidName(_))
+        ));
+    }
+
+    #[test]
+    fn test_list_and_leaderboard() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProfileStore::new(dir.path().join("profiles"));
+        assert!(store.list().unwrap().is_empty());
+        assert_eq!(leaderboard(&[]), "Aucun profil enregistré.");
+
+        for (name, wins, losses) in [("alice", 2, 1), ("bob", 5, 0), ("carol", 2, 0)] {
+            let mut profile = Profile::new(name);
+            profile.wins = wins;
+            profile.losses = losses;
+            store.save(&profile).unwrap();
+        }
+        fs::write(dir.path().join("profiles").join("notes.txt"), "").unwrap();
+
+        let profiles = store.list().unwrap();
+        let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob", "carol"]);
+        let board = leaderboard(&profiles);
+        let ranked: Vec<&str> = board
+            .lines()
+            .skip(1)
+            .map(|line| line.split_whitespace().nth(1).unwrap())
+            .collect();
+        assert_eq!(ranked, ["bob", "carol", "alice"]);
+    }
+}
@@ -0,0 +1,752 @@
+//! Module du protocole en lignes destiné aux interfaces externes.
+//!
+//! Avec `dual_game --protocol`, le jeu lit des commandes sur son entrée et écrit des événements sur sa
+//! sortie, un par ligne, sans invite ni compteur animé : l'interface mesure elle-même l'arrêt du
+//! compteur et en transmet la valeur. Le [`ProtocolController`] relaie les décisions d'un joueur à
+//! l'interface et le [`ProtocolObserver`] lui transmet les événements de la partie, tous deux par
+//! l'intermédiaire d'un [`ProtocolIo`] partagé.
+//!
+//! # Commandes
+//!
+//! Une commande par ligne ; les mots-clés ne tiennent pas compte de la casse et les lignes vides sont
+//! ignorées.
+//!
+//! ```text
+//! STOP <compteur> [<miss> [<endurance>]]   arrête le compteur (0 à 100), après <miss> tours complets,
+//!                                          en dépensant <endurance> points d'endurance (défaut : 0)
+//! POISON <n>                               choisit le n-ième poison proposé (0 : aucun poison)
+//! COUNTER yes|no                           contre ou accepte le poison
+//! CONTINUE                                 poursuit la partie après une pause
+//! QUIT                                     abandonne la partie
+//! ```
+//!
+//! # Événements
+//!
+//! `EVENT <nom> [<valeur>] [<clé>=<valeur>]...` ; les joueurs sont désignés par leur index (0 ou 1).
+//! Une valeur contenant une espace, un guillemet ou une barre oblique inverse est entourée de
+//! guillemets, les deux derniers caractères y étant précédés d'une barre oblique inverse.
+//!
+//! ```text
+//! EVENT game_start objectives=5 player0=Alice player1="Jean Bob"
+//! EVENT round_start 1
+//! EVENT objectives player=0 values=12,40,88,3,57
+//! EVENT need_continue player=0
+//! EVENT need_stop player=0 objective=12 index=0 speed=75 strength=50
+//! EVENT stop player=0 objective=12 counter=13 miss=0 score=130
+//! EVENT turn_end player=0 average=112
+//! EVENT round_tied score=90 consecutive=1
+//! EVENT damage winner=0 loser=1 damage=30 absorbed=0 vitality=20
+//! EVENT winners_curse player=0 margin=61 speed=3 round=2
+//! EVENT need_poison player=0 options=speed,strength
+//! EVENT need_counter player=1 poison=speed extra_damage=30
+//! EVENT poison player=1 poison=speed
+//! EVENT poison_countered player=1 extra_damage=30 vitality=0
+//! EVENT round_end 1
+//! EVENT game_end result=victory winner=0 rounds=1
+//! ```
+//!
+//! `need_stop` comporte aussi `stamina=<n>` lorsque la règle d'endurance est active. Une commande
+//! invalide ou inattendue produit `ERROR line=<n> message=<texte>`, puis la commande est attendue à
+//! nouveau. `QUIT`, ou la fin de l'entrée, fait abandonner le joueur dont une décision est attendue.
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::rc::Rc;
+use std::str::FromStr;
+
+use crate::controller::{Controller, CounterView, StopDecision};
+use crate::game::GameResult;
+use crate::messages::Msg;
+use crate::observer::{GameEvent, GameObserver};
+use crate::player::Player;
+use crate::poison::PoisonType;
+use crate::tr;
+
+/// Commande envoyée par l'interface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Command {
+    /// Arrêt du compteur.
+    Stop(StopDecision),
+    /// Poison choisi, numéroté à partir de 1 ; 0 pour n'appliquer aucun poison.
+    Poison(usize),
+    /// Réponse à la proposition de contrer le poison.
+    Counter(bool),
+    /// Poursuite de la partie après une pause.
+    Continue,
+    /// Abandon de la partie.
+    Quit,
+}
+
+impl Command {
+    /// Retourne le mot-clé de la commande.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Command::Stop(_) => "STOP",
+            Command::Poison(_) => "POISON",
+            Command::Counter(_) => "COUNTER",
+            Command::Continue => "CONTINUE",
+            Command::Quit => "QUIT",
+        }
+    }
+}
+
+/// Erreur d'analyse ou d'enchaînement d'une commande.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProtocolError {
+    /// Le mot-clé n'est pas une commande.
+    UnknownCommand {
+        /// Le mot-clé reçu.
+        command: String,
+    },
+    /// Un argument obligatoire est absent.
+    MissingArgument {
+        /// Le mot-clé de la commande.
+        command: &'static str,
+    },
+    /// Un argument n'est pas valide.
+    InvalidArgument {
+        /// Le mot-clé de la commande.
+        command: &'static str,
+        /// L'argument reçu.
+        value: String,
+    },
+    /// La commande a reçu trop d'arguments.
+    ExtraArgument {
+        /// Le mot-clé de la commande.
+        command: &'static str,
+    },
+    /// La commande est valide mais ne répond pas à la décision attendue.
+    Unexpected {
+        /// Le mot-clé de la commande.
+        command: &'static str,
+        /// Le mot-clé de la commande attendue.
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            ProtocolError::UnknownCommand { command } => {
+                tr!(Msg::ProtocolUnknownCommand, command = command)
+            }
+            ProtocolError::MissingArgument { command } => {
+                tr!(Msg::ProtocolMissingArgument, command = command)
+            }
+            ProtocolError::InvalidArgument { command, value } => {
+                tr!(
+                    Msg::ProtocolInvalidArgument,
+                    command = command,
+                    value = value
+                )
+            }
+            ProtocolError::ExtraArgument { command } => {
+                tr!(Msg::ProtocolExtraArgument, command = command)
+            }
+            ProtocolError::Unexpected { command, expected } => {
+                tr!(
+                    Msg::ProtocolUnexpected,
+                    command = command,
+                    expected = expected
+                )
+            }
+        };
+        write!(f, "{text}")
+    }
+}
+
+impl Error for ProtocolError {}
+
+/// Analyse l'argument numérique d'une commande.
+fn number<T: FromStr>(command: &'static str, value: Option<&str>) -> Result<T, ProtocolError> {
+    let value = value.ok_or(ProtocolError::MissingArgument { command })?;
+    value.parse().map_err(|_| ProtocolError::InvalidArgument {
+        command,
+        value: value.to_string(),
+    })
+}
+
+impl FromStr for Command {
+    type Err = ProtocolError;
+
+    /// Analyse une ligne de commande.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::protocol::{Command, ProtocolError};
+    ///
+    /// assert_eq!("poison 2".parse(), Ok(Command::Poison(2)));
+    /// assert_eq!(
+    ///     "STOP".parse::<Command>(),
+    ///     Err(ProtocolError::MissingArgument { command: "STOP" })
+    /// );
+    /// ```
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut words = line.split_whitespace();
+        let keyword = words.next().unwrap_or_default();
+        let (command, arguments) = match keyword.to_ascii_uppercase().as_str() {
+            "STOP" => {
+                let counter_value: u32 = number("STOP", words.next())?;
+                if counter_value > 100 {
+                    return Err(ProtocolError::InvalidArgument {
+                        command: "STOP",
+                        value: counter_value.to_string(),
+                    });
+                }
+                let miss = words.next().map_or(Ok(0), |w| number("STOP", Some(w)))?;
+                let stamina_spent = words.next().map_or(Ok(0), |w| number("STOP", Some(w)))?;
+                let stop = StopDecision {
+                    counter_value,
+                    miss,
+                    stamina_spent,
+                };
+                (Command::Stop(stop), words)
+            }
+            "POISON" => (Command::Poison(number("POISON", words.next())?), words),
+            "COUNTER" => {
+                let answer = match words.next().map(str::to_ascii_lowercase).as_deref() {
+                    Some("yes") => true,
+                    Some("no") => false,
+                    Some(other) => {
+                        return Err(ProtocolError::InvalidArgument {
+                            command: "COUNTER",
+                            value: other.to_string(),
+                        });
+                    }
+                    None => return Err(ProtocolError::MissingArgument { command: "COUNTER" }),
+                };
+                (Command::Counter(answer), words)
+            }
+            "CONTINUE" => (Command::Continue, words),
+            "QUIT" => (Command::Quit, words),
+            _ => {
+                return Err(ProtocolError::UnknownCommand {
+                    command: keyword.to_string(),
+                });
+            }
+        };
+        match arguments.count() {
+            0 => Ok(command),
+            _ => Err(ProtocolError::ExtraArgument {
+                command: command.keyword(),
+            }),
+        }
+    }
+}
+
+/// Événement écrit à destination de l'interface.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    /// Nom de l'événement.
+    name: &'static str,
+    /// Valeur principale, écrite juste après le nom.
+    value: Option<String>,
+    /// Champs `clé=valeur`, dans leur ordre d'écriture.
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Event {
+    /// Crée un événement sans valeur ni champ.
+    pub fn new(name: &'static str) -> Self {
+        Event {
+            name,
+            value: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Fixe la valeur principale de l'événement.
+    pub fn value(mut self, value: impl ToString) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    /// Ajoute un champ à l'événement.
+    pub fn field(mut self, key: &'static str, value: impl ToString) -> Self {
+        self.fields.push((key, value.to_string()));
+        self
+    }
+}
+
+/// Valeur écrite entre guillemets si elle est vide ou contient une espace, un guillemet ou une barre
+/// oblique inverse.
+struct Quoted<'a>(&'a str);
+
+impl fmt::Display for Quoted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let special = |c: char| c.is_whitespace() || c == '"' || c == '\\';
+        if !self.0.is_empty() && !self.0.contains(special) {
+            return write!(f, "{}", self.0);
+        }
+        write!(f, "\"")?;
+        for c in self.0.chars() {
+            if c == '"' || c == '\\' {
+                write!(f, "\\")?;
+            }
+            write!(f, "{c}")?;
+        }
+        write!(f, "\"")
+    }
+}
+
+impl fmt::Display for Event {
+    /// Formate l'événement selon la grammaire du protocole.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::protocol::Event;
+    ///
+    /// let event = Event::new("game_start").field("player0", "Jean Bob");
+    /// assert_eq!(event.to_string(), r#"EVENT game_start player0="Jean Bob""#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EVENT {}", self.name)?;
+        if let Some(value) = &self.value {
+            write!(f, " {}", Quoted(value))?;
+        }
+        for (key, value) in &self.fields {
+            write!(f, " {key}={}", Quoted(value))?;
+        }
+        Ok(())
+    }
+}
+
+/// Entrée des commandes et sortie des événements du protocole.
+#[derive(Debug)]
+pub struct ProtocolIo<R, W> {
+    /// Source des commandes.
+    input: R,
+    /// Destination des événements.
+    output: W,
+    /// Numéro de la dernière ligne lue.
+    line: usize,
+}
+
+/// Entrée et sortie du protocole partagées entre les contrôleurs et l'observateur d'une partie.
+pub type SharedProtocolIo<R, W> = Rc<RefCell<ProtocolIo<R, W>>>;
+
+impl<R: BufRead, W: Write> ProtocolIo<R, W> {
+    /// Crée l'entrée et la sortie du protocole.
+    pub fn new(input: R, output: W) -> Self {
+        ProtocolIo {
+            input,
+            output,
+            line: 0,
+        }
+    }
+
+    /// Crée l'entrée et la sortie du protocole, partagées.
+    pub fn shared(input: R, output: W) -> SharedProtocolIo<R, W> {
+        Rc::new(RefCell::new(ProtocolIo::new(input, output)))
+    }
+
+    /// Écrit une ligne et la transmet aussitôt.
+    ///
+    /// Une interface qui ne lit plus sa sortie ne doit pas interrompre la partie : l'erreur est
+    /// seulement consignée.
+    fn write_line(&mut self, line: &dyn fmt::Display) {
+        let written = writeln!(self.output, "{line}").and_then(|()| self.output.flush());
+        if let Err(err) = written {
+            log::warn!("{err}");
+        }
+    }
+
+    /// Écrit un événement.
+    pub fn emit(&mut self, event: &Event) {
+        self.write_line(event);
+    }
+
+    /// Signale une commande invalide ou inattendue à l'interface.
+    pub fn report(&mut self, err: &ProtocolError) {
+        let message = err.to_string();
+        let line = format!("ERROR line={} message={}", self.line, Quoted(&message));
+        self.write_line(&line);
+    }
+
+    /// Lit la prochaine commande valide, en signalant chaque ligne invalide.
+    ///
+    /// Retourne `None` à la fin de l'entrée ou si elle ne peut plus être lue.
+    pub fn command(&mut self) -> Option<Command> {
+        loop {
+            let mut text = String::new();
+            match self.input.read_line(&mut text) {
+                Ok(0) => return None,
+                Ok(_) => self.line += 1,
+                Err(err) => {
+                    log::warn!("{err}");
+                    return None;
+                }
+            }
+            if text.trim().is_empty() {
+                continue;
+            }
+            match text.parse() {
+                Ok(command) => return Some(command),
+                Err(err) => self.report(&err),
+            }
+        }
+    }
+}
+
+/// Contrôleur relayant les décisions d'un joueur à l'interface.
+///
+/// Si l'interface abandonne (`QUIT`) ou ferme son entrée alors qu'une décision du joueur est attendue,
+/// les décisions suivantes sont neutres et le joueur abandonne la partie.
+#[derive(Debug)]
+pub struct ProtocolController<R, W> {
+    /// Index du joueur, rappelé dans chaque demande.
+    index: usize,
+    /// Entrée et sortie du protocole.
+    io: SharedProtocolIo<R, W>,
+    /// Indique si le joueur a abandonné.
+    forfeited: bool,
+}
+
+impl<R: BufRead, W: Write> ProtocolController<R, W> {
+    /// Crée le contrôleur du joueur d'index `index`.
+    pub fn new(index: usize, io: SharedProtocolIo<R, W>) -> Self {
+        ProtocolController {
+            index,
+            io,
+            forfeited: false,
+        }
+    }
+
+    /// Crée une demande adressée à l'interface pour ce joueur.
+    fn request(&self, name: &'static str) -> Event {
+        Event::new(name).field("player", self.index)
+    }
+
+    /// Écrit une demande, puis lit les commandes jusqu'à celle qu'accepte `parse`.
+    ///
+    /// Les commandes qui ne répondent pas à la demande sont signalées comme inattendues. Retourne
+    /// `None`, le joueur abandonnant, sur `QUIT` ou à la fin de l'entrée.
+    fn ask<T>(
+        &mut self,
+        request: Event,
+        expected: &'static str,
+        parse: impl Fn(Command) -> Option<T>,
+    ) -> Option<T> {
+        if self.forfeited {
+            return None;
+        }
+        let mut io = self.io.borrow_mut();
+        io.emit(&request);
+        loop {
+            let command = match io.command() {
+                None | Some(Command::Quit) => break,
+                Some(command) => command,
+            };
+            match parse(command) {
+                Some(value) => return Some(value),
+                None => io.report(&ProtocolError::Unexpected {
+                    command: command.keyword(),
+                    expected,
+                }),
+            }
+        }
+        self.forfeited = true;
+        None
+    }
+}
+
+/// Retourne le nom d'un poison dans le protocole.
+fn poison_name(poison: &PoisonType) -> &'static str {
+    match poison {
+        PoisonType::Speed => "speed",
+        PoisonType::Strength => "strength",
+    }
+}
+
+impl<R: BufRead, W: Write> Controller for ProtocolController<R, W> {
+    /// L'interface mesure l'arrêt du compteur ; l'endurance dépensée est bornée par celle du joueur.
+    fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+        let mut request = self
+            .request("need_stop")
+            .field("objective", ctx.objective)
+            .field("index", ctx.index)
+            .field("speed", ctx.speed)
+            .field("strength", ctx.strength);
+        if let Some(stamina) = ctx.stamina {
+            request = request.field("stamina", stamina);
+        }
+        let stop = self.ask(request, "STOP", |command| match command {
+            Command::Stop(stop) => Some(StopDecision {
+                stamina_spent: stop.stamina_spent.min(ctx.stamina.unwrap_or(0)),
+                ..stop
+            }),
+            _ => None,
+        });
+        stop.unwrap_or(StopDecision {
+            counter_value: 0,
+            miss: 0,
+            stamina_spent: 0,
+        })
+    }
+
+    fn choose_poison(&mut self, options: &[PoisonType], _opponent: &Player) -> usize {
+        let names: Vec<_> = options.iter().map(poison_name).collect();
+        let request = self
+            .request("need_poison")
+            .field("options", names.join(","));
+        let choice = self.ask(request, "POISON", |command| match command {
+            Command::Poison(choice) => Some(choice),
+            _ => None,
+        });
+        choice
+            .and_then(|choice| choice.checked_sub(1))
+            .unwrap_or(usize::MAX)
+    }
+
+    fn choose_counter(&mut self, poison: &PoisonType, extra_damage: u32, _me: &Player) -> bool {
+        let request = self
+            .request("need_counter")
+            .field("poison", poison_name(poison))
+            .field("extra_damage", extra_damage);
+        self.ask(request, "COUNTER", |command| match command {
+            Command::Counter(counter) => Some(counter),
+            _ => None,
+        })
+        .unwrap_or(false)
+    }
+
+    fn acknowledge(&mut self, _msg: &str) {
+        self.ask(self.request("need_continue"), "CONTINUE", |command| {
+            (command == Command::Continue).then_some(())
+        });
+    }
+
+    fn has_forfeited(&self) -> bool {
+        self.forfeited
+    }
+}
+
+/// Observateur transmettant les événements de la partie à l'interface.
+#[derive(Debug)]
+pub struct ProtocolObserver<R, W> {
+    /// Entrée et sortie du protocole.
+    io: SharedProtocolIo<R, W>,
+    /// Noms des joueurs de la partie en cours, pour retrouver leur index.
+    players: Vec<String>,
+}
+
+impl<R: BufRead, W: Write> ProtocolObserver<R, W> {
+    /// Crée un observateur écrivant sur la sortie du protocole.
+    pub fn new(io: SharedProtocolIo<R, W>) -> Self {
+        ProtocolObserver {
+            io,
+            players: Vec::new(),
+        }
+    }
+
+    /// Retourne l'index du joueur nommé `name`.
+    fn index(&self, name: &str) -> usize {
+        self.players.iter().position(|p| p == name).unwrap_or(0)
+    }
+
+    /// Traduit un événement de la partie dans le protocole.
+    fn event(&mut self, event: &GameEvent) -> Event {
+        match event {
+            GameEvent::GameStarted { players, objectifs } => {
+                self.players = players.clone();
+                let mut start = Event::new("game_start").field("objectives", objectifs);
+                for (key, name) in ["player0", "player1"].into_iter().zip(players) {
+                    start = start.field(key, name);
+                }
+                start
+            }
+            GameEvent::RoundStarted { round, .. } => Event::new("round_start").value(round),
+            GameEvent::ObjectivesDrawn { player, objectives } => {
+                let values: Vec<_> = objectives.iter().map(u32::to_string).collect();
+                Event::new("objectives")
+                    .field("player", self.index(player))
+                    .field("values", values.join(","))
+            }
+            GameEvent::CounterStopped { player, stop } => Event::new("stop")
+                .field("player", self.index(player))
+                .field("objective", stop.objective)
+                .field("counter", stop.counter)
+                .field("miss", stop.miss)
+                .field("score", stop.score),
+            GameEvent::TurnEnded { player, average } => Event::new("turn_end")
+                .field("player", self.index(player))
+                .field("average", average),
+            GameEvent::RoundTied { score, consecutive } => Event::new("round_tied")
+                .field("score", score)
+                .field("consecutive", consecutive),
+            GameEvent::DamageDealt {
+                winner,
+                loser,
+                damage,
+                absorbed,
+                vitality,
+            } => Event::new("damage")
+                .field("winner", self.index(winner))
+                .field("loser", self.index(loser))
+                .field("damage", damage)
+                .field("absorbed", absorbed)
+                .field("vitality", vitality),
+            GameEvent::WinnersCurse {
+                player,
+                margin,
+                speed,
+                round,
+            } => Event::new("winners_curse")
+                .field("player", self.index(player))
+                .field("margin", margin)
+                .field("speed", speed)
+                .field("round", round),
+            GameEvent::PoisonApplied { player, poison } => Event::new("poison")
+                .field("player", self.index(player))
+                .field("poison", poison_name(poison)),
+            GameEvent::PoisonCountered {
+                player,
+                extra_damage,
+                vitality,
+            } => Event::new("poison_countered")
+                .field("player", self.index(player))
+                .field("extra_damage", extra_damage)
+                .field("vitality", vitality),
+            GameEvent::RoundEnded { round } => Event::new("round_end").value(round),
+            GameEvent::GameEnded { result, rounds, .. } => {
+                let outcome = match result {
+                    GameResult::Victory { .. } => "victory",
+                    GameResult::Draw => "draw",
+                    GameResult::Forfeit { .. } => "forfeit",
+                    GameResult::Interrupted => "interrupted",
+                };
+                let mut end = Event::new("game_end").field("result", outcome);
+                if let Some(winner) = result.winner() {
+                    end = end.field("winner", winner.index());
+                }
+                end.field("rounds", rounds)
+            }
+        }
+    }
+}
+
+impl<R: BufRead, W: Write> GameObserver for ProtocolObserver<R, W> {
+    fn on_event(&mut self, event: &GameEvent) {
+        let event = self.event(event);
+        self.io.borrow_mut().emit(&event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cancel::CancelToken;
+    use crate::rules::GameRules;
+
+    /// Vérifie l'analyse des commandes et les erreurs signalées pour les commandes malformées.
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            "stop 42".parse(),
+            Ok(Command::Stop(StopDecision::from_offset(42, 0)))
+        );
+        assert_eq!(
+            "STOP 7 2 1".parse(),
+            Ok(Command::Stop(StopDecision {
+                counter_value: 7,
+                miss: 2,
+                stamina_spent: 1
+            }))
+        );
+        assert_eq!("Counter YES".parse(), Ok(Command::Counter(true)));
+        assert_eq!(" continue ".parse(), Ok(Command::Continue));
+        assert_eq!(
+            "STOP 101".parse::<Command>(),
+            Err(ProtocolError::InvalidArgument {
+                command: "STOP",
+                value: String::from("101")
+            })
+        );
+        assert_eq!(
+            "POISON x".parse::<Command>(),
+            Err(ProtocolError::InvalidArgument {
+                command: "POISON",
+                value: String::from("x")
+            })
+        );
+        assert_eq!(
+            "COUNTER".parse::<Command>(),
+            Err(ProtocolError::MissingArgument { command: "COUNTER" })
+        );
+        assert_eq!(
+            "CONTINUE now".parse::<Command>(),
+            Err(ProtocolError::ExtraArgument {
+                command: "CONTINUE"
+            })
+        );
+        assert_eq!(
+            "JUMP".parse::<Command>(),
+            Err(ProtocolError::UnknownCommand {
+                command: String::from("JUMP")
+            })
+        );
+    }
+
+    /// Vérifie qu'une commande inattendue est signalée, puis que la fin de l'entrée fait abandonner.
+    #[test]
+    fn test_controller_errors_and_forfeit() {
+        let input = "POISON 1\n\nSTOP 40 1\nSTOP 12\n";
+        let io = ProtocolIo::shared(input.as_bytes(), Vec::new());
+        let mut controller = ProtocolController::new(1, Rc::clone(&io));
+        let view = CounterView {
+            objective: 50,
+            index: 0,
+            speed: 75,
+            hidden: false,
+            strength: 50,
+            stamina: None,
+            assist: None,
+            cancel: CancelToken::new(),
+            rules: GameRules::default(),
+        };
+        assert_eq!(
+            controller.stop_counter(&view),
+            StopDecision::from_offset(40, 101)
+        );
+        assert_eq!(controller.stop_counter(&view).counter_value, 12);
+        assert!(!controller.has_forfeited());
+        controller.acknowledge("");
+        assert!(controller.has_forfeited());
+
+        let output = String::from_utf8(io.borrow().output.clone()).unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(
+            lines[0],
+            "EVENT need_stop player=1 objective=50 index=0 speed=75 strength=50"
+        );
+        assert!(lines[1].starts_with("ERROR line=1 message="), "{output}");
+        assert_eq!(lines[3], "EVENT need_continue player=1");
+    }
+
+    /// Vérifie que la malédiction du vainqueur est transmise avec l'index du joueur maudit.
+    #[test]
+    fn test_observer_winners_curse() {
+        let io = ProtocolIo::shared("".as_bytes(), Vec::new());
+        let mut observer = ProtocolObserver::new(Rc::clone(&io));
+        observer.on_event(&GameEvent::GameStarted {
+            players: vec![String::from("Alice"), String::from("Bob")],
+            objectifs: 2,
+        });
+        observer.on_event(&GameEvent::WinnersCurse {
+            player: String::from("Bob"),
+            margin: 61,
+            speed: 3,
+            round: 2,
+        });
+
+        let output = String::from_utf8(io.borrow().output.clone()).unwrap();
+        assert_eq!(
+            output.lines().nth(1),
+            Some("EVENT winners_curse player=1 margin=61 speed=3 round=2")
+        );
+    }
+}
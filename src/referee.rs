@@ -0,0 +1,136 @@
+//! Module de l'arbitre, un spectateur qui pronostique en secret le vainqueur de chaque manche.
+//!
+//! L'arbitre ne joue pas : il n'a qu'un nom et un contrôleur (voir
+//! [`Game::set_referee`](crate::game::Game::set_referee)). Au début de chaque manche, les joueurs
+//! détournent le regard pendant qu'il désigne le vainqueur qu'il pressent
+//! ([`Controller::choose_pick`](crate::controller::Controller::choose_pick)). Après
+//! [`REFEREE_STREAK`] pronostics justes consécutifs, il impose à la manche suivante un mutateur de
+//! son choix parmi [`POOL`](crate::mutators::POOL), à la place du mutateur tiré au sort.
+
+/// Nombre de pronostics justes consécutifs après lequel l'arbitre impose un mutateur.
+pub const REFEREE_STREAK: u32 = 3;
+
+/// Issue du pronostic de l'arbitre pour une manche.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Verdict {
+    /// Index du joueur pronostiqué.
+    pub pick: usize,
+    /// Indique si le joueur pronostiqué a remporté la manche.
+    pub right: bool,
+    /// Pronostics justes consécutifs, celui-ci compris.
+    pub streak: u32,
+    /// Indique si la série vaut à l'arbitre d'imposer un mutateur à la manche suivante.
+    pub reward: bool,
+}
+
+/// Arbitre de la partie : un participant qui ne joue pas, désigné par son nom.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Referee {
+    /// Nom de l'arbitre.
+    pub name: String,
+    /// Joueur pronostiqué pour la manche en cours, `None` si l'arbitre s'est abstenu.
+    pick: Option<usize>,
+    /// Pronostics justes consécutifs.
+    streak: u32,
+    /// Indique si l'arbitre impose un mutateur à la prochaine manche.
+    reward: bool,
+}
+
+impl Referee {
+    /// Crée un arbitre, sans pronostic ni série en cours.
+    pub fn new(name: String) -> Self {
+        Referee {
+            name,
+            ..Referee::default()
+        }
+    }
+
+    /// Retourne le nombre de pronostics justes consécutifs.
+    pub fn streak(&self) -> u32 {
+        self.streak
+    }
+
+    /// Retient le pronostic de la manche en cours, `None` pour s'abstenir.
+    pub fn pick(&mut self, pick: Option<usize>) {
+        self.pick = pick;
+    }
+
+    /// Confronte le pronostic de la manche au vainqueur (`None` pour une manche nulle).
+    ///
+    /// Un pronostic manqué remet la série à zéro, tout comme une abstention ; la série qui atteint
+    /// [`REFEREE_STREAK`] vaut un mutateur imposé et repart de zéro.
+    ///
+    /// Retourne l'issue du pronostic, ou `None` si l'arbitre s'est abstenu.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::referee::{REFEREE_STREAK, Referee};
+    ///
+    /// let mut referee = Referee::new(String::from("Chloé"));
+    /// for _ in 1..REFEREE_STREAK {
+    ///     referee.pick(Some(0));
+    ///     assert!(!referee.settle(Some(0)).unwrap().reward);
+    /// }
+    /// referee.pick(Some(0));
+    /// assert!(referee.settle(Some(0)).unwrap().reward);
+    /// assert!(referee.take_reward());
+    /// assert!(!referee.take_reward());
+    /// ```
+    pub fn settle(&mut self, winner: Option<usize>) -> Option<Verdict> {
+        let Some(pick) = self.pick.take() else {
+            self.streak = 0;
+            return None;
+        };
+        let right = winner == Some(pick);
+        self.streak = if right { self.streak + 1 } else { 0 };
+        let streak = self.streak;
+        let reward = streak >= REFEREE_STREAK;
+        if reward {
+            self.streak = 0;
+            self.reward = true;
+        }
+        Some(Verdict {
+            pick,
+            right,
+            streak,
+            reward,
+        })
+    }
+
+    /// Indique si l'arbitre impose un mutateur à la manche qui commence, et consomme ce droit.
+    pub fn take_reward(&mut self) -> bool {
+        std::mem::take(&mut self.reward)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie la série de l'arbitre : une manche nulle, un pronostic manqué ou une abstention la
+    /// remettent à zéro, et seule une série complète vaut un mutateur imposé.
+    #[test]
+    fn test_referee_streak() {
+        let mut referee = Referee::new(String::from("Chloé"));
+        referee.pick(Some(1));
+        assert_eq!(
+            referee.settle(Some(1)),
+            Some(Verdict {
+                pick: 1,
+                right: true,
+                streak: 1,
+                reward: false
+            })
+        );
+        referee.pick(Some(1));
+        assert!(!referee.settle(None).unwrap().right);
+        assert_eq!(referee.streak(), 0);
+
+        referee.pick(Some(0));
+        referee.settle(Some(0));
+        assert_eq!(referee.settle(Some(0)), None);
+        assert_eq!(referee.streak(), 0);
+        assert!(!referee.take_reward());
+    }
+}
@@ -0,0 +1,133 @@
+//! Module du menu proposé à la fin d'une partie.
+//!
+//! La fonction [`rematch_menu`] propose de rejouer avec les mêmes réglages, en échangeant les places
+//! des joueurs ou après avoir modifié les réglages, d'afficher le bilan de la session ou de quitter.
+//! Elle ne fait que retourner le choix de l'utilisateur ([`RematchDecision`]), appliqué par
+//! l'application.
+
+use crate::console::Console;
+use crate::messages::Msg;
+use crate::session::Session;
+use crate::tr;
+
+/// Suite choisie à la fin d'une partie.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RematchDecision {
+    /// Revanche avec les mêmes réglages.
+    Same,
+    /// Revanche en échangeant les places des joueurs (le premier joueur change).
+    Swap,
+    /// Modification des réglages avec l'assistant avant la revanche.
+    Edit,
+    /// Fin de la session.
+    Quit,
+}
+
+/// Affiche le menu de fin de partie et lit le choix de l'utilisateur.
+///
+/// Le bilan de la session peut être affiché autant de fois que souhaité avant de choisir. Une saisie
+/// invalide est signalée puis redemandée, et la fin du flux d'entrée met fin à la session.
+///
+/// # Arguments
+///
+/// * `console` - Les entrées/sorties utilisées pour dialoguer avec l'utilisateur.
+/// * `session` - Le bilan de la session, affiché à la demande.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::console::ScriptedConsole;
+/// use dual_game::player::Player;
+/// use dual_game::rematch::{RematchDecision, rematch_menu};
+/// use dual_game::session::Session;
+///
+/// let session = Session::new(&[
+///     Player::new(String::from("Alice"), 50, 50, 50),
+///     Player::new(String::from("Bob"), 50, 50, 50),
+/// ]);
+/// let mut console = ScriptedConsole::new(["2"]);
+/// assert_eq!(rematch_menu(&mut console, &session), RematchDecision::Swap);
+/// ```
+pub fn rematch_menu(console: &mut dyn Console, session: &Session) -> RematchDecision {
+    loop {
+        console.println(&format!("\n{}", tr!(Msg::RematchPrompt)));
+        for option in [
+            Msg::RematchSame,
+            Msg::RematchSwap,
+            Msg::RematchEdit,
+            Msg::RematchStats,
+            Msg::RematchQuit,
+        ] {
+            console.println(&tr!(option));
+        }
+        loop {
+            let Some(answer) = console.prompt("> ") else {
+                return RematchDecision::Quit;
+            };
+            match answer.trim() {
+                "1" => return RematchDecision::Same,
+                "2" => return RematchDecision::Swap,
+                "3" => return RematchDecision::Edit,
+                "4" => {
+                    console.println(&format!("\n{session}"));
+                    break;
+                }
+                "5" => return RematchDecision::Quit,
+                _ => console.println(&tr!(Msg::RematchInvalid)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::ScriptedConsole;
+    use crate::player::Player;
+
+    /// Crée le bilan d'une session entre Alice et Bob.
+    fn session() -> Session {
+        Session::new(&[
+            Player::new(String::from("Alice"), 50, 50, 50),
+            Player::new(String::from("Bob"), 50, 50, 50),
+        ])
+    }
+
+    /// Vérifie chaque choix du menu, la saisie invalide redemandée et l'affichage du bilan.
+    #[test]
+    fn test_rematch_menu_choices() {
+        let cases = [
+            ("1", RematchDecision::Same),
+            ("2", RematchDecision::Swap),
+            ("3", RematchDecision::Edit),
+            ("5", RematchDecision::Quit),
+        ];
+        for (input, expected) in cases {
+            let mut console = ScriptedConsole::new([input]);
+            assert_eq!(rematch_menu(&mut console, &session()), expected);
+        }
+
+        let mut console = ScriptedConsole::new(["Y", "0", "4", " 1 "]);
+        assert_eq!(
+            rematch_menu(&mut console, &session()),
+            RematchDecision::Same
+        );
+        assert_eq!(console.output.matches("Entrée invalide").count(), 2);
+        assert!(
+            console
+                .output
+                .contains("Score de la soirée — Alice 0 : 0 Bob")
+        );
+        assert_eq!(console.output.matches("→ 5: quitter").count(), 2);
+    }
+
+    /// Vérifie que la fin du flux d'entrée met fin à la session.
+    #[test]
+    fn test_rematch_menu_eof() {
+        let mut console = ScriptedConsole::new(["abc"]);
+        assert_eq!(
+            rematch_menu(&mut console, &session()),
+            RematchDecision::Quit
+        );
+    }
+}
@@ -0,0 +1,563 @@
+//! Module de rendu textuel de l'état de la partie.
+//!
+//! Ce module fournit des fonctions produisant des représentations textuelles (barres de vie, etc.)
+//! destinées à l'affichage dans le terminal. Lorsque la largeur du terminal est connue (voir
+//! [`style::terminal_width`]), les barres de vie et le comparatif des joueurs s'y ajustent, les noms
+//! trop longs étant tronqués par [`fit_to_width`].
+
+use std::borrow::Borrow;
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::player::{Player, PlayerId, PlayerSnapshot};
+use crate::scoring::ScoringCalculator;
+use crate::style::{self, Role};
+
+/// Largeur par défaut, en caractères, d'une barre de vie.
+pub const HEALTH_BAR_WIDTH: usize = 20;
+
+/// Largeur minimale d'une barre de vie raccourcie pour tenir dans un terminal étroit.
+const MIN_HEALTH_BAR_WIDTH: usize = 5;
+
+/// Largeur minimale d'un nom tronqué, points de suspension compris.
+const MIN_NAME_WIDTH: usize = 4;
+
+/// Tronque un texte à la largeur d'affichage donnée, en le terminant par des points de suspension.
+///
+/// La largeur est celle du terminal : un caractère large (emoji, idéogramme) occupe deux colonnes et
+/// un caractère combinant aucune. Un texte qui tient dans la largeur est retourné tel quel. Les points
+/// de suspension sont `…`, ou `...` si l'affichage est limité à l'ASCII. Le texte ne doit pas contenir
+/// de séquences d'échappement ANSI.
+///
+/// # Arguments
+///
+/// * `s` - Le texte à tronquer.
+/// * `width` - La largeur maximale, en colonnes.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::render::fit_to_width;
+///
+/// assert_eq!(fit_to_width("Alice", 10), "Alice");
+/// assert_eq!(fit_to_width("Maximilienne", 6), "Maxim…");
+/// ```
+pub fn fit_to_width(s: &str, width: usize) -> String {
+    fit(s, width, style::is_ascii())
+}
+
+/// Tronque un texte à la largeur donnée, avec des points de suspension ASCII si demandé.
+fn fit(s: &str, width: usize, ascii: bool) -> String {
+    if s.width() <= width {
+        return s.to_string();
+    }
+    let ellipsis = if ascii { "..." } else { "…" };
+    if width < ellipsis.width() {
+        return ".".repeat(width);
+    }
+    let budget = width - ellipsis.width();
+    let mut fitted = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > budget {
+            break;
+        }
+        used += w;
+        fitted.push(c);
+    }
+    fitted.push_str(ellipsis);
+    fitted
+}
+
+/// Retourne le nom stylé d'un joueur (avatar, couleur), tronqué à la largeur donnée, et sa largeur.
+fn fitted_name(player: &Player, width: usize, colored: bool, ascii: bool) -> (String, usize) {
+    if player.name_width(ascii) <= width {
+        return (player.styled_name(colored, ascii), player.name_width(ascii));
+    }
+    let avatar = player.name_width(ascii) - player.name.width();
+    let mut short = player.clone();
+    short.name = fit(&player.name, width.saturating_sub(avatar), ascii);
+    (short.styled_name(colored, ascii), short.name_width(ascii))
+}
+
+/// Produit une barre de vie, par exemple `[██████████░░░░░░░░░░] 25/50`.
+///
+/// La barre est mise à l'échelle de la vitalité maximale et arrondie à la case la plus proche.
+/// Une barre n'est pleine qu'à vitalité maximale et n'est vide qu'à vitalité nulle. La partie
+/// remplie est colorée en vert, jaune ou rouge selon le pourcentage restant si les couleurs sont
+/// activées, et la barre est dessinée en `#` et `-` si l'affichage est limité à l'ASCII.
+///
+/// # Arguments
+///
+/// * `current` - La vitalité actuelle.
+/// * `max` - La vitalité maximale.
+/// * `width` - Le nombre de cases de la barre.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::render::render_health_bar;
+/// use dual_game::style;
+///
+/// style::set_enabled(false);
+/// assert_eq!(render_health_bar(25, 50, 10), "[█████░░░░░] 25/50");
+/// ```
+pub fn render_health_bar(current: u32, max: u32, width: usize) -> String {
+    health_bar(current, max, width, style::is_enabled(), style::is_ascii())
+}
+
+/// Affiche le nom de chaque joueur suivi de sa barre de vie, les barres étant alignées.
+#[cfg(feature = "cli")]
+pub fn print_health_bars<P: Borrow<Player>>(players: &[P]) {
+    println!("{}", health_bars(players));
+}
+
+/// Produit les lignes de `print_health_bars`, séparées par des retours à la ligne.
+pub fn health_bars<P: Borrow<Player>>(players: &[P]) -> String {
+    health_bar_rows(players).join("\n")
+}
+
+/// Produit les lignes de [`health_bars`] séparément, selon les couleurs, le mode ASCII et la largeur
+/// du terminal retenus.
+pub(crate) fn health_bar_rows<P: Borrow<Player>>(players: &[P]) -> Vec<String> {
+    health_bar_lines(
+        players,
+        style::is_enabled(),
+        style::is_ascii(),
+        style::terminal_width(),
+    )
+}
+
+/// Produit les lignes de [`health_bar_rows`] pour les vitalités d'instantanés, l'identifiant de chaque
+/// instantané désignant un joueur de la liste, dont le nom et l'avatar sont affichés.
+pub(crate) fn snapshot_rows(players: &[&Player], snapshots: &[PlayerSnapshot]) -> Vec<String> {
+    snapshot_lines(
+        players,
+        snapshots,
+        style::is_enabled(),
+        style::is_ascii(),
+        style::terminal_width(),
+    )
+}
+
+/// Produit, pour chaque joueur, son nom (précédé de son avatar) suivi de sa barre de vie.
+///
+/// Les noms sont complétés par des espaces selon leur largeur d'affichage, de sorte que les barres
+/// restent alignées même en présence de caractères larges comme les emojis. Si la largeur du terminal
+/// est connue, les noms sont tronqués, puis les barres raccourcies, pour que chaque ligne y tienne
+/// sans toucher la dernière colonne.
+fn health_bar_lines<P: Borrow<Player>>(
+    players: &[P],
+    colored: bool,
+    ascii: bool,
+    width: Option<usize>,
+) -> Vec<String> {
+    let players: Vec<&Player> = players.iter().map(Borrow::borrow).collect();
+    let snapshots: Vec<PlayerSnapshot> = players
+        .iter()
+        .enumerate()
+        .map(|(index, player)| player.snapshot(PlayerId(index)))
+        .collect();
+    snapshot_lines(&players, &snapshots, colored, ascii, width)
+}
+
+/// Produit les lignes de [`health_bar_lines`] pour les vitalités des instantanés donnés.
+fn snapshot_lines(
+    players: &[&Player],
+    snapshots: &[PlayerSnapshot],
+    colored: bool,
+    ascii: bool,
+    width: Option<usize>,
+) -> Vec<String> {
+    let mut name_width = players
+        .iter()
+        .map(|p| p.name_width(ascii))
+        .max()
+        .unwrap_or(0);
+    let mut cells = HEALTH_BAR_WIDTH;
+    if let Some(width) = width {
+        // Espace, crochets et espace autour des cases, puis la vitalité (« 25/50 »).
+        let fixed = 4 + snapshots
+            .iter()
+            .map(|s| format!("{}/{}", s.vitality, s.max_vitality).len())
+            .max()
+            .unwrap_or(0);
+        let available = width.saturating_sub(1);
+        name_width = name_width.min(available.saturating_sub(fixed + cells).max(MIN_NAME_WIDTH));
+        cells = cells.min(
+            available
+                .saturating_sub(fixed + name_width)
+                .max(MIN_HEALTH_BAR_WIDTH),
+        );
+    }
+    snapshots
+        .iter()
+        .map(|snapshot| {
+            let player = players[snapshot.id.index()];
+            let (name, used) = fitted_name(player, name_width, colored, ascii);
+            format!(
+                "{name}{} {}",
+                " ".repeat(name_width.saturating_sub(used)),
+                health_bar(
+                    snapshot.vitality,
+                    snapshot.max_vitality,
+                    cells,
+                    colored,
+                    ascii
+                )
+            )
+        })
+        .collect()
+}
+
+/// Produit un comparatif des deux joueurs avant le début de la partie.
+///
+/// Chaque caractéristique est suivie d'une flèche pointant vers le joueur avantagé (`<` pour le
+/// premier, `>` pour le second, `=` en cas d'égalité). Le comparatif indique aussi la cadence du
+/// compteur (incrémentations par seconde) et le score maximal théorique d'un objectif, atteint en
+/// arrêtant le compteur exactement sur l'objectif. La classe, l'armure et la chance ne sont
+/// affichées que si l'un des joueurs en dispose.
+///
+/// # Arguments
+///
+/// * `left` - Le premier joueur.
+/// * `right` - Le second joueur.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::player::Player;
+/// use dual_game::render::matchup;
+///
+/// let alice = Player::new(String::from("Alice"), 50, 75, 50);
+/// let bob = Player::new(String::from("Bob"), 40, 75, 50);
+/// assert!(matchup(&alice, &bob).contains("Vitalité"));
+/// ```
+pub fn matchup(left: &Player, right: &Player) -> String {
+    matchup_within(left, right, style::terminal_width())
+}
+
+/// Produit le comparatif de [`matchup`], ajusté à la largeur du terminal si elle est connue.
+///
+/// Les noms des joueurs sont tronqués pour que la ligne d'en-tête tienne dans la largeur, puis chaque
+/// ligne est tronquée par sécurité.
+fn matchup_within(left: &Player, right: &Player, width: Option<usize>) -> String {
+    let mut rows: Vec<(&str, String, &str, String)> = Vec::new();
+    if left.class.is_some() || right.class.is_some() {
+        let class = |p: &Player| p.class.map_or(String::from("-"), |c| c.to_string());
+        rows.push(("Classe", class(left), " ", class(right)));
+    }
+    let mut compare = |label, a: u32, b: u32| {
+        rows.push((label, a.to_string(), edge(a, b), b.to_string()));
+    };
+    compare("Vitalité", left.vitality(), right.vitality());
+    compare("Vitesse (ms)", left.speed, right.speed);
+    compare("Force", left.strength, right.strength);
+    if left.armor > 0 || right.armor > 0 {
+        compare("Armure", left.armor, right.armor);
+    }
+    if left.luck > 0 || right.luck > 0 {
+        compare("Chance", left.luck, right.luck);
+    }
+    compare(
+        "Score max / objectif",
+        max_objective_score(left),
+        max_objective_score(right),
+    );
+    rows.push(("Cadence (incr./s)", tick_rate(left), " ", tick_rate(right)));
+
+    let label_width = rows.iter().map(|row| row.0.width()).max().unwrap_or(0);
+    let (left_name, right_name) = match width {
+        Some(width) => {
+            let budget = (width.saturating_sub(label_width + 5) / 2).max(MIN_NAME_WIDTH);
+            (
+                fit_to_width(&left.name, budget),
+                fit_to_width(&right.name, budget),
+            )
+        }
+        None => (left.name.clone(), right.name.clone()),
+    };
+    let left_width = rows
+        .iter()
+        .map(|row| row.1.width())
+        .chain([left_name.width()])
+        .max()
+        .unwrap_or(0);
+    let mut lines = vec![format!(
+        "{} {}{left_name}   {right_name}",
+        " ".repeat(label_width),
+        " ".repeat(left_width - left_name.width()),
+    )];
+    for (label, a, arrow, b) in rows {
+        lines.push(format!(
+            "{label}{} {}{a} {arrow} {b}",
+            " ".repeat(label_width - label.width()),
+            " ".repeat(left_width - a.width()),
+        ));
+    }
+    if let Some(width) = width {
+        for line in &mut lines {
+            *line = fit_to_width(line, width.saturating_sub(1));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Produit une courbe miniature des valeurs, un caractère par valeur, de hauteur proportionnelle à la
+/// plus grande valeur (caractères ASCII si `--ascii` est actif).
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::render::sparkline;
+///
+/// assert_eq!(sparkline(&[0, 35, 70, 140]), "▁▃▅█");
+/// assert_eq!(sparkline(&[]), "");
+/// ```
+pub fn sparkline(values: &[u32]) -> String {
+    spark(values, style::is_ascii())
+}
+
+/// Produit la courbe miniature de [`sparkline`], en caractères ASCII si demandé.
+fn spark(values: &[u32], ascii: bool) -> String {
+    let levels: Vec<char> = if ascii {
+        "_.-=+*#@".chars().collect()
+    } else {
+        "▁▂▃▄▅▆▇█".chars().collect()
+    };
+    let top = levels.len() as u64 - 1;
+    let max = u64::from(values.iter().copied().max().unwrap_or(0));
+    values
+        .iter()
+        .map(|&value| match max {
+            0 => levels[0],
+            _ => levels[((u64::from(value) * top + max / 2) / max) as usize],
+        })
+        .collect()
+}
+
+/// Produit une barre d'histogramme ASCII en `#`, à l'échelle de la plus grande valeur de
+/// l'histogramme : celle-ci occupe `width` caractères. La barre est complétée d'espaces jusqu'à `width`
+/// pour aligner les colonnes qui la suivent.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::render::histogram_bar;
+///
+/// assert_eq!(histogram_bar(3.0, 6.0, 8), "####    ");
+/// assert_eq!(histogram_bar(6.0, 6.0, 4), "####");
+/// assert_eq!(histogram_bar(0.0, 0.0, 3), "   ");
+/// ```
+pub fn histogram_bar(value: f64, max: f64, width: usize) -> String {
+    let filled = if max > 0.0 {
+        ((value / max * width as f64).round() as usize).min(width)
+    } else {
+        0
+    };
+    format!("{:<width$}", "#".repeat(filled))
+}
+
+/// Retourne la flèche pointant vers la valeur la plus élevée.
+fn edge(a: u32, b: u32) -> &'static str {
+    match a.cmp(&b) {
+        std::cmp::Ordering::Greater => "<",
+        std::cmp::Ordering::Less => ">",
+        std::cmp::Ordering::Equal => "=",
+    }
+}
+
+/// Score maximal théorique d'un objectif : compteur arrêté exactement sur l'objectif, sans « miss ».
+fn max_objective_score(player: &Player) -> u32 {
+    ScoringCalculator::calculate_score(0, 0, 0, player.strength)
+}
+
+/// Nombre d'incrémentations du compteur par seconde, formaté avec une décimale.
+fn tick_rate(player: &Player) -> String {
+    if player.speed == 0 {
+        return String::from("-");
+    }
+    format!("{:.1}", 1000.0 / f64::from(player.speed))
+}
+
+/// Calcule le nombre de cases remplies d'une barre de vie.
+fn filled_cells(current: u32, max: u32, width: usize) -> usize {
+    if max == 0 || current == 0 || width == 0 {
+        return 0;
+    }
+    if current >= max {
+        return width;
+    }
+    let (current, max, width) = (u64::from(current), u64::from(max), width as u64);
+    let rounded = (current * width + max / 2) / max;
+    rounded.clamp(1, width - 1) as usize
+}
+
+/// Produit une barre de vie, colorée ou non, dessinée en caractères ASCII (`#` et `-`) si demandé.
+fn health_bar(current: u32, max: u32, width: usize, colored: bool, ascii: bool) -> String {
+    let (full, empty) = if ascii { ("#", "-") } else { ("█", "░") };
+    let filled = filled_cells(current, max, width);
+    let (current_wide, max_wide) = (u64::from(current), u64::from(max));
+    let role = if current_wide * 2 > max_wide {
+        Role::HealthHigh
+    } else if current_wide * 4 > max_wide {
+        Role::HealthMedium
+    } else {
+        Role::HealthLow
+    };
+    format!(
+        "[{}{}] {current}/{max}",
+        style::paint_role_with(&full.repeat(filled), role, colored),
+        empty.repeat(width - filled)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::class::PlayerClass;
+
+    /// Vérifie l'arrondi des cases partiellement remplies.
+    #[test]
+    fn test_health_bar_rounding() {
+        assert_eq!(
+            health_bar(25, 50, 20, false, false),
+            "[██████████░░░░░░░░░░] 25/50"
+        );
+        // 7/50 de 20 cases = 2,8 cases : arrondi à 3.
+        assert_eq!(filled_cells(7, 50, 20), 3);
+        // 6/50 de 20 cases = 2,4 cases : arrondi à 2.
+        assert_eq!(filled_cells(6, 50, 20), 2);
+        // Une demi-case est arrondie à la case supérieure.
+        assert_eq!(filled_cells(5, 40, 4), 1);
+        // Un joueur en vie garde au moins une case, et seule une vitalité pleine remplit la barre.
+        assert_eq!(filled_cells(1, 100, 20), 1);
+        assert_eq!(filled_cells(99, 100, 20), 19);
+        assert_eq!(filled_cells(0, 50, 20), 0);
+        assert_eq!(filled_cells(50, 50, 20), 20);
+    }
+
+    /// Vérifie la couleur de la barre selon le pourcentage de vitalité restant.
+    #[test]
+    fn test_health_bar_color() {
+        assert!(health_bar(40, 50, 10, true, false).contains("\x1b[32m"));
+        assert!(health_bar(20, 50, 10, true, false).contains("\x1b[33m"));
+        assert!(health_bar(10, 50, 10, true, false).contains("\x1b[31m"));
+        assert!(!health_bar(10, 50, 10, false, false).contains('\x1b'));
+        // Les comparaisons ne débordent pas pour une vitalité proche de u32::MAX.
+        assert!(health_bar(u32::MAX, u32::MAX, 10, true, false).contains("\x1b[32m"));
+        assert!(health_bar(u32::MAX / 3, u32::MAX, 10, true, false).contains("\x1b[33m"));
+    }
+
+    /// Vérifie l'alignement des barres avec et sans avatar large, et en mode ASCII.
+    #[test]
+    fn test_health_bars_alignment() {
+        let bar_column = |line: &String| line[..line.find('[').unwrap()].width();
+        let players = vec![
+            Player::builder("Alice").avatar("🦊").build().unwrap(),
+            Player::builder("Bob").build().unwrap(),
+            Player::builder("Eve").avatar("E").build().unwrap(),
+        ];
+        let lines = health_bar_lines(&players, false, false, None);
+        assert_eq!(lines[0], format!("🦊 Alice [{}] 50/50", "█".repeat(20)));
+        assert!(
+            lines
+                .iter()
+                .all(|line| bar_column(line) == bar_column(&lines[0]))
+        );
+        assert_eq!(bar_column(&lines[0]), 9);
+
+        let lines = health_bar_lines(&players, true, false, None);
+        assert!(lines.iter().all(|line| line.contains('\x1b')));
+
+        let lines = health_bar_lines(&players, false, true, None);
+        assert_eq!(lines[0], format!("Alice [{}] 50/50", "#".repeat(20)));
+        assert_eq!(lines[1], format!("Bob   [{}] 50/50", "#".repeat(20)));
+        assert!(lines.iter().all(|line| line.is_ascii()));
+    }
+
+    /// Vérifie la troncature à une largeur d'affichage, caractères multi-octets et larges compris.
+    #[test]
+    fn test_fit_to_width() {
+        assert_eq!(fit("Alice", 5, false), "Alice");
+        assert_eq!(fit("Alice", 4, false), "Ali…");
+        assert_eq!(fit("Alice", 4, true), "A...");
+        assert_eq!(fit("Éloïse-Amélie", 7, false), "Éloïse…");
+        // Un caractère large n'est jamais coupé en deux : la largeur peut rester inférieure.
+        assert_eq!(fit("🦊🦊🦊", 4, false), "🦊…");
+        assert_eq!(fit("日本語の名前", 6, false).width(), 5);
+        // Un caractère combinant reste attaché à sa lettre.
+        assert_eq!(fit("Zoe\u{301} Martin", 4, false), "Zoe\u{301}…");
+        assert_eq!(fit("Alice", 0, false), "");
+        assert_eq!(fit("Alice", 2, true), "..");
+    }
+
+    /// Vérifie la hauteur de chaque caractère de la courbe miniature, en Unicode et en ASCII.
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(spark(&[10, 20, 30, 40, 50, 60, 70], false), "▂▃▄▅▆▇█");
+        assert_eq!(spark(&[0, 100, 50], true), "_@+");
+        assert_eq!(spark(&[0, 0], false), "▁▁");
+        assert_eq!(spark(&[42], true), "@");
+    }
+
+    /// Vérifie que les barres de vie tiennent dans un terminal étroit, noms tronqués et alignés.
+    #[test]
+    fn test_health_bars_narrow() {
+        let players = vec![
+            Player::builder("Maximilienne de la Tour").build().unwrap(),
+            Player::builder("Bob").avatar("🦊").build().unwrap(),
+        ];
+        let lines = health_bar_lines(&players, false, false, Some(40));
+        assert!(lines.iter().all(|line| line.width() < 40), "{lines:#?}");
+        assert!(lines[0].starts_with("Maximilie… ["), "{lines:#?}");
+        let bar_column = |line: &String| line[..line.find('[').unwrap()].width();
+        assert_eq!(bar_column(&lines[0]), bar_column(&lines[1]));
+
+        // Trop étroit pour le nom et la barre complète : la barre est raccourcie.
+        let lines = health_bar_lines(&players, false, false, Some(20));
+        assert!(lines.iter().all(|line| line.width() < 20), "{lines:#?}");
+        assert!(
+            lines[0].contains(&format!("[{}]", "█".repeat(MIN_HEALTH_BAR_WIDTH + 1))),
+            "{lines:#?}"
+        );
+    }
+
+    /// Vérifie le comparatif de deux joueurs, avec les caractéristiques optionnelles.
+    #[test]
+    fn test_matchup() {
+        let alice = Player::new(String::from("Alice"), 50, 75, 50);
+        let bob = Player::new(String::from("Bob"), 40, 80, 50);
+        assert_eq!(
+            matchup(&alice, &bob),
+            [
+                "                     Alice   Bob",
+                "Vitalité                50 < 40",
+                "Vitesse (ms)            75 > 80",
+                "Force                   50 = 50",
+                "Score max / objectif   150 = 150",
+                "Cadence (incr./s)     13.3   12.5",
+            ]
+            .join("\n")
+        );
+
+        let tank = Player::from_class(String::from("Tom"), PlayerClass::Tank);
+        let preview = matchup(&tank, &alice);
+        assert!(preview.contains("Classe"));
+        let armor = preview
+            .lines()
+            .find(|line| line.starts_with("Armure"))
+            .unwrap();
+        assert!(armor.ends_with(" 3 < 0"), "{armor}");
+        assert!(!preview.contains("Chance"));
+
+        let long = Player::new(String::from("Maximilienne de la Tour"), 50, 75, 50);
+        let narrow = matchup_within(&long, &bob, Some(40));
+        assert!(narrow.lines().all(|line| line.width() < 40), "{narrow}");
+        assert!(
+            narrow.lines().next().unwrap().contains("Maximi…   Bob"),
+            "{narrow}"
+        );
+    }
+}
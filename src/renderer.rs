@@ -0,0 +1,3428 @@
+//! Module du rendu du déroulement de la partie.
+//!
+//! La partie ne formate elle-même aucune ligne de son déroulement : à chaque moment à afficher (en-tête de manche, début
+//! de tour, résultat d'un objectif, résultat de la manche, menus du poison, fin de partie), elle appelle
+//! son [`Renderer`], qui écrit dans la [`Output`] de la partie en choisissant le niveau de détail de
+//! chaque ligne. Cinq rendus sont fournis :
+//!
+//! - [`PlainRenderer`], l'affichage textuel habituel ;
+//! - [`FancyRenderer`], qui colore les titres et ajoute des barres (précision de chaque objectif, vie
+//!   des joueurs après chaque manche), éventuellement animées ;
+//! - [`JsonRenderer`], qui écrit un objet JSON par ligne, destiné à un autre programme ;
+//! - [`AccessibleRenderer`], qui décrit la partie par des phrases complètes, destiné aux lecteurs
+//!   d'écran ;
+//! - [`PlainAsciiRenderer`], qui écrit des lignes ASCII à mots-clés anglais stables, destiné aux
+//!   chaînes de journalisation.
+//!
+//! Le rendu est choisi en ligne de commande par `--render` (voir [`RenderStyle`]). Avec `--sound`, la
+//! partie lui transmet aussi des signaux sonores ([`SoundCue`]), joués par la sonnerie du terminal ou
+//! écrits comme des événements par le [`JsonRenderer`].
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::clock::Clock;
+use crate::commentary::Category;
+use crate::export::Outcome;
+use crate::game::GameResult;
+use crate::items::{Item, ShopOutcome};
+use crate::messages::Msg;
+use crate::mutators::RoundModifier;
+use crate::output::{Output, Verbosity};
+use crate::panel::{Panel, PanelMode};
+use crate::player::{DamageOutcome, Player, PlayerId, PlayerSnapshot};
+use crate::poison::{POISON_AMOUNT, PoisonOutcome, PoisonType};
+use crate::referee::{REFEREE_STREAK, Verdict};
+use crate::render::{self, render_health_bar};
+use crate::replay::StopRecord;
+use crate::risk::Risk;
+use crate::rules::{STALEMATE_MIN_DAMAGE, WINNERS_CURSE_SPEED};
+use crate::scoring::ScoringCalculator;
+use crate::stats::{self, GameStats, ObjectiveHighlight};
+use crate::style::{self, Role};
+use crate::tr;
+
+/// Largeur, en caractères, de la barre de précision d'un objectif dans le [`FancyRenderer`].
+const PRECISION_BAR_WIDTH: usize = 10;
+/// Nombre d'étapes de l'animation de la barre de vie du perdant, après sa première image.
+const DRAIN_STEPS: u32 = 5;
+/// Durée de l'animation de la barre de vie du perdant.
+const DRAIN_DURATION: Duration = Duration::from_millis(500);
+/// Nombre de clignotements de la caractéristique empoisonnée.
+const FLASH_COUNT: usize = 3;
+/// Durée de chaque image du clignotement de la caractéristique empoisonnée.
+const FLASH_FRAME: Duration = Duration::from_millis(80);
+
+/// Début du tour d'un joueur.
+#[derive(Clone, Copy, Debug)]
+pub struct TurnView<'a> {
+    /// Place du joueur dans la manche (0 pour le premier à jouer).
+    pub index: usize,
+    /// Le joueur dont c'est le tour.
+    pub player: &'a Player,
+    /// Pénalité de force due à la fatigue (0 sans fatigue).
+    pub fatigue: u32,
+    /// Objectifs du tour.
+    pub objectives: &'a [u32],
+    /// Niveau de détail des lignes destinées au joueur (voir [`Renderer`]).
+    pub prompt: Verbosity,
+}
+
+/// Résultat de l'arrêt du compteur sur un objectif.
+#[derive(Clone, Copy, Debug)]
+pub struct ObjectiveView<'a> {
+    /// Le joueur ayant arrêté le compteur.
+    pub player: &'a Player,
+    /// Force effective du joueur pour ce tour.
+    pub strength: u32,
+    /// Objectif, compteur, « miss » et score.
+    pub stop: StopRecord,
+    /// Durée de l'arrêt du compteur.
+    pub elapsed: Duration,
+    /// Indique si le compteur a déjà été affiché pendant son animation (joueur humain).
+    pub counter_shown: bool,
+    /// Indique si le joueur a joué avec l'assistance, qui ralentit le compteur près de l'objectif.
+    pub assisted: bool,
+}
+
+/// Point d'un objectif disputé en mode duel, une fois les arrêts de chaque joueur joués.
+#[derive(Clone, Copy, Debug)]
+pub struct DuelPointView<'a> {
+    /// Le joueur ayant remporté le point, `None` si les scores sont égaux.
+    pub winner: Option<&'a Player>,
+    /// Points de chaque joueur dans la manche, dans l'ordre des joueurs.
+    pub points: &'a [u32],
+}
+
+impl DuelPointView<'_> {
+    /// Retourne les points de la manche sous la forme « 2 - 1 ».
+    fn score_line(&self) -> String {
+        let points: Vec<String> = self.points.iter().map(u32::to_string).collect();
+        points.join(" - ")
+    }
+}
+
+/// Résultat d'une manche, une fois les scores moyens comparés.
+#[derive(Clone, Copy, Debug)]
+pub enum RoundOutcome<'a> {
+    /// Les deux joueurs ont obtenu le même score moyen : la manche est nulle.
+    Draw {
+        /// Le score moyen commun.
+        score: u32,
+        /// Le nombre de manches nulles consécutives, celle-ci comprise.
+        consecutive: u32,
+        /// Indique si la manche suivante est décisive, jouée sur un seul objectif.
+        tiebreaker_next: bool,
+    },
+    /// Un joueur a remporté la manche et infligé des dégâts à son adversaire.
+    Won {
+        /// Le gagnant de la manche.
+        winner: &'a Player,
+        /// Le perdant, après les dégâts.
+        loser: &'a Player,
+        /// Les dégâts infligés au perdant.
+        damage: DamageOutcome,
+        /// Indique que les scores étaient égaux et que la manche a été départagée au temps.
+        time_tiebreak: bool,
+    },
+}
+
+/// Fin d'une partie.
+#[derive(Clone, Copy, Debug)]
+pub struct EndView<'a> {
+    /// Issue de la partie.
+    pub result: GameResult,
+    /// Joueurs de la partie, dans leur état final.
+    pub players: &'a [Player],
+    /// Scores cumulés des manches terminées, dans l'ordre des joueurs.
+    pub total_scores: &'a [u32],
+    /// Nombre de manches terminées.
+    pub rounds: u32,
+}
+
+/// Tableau des scores, mis à jour au début de chaque manche et à la fin de la partie.
+#[derive(Clone, Debug)]
+pub struct ScoreboardView<'a> {
+    /// Manche qui commence, ou dernière manche terminée en fin de partie.
+    pub round: u32,
+    /// Joueurs de la partie, dans leur état actuel.
+    pub players: &'a [Player],
+    /// Manches remportées par chaque joueur, dans l'ordre des joueurs.
+    pub wins: Vec<u32>,
+    /// Score moyen de chaque joueur lors de la manche précédente, `None` avant la première.
+    pub averages: Vec<Option<u32>>,
+    /// Indique si la partie est terminée.
+    pub finished: bool,
+}
+
+/// Signal sonore accompagnant un moment de la partie.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SoundCue {
+    /// Le compteur a dépassé 100 avant d'être arrêté.
+    Miss,
+    /// Le compteur a été arrêté exactement sur l'objectif.
+    Perfect,
+    /// La partie est terminée.
+    GameOver,
+}
+
+impl SoundCue {
+    /// Retourne le signal correspondant à l'arrêt du compteur, s'il y en a un.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::renderer::SoundCue;
+    /// use dual_game::replay::StopRecord;
+    ///
+    /// let stop = StopRecord { objective: 40, counter: 40, miss: 0, score: 150, retried: false };
+    /// assert_eq!(SoundCue::for_stop(&stop), Some(SoundCue::Perfect));
+    /// let stop = StopRecord { objective: 40, counter: 40, miss: 1, score: 60, retried: false };
+    /// assert_eq!(SoundCue::for_stop(&stop), Some(SoundCue::Miss));
+    /// let stop = StopRecord { objective: 40, counter: 45, miss: 0, score: 130, retried: false };
+    /// assert_eq!(SoundCue::for_stop(&stop), None);
+    /// ```
+    pub fn for_stop(stop: &StopRecord) -> Option<SoundCue> {
+        if stop.miss > 0 {
+            Some(SoundCue::Miss)
+        } else if stats::is_perfect(stop) {
+            Some(SoundCue::Perfect)
+        } else {
+            None
+        }
+    }
+
+    /// Retourne l'identifiant du signal, utilisé par le [`JsonRenderer`].
+    pub fn id(&self) -> &'static str {
+        match self {
+            SoundCue::Miss => "miss",
+            SoundCue::Perfect => "perfect",
+            SoundCue::GameOver => "game_over",
+        }
+    }
+
+    /// Retourne les caractères de sonnerie (BEL) jouant le signal : une sonnerie pour un dépassement,
+    /// deux pour un arrêt parfait, trois pour la fin de la partie.
+    pub fn bells(&self) -> &'static str {
+        match self {
+            SoundCue::Miss => "\x07",
+            SoundCue::Perfect => "\x07\x07",
+            SoundCue::GameOver => "\x07\x07\x07",
+        }
+    }
+}
+
+/// Rendu du déroulement d'une partie.
+///
+/// Chaque méthode correspond à un moment affiché de la partie et écrit ses lignes dans la sortie
+/// donnée, qui les filtre selon leur niveau de détail. Les lignes destinées à un joueur (objectifs,
+/// menus) sont écrites au niveau `prompt` fourni par la partie : [`Verbosity::Quiet`] pour un joueur
+/// humain, qui en a besoin pour jouer, [`Verbosity::Normal`] pour un bot.
+pub trait Renderer {
+    /// Début de la partie, avant la première manche.
+    fn game_start(&mut self, out: &mut Output, players: &[Player]);
+
+    /// En-tête d'une manche.
+    fn round_header(&mut self, out: &mut Output, round: u32, players: &[Player]);
+
+    /// Début du tour d'un joueur, avec ses objectifs.
+    fn turn_start(&mut self, out: &mut Output, turn: &TurnView);
+
+    /// Résultat de l'arrêt du compteur sur un objectif.
+    fn objective_result(&mut self, out: &mut Output, objective: &ObjectiveView);
+
+    /// Fin du tour d'un joueur, avec son score moyen.
+    fn turn_end(&mut self, out: &mut Output, player: &Player, average: u32);
+
+    /// Compte à rebours avant le tour d'un joueur, à `seconds` secondes de son début, lorsque la
+    /// cadence le substitue à l'attente d'ENTREE (voir [`Pacing`](crate::pacing::Pacing)).
+    ///
+    /// Par défaut, chaque seconde est annoncée sur une ligne.
+    fn turn_countdown(&mut self, out: &mut Output, player: &Player, seconds: u32) {
+        out.line(
+            Verbosity::Normal,
+            &tr!(
+                Msg::TurnCountdown,
+                player = player.colored_name(),
+                seconds = seconds
+            ),
+        );
+    }
+
+    /// Objectif commun aux joueurs en mode duel ou partagé, avant que les joueurs ne le visent.
+    ///
+    /// Par défaut, l'objectif est annoncé sur une ligne.
+    fn duel_objective(&mut self, out: &mut Output, number: usize, total: usize, objective: u32) {
+        out.line(
+            Verbosity::Normal,
+            &format!(
+                "\n{}",
+                tr!(
+                    Msg::DuelObjective,
+                    number = number,
+                    total = total,
+                    objective = objective
+                )
+            ),
+        );
+    }
+
+    /// Point d'un objectif disputé en mode duel.
+    ///
+    /// Par défaut, le gagnant du point et les points de la manche sont annoncés sur une ligne.
+    fn duel_point(&mut self, out: &mut Output, point: &DuelPointView) {
+        let line = match point.winner {
+            Some(winner) => tr!(
+                Msg::DuelPointWon,
+                player = winner.name,
+                points = point.score_line()
+            ),
+            None => tr!(Msg::DuelPointTied, points = point.score_line()),
+        };
+        out.line(Verbosity::Normal, &line);
+    }
+
+    /// Résultat de la manche : égalité ou dégâts infligés au perdant.
+    fn round_result(&mut self, out: &mut Output, outcome: &RoundOutcome);
+
+    /// Menu du poison proposé au gagnant de la manche.
+    fn poison_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        winner: &Player,
+        loser: &Player,
+        options: &[PoisonType],
+    );
+
+    /// Menu de la contre-proposition offerte au perdant.
+    fn counter_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        poison: &PoisonType,
+        extra_damage: u32,
+    );
+
+    /// Effet du poison sur le perdant.
+    fn poison_result(&mut self, out: &mut Output, player: &Player, outcome: &PoisonOutcome);
+
+    /// Choix hors du menu des poisons, redemandé au vainqueur ; `max` est le nombre de poisons
+    /// proposés.
+    ///
+    /// Par défaut, le rappel des choix possibles est affiché sur une ligne, avant le menu.
+    fn invalid_choice(&mut self, out: &mut Output, max: usize) {
+        out.line(Verbosity::Normal, &tr!(Msg::PoisonChoiceRetry, max = max));
+    }
+
+    /// Poison choisi pour le perdant, qui ne fera effet qu'au début de la manche suivante (voir
+    /// [`GameRules::delayed_poison`](crate::rules::GameRules::delayed_poison)).
+    ///
+    /// Par défaut, le report est annoncé sur une ligne.
+    fn poison_delayed(&mut self, out: &mut Output, player: &Player, poison: &PoisonType) {
+        out.line(
+            Verbosity::Normal,
+            &tr!(
+                Msg::PoisonDelayed,
+                player = player.colored_name(),
+                poison = poison.label()
+            ),
+        );
+    }
+
+    /// Avertissement de la victime d'un poison différé, au début de la manche où il fait effet.
+    ///
+    /// Par défaut, l'avertissement est affiché sur une ligne.
+    fn poison_warning(&mut self, out: &mut Output, player: &Player, poison: &PoisonType) {
+        let field = match poison {
+            PoisonType::Speed => tr!(Msg::FieldSpeed),
+            PoisonType::Strength => tr!(Msg::FieldStrength),
+        };
+        out.line(
+            Verbosity::Normal,
+            &tr!(
+                Msg::PoisonWarning,
+                player = player.colored_name(),
+                field = field
+            ),
+        );
+    }
+
+    /// Malédiction du vainqueur : `player` a remporté la manche avec `margin` points d'écart et
+    /// sera ralenti pendant la manche suivante (voir
+    /// [`GameRules::winners_curse`](crate::rules::GameRules::winners_curse)).
+    ///
+    /// Par défaut, la malédiction est annoncée sur une ligne.
+    fn winners_curse(&mut self, out: &mut Output, player: &Player, margin: u32) {
+        out.line(
+            Verbosity::Normal,
+            &tr!(
+                Msg::WinnersCurse,
+                player = player.colored_name(),
+                margin = margin,
+                speed = WINNERS_CURSE_SPEED
+            ),
+        );
+    }
+
+    /// Annonce du mode miroir avant l'aperçu du duel, les caractéristiques des joueurs étant déjà
+    /// échangées.
+    ///
+    /// Par défaut, l'échange est annoncé sur une ligne.
+    fn mirror_notice(&mut self, out: &mut Output, players: &[Player]) {
+        if let [first, second, ..] = players {
+            out.line(
+                Verbosity::Normal,
+                &tr!(
+                    Msg::MirrorNotice,
+                    first = first.colored_name(),
+                    second = second.colored_name()
+                ),
+            );
+        }
+    }
+
+    /// Menu des interdictions proposé au joueur qui a le moins de vitalité, au début d'une manche.
+    ///
+    /// Par défaut, les poisons sont numérotés dans l'ordre, suivis du choix de n'en interdire aucun.
+    fn ban_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        options: &[PoisonType],
+    ) {
+        out.line(prompt, &tr!(Msg::BanMenu, player = player.colored_name()));
+        for (n, poison) in options.iter().enumerate() {
+            out.line(
+                prompt,
+                &tr!(Msg::BanOption, number = n + 1, poison = poison.label()),
+            );
+        }
+        out.line(prompt, &tr!(Msg::BanNone, number = options.len() + 1));
+    }
+
+    /// Poison interdit pour la manche, affiché avec l'en-tête de la manche.
+    ///
+    /// Par défaut, l'interdiction est annoncée sur une ligne.
+    fn round_ban(&mut self, out: &mut Output, player: &Player, poison: &PoisonType) {
+        out.line(
+            Verbosity::Normal,
+            &tr!(
+                Msg::BanNotice,
+                player = player.colored_name(),
+                poison = poison.label()
+            ),
+        );
+    }
+
+    /// Aucun poison ne peut être choisi contre le perdant, tous étant interdits pour la manche.
+    ///
+    /// Par défaut, l'absence de poison est annoncée sur une ligne.
+    fn poison_unavailable(&mut self, out: &mut Output, loser: &Player) {
+        out.line(
+            Verbosity::Normal,
+            &tr!(Msg::PoisonAllBanned, player = loser.colored_name()),
+        );
+    }
+
+    /// Mutateur tiré pour la manche, après l'en-tête de chaque manche lorsque les mutateurs sont
+    /// activés.
+    ///
+    /// Par défaut, le mutateur est annoncé sur une ligne avec son effet.
+    fn round_modifier(&mut self, out: &mut Output, modifier: &dyn RoundModifier) {
+        out.line(
+            Verbosity::Normal,
+            &tr!(
+                Msg::MutatorAnnounce,
+                name = modifier.name(),
+                description = modifier.description()
+            ),
+        );
+    }
+
+    /// Anti-stagnation activée au début d'une manche, après des manches aux dégâts trop faibles
+    /// (voir [`GameRules::stalemate_rounds`](crate::rules::GameRules::stalemate_rounds)).
+    ///
+    /// Par défaut, l'activation est annoncée sur une ligne.
+    fn stalemate_on(&mut self, out: &mut Output, rounds: u32, threshold: u32) {
+        out.line(
+            Verbosity::Normal,
+            &tr!(
+                Msg::StalemateOn,
+                threshold = threshold,
+                rounds = rounds,
+                damage = STALEMATE_MIN_DAMAGE
+            ),
+        );
+    }
+
+    /// Anti-stagnation levée au début d'une manche, après une manche aux dégâts suffisants.
+    ///
+    /// Par défaut, la levée est annoncée sur une ligne.
+    fn stalemate_off(&mut self, out: &mut Output, dealt: u32) {
+        out.line(Verbosity::Normal, &tr!(Msg::StalemateOff, dealt = dealt));
+    }
+
+    /// Menu des pronostics proposé en secret à l'arbitre, au début de chaque manche.
+    ///
+    /// Par défaut, les joueurs sont numérotés dans l'ordre, suivis du choix de s'abstenir.
+    fn referee_pick(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        referee: &str,
+        players: &[Player],
+    ) {
+        out.line(prompt, &tr!(Msg::RefereeMenu, referee = referee));
+        for (n, player) in players.iter().enumerate() {
+            out.line(
+                prompt,
+                &tr!(
+                    Msg::RefereeOption,
+                    number = n + 1,
+                    player = player.colored_name()
+                ),
+            );
+        }
+        out.line(
+            prompt,
+            &tr!(Msg::RefereeAbstain, number = players.len() + 1),
+        );
+    }
+
+    /// Issue du pronostic de l'arbitre, après le résultat de la manche.
+    ///
+    /// Par défaut, le pronostic est annoncé sur une ligne avec la série en cours.
+    fn referee_verdict(
+        &mut self,
+        out: &mut Output,
+        referee: &str,
+        pick: &Player,
+        verdict: &Verdict,
+    ) {
+        let line = if verdict.right {
+            tr!(
+                Msg::RefereeRight,
+                referee = referee,
+                player = pick.colored_name(),
+                streak = verdict.streak,
+                needed = REFEREE_STREAK
+            )
+        } else {
+            tr!(
+                Msg::RefereeWrong,
+                referee = referee,
+                player = pick.colored_name()
+            )
+        };
+        out.line(Verbosity::Normal, &line);
+    }
+
+    /// Menu des mutateurs proposé à l'arbitre dont la série de pronostics justes est complète, au
+    /// début de la manche suivante.
+    ///
+    /// Par défaut, les mutateurs sont numérotés avec leur effet, suivis du choix de n'en imposer
+    /// aucun.
+    fn referee_reward(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        referee: &str,
+        options: &[&'static dyn RoundModifier],
+    ) {
+        out.line(
+            prompt,
+            &tr!(
+                Msg::RefereeReward,
+                referee = referee,
+                needed = REFEREE_STREAK
+            ),
+        );
+        for (n, modifier) in options.iter().enumerate() {
+            out.line(
+                prompt,
+                &tr!(
+                    Msg::RefereeMutatorOption,
+                    number = n + 1,
+                    name = modifier.name(),
+                    description = modifier.description()
+                ),
+            );
+        }
+        out.line(
+            prompt,
+            &tr!(Msg::RefereeMutatorNone, number = options.len() + 1),
+        );
+    }
+
+    /// Pièces de chaque joueur, après l'en-tête de chaque manche lorsque la boutique est activée.
+    ///
+    /// Par défaut, les soldes sont affichés sur une ligne.
+    fn coin_balances(&mut self, out: &mut Output, players: &[Player]) {
+        let balances: Vec<String> = players
+            .iter()
+            .map(|player| format!("{} {}", player.name, player.coins))
+            .collect();
+        out.line(
+            Verbosity::Normal,
+            &tr!(Msg::CoinBalances, balances = balances.join(" · ")),
+        );
+    }
+
+    /// Menu de la boutique proposé à un joueur entre deux manches.
+    ///
+    /// Par défaut, les objets sont numérotés dans l'ordre, suivis du choix de ne rien acheter.
+    fn shop_menu(&mut self, out: &mut Output, prompt: Verbosity, player: &Player, items: &[Item]) {
+        out.line(
+            prompt,
+            &tr!(
+                Msg::ShopMenu,
+                player = player.colored_name(),
+                coins = player.coins
+            ),
+        );
+        for (n, item) in items.iter().enumerate() {
+            out.line(
+                prompt,
+                &tr!(
+                    Msg::ShopItem,
+                    number = n + 1,
+                    item = item,
+                    effect = item.label(),
+                    price = item.price()
+                ),
+            );
+        }
+        out.line(prompt, &tr!(Msg::ShopSkip, number = items.len() + 1));
+    }
+
+    /// Achat d'un joueur à la boutique.
+    ///
+    /// Par défaut, l'achat ou son refus est annoncé sur une ligne, et rien n'est affiché si le joueur
+    /// n'a rien acheté.
+    fn shop_result(&mut self, out: &mut Output, player: &Player, outcome: &ShopOutcome) {
+        let line = match outcome {
+            ShopOutcome::Bought(item) => tr!(
+                Msg::ShopBought,
+                player = player.colored_name(),
+                item = item,
+                effect = item.label(),
+                coins = player.coins
+            ),
+            ShopOutcome::Refused(item, reason) => tr!(
+                Msg::ShopRefused,
+                player = player.colored_name(),
+                item = item,
+                reason = reason
+            ),
+            ShopOutcome::Skipped => return,
+        };
+        out.line(Verbosity::Normal, &line);
+    }
+
+    /// Menu de la prise de risque proposé à un joueur avant son tour.
+    ///
+    /// Par défaut, les prises de risque sont numérotées dans l'ordre.
+    fn risk_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        options: &[Risk],
+    ) {
+        out.line(prompt, &tr!(Msg::RiskMenu, player = player.colored_name()));
+        for (n, risk) in options.iter().enumerate() {
+            out.line(
+                prompt,
+                &tr!(
+                    Msg::RiskOption,
+                    number = n + 1,
+                    risk = risk,
+                    objectives = risk.objectives(),
+                    percent = risk.multiplier_percent()
+                ),
+            );
+        }
+    }
+
+    /// Proposition de rejouer l'objectif qui vient d'être joué, faite à un joueur ayant un rejeu en
+    /// réserve.
+    ///
+    /// Par défaut, la question est posée sur une ligne.
+    fn retry_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        stop: &StopRecord,
+    ) {
+        out.line(
+            prompt,
+            &tr!(
+                Msg::RetryMenu,
+                player = player.colored_name(),
+                objective = stop.objective,
+                score = stop.score
+            ),
+        );
+    }
+
+    /// Fin d'une manche.
+    fn round_end(&mut self, out: &mut Output, round: u32);
+
+    /// Commentaire de la manche terminée (voir [`for_round`](crate::commentary::for_round)).
+    ///
+    /// Par défaut, le commentaire est affiché sur une ligne.
+    fn round_commentary(&mut self, out: &mut Output, _category: Category, text: &str) {
+        out.line(Verbosity::Normal, text);
+    }
+
+    /// Fin de la partie : vainqueur, abandon ou bilan partiel d'une partie interrompue.
+    fn game_end(&mut self, out: &mut Output, end: &EndView);
+
+    /// Statistiques d'une partie terminée (voir [`stats::summarize`]).
+    fn game_stats(&mut self, out: &mut Output, stats: &GameStats);
+
+    /// Tableau des scores, avant l'en-tête de chaque manche et avant la fin de la partie.
+    ///
+    /// Par défaut, rien n'est affiché : seul le [`FancyRenderer`] tient un tableau des scores.
+    fn scoreboard(&mut self, _out: &mut Output, _view: &ScoreboardView) {}
+
+    /// Signal sonore, émis par la partie seulement si les sons sont activés.
+    ///
+    /// Par défaut, le signal est joué par la sonnerie du terminal, sauf si l'affichage est désactivé.
+    fn sound_cue(&mut self, out: &mut Output, cue: SoundCue) {
+        out.print(Verbosity::Quiet, cue.bells());
+    }
+}
+
+/// Affiche une ligne si son niveau de détail est retenu, sans la formater sinon.
+macro_rules! say {
+    ($out:expr, $level:expr) => {
+        $out.line($level, "")
+    };
+    ($out:expr, $level:expr, $($arg:tt)*) => {
+        if $out.shows($level) {
+            $out.line($level, &format!($($arg)*));
+        }
+    };
+}
+
+/// Rendu textuel habituel de la partie.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PlainRenderer;
+
+impl Renderer for PlainRenderer {
+    fn game_start(&mut self, out: &mut Output, players: &[Player]) {
+        say!(out, Verbosity::Normal, "{}", tr!(Msg::GameStart));
+        // Comparatif des deux premiers joueurs avant la première manche.
+        if let [left, right, ..] = players {
+            say!(out, Verbosity::Normal, "\n{}", render::matchup(left, right));
+        }
+    }
+
+    fn round_header(&mut self, out: &mut Output, round: u32, players: &[Player]) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "\n{}",
+            tr!(Msg::RoundStart, round = round)
+        );
+        say!(out, Verbosity::Normal, "{}", render::health_bars(players));
+    }
+
+    fn turn_start(&mut self, out: &mut Output, turn: &TurnView) {
+        if turn.index > 0 {
+            say!(out, Verbosity::Normal);
+        }
+        if turn.fatigue > 0 {
+            say!(
+                out,
+                turn.prompt,
+                "{}",
+                tr!(
+                    Msg::TurnOfFatigued,
+                    player = turn.player,
+                    fatigue = turn.fatigue
+                )
+            );
+        } else {
+            say!(
+                out,
+                turn.prompt,
+                "{}",
+                tr!(Msg::TurnOf, player = turn.player)
+            );
+        }
+        say!(
+            out,
+            turn.prompt,
+            "{}",
+            tr!(
+                Msg::Objectives,
+                objectives = format!("{:?}", turn.objectives)
+            )
+        );
+    }
+
+    fn objective_result(&mut self, out: &mut Output, objective: &ObjectiveView) {
+        let stop = &objective.stop;
+        if !objective.counter_shown {
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::CounterStatus,
+                    objective = stop.objective,
+                    miss = stop.miss,
+                    counter = stop.counter
+                )
+            );
+        }
+        say!(
+            out,
+            Verbosity::Verbose,
+            "{}",
+            tr!(
+                Msg::ObjectiveDetail,
+                objective = stop.objective,
+                counter = stop.counter,
+                gap = ScoringCalculator::difference(stop.objective, stop.counter),
+                miss = stop.miss,
+                score = stop.score,
+                elapsed = format!("{:.2}", objective.elapsed.as_secs_f64())
+            )
+        );
+        if stop.retried {
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(Msg::ObjectiveRetried, score = stop.score)
+            );
+        }
+    }
+
+    /// Le score moyen d'un joueur assisté est signalé, afin qu'une partie mixte reste loyale.
+    fn turn_end(&mut self, out: &mut Output, player: &Player, average: u32) {
+        say!(out, Verbosity::Normal, "\n{}", tr!(Msg::TurnEnd));
+        let mut line = tr!(Msg::AverageScore, score = average);
+        if player.assist.is_some() {
+            line = format!("{line} {}", tr!(Msg::AssistedTag));
+        }
+        say!(out, Verbosity::Normal, "{line}");
+    }
+
+    fn round_result(&mut self, out: &mut Output, outcome: &RoundOutcome) {
+        match outcome {
+            RoundOutcome::Draw {
+                consecutive,
+                tiebreaker_next,
+                ..
+            } => {
+                say!(out, Verbosity::Quiet, "\n{}", tr!(Msg::ScoreTie));
+                if *tiebreaker_next {
+                    say!(
+                        out,
+                        Verbosity::Quiet,
+                        "{}",
+                        tr!(Msg::TiebreakerNext, draws = consecutive)
+                    );
+                }
+            }
+            RoundOutcome::Won {
+                winner,
+                loser,
+                damage,
+                time_tiebreak,
+            } => {
+                if *time_tiebreak {
+                    say!(out, Verbosity::Quiet, "\n{}", tr!(Msg::TimeTiebreak));
+                }
+                let detail = if damage.absorbed > 0 {
+                    tr!(
+                        Msg::ArmorDetail,
+                        raw = damage.raw,
+                        absorbed = damage.absorbed
+                    )
+                } else {
+                    String::new()
+                };
+                say!(
+                    out,
+                    Verbosity::Quiet,
+                    "\n{}",
+                    tr!(
+                        Msg::RoundWon,
+                        winner = winner.colored_name(),
+                        loser = loser.colored_name(),
+                        damage = damage.dealt,
+                        detail = detail
+                    )
+                );
+            }
+        }
+    }
+
+    fn poison_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        winner: &Player,
+        loser: &Player,
+        options: &[PoisonType],
+    ) {
+        say!(
+            out,
+            prompt,
+            "{}",
+            tr!(
+                Msg::ChoosePoison,
+                winner = winner.colored_name(),
+                loser = loser.colored_name()
+            )
+        );
+        for (n, option) in options.iter().enumerate() {
+            say!(out, prompt, "→ {}: {}", n + 1, option.label());
+        }
+    }
+
+    fn counter_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        poison: &PoisonType,
+        extra_damage: u32,
+    ) {
+        say!(
+            out,
+            prompt,
+            "{}",
+            tr!(
+                Msg::CounterOffer,
+                player = player.colored_name(),
+                remaining = player.counters_remaining
+            )
+        );
+        say!(
+            out,
+            prompt,
+            "{}",
+            tr!(Msg::AcceptPoison, poison = poison.label())
+        );
+        say!(
+            out,
+            prompt,
+            "{}",
+            tr!(
+                Msg::CounterPoison,
+                damage = extra_damage,
+                vitality = player.vitality(),
+                remaining = player.vitality().saturating_sub(extra_damage)
+            )
+        );
+    }
+
+    fn poison_result(&mut self, out: &mut Output, player: &Player, outcome: &PoisonOutcome) {
+        match outcome {
+            PoisonOutcome::Applied(poison) => say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::PoisonApplied,
+                    player = player.colored_name(),
+                    poison = poison.label()
+                )
+            ),
+            PoisonOutcome::Countered { extra_damage } => say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::PoisonCountered,
+                    player = player.colored_name(),
+                    damage = extra_damage,
+                    vitality = player.vitality()
+                )
+            ),
+        }
+    }
+
+    fn round_end(&mut self, out: &mut Output, round: u32) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "{}",
+            tr!(Msg::RoundEnd, round = round)
+        );
+    }
+
+    fn game_end(&mut self, out: &mut Output, end: &EndView) {
+        let players = end.players;
+        match end.result {
+            GameResult::Victory { .. } | GameResult::Draw => {
+                say!(out, Verbosity::Normal, "\n{}", tr!(Msg::GameOver));
+                match end.result.winner() {
+                    Some(winner) => say!(
+                        out,
+                        Verbosity::Quiet,
+                        "{}",
+                        tr!(Msg::Winner, winner = players[winner.index()].colored_name())
+                    ),
+                    None => say!(out, Verbosity::Quiet, "{}", tr!(Msg::Draw)),
+                }
+                say!(out, Verbosity::Normal, "\n{}", tr!(Msg::PlayerStats));
+                for player in players {
+                    say!(out, Verbosity::Normal, "{player}");
+                }
+                say!(out, Verbosity::Normal, "\n{}", render::health_bars(players));
+            }
+            GameResult::Forfeit { forfeited, winner } => {
+                say!(
+                    out,
+                    Verbosity::Quiet,
+                    "\n{}",
+                    tr!(
+                        Msg::PlayerForfeited,
+                        player = players[forfeited.index()].colored_name()
+                    )
+                );
+                say!(
+                    out,
+                    Verbosity::Quiet,
+                    "{}",
+                    tr!(Msg::Winner, winner = players[winner.index()].colored_name())
+                );
+            }
+            GameResult::Interrupted => {
+                say!(out, Verbosity::Quiet, "\n{}", tr!(Msg::GameInterrupted));
+                say!(
+                    out,
+                    Verbosity::Quiet,
+                    "{}",
+                    tr!(Msg::RoundsCompleted, rounds = end.rounds)
+                );
+                say!(out, Verbosity::Quiet, "{}", tr!(Msg::TotalScores));
+                for (player, total) in players.iter().zip(end.total_scores) {
+                    say!(
+                        out,
+                        Verbosity::Quiet,
+                        "→ {} : {}",
+                        player.colored_name(),
+                        total
+                    );
+                }
+                say!(out, Verbosity::Normal, "\n{}", render::health_bars(players));
+            }
+        }
+    }
+
+    fn game_stats(&mut self, out: &mut Output, stats: &GameStats) {
+        write_stats(out, &tr!(Msg::StatsTitle), stats);
+    }
+}
+
+/// Écrit les statistiques de chaque joueur sous le titre donné.
+fn write_stats(out: &mut Output, heading: &str, stats: &GameStats) {
+    if !out.shows(Verbosity::Normal) {
+        return;
+    }
+    say!(out, Verbosity::Normal, "\n{heading}");
+    for player in &stats.players {
+        say!(
+            out,
+            Verbosity::Normal,
+            "{}",
+            tr!(
+                Msg::StatsPrecision,
+                player = player.name,
+                diff = format!("{:.1}", player.average_diff),
+                perfect = player.perfect_stops,
+                misses = player.misses
+            )
+        );
+        if let (Some(best), Some(worst)) = (player.best, player.worst) {
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::StatsObjectives,
+                    best = best.objective,
+                    best_score = best.score,
+                    worst = worst.objective,
+                    worst_score = worst.score
+                )
+            );
+        }
+        if let Some(highlight) = &player.highlight {
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::StatsHighlight,
+                    round = highlight.round,
+                    objective = highlight.stop.objective,
+                    counter = highlight.stop.counter,
+                    score = highlight.stop.score,
+                    turn = highlight.turn + 1,
+                    index = highlight.index + 1
+                )
+            );
+        }
+        say!(
+            out,
+            Verbosity::Normal,
+            "{}",
+            tr!(
+                Msg::StatsDamage,
+                dealt = player.damage_dealt,
+                taken = player.damage_taken
+            )
+        );
+        if player.draws > 0 {
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(Msg::StatsDraws, draws = player.draws)
+            );
+        }
+        if !player.round_averages.is_empty() {
+            let scores: Vec<String> = player.round_averages.iter().map(u32::to_string).collect();
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::StatsRounds,
+                    sparkline = render::sparkline(&player.round_averages),
+                    scores = scores.join(", ")
+                )
+            );
+        }
+        if player.errors.stops() > 0 {
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::StatsErrors,
+                    early = player.errors.early,
+                    exact = player.errors.exact,
+                    late = player.errors.late
+                )
+            );
+            for line in player.errors.to_string().lines() {
+                say!(out, Verbosity::Normal, "    {line}");
+            }
+        }
+    }
+    if let Some(mvp) = &stats.mvp {
+        say!(
+            out,
+            Verbosity::Normal,
+            "{}",
+            tr!(
+                Msg::StatsMvp,
+                player = stats.players[mvp.player].name,
+                round = mvp.round,
+                objective = mvp.stop.objective,
+                counter = mvp.stop.counter,
+                score = mvp.stop.score,
+                turn = mvp.turn + 1,
+                index = mvp.index + 1
+            )
+        );
+    }
+}
+
+/// Rendu enrichi : titres colorés, barre de précision de chaque objectif et barres de vie après chaque
+/// manche.
+///
+/// Les lignes sont celles du [`PlainRenderer`], complétées par ces éléments. Les couleurs suivent
+/// `--no-color`, et les barres `--ascii`.
+///
+/// Créé par [`FancyRenderer::animated`], il anime aussi la fin de chaque manche : la barre de vie du
+/// perdant se vide progressivement et la caractéristique empoisonnée clignote. Chaque image remplace
+/// la précédente sur la même ligne : ces animations ne conviennent qu'à un terminal.
+///
+/// Il tient aussi un tableau des scores, réimprimé à chaque manche ou, avec
+/// [`FancyRenderer::with_panel`], épinglé en haut du terminal (voir [`Panel`]).
+#[derive(Default)]
+pub struct FancyRenderer {
+    /// Rendu textuel complété.
+    plain: PlainRenderer,
+    /// Horloge cadençant les animations, absente lorsqu'elles sont désactivées.
+    clock: Option<Box<dyn Clock>>,
+    /// Panneau du tableau des scores.
+    panel: Panel,
+}
+
+impl FancyRenderer {
+    /// Crée un rendu enrichi dont les animations sont cadencées par l'horloge donnée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// use std::time::Duration;
+    ///
+    /// use dual_game::clock::{Clock, ManualClock};
+    /// use dual_game::console::ScriptedConsole;
+    /// use dual_game::output::{Output, Verbosity};
+    /// use dual_game::poison::{PoisonOutcome, PoisonType};
+    /// use dual_game::player::Player;
+    /// use dual_game::renderer::{FancyRenderer, Renderer};
+    ///
+    /// let clock = ManualClock::new();
+    /// let mut renderer = FancyRenderer::animated(Box::new(clock.clone()));
+    /// let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+    /// let mut output = Output::new(Box::new(Rc::clone(&console)), Verbosity::Normal);
+    /// let bob = Player::new(String::from("Bob"), 40, 75, 50);
+    /// let poison = PoisonOutcome::Applied(PoisonType::Speed);
+    /// renderer.poison_result(&mut output, &bob, &poison);
+    /// assert!(console.borrow().output.contains('\r'));
+    /// assert!(clock.now() > Duration::ZERO);
+    /// ```
+    pub fn animated(clock: Box<dyn Clock>) -> Self {
+        FancyRenderer {
+            clock: Some(clock),
+            ..FancyRenderer::default()
+        }
+    }
+
+    /// Affiche le tableau des scores de la manière donnée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// use dual_game::console::ScriptedConsole;
+    /// use dual_game::output::{Output, Verbosity};
+    /// use dual_game::panel::PanelMode;
+    /// use dual_game::player::Player;
+    /// use dual_game::renderer::{FancyRenderer, Renderer, ScoreboardView};
+    ///
+    /// let mut renderer = FancyRenderer::default().with_panel(PanelMode::Pinned { rows: 24 });
+    /// let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+    /// let mut output = Output::new(Box::new(Rc::clone(&console)), Verbosity::Normal);
+    /// let players = [Player::new(String::from("Alice"), 50, 50, 50)];
+    /// let view = ScoreboardView {
+    ///     round: 1,
+    ///     players: &players,
+    ///     wins: vec![0],
+    ///     averages: vec![None],
+    ///     finished: false,
+    /// };
+    /// renderer.scoreboard(&mut output, &view);
+    /// assert!(console.borrow().output.starts_with("\n\n\x1b[3;24r"));
+    /// ```
+    pub fn with_panel(mut self, mode: PanelMode) -> Self {
+        self.panel = Panel::new(mode);
+        self
+    }
+
+    /// Retourne l'horloge des animations si elles sont actives et si la sortie affiche leurs lignes.
+    fn animation_clock(&self, out: &Output) -> Option<&dyn Clock> {
+        self.clock
+            .as_deref()
+            .filter(|_| out.shows(Verbosity::Normal))
+    }
+}
+
+/// Affiche successivement les images d'une ligne, chacune effaçant la précédente, en attendant la
+/// durée donnée entre deux images, puis passe à la ligne.
+fn play_frames(out: &mut Output, clock: &dyn Clock, frames: &[String], delay: Duration) {
+    for (index, frame) in frames.iter().enumerate() {
+        if index > 0 {
+            clock.sleep(delay);
+        }
+        out.print(Verbosity::Normal, &format!("\r{frame}\x1b[K"));
+    }
+    out.print(Verbosity::Normal, "\n");
+}
+
+/// Produit les images de la barre de vie du perdant, de sa vitalité avant les dégâts à sa vitalité
+/// actuelle, alignées sur la barre du gagnant.
+fn drain_frames(winner: &Player, loser: &Player, dealt: u32) -> Vec<String> {
+    let players = [winner, loser];
+    let current = loser.snapshot(PlayerId(1));
+    (0..=DRAIN_STEPS)
+        .map(|step| {
+            let healed = current.vitality + dealt * (DRAIN_STEPS - step) / DRAIN_STEPS;
+            let before = PlayerSnapshot {
+                vitality: healed.min(current.max_vitality),
+                ..current
+            };
+            render::snapshot_rows(&players, &[winner.snapshot(PlayerId(0)), before]).remove(1)
+        })
+        .collect()
+}
+
+/// Produit les images du clignotement de la caractéristique réduite par le poison : mise en évidence
+/// et effacée tour à tour, puis affichée normalement.
+fn flash_frames(player: &Player, poison: &PoisonType) -> Vec<String> {
+    let (field, value) = match poison {
+        PoisonType::Speed => (tr!(Msg::FieldSpeed), player.speed),
+        PoisonType::Strength => (tr!(Msg::FieldStrength), player.strength),
+    };
+    let stat = tr!(
+        Msg::PoisonedStat,
+        field = field,
+        player = player.colored_name(),
+        value = value
+    );
+    let highlighted = style::paint_role(&stat, Role::Alert);
+    let mut frames = Vec::new();
+    for _ in 0..FLASH_COUNT {
+        frames.push(highlighted.clone());
+        frames.push(String::new());
+    }
+    frames.push(stat);
+    frames
+}
+
+/// Produit les lignes du tableau des scores : un titre, puis une ligne par joueur, les noms étant
+/// complétés par des espaces pour aligner les colonnes.
+fn scoreboard_lines(view: &ScoreboardView) -> Vec<String> {
+    let ascii = style::is_ascii();
+    let name_width = view
+        .players
+        .iter()
+        .map(|player| player.name_width(ascii))
+        .max()
+        .unwrap_or(0);
+    let mut lines = vec![title(&tr!(Msg::ScoreboardTitle, round = view.round))];
+    for (index, player) in view.players.iter().enumerate() {
+        let average = view.averages.get(index).copied().flatten();
+        let record = tr!(
+            Msg::ScoreboardRecord,
+            wins = view.wins.get(index).copied().unwrap_or(0),
+            average = average.map_or_else(|| String::from("-"), |average| average.to_string())
+        );
+        let padding = " ".repeat(name_width - player.name_width(ascii));
+        let line = tr!(
+            Msg::ScoreboardRow,
+            player = format!("{}{padding}", player.colored_name()),
+            record = record,
+            vitality = player.vitality(),
+            max = player.max_vitality()
+        );
+        lines.push(line);
+    }
+    lines
+}
+
+impl fmt::Debug for FancyRenderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FancyRenderer")
+            .field("animated", &self.clock.is_some())
+            .field("panel", &self.panel.mode())
+            .finish()
+    }
+}
+
+/// Met en forme un titre selon le thème si les couleurs sont activées.
+fn title(text: &str) -> String {
+    style::paint_role(text, Role::Title)
+}
+
+impl Renderer for FancyRenderer {
+    fn game_start(&mut self, out: &mut Output, players: &[Player]) {
+        say!(out, Verbosity::Normal, "{}", title(&tr!(Msg::GameStart)));
+        if let [left, right, ..] = players {
+            say!(out, Verbosity::Normal, "\n{}", render::matchup(left, right));
+        }
+    }
+
+    fn round_header(&mut self, out: &mut Output, round: u32, players: &[Player]) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "\n{}",
+            title(&tr!(Msg::RoundStart, round = round))
+        );
+        say!(out, Verbosity::Normal, "{}", render::health_bars(players));
+    }
+
+    fn turn_start(&mut self, out: &mut Output, turn: &TurnView) {
+        self.plain.turn_start(out, turn);
+    }
+
+    /// Ajoute une barre comparant le score de l'objectif au score maximal du joueur.
+    fn objective_result(&mut self, out: &mut Output, objective: &ObjectiveView) {
+        self.plain.objective_result(out, objective);
+        let max = ScoringCalculator::calculate_score(0, 0, 0, objective.strength);
+        say!(
+            out,
+            Verbosity::Normal,
+            "  {}",
+            render_health_bar(objective.stop.score, max, PRECISION_BAR_WIDTH)
+        );
+    }
+
+    fn turn_end(&mut self, out: &mut Output, player: &Player, average: u32) {
+        self.plain.turn_end(out, player, average);
+    }
+
+    /// Ajoute les barres de vie des deux joueurs après les dégâts de la manche ; celle du perdant se
+    /// vide progressivement si les animations sont actives.
+    fn round_result(&mut self, out: &mut Output, outcome: &RoundOutcome) {
+        self.plain.round_result(out, outcome);
+        let RoundOutcome::Won {
+            winner,
+            loser,
+            damage,
+            ..
+        } = outcome
+        else {
+            return;
+        };
+        match self.animation_clock(out) {
+            Some(clock) if damage.dealt > 0 => {
+                let frames = drain_frames(winner, loser, damage.dealt);
+                let winner_row = render::health_bar_rows(&[*winner, *loser]).remove(0);
+                say!(out, Verbosity::Normal, "{winner_row}");
+                let delay = DRAIN_DURATION / DRAIN_STEPS;
+                play_frames(out, clock, &frames, delay);
+            }
+            _ => {
+                say!(
+                    out,
+                    Verbosity::Normal,
+                    "{}",
+                    render::health_bars(&[*winner, *loser])
+                );
+            }
+        }
+    }
+
+    fn poison_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        winner: &Player,
+        loser: &Player,
+        options: &[PoisonType],
+    ) {
+        self.plain.poison_menu(out, prompt, winner, loser, options);
+    }
+
+    fn counter_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        poison: &PoisonType,
+        extra_damage: u32,
+    ) {
+        self.plain
+            .counter_menu(out, prompt, player, poison, extra_damage);
+    }
+
+    fn poison_result(&mut self, out: &mut Output, player: &Player, outcome: &PoisonOutcome) {
+        self.plain.poison_result(out, player, outcome);
+        // La caractéristique réduite par le poison clignote si les animations sont actives.
+        if let (Some(clock), PoisonOutcome::Applied(poison)) = (self.animation_clock(out), outcome)
+        {
+            play_frames(out, clock, &flash_frames(player, poison), FLASH_FRAME);
+        }
+    }
+
+    fn round_end(&mut self, out: &mut Output, round: u32) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "{}",
+            title(&tr!(Msg::RoundEnd, round = round))
+        );
+    }
+
+    /// Libère d'abord les lignes réservées au tableau des scores, qui reste affiché.
+    fn game_end(&mut self, out: &mut Output, end: &EndView) {
+        let release = self.panel.release();
+        out.print(Verbosity::Normal, &release);
+        self.plain.game_end(out, end);
+    }
+
+    fn game_stats(&mut self, out: &mut Output, stats: &GameStats) {
+        write_stats(out, &title(&tr!(Msg::StatsTitle)), stats);
+    }
+
+    /// Redessine le tableau des scores en place s'il est épinglé ; sinon, le réimprime avant chaque
+    /// manche, les statistiques le remplaçant en fin de partie.
+    fn scoreboard(&mut self, out: &mut Output, view: &ScoreboardView) {
+        if !out.shows(Verbosity::Normal)
+            || (view.finished && self.panel.mode() == PanelMode::Reprint)
+        {
+            return;
+        }
+        let text = self.panel.draw(&scoreboard_lines(view));
+        let text = if self.panel.mode() == PanelMode::Reprint {
+            format!("\n{text}")
+        } else {
+            text
+        };
+        out.print(Verbosity::Normal, &text);
+    }
+}
+
+/// Rendu destiné aux lecteurs d'écran : chaque moment de la partie est décrit par des phrases
+/// complètes, sans barre, tableau ni décoration.
+///
+/// Les vitalités sont énoncées après chaque manche, et chaque arrêt du compteur avec son score. Les
+/// menus indiquent la touche de chaque choix.
+///
+/// # Exemples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use dual_game::console::ScriptedConsole;
+/// use dual_game::messages::{Lang, set_lang};
+/// use dual_game::output::{Output, Verbosity};
+/// use dual_game::renderer::{AccessibleRenderer, Renderer};
+///
+/// set_lang(Lang::Fr);
+/// let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+/// let mut output = Output::new(Box::new(Rc::clone(&console)), Verbosity::Normal);
+/// AccessibleRenderer.round_end(&mut output, 2);
+/// assert_eq!(console.borrow().output, "La manche 2 est terminée.\n");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AccessibleRenderer;
+
+impl AccessibleRenderer {
+    /// Décrit l'effet d'un poison par une phrase plutôt que par son libellé abrégé.
+    fn poison(poison: &PoisonType) -> String {
+        let field = match poison {
+            PoisonType::Speed => tr!(Msg::FieldSpeed),
+            PoisonType::Strength => tr!(Msg::FieldStrength),
+        };
+        tr!(Msg::SpokenPoison, amount = POISON_AMOUNT, field = field)
+    }
+
+    /// Énonce la vitalité de chaque joueur.
+    fn vitalities(out: &mut Output, level: Verbosity, players: &[&Player]) {
+        for player in players {
+            say!(
+                out,
+                level,
+                "{}",
+                tr!(
+                    Msg::SpokenVitality,
+                    player = player.colored_name(),
+                    vitality = player.vitality(),
+                    max = player.max_vitality()
+                )
+            );
+        }
+    }
+
+    /// Énonce les caractéristiques de chaque joueur.
+    fn players(out: &mut Output, players: &[Player]) {
+        for player in players {
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::SpokenPlayer,
+                    player = player.colored_name(),
+                    vitality = player.vitality(),
+                    max = player.max_vitality(),
+                    speed = player.speed,
+                    strength = player.strength,
+                    armor = player.armor
+                )
+            );
+        }
+    }
+}
+
+impl Renderer for AccessibleRenderer {
+    fn game_start(&mut self, out: &mut Output, players: &[Player]) {
+        say!(out, Verbosity::Normal, "{}", tr!(Msg::SpokenGameStart));
+        Self::players(out, players);
+    }
+
+    fn round_header(&mut self, out: &mut Output, round: u32, players: &[Player]) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "{}",
+            tr!(Msg::SpokenRoundStart, round = round)
+        );
+        let players: Vec<&Player> = players.iter().collect();
+        Self::vitalities(out, Verbosity::Normal, &players);
+    }
+
+    fn turn_start(&mut self, out: &mut Output, turn: &TurnView) {
+        say!(
+            out,
+            turn.prompt,
+            "{}",
+            tr!(Msg::SpokenTurn, player = turn.player.colored_name())
+        );
+        if turn.fatigue > 0 {
+            say!(
+                out,
+                turn.prompt,
+                "{}",
+                tr!(
+                    Msg::SpokenFatigue,
+                    player = turn.player.colored_name(),
+                    fatigue = turn.fatigue
+                )
+            );
+        }
+        let objectives: Vec<String> = turn.objectives.iter().map(u32::to_string).collect();
+        say!(
+            out,
+            turn.prompt,
+            "{}",
+            tr!(Msg::SpokenObjectives, objectives = objectives.join(", "))
+        );
+    }
+
+    /// Énonce le score de l'objectif, avec la valeur du compteur si celui-ci ne l'a pas annoncée.
+    fn objective_result(&mut self, out: &mut Output, objective: &ObjectiveView) {
+        let stop = &objective.stop;
+        let gap = ScoringCalculator::difference(stop.objective, stop.counter);
+        let sentence = if objective.counter_shown {
+            tr!(
+                Msg::SpokenScore,
+                objective = stop.objective,
+                gap = gap,
+                score = stop.score
+            )
+        } else {
+            tr!(
+                Msg::SpokenStop,
+                objective = stop.objective,
+                counter = stop.counter,
+                gap = gap,
+                miss = stop.miss,
+                score = stop.score
+            )
+        };
+        say!(out, Verbosity::Normal, "{sentence}");
+        if stop.retried {
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(Msg::ObjectiveRetried, score = stop.score)
+            );
+        }
+    }
+
+    fn turn_end(&mut self, out: &mut Output, player: &Player, average: u32) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "{}",
+            tr!(
+                Msg::SpokenTurnEnd,
+                player = player.colored_name(),
+                score = average
+            )
+        );
+    }
+
+    /// Énonce le résultat de la manche, puis la vitalité des deux joueurs après les dégâts.
+    fn round_result(&mut self, out: &mut Output, outcome: &RoundOutcome) {
+        PlainRenderer.round_result(out, outcome);
+        if let RoundOutcome::Won { winner, loser, .. } = outcome {
+            Self::vitalities(out, Verbosity::Quiet, &[winner, loser]);
+        }
+    }
+
+    fn poison_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        winner: &Player,
+        loser: &Player,
+        options: &[PoisonType],
+    ) {
+        say!(
+            out,
+            prompt,
+            "{}",
+            tr!(
+                Msg::ChoosePoison,
+                winner = winner.colored_name(),
+                loser = loser.colored_name()
+            )
+        );
+        for (n, option) in options.iter().enumerate() {
+            say!(
+                out,
+                prompt,
+                "{}",
+                tr!(
+                    Msg::SpokenOption,
+                    number = n + 1,
+                    option = Self::poison(option)
+                )
+            );
+        }
+    }
+
+    fn counter_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        poison: &PoisonType,
+        extra_damage: u32,
+    ) {
+        say!(
+            out,
+            prompt,
+            "{}",
+            tr!(
+                Msg::CounterOffer,
+                player = player.colored_name(),
+                remaining = player.counters_remaining
+            )
+        );
+        let accept = tr!(Msg::SpokenAccept, poison = Self::poison(poison));
+        let counter = tr!(
+            Msg::SpokenCounter,
+            damage = extra_damage,
+            remaining = player.vitality().saturating_sub(extra_damage)
+        );
+        for (n, option) in [accept, counter].iter().enumerate() {
+            say!(
+                out,
+                prompt,
+                "{}",
+                tr!(Msg::SpokenOption, number = n + 1, option = option)
+            );
+        }
+    }
+
+    fn poison_result(&mut self, out: &mut Output, player: &Player, outcome: &PoisonOutcome) {
+        match outcome {
+            PoisonOutcome::Applied(poison) => say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::PoisonApplied,
+                    player = player.colored_name(),
+                    poison = Self::poison(poison)
+                )
+            ),
+            _ => PlainRenderer.poison_result(out, player, outcome),
+        }
+    }
+
+    fn poison_delayed(&mut self, out: &mut Output, player: &Player, poison: &PoisonType) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "{}",
+            tr!(
+                Msg::PoisonDelayed,
+                player = player.colored_name(),
+                poison = Self::poison(poison)
+            )
+        );
+    }
+
+    fn round_end(&mut self, out: &mut Output, round: u32) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "{}",
+            tr!(Msg::SpokenRoundEnd, round = round)
+        );
+    }
+
+    fn game_end(&mut self, out: &mut Output, end: &EndView) {
+        let players = end.players;
+        match end.result {
+            GameResult::Victory { .. } | GameResult::Draw => {
+                say!(out, Verbosity::Quiet, "{}", tr!(Msg::SpokenGameOver));
+                match end.result.winner() {
+                    Some(winner) => say!(
+                        out,
+                        Verbosity::Quiet,
+                        "{}",
+                        tr!(Msg::Winner, winner = players[winner.index()].colored_name())
+                    ),
+                    None => say!(out, Verbosity::Quiet, "{}", tr!(Msg::Draw)),
+                }
+                Self::players(out, players);
+            }
+            GameResult::Forfeit { forfeited, winner } => {
+                say!(
+                    out,
+                    Verbosity::Quiet,
+                    "{}",
+                    tr!(
+                        Msg::SpokenForfeit,
+                        player = players[forfeited.index()].colored_name()
+                    )
+                );
+                say!(
+                    out,
+                    Verbosity::Quiet,
+                    "{}",
+                    tr!(Msg::Winner, winner = players[winner.index()].colored_name())
+                );
+            }
+            GameResult::Interrupted => {
+                say!(
+                    out,
+                    Verbosity::Quiet,
+                    "{}",
+                    tr!(Msg::SpokenInterrupted, rounds = end.rounds)
+                );
+                for (player, total) in players.iter().zip(end.total_scores) {
+                    say!(
+                        out,
+                        Verbosity::Quiet,
+                        "{}",
+                        tr!(
+                            Msg::SpokenTotal,
+                            player = player.colored_name(),
+                            total = total
+                        )
+                    );
+                }
+            }
+        }
+    }
+
+    fn game_stats(&mut self, out: &mut Output, stats: &GameStats) {
+        say!(out, Verbosity::Normal, "{}", tr!(Msg::StatsTitle));
+        for player in &stats.players {
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::SpokenStats,
+                    player = player.name,
+                    diff = format!("{:.1}", player.average_diff),
+                    perfect = player.perfect_stops,
+                    misses = player.misses,
+                    dealt = player.damage_dealt,
+                    taken = player.damage_taken
+                )
+            );
+            if player.draws > 0 {
+                say!(
+                    out,
+                    Verbosity::Normal,
+                    "{}",
+                    tr!(Msg::SpokenDraws, player = player.name, draws = player.draws)
+                );
+            }
+            if !player.round_averages.is_empty() {
+                let scores: Vec<String> =
+                    player.round_averages.iter().map(u32::to_string).collect();
+                say!(
+                    out,
+                    Verbosity::Normal,
+                    "{}",
+                    tr!(
+                        Msg::SpokenRounds,
+                        player = player.name,
+                        scores = scores.join(", ")
+                    )
+                );
+            }
+            if player.errors.stops() > 0 {
+                say!(
+                    out,
+                    Verbosity::Normal,
+                    "{}",
+                    tr!(
+                        Msg::SpokenErrors,
+                        player = player.name,
+                        early = player.errors.early,
+                        exact = player.errors.exact,
+                        late = player.errors.late
+                    )
+                );
+            }
+            if let Some(highlight) = &player.highlight {
+                say!(
+                    out,
+                    Verbosity::Normal,
+                    "{}",
+                    tr!(
+                        Msg::SpokenHighlight,
+                        player = player.name,
+                        round = highlight.round,
+                        objective = highlight.stop.objective,
+                        counter = highlight.stop.counter,
+                        score = highlight.stop.score
+                    )
+                );
+            }
+        }
+        if let Some(mvp) = &stats.mvp {
+            say!(
+                out,
+                Verbosity::Normal,
+                "{}",
+                tr!(
+                    Msg::SpokenMvp,
+                    player = stats.players[mvp.player].name,
+                    round = mvp.round,
+                    objective = mvp.stop.objective,
+                    counter = mvp.stop.counter,
+                    score = mvp.stop.score
+                )
+            );
+        }
+    }
+}
+
+/// Rendu en JSON : un objet par ligne, avec un champ `event` nommant le moment affiché.
+///
+/// Toutes les lignes sont écrites au niveau [`Verbosity::Quiet`] : seul [`Verbosity::Silent`] les
+/// supprime. Les noms des joueurs sont écrits sans couleur.
+///
+/// # Exemples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use dual_game::console::ScriptedConsole;
+/// use dual_game::output::{Output, Verbosity};
+/// use dual_game::renderer::{JsonRenderer, Renderer};
+///
+/// let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+/// let mut output = Output::new(Box::new(Rc::clone(&console)), Verbosity::Normal);
+/// JsonRenderer.round_end(&mut output, 2);
+/// assert_eq!(console.borrow().output, "{\"event\":\"round_end\",\"round\":2}\n");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonRenderer;
+
+impl JsonRenderer {
+    /// Écrit un objet JSON sur une ligne.
+    fn emit(out: &mut Output, value: Value) {
+        out.line(Verbosity::Quiet, &value.to_string());
+    }
+}
+
+impl Renderer for JsonRenderer {
+    fn game_start(&mut self, out: &mut Output, players: &[Player]) {
+        let names: Vec<&str> = players.iter().map(|p| p.name.as_str()).collect();
+        Self::emit(out, json!({ "event": "game_start", "players": names }));
+    }
+
+    fn round_header(&mut self, out: &mut Output, round: u32, players: &[Player]) {
+        let vitality: Vec<u32> = players.iter().map(Player::vitality).collect();
+        Self::emit(
+            out,
+            json!({ "event": "round_start", "round": round, "vitality": vitality }),
+        );
+    }
+
+    fn turn_start(&mut self, out: &mut Output, turn: &TurnView) {
+        Self::emit(
+            out,
+            json!({
+                "event": "turn_start",
+                "player": turn.player.name,
+                "fatigue": turn.fatigue,
+                "objectives": turn.objectives,
+            }),
+        );
+    }
+
+    fn objective_result(&mut self, out: &mut Output, objective: &ObjectiveView) {
+        let stop = &objective.stop;
+        Self::emit(
+            out,
+            json!({
+                "event": "objective",
+                "player": objective.player.name,
+                "objective": stop.objective,
+                "counter": stop.counter,
+                "miss": stop.miss,
+                "score": stop.score,
+                "elapsed_ms": objective.elapsed.as_millis() as u64,
+                "assisted": objective.assisted,
+                "retried": stop.retried,
+            }),
+        );
+    }
+
+    fn turn_end(&mut self, out: &mut Output, player: &Player, average: u32) {
+        Self::emit(
+            out,
+            json!({ "event": "turn_end", "player": player.name, "average": average }),
+        );
+    }
+
+    fn turn_countdown(&mut self, out: &mut Output, player: &Player, seconds: u32) {
+        Self::emit(
+            out,
+            json!({ "event": "turn_countdown", "player": player.name, "seconds": seconds }),
+        );
+    }
+
+    fn duel_objective(&mut self, out: &mut Output, number: usize, total: usize, objective: u32) {
+        Self::emit(
+            out,
+            json!({
+                "event": "duel_objective",
+                "number": number,
+                "total": total,
+                "objective": objective,
+            }),
+        );
+    }
+
+    fn duel_point(&mut self, out: &mut Output, point: &DuelPointView) {
+        Self::emit(
+            out,
+            json!({
+                "event": "duel_point",
+                "winner": point.winner.map(|winner| winner.name.as_str()),
+                "points": point.points,
+            }),
+        );
+    }
+
+    fn round_result(&mut self, out: &mut Output, outcome: &RoundOutcome) {
+        let value = match outcome {
+            RoundOutcome::Draw {
+                score,
+                consecutive,
+                tiebreaker_next,
+            } => json!({
+                "event": "round_tied",
+                "score": score,
+                "consecutive": consecutive,
+                "tiebreaker_next": tiebreaker_next,
+            }),
+            RoundOutcome::Won {
+                winner,
+                loser,
+                damage,
+                time_tiebreak,
+            } => json!({
+                "event": "round_won",
+                "winner": winner.name,
+                "loser": loser.name,
+                "damage": damage.dealt,
+                "absorbed": damage.absorbed,
+                "vitality": loser.vitality(),
+                "time_tiebreak": time_tiebreak,
+            }),
+        };
+        Self::emit(out, value);
+    }
+
+    fn poison_menu(
+        &mut self,
+        out: &mut Output,
+        _prompt: Verbosity,
+        winner: &Player,
+        loser: &Player,
+        options: &[PoisonType],
+    ) {
+        let options: Vec<&str> = options.iter().map(PoisonType::label).collect();
+        Self::emit(
+            out,
+            json!({
+                "event": "poison_menu",
+                "winner": winner.name,
+                "loser": loser.name,
+                "options": options,
+            }),
+        );
+    }
+
+    fn counter_menu(
+        &mut self,
+        out: &mut Output,
+        _prompt: Verbosity,
+        player: &Player,
+        poison: &PoisonType,
+        extra_damage: u32,
+    ) {
+        Self::emit(
+            out,
+            json!({
+                "event": "counter_menu",
+                "player": player.name,
+                "poison": poison.label(),
+                "extra_damage": extra_damage,
+                "counters_remaining": player.counters_remaining,
+            }),
+        );
+    }
+
+    fn poison_result(&mut self, out: &mut Output, player: &Player, outcome: &PoisonOutcome) {
+        let value = match outcome {
+            PoisonOutcome::Applied(poison) => json!({
+                "event": "poison_applied",
+                "player": player.name,
+                "poison": poison.label(),
+            }),
+            PoisonOutcome::Countered { extra_damage } => json!({
+                "event": "poison_countered",
+                "player": player.name,
+                "extra_damage": extra_damage,
+                "vitality": player.vitality(),
+            }),
+        };
+        Self::emit(out, value);
+    }
+
+    fn invalid_choice(&mut self, out: &mut Output, max: usize) {
+        Self::emit(out, json!({ "event": "invalid_choice", "max": max }));
+    }
+
+    fn poison_delayed(&mut self, out: &mut Output, player: &Player, poison: &PoisonType) {
+        Self::emit(
+            out,
+            json!({
+                "event": "poison_delayed",
+                "player": player.name,
+                "poison": poison.label(),
+            }),
+        );
+    }
+
+    fn poison_warning(&mut self, out: &mut Output, player: &Player, poison: &PoisonType) {
+        Self::emit(
+            out,
+            json!({
+                "event": "poison_warning",
+                "player": player.name,
+                "poison": poison.label(),
+            }),
+        );
+    }
+
+    fn winners_curse(&mut self, out: &mut Output, player: &Player, margin: u32) {
+        Self::emit(
+            out,
+            json!({
+                "event": "winners_curse",
+                "player": player.name,
+                "margin": margin,
+                "speed": WINNERS_CURSE_SPEED,
+            }),
+        );
+    }
+
+    fn mirror_notice(&mut self, out: &mut Output, players: &[Player]) {
+        let players: Vec<Value> = players
+            .iter()
+            .map(|player| {
+                json!({ "player": player.name, "speed": player.speed, "strength": player.strength })
+            })
+            .collect();
+        Self::emit(out, json!({ "event": "mirror", "players": players }));
+    }
+
+    fn risk_menu(
+        &mut self,
+        out: &mut Output,
+        _prompt: Verbosity,
+        player: &Player,
+        options: &[Risk],
+    ) {
+        let options: Vec<Value> = options
+            .iter()
+            .map(|risk| {
+                json!({
+                    "risk": risk,
+                    "objectives": risk.objectives(),
+                    "percent": risk.multiplier_percent(),
+                })
+            })
+            .collect();
+        Self::emit(
+            out,
+            json!({ "event": "risk_menu", "player": player.name, "options": options }),
+        );
+    }
+
+    fn ban_menu(
+        &mut self,
+        out: &mut Output,
+        _prompt: Verbosity,
+        player: &Player,
+        options: &[PoisonType],
+    ) {
+        Self::emit(
+            out,
+            json!({ "event": "ban_menu", "player": player.name, "options": options }),
+        );
+    }
+
+    fn retry_menu(
+        &mut self,
+        out: &mut Output,
+        _prompt: Verbosity,
+        player: &Player,
+        stop: &StopRecord,
+    ) {
+        Self::emit(
+            out,
+            json!({
+                "event": "retry_menu",
+                "player": player.name,
+                "objective": stop.objective,
+                "score": stop.score,
+                "retries": player.retries,
+            }),
+        );
+    }
+
+    fn round_ban(&mut self, out: &mut Output, player: &Player, poison: &PoisonType) {
+        Self::emit(
+            out,
+            json!({ "event": "ban", "player": player.name, "poison": poison }),
+        );
+    }
+
+    fn poison_unavailable(&mut self, out: &mut Output, loser: &Player) {
+        Self::emit(
+            out,
+            json!({ "event": "poison_skipped", "player": loser.name, "reason": "banned" }),
+        );
+    }
+
+    fn round_modifier(&mut self, out: &mut Output, modifier: &dyn RoundModifier) {
+        Self::emit(
+            out,
+            json!({ "event": "mutator", "id": modifier.id(), "name": modifier.name() }),
+        );
+    }
+
+    fn stalemate_on(&mut self, out: &mut Output, rounds: u32, threshold: u32) {
+        Self::emit(
+            out,
+            json!({
+                "event": "stalemate",
+                "active": true,
+                "rounds": rounds,
+                "threshold": threshold,
+                "min_damage": STALEMATE_MIN_DAMAGE,
+            }),
+        );
+    }
+
+    fn stalemate_off(&mut self, out: &mut Output, dealt: u32) {
+        Self::emit(
+            out,
+            json!({ "event": "stalemate", "active": false, "dealt": dealt }),
+        );
+    }
+
+    fn referee_pick(
+        &mut self,
+        out: &mut Output,
+        _prompt: Verbosity,
+        referee: &str,
+        players: &[Player],
+    ) {
+        let options: Vec<&str> = players.iter().map(|player| player.name.as_str()).collect();
+        Self::emit(
+            out,
+            json!({ "event": "referee_menu", "referee": referee, "options": options }),
+        );
+    }
+
+    fn referee_verdict(
+        &mut self,
+        out: &mut Output,
+        referee: &str,
+        pick: &Player,
+        verdict: &Verdict,
+    ) {
+        Self::emit(
+            out,
+            json!({
+                "event": "referee_verdict",
+                "referee": referee,
+                "pick": pick.name,
+                "right": verdict.right,
+                "streak": verdict.streak,
+                "reward": verdict.reward,
+            }),
+        );
+    }
+
+    fn referee_reward(
+        &mut self,
+        out: &mut Output,
+        _prompt: Verbosity,
+        referee: &str,
+        options: &[&'static dyn RoundModifier],
+    ) {
+        let options: Vec<&str> = options.iter().map(|modifier| modifier.id()).collect();
+        Self::emit(
+            out,
+            json!({ "event": "referee_reward", "referee": referee, "options": options }),
+        );
+    }
+
+    fn coin_balances(&mut self, out: &mut Output, players: &[Player]) {
+        let coins: Vec<u32> = players.iter().map(|player| player.coins).collect();
+        Self::emit(out, json!({ "event": "coins", "coins": coins }));
+    }
+
+    fn shop_menu(&mut self, out: &mut Output, _prompt: Verbosity, player: &Player, items: &[Item]) {
+        let items: Vec<Value> = items
+            .iter()
+            .map(|item| json!({ "item": item, "price": item.price() }))
+            .collect();
+        Self::emit(
+            out,
+            json!({
+                "event": "shop_menu",
+                "player": player.name,
+                "coins": player.coins,
+                "items": items,
+            }),
+        );
+    }
+
+    fn shop_result(&mut self, out: &mut Output, player: &Player, outcome: &ShopOutcome) {
+        let value = match outcome {
+            ShopOutcome::Bought(item) => json!({
+                "event": "item_bought",
+                "player": player.name,
+                "item": item,
+                "coins": player.coins,
+            }),
+            ShopOutcome::Refused(item, reason) => json!({
+                "event": "item_refused",
+                "player": player.name,
+                "item": item,
+                "reason": reason.to_string(),
+            }),
+            ShopOutcome::Skipped => json!({ "event": "shop_skipped", "player": player.name }),
+        };
+        Self::emit(out, value);
+    }
+
+    fn round_end(&mut self, out: &mut Output, round: u32) {
+        Self::emit(out, json!({ "event": "round_end", "round": round }));
+    }
+
+    fn round_commentary(&mut self, out: &mut Output, category: Category, text: &str) {
+        Self::emit(
+            out,
+            json!({ "event": "commentary", "category": category.id(), "text": text }),
+        );
+    }
+
+    fn game_end(&mut self, out: &mut Output, end: &EndView) {
+        let winner = end
+            .result
+            .winner()
+            .map(|id| end.players[id.index()].name.as_str());
+        let vitality: Vec<u32> = end.players.iter().map(Player::vitality).collect();
+        Self::emit(
+            out,
+            json!({
+                "event": "game_end",
+                "outcome": Outcome::from(end.result),
+                "winner": winner,
+                "rounds": end.rounds,
+                "total_scores": end.total_scores,
+                "vitality": vitality,
+            }),
+        );
+    }
+
+    fn game_stats(&mut self, out: &mut Output, stats: &GameStats) {
+        Self::emit(
+            out,
+            json!({ "event": "game_stats", "players": stats.players, "mvp": stats.mvp }),
+        );
+    }
+
+    /// Écrit le signal comme un événement plutôt que de faire sonner le terminal.
+    fn sound_cue(&mut self, out: &mut Output, cue: SoundCue) {
+        Self::emit(out, json!({ "event": "sound", "cue": cue.id() }));
+    }
+}
+
+/// Rendu ASCII stable destiné aux chaînes de journalisation : une ligne par moment de la partie,
+/// commençant par des mots-clés anglais en majuscules (`ROUND 3 START`, `DAMAGE Bob 12`), quelle que
+/// soit la langue choisie par `--lang`.
+///
+/// Le rendu n'écrit que des caractères ASCII, sans retour chariot, séquence ANSI ni sonnerie : les
+/// accents des noms sont retirés, leurs espaces remplacés par `_` et tout autre caractère par `?` (voir
+/// [`ascii_token`]), afin que chaque champ d'une ligne reste un seul mot.
+///
+/// # Exemples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use dual_game::console::ScriptedConsole;
+/// use dual_game::output::{Output, Verbosity};
+/// use dual_game::renderer::{PlainAsciiRenderer, Renderer};
+///
+/// let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+/// let mut output = Output::new(Box::new(Rc::clone(&console)), Verbosity::Normal);
+/// PlainAsciiRenderer.round_end(&mut output, 2);
+/// assert_eq!(console.borrow().output, "ROUND 2 END\n");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PlainAsciiRenderer;
+
+impl PlainAsciiRenderer {
+    /// Retourne l'identifiant sérialisé d'une valeur (poison, objet, prise de risque, issue).
+    fn id<T: Serialize>(value: &T) -> String {
+        match serde_json::to_value(value) {
+            Ok(Value::String(id)) => ascii_token(&id),
+            _ => String::from("?"),
+        }
+    }
+
+    /// Retourne le nom d'un joueur sous forme d'un mot ASCII.
+    fn name(player: &Player) -> String {
+        ascii_token(&player.name)
+    }
+
+    /// Formate la valeur de chaque joueur à la suite de son nom : `Alice 50 Bob 42`.
+    fn per_player(players: &[Player], value: impl Fn(&Player) -> String) -> String {
+        let fields: Vec<String> = players
+            .iter()
+            .map(|player| format!("{} {}", Self::name(player), value(player)))
+            .collect();
+        fields.join(" ")
+    }
+
+    /// Formate les options numérotées d'un menu : `1:speed 2:strength`.
+    fn options(options: impl IntoIterator<Item = String>) -> String {
+        let options: Vec<String> = options
+            .into_iter()
+            .enumerate()
+            .map(|(n, option)| format!("{}:{option}", n + 1))
+            .collect();
+        options.join(" ")
+    }
+
+    /// Formate un objectif mis en avant et sa position dans la rediffusion (comptée à partir de 1).
+    fn highlight(highlight: &ObjectiveHighlight) -> String {
+        format!(
+            "ROUND {} TARGET {} STOP {} SCORE {} TURN {} INDEX {}",
+            highlight.round,
+            highlight.stop.objective,
+            highlight.stop.counter,
+            highlight.stop.score,
+            highlight.turn + 1,
+            highlight.index + 1
+        )
+    }
+}
+
+impl Renderer for PlainAsciiRenderer {
+    fn game_start(&mut self, out: &mut Output, players: &[Player]) {
+        let names: Vec<String> = players.iter().map(Self::name).collect();
+        say!(out, Verbosity::Normal, "GAME START {}", names.join(" "));
+        for player in players {
+            say!(
+                out,
+                Verbosity::Normal,
+                "PLAYER {} VITALITY {} SPEED {} STRENGTH {}",
+                Self::name(player),
+                player.vitality(),
+                player.speed,
+                player.strength
+            );
+        }
+    }
+
+    fn round_header(&mut self, out: &mut Output, round: u32, players: &[Player]) {
+        say!(out, Verbosity::Normal, "ROUND {round} START");
+        say!(
+            out,
+            Verbosity::Normal,
+            "VITALITY {}",
+            Self::per_player(players, |p| p.vitality().to_string())
+        );
+    }
+
+    fn turn_start(&mut self, out: &mut Output, turn: &TurnView) {
+        let objectives: Vec<String> = turn.objectives.iter().map(u32::to_string).collect();
+        let fatigue = if turn.fatigue > 0 {
+            format!(" FATIGUE {}", turn.fatigue)
+        } else {
+            String::new()
+        };
+        say!(
+            out,
+            turn.prompt,
+            "TURN {}{fatigue} OBJECTIVES {}",
+            Self::name(turn.player),
+            objectives.join(" ")
+        );
+    }
+
+    fn objective_result(&mut self, out: &mut Output, objective: &ObjectiveView) {
+        let stop = &objective.stop;
+        say!(
+            out,
+            Verbosity::Normal,
+            "STOP {} OBJECTIVE {} COUNTER {} MISS {} SCORE {}{}{}",
+            Self::name(objective.player),
+            stop.objective,
+            stop.counter,
+            stop.miss,
+            stop.score,
+            if objective.assisted { " ASSISTED" } else { "" },
+            if stop.retried { " RETRIED" } else { "" }
+        );
+    }
+
+    fn turn_end(&mut self, out: &mut Output, player: &Player, average: u32) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "TURN END {} AVERAGE {average}",
+            Self::name(player)
+        );
+    }
+
+    fn turn_countdown(&mut self, out: &mut Output, player: &Player, seconds: u32) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "COUNTDOWN {} {seconds}",
+            Self::name(player)
+        );
+    }
+
+    fn duel_objective(&mut self, out: &mut Output, number: usize, total: usize, objective: u32) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "DUEL OBJECTIVE {number}/{total} {objective}"
+        );
+    }
+
+    fn duel_point(&mut self, out: &mut Output, point: &DuelPointView) {
+        let winner = point.winner.map_or(String::from("TIE"), Self::name);
+        let points: Vec<String> = point.points.iter().map(u32::to_string).collect();
+        say!(
+            out,
+            Verbosity::Normal,
+            "DUEL POINT {winner} {}",
+            points.join("-")
+        );
+    }
+
+    fn round_result(&mut self, out: &mut Output, outcome: &RoundOutcome) {
+        match outcome {
+            RoundOutcome::Draw {
+                score,
+                consecutive,
+                tiebreaker_next,
+            } => {
+                say!(
+                    out,
+                    Verbosity::Quiet,
+                    "ROUND TIED SCORE {score} CONSECUTIVE {consecutive}{}",
+                    if *tiebreaker_next { " TIEBREAKER" } else { "" }
+                );
+            }
+            RoundOutcome::Won {
+                winner,
+                loser,
+                damage,
+                time_tiebreak,
+            } => {
+                say!(
+                    out,
+                    Verbosity::Quiet,
+                    "ROUND WON {}{}",
+                    Self::name(winner),
+                    if *time_tiebreak { " TIME TIEBREAK" } else { "" }
+                );
+                say!(
+                    out,
+                    Verbosity::Quiet,
+                    "DAMAGE {} {} ABSORBED {} VITALITY {}",
+                    Self::name(loser),
+                    damage.dealt,
+                    damage.absorbed,
+                    loser.vitality()
+                );
+            }
+        }
+    }
+
+    fn poison_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        winner: &Player,
+        loser: &Player,
+        options: &[PoisonType],
+    ) {
+        say!(
+            out,
+            prompt,
+            "POISON MENU {} TARGET {} OPTIONS {}",
+            Self::name(winner),
+            Self::name(loser),
+            Self::options(options.iter().map(Self::id))
+        );
+    }
+
+    fn counter_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        poison: &PoisonType,
+        extra_damage: u32,
+    ) {
+        say!(
+            out,
+            prompt,
+            "COUNTER MENU {} POISON {} EXTRA {extra_damage} REMAINING {} OPTIONS 1:accept 2:counter",
+            Self::name(player),
+            Self::id(poison),
+            player.counters_remaining
+        );
+    }
+
+    fn poison_result(&mut self, out: &mut Output, player: &Player, outcome: &PoisonOutcome) {
+        match outcome {
+            PoisonOutcome::Applied(poison) => say!(
+                out,
+                Verbosity::Normal,
+                "POISON {} {}",
+                Self::name(player),
+                Self::id(poison)
+            ),
+            PoisonOutcome::Countered { extra_damage } => say!(
+                out,
+                Verbosity::Normal,
+                "POISON COUNTERED {} EXTRA {extra_damage} VITALITY {}",
+                Self::name(player),
+                player.vitality()
+            ),
+        }
+    }
+
+    fn invalid_choice(&mut self, out: &mut Output, max: usize) {
+        say!(out, Verbosity::Normal, "INVALID CHOICE MAX {max}");
+    }
+
+    fn poison_delayed(&mut self, out: &mut Output, player: &Player, poison: &PoisonType) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "POISON DELAYED {} {}",
+            Self::name(player),
+            Self::id(poison)
+        );
+    }
+
+    fn poison_warning(&mut self, out: &mut Output, player: &Player, poison: &PoisonType) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "POISON WARNING {} {}",
+            Self::name(player),
+            Self::id(poison)
+        );
+    }
+
+    fn winners_curse(&mut self, out: &mut Output, player: &Player, margin: u32) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "WINNERS CURSE {} MARGIN {margin} SPEED {WINNERS_CURSE_SPEED}",
+            Self::name(player)
+        );
+    }
+
+    fn mirror_notice(&mut self, out: &mut Output, players: &[Player]) {
+        for player in players {
+            say!(
+                out,
+                Verbosity::Normal,
+                "MIRROR {} SPEED {} STRENGTH {}",
+                Self::name(player),
+                player.speed,
+                player.strength
+            );
+        }
+    }
+
+    fn ban_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        options: &[PoisonType],
+    ) {
+        let options = options.iter().map(Self::id).chain([String::from("none")]);
+        say!(
+            out,
+            prompt,
+            "BAN MENU {} OPTIONS {}",
+            Self::name(player),
+            Self::options(options)
+        );
+    }
+
+    fn round_ban(&mut self, out: &mut Output, player: &Player, poison: &PoisonType) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "BAN {} {}",
+            Self::name(player),
+            Self::id(poison)
+        );
+    }
+
+    fn poison_unavailable(&mut self, out: &mut Output, loser: &Player) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "POISON SKIPPED {} BANNED",
+            Self::name(loser)
+        );
+    }
+
+    fn round_modifier(&mut self, out: &mut Output, modifier: &dyn RoundModifier) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "MUTATOR {}",
+            ascii_token(modifier.id())
+        );
+    }
+
+    fn stalemate_on(&mut self, out: &mut Output, rounds: u32, threshold: u32) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "STALEMATE ON ROUNDS {} THRESHOLD {} MIN DAMAGE {}",
+            rounds,
+            threshold,
+            STALEMATE_MIN_DAMAGE
+        );
+    }
+
+    fn stalemate_off(&mut self, out: &mut Output, dealt: u32) {
+        say!(out, Verbosity::Normal, "STALEMATE OFF DEALT {}", dealt);
+    }
+
+    fn referee_pick(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        referee: &str,
+        players: &[Player],
+    ) {
+        let options = players.iter().map(Self::name).chain([String::from("none")]);
+        say!(
+            out,
+            prompt,
+            "REFEREE MENU {} OPTIONS {}",
+            ascii_token(referee),
+            Self::options(options)
+        );
+    }
+
+    fn referee_verdict(
+        &mut self,
+        out: &mut Output,
+        referee: &str,
+        pick: &Player,
+        verdict: &Verdict,
+    ) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "REFEREE VERDICT {} {} {} STREAK {}",
+            ascii_token(referee),
+            Self::name(pick),
+            if verdict.right { "RIGHT" } else { "WRONG" },
+            verdict.streak
+        );
+    }
+
+    fn referee_reward(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        referee: &str,
+        options: &[&'static dyn RoundModifier],
+    ) {
+        let options = options
+            .iter()
+            .map(|modifier| ascii_token(modifier.id()))
+            .chain([String::from("none")]);
+        say!(
+            out,
+            prompt,
+            "REFEREE REWARD {} OPTIONS {}",
+            ascii_token(referee),
+            Self::options(options)
+        );
+    }
+
+    fn coin_balances(&mut self, out: &mut Output, players: &[Player]) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "COINS {}",
+            Self::per_player(players, |p| p.coins.to_string())
+        );
+    }
+
+    fn shop_menu(&mut self, out: &mut Output, prompt: Verbosity, player: &Player, items: &[Item]) {
+        let options = items
+            .iter()
+            .map(|item| format!("{}:{}", Self::id(item), item.price()))
+            .chain([String::from("none")]);
+        say!(
+            out,
+            prompt,
+            "SHOP MENU {} COINS {} OPTIONS {}",
+            Self::name(player),
+            player.coins,
+            Self::options(options)
+        );
+    }
+
+    fn shop_result(&mut self, out: &mut Output, player: &Player, outcome: &ShopOutcome) {
+        match outcome {
+            ShopOutcome::Bought(item) => say!(
+                out,
+                Verbosity::Normal,
+                "SHOP BOUGHT {} {} COINS {}",
+                Self::name(player),
+                Self::id(item),
+                player.coins
+            ),
+            ShopOutcome::Refused(item, _) => say!(
+                out,
+                Verbosity::Normal,
+                "SHOP REFUSED {} {}",
+                Self::name(player),
+                Self::id(item)
+            ),
+            ShopOutcome::Skipped => say!(
+                out,
+                Verbosity::Normal,
+                "SHOP SKIPPED {}",
+                Self::name(player)
+            ),
+        }
+    }
+
+    fn risk_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        options: &[Risk],
+    ) {
+        let options = options.iter().map(|risk| {
+            format!(
+                "{}:{}:{}",
+                Self::id(risk),
+                risk.objectives(),
+                risk.multiplier_percent()
+            )
+        });
+        say!(
+            out,
+            prompt,
+            "RISK MENU {} OPTIONS {}",
+            Self::name(player),
+            Self::options(options)
+        );
+    }
+
+    fn retry_menu(
+        &mut self,
+        out: &mut Output,
+        prompt: Verbosity,
+        player: &Player,
+        stop: &StopRecord,
+    ) {
+        say!(
+            out,
+            prompt,
+            "RETRY MENU {} OBJECTIVE {} SCORE {}",
+            Self::name(player),
+            stop.objective,
+            stop.score
+        );
+    }
+
+    fn round_end(&mut self, out: &mut Output, round: u32) {
+        say!(out, Verbosity::Normal, "ROUND {round} END");
+    }
+
+    fn round_commentary(&mut self, out: &mut Output, category: Category, _text: &str) {
+        say!(
+            out,
+            Verbosity::Normal,
+            "COMMENTARY {}",
+            ascii_token(category.id())
+        );
+    }
+
+    fn game_end(&mut self, out: &mut Output, end: &EndView) {
+        let outcome = Self::id(&Outcome::from(end.result)).to_uppercase();
+        let winner = end
+            .result
+            .winner()
+            .map(|id| format!(" WINNER {}", Self::name(&end.players[id.index()])))
+            .unwrap_or_default();
+        say!(
+            out,
+            Verbosity::Quiet,
+            "GAME END {outcome}{winner} ROUNDS {}",
+            end.rounds
+        );
+        let totals: Vec<String> = end
+            .players
+            .iter()
+            .zip(end.total_scores)
+            .map(|(player, total)| format!("{} {total}", Self::name(player)))
+            .collect();
+        say!(out, Verbosity::Normal, "TOTAL {}", totals.join(" "));
+        say!(
+            out,
+            Verbosity::Normal,
+            "VITALITY {}",
+            Self::per_player(end.players, |p| p.vitality().to_string())
+        );
+    }
+
+    fn game_stats(&mut self, out: &mut Output, stats: &GameStats) {
+        for player in &stats.players {
+            say!(
+                out,
+                Verbosity::Normal,
+                "STATS {} DIFF {:.1} PERFECT {} MISSES {} DEALT {} TAKEN {} DRAWS {}",
+                ascii_token(&player.name),
+                player.average_diff,
+                player.perfect_stops,
+                player.misses,
+                player.damage_dealt,
+                player.damage_taken,
+                player.draws
+            );
+            let buckets: Vec<String> = player.errors.buckets.iter().map(u32::to_string).collect();
+            say!(
+                out,
+                Verbosity::Normal,
+                "ERRORS {} EARLY {} EXACT {} LATE {} BUCKETS {}",
+                ascii_token(&player.name),
+                player.errors.early,
+                player.errors.exact,
+                player.errors.late,
+                buckets.join(",")
+            );
+            if let Some(highlight) = &player.highlight {
+                say!(
+                    out,
+                    Verbosity::Normal,
+                    "BEST {} {}",
+                    ascii_token(&player.name),
+                    Self::highlight(highlight)
+                );
+            }
+        }
+        if let Some(mvp) = &stats.mvp {
+            say!(
+                out,
+                Verbosity::Normal,
+                "MVP {} {}",
+                ascii_token(&stats.players[mvp.player].name),
+                Self::highlight(mvp)
+            );
+        }
+    }
+
+    /// Écrit le signal comme une ligne plutôt que de faire sonner le terminal.
+    fn sound_cue(&mut self, out: &mut Output, cue: SoundCue) {
+        say!(out, Verbosity::Quiet, "SOUND {}", cue.id());
+    }
+}
+
+/// Convertit un texte en un mot ASCII : les lettres accentuées perdent leur accent, les espaces
+/// deviennent `_` et les autres caractères non ASCII ou de contrôle deviennent `?`.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::renderer::ascii_token;
+///
+/// assert_eq!(ascii_token("Léa Müller"), "Lea_Muller");
+/// assert_eq!(ascii_token("Bot 🤖"), "Bot_?");
+/// ```
+pub fn ascii_token(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            c if c.is_ascii_graphic() => c,
+            c if c.is_whitespace() => '_',
+            'à' | 'á' | 'â' | 'ä' | 'ã' | 'å' => 'a',
+            'À' | 'Á' | 'Â' | 'Ä' | 'Ã' | 'Å' => 'A',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'É' | 'È' | 'Ê' | 'Ë' => 'E',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'Í' | 'Ì' | 'Î' | 'Ï' => 'I',
+            'ó' | 'ò' | 'ô' | 'ö' | 'õ' => 'o',
+            'Ó' | 'Ò' | 'Ô' | 'Ö' | 'Õ' => 'O',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'Ú' | 'Ù' | 'Û' | 'Ü' => 'U',
+            'ç' => 'c',
+            'Ç' => 'C',
+            'ñ' => 'n',
+            'Ñ' => 'N',
+            'ÿ' => 'y',
+            _ => '?',
+        })
+        .collect()
+}
+
+/// Rendus disponibles en ligne de commande (`--render`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RenderStyle {
+    /// [`PlainRenderer`].
+    #[default]
+    Plain,
+    /// [`FancyRenderer`].
+    Fancy,
+    /// [`JsonRenderer`].
+    Json,
+    /// [`AccessibleRenderer`].
+    Accessible,
+    /// [`PlainAsciiRenderer`].
+    PlainAscii,
+}
+
+impl RenderStyle {
+    /// Liste de tous les rendus.
+    pub const ALL: [RenderStyle; 5] = [
+        RenderStyle::Plain,
+        RenderStyle::Fancy,
+        RenderStyle::Json,
+        RenderStyle::Accessible,
+        RenderStyle::PlainAscii,
+    ];
+
+    /// Retourne l'identifiant du rendu utilisé en ligne de commande.
+    pub fn id(&self) -> &'static str {
+        match self {
+            RenderStyle::Plain => "plain",
+            RenderStyle::Fancy => "fancy",
+            RenderStyle::Json => "json",
+            RenderStyle::Accessible => "accessible",
+            RenderStyle::PlainAscii => "plain-ascii",
+        }
+    }
+
+    /// Crée le rendu correspondant.
+    pub fn renderer(&self) -> Box<dyn Renderer> {
+        match self {
+            RenderStyle::Plain => Box::new(PlainRenderer),
+            RenderStyle::Fancy => Box::new(FancyRenderer::default()),
+            RenderStyle::Json => Box::new(JsonRenderer),
+            RenderStyle::Accessible => Box::new(AccessibleRenderer),
+            RenderStyle::PlainAscii => Box::new(PlainAsciiRenderer),
+        }
+    }
+}
+
+impl fmt::Display for RenderStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+impl FromStr for RenderStyle {
+    type Err = String;
+
+    /// Analyse un rendu à partir de son identifiant (insensible à la casse).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s.trim().to_lowercase();
+        RenderStyle::ALL
+            .into_iter()
+            .find(|style| style.id() == id)
+            .ok_or_else(|| {
+                let ids: Vec<&str> = RenderStyle::ALL.iter().map(|s| s.id()).collect();
+                format!(
+                    "rendu inconnu « {s} » (valeurs possibles : {})",
+                    ids.join(", ")
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::clock::ManualClock;
+    use crate::console::ScriptedConsole;
+    use crate::player::PlayerId;
+
+    /// Crée une sortie détaillée capturée dans la console retournée.
+    fn captured() -> (Output, Rc<RefCell<ScriptedConsole>>) {
+        let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+        let output = Output::new(Box::new(Rc::clone(&console)), Verbosity::Verbose);
+        (output, console)
+    }
+
+    /// Vérifie l'analyse des identifiants de rendu.
+    #[test]
+    fn test_render_style_from_str() {
+        assert_eq!("Fancy".parse(), Ok(RenderStyle::Fancy));
+        assert_eq!(" json ".parse(), Ok(RenderStyle::Json));
+        assert!("html".parse::<RenderStyle>().is_err());
+        for style in RenderStyle::ALL {
+            assert_eq!(style.to_string().parse(), Ok(style));
+        }
+    }
+
+    /// Vérifie que chaque ligne du rendu JSON est un objet nommant son événement.
+    #[test]
+    fn test_json_renderer() {
+        let (mut out, console) = captured();
+        let players = vec![
+            Player::new(String::from("Alice"), 50, 75, 50),
+            Player::new(String::from("Bob"), 0, 75, 50),
+        ];
+        let mut renderer = JsonRenderer;
+        renderer.poison_result(
+            &mut out,
+            &players[1],
+            &PoisonOutcome::Applied(PoisonType::Speed),
+        );
+        renderer.game_end(
+            &mut out,
+            &EndView {
+                result: GameResult::Victory {
+                    winner: PlayerId(0),
+                },
+                players: &players,
+                total_scores: &[210, 150],
+                rounds: 2,
+            },
+        );
+        let output = console.borrow().output.clone();
+        let lines: Vec<Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines[0]["event"], "poison_applied");
+        assert_eq!(lines[0]["poison"], PoisonType::Speed.label());
+        assert_eq!(lines[1]["event"], "game_end");
+        assert_eq!(lines[1]["outcome"], "victory");
+        assert_eq!(lines[1]["winner"], "Alice");
+        assert_eq!(lines[1]["total_scores"], json!([210, 150]));
+    }
+
+    /// Vérifie que le rendu enrichi ajoute une barre de précision au rendu habituel d'un objectif.
+    #[test]
+    fn test_fancy_objective_bar() {
+        style::set_enabled(false);
+        let player = Player::new(String::from("Alice"), 50, 75, 50);
+        let objective = ObjectiveView {
+            player: &player,
+            strength: 50,
+            stop: StopRecord {
+                objective: 40,
+                counter: 40,
+                miss: 0,
+                score: 150,
+                retried: false,
+            },
+            elapsed: Duration::ZERO,
+            counter_shown: true,
+            assisted: false,
+        };
+        let (mut plain, plain_console) = captured();
+        PlainRenderer.objective_result(&mut plain, &objective);
+        let (mut fancy, fancy_console) = captured();
+        FancyRenderer::default().objective_result(&mut fancy, &objective);
+        let plain = plain_console.borrow().output.clone();
+        let fancy = fancy_console.borrow().output.clone();
+        assert_eq!(fancy.strip_prefix(&plain), Some("  [██████████] 150/150\n"));
+    }
+
+    /// Vérifie que les signaux sonores font sonner le terminal, sauf avec le rendu JSON qui les écrit
+    /// comme des événements.
+    #[test]
+    fn test_sound_cue() {
+        let (mut out, console) = captured();
+        PlainRenderer.sound_cue(&mut out, SoundCue::Perfect);
+        FancyRenderer::default().sound_cue(&mut out, SoundCue::GameOver);
+        assert_eq!(console.borrow().output, "\x07\x07\x07\x07\x07");
+
+        let (mut out, console) = captured();
+        JsonRenderer.sound_cue(&mut out, SoundCue::Miss);
+        assert_eq!(
+            console.borrow().output,
+            "{\"cue\":\"miss\",\"event\":\"sound\"}\n"
+        );
+    }
+
+    /// Vérifie les images de l'animation des dégâts, cadencées par une horloge manuelle.
+    #[test]
+    fn test_fancy_damage_animation() {
+        style::set_enabled(false);
+        let clock = ManualClock::new();
+        let mut renderer = FancyRenderer::animated(Box::new(clock.clone()));
+        let alice = Player::new(String::from("Alice"), 50, 75, 50);
+        let mut bob = Player::new(String::from("Bob"), 50, 75, 50);
+        bob.take_damage(30);
+        let outcome = RoundOutcome::Won {
+            winner: &alice,
+            loser: &bob,
+            damage: DamageOutcome {
+                raw: 10,
+                absorbed: 0,
+                dealt: 10,
+                fatal: false,
+            },
+            time_tiebreak: false,
+        };
+        let (mut out, console) = captured();
+        renderer.round_result(&mut out, &outcome);
+        let output = console.borrow().output.clone();
+        let (_, animation) = output.split_once("Alice [").unwrap();
+        let frames: Vec<&str> = animation
+            .split('\r')
+            .skip(1)
+            .map(|frame| frame.trim_end_matches('\n').trim_end_matches("\x1b[K"))
+            .collect();
+        assert_eq!(frames.len(), DRAIN_STEPS as usize + 1, "{output:?}");
+        assert_eq!(frames[0], "Bob   [████████████░░░░░░░░] 30/50");
+        assert_eq!(frames[2], "Bob   [██████████░░░░░░░░░░] 26/50");
+        assert_eq!(frames[5], "Bob   [████████░░░░░░░░░░░░] 20/50");
+        assert_eq!(clock.now(), DRAIN_DURATION);
+
+        // Sans animation, les barres sont affichées directement dans leur état final.
+        let (mut out, console) = captured();
+        FancyRenderer::default().round_result(&mut out, &outcome);
+        let output = console.borrow().output.clone();
+        assert!(!output.contains('\r'));
+        assert!(
+            output.ends_with("Bob   [████████░░░░░░░░░░░░] 20/50\n"),
+            "{output:?}"
+        );
+    }
+
+    /// Vérifie le clignotement de la caractéristique empoisonnée et son absence si le poison est contré.
+    #[test]
+    fn test_fancy_poison_flash() {
+        style::set_enabled(false);
+        let clock = ManualClock::new();
+        let mut renderer = FancyRenderer::animated(Box::new(clock.clone()));
+        let mut bob = Player::new(String::from("Bob"), 40, 75, 50);
+        bob.apply_poison(PoisonType::Strength);
+        let (mut out, console) = captured();
+        let poison = PoisonOutcome::Applied(PoisonType::Strength);
+        renderer.poison_result(&mut out, &bob, &poison);
+        let output = console.borrow().output.clone();
+        let stat = "force de Bob : 45";
+        assert!(
+            output.ends_with(&format!(
+                "\r{stat}\x1b[K\r\x1b[K\r{stat}\x1b[K\r\x1b[K\r{stat}\x1b[K\r\x1b[K\r{stat}\x1b[K\n"
+            )),
+            "{output:?}"
+        );
+        assert_eq!(clock.now(), FLASH_FRAME * (2 * FLASH_COUNT as u32));
+
+        let (mut out, console) = captured();
+        let countered = PoisonOutcome::Countered { extra_damage: 5 };
+        renderer.poison_result(&mut out, &bob, &countered);
+        assert!(!console.borrow().output.contains('\r'));
+        assert_eq!(clock.now(), FLASH_FRAME * (2 * FLASH_COUNT as u32));
+    }
+
+    /// Vérifie le tableau des scores : épinglé, il est redessiné en place à chaque manche puis libéré
+    /// en fin de partie ; sinon, il est réimprimé, sauf en fin de partie.
+    #[test]
+    fn test_fancy_scoreboard() {
+        style::set_enabled(false);
+        let alice = Player::new(String::from("Alice"), 50, 75, 50);
+        let mut bob = Player::new(String::from("Bob"), 50, 75, 50);
+        bob.take_damage(20);
+        let players = [alice, bob];
+        let mut view = ScoreboardView {
+            round: 2,
+            players: &players,
+            wins: vec![1, 0],
+            averages: vec![Some(118), Some(96)],
+            finished: false,
+        };
+        let end = EndView {
+            result: GameResult::Victory {
+                winner: PlayerId(0),
+            },
+            players: &players,
+            total_scores: &[118, 96],
+            rounds: 1,
+        };
+
+        let mut renderer = FancyRenderer::default().with_panel(PanelMode::Pinned { rows: 24 });
+        let (mut out, console) = captured();
+        renderer.scoreboard(&mut out, &view);
+        let output = console.borrow().output.clone();
+        assert!(
+            output.starts_with("\n\n\n\x1b[4;24r\x1b[24;1H\x1b7"),
+            "{output:?}"
+        );
+        assert!(
+            output.contains("\x1b[3;1H\x1b[2KBob    victoires : 0  moyenne : 96  vitalité : 30/50"),
+            "{output:?}"
+        );
+        assert!(output.ends_with("\x1b8"), "{output:?}");
+        view.finished = true;
+        let (mut out, console) = captured();
+        renderer.scoreboard(&mut out, &view);
+        renderer.game_end(&mut out, &end);
+        let output = console.borrow().output.clone();
+        assert!(output.starts_with("\x1b7\x1b[1;1H\x1b[2K"), "{output:?}");
+        assert!(output.contains("\x1b8\x1b[r\x1b[24;1H"), "{output:?}");
+
+        let mut renderer = FancyRenderer::default();
+        view.finished = false;
+        let (mut out, console) = captured();
+        renderer.scoreboard(&mut out, &view);
+        let output = console.borrow().output.clone();
+        assert_eq!(
+            output,
+            "\nTableau des scores (manche 2)\n\
+             Alice  victoires : 1  moyenne : 118  vitalité : 50/50\n\
+             Bob    victoires : 0  moyenne : 96  vitalité : 30/50\n"
+        );
+        view.finished = true;
+        let (mut out, console) = captured();
+        renderer.scoreboard(&mut out, &view);
+        assert!(console.borrow().output.is_empty());
+    }
+
+    /// Vérifie le rendu ASCII d'une partie scriptée de deux manches : des mots-clés anglais stables,
+    /// quel que soit le nom des joueurs, et un flux d'octets purement ASCII sans retour chariot ni
+    /// séquence ANSI.
+    #[test]
+    fn test_plain_ascii_game() {
+        use crate::controller::{ScriptedController, StopDecision};
+        use crate::game::Game;
+
+        let players = vec![
+            Player::new(String::from("Léa Noël"), 100, 75, 50),
+            Player::new(String::from("Bob 🤖"), 100, 75, 50),
+        ];
+        let mut game = Game::new(players, 2);
+        let (output, console) = captured();
+        game.set_output(output);
+        game.set_renderer(Box::new(PlainAsciiRenderer));
+        game.set_clock(Box::new(ManualClock::new()));
+        game.set_seed(7);
+        let lea = ScriptedController::new();
+        let bob = ScriptedController::new();
+        game.set_controller(0, Box::new(lea.clone()));
+        game.set_controller(1, Box::new(bob.clone()));
+        for _ in 0..2 {
+            for _ in 0..2 {
+                lea.push_stop(StopDecision::from_offset(50, 0));
+                bob.push_stop(StopDecision::from_offset(50, 303));
+            }
+            lea.push_poison(0);
+        }
+        let result = game.run().unwrap();
+        assert_eq!(result.winner(), Some(PlayerId(0)));
+
+        let output = console.borrow().output.clone();
+        assert!(output.is_ascii(), "{output}");
+        assert!(!output.contains(['\r', '\x1b', '\x07']));
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            [
+                "GAME START Lea_Noel Bob_?",
+                "PLAYER Lea_Noel VITALITY 100 SPEED 75 STRENGTH 50",
+                "PLAYER Bob_? VITALITY 100 SPEED 75 STRENGTH 50",
+                "ROUND 1 START",
+                "VITALITY Lea_Noel 100 Bob_? 100",
+                "TURN Lea_Noel OBJECTIVES 42 3",
+                "STOP Lea_Noel OBJECTIVE 42 COUNTER 50 MISS 0 SCORE 110",
+                "STOP Lea_Noel OBJECTIVE 3 COUNTER 50 MISS 0 SCORE 70",
+                "TURN END Lea_Noel AVERAGE 90",
+                "TURN Bob_? OBJECTIVES 14 31",
+                "STOP Bob_? OBJECTIVE 14 COUNTER 50 MISS 3 SCORE 17",
+                "STOP Bob_? OBJECTIVE 31 COUNTER 50 MISS 3 SCORE 22",
+                "TURN END Bob_? AVERAGE 20",
+                "ROUND WON Lea_Noel",
+                "DAMAGE Bob_? 70 ABSORBED 0 VITALITY 30",
+                "POISON MENU Lea_Noel TARGET Bob_? OPTIONS 1:speed 2:strength",
+                "COUNTER MENU Bob_? POISON speed EXTRA 70 REMAINING 1 OPTIONS 1:accept 2:counter",
+                "POISON Bob_? speed",
+                "ROUND 1 END",
+                "COMMENTARY disaster",
+                "ROUND 2 START",
+                "VITALITY Lea_Noel 100 Bob_? 30",
+                "TURN Lea_Noel OBJECTIVES 6 14",
+                "STOP Lea_Noel OBJECTIVE 6 COUNTER 50 MISS 0 SCORE 70",
+                "STOP Lea_Noel OBJECTIVE 14 COUNTER 50 MISS 0 SCORE 70",
+                "TURN END Lea_Noel AVERAGE 70",
+                "TURN Bob_? OBJECTIVES 65 54",
+                "STOP Bob_? OBJECTIVE 65 COUNTER 50 MISS 3 SCORE 22",
+                "STOP Bob_? OBJECTIVE 54 COUNTER 50 MISS 3 SCORE 32",
+                "TURN END Bob_? AVERAGE 27",
+                "ROUND WON Lea_Noel",
+                "DAMAGE Bob_? 30 ABSORBED 0 VITALITY 0",
+                "ROUND 2 END",
+                "COMMENTARY disaster",
+                "GAME END VICTORY WINNER Lea_Noel ROUNDS 2",
+                "TOTAL Lea_Noel 160 Bob_? 47",
+                "VITALITY Lea_Noel 100 Bob_? 0",
+                "STATS Lea_Noel DIFF 33.8 PERFECT 0 MISSES 0 DEALT 100 TAKEN 0 DRAWS 0",
+                "ERRORS Lea_Noel EARLY 0 EXACT 0 LATE 4 BUCKETS 0,0,0,0,0,1,0,0,1,2",
+                "BEST Lea_Noel ROUND 1 TARGET 42 STOP 50 SCORE 110 TURN 1 INDEX 1",
+                "STATS Bob_? DIFF 18.5 PERFECT 0 MISSES 12 DEALT 0 TAKEN 100 DRAWS 0",
+                "ERRORS Bob_? EARLY 2 EXACT 0 LATE 2 BUCKETS 0,0,0,1,1,0,1,0,1,0",
+                "BEST Bob_? ROUND 2 TARGET 54 STOP 50 SCORE 32 TURN 4 INDEX 2",
+                "MVP Lea_Noel ROUND 1 TARGET 42 STOP 50 SCORE 110 TURN 1 INDEX 1",
+            ]
+        );
+    }
+}
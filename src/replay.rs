@@ -0,0 +1,773 @@
+//! Module d'enregistrement et de relecture des parties.
+//!
+//! Chaque tour joué est consigné dans l'historique de la partie sous forme de [`TurnRecord`]. Une
+//! partie terminée peut être enregistrée au format JSON ([`Replay::save`]) puis relue avec
+//! `dual_game replay <fichier>`, tout comme l'historique écrit au fil de la partie au format JSON
+//! Lines par un [`JsonlSink`](crate::history::JsonlSink).
+//!
+//! Les noms des joueurs ne sont conservés qu'une fois, dans l'en-tête de l'historique
+//! ([`PlayerNames`]) : les manches ([`RoundRecord`]) ne retiennent que des instantanés des joueurs
+//! ([`PlayerSnapshot`]), désignés par leur identifiant.
+//!
+//! Les rediffusions portent la version de leur format ([`REPLAY_VERSION`]) : celles des versions
+//! antérieures restent lisibles, les champs ajoutés depuis prenant leur valeur par défaut, tandis
+//! qu'une version plus récente que l'application est refusée plutôt que mal interprétée.
+//!
+//! L'en-tête ([`ReplayHeader`]) retient aussi la version de l'application, le barème, les règles et
+//! les caractéristiques initiales des joueurs : [`verify_replay`] recalcule la première manche à
+//! partir des arrêts enregistrés et signale toute divergence avec les scores enregistrés, auquel
+//! cas `dual_game replay` refuse la relecture sauf avec `--force`.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::export::Outcome;
+use crate::game::Game;
+use crate::history::HistoryEntry;
+use crate::mutators;
+use crate::player::{Player, PlayerId, PlayerSnapshot};
+use crate::risk::Risk;
+use crate::rules::GameRules;
+use crate::scoring::{ScoringCalculator, ScoringConfig};
+
+/// Version du format des rediffusions écrites par l'application.
+///
+/// - 1 : format d'origine, sans numéro de version ; l'état des joueurs à la fin de chaque manche et
+///   l'indication d'une partie inachevée n'y figurent pas toujours.
+/// - 2 : numéro de version enregistré avec la partie.
+/// - 3 : manches nulles enregistrées ([`DrawRecord`]).
+/// - 4 : prise de risque de chaque tour et mutateur de chaque manche enregistrés.
+/// - 5 : version de l'application, barème, règles et caractéristiques initiales des joueurs
+///   enregistrés ([`ReplayHeader`]).
+pub const REPLAY_VERSION: u32 = 5;
+
+/// Version des rediffusions enregistrées avant l'introduction du numéro de version.
+pub(crate) fn legacy_version() -> u32 {
+    1
+}
+
+/// Noms des joueurs d'une partie, dans l'ordre de leurs identifiants.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PlayerNames(Vec<String>);
+
+impl PlayerNames {
+    /// Retient le nom de chaque joueur, dans l'ordre de la liste.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::player::{Player, PlayerId};
+    /// use dual_game::replay::PlayerNames;
+    ///
+    /// let players = [Player::new(String::from("Alice"), 50, 50, 50)];
+    /// let names = PlayerNames::from_players(&players);
+    /// assert_eq!(names.name(PlayerId(0)), Some("Alice"));
+    /// assert_eq!(names.resolve(&players[0].snapshot(PlayerId(0))), Some("Alice"));
+    /// assert_eq!(names.name(PlayerId(1)), None);
+    /// ```
+    pub fn from_players(players: &[Player]) -> Self {
+        PlayerNames(players.iter().map(|player| player.name.clone()).collect())
+    }
+
+    /// Retourne le nom du joueur d'identifiant donné, s'il existe.
+    pub fn name(&self, id: PlayerId) -> Option<&str> {
+        self.0.get(id.index()).map(String::as_str)
+    }
+
+    /// Retourne le nom du joueur d'un instantané, s'il existe.
+    pub fn resolve(&self, snapshot: &PlayerSnapshot) -> Option<&str> {
+        self.name(snapshot.id)
+    }
+
+    /// Retourne le nombre de joueurs.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Indique si aucun nom n'est retenu.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Parcourt les noms, dans l'ordre des identifiants.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+impl From<Vec<String>> for PlayerNames {
+    fn from(names: Vec<String>) -> Self {
+        PlayerNames(names)
+    }
+}
+
+/// En-tête d'une partie enregistrée : ses joueurs, ses paramètres et de quoi vérifier sa relecture.
+///
+/// Les champs ajoutés par la version 5 du format sont absents des rediffusions plus anciennes, qui
+/// ne peuvent alors pas être vérifiées (voir [`verify_replay`]).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReplayHeader {
+    /// Noms des joueurs.
+    pub players: PlayerNames,
+    /// Nombre d'objectifs par tour.
+    pub objectifs: usize,
+    /// Graine des objectifs, si la partie a été jouée avec `--seed`.
+    pub seed: Option<u64>,
+    /// Version du format de la rediffusion (voir [`REPLAY_VERSION`]).
+    #[serde(default = "legacy_version")]
+    pub version: u32,
+    /// Version de l'application qui a joué la partie.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crate_version: Option<String>,
+    /// Barème des scores de la partie.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scoring: Option<ScoringConfig>,
+    /// Règles de la partie.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules: Option<GameRules>,
+    /// Instantané de chaque joueur au début de la partie, dans l'ordre des identifiants.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub start: Vec<PlayerSnapshot>,
+}
+
+impl ReplayHeader {
+    /// Crée l'en-tête d'une partie au format courant, sans les informations nécessaires à sa
+    /// vérification.
+    pub fn new(players: PlayerNames, objectifs: usize, seed: Option<u64>) -> Self {
+        ReplayHeader {
+            players,
+            objectifs,
+            seed,
+            version: REPLAY_VERSION,
+            crate_version: None,
+            scoring: None,
+            rules: None,
+            start: Vec::new(),
+        }
+    }
+}
+
+/// État des joueurs à la fin d'une manche.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoundRecord {
+    /// Numéro de la manche.
+    pub round: u32,
+    /// Instantané de chaque joueur, dans l'ordre des identifiants.
+    pub players: Vec<PlayerSnapshot>,
+    /// Identifiant du mutateur appliqué pendant la manche, lorsqu'ils sont activés (voir
+    /// [`mutators`](crate::mutators)).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mutator: Option<String>,
+}
+
+/// Arrêt du compteur sur un objectif.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StopRecord {
+    /// Objectif visé.
+    pub objective: u32,
+    /// Valeur du compteur à l'arrêt.
+    pub counter: u32,
+    /// Nombre de tours complets du compteur.
+    pub miss: u32,
+    /// Score obtenu sur l'objectif.
+    pub score: u32,
+    /// Indique si l'objectif a été rejoué : l'arrêt est alors celui du second essai (voir
+    /// [`GameRules::retry`](crate::rules::GameRules::retry)).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub retried: bool,
+}
+
+/// Tour joué par un joueur lors d'une manche.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TurnRecord {
+    /// Numéro de la manche.
+    pub round: u32,
+    /// Index du joueur.
+    pub player: usize,
+    /// Arrêts du compteur, dans l'ordre des objectifs.
+    pub stops: Vec<StopRecord>,
+    /// Score moyen du tour, après le multiplicateur de la prise de risque.
+    pub average: u32,
+    /// Prise de risque choisie avant le tour, lorsqu'elle est activée (voir [`Risk`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub risk: Option<Risk>,
+}
+
+/// Dégâts subis par le perdant d'une manche.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DamageRecord {
+    /// Numéro de la manche.
+    pub round: u32,
+    /// Index du gagnant de la manche.
+    pub winner: usize,
+    /// Index du perdant.
+    pub loser: usize,
+    /// Dégâts retirés à la vitalité du perdant, y compris ceux d'un poison contré.
+    pub dealt: u32,
+    /// Indique que les scores étaient égaux et que la manche a été départagée au temps (voir
+    /// [`GameRules::time_tiebreak`](crate::rules::GameRules::time_tiebreak)).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub time_tiebreak: bool,
+    /// Indique que le gagnant l'a emporté avec trop d'écart et subit la malédiction du vainqueur
+    /// pendant la manche suivante (voir
+    /// [`GameRules::winners_curse`](crate::rules::GameRules::winners_curse)).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub winners_curse: bool,
+}
+
+/// Manche nulle : les deux joueurs ont obtenu le même score moyen, et aucun n'a subi de dégâts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DrawRecord {
+    /// Numéro de la manche.
+    pub round: u32,
+    /// Score moyen commun aux deux joueurs.
+    pub score: u32,
+    /// Nombre de manches nulles consécutives, celle-ci comprise.
+    pub consecutive: u32,
+}
+
+/// Enregistrement complet d'une partie.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    /// En-tête de la partie.
+    #[serde(flatten)]
+    pub header: ReplayHeader,
+    /// Tours joués, dans l'ordre.
+    pub turns: Vec<TurnRecord>,
+    /// État des joueurs à la fin de chaque manche, absent des rediffusions plus anciennes.
+    #[serde(default)]
+    pub rounds: Vec<RoundRecord>,
+    /// Manches nulles, dans l'ordre, absentes des rediffusions antérieures à la version 3.
+    #[serde(default)]
+    pub draws: Vec<DrawRecord>,
+    /// Index du vainqueur, ou `None` en cas de match nul.
+    pub winner: Option<usize>,
+    /// Indique si la partie a été interrompue ou arrêtée brutalement avant sa fin.
+    #[serde(default)]
+    pub unfinished: bool,
+}
+
+/// Erreur survenue lors de l'enregistrement ou du chargement d'une rediffusion.
+#[derive(Debug)]
+pub enum ReplayError {
+    /// Erreur d'entrée/sortie sur le fichier.
+    Io {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: io::Error,
+    },
+    /// Le fichier n'est pas une rediffusion valide.
+    Parse {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: serde_json::Error,
+    },
+    /// La rediffusion a été écrite par une version plus récente de l'application.
+    UnsupportedVersion {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Version du format de la rediffusion.
+        version: u32,
+    },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Io { path, source } => {
+                write!(
+                    f,
+                    "erreur d'accès à la rediffusion {} : {source}",
+                    path.display()
+                )
+            }
+            ReplayError::Parse { path, source } => {
+                write!(f, "rediffusion invalide {} : {source}", path.display())
+            }
+            ReplayError::UnsupportedVersion { path, version } => {
+                write!(
+                    f,
+                    "rediffusion {} au format {version}, plus récent que le format {REPLAY_VERSION} \
+                     pris en charge",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl Error for ReplayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReplayError::Io { source, .. } => Some(source),
+            ReplayError::Parse { source, .. } => Some(source),
+            ReplayError::UnsupportedVersion { .. } => None,
+        }
+    }
+}
+
+/// Premier écart entre un score enregistré et le score recalculé lors de la vérification d'une
+/// rediffusion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    /// Numéro de la manche.
+    pub round: u32,
+    /// Index du joueur.
+    pub player: usize,
+    /// Index de l'objectif, ou `None` si l'écart porte sur le score moyen du tour.
+    pub objective: Option<usize>,
+    /// Score enregistré.
+    pub recorded: u32,
+    /// Score recalculé à partir des arrêts enregistrés.
+    pub recomputed: u32,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "manche {}, joueur {}", self.round, self.player + 1)?;
+        match self.objective {
+            Some(objective) => write!(f, ", objectif {}", objective + 1)?,
+            None => write!(f, ", moyenne du tour")?,
+        }
+        write!(
+            f,
+            " : score enregistré {}, score recalculé {}",
+            self.recorded, self.recomputed
+        )
+    }
+}
+
+/// Résultat de la vérification d'une rediffusion (voir [`verify_replay`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationResult {
+    /// Les scores recalculés concordent avec les scores enregistrés.
+    Verified,
+    /// La rediffusion ne contient pas de quoi recalculer la manche : format antérieur à la
+    /// version 5, joueur inconnu ou manche vide.
+    Unverifiable,
+    /// Un score recalculé diffère du score enregistré.
+    Diverged(Divergence),
+}
+
+/// Recalcule une manche à partir des arrêts enregistrés, du barème, des règles et des
+/// caractéristiques initiales des joueurs de l'en-tête, et compare le résultat aux scores
+/// enregistrés.
+///
+/// La force de chaque joueur est celle de l'en-tête, diminuée de la fatigue de la manche : la
+/// vérification ne vaut que pour la première manche, avant que les poisons et les achats ne
+/// modifient les caractéristiques des joueurs.
+///
+/// # Arguments
+///
+/// * `header` - L'en-tête de la partie.
+/// * `first_round` - Les tours de la première manche.
+///
+/// # Retour
+///
+/// Retourne [`VerificationResult::Diverged`] avec le premier écart rencontré, dans l'ordre des
+/// tours puis des objectifs.
+pub fn verify_replay(header: &ReplayHeader, first_round: &[TurnRecord]) -> VerificationResult {
+    let (Some(scoring), Some(rules), Some(first)) =
+        (&header.scoring, &header.rules, first_round.first())
+    else {
+        return VerificationResult::Unverifiable;
+    };
+    let penalty = rules.fatigue_penalty(first.round);
+    for turn in first_round {
+        let Some(snapshot) = header.start.iter().find(|s| s.id.index() == turn.player) else {
+            return VerificationResult::Unverifiable;
+        };
+        let strength = snapshot.strength.saturating_sub(penalty);
+        let diverged = |objective, recorded, recomputed| {
+            VerificationResult::Diverged(Divergence {
+                round: turn.round,
+                player: turn.player,
+                objective,
+                recorded,
+                recomputed,
+            })
+        };
+        let mut scores = Vec::new();
+        for (index, stop) in turn.stops.iter().enumerate() {
+            let score = scoring.score(stop.objective, stop.counter, stop.miss, strength);
+            if score != stop.score {
+                return diverged(Some(index), stop.score, score);
+            }
+            scores.push(score);
+        }
+        let average = ScoringCalculator::calculate_average(&scores);
+        let average = turn.risk.map_or(average, |risk| risk.apply(average));
+        if average != turn.average {
+            return diverged(None, turn.average, average);
+        }
+    }
+    VerificationResult::Verified
+}
+
+impl Replay {
+    /// Construit l'enregistrement d'une partie à partir de son historique.
+    pub fn from_game(game: &Game) -> Self {
+        Replay {
+            header: game.replay_header(),
+            turns: game.history.clone(),
+            rounds: game.rounds.clone(),
+            draws: game.draws.clone(),
+            winner: game.winner().map(|id| id.index()),
+            unfinished: false,
+        }
+    }
+
+    /// Reconstruit la rediffusion d'une partie à partir des enregistrements de son historique.
+    ///
+    /// Retourne `None` si les enregistrements ne commencent pas par l'en-tête de la partie. Sans
+    /// enregistrement de fin, la partie est considérée comme inachevée.
+    pub fn from_entries<I>(entries: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = HistoryEntry>,
+    {
+        let mut entries = entries.into_iter();
+        let Some(HistoryEntry::Header(header)) = entries.next() else {
+            return None;
+        };
+        let mut replay = Replay {
+            header,
+            turns: Vec::new(),
+            rounds: Vec::new(),
+            draws: Vec::new(),
+            winner: None,
+            unfinished: true,
+        };
+        for entry in entries {
+            match entry {
+                HistoryEntry::Turn(turn) => replay.turns.push(turn),
+                HistoryEntry::Round(round) => replay.rounds.push(round),
+                HistoryEntry::Draw(draw) => replay.draws.push(draw),
+                HistoryEntry::End { outcome, winner } => {
+                    replay.winner = winner;
+                    replay.unfinished = outcome == Outcome::Interrupted;
+                }
+                HistoryEntry::Header(_) | HistoryEntry::Damage(_) => {}
+            }
+        }
+        Some(replay)
+    }
+
+    /// Enregistre la rediffusion au format JSON.
+    pub fn save(&self, path: &Path) -> Result<(), ReplayError> {
+        let content =
+            serde_json::to_string_pretty(self).expect("une rediffusion est toujours sérialisable");
+        fs::write(path, content).map_err(|source| ReplayError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Charge une rediffusion enregistrée avec [`Replay::save`], ou un historique écrit au format JSON
+    /// Lines par un [`JsonlSink`](crate::history::JsonlSink).
+    ///
+    /// Les rediffusions des versions antérieures du format sont acceptées ; celles d'une version plus
+    /// récente que [`REPLAY_VERSION`] sont refusées.
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        let replay = Replay::parse(path)?;
+        if replay.header.version > REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion {
+                path: path.to_path_buf(),
+                version: replay.header.version,
+            });
+        }
+        Ok(replay)
+    }
+
+    /// Charge toutes les rediffusions (fichiers `.json` et `.jsonl`) d'un répertoire, dans l'ordre de
+    /// leurs noms.
+    ///
+    /// Seule la lecture du répertoire peut échouer globalement : chaque fichier donne son propre
+    /// résultat, de sorte qu'une rediffusion illisible n'empêche pas d'exploiter les autres.
+    pub fn load_dir(dir: &Path) -> Result<Vec<Result<Self, ReplayError>>, ReplayError> {
+        let io_error = |source| ReplayError::Io {
+            path: dir.to_path_buf(),
+            source,
+        };
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            let extension = path.extension().and_then(|extension| extension.to_str());
+            if path.is_file() && matches!(extension, Some("json" | "jsonl")) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        Ok(paths.iter().map(|path| Replay::load(path)).collect())
+    }
+
+    /// Lit le contenu d'une rediffusion, quelle que soit la version de son format.
+    fn parse(path: &Path) -> Result<Self, ReplayError> {
+        let content = fs::read_to_string(path).map_err(|source| ReplayError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let parse_error = |source| ReplayError::Parse {
+            path: path.to_path_buf(),
+            source,
+        };
+        if !content.starts_with("{\"kind\":") {
+            return serde_json::from_str(&content).map_err(parse_error);
+        }
+        let entries = content
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<HistoryEntry>, _>>()
+            .map_err(parse_error)?;
+        Replay::from_entries(entries).ok_or_else(|| {
+            parse_error(serde::de::Error::custom(
+                "l'historique ne commence pas par son en-tête",
+            ))
+        })
+    }
+
+    /// Vérifie la première manche de la partie à partir de son en-tête (voir [`verify_replay`]).
+    pub fn verify(&self) -> VerificationResult {
+        let Some(first) = self.turns.iter().map(|turn| turn.round).min() else {
+            return VerificationResult::Unverifiable;
+        };
+        let turns: Vec<TurnRecord> = self
+            .turns
+            .iter()
+            .filter(|turn| turn.round == first)
+            .cloned()
+            .collect();
+        verify_replay(&self.header, &turns)
+    }
+
+    /// Retourne le nom d'un joueur, ou son numéro si l'index est inconnu.
+    fn name(&self, index: usize) -> String {
+        self.header
+            .players
+            .name(PlayerId(index))
+            .map(String::from)
+            .unwrap_or_else(|| format!("Joueur {}", index + 1))
+    }
+}
+
+impl fmt::Display for Replay {
+    /// Retrace la partie manche par manche.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Rediffusion : {} ({} objectifs par tour",
+            self.header
+                .players
+                .iter()
+                .collect::<Vec<_>>()
+                .join(" contre "),
+            self.header.objectifs
+        )?;
+        if let Some(seed) = self.header.seed {
+            write!(f, ", graine {seed}")?;
+        }
+        writeln!(f, ")")?;
+
+        let mut round = None;
+        let mut turns = self.turns.iter().peekable();
+        while let Some(turn) = turns.next() {
+            if round != Some(turn.round) {
+                round = Some(turn.round);
+                writeln!(f, "\n## Manche {} ##", turn.round)?;
+                let record = self.rounds.iter().find(|record| record.round == turn.round);
+                if let Some(id) = record.and_then(|record| record.mutator.as_deref()) {
+                    let name = mutators::find(id).map_or(id, |modifier| modifier.name());
+                    writeln!(f, "Mutateur : {name}")?;
+                }
+            }
+            let stops: Vec<String> = turn
+                .stops
+                .iter()
+                .map(|stop| {
+                    format!(
+                        "{}→{} (miss {}, score {}{})",
+                        stop.objective,
+                        stop.counter,
+                        stop.miss,
+                        stop.score,
+                        if stop.retried { ", rejoué" } else { "" }
+                    )
+                })
+                .collect();
+            write!(
+                f,
+                "{} : {} | moyenne {}",
+                self.name(turn.player),
+                stops.join(", "),
+                turn.average
+            )?;
+            match turn.risk {
+                Some(risk) => writeln!(f, " (risque : {risk})")?,
+                None => writeln!(f)?,
+            }
+            let round_over = turns.peek().is_none_or(|next| next.round != turn.round);
+            if round_over && let Some(draw) = self.draws.iter().find(|d| d.round == turn.round) {
+                writeln!(f, "Égalité à {}, aucune pénalité", draw.score)?;
+            }
+        }
+
+        match self.winner {
+            _ if self.unfinished => write!(f, "\nPartie inachevée"),
+            Some(winner) => write!(f, "\nVainqueur : {}", self.name(winner)),
+            None => write!(f, "\nMatch nul"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::{BotController, Difficulty};
+    use crate::output::Verbosity;
+    use crate::player::Player;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    /// Vérifie qu'une partie enregistrée puis rechargée retrace toutes ses manches et leurs mutateurs.
+    #[test]
+    fn test_replay_roundtrip() {
+        let players = vec![
+            Player::new(String::from("Alice"), 20, 75, 50),
+            Player::new(String::from("Bob"), 20, 75, 50),
+        ];
+        let mut game = Game::new(players, 3);
+        game.set_verbosity(Verbosity::Silent);
+        game.set_seed(7);
+        game.rules.mutators = true;
+        for index in 0..2 {
+            let rng = StdRng::seed_from_u64(index);
+            game.set_controller(
+                index as usize,
+                Box::new(BotController::with_rng(Difficulty::Normal, rng)),
+            );
+        }
+        game.run().unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("partie.json");
+        let replay = Replay::from_game(&game);
+        replay.save(&path).unwrap();
+        let loaded = Replay::load(&path).unwrap();
+        assert_eq!(loaded, replay);
+        assert_eq!(loaded.header.seed, Some(7));
+        assert_eq!(loaded.verify(), VerificationResult::Verified);
+        assert_eq!(loaded.turns.len(), 2 * (game.round as usize - 1));
+        assert!(loaded.turns.iter().all(|turn| turn.stops.len() == 3));
+        assert_eq!(loaded.rounds.len(), game.round as usize - 1);
+        assert!(loaded.rounds.iter().all(|record| record.mutator.is_some()));
+        let last = loaded.rounds.last().unwrap();
+        assert_eq!(
+            last.players
+                .iter()
+                .map(|snapshot| snapshot.vitality)
+                .collect::<Vec<_>>(),
+            game.players
+                .iter()
+                .map(Player::vitality)
+                .collect::<Vec<_>>()
+        );
+
+        let text = loaded.to_string();
+        assert!(
+            text.starts_with("Rediffusion : Alice contre Bob (3 objectifs par tour, graine 7)")
+        );
+        assert!(text.contains("## Manche 1 ##\nMutateur : "));
+        assert!(text.ends_with(&format!(
+            "Vainqueur : {}",
+            game.player(game.winner().unwrap()).name
+        )));
+    }
+
+    /// Vérifie qu'une rediffusion au format d'origine reste lisible, qu'une rediffusion d'un format
+    /// plus récent est refusée et que seuls les fichiers de rediffusion d'un répertoire sont lus.
+    #[test]
+    fn test_replay_versions() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/replays");
+        let legacy = Replay::load(&fixtures.join("ancienne.json")).unwrap();
+        assert_eq!(legacy.header.version, 1);
+        assert!(legacy.rounds.is_empty() && !legacy.unfinished);
+        assert_eq!(legacy.winner, Some(0));
+        let recent = Replay::load(&fixtures.join("recente.jsonl")).unwrap();
+        assert_eq!((recent.header.version, recent.turns.len()), (2, 2));
+        assert!(recent.draws.is_empty());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("future.json");
+        let mut future = legacy.clone();
+        future.header.version = REPLAY_VERSION + 1;
+        future.save(&path).unwrap();
+        assert!(matches!(
+            Replay::load(&path),
+            Err(ReplayError::UnsupportedVersion { version, .. }) if version == REPLAY_VERSION + 1
+        ));
+
+        let loaded = Replay::load_dir(&fixtures).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].as_ref().unwrap(), &legacy);
+        assert_eq!(loaded[1].as_ref().unwrap(), &recent);
+        assert!(matches!(
+            Replay::load_dir(&dir.path().join("absent")),
+            Err(ReplayError::Io { .. })
+        ));
+    }
+
+    /// Vérifie qu'une rediffusion dont le barème enregistré ne concorde pas avec ses scores est
+    /// signalée au premier écart, qu'elle se vérifie avec le barème standard, et qu'une rediffusion
+    /// antérieure à la version 5 ne peut pas être vérifiée.
+    #[test]
+    fn test_verify_mismatched_scoring() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let mismatched = Replay::load(&fixtures.join("mismatched_scoring.json")).unwrap();
+        assert_eq!(mismatched.header.crate_version.as_deref(), Some("0.1.0"));
+        let divergence = Divergence {
+            round: 1,
+            player: 0,
+            objective: Some(0),
+            recorded: 150,
+            recomputed: 170,
+        };
+        assert_eq!(
+            mismatched.verify(),
+            VerificationResult::Diverged(divergence)
+        );
+        assert_eq!(
+            divergence.to_string(),
+            "manche 1, joueur 1, objectif 1 : score enregistré 150, score recalculé 170"
+        );
+
+        let mut standard = mismatched.clone();
+        standard.header.scoring = Some(ScoringConfig::default());
+        assert_eq!(standard.verify(), VerificationResult::Verified);
+        standard.turns[1].average = 99;
+        assert!(matches!(
+            standard.verify(),
+            VerificationResult::Diverged(Divergence {
+                player: 1,
+                objective: None,
+                recorded: 99,
+                recomputed: 100,
+                ..
+            })
+        ));
+        assert_eq!(
+            verify_replay(&mismatched.header, &[]),
+            VerificationResult::Unverifiable
+        );
+
+        let legacy = Replay::load(&fixtures.join("replays/ancienne.json")).unwrap();
+        assert_eq!(legacy.header.scoring, None);
+        assert_eq!(legacy.verify(), VerificationResult::Unverifiable);
+    }
+}
@@ -0,0 +1,104 @@
+//! Module de la prise de risque choisie par chaque joueur avant son tour.
+//!
+//! Lorsque la prise de risque est activée ([`GameRules::risk_choice`](crate::rules::GameRules::risk_choice)),
+//! chaque joueur choisit un [`Risk`] avant le tirage de ses objectifs en mode classique : moins
+//! d'objectifs pour une moyenne réduite, ou davantage pour une moyenne majorée. Le multiplicateur
+//! s'applique au score moyen du tour ([`Risk::apply`]), et le choix est consigné dans l'historique.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Prise de risque d'un joueur pour son tour.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Risk {
+    /// Prudent : 3 objectifs, moyenne multipliée par 0,9.
+    Safe,
+    /// Normal : 5 objectifs, moyenne inchangée.
+    #[default]
+    Normal,
+    /// Risqué : 7 objectifs, moyenne multipliée par 1,15.
+    Risky,
+}
+
+impl Risk {
+    /// Prises de risque proposées, dans l'ordre du menu.
+    pub const ALL: [Risk; 3] = [Risk::Safe, Risk::Normal, Risk::Risky];
+
+    /// Retourne le nom de la prise de risque, tel qu'affiché dans le menu.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Risk::Safe => "Prudent",
+            Risk::Normal => "Normal",
+            Risk::Risky => "Risqué",
+        }
+    }
+
+    /// Retourne le nombre d'objectifs du tour.
+    pub fn objectives(&self) -> usize {
+        match self {
+            Risk::Safe => 3,
+            Risk::Normal => 5,
+            Risk::Risky => 7,
+        }
+    }
+
+    /// Retourne le multiplicateur du score moyen, en pourcentage.
+    pub fn multiplier_percent(&self) -> u32 {
+        match self {
+            Risk::Safe => 90,
+            Risk::Normal => 100,
+            Risk::Risky => 115,
+        }
+    }
+
+    /// Applique le multiplicateur au score moyen d'un tour, arrondi à l'entier le plus proche (les
+    /// demis sont arrondis vers le haut).
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::risk::Risk;
+    ///
+    /// assert_eq!(Risk::Safe.apply(100), 90);
+    /// assert_eq!(Risk::Normal.apply(77), 77);
+    /// assert_eq!(Risk::Risky.apply(100), 115);
+    /// ```
+    pub fn apply(&self, average: u32) -> u32 {
+        let scaled = u64::from(average) * u64::from(self.multiplier_percent()) + 50;
+        u32::try_from(scaled / 100).unwrap_or(u32::MAX)
+    }
+}
+
+impl fmt::Display for Risk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie l'arrondi du score moyen multiplié, y compris les demis et les très grands scores.
+    #[test]
+    fn test_multiplier_rounding() {
+        assert_eq!(Risk::Safe.apply(55), 50);
+        assert_eq!(Risk::Safe.apply(54), 49);
+        assert_eq!(Risk::Safe.apply(0), 0);
+        assert_eq!(Risk::Risky.apply(10), 12);
+        assert_eq!(Risk::Risky.apply(70), 81);
+        assert_eq!(Risk::Risky.apply(3), 3);
+        assert_eq!(Risk::Normal.apply(149), 149);
+        assert_eq!(Risk::Risky.apply(u32::MAX), u32::MAX);
+    }
+
+    /// Vérifie le nombre d'objectifs de chaque prise de risque, dans l'ordre du menu.
+    #[test]
+    fn test_objectives() {
+        let counts: Vec<usize> = Risk::ALL.iter().map(Risk::objectives).collect();
+        assert_eq!(counts, [3, 5, 7]);
+        assert_eq!(Risk::default(), Risk::Normal);
+    }
+}
@@ -0,0 +1,886 @@
+//! Module définissant les règles optionnelles d'une partie.
+//!
+//! La structure [`GameRules`] regroupe les variantes de règles activables pour une partie
+//! ([`Game::rules`](crate::game::Game::rules)). La fonction [`render`] explique les règles du jeu à
+//! partir des valeurs effectivement utilisées (barème, poison, règles optionnelles), et [`offer`] propose
+//! de les afficher avant la première partie. Le [`GameMode`] choisit le déroulement des manches :
+//! tours complets (classique), objectifs disputés un à un (duel) ou compteur commun aux deux joueurs
+//! (partagé). En mode miroir, [`mirror`] échange les caractéristiques des deux joueurs avant la partie.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::console::Console;
+use crate::counter::BREATH_SLOWDOWN_PERCENT;
+use crate::items::COIN_DIVISOR;
+use crate::keys;
+use crate::messages::{self, Lang, Msg};
+use crate::player::{DEFAULT_COUNTERS, DEFAULT_STAMINA, Player};
+use crate::poison::POISON_AMOUNT;
+use crate::scoring::ScoringConfig;
+use crate::setup::{ask, parse_yes_no};
+use crate::shared::SHARED_MISS_CAP;
+use crate::tr;
+
+/// Dernière manche jouée sans fatigue.
+pub const FATIGUE_START_ROUND: u32 = 5;
+/// Force effective perdue par manche jouée au-delà de [`FATIGUE_START_ROUND`].
+pub const FATIGUE_PER_ROUND: u32 = 2;
+/// Vitalité perdue par point d'écart en mode duel, par défaut.
+pub const DEFAULT_DUEL_FACTOR: u32 = 10;
+/// Nombre de manches observées pour détecter la stagnation, par défaut.
+pub const DEFAULT_STALEMATE_ROUNDS: u32 = 5;
+/// Dégâts moyens par manche en deçà desquels la partie stagne.
+pub const STALEMATE_DAMAGE_PER_ROUND: u32 = 2;
+/// Vitalité perdue au moins par le perdant d'une manche tant que l'anti-stagnation est activée.
+pub const STALEMATE_MIN_DAMAGE: u32 = 5;
+/// Dégâts d'une manche qui lèvent l'anti-stagnation.
+pub const STALEMATE_RELIEF_DAMAGE: u32 = 2 * STALEMATE_MIN_DAMAGE;
+/// Nombre maximal d'objectifs par tour, par défaut.
+pub const DEFAULT_MAX_OBJECTIVES: usize = 1000;
+
+/// Déroulement des manches d'une partie.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GameMode {
+    /// Chaque joueur joue tous ses objectifs à son tour ; le meilleur score moyen remporte la manche.
+    #[default]
+    Classic,
+    /// Les joueurs visent tour à tour le même objectif ; chaque objectif rapporte un point au
+    /// meilleur score, et le joueur ayant le plus de points remporte la manche.
+    Duel,
+    /// Un seul compteur défile pour les deux joueurs, qui verrouillent chacun leur valeur sur le même
+    /// objectif (voir [`shared`](crate::shared)) ; les scores moyens sont comparés comme en mode
+    /// classique.
+    Shared,
+}
+
+impl GameMode {
+    /// Liste de tous les modes de jeu.
+    pub const ALL: [GameMode; 3] = [GameMode::Classic, GameMode::Duel, GameMode::Shared];
+
+    /// Retourne l'identifiant du mode utilisé en ligne de commande.
+    pub fn id(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "classic",
+            GameMode::Duel => "duel",
+            GameMode::Shared => "shared",
+        }
+    }
+}
+
+impl fmt::Display for GameMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            GameMode::Classic => "Classique",
+            GameMode::Duel => "Duel",
+            GameMode::Shared => "Partagé",
+        };
+        write!(f, "{label}")
+    }
+}
+
+impl FromStr for GameMode {
+    type Err = String;
+
+    /// Analyse un mode de jeu à partir de son identifiant (insensible à la casse).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s.trim().to_lowercase();
+        GameMode::ALL
+            .into_iter()
+            .find(|mode| mode.id() == id)
+            .ok_or_else(|| {
+                let ids: Vec<&str> = GameMode::ALL.iter().map(|m| m.id()).collect();
+                format!(
+                    "mode inconnu « {s} » (valeurs possibles : {})",
+                    ids.join(", ")
+                )
+            })
+    }
+}
+
+/// Règles optionnelles d'une partie.
+///
+/// Les règles absentes d'une sauvegarde (voir [`saves`](crate::saves)) prennent leur valeur par défaut.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRules {
+    /// Active la fatigue : après la 5e manche, chaque manche retire 2 points de force effective
+    /// supplémentaires à chaque joueur, pour pousser la partie vers sa conclusion.
+    pub fatigue: bool,
+    /// Active l'endurance : chaque joueur dispose de points d'endurance par partie, dépensés pour
+    /// ralentir le compteur de 50 % pendant un objectif.
+    pub stamina: bool,
+    /// Déroulement des manches.
+    pub mode: GameMode,
+    /// Vitalité perdue par le perdant d'une manche en duel, par point d'écart.
+    pub duel_factor: u32,
+    /// Active la boutique : chaque manche rapporte des pièces, dépensées entre les manches en objets
+    /// (voir [`items`](crate::items)).
+    pub shop: bool,
+    /// Active le mode miroir : les joueurs échangent leur vitesse et leur force avant la première
+    /// manche (voir [`mirror`]).
+    pub mirror: bool,
+    /// Active la prise de risque : en mode classique, chaque joueur choisit avant son tour le nombre
+    /// de ses objectifs, qui module son score moyen (voir [`risk`](crate::risk)).
+    pub risk_choice: bool,
+    /// Active les mutateurs : au début de chaque manche, un modificateur tiré au sort s'applique aux
+    /// deux joueurs pour la manche (voir [`mutators`](crate::mutators)).
+    pub mutators: bool,
+    /// Active les interdictions : au début de chaque manche, le joueur qui a le moins de vitalité peut
+    /// interdire un poison au vainqueur de la manche.
+    pub bans: bool,
+    /// Active le rejeu : le perdant d'une manche gagne un rejeu (un seul en réserve), qu'il peut
+    /// dépenser lors d'une manche suivante pour rejouer aussitôt un objectif dont l'arrêt l'a déçu ;
+    /// le second arrêt est retenu, même s'il est moins bon.
+    pub retry: bool,
+    /// Active le départage au temps : une manche à égalité de scores est remportée par le joueur dont
+    /// les arrêts du compteur ont duré le moins longtemps au total, pour des dégâts fixes
+    /// ([`TIME_TIEBREAK_DAMAGE`]) ; à durées égales, la manche reste nulle.
+    pub time_tiebreak: bool,
+    /// Nombre de manches sur lesquelles la stagnation est détectée, 0 pour ne jamais la détecter :
+    /// lorsque ces manches ont infligé en tout moins de [`STALEMATE_DAMAGE_PER_ROUND`] points de
+    /// dégâts par manche, l'anti-stagnation impose [`STALEMATE_MIN_DAMAGE`] points de dégâts à
+    /// chaque manche perdue, jusqu'à une manche d'au moins [`STALEMATE_RELIEF_DAMAGE`] points.
+    pub stalemate_rounds: u32,
+    /// Nombre maximal d'objectifs par tour accepté à la création de la partie (voir
+    /// [`GameRules::check_objectives`]) : chaque objectif est retenu dans l'historique, et une
+    /// simulation lancée avec des milliers d'objectifs par tour épuiserait la mémoire.
+    pub max_objectives: usize,
+    /// Active le poison différé : le poison choisi par le vainqueur d'une manche ne fait effet qu'au
+    /// début de la manche suivante, annoncé à sa victime ; il est perdu si la partie se termine
+    /// avant. La contre-proposition, elle, fait toujours effet aussitôt.
+    pub delayed_poison: bool,
+    /// Active la malédiction du vainqueur (« l'excès de confiance ») : le joueur qui remporte une
+    /// manche avec plus de [`WINNERS_CURSE_MARGIN`] points d'écart de score perd
+    /// [`WINNERS_CURSE_SPEED`] points de vitesse effective pendant la manche suivante seulement.
+    pub winners_curse: bool,
+}
+
+/// Dégâts infligés au perdant d'une manche départagée au temps ([`GameRules::time_tiebreak`]),
+/// sans armure ni mutateur.
+pub const TIME_TIEBREAK_DAMAGE: u32 = 3;
+
+/// Écart de score moyen au-delà duquel le vainqueur d'une manche est frappé par la malédiction du
+/// vainqueur ([`GameRules::winners_curse`]).
+pub const WINNERS_CURSE_MARGIN: u32 = 60;
+
+/// Points de vitesse effective retirés au vainqueur maudit pendant la manche suivante : le délai
+/// entre deux incrémentations du compteur raccourcit d'autant.
+pub const WINNERS_CURSE_SPEED: u32 = 3;
+
+/// Règles par défaut, celles du préréglage `classic`.
+const DEFAULT_RULES: GameRules = GameRules {
+    fatigue: false,
+    stamina: false,
+    mode: GameMode::Classic,
+    duel_factor: DEFAULT_DUEL_FACTOR,
+    shop: false,
+    mirror: false,
+    risk_choice: false,
+    mutators: false,
+    bans: false,
+    retry: false,
+    time_tiebreak: false,
+    stalemate_rounds: DEFAULT_STALEMATE_ROUNDS,
+    max_objectives: DEFAULT_MAX_OBJECTIVES,
+    delayed_poison: false,
+    winners_curse: false,
+};
+
+impl Default for GameRules {
+    fn default() -> Self {
+        DEFAULT_RULES
+    }
+}
+
+impl GameRules {
+    /// Calcule la pénalité de force due à la fatigue pour la manche donnée.
+    ///
+    /// La pénalité est temporaire : elle s'applique à la force effective des joueurs sans modifier
+    /// leurs caractéristiques.
+    ///
+    /// # Arguments
+    ///
+    /// * `round` - Le numéro de la manche (à partir de 1).
+    ///
+    /// # Retour
+    ///
+    /// Retourne la force effective retirée à chaque joueur, ou 0 si la fatigue est désactivée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::rules::GameRules;
+    ///
+    /// let rules = GameRules {
+    ///     fatigue: true,
+    ///     ..GameRules::default()
+    /// };
+    /// assert_eq!(rules.fatigue_penalty(7), 4);
+    /// ```
+    pub fn fatigue_penalty(&self, round: u32) -> u32 {
+        if !self.fatigue {
+            return 0;
+        }
+        round
+            .saturating_sub(FATIGUE_START_ROUND)
+            .saturating_mul(FATIGUE_PER_ROUND)
+    }
+
+    /// Calcule l'écart de la manche à infliger au perdant, avant son armure.
+    ///
+    /// En modes classique et partagé, l'écart est celui des scores moyens ; en duel, chaque point d'écart vaut
+    /// [`GameRules::duel_factor`] points de vitalité.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::rules::{GameMode, GameRules};
+    ///
+    /// let duel = GameRules {
+    ///     mode: GameMode::Duel,
+    ///     ..GameRules::default()
+    /// };
+    /// assert_eq!(duel.round_damage(3, 1), 20);
+    /// assert_eq!(GameRules::default().round_damage(80, 65), 15);
+    /// ```
+    pub fn round_damage(&self, winner: u32, loser: u32) -> u32 {
+        let diff = winner.saturating_sub(loser);
+        match self.mode {
+            GameMode::Classic | GameMode::Shared => diff,
+            GameMode::Duel => diff.saturating_mul(self.duel_factor),
+        }
+    }
+
+    /// Retourne le total de dégâts en deçà duquel les [`GameRules::stalemate_rounds`] dernières
+    /// manches déclenchent l'anti-stagnation.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::rules::GameRules;
+    ///
+    /// assert_eq!(GameRules::default().stalemate_threshold(), 10);
+    /// ```
+    pub fn stalemate_threshold(&self) -> u32 {
+        self.stalemate_rounds
+            .saturating_mul(STALEMATE_DAMAGE_PER_ROUND)
+    }
+
+    /// Vérifie que `count` objectifs par tour ne dépassent pas [`GameRules::max_objectives`].
+    ///
+    /// # Erreurs
+    ///
+    /// Retourne [`SetupError::TooManyObjectives`] au-delà de la limite.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::rules::GameRules;
+    ///
+    /// let rules = GameRules::default();
+    /// assert!(rules.check_objectives(1000).is_ok());
+    /// assert!(rules.check_objectives(10_000).is_err());
+    /// let raised = GameRules { max_objectives: 10_000, ..rules };
+    /// assert!(raised.check_objectives(10_000).is_ok());
+    /// ```
+    pub fn check_objectives(&self, count: usize) -> Result<(), SetupError> {
+        if count > self.max_objectives {
+            return Err(SetupError::TooManyObjectives {
+                count,
+                limit: self.max_objectives,
+            });
+        }
+        Ok(())
+    }
+
+    /// Retourne les règles du préréglage nommé (voir [`PRESETS`]).
+    ///
+    /// # Erreurs
+    ///
+    /// Retourne [`UnknownPreset`] si aucun préréglage ne porte ce nom.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::rules::GameRules;
+    ///
+    /// assert_eq!(GameRules::preset("classic"), Ok(GameRules::default()));
+    /// assert!(GameRules::preset("party").unwrap().mutators);
+    /// assert!(GameRules::preset("hardcore").is_err());
+    /// ```
+    pub fn preset(name: &str) -> Result<GameRules, UnknownPreset> {
+        Preset::find(name).map(|preset| preset.rules)
+    }
+}
+
+/// Préréglage nommé des règles d'une partie, choisi par `--preset`.
+///
+/// Un préréglage fixe des règles optionnelles et, le cas échéant, le nombre d'objectifs par tour et la
+/// vitesse des joueurs ; les options données en ligne de commande l'emportent sur ses valeurs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Preset {
+    /// Nom du préréglage, utilisé en ligne de commande.
+    pub name: &'static str,
+    /// Règles optionnelles de la partie.
+    pub rules: GameRules,
+    /// Nombre d'objectifs par tour, `None` pour garder celui de la configuration.
+    pub objectives: Option<usize>,
+    /// Vitesse des joueurs, `None` pour garder la leur.
+    pub speed: Option<u32>,
+    /// Description affichée par `dual_game presets`.
+    description: Msg,
+}
+
+/// Préréglages disponibles, dans l'ordre de leur affichage.
+pub const PRESETS: [Preset; 4] = [
+    Preset {
+        name: "classic",
+        rules: DEFAULT_RULES,
+        objectives: None,
+        speed: None,
+        description: Msg::PresetClassic,
+    },
+    Preset {
+        name: "blitz",
+        rules: GameRules {
+            fatigue: true,
+            ..DEFAULT_RULES
+        },
+        objectives: Some(3),
+        speed: Some(50),
+        description: Msg::PresetBlitz,
+    },
+    Preset {
+        name: "marathon",
+        rules: GameRules {
+            stamina: true,
+            ..DEFAULT_RULES
+        },
+        objectives: Some(8),
+        speed: None,
+        description: Msg::PresetMarathon,
+    },
+    Preset {
+        name: "party",
+        rules: GameRules {
+            shop: true,
+            mutators: true,
+            ..DEFAULT_RULES
+        },
+        objectives: None,
+        speed: None,
+        description: Msg::PresetParty,
+    },
+];
+
+impl Preset {
+    /// Retourne le préréglage nommé (insensible à la casse).
+    ///
+    /// # Erreurs
+    ///
+    /// Retourne [`UnknownPreset`] si aucun préréglage ne porte ce nom.
+    pub fn find(name: &str) -> Result<&'static Preset, UnknownPreset> {
+        let id = name.trim().to_lowercase();
+        PRESETS
+            .iter()
+            .find(|preset| preset.name == id)
+            .ok_or_else(|| UnknownPreset(name.to_string()))
+    }
+
+    /// Retourne la description du préréglage dans la langue courante.
+    pub fn description(&self) -> String {
+        tr!(self.description)
+    }
+}
+
+impl FromStr for Preset {
+    type Err = UnknownPreset;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Preset::find(s).copied()
+    }
+}
+
+/// Erreur d'un nom de préréglage inconnu.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownPreset(pub String);
+
+impl fmt::Display for UnknownPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = PRESETS.iter().map(|preset| preset.name).collect();
+        write!(
+            f,
+            "{}",
+            tr!(Msg::UnknownPreset, name = self.0, names = names.join(", "))
+        )
+    }
+}
+
+impl Error for UnknownPreset {}
+
+/// Liste les préréglages disponibles, un par ligne avec sa description.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::rules::list_presets;
+///
+/// assert!(list_presets().contains("blitz : "));
+/// ```
+pub fn list_presets() -> String {
+    let mut lines = vec![tr!(Msg::PresetsTitle)];
+    lines.extend(PRESETS.iter().map(|preset| {
+        tr!(
+            Msg::PresetEntry,
+            name = preset.name,
+            description = preset.description()
+        )
+    }));
+    lines.join("\n")
+}
+
+/// Rédige l'explication des règles du jeu à partir des règles et du barème donnés.
+///
+/// Le texte est généré à partir des valeurs réellement utilisées par la partie, afin de ne jamais
+/// s'écarter de son comportement.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::rules::{GameRules, render};
+/// use dual_game::scoring::ScoringConfig;
+///
+/// let text = render(&GameRules::default(), &ScoringConfig::default());
+/// assert!(text.contains("100 + force"));
+/// ```
+pub fn render(rules: &GameRules, scoring: &ScoringConfig) -> String {
+    let mut lines = vec![
+        tr!(Msg::RulesTitle),
+        tr!(Msg::RulesCounter),
+        tr!(Msg::RulesScoring),
+    ];
+    let mut low = 0;
+    for tier in &scoring.tiers {
+        lines.push(if tier.max_difference == low {
+            tr!(Msg::RulesTierSingle, difference = low, base = tier.base)
+        } else {
+            tr!(
+                Msg::RulesTierRange,
+                low = low,
+                high = tier.max_difference,
+                base = tier.base
+            )
+        });
+        low = tier.max_difference + 1;
+    }
+    if let Some(last) = scoring.tiers.last() {
+        lines.push(tr!(Msg::RulesTierBeyond, difference = last.max_difference));
+    }
+    lines.push(tr!(Msg::RulesMiss));
+    lines.push(tr!(Msg::RulesDamage));
+    lines.push(tr!(
+        Msg::RulesPoison,
+        amount = POISON_AMOUNT,
+        counters = DEFAULT_COUNTERS
+    ));
+    lines.push(tr!(Msg::RulesWin));
+    lines.push(if rules.fatigue {
+        tr!(
+            Msg::RulesFatigueOn,
+            start = FATIGUE_START_ROUND,
+            per_round = FATIGUE_PER_ROUND
+        )
+    } else {
+        tr!(Msg::RulesFatigueOff)
+    });
+    lines.push(if rules.stamina {
+        tr!(
+            Msg::RulesStaminaOn,
+            stamina = DEFAULT_STAMINA,
+            key = keys::bindings().stamina,
+            slowdown = BREATH_SLOWDOWN_PERCENT - 100
+        )
+    } else {
+        tr!(Msg::RulesStaminaOff)
+    });
+    lines.push(tr!(Msg::RulesPause, key = keys::bindings().pause));
+    if rules.stalemate_rounds > 0 {
+        lines.push(tr!(
+            Msg::RulesStalemate,
+            rounds = rules.stalemate_rounds,
+            threshold = rules.stalemate_threshold(),
+            damage = STALEMATE_MIN_DAMAGE,
+            relief = STALEMATE_RELIEF_DAMAGE
+        ));
+    }
+    match rules.mode {
+        GameMode::Classic => {}
+        GameMode::Duel => lines.push(tr!(Msg::RulesDuel, factor = rules.duel_factor)),
+        GameMode::Shared => {
+            let [first_key, second_key] = keys::bindings().locks;
+            lines.push(tr!(
+                Msg::RulesShared,
+                first_key = first_key,
+                second_key = second_key,
+                cap = SHARED_MISS_CAP
+            ));
+        }
+    }
+    if rules.shop {
+        lines.push(tr!(Msg::RulesShop, divisor = COIN_DIVISOR));
+    }
+    if rules.mirror {
+        lines.push(tr!(Msg::RulesMirror));
+    }
+    if rules.risk_choice {
+        lines.push(tr!(Msg::RulesRisk));
+    }
+    if rules.mutators {
+        lines.push(tr!(Msg::RulesMutators));
+    }
+    if rules.bans {
+        lines.push(tr!(Msg::RulesBans));
+    }
+    if rules.retry {
+        lines.push(tr!(Msg::RulesRetry));
+    }
+    if rules.time_tiebreak {
+        lines.push(tr!(Msg::RulesTimeTiebreak, damage = TIME_TIEBREAK_DAMAGE));
+    }
+    if rules.delayed_poison {
+        lines.push(tr!(Msg::RulesDelayedPoison));
+    }
+    if rules.winners_curse {
+        lines.push(tr!(
+            Msg::RulesWinnersCurse,
+            margin = WINNERS_CURSE_MARGIN,
+            speed = WINNERS_CURSE_SPEED
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Erreur empêchant d'appliquer le mode miroir.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MirrorError {
+    /// Le mode miroir se joue à deux joueurs exactement.
+    PlayerCount(usize),
+}
+
+impl fmt::Display for MirrorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MirrorError::PlayerCount(count) => {
+                write!(f, "{}", tr!(Msg::MirrorPlayerCount, count = count))
+            }
+        }
+    }
+}
+
+impl Error for MirrorError {}
+
+/// Erreur empêchant de créer une partie avec les règles demandées (voir
+/// [`Game::new_with_rules`](crate::game::Game::new_with_rules)).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SetupError {
+    /// Le mode miroir ne peut pas s'appliquer aux joueurs.
+    Mirror(MirrorError),
+    /// Le nombre d'objectifs par tour dépasse [`GameRules::max_objectives`].
+    TooManyObjectives {
+        /// Nombre d'objectifs par tour demandé.
+        count: usize,
+        /// Nombre maximal d'objectifs par tour.
+        limit: usize,
+    },
+}
+
+impl fmt::Display for SetupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetupError::Mirror(error) => write!(f, "{error}"),
+            SetupError::TooManyObjectives { count, limit } => write!(
+                f,
+                "{}",
+                tr!(Msg::TooManyObjectives, count = count, limit = limit)
+            ),
+        }
+    }
+}
+
+impl Error for SetupError {}
+
+impl From<MirrorError> for SetupError {
+    fn from(error: MirrorError) -> Self {
+        SetupError::Mirror(error)
+    }
+}
+
+/// Échange la vitesse et la force des deux joueurs, sans toucher à leur vitalité ni au reste de leurs
+/// caractéristiques.
+///
+/// # Erreurs
+///
+/// Retourne [`MirrorError::PlayerCount`] s'il n'y a pas exactement deux joueurs.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::player::Player;
+/// use dual_game::rules::mirror;
+///
+/// let alice = Player::new(String::from("Alice"), 50, 40, 70);
+/// let bob = Player::new(String::from("Bob"), 60, 90, 30);
+/// let mirrored = mirror(vec![alice, bob]).unwrap();
+/// assert_eq!((mirrored[0].speed, mirrored[0].strength), (90, 30));
+/// assert_eq!((mirrored[1].speed, mirrored[1].strength), (40, 70));
+/// ```
+pub fn mirror(mut players: Vec<Player>) -> Result<Vec<Player>, MirrorError> {
+    let [first, second] = players.as_mut_slice() else {
+        return Err(MirrorError::PlayerCount(players.len()));
+    };
+    std::mem::swap(&mut first.speed, &mut second.speed);
+    std::mem::swap(&mut first.strength, &mut second.strength);
+    Ok(players)
+}
+
+/// Retourne l'emplacement du fichier marquant que les règles ont déjà été proposées :
+/// `~/.dual_game/rules_offered`.
+pub fn first_run_marker() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    Some(Path::new(&home).join(".dual_game").join("rules_offered"))
+}
+
+/// Propose d'afficher les règles avant la première partie, si le fichier `marker` n'existe pas encore.
+///
+/// Le fichier est créé quelle que soit la réponse, afin que la question ne soit posée qu'une fois ;
+/// un échec de création est seulement journalisé.
+///
+/// # Arguments
+///
+/// * `console` - Les entrées/sorties utilisées pour dialoguer avec l'utilisateur.
+/// * `marker` - Le fichier marquant que les règles ont déjà été proposées.
+/// * `rules` - Les règles de la partie à venir.
+/// * `scoring` - Le barème de la partie à venir.
+///
+/// # Retour
+///
+/// Retourne `true` si les règles ont été affichées.
+pub fn offer(
+    console: &mut dyn Console,
+    marker: &Path,
+    rules: &GameRules,
+    scoring: &ScoringConfig,
+) -> bool {
+    if marker.exists() {
+        return false;
+    }
+    let default = if messages::lang() == Lang::En {
+        "y"
+    } else {
+        "o"
+    };
+    let show = ask(console, &tr!(Msg::RulesOffer), Some(default), parse_yes_no);
+    let written = match marker.parent() {
+        Some(parent) => fs::create_dir_all(parent).and_then(|()| fs::write(marker, "")),
+        None => fs::write(marker, ""),
+    };
+    if let Err(err) = written {
+        log::warn!("impossible de créer {} : {err}", marker.display());
+    }
+    if show == Some(true) {
+        console.println(&render(rules, scoring));
+        return true;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::ScriptedConsole;
+    use crate::scoring::ScoringTier;
+
+    /// Vérifie la pénalité de fatigue avant et après la 5e manche.
+    #[test]
+    fn test_fatigue_penalty() {
+        let rules = GameRules {
+            fatigue: true,
+            ..GameRules::default()
+        };
+        assert_eq!(rules.fatigue_penalty(5), 0);
+        assert_eq!(rules.fatigue_penalty(6), 2);
+        assert_eq!(rules.fatigue_penalty(20), 30);
+        assert_eq!(GameRules::default().fatigue_penalty(20), 0);
+    }
+
+    /// Vérifie le texte complet des règles standard, puis celui des règles optionnelles activées.
+    #[test]
+    fn test_render_snapshot() {
+        let expected = "\
+📜 Règles du jeu
+À son tour, chaque joueur arrête un compteur qui défile de 0 à 100 en visant au plus près chacun de ses objectifs. La vitesse est le délai en millisecondes entre deux incrémentations : plus elle est élevée, plus le compteur est lent.
+Score d'un objectif selon l'écart entre le compteur et l'objectif (le compteur reboucle de 100 à 0) :
+  • écart de 0 : 100 + force
+  • écart de 1 à 5 : 80 + force
+  • écart de 6 à 10 : 60 + force
+  • écart de 11 à 20 : 40 + force
+  • écart de 21 à 50 : 20 + force
+  • écart supérieur à 50 : force seule
+Chaque « miss » (passage du compteur par 100) divise le score de l'objectif : score / (miss + 1). Le score du tour est la moyenne de ses objectifs, arrondie au supérieur.
+Le perdant de la manche perd en vitalité l'écart entre les scores moyens, diminué de son armure (au moins 1 point). En cas d'égalité, personne ne perd de vitalité.
+S'il reste en vie, le perdant est ensuite empoisonné par le gagnant : 5 points de vitesse ou de force en moins. Il peut contrer le poison 1 fois par partie en subissant à nouveau les dégâts de la manche, sans armure.
+La partie se termine lorsqu'un joueur n'a plus de vitalité : son adversaire l'emporte.
+Fatigue : désactivée (--fatigue).
+Endurance : désactivée (--stamina).
+Pause : pendant le compteur, la touche p ouvre un menu pour reprendre, relire les règles ou abandonner ; le temps passé en pause n'est pas compté.
+Anti-stagnation : si les 5 dernières manches ont infligé moins de 10 points de dégâts en tout, chaque manche perdue coûte au moins 5 points de vitalité, jusqu'à une manche de 10 points de dégâts ou plus.";
+        assert_eq!(
+            render(&GameRules::default(), &ScoringConfig::default()),
+            expected
+        );
+
+        let rules = GameRules {
+            fatigue: true,
+            stamina: true,
+            mode: GameMode::Duel,
+            duel_factor: 5,
+            shop: false,
+            mirror: false,
+            risk_choice: false,
+            mutators: false,
+            bans: false,
+            retry: false,
+            time_tiebreak: false,
+            stalemate_rounds: 0,
+            max_objectives: 50,
+            delayed_poison: true,
+            winners_curse: true,
+        };
+        let scoring = ScoringConfig {
+            tiers: vec![ScoringTier {
+                max_difference: 3,
+                base: 90,
+            }],
+        };
+        let text = render(&rules, &scoring);
+        assert!(
+            text.contains("  • écart de 0 à 3 : 90 + force\n  • écart supérieur à 3 : force seule")
+        );
+        assert!(text.contains("après la manche 5, chaque manche retire 2 points"));
+        assert!(text.contains(
+            "3 points par partie ; la touche s en dépense un pour ralentir le compteur de 50 %"
+        ));
+        assert!(text.contains("perd 5 points de vitalité par point d'écart.\n"));
+        assert!(text.contains("Il est perdu si la partie se termine avant.\n"));
+        assert!(text.ends_with(
+            "plus de 60 points d'écart perd 3 points de vitesse (compteur plus rapide) pendant la \
+             manche suivante."
+        ));
+
+        let shared = GameRules {
+            mode: GameMode::Shared,
+            shop: true,
+            ..GameRules::default()
+        };
+        let text = render(&shared, &ScoringConfig::default());
+        assert!(text.contains("sans verrou après 3 miss, l'écart est maximal.\nBoutique : "));
+        assert!(text.ends_with("chaque joueur peut acheter un objet."));
+    }
+
+    /// Vérifie que le mode miroir échange la vitesse et la force de deux joueurs, et seulement elles,
+    /// et qu'il refuse un autre nombre de joueurs.
+    #[test]
+    fn test_mirror() {
+        let alice = Player::builder("Alice")
+            .vitality(30)
+            .speed(40)
+            .strength(70)
+            .armor(2)
+            .build()
+            .unwrap();
+        let bob = Player::new(String::from("Bob"), 80, 90, 30);
+        let mirrored = mirror(vec![alice.clone(), bob.clone()]).unwrap();
+        assert_eq!(
+            (
+                mirrored[0].speed,
+                mirrored[0].strength,
+                mirrored[0].vitality()
+            ),
+            (90, 30, 30)
+        );
+        assert_eq!(mirrored[0].armor, 2);
+        assert_eq!(
+            (
+                mirrored[1].speed,
+                mirrored[1].strength,
+                mirrored[1].vitality()
+            ),
+            (40, 70, 80)
+        );
+        let rules = GameRules {
+            mirror: true,
+            ..GameRules::default()
+        };
+        let text = render(&rules, &ScoringConfig::default());
+        assert!(text.ends_with("\nMiroir : avant la première manche, les joueurs échangent leur vitesse et leur force ; la vitalité ne change pas."));
+        let twice = mirror(mirrored).unwrap();
+        assert_eq!((twice[0].speed, twice[0].strength), (40, 70));
+
+        assert_eq!(
+            mirror(vec![alice.clone()]).unwrap_err(),
+            MirrorError::PlayerCount(1)
+        );
+        assert_eq!(
+            mirror(vec![alice, bob.clone(), bob])
+                .unwrap_err()
+                .to_string(),
+            "le mode miroir se joue à 2 joueurs (3 joueur(s) donné(s))"
+        );
+        assert_eq!(mirror(Vec::new()).unwrap_err(), MirrorError::PlayerCount(0));
+    }
+
+    /// Vérifie que les règles ne sont proposées qu'une fois, le fichier témoin étant créé.
+    #[test]
+    fn test_offer_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("dual_game").join("rules_offered");
+        let (rules, scoring) = (GameRules::default(), ScoringConfig::default());
+
+        let mut console = ScriptedConsole::new([""]);
+        assert!(offer(&mut console, &marker, &rules, &scoring));
+        assert!(console.output.contains("📜 Règles du jeu"));
+        assert!(marker.exists());
+
+        let mut console = ScriptedConsole::new(["o"]);
+        assert!(!offer(&mut console, &marker, &rules, &scoring));
+        assert!(console.output.is_empty());
+
+        let marker = dir.path().join("rules_declined");
+        let mut console = ScriptedConsole::new(["n"]);
+        assert!(!offer(&mut console, &marker, &rules, &scoring));
+        assert!(marker.exists());
+    }
+
+    /// Vérifie la recherche des préréglages par nom, insensible à la casse, et l'erreur d'un nom
+    /// inconnu listant les préréglages disponibles.
+    #[test]
+    fn test_presets() {
+        assert_eq!(GameRules::preset("classic"), Ok(GameRules::default()));
+        let blitz = Preset::find(" Blitz ").unwrap();
+        assert_eq!((blitz.objectives, blitz.speed), (Some(3), Some(50)));
+        assert!(blitz.rules.fatigue);
+        assert!(GameRules::preset("marathon").unwrap().stamina);
+        assert_eq!(
+            GameRules::preset("hardcore").unwrap_err().to_string(),
+            "préréglage inconnu « hardcore » (valeurs possibles : classic, blitz, marathon, party)"
+        );
+        assert_eq!(list_presets().lines().count(), PRESETS.len() + 1);
+    }
+}
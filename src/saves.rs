@@ -0,0 +1,513 @@
+//! Module des sauvegardes de partie.
+//!
+//! Une [`SaveState`] retient l'état d'une partie entre deux manches : les joueurs et leurs
+//! caractéristiques courantes, la prochaine manche, les règles et les scores cumulés. Elle est
+//! enregistrée dans un emplacement nommé du répertoire des sauvegardes ([`SaveStore`], par défaut
+//! `~/.dual_game/saves`), par exemple à la fin de chaque manche avec `play --autosave <emplacement>`,
+//! puis reprise avec `play --load-slot <emplacement>`.
+//!
+//! Chaque sauvegarde porte la version de son format ([`SAVE_VERSION`]) : une sauvegarde écrite par une
+//! version plus récente du jeu est refusée plutôt que mal interprétée. L'historique des manches déjà
+//! jouées n'est pas sauvegardé : les statistiques d'une partie reprise ne portent que sur les manches
+//! jouées depuis la reprise. La sauvegarde retient aussi la version de l'application qui l'a
+//! écrite, le barème des scores et la graine des objectifs.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::class::PlayerClass;
+use crate::game_log::timestamp;
+use crate::messages::Msg;
+use crate::player::Player;
+use crate::poison::PoisonType;
+use crate::predictions::write_atomic;
+use crate::rules::GameRules;
+use crate::scoring::ScoringConfig;
+use crate::style::Color;
+use crate::tr;
+
+/// Version du format des sauvegardes.
+pub const SAVE_VERSION: u32 = 1;
+
+/// Erreur survenue lors de l'accès aux sauvegardes.
+#[derive(Debug)]
+pub enum SaveError {
+    /// Le nom d'emplacement contient des caractères non autorisés.
+    InvalidName(String),
+    /// Le répertoire personnel de l'utilisateur est introuvable.
+    NoHomeDir,
+    /// Aucune sauvegarde n'occupe l'emplacement demandé.
+    NotFound(String),
+    /// La sauvegarde a été écrite par une version plus récente du jeu.
+    NewerVersion {
+        /// Nom de l'emplacement.
+        slot: String,
+        /// Version du format de la sauvegarde.
+        version: u32,
+    },
+    /// Erreur d'entrée/sortie sur le fichier de sauvegarde.
+    Io {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: io::Error,
+    },
+    /// Le fichier de sauvegarde n'est pas un JSON valide.
+    Corrupt {
+        /// Chemin du fichier concerné.
+        path: PathBuf,
+        /// Erreur d'origine.
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::InvalidName(name) => write!(
+                f,
+                "nom d'emplacement invalide « {name} » : seuls les lettres, chiffres, '-' et '_' sont autorisés"
+            ),
+            SaveError::NoHomeDir => write!(f, "répertoire personnel introuvable"),
+            SaveError::NotFound(slot) => write!(
+                f,
+                "aucune sauvegarde dans l'emplacement « {slot} » (voir `dual_game saves list`)"
+            ),
+            SaveError::NewerVersion { slot, version } => write!(
+                f,
+                "la sauvegarde « {slot} » utilise le format {version}, plus récent que celui de \
+                 cette version du jeu ({SAVE_VERSION}) : mettez le jeu à jour pour la reprendre"
+            ),
+            SaveError::Io { path, source } => {
+                write!(
+                    f,
+                    "erreur d'accès à la sauvegarde {} : {source}",
+                    path.display()
+                )
+            }
+            SaveError::Corrupt { path, source } => {
+                write!(f, "sauvegarde corrompue {} : {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for SaveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SaveError::Io { source, .. } => Some(source),
+            SaveError::Corrupt { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Joueur sauvegardé, avec ses caractéristiques courantes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedPlayer {
+    /// Nom du joueur.
+    pub name: String,
+    /// Vitalité du joueur.
+    pub vitality: u32,
+    /// Vitalité maximale du joueur.
+    pub max_vitality: u32,
+    /// Vitesse du joueur.
+    pub speed: u32,
+    /// Force du joueur.
+    pub strength: u32,
+    /// Contre-propositions au poison encore disponibles.
+    pub counters_remaining: u32,
+    /// Classe de personnage, le cas échéant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<PlayerClass>,
+    /// Armure du joueur.
+    pub armor: u32,
+    /// Couleur du nom du joueur, le cas échéant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    /// Chance du joueur.
+    pub luck: u32,
+    /// Endurance restante.
+    pub stamina: u32,
+    /// Avatar du joueur, le cas échéant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    /// Pièces du joueur.
+    pub coins: u32,
+    /// Poisons subis, du plus ancien au plus récent.
+    pub poisons: Vec<PoisonType>,
+    /// Ralentissement du compteur près de l'objectif, le cas échéant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assist: Option<u32>,
+    /// Rejeux d'objectif en réserve.
+    #[serde(default)]
+    pub retries: u32,
+}
+
+impl From<&Player> for SavedPlayer {
+    fn from(player: &Player) -> Self {
+        SavedPlayer {
+            name: player.name.clone(),
+            vitality: player.vitality,
+            max_vitality: player.max_vitality,
+            speed: player.speed,
+            strength: player.strength,
+            counters_remaining: player.counters_remaining,
+            class: player.class,
+            armor: player.armor,
+            color: player.color,
+            luck: player.luck,
+            stamina: player.stamina,
+            avatar: player.avatar.clone(),
+            coins: player.coins,
+            poisons: player.poisons.clone(),
+            assist: player.assist,
+            retries: player.retries,
+        }
+    }
+}
+
+impl SavedPlayer {
+    /// Recrée le joueur tel qu'il a été sauvegardé.
+    pub fn to_player(&self) -> Player {
+        let mut player = Player::new(self.name.clone(), self.vitality, self.speed, self.strength);
+        player.max_vitality = self.max_vitality;
+        player.counters_remaining = self.counters_remaining;
+        player.class = self.class;
+        player.armor = self.armor;
+        player.color = self.color;
+        player.luck = self.luck;
+        player.stamina = self.stamina;
+        player.avatar = self.avatar.clone();
+        player.coins = self.coins;
+        player.poisons = self.poisons.clone();
+        player.assist = self.assist;
+        player.retries = self.retries;
+        player
+    }
+
+    /// Recrée le joueur pour une nouvelle partie : sa vitalité est pleine, ses poisons sont annulés et
+    /// ses pièces, son endurance et ses rejeux sont remis à zéro.
+    pub fn to_fresh_player(&self) -> Player {
+        let mut player = self.to_player();
+        while player.cure_poison().is_some() {}
+        player.vitality = player.max_vitality;
+        player.coins = 0;
+        player.stamina = 0;
+        player.retries = 0;
+        player
+    }
+}
+
+/// État d'une partie sauvegardé entre deux manches.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SaveState {
+    /// Version du format de la sauvegarde.
+    pub version: u32,
+    /// Instant de la sauvegarde, en secondes depuis l'époque Unix.
+    pub saved_at: u64,
+    /// Prochaine manche à jouer.
+    pub round: u32,
+    /// Nombre d'objectifs par tour.
+    pub objectifs: usize,
+    /// Règles de la partie.
+    pub rules: GameRules,
+    /// Joueurs, dans l'ordre de la partie.
+    pub players: Vec<SavedPlayer>,
+    /// Somme des scores moyens de chaque joueur.
+    pub total_scores: Vec<u32>,
+    /// Nombre de manches nulles consécutives avant la prochaine manche.
+    pub consecutive_draws: u32,
+    /// Version de l'application qui a écrit la sauvegarde, vide pour les anciennes sauvegardes.
+    #[serde(default)]
+    pub crate_version: String,
+    /// Barème des scores de la partie.
+    #[serde(default)]
+    pub scoring: ScoringConfig,
+    /// Graine des objectifs, si la partie a été jouée avec `--seed`.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Poison différé en attente, avec l'index de sa victime, appliqué au début de la prochaine
+    /// manche (voir [`GameRules::delayed_poison`]).
+    #[serde(default)]
+    pub pending_poison: Option<(usize, PoisonType)>,
+    /// Index du joueur frappé par la malédiction du vainqueur pendant la prochaine manche (voir
+    /// [`GameRules::winners_curse`]).
+    #[serde(default)]
+    pub winners_curse: Option<usize>,
+}
+
+/// En-tête d'une sauvegarde, lu avant le reste afin de refuser un format plus récent.
+#[derive(Deserialize)]
+struct SaveHeader {
+    /// Version du format de la sauvegarde.
+    version: u32,
+}
+
+/// Retourne l'instant présent, en secondes depuis l'époque Unix.
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Répertoire contenant les emplacements de sauvegarde.
+#[derive(Clone, Debug)]
+pub struct SaveStore {
+    dir: PathBuf,
+}
+
+impl SaveStore {
+    /// Crée un gestionnaire de sauvegardes stockées dans le répertoire donné.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        SaveStore { dir: dir.into() }
+    }
+
+    /// Crée un gestionnaire de sauvegardes stockées dans `~/.dual_game/saves`.
+    pub fn default_location() -> Result<Self, SaveError> {
+        let home = std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .ok_or(SaveError::NoHomeDir)?;
+        Ok(SaveStore::new(
+            Path::new(&home).join(".dual_game").join("saves"),
+        ))
+    }
+
+    /// Retourne le chemin du fichier associé à un emplacement.
+    pub fn path_for(&self, slot: &str) -> Result<PathBuf, SaveError> {
+        let valid = !slot.is_empty()
+            && slot
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(SaveError::InvalidName(slot.to_string()));
+        }
+        Ok(self.dir.join(format!("{slot}.json")))
+    }
+
+    /// Enregistre une sauvegarde dans un emplacement, en remplaçant atomiquement la précédente et en
+    /// créant le répertoire si nécessaire.
+    pub fn save(&self, slot: &str, state: &SaveState) -> Result<(), SaveError> {
+        let path = self.path_for(slot)?;
+        let io_err = |source| SaveError::Io {
+            path: path.clone(),
+            source,
+        };
+        fs::create_dir_all(&self.dir).map_err(io_err)?;
+        let content =
+            serde_json::to_string_pretty(state).expect("une sauvegarde est toujours sérialisable");
+        write_atomic(&path, &content).map_err(io_err)
+    }
+
+    /// Charge la sauvegarde d'un emplacement.
+    ///
+    /// # Retour
+    ///
+    /// Retourne [`SaveError::NotFound`] si l'emplacement est vide, et [`SaveError::NewerVersion`] si
+    /// la sauvegarde a été écrite par une version plus récente du jeu.
+    pub fn load(&self, slot: &str) -> Result<SaveState, SaveError> {
+        let path = self.path_for(slot)?;
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(SaveError::NotFound(slot.to_string()));
+            }
+            Err(source) => return Err(SaveError::Io { path, source }),
+        };
+        let corrupt = |source| SaveError::Corrupt {
+            path: path.clone(),
+            source,
+        };
+        let header: SaveHeader = serde_json::from_str(&content).map_err(corrupt)?;
+        if header.version > SAVE_VERSION {
+            return Err(SaveError::NewerVersion {
+                slot: slot.to_string(),
+                version: header.version,
+            });
+        }
+        serde_json::from_str(&content).map_err(corrupt)
+    }
+
+    /// Charge toutes les sauvegardes, triées par nom d'emplacement.
+    ///
+    /// # Retour
+    ///
+    /// Retourne une liste vide si le répertoire des sauvegardes n'existe pas encore. Les fichiers
+    /// illisibles ou d'un format plus récent sont ignorés.
+    pub fn list(&self) -> Result<Vec<(String, SaveState)>, SaveError> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => {
+                return Err(SaveError::Io {
+                    path: self.dir.clone(),
+                    source,
+                });
+            }
+        };
+        let mut saves = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let Some(slot) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            match self.load(slot) {
+                Ok(state) => saves.push((slot.to_string(), state)),
+                Err(err) => log::warn!("sauvegarde ignorée : {err}"),
+            }
+        }
+        saves.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(saves)
+    }
+}
+
+/// Formate la liste des emplacements : pour chacun, la prochaine manche, les joueurs et l'instant
+/// de la sauvegarde (UTC).
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::saves::list_slots;
+///
+/// assert_eq!(list_slots(&[]), "Aucune sauvegarde.");
+/// ```
+pub fn list_slots(saves: &[(String, SaveState)]) -> String {
+    if saves.is_empty() {
+        return tr!(Msg::SavesEmpty);
+    }
+    let lines: Vec<String> = saves
+        .iter()
+        .map(|(slot, state)| {
+            let names: Vec<&str> = state.players.iter().map(|p| p.name.as_str()).collect();
+            tr!(
+                Msg::SaveSlot,
+                slot = slot,
+                round = state.round,
+                players = names.join(", "),
+                time = timestamp(UNIX_EPOCH + Duration::from_secs(state.saved_at))
+            )
+        })
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::controller::{ScriptedController, StopDecision};
+    use crate::game::Game;
+    use crate::output::Verbosity;
+
+    /// Sauvegarde d'une partie à sa deuxième manche, sauvegardée à l'instant donné.
+    fn state(names: [&str; 2], saved_at: u64) -> SaveState {
+        let players = names
+            .into_iter()
+            .map(|name| Player::new(String::from(name), 50, 75, 50))
+            .collect();
+        let mut state = Game::new(players, 3).save_state();
+        state.round = 2;
+        state.saved_at = saved_at;
+        state
+    }
+
+    /// Vérifie que la partie est sauvegardée à la fin de chaque manche scriptée, et qu'une partie
+    /// recréée depuis la sauvegarde reprend ses joueurs, sa manche et ses scores.
+    #[test]
+    fn test_autosave_each_round() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SaveStore::new(dir.path());
+        let players = vec![
+            Player::new(String::from("Alice"), 1000, 75, 50),
+            Player::new(String::from("Bob"), 1000, 75, 50),
+        ];
+        let mut game = Game::new(players, 1);
+        game.set_verbosity(Verbosity::Silent);
+        game.set_clock(Box::new(ManualClock::new()));
+        game.set_autosave(store.clone(), "slot1");
+        let alice = ScriptedController::new();
+        let bob = ScriptedController::new();
+        game.set_controller(0, Box::new(alice.clone()));
+        game.set_controller(1, Box::new(bob.clone()));
+        game.start();
+
+        for round in 2..=3 {
+            alice.push_stop(StopDecision::from_offset(50, 0));
+            bob.push_stop(StopDecision::from_offset(50, 303));
+            alice.push_poison(0);
+            assert_eq!(game.play_round().unwrap(), None);
+            let saved = store.load("slot1").unwrap();
+            assert_eq!(saved.version, SAVE_VERSION);
+            assert_eq!(saved.crate_version, env!("CARGO_PKG_VERSION"));
+            assert_eq!(saved.scoring, ScoringConfig::default());
+            assert_eq!(saved.round, round);
+            assert_eq!(saved.players[1].vitality, game.players[1].vitality());
+            assert_eq!(saved.total_scores, game.total_scores);
+        }
+        assert_eq!(
+            game.players[1].poisons,
+            [PoisonType::Speed, PoisonType::Speed]
+        );
+
+        let resumed = Game::from_save(&store.load("slot1").unwrap());
+        assert_eq!(resumed.round, 3);
+        assert_eq!(resumed.total_scores, game.total_scores);
+        let saved: Vec<SavedPlayer> = resumed.players.iter().map(SavedPlayer::from).collect();
+        let played: Vec<SavedPlayer> = game.players.iter().map(SavedPlayer::from).collect();
+        assert_eq!(saved, played);
+        let fresh = saved[1].to_fresh_player();
+        assert_eq!((fresh.vitality(), fresh.speed), (1000, 75));
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    /// Vérifie qu'une sauvegarde d'un format plus récent est refusée avec un message explicite, et
+    /// qu'un emplacement vide est signalé.
+    #[test]
+    fn test_newer_version_refused() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SaveStore::new(dir.path());
+        let mut newer = state(["Alice", "Bob"], 0);
+        newer.version = SAVE_VERSION + 1;
+        store.save("futur", &newer).unwrap();
+        let err = store.load("futur").unwrap_err();
+        assert!(matches!(err, SaveError::NewerVersion { version, .. } if version == 2));
+        assert!(err.to_string().contains("mettez le jeu à jour"), "{err}");
+        assert!(matches!(store.load("absent"), Err(SaveError::NotFound(_))));
+        assert!(matches!(store.load("../x"), Err(SaveError::InvalidName(_))));
+        assert!(store.list().unwrap().is_empty());
+    }
+
+    /// Vérifie la liste des emplacements : triés par nom, avec manche, joueurs et horodatage UTC.
+    #[test]
+    fn test_list_formatting() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SaveStore::new(dir.path());
+        store
+            .save("slot2", &state(["Carol", "Dave"], 1_700_000_000))
+            .unwrap();
+        let mut first = state(["Alice", "Bob"], 1_600_000_000);
+        first.round = 7;
+        store.save("slot1", &first).unwrap();
+        assert_eq!(
+            list_slots(&store.list().unwrap()),
+            "slot1 — manche 7 — Alice, Bob — 2020-09-13T12:26:40.000Z\n\
+             slot2 — manche 2 — Carol, Dave — 2023-11-14T22:13:20.000Z"
+        );
+        assert!(
+            SaveStore::new(dir.path().join("absent"))
+                .list()
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -2,7 +2,156 @@
 //!
 //! Ce module fournit la structure [`ScoringCalculator`] qui propose des fonctions pour calculer
 //! le score en fonction de la différence entre un objectif et une valeur de compteur, du nombre de "miss"
-//! et de la force du joueur.
+//! et de la force du joueur. Le barème des scores de base est décrit par un [`ScoringConfig`], dont
+//! une [`ScoringTable`] précalcule le score de base de chaque écart pour les calculs répétés, par
+//! exemple ceux de [`ScoringCalculator::score_batch`].
+
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+/// Nombre d'écarts couverts par une [`ScoringTable`], de 0 à 100 inclus.
+const TABLE_LEN: usize = 101;
+
+/// Palier du barème : score de base accordé jusqu'à un écart donné entre le compteur et l'objectif.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoringTier {
+    /// Écart maximal (inclus) couvert par le palier.
+    pub max_difference: u32,
+    /// Score de base du palier, auquel s'ajoute la force du joueur.
+    pub base: u32,
+}
+
+/// Barème des scores de base, par paliers d'écart croissant.
+///
+/// Au-delà du dernier palier, le score de base est nul : seule la force du joueur compte.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoringConfig {
+    /// Paliers, triés par écart maximal croissant.
+    pub tiers: Vec<ScoringTier>,
+}
+
+impl Default for ScoringConfig {
+    /// Barème standard du jeu.
+    fn default() -> Self {
+        let tier = |max_difference, base| ScoringTier {
+            max_difference,
+            base,
+        };
+        ScoringConfig {
+            tiers: vec![
+                tier(0, 100),
+                tier(5, 80),
+                tier(10, 60),
+                tier(20, 40),
+                tier(50, 20),
+            ],
+        }
+    }
+}
+
+impl ScoringConfig {
+    /// Retourne le score de base correspondant à un écart entre le compteur et l'objectif.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::scoring::ScoringConfig;
+    ///
+    /// let scoring = ScoringConfig::default();
+    /// assert_eq!(scoring.base(7), 60);
+    /// assert_eq!(scoring.base(51), 0);
+    /// ```
+    pub fn base(&self, difference: u32) -> u32 {
+        self.tiers
+            .iter()
+            .find(|tier| difference <= tier.max_difference)
+            .map_or(0, |tier| tier.base)
+    }
+
+    /// Calcule le score d'un objectif selon ce barème : `(base + force) / (miss + 1)`.
+    ///
+    /// Les additions sont saturées : une force ou un nombre de miss démesurés ne débordent pas.
+    ///
+    /// # Arguments
+    ///
+    /// * `objective` - La valeur cible.
+    /// * `counter_value` - La valeur atteinte par le compteur.
+    /// * `miss` - Le nombre de fois où le compteur s'est réinitialisé (ou "miss").
+    /// * `strength` - La force du joueur.
+    pub fn score(&self, objective: u32, counter_value: u32, miss: u32, strength: u32) -> u32 {
+        let diff = ScoringCalculator::difference(objective, counter_value);
+        self.base(diff).saturating_add(strength) / miss.saturating_add(1)
+    }
+
+    /// Précalcule le score de base de chaque écart selon ce barème.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::scoring::ScoringConfig;
+    ///
+    /// let scoring = ScoringConfig::default();
+    /// let table = scoring.table();
+    /// assert_eq!(table.base(7), scoring.base(7));
+    /// assert_eq!(table.score(40, 95, 1, 50), scoring.score(40, 95, 1, 50));
+    /// ```
+    pub fn table(&self) -> ScoringTable {
+        let mut bases = [0; TABLE_LEN];
+        for (diff, base) in bases.iter_mut().enumerate() {
+            *base = self.base(diff as u32);
+        }
+        ScoringTable { bases }
+    }
+}
+
+/// Score de base de chaque écart entre le compteur et l'objectif, précalculé à partir d'un
+/// [`ScoringConfig`] (voir [`ScoringConfig::table`]).
+///
+/// La table couvre les écarts de 0 à 100, les seuls possibles entre des valeurs du compteur ; un écart
+/// supérieur reçoit le score de base d'un écart de 100.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScoringTable {
+    /// Score de base de chaque écart, indexé par l'écart.
+    bases: [u32; TABLE_LEN],
+}
+
+impl ScoringTable {
+    /// Retourne le score de base correspondant à un écart entre le compteur et l'objectif.
+    pub fn base(&self, difference: u32) -> u32 {
+        self.bases[(difference as usize).min(TABLE_LEN - 1)]
+    }
+
+    /// Calcule le score d'un objectif selon cette table : `(base + force) / (miss + 1)`.
+    pub fn score(&self, objective: u32, counter_value: u32, miss: u32, strength: u32) -> u32 {
+        let diff = ScoringCalculator::difference(objective, counter_value);
+        self.base(diff).saturating_add(strength) / miss.saturating_add(1)
+    }
+}
+
+/// Score qu'obtiendrait un joueur en arrêtant le compteur sur sa valeur courante, affiché pendant le
+/// défilement du compteur (voir [`ScoringCalculator::preview`]).
+///
+/// L'aperçu retient l'objectif, la force du joueur et la table du barème standard : chaque valeur du
+/// compteur se traduit en score par une lecture de la table, sans allocation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScorePreview {
+    /// Table du barème.
+    table: &'static ScoringTable,
+    /// Objectif visé.
+    objective: u32,
+    /// Force du joueur.
+    strength: u32,
+}
+
+impl ScorePreview {
+    /// Retourne le score obtenu en arrêtant le compteur sur `counter_value` après `miss` tours
+    /// complets.
+    pub fn score(&self, counter_value: u32, miss: u32) -> u32 {
+        self.table
+            .score(self.objective, counter_value, miss, self.strength)
+    }
+}
 
 /// Structure pour le calcul du score.
 pub struct ScoringCalculator;
@@ -19,6 +168,8 @@ impl ScoringCalculator {
     /// - Différence 21 à 50      : (20 + force) / (miss+1)
     /// - Différence > 50         : (0 + force) / (miss+1)
     ///
+    /// Ces paliers sont ceux du barème standard ([`ScoringConfig::default`]).
+    ///
     /// # Arguments
     ///
     /// * `objective` - La valeur cible.
@@ -30,35 +181,134 @@ impl ScoringCalculator {
     ///
     /// Retourne le score calculé sous forme de `u32`.
     pub fn calculate_score(objective: u32, counter_value: u32, miss: u32, strength: u32) -> u32 {
-        let diff = Self::difference(objective, counter_value);
-        let base = if diff == 0 {
-            100
-        } else if diff <= 5 {
-            80
-        } else if diff <= 10 {
-            60
-        } else if diff <= 20 {
-            40
-        } else if diff <= 50 {
-            20
-        } else {
-            0
-        };
-        (base + strength) / (miss + 1)
+        let table = Self::standard_table();
+        let score = table.score(objective, counter_value, miss, strength);
+        if log::log_enabled!(log::Level::Trace) {
+            let diff = Self::difference(objective, counter_value);
+            log::trace!(
+                "palier du barème objective={objective} counter={counter_value} diff={diff} \
+                 base={} strength={strength} divisor={} score={score}",
+                table.base(diff),
+                miss.saturating_add(1)
+            );
+        }
+        debug_assert!(
+            miss == 0 || score <= table.score(objective, counter_value, miss - 1, strength),
+            "le score augmente avec le nombre de miss"
+        );
+        score
+    }
+
+    /// Calcule le score de chaque objectif d'une série, comme autant d'appels à
+    /// [`ScoringCalculator::calculate_score`] pour un même joueur.
+    ///
+    /// Les scores remplacent le contenu de `out`, dont la capacité est réutilisée d'un appel à l'autre.
+    ///
+    /// # Arguments
+    ///
+    /// * `objectives` - Les valeurs cibles.
+    /// * `stops` - Les valeurs atteintes par le compteur, dans l'ordre des objectifs.
+    /// * `misses` - Les nombres de « miss » de chaque objectif.
+    /// * `strength` - La force du joueur.
+    /// * `out` - Les scores calculés, dans l'ordre des objectifs.
+    ///
+    /// # Panics
+    ///
+    /// Panique si les trois séries n'ont pas la même longueur.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::scoring::ScoringCalculator;
+    ///
+    /// let mut scores = Vec::new();
+    /// ScoringCalculator::score_batch(&[50, 15], &[50, 95], &[0, 1], 50, &mut scores);
+    /// assert_eq!(scores, [150, 45]);
+    /// ```
+    pub fn score_batch(
+        objectives: &[u32],
+        stops: &[u32],
+        misses: &[u32],
+        strength: u32,
+        out: &mut Vec<u32>,
+    ) {
+        assert!(
+            objectives.len() == stops.len() && stops.len() == misses.len(),
+            "les objectifs, les arrêts et les miss doivent être en même nombre"
+        );
+        let table = Self::standard_table();
+        out.clear();
+        out.extend(
+            objectives
+                .iter()
+                .zip(stops)
+                .zip(misses)
+                .map(|((&objective, &stop), &miss)| table.score(objective, stop, miss, strength)),
+        );
+    }
+
+    /// Retourne l'aperçu du score d'un objectif selon le barème standard, pour un joueur de force
+    /// donnée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::scoring::ScoringCalculator;
+    ///
+    /// let preview = ScoringCalculator::preview(50, 30);
+    /// assert_eq!(preview.score(50, 0), 130);
+    /// assert_eq!(preview.score(48, 1), 55);
+    /// ```
+    pub fn preview(objective: u32, strength: u32) -> ScorePreview {
+        ScorePreview {
+            table: Self::standard_table(),
+            objective,
+            strength,
+        }
+    }
+
+    /// Retourne la table du barème standard, calculée au premier appel.
+    fn standard_table() -> &'static ScoringTable {
+        static TABLE: OnceLock<ScoringTable> = OnceLock::new();
+        TABLE.get_or_init(|| {
+            let table = ScoringConfig::default().table();
+            debug_assert!(
+                table.bases.windows(2).all(|pair| pair[0] >= pair[1]),
+                "le score de base augmente avec l'écart"
+            );
+            table
+        })
     }
 
     /// Calcule la moyenne arrondie à l’entier supérieur d'une liste de scores.
     ///
+    /// Le calcul se fait en entiers, sans passer par `f64` : la somme est accumulée sur 64 bits, avec
+    /// vérification du débordement, puis divisée en arrondissant à l'entier supérieur. Elle ne déborde
+    /// pas en pratique, même pour des milliers d'objectifs aux scores démesurés.
+    ///
     /// # Arguments
     ///
     /// * `scores` - Un slice de scores (`u32`) à moyenner.
     ///
     /// # Retour
     ///
-    /// Retourne la moyenne arrondie à l'entier supérieur.
+    /// Retourne la moyenne arrondie à l'entier supérieur, comprise entre le plus petit et le plus grand
+    /// score, ou 0 pour une liste vide.
     pub fn calculate_average(scores: &[u32]) -> u32 {
-        let sum: u32 = scores.iter().sum();
-        (sum as f64 / scores.len() as f64).ceil() as u32
+        if scores.is_empty() {
+            return 0;
+        }
+        let sum = scores
+            .iter()
+            .try_fold(0u64, |sum, &score| sum.checked_add(u64::from(score)))
+            .expect("la somme des scores déborde 64 bits");
+        // La moyenne ne dépasse pas le plus grand score : elle tient sur 32 bits.
+        let average = sum.div_ceil(scores.len() as u64) as u32;
+        debug_assert!(
+            scores.iter().min() <= Some(&average) && scores.iter().max() >= Some(&average),
+            "la moyenne sort de l'intervalle des scores"
+        );
+        average
     }
 
     /// Calcule la différence entre l'objectif et la valeur du compteur en tenant compte du wrap-around entre 0 et 100.
@@ -75,17 +325,49 @@ impl ScoringCalculator {
     ///
     /// Retourne la différence minimale en tenant compte du wrap-around.
     pub fn difference(objective: u32, counter_value: u32) -> u32 {
-        let diff = if counter_value >= objective {
-            counter_value - objective
-        } else {
-            objective - counter_value
-        };
+        let diff = counter_value.abs_diff(objective);
         let wrap_diff = if counter_value > objective {
             objective + (100 - counter_value)
         } else {
             counter_value + (100 - objective)
         };
-        diff.min(wrap_diff)
+        let difference = diff.min(wrap_diff);
+        debug_assert!(
+            difference <= 50,
+            "l'écart dépasse la moitié du cycle du compteur"
+        );
+        difference
+    }
+
+    /// Calcule l'écart signé entre l'objectif et la valeur du compteur : négatif si le compteur a été
+    /// arrêté avant l'objectif, positif s'il l'a dépassé.
+    ///
+    /// Sa valeur absolue est celle de [`ScoringCalculator::difference`] et son sens suit le plus court
+    /// chemin entre les deux valeurs, wrap-around compris : pour un objectif de 15, un compteur arrêté
+    /// à 95 l'est 20 unités trop tôt. À mi-cycle, lorsque les deux chemins ont la même longueur, le
+    /// sens direct l'emporte.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::scoring::ScoringCalculator;
+    ///
+    /// assert_eq!(ScoringCalculator::signed_difference(15, 95), -20);
+    /// assert_eq!(ScoringCalculator::signed_difference(95, 5), 10);
+    /// assert_eq!(ScoringCalculator::signed_difference(40, 35), -5);
+    /// ```
+    pub fn signed_difference(objective: u32, counter_value: u32) -> i32 {
+        let direct = counter_value as i32 - objective as i32;
+        let difference = Self::difference(objective, counter_value) as i32;
+        if direct.abs() == difference {
+            direct
+        } else if direct > 0 {
+            // Le chemin le plus court passe par 100 : le compteur n'avait pas encore atteint
+            // l'objectif.
+            -difference
+        } else {
+            difference
+        }
     }
 }
 
@@ -99,6 +381,124 @@ mod tests {
         assert_eq!(ScoringCalculator::difference(50, 50), 0);
     }
 
+    /// Vérifie le sens de l'écart signé de part et d'autre de l'objectif, autour du passage par 100
+    /// et à mi-cycle.
+    #[test]
+    fn test_signed_difference() {
+        assert_eq!(ScoringCalculator::signed_difference(50, 50), 0);
+        assert_eq!(ScoringCalculator::signed_difference(50, 49), -1);
+        assert_eq!(ScoringCalculator::signed_difference(50, 51), 1);
+        // Passage par 100, dans les deux sens.
+        assert_eq!(ScoringCalculator::signed_difference(2, 98), -4);
+        assert_eq!(ScoringCalculator::signed_difference(98, 2), 4);
+        assert_eq!(ScoringCalculator::signed_difference(0, 100), 0);
+        assert_eq!(ScoringCalculator::signed_difference(100, 0), 0);
+        assert_eq!(ScoringCalculator::signed_difference(100, 1), 1);
+        assert_eq!(ScoringCalculator::signed_difference(0, 99), -1);
+        // À mi-cycle, le sens direct l'emporte.
+        assert_eq!(ScoringCalculator::signed_difference(0, 50), 50);
+        assert_eq!(ScoringCalculator::signed_difference(50, 0), -50);
+        assert_eq!(ScoringCalculator::signed_difference(30, 80), 50);
+        assert_eq!(ScoringCalculator::signed_difference(30, 81), -49);
+        for objective in 0..=100 {
+            for counter in 0..=100 {
+                let signed = ScoringCalculator::signed_difference(objective, counter);
+                assert_eq!(
+                    signed.unsigned_abs(),
+                    ScoringCalculator::difference(objective, counter)
+                );
+            }
+        }
+    }
+
+    /// Vérifie les bornes de chaque palier du barème standard.
+    #[test]
+    fn test_calculate_score_tiers() {
+        let cases = [
+            (0, 150),
+            (5, 130),
+            (6, 110),
+            (10, 110),
+            (20, 90),
+            (21, 70),
+            (50, 70),
+        ];
+        for (diff, expected) in cases {
+            assert_eq!(
+                ScoringCalculator::calculate_score(50, 50 + diff, 0, 50),
+                expected,
+                "écart {diff}"
+            );
+        }
+        assert_eq!(ScoringCalculator::calculate_score(40, 40, 2, 50), 50);
+    }
+
+    /// Vérifie que l'aperçu donne, pour chaque valeur du compteur, le score de
+    /// [`ScoringCalculator::calculate_score`].
+    #[test]
+    fn test_preview_matches_score() {
+        for (objective, strength) in [(0, 50), (50, 30), (97, 0)] {
+            let preview = ScoringCalculator::preview(objective, strength);
+            for miss in 0..3 {
+                for counter in 0..=100 {
+                    assert_eq!(
+                        preview.score(counter, miss),
+                        ScoringCalculator::calculate_score(objective, counter, miss, strength),
+                        "objectif {objective}, compteur {counter}, miss {miss}"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Vérifie qu'une force ou un nombre de miss démesurés ne font pas déborder le calcul.
+    #[test]
+    fn test_score_saturates() {
+        assert_eq!(
+            ScoringCalculator::calculate_score(50, 50, 0, u32::MAX),
+            u32::MAX
+        );
+        assert_eq!(ScoringCalculator::calculate_score(50, 50, u32::MAX, 50), 0);
+        assert_eq!(
+            ScoringCalculator::calculate_average(&[u32::MAX, u32::MAX]),
+            u32::MAX
+        );
+        assert_eq!(ScoringCalculator::calculate_average(&[]), 0);
+    }
+
+    /// Vérifie que le calcul par série donne exactement les scores de `calculate_score`, et du barème
+    /// standard, sur des entrées aléatoires.
+    #[test]
+    fn test_score_batch_matches_scalar() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(158);
+        let scoring = ScoringConfig::default();
+        let mut scores = Vec::new();
+        for _ in 0..1000 {
+            let len = rng.random_range(0..16);
+            let objectives: Vec<u32> = (0..len).map(|_| rng.random_range(0..=100)).collect();
+            let stops: Vec<u32> = (0..len).map(|_| rng.random_range(0..=100)).collect();
+            let misses: Vec<u32> = (0..len).map(|_| rng.random_range(0..5)).collect();
+            let strength = rng.random_range(0..=200);
+            ScoringCalculator::score_batch(&objectives, &stops, &misses, strength, &mut scores);
+            assert_eq!(scores.len(), len);
+            for index in 0..len {
+                let (objective, stop, miss) = (objectives[index], stops[index], misses[index]);
+                let expected = scoring.score(objective, stop, miss, strength);
+                assert_eq!(
+                    ScoringCalculator::calculate_score(objective, stop, miss, strength),
+                    expected
+                );
+                assert_eq!(
+                    scores[index], expected,
+                    "{objective} {stop} {miss} {strength}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_calculate_average() {
         let scores = vec![45, 130, 130, 55, 65];
@@ -106,4 +506,26 @@ mod tests {
         let avg = ScoringCalculator::calculate_average(&scores);
         assert_eq!(avg, 85);
     }
+
+    /// Vérifie que la moyenne entière égale la moyenne calculée en `f64` et arrondie à l'entier
+    /// supérieur, sur des séries aléatoires allant jusqu'à des milliers d'objectifs.
+    #[test]
+    fn test_calculate_average_matches_float() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(198);
+        for _ in 0..500 {
+            let len = rng.random_range(1..=10_000);
+            let max = [3, 150, 300, u32::MAX][rng.random_range(0..4)];
+            let scores: Vec<u32> = (0..len).map(|_| rng.random_range(0..=max)).collect();
+            let sum: u64 = scores.iter().map(|&score| u64::from(score)).sum();
+            let expected = (sum as f64 / len as f64).ceil() as u32;
+            assert_eq!(
+                ScoringCalculator::calculate_average(&scores),
+                expected,
+                "{len} scores, somme {sum}"
+            );
+        }
+    }
 }
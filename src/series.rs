@@ -0,0 +1,325 @@
+//! Module des séries de parties au meilleur des N.
+//!
+//! Une [`Series`] enchaîne les parties entre les mêmes joueurs jusqu'à ce que l'un d'eux en remporte
+//! la majorité : 2 parties au meilleur des 3, 3 au meilleur des 5. Les matchs nuls ne comptent pour
+//! personne et sont rejoués.
+//!
+//! Avec le report de vitalité, le vainqueur d'une partie commence la suivante avec un bonus de
+//! vitalité au-dessus de sa vitalité initiale : [`CARRY_OVER_PERCENT`] % de la vitalité qui lui restait,
+//! au plus [`MAX_CARRY_OVER`] (voir [`carry_over`]). Le perdant ne reporte rien, et le bonus ne vaut
+//! que pour la partie suivante.
+
+use std::fmt;
+
+use crate::game::GameResult;
+use crate::messages::Msg;
+use crate::player::{Player, PlayerId};
+use crate::style;
+use crate::tr;
+
+/// Part de la vitalité restante du vainqueur reportée sur la partie suivante, en pourcentage.
+pub const CARRY_OVER_PERCENT: u32 = 25;
+/// Bonus de vitalité maximal reporté sur la partie suivante.
+pub const MAX_CARRY_OVER: u32 = 15;
+
+/// Calcule le bonus de vitalité reporté par le vainqueur d'une partie, à partir de sa vitalité
+/// restante : [`CARRY_OVER_PERCENT`] % arrondis à l'entier inférieur, au plus [`MAX_CARRY_OVER`].
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::series::carry_over;
+///
+/// assert_eq!(carry_over(34), 8);
+/// assert_eq!(carry_over(200), 15);
+/// ```
+pub fn carry_over(vitality: u32) -> u32 {
+    (vitality.saturating_mul(CARRY_OVER_PERCENT) / 100).min(MAX_CARRY_OVER)
+}
+
+/// Partie jouée au cours d'une série.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeriesGame {
+    /// Vainqueur de la partie, `None` en cas de match nul.
+    pub winner: Option<PlayerId>,
+    /// Bonus de vitalité reporté avec lequel chaque joueur a commencé la partie.
+    pub carried_over: Vec<u32>,
+}
+
+/// Série de parties au meilleur des N, avec son éventuel report de vitalité.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    /// Nombre de parties de la série, dont la majorité doit être remportée.
+    best_of: u32,
+    /// Indique si le vainqueur d'une partie reporte une part de sa vitalité sur la suivante.
+    carry_over: bool,
+    /// Noms des joueurs précédés de leur avatar, dans l'ordre de la partie.
+    names: Vec<String>,
+    /// Parties remportées par chaque joueur, abandons de l'adversaire compris.
+    wins: Vec<u32>,
+    /// Bonus de vitalité de chaque joueur pour la prochaine partie.
+    bonuses: Vec<u32>,
+    /// Parties jouées, dans l'ordre.
+    games: Vec<SeriesGame>,
+}
+
+impl Series {
+    /// Crée une série au meilleur de `best_of` parties entre les joueurs donnés.
+    ///
+    /// # Arguments
+    ///
+    /// * `best_of` - Le nombre de parties de la série (au moins 1).
+    /// * `players` - Les joueurs de la série, dans l'ordre de la partie.
+    /// * `carry_over` - Active le report de vitalité du vainqueur de chaque partie.
+    pub fn new(best_of: u32, players: &[Player], carry_over: bool) -> Self {
+        Series {
+            best_of: best_of.max(1),
+            carry_over,
+            names: players
+                .iter()
+                .map(|p| p.styled_name(false, style::is_ascii()))
+                .collect(),
+            wins: vec![0; players.len()],
+            bonuses: vec![0; players.len()],
+            games: Vec::new(),
+        }
+    }
+
+    /// Retourne le nombre de victoires nécessaires pour remporter la série.
+    pub fn wins_needed(&self) -> u32 {
+        self.best_of / 2 + 1
+    }
+
+    /// Retourne le bonus de vitalité de chaque joueur pour la prochaine partie.
+    pub fn bonuses(&self) -> &[u32] {
+        &self.bonuses
+    }
+
+    /// Retourne les parties jouées, dans l'ordre.
+    pub fn games(&self) -> &[SeriesGame] {
+        &self.games
+    }
+
+    /// Ajoute aux joueurs de la prochaine partie leur bonus de vitalité reporté, au-dessus de leur
+    /// vitalité initiale.
+    pub fn apply(&self, players: &mut [Player]) {
+        for (player, &bonus) in players.iter_mut().zip(&self.bonuses) {
+            player.vitality = player.vitality.saturating_add(bonus);
+            player.max_vitality = player.max_vitality.saturating_add(bonus);
+        }
+    }
+
+    /// Comptabilise l'issue d'une partie et calcule le report de vitalité sur la suivante.
+    ///
+    /// Seul le vainqueur de la partie reporte une part de sa vitalité restante ; une partie
+    /// interrompue n'est pas comptabilisée.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - L'issue de la partie.
+    /// * `players` - Les joueurs à la fin de la partie, dans l'ordre de la série.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::game::GameResult;
+    /// use dual_game::player::{Player, PlayerId};
+    /// use dual_game::series::Series;
+    ///
+    /// let mut players = vec![
+    ///     Player::new(String::from("Alice"), 50, 50, 50),
+    ///     Player::new(String::from("Bob"), 50, 50, 50),
+    /// ];
+    /// let mut series = Series::new(3, &players, true);
+    /// players[0].take_damage(18);
+    /// players[1].take_damage(50);
+    /// series.record(&GameResult::Victory { winner: PlayerId(0) }, &players);
+    /// assert_eq!(series.bonuses(), [8, 0]);
+    /// assert_eq!(series.winner(), None);
+    /// ```
+    pub fn record(&mut self, result: &GameResult, players: &[Player]) {
+        if *result == GameResult::Interrupted {
+            return;
+        }
+        let winner = result.winner();
+        self.games.push(SeriesGame {
+            winner,
+            carried_over: self.bonuses.clone(),
+        });
+        if let Some(winner) = winner {
+            self.wins[winner.index()] += 1;
+        }
+        for (index, bonus) in self.bonuses.iter_mut().enumerate() {
+            *bonus = match (winner, players.get(index)) {
+                (Some(winner), Some(player)) if self.carry_over && winner.index() == index => {
+                    carry_over(player.vitality())
+                }
+                _ => 0,
+            };
+        }
+    }
+
+    /// Retourne le vainqueur de la série, `None` tant qu'aucun joueur n'a remporté la majorité des
+    /// parties.
+    pub fn winner(&self) -> Option<PlayerId> {
+        self.wins
+            .iter()
+            .position(|&wins| wins >= self.wins_needed())
+            .map(PlayerId)
+    }
+
+    /// Indique si la série est terminée.
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    /// Formate le score de la série, par exemple `Alice 2 : 1 Bob`.
+    fn score(&self) -> String {
+        if let ([first, second], [w1, w2]) = (self.names.as_slice(), self.wins.as_slice()) {
+            format!("{first} {w1} : {w2} {second}")
+        } else {
+            let scores: Vec<String> = self
+                .names
+                .iter()
+                .zip(&self.wins)
+                .map(|(name, wins)| format!("{name} {wins}"))
+                .collect();
+            scores.join(", ")
+        }
+    }
+}
+
+impl fmt::Display for Series {
+    /// Formate le bilan de la série : son score, chaque partie avec le report dont les joueurs ont
+    /// bénéficié, puis son vainqueur une fois la série terminée.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            tr!(
+                Msg::SeriesScore,
+                best_of = self.best_of,
+                score = self.score()
+            )
+        )?;
+        for (index, game) in self.games.iter().enumerate() {
+            let number = index + 1;
+            let line = match game.winner {
+                Some(winner) => tr!(
+                    Msg::SeriesGameWon,
+                    number = number,
+                    winner = self.names[winner.index()]
+                ),
+                None => tr!(Msg::SeriesGameDrawn, number = number),
+            };
+            write!(f, "\n  {line}")?;
+            for (name, &bonus) in self.names.iter().zip(&game.carried_over) {
+                if bonus > 0 {
+                    write!(
+                        f,
+                        " — {}",
+                        tr!(Msg::SeriesCarriedOver, player = name, bonus = bonus)
+                    )?;
+                }
+            }
+        }
+        if let Some(winner) = self.winner() {
+            write!(
+                f,
+                "\n{}",
+                tr!(Msg::SeriesWinner, winner = self.names[winner.index()])
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Joueurs d'une série, avec leur vitalité restante à la fin d'une partie.
+    fn players(vitality: [u32; 2]) -> Vec<Player> {
+        ["Alice", "Bob"]
+            .into_iter()
+            .zip(vitality)
+            .map(|(name, vitality)| {
+                let mut player = Player::new(String::from(name), 50, 75, 50);
+                player.vitality = vitality;
+                player
+            })
+            .collect()
+    }
+
+    /// Vérifie le report : 25 % de la vitalité restante arrondis à l'entier inférieur, plafonnés à 15
+    /// même lorsque le vainqueur avait lui-même commencé avec un bonus.
+    #[test]
+    fn test_carry_over_cap() {
+        let reported: Vec<u32> = [0, 3, 4, 34, 59, 60, 61, 65, u32::MAX]
+            .into_iter()
+            .map(carry_over)
+            .collect();
+        assert_eq!(reported, [0, 0, 1, 8, 14, 15, 15, 15, 15]);
+
+        let mut series = Series::new(5, &players([50, 50]), true);
+        series.record(
+            &GameResult::Victory {
+                winner: PlayerId(1),
+            },
+            &players([0, 65]),
+        );
+        assert_eq!(series.bonuses(), [0, MAX_CARRY_OVER]);
+        let mut next = players([50, 50]);
+        series.apply(&mut next);
+        assert_eq!((next[1].vitality(), next[1].max_vitality()), (65, 65));
+        assert_eq!(next[0].vitality(), 50);
+    }
+
+    /// Vérifie que le perdant ne reporte rien, qu'un match nul ou une série sans report n'apporte
+    /// aucun bonus, et que la série se termine à la majorité des parties gagnées.
+    #[test]
+    fn test_no_carry_over_for_loser() {
+        let mut series = Series::new(3, &players([50, 50]), true);
+        series.record(
+            &GameResult::Victory {
+                winner: PlayerId(0),
+            },
+            &players([32, 0]),
+        );
+        assert_eq!(series.bonuses(), [8, 0]);
+        series.record(
+            &GameResult::Forfeit {
+                forfeited: PlayerId(0),
+                winner: PlayerId(1),
+            },
+            &players([58, 20]),
+        );
+        assert_eq!(series.bonuses(), [0, 5]);
+        series.record(&GameResult::Interrupted, &players([0, 50]));
+        series.record(&GameResult::Draw, &players([10, 10]));
+        assert_eq!(series.bonuses(), [0, 0]);
+        assert!(!series.is_over());
+        series.record(
+            &GameResult::Victory {
+                winner: PlayerId(0),
+            },
+            &players([40, 0]),
+        );
+        assert_eq!(series.winner(), Some(PlayerId(0)));
+        assert_eq!(
+            series.to_string(),
+            "Série au meilleur des 3 — Alice 2 : 1 Bob\n  Partie 1 : Alice\n  Partie 2 : Bob — \
+             Alice +8 report\n  Partie 3 : match nul — Bob +5 report\n  Partie 4 : Alice\n🏆 \
+             Vainqueur de la série : Alice"
+        );
+
+        let mut plain = Series::new(3, &players([50, 50]), false);
+        plain.record(
+            &GameResult::Victory {
+                winner: PlayerId(0),
+            },
+            &players([50, 0]),
+        );
+        assert_eq!(plain.bonuses(), [0, 0]);
+    }
+}
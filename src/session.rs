@@ -0,0 +1,214 @@
+//! Module de suivi des résultats d'une soirée de jeu.
+//!
+//! La structure [`Session`] comptabilise les victoires, matchs nuls et abandons des parties
+//! enchaînées sans quitter l'application. Contrairement aux profils, ce bilan n'est pas enregistré.
+
+use std::fmt;
+
+use crate::game::GameResult;
+use crate::messages::Msg;
+use crate::player::{Player, PlayerId};
+use crate::style;
+use crate::tr;
+
+/// Bilan des parties jouées pendant une session.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Session {
+    /// Noms des joueurs précédés de leur avatar, dans l'ordre de la partie.
+    names: Vec<String>,
+    /// Victoires de chaque joueur, abandons de l'adversaire compris.
+    wins: Vec<u32>,
+    /// Abandons de chaque joueur.
+    forfeits: Vec<u32>,
+    /// Nombre de matchs nuls.
+    draws: u32,
+}
+
+impl Session {
+    /// Crée un bilan vierge pour les joueurs donnés.
+    ///
+    /// # Arguments
+    ///
+    /// * `players` - Les joueurs de la session, dans l'ordre de la partie.
+    pub fn new(players: &[Player]) -> Self {
+        Session {
+            names: players
+                .iter()
+                .map(|p| p.styled_name(false, style::is_ascii()))
+                .collect(),
+            wins: vec![0; players.len()],
+            forfeits: vec![0; players.len()],
+            draws: 0,
+        }
+    }
+
+    /// Comptabilise l'issue d'une partie.
+    ///
+    /// Une partie interrompue n'est pas comptabilisée.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::game::GameResult;
+    /// use dual_game::player::{Player, PlayerId};
+    /// use dual_game::session::Session;
+    ///
+    /// let players = vec![
+    ///     Player::new(String::from("Alice"), 50, 50, 50),
+    ///     Player::new(String::from("Bob"), 50, 50, 50),
+    /// ];
+    /// let mut session = Session::new(&players);
+    /// session.record(&GameResult::Victory { winner: PlayerId(0) });
+    /// session.record(&GameResult::Draw);
+    /// assert_eq!(session.to_string(), "Score de la soirée — Alice 1 : 0 Bob (1 nul)");
+    /// ```
+    pub fn record(&mut self, result: &GameResult) {
+        match result {
+            GameResult::Victory { winner } => self.wins[winner.index()] += 1,
+            GameResult::Draw => self.draws += 1,
+            GameResult::Forfeit { forfeited, winner } => {
+                self.wins[winner.index()] += 1;
+                self.forfeits[forfeited.index()] += 1;
+            }
+            GameResult::Interrupted => {}
+        }
+    }
+
+    /// Inverse l'ordre des joueurs, lorsqu'ils échangent leurs places pour la partie suivante.
+    ///
+    /// Le bilan de chaque joueur le suit à sa nouvelle place.
+    pub fn swap_sides(&mut self) {
+        self.names.reverse();
+        self.wins.reverse();
+        self.forfeits.reverse();
+    }
+
+    /// Retourne le nombre de victoires d'un joueur, abandons de l'adversaire compris.
+    pub fn wins(&self, id: PlayerId) -> u32 {
+        self.wins[id.index()]
+    }
+
+    /// Retourne le nombre d'abandons d'un joueur.
+    pub fn forfeits(&self, id: PlayerId) -> u32 {
+        self.forfeits[id.index()]
+    }
+
+    /// Retourne le nombre de matchs nuls.
+    pub fn draws(&self) -> u32 {
+        self.draws
+    }
+
+    /// Retourne le nombre de parties comptabilisées.
+    pub fn games(&self) -> u32 {
+        self.wins.iter().sum::<u32>() + self.draws
+    }
+}
+
+impl fmt::Display for Session {
+    /// Formate le bilan, par exemple `Score de la soirée — Alice 3 : 2 Bob (1 nul)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scores =
+            if let ([first, second], [w1, w2]) = (self.names.as_slice(), self.wins.as_slice()) {
+                format!("{first} {w1} : {w2} {second}")
+            } else {
+                let scores: Vec<String> = self
+                    .names
+                    .iter()
+                    .zip(&self.wins)
+                    .map(|(name, wins)| format!("{name} {wins}"))
+                    .collect();
+                scores.join(", ")
+            };
+        write!(f, "{}", tr!(Msg::SessionScore, scores = scores))?;
+
+        let forfeits: u32 = self.forfeits.iter().sum();
+        let mut notes = Vec::new();
+        if self.draws > 0 {
+            notes.push(plural(self.draws, Msg::SessionDraw, Msg::SessionDraws));
+        }
+        if forfeits > 0 {
+            notes.push(plural(forfeits, Msg::SessionForfeit, Msg::SessionForfeits));
+        }
+        if !notes.is_empty() {
+            write!(f, " ({})", notes.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Formate une quantité avec le message accordé en nombre, au singulier ou au pluriel.
+fn plural(count: u32, singular: Msg, plural: Msg) -> String {
+    if count > 1 {
+        tr!(plural, count = count)
+    } else {
+        tr!(singular, count = count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::{Controller, CounterView, StopDecision};
+    use crate::game::Game;
+    use crate::poison::PoisonType;
+
+    /// Contrôleur arrêtant toujours le compteur au même écart de l'objectif.
+    struct Scripted {
+        offset: i64,
+    }
+
+    impl Controller for Scripted {
+        fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+            StopDecision::from_offset(ctx.objective, self.offset)
+        }
+
+        fn choose_poison(&mut self, _options: &[PoisonType], _opponent: &Player) -> usize {
+            0
+        }
+
+        fn choose_counter(&mut self, _poison: &PoisonType, _extra: u32, _me: &Player) -> bool {
+            false
+        }
+
+        fn acknowledge(&mut self, _msg: &str) {}
+    }
+
+    /// Joue une partie où le joueur `sharp` arrête toujours le compteur sur l'objectif.
+    fn scripted_game(players: &[Player], sharp: usize) -> GameResult {
+        let mut game = Game::new(players.to_vec(), 3);
+        for index in 0..players.len() {
+            let offset = if index == sharp { 0 } else { 50 };
+            game.set_controller(index, Box::new(Scripted { offset }));
+        }
+        game.run().unwrap()
+    }
+
+    /// Vérifie le bilan après deux parties scriptées, un match nul et un abandon.
+    #[test]
+    fn test_session_tally() {
+        let players = vec![
+            Player::new(String::from("Alice"), 30, 75, 50),
+            Player::new(String::from("Bob"), 30, 75, 50),
+        ];
+        let mut session = Session::new(&players);
+        session.record(&scripted_game(&players, 0));
+        session.record(&scripted_game(&players, 0));
+        assert_eq!(session.to_string(), "Score de la soirée — Alice 2 : 0 Bob");
+
+        session.record(&scripted_game(&players, 1));
+        session.record(&GameResult::Draw);
+        session.record(&GameResult::Forfeit {
+            forfeited: PlayerId(0),
+            winner: PlayerId(1),
+        });
+        assert_eq!(session.wins(PlayerId(0)), 2);
+        assert_eq!(session.wins(PlayerId(1)), 2);
+        assert_eq!(session.forfeits(PlayerId(0)), 1);
+        assert_eq!(session.draws(), 1);
+        assert_eq!(session.games(), 5);
+        assert_eq!(
+            session.to_string(),
+            "Score de la soirée — Alice 2 : 2 Bob (1 nul, 1 abandon)"
+        );
+    }
+}
@@ -0,0 +1,338 @@
+//! Module de l'assistant de configuration interactif.
+//!
+//! Lorsque les joueurs ne sont pas nommés en ligne de commande, l'assistant [`wizard`] demande
+//! successivement le nom et la classe de chaque joueur, la vitalité initiale, le nombre d'objectifs
+//! par tour et les règles optionnelles, en validant chaque saisie avant de passer à la suivante.
+//! L'assistant [`edit`] pose les mêmes questions en proposant les valeurs d'une configuration
+//! existante, par exemple pour modifier les réglages entre deux parties.
+
+use std::fmt::Display;
+
+use crate::class::PlayerClass;
+use crate::console::Console;
+use crate::messages::Msg;
+use crate::player::{DEFAULT_SPEED, DEFAULT_STRENGTH, DEFAULT_VITALITY, Player};
+use crate::rules::GameRules;
+use crate::tr;
+
+/// Nombre d'objectifs par tour proposé par défaut.
+pub const DEFAULT_OBJECTIFS: usize = 5;
+
+/// Configuration choisie au moyen de l'assistant.
+#[derive(Clone, Debug)]
+pub struct SetupChoices {
+    /// Joueurs validés.
+    pub players: Vec<Player>,
+    /// Règles optionnelles de la partie.
+    pub rules: GameRules,
+    /// Nombre d'objectifs par tour.
+    pub objectifs: usize,
+}
+
+/// Valeurs proposées par défaut par l'assistant.
+struct Defaults {
+    /// Noms des joueurs, s'ils sont connus.
+    names: [Option<String>; 2],
+    /// Classes des joueurs.
+    classes: [Option<PlayerClass>; 2],
+    /// Vitalité initiale des joueurs sans classe.
+    vitality: u32,
+    /// Nombre d'objectifs par tour.
+    objectifs: usize,
+    /// Règles optionnelles.
+    rules: GameRules,
+}
+
+/// Pose une question jusqu'à obtenir une réponse valide.
+///
+/// Une réponse vide retient la valeur par défaut, si elle existe. Les erreurs de validation sont
+/// affichées avant de reposer la question.
+///
+/// # Retour
+///
+/// Retourne `None` si la saisie a été interrompue (fin de flux).
+pub(crate) fn ask<T, E: Display>(
+    console: &mut dyn Console,
+    question: &str,
+    default: Option<&str>,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Option<T> {
+    let question = match default {
+        Some(default) => format!("{question} [{default}] : "),
+        None => format!("{question} : "),
+    };
+    loop {
+        let answer = console.prompt(&question)?;
+        let answer = match (answer.trim(), default) {
+            ("", Some(default)) => default,
+            (answer, _) => answer,
+        };
+        match parse(answer) {
+            Ok(value) => return Some(value),
+            Err(err) => console.println(&tr!(Msg::SetupInvalidInput, error = err)),
+        }
+    }
+}
+
+/// Interprète une réponse oui/non, en français ou en anglais.
+pub(crate) fn parse_yes_no(answer: &str) -> Result<bool, String> {
+    match answer.to_lowercase().as_str() {
+        "o" | "oui" | "y" | "yes" => Ok(true),
+        "n" | "non" | "no" => Ok(false),
+        _ => Err(tr!(Msg::SetupYesNoError)),
+    }
+}
+
+/// Formate une valeur booléenne pour le résumé.
+fn yes_no(value: bool) -> String {
+    if value {
+        tr!(Msg::SetupYes)
+    } else {
+        tr!(Msg::SetupNo)
+    }
+}
+
+/// Formate une valeur booléenne comme réponse par défaut à une question oui/non.
+fn yes_no_default(value: bool) -> String {
+    if value {
+        tr!(Msg::SetupYesKey)
+    } else {
+        tr!(Msg::SetupNoKey)
+    }
+}
+
+/// Lance l'assistant de configuration interactif.
+///
+/// # Arguments
+///
+/// * `console` - Les entrées/sorties utilisées pour dialoguer avec l'utilisateur.
+/// * `names` - Les noms déjà connus des joueurs, proposés par défaut.
+///
+/// # Retour
+///
+/// Retourne les joueurs, les règles et le nombre d'objectifs choisis, ou `None` si la saisie a été
+/// interrompue avant la fin.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::console::ScriptedConsole;
+/// use dual_game::setup::wizard;
+///
+/// let mut console = ScriptedConsole::new(["Alice", "", "Bob", "tank", "", "3", "", ""]);
+/// let choices = wizard(&mut console, [None, None]).unwrap();
+/// assert_eq!(choices.players[1].name, "Bob");
+/// assert_eq!(choices.objectifs, 3);
+/// ```
+pub fn wizard(console: &mut dyn Console, names: [Option<String>; 2]) -> Option<SetupChoices> {
+    run(
+        console,
+        Defaults {
+            names,
+            classes: [None, None],
+            vitality: DEFAULT_VITALITY,
+            objectifs: DEFAULT_OBJECTIFS,
+            rules: GameRules::default(),
+        },
+    )
+}
+
+/// Relance l'assistant en proposant les valeurs d'une configuration existante.
+///
+/// # Arguments
+///
+/// * `console` - Les entrées/sorties utilisées pour dialoguer avec l'utilisateur.
+/// * `current` - La configuration actuelle, dont les valeurs sont proposées par défaut.
+///
+/// # Retour
+///
+/// Retourne la nouvelle configuration, ou `None` si la saisie a été interrompue avant la fin.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::console::ScriptedConsole;
+/// use dual_game::setup::{edit, wizard};
+///
+/// let mut console = ScriptedConsole::new(["Alice", "", "Bob", "", "30", "3", "o", ""]);
+/// let current = wizard(&mut console, [None, None]).unwrap();
+/// let mut console = ScriptedConsole::new(["", "", "", "", "", "4", "", ""]);
+/// let edited = edit(&mut console, &current).unwrap();
+/// assert_eq!(edited.players[0].name, "Alice");
+/// assert_eq!(edited.players[1].vitality(), 30);
+/// assert_eq!(edited.objectifs, 4);
+/// assert!(edited.rules.fatigue);
+/// ```
+pub fn edit(console: &mut dyn Console, current: &SetupChoices) -> Option<SetupChoices> {
+    let player = |index: usize| current.players.get(index);
+    run(
+        console,
+        Defaults {
+            names: [0, 1].map(|index| player(index).map(|p| p.name.clone())),
+            classes: [0, 1].map(|index| player(index).and_then(|p| p.class)),
+            vitality: current
+                .players
+                .iter()
+                .find(|p| p.class.is_none())
+                .map_or(DEFAULT_VITALITY, Player::max_vitality),
+            objectifs: current.objectifs,
+            rules: current.rules,
+        },
+    )
+}
+
+/// Pose les questions de l'assistant en proposant les valeurs par défaut données.
+fn run(console: &mut dyn Console, defaults: Defaults) -> Option<SetupChoices> {
+    console.println(&tr!(Msg::SetupTitle));
+    let classes = PlayerClass::ALL
+        .iter()
+        .map(PlayerClass::id)
+        .collect::<Vec<_>>()
+        .join(", ");
+    // Mot-clé d'un joueur sans classe.
+    let none = tr!(Msg::SetupNoClass);
+
+    let mut entries = Vec::new();
+    for (index, default_name) in defaults.names.iter().enumerate() {
+        let name = ask(
+            console,
+            &tr!(Msg::SetupPlayerName, number = index + 1),
+            default_name.as_deref(),
+            |name| Player::builder(name).build().map(|player| player.name),
+        )?;
+        let class = ask(
+            console,
+            &tr!(Msg::SetupPlayerClass, name = name, classes = classes),
+            Some(defaults.classes[index].map_or(none.as_str(), |class| class.id())),
+            |answer| match answer {
+                answer if answer == none => Ok(None),
+                answer => answer.parse::<PlayerClass>().map(Some),
+            },
+        )?;
+        entries.push((name, class));
+    }
+
+    let vitality = if entries.iter().any(|(_, class)| class.is_none()) {
+        ask(
+            console,
+            &tr!(Msg::SetupVitality),
+            Some(&defaults.vitality.to_string()),
+            |answer| match answer.parse::<u32>() {
+                Ok(0) | Err(_) => Err(tr!(Msg::SetupPositiveNumber)),
+                Ok(vitality) => Ok(vitality),
+            },
+        )?
+    } else {
+        DEFAULT_VITALITY
+    };
+    let objectifs = ask(
+        console,
+        &tr!(Msg::SetupObjectives),
+        Some(&defaults.objectifs.to_string()),
+        |answer| match answer.parse::<usize>() {
+            Ok(0) | Err(_) => Err(tr!(Msg::SetupPositiveNumber)),
+            Ok(objectifs) => Ok(objectifs),
+        },
+    )?;
+    let rules = GameRules {
+        fatigue: ask(
+            console,
+            &tr!(Msg::SetupFatigue),
+            Some(&yes_no_default(defaults.rules.fatigue)),
+            parse_yes_no,
+        )?,
+        stamina: ask(
+            console,
+            &tr!(Msg::SetupStamina),
+            Some(&yes_no_default(defaults.rules.stamina)),
+            parse_yes_no,
+        )?,
+        ..defaults.rules
+    };
+
+    let players = entries
+        .into_iter()
+        .map(|(name, class)| {
+            let builder = Player::builder(name)
+                .vitality(vitality)
+                .speed(DEFAULT_SPEED)
+                .strength(DEFAULT_STRENGTH);
+            match class {
+                Some(class) => builder.class(class),
+                None => builder,
+            }
+            .build()
+            .expect("nom et caractéristiques déjà validés")
+        })
+        .collect::<Vec<_>>();
+
+    console.println(&tr!(Msg::SetupSummary));
+    for (index, player) in players.iter().enumerate() {
+        console.println(&tr!(
+            Msg::SetupSummaryPlayer,
+            number = index + 1,
+            player = player
+        ));
+    }
+    console.println(&tr!(
+        Msg::SetupSummaryRules,
+        objectifs = objectifs,
+        fatigue = yes_no(rules.fatigue),
+        stamina = yes_no(rules.stamina)
+    ));
+
+    Some(SetupChoices {
+        players,
+        rules,
+        objectifs,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::ScriptedConsole;
+
+    /// Vérifie que les saisies invalides sont signalées puis redemandées, et que les valeurs par défaut
+    /// sont retenues pour les réponses vides.
+    #[test]
+    fn test_wizard_validation() {
+        crate::style::set_enabled(false);
+        let mut console = ScriptedConsole::new([
+            "   ", "Alice", "mage", "", "", "sprinter", "0", "40", "", "oui", "",
+        ]);
+        let choices = wizard(&mut console, [None, Some(String::from("Bot"))]).unwrap();
+
+        assert_eq!(choices.players[0].name, "Alice");
+        assert_eq!(choices.players[0].class, None);
+        assert_eq!(choices.players[0].vitality(), 40);
+        assert_eq!(choices.players[1].name, "Bot");
+        assert_eq!(choices.players[1].class, Some(PlayerClass::Sprinter));
+        assert_eq!(
+            choices.players[1].vitality(),
+            PlayerClass::Sprinter.stats().vitality
+        );
+        assert_eq!(choices.objectifs, DEFAULT_OBJECTIFS);
+        assert_eq!(
+            choices.rules,
+            GameRules {
+                fatigue: true,
+                stamina: false,
+                ..GameRules::default()
+            }
+        );
+
+        let errors = console.output.matches("Saisie invalide").count();
+        assert_eq!(errors, 3);
+        assert!(console.output.contains("Nom du joueur 2 [Bot] : "));
+        assert!(console.output.contains("Configuration choisie :"));
+        assert!(console.output.contains("Fatigue : oui | Endurance : non"));
+    }
+
+    /// Vérifie que l'assistant s'interrompt en fin de flux.
+    #[test]
+    fn test_wizard_interrupted() {
+        let mut console = ScriptedConsole::new(["Alice", ""]);
+        assert!(wizard(&mut console, [None, None]).is_none());
+    }
+}
@@ -0,0 +1,200 @@
+//! Module du compteur partagé (mode `shared`).
+//!
+//! En mode partagé, un seul compteur défile pour les deux joueurs, assis devant le même écran : le
+//! premier joueur verrouille sa valeur avec la touche `a`, le second avec la touche `l` (touches par
+//! défaut, voir [`keys`](crate::keys)), une seule fois chacun par objectif, et chaque valeur est comparée au même objectif. Les verrous sont tenus
+//! par [`SharedLocks`] ; un joueur qui n'a pas verrouillé avant [`SHARED_MISS_CAP`] dépassements du
+//! compteur est compté à l'écart maximal.
+//!
+//! Le compteur lui-même est fourni à la partie par un [`SharedCounter`] : [`TerminalSharedCounter`]
+//! lit les deux touches au clavier (fonctionnalité `cli`). Sans compteur partagé, par exemple lorsqu'un
+//! bot joue, chaque contrôleur arrête à son tour le compteur sur l'objectif commun.
+
+use crate::cancel::CancelToken;
+use crate::console::InputEvent;
+use crate::controller::StopDecision;
+use crate::keys::{self, Action};
+
+/// Touches verrouillant par défaut la valeur du compteur, dans l'ordre des joueurs.
+pub const SHARED_KEYS: [char; 2] = ['a', 'l'];
+
+/// Nombre de dépassements du compteur après lequel l'objectif se termine, même sans verrou.
+pub const SHARED_MISS_CAP: u32 = 3;
+
+/// Écart maximal entre le compteur et l'objectif, compté dans le sens le plus court du cycle (voir
+/// [`ScoringCalculator::difference`](crate::scoring::ScoringCalculator::difference)).
+const MAX_DIFFERENCE: u32 = 50;
+
+/// Verrous posés par les joueurs sur le compteur partagé, pour un objectif.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SharedLocks {
+    /// Valeur `(counter_value, miss)` verrouillée par chaque joueur, `None` tant qu'il n'a pas joué.
+    locks: [Option<(u32, u32)>; 2],
+}
+
+impl SharedLocks {
+    /// Retourne le joueur associé à une touche (insensible à la casse) par les touches du jeu (voir
+    /// [`keys::bindings`]), `None` pour une autre touche.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::shared::SharedLocks;
+    ///
+    /// assert_eq!(SharedLocks::player_for_key('L'), Some(1));
+    /// assert_eq!(SharedLocks::player_for_key('s'), None);
+    /// ```
+    pub fn player_for_key(key: char) -> Option<usize> {
+        match keys::bindings().translate(InputEvent::Char(key)) {
+            Some(Action::Lock(player)) => Some(player),
+            _ => None,
+        }
+    }
+
+    /// Traite l'appui sur une touche alors que le compteur affiche la valeur donnée.
+    ///
+    /// Seul le premier appui de chaque joueur est retenu : les suivants, comme les autres touches, sont
+    /// ignorés.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le joueur dont la valeur vient d'être verrouillée, ou `None` si l'appui est ignoré.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::shared::SharedLocks;
+    ///
+    /// let mut locks = SharedLocks::default();
+    /// assert_eq!(locks.press('a', 42, 0), Some(0));
+    /// assert_eq!(locks.press('a', 57, 0), None);
+    /// assert_eq!(locks.locked(0), Some((42, 0)));
+    /// ```
+    pub fn press(&mut self, key: char, counter: u32, miss: u32) -> Option<usize> {
+        self.lock(Self::player_for_key(key)?, counter, miss)
+    }
+
+    /// Verrouille la valeur du compteur pour un joueur, si ce n'est déjà fait.
+    ///
+    /// # Retour
+    ///
+    /// Retourne le joueur dont la valeur vient d'être verrouillée, ou `None` si le joueur avait déjà
+    /// verrouillé ou n'existe pas.
+    pub fn lock(&mut self, player: usize, counter: u32, miss: u32) -> Option<usize> {
+        let lock = self.locks.get_mut(player)?;
+        if lock.is_some() {
+            return None;
+        }
+        *lock = Some((counter, miss));
+        Some(player)
+    }
+
+    /// Retourne la valeur `(counter_value, miss)` verrouillée par un joueur, `None` s'il n'a pas joué.
+    pub fn locked(&self, player: usize) -> Option<(u32, u32)> {
+        self.locks.get(player).copied().flatten()
+    }
+
+    /// Indique si les deux joueurs ont verrouillé leur valeur.
+    pub fn is_complete(&self) -> bool {
+        self.locks.iter().all(Option::is_some)
+    }
+
+    /// Convertit les verrous en arrêts du compteur, dans l'ordre des joueurs.
+    ///
+    /// Un joueur qui n'a pas verrouillé est compté à l'écart maximal de l'objectif, après
+    /// [`SHARED_MISS_CAP`] dépassements.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::shared::{SHARED_MISS_CAP, SharedLocks};
+    ///
+    /// let mut locks = SharedLocks::default();
+    /// locks.press('l', 30, 1);
+    /// let [first, second] = locks.decisions(20);
+    /// assert_eq!((first.counter_value, first.miss), (70, SHARED_MISS_CAP));
+    /// assert_eq!((second.counter_value, second.miss), (30, 1));
+    /// ```
+    pub fn decisions(&self, objective: u32) -> [StopDecision; 2] {
+        self.locks.map(|lock| {
+            let (counter_value, miss) =
+                lock.unwrap_or(((objective + MAX_DIFFERENCE) % 100, SHARED_MISS_CAP));
+            StopDecision {
+                counter_value,
+                miss,
+                stamina_spent: 0,
+            }
+        })
+    }
+}
+
+/// Objectif joué sur le compteur partagé.
+#[derive(Clone, Debug)]
+pub struct SharedView<'a> {
+    /// Objectif commun aux deux joueurs.
+    pub objective: u32,
+    /// Délai du compteur, en millisecondes : la moyenne des vitesses des joueurs.
+    pub speed: u32,
+    /// Noms des joueurs, dans l'ordre des touches [`SHARED_KEYS`].
+    pub names: [&'a str; 2],
+    /// Jeton d'interruption de la partie, arrêtant le compteur.
+    pub cancel: CancelToken,
+}
+
+/// Compteur commun aux deux joueurs, sur lequel chacun verrouille sa valeur.
+pub trait SharedCounter {
+    /// Fait défiler le compteur pour un objectif jusqu'à ce que les deux joueurs aient verrouillé
+    /// leur valeur, que le compteur ait dépassé 100 [`SHARED_MISS_CAP`] fois ou que la partie soit
+    /// interrompue.
+    fn run(&mut self, view: &SharedView) -> SharedLocks;
+}
+
+/// Compteur partagé au clavier : touche `a` pour le premier joueur, `l` pour le second (voir
+/// [`Counter::run_shared`](crate::counter::Counter::run_shared)).
+#[cfg(feature = "cli")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TerminalSharedCounter;
+
+#[cfg(feature = "cli")]
+impl SharedCounter for TerminalSharedCounter {
+    fn run(&mut self, view: &SharedView) -> SharedLocks {
+        crate::counter::Counter::new(view.speed)
+            .with_cancel(view.cancel.clone())
+            .run_shared(view.objective, view.names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie la tenue des verrous au fil d'une suite de touches scriptée : une seule valeur par
+    /// joueur, les autres touches ignorées, et l'écart maximal pour le joueur qui n'a pas verrouillé.
+    #[test]
+    fn test_lock_bookkeeping() {
+        let mut locks = SharedLocks::default();
+        let events = [
+            ('x', 3, 0, None),
+            ('A', 12, 0, Some(0)),
+            ('a', 13, 0, None),
+            ('s', 40, 0, None),
+            ('a', 80, 1, None),
+        ];
+        for (key, counter, miss, expected) in events {
+            assert_eq!(locks.press(key, counter, miss), expected, "touche {key}");
+        }
+        assert!(!locks.is_complete());
+        assert_eq!(locks.locked(0), Some((12, 0)));
+        assert_eq!(locks.locked(1), None);
+        assert_eq!(locks.locked(2), None);
+
+        let [first, second] = locks.decisions(90);
+        assert_eq!((first.counter_value, first.miss), (12, 0));
+        assert_eq!((second.counter_value, second.miss), (40, SHARED_MISS_CAP));
+
+        assert_eq!(locks.press('l', 5, 2), Some(1));
+        assert_eq!(locks.press('L', 6, 2), None);
+        assert!(locks.is_complete());
+        assert_eq!(locks.decisions(90)[1].counter_value, 5);
+    }
+}
@@ -0,0 +1,194 @@
+//! Module de simulation de parties entre bots.
+//!
+//! La fonction [`simulate`] enchaîne des parties silencieuses entre deux [`BotController`] et
+//! agrège leurs résultats dans un [`SimulationReport`], afin de comparer des classes, des
+//! caractéristiques ou des difficultés sans intervention humaine. Le module [`balance`](crate::balance)
+//! s'en sert pour balayer une grille de caractéristiques.
+
+use std::error::Error;
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::controller::{BotController, Difficulty};
+use crate::game::{Game, GameResult};
+use crate::messages::Msg;
+use crate::output::Verbosity;
+use crate::player::Player;
+use crate::rules::GameRules;
+use crate::tr;
+
+/// Résultats agrégés d'une série de parties simulées.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimulationReport {
+    /// Noms des deux joueurs.
+    pub players: Vec<String>,
+    /// Nombre de parties simulées.
+    pub games: u32,
+    /// Nombre de victoires de chaque joueur.
+    pub wins: [u32; 2],
+    /// Nombre de matchs nuls.
+    pub draws: u32,
+    /// Nombre total de manches jouées.
+    pub rounds: u32,
+}
+
+impl SimulationReport {
+    /// Retourne le taux de victoire d'un joueur, en pourcentage.
+    pub fn win_rate(&self, index: usize) -> f64 {
+        if self.games == 0 {
+            return 0.0;
+        }
+        f64::from(self.wins[index]) * 100.0 / f64::from(self.games)
+    }
+
+    /// Retourne la durée moyenne d'une partie, en manches.
+    pub fn average_rounds(&self) -> f64 {
+        if self.games == 0 {
+            return 0.0;
+        }
+        f64::from(self.rounds) / f64::from(self.games)
+    }
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", tr!(Msg::SimulationTitle, games = self.games))?;
+        for (index, name) in self.players.iter().enumerate().take(2) {
+            writeln!(
+                f,
+                "{}",
+                tr!(
+                    Msg::SimulationWins,
+                    player = name,
+                    wins = self.wins[index],
+                    rate = format!("{:.1}", self.win_rate(index))
+                )
+            )?;
+        }
+        writeln!(f, "{}", tr!(Msg::SimulationDraws, draws = self.draws))?;
+        write!(
+            f,
+            "{}",
+            tr!(
+                Msg::SimulationLength,
+                rounds = format!("{:.1}", self.average_rounds())
+            )
+        )
+    }
+}
+
+/// Simule des parties entre deux bots.
+///
+/// # Arguments
+///
+/// * `players` - Les deux joueurs, remis à neuf au début de chaque partie.
+/// * `difficulties` - La difficulté du bot contrôlant chaque joueur.
+/// * `games` - Le nombre de parties à simuler.
+/// * `objectifs` - Le nombre d'objectifs par tour.
+/// * `rules` - Les règles optionnelles des parties.
+/// * `seed` - La graine rendant la simulation reproductible, le cas échéant.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::controller::Difficulty;
+/// use dual_game::player::Player;
+/// use dual_game::rules::GameRules;
+/// use dual_game::simulation::simulate;
+///
+/// let players = [
+///     Player::new(String::from("Alice"), 30, 75, 50),
+///     Player::new(String::from("Bob"), 30, 75, 50),
+/// ];
+/// let difficulties = [Difficulty::Hard, Difficulty::Easy];
+/// let report = simulate(&players, difficulties, 4, 3, GameRules::default(), Some(1)).unwrap();
+/// assert_eq!(report.wins[0] + report.wins[1] + report.draws, 4);
+/// ```
+pub fn simulate(
+    players: &[Player; 2],
+    difficulties: [Difficulty; 2],
+    games: u32,
+    objectifs: usize,
+    rules: GameRules,
+    seed: Option<u64>,
+) -> Result<SimulationReport, Box<dyn Error>> {
+    simulate_with_progress(
+        players,
+        difficulties,
+        games,
+        objectifs,
+        rules,
+        seed,
+        &mut |_| {},
+    )
+}
+
+/// Simule des parties entre deux bots comme [`simulate`], en signalant la fin de chaque partie.
+///
+/// `on_game` reçoit le nombre de parties terminées, de 1 à `games`.
+pub fn simulate_with_progress(
+    players: &[Player; 2],
+    difficulties: [Difficulty; 2],
+    games: u32,
+    objectifs: usize,
+    rules: GameRules,
+    seed: Option<u64>,
+    on_game: &mut dyn FnMut(u32),
+) -> Result<SimulationReport, Box<dyn Error>> {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    };
+    let mut report = SimulationReport {
+        players: players.iter().map(|p| p.name.clone()).collect(),
+        games,
+        ..SimulationReport::default()
+    };
+
+    for played in 1..=games {
+        let mut game = Game::new_with_rules(players.to_vec(), objectifs, rules)?;
+        game.set_verbosity(Verbosity::Silent);
+        game.set_seed(rng.random());
+        for (index, difficulty) in difficulties.into_iter().enumerate() {
+            let bot_rng = StdRng::seed_from_u64(rng.random());
+            game.set_controller(
+                index,
+                Box::new(BotController::with_rng(difficulty, bot_rng)),
+            );
+        }
+        match game.run()? {
+            GameResult::Victory { winner } | GameResult::Forfeit { winner, .. } => {
+                report.wins[winner.index()] += 1
+            }
+            GameResult::Draw => report.draws += 1,
+            GameResult::Interrupted => {}
+        }
+        report.rounds += game.round - 1;
+        on_game(played);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie qu'une simulation avec graine est reproductible et favorise le bot difficile.
+    #[test]
+    fn test_simulate_seeded() {
+        let players = [
+            Player::new(String::from("Alice"), 40, 75, 50),
+            Player::new(String::from("Bob"), 40, 75, 50),
+        ];
+        let difficulties = [Difficulty::Hard, Difficulty::Easy];
+        let report =
+            simulate(&players, difficulties, 20, 5, GameRules::default(), Some(9)).unwrap();
+        let again = simulate(&players, difficulties, 20, 5, GameRules::default(), Some(9)).unwrap();
+        assert_eq!(report, again);
+        assert_eq!(report.wins[0] + report.wins[1] + report.draws, 20);
+        assert!(report.wins[0] > report.wins[1], "{report}");
+        assert!(report.to_string().contains("Alice : "));
+    }
+}
@@ -0,0 +1,938 @@
+//! Module des statistiques de fin de partie.
+//!
+//! [`summarize`] dérive de l'historique d'une partie ([`GameHistory`]) les statistiques de chaque
+//! joueur : écart moyen à l'objectif, arrêts parfaits, dépassements, meilleur et pire objectif, dégâts
+//! infligés et subis, manches nulles, score moyen de chaque manche, répartition des arrêts trop tôt
+//! ou trop tard ([`ErrorHistogram`]), ainsi que l'objectif phare de chaque joueur et l'objectif MVP
+//! de la partie ([`best_objective`], [`mvp_objective`]). Le même [`GameStats`] est affiché en fin de partie
+//! par le rendu et exporté avec le résultat (`--export`), de sorte que les chiffres ne peuvent
+//! diverger. Les compteurs sur lesquels reposent ces statistiques ([`StatsAccumulator`]) peuvent aussi
+//! être alimentés au fil de la partie, sans conserver tout son historique.
+//!
+//! Sur un ensemble de parties enregistrées, [`ObjectiveHeatmap`] mesure la précision des arrêts selon
+//! la valeur de l'objectif, par tranche de dix : elle indique si les objectifs extrêmes sont vraiment
+//! plus difficiles et s'affiche sous forme d'histogramme (`dual_game stats --replays <répertoire>`).
+
+use std::cmp::Reverse;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::player::PlayerId;
+use crate::render;
+use crate::replay::{
+    DamageRecord, DrawRecord, PlayerNames, Replay, RoundRecord, StopRecord, TurnRecord,
+};
+use crate::scoring::ScoringCalculator;
+
+/// Historique d'une partie, tel qu'analysé par [`summarize`].
+#[derive(Clone, Copy, Debug)]
+pub struct GameHistory<'a> {
+    /// Noms des joueurs de la partie, dans l'ordre de leurs identifiants.
+    pub names: &'a PlayerNames,
+    /// Tours joués, dans l'ordre.
+    pub turns: &'a [TurnRecord],
+    /// Dégâts infligés à la fin des manches gagnées, dans l'ordre.
+    pub damage: &'a [DamageRecord],
+    /// Manches nulles, dans l'ordre.
+    pub draws: &'a [DrawRecord],
+    /// État des joueurs à la fin de chaque manche, dans l'ordre.
+    pub rounds: &'a [RoundRecord],
+}
+
+/// Statistiques d'un joueur sur l'ensemble d'une partie.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlayerStats {
+    /// Nom du joueur.
+    pub name: String,
+    /// Écart moyen entre l'objectif et le compteur, arrondi au centième, 0 si le joueur n'a arrêté aucun
+    /// compteur.
+    pub average_diff: f64,
+    /// Nombre d'arrêts exactement sur l'objectif, sans dépassement.
+    pub perfect_stops: u32,
+    /// Nombre total de dépassements de 100 par le compteur.
+    pub misses: u32,
+    /// Arrêt au meilleur score (le premier en cas d'égalité).
+    pub best: Option<StopRecord>,
+    /// Arrêt au pire score (le premier en cas d'égalité).
+    pub worst: Option<StopRecord>,
+    /// Dégâts infligés à l'adversaire.
+    pub damage_dealt: u32,
+    /// Dégâts subis.
+    pub damage_taken: u32,
+    /// Nombre de manches nulles, absent des statistiques exportées avant leur introduction.
+    #[serde(default)]
+    pub draws: u32,
+    /// Score moyen de chaque manche jouée, dans l'ordre.
+    pub round_averages: Vec<u32>,
+    /// Répartition des écarts signés des arrêts, absente des statistiques exportées avant son
+    /// introduction.
+    #[serde(default)]
+    pub errors: ErrorHistogram,
+    /// Objectif phare du joueur, avec sa provenance (voir [`best_objective`]), absent des
+    /// statistiques exportées avant son introduction.
+    #[serde(default)]
+    pub highlight: Option<ObjectiveHighlight>,
+}
+
+/// Statistiques d'une partie, dans l'ordre des joueurs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GameStats {
+    /// Statistiques de chaque joueur.
+    pub players: Vec<PlayerStats>,
+    /// Objectif MVP de la partie (voir [`mvp_objective`]), absent des statistiques exportées avant
+    /// son introduction.
+    #[serde(default)]
+    pub mvp: Option<ObjectiveHighlight>,
+}
+
+/// Objectif remarquable d'une partie : l'arrêt du compteur, avec la manche, le joueur et sa position
+/// dans la rediffusion.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ObjectiveHighlight {
+    /// Index du joueur.
+    pub player: usize,
+    /// Numéro de la manche.
+    pub round: u32,
+    /// Index du tour parmi les tours de la partie, tels qu'enregistrés dans la rediffusion
+    /// ([`Replay::turns`]).
+    pub turn: usize,
+    /// Position de l'objectif dans le tour.
+    pub index: usize,
+    /// Arrêt du compteur sur l'objectif.
+    pub stop: StopRecord,
+}
+
+impl ObjectiveHighlight {
+    /// Retourne les objectifs des tours donnés, dans l'ordre, le premier tour ayant l'index `first`
+    /// parmi les tours de la partie.
+    fn all(turns: &[TurnRecord], first: usize) -> impl Iterator<Item = ObjectiveHighlight> + '_ {
+        turns.iter().enumerate().flat_map(move |(offset, turn)| {
+            turn.stops
+                .iter()
+                .enumerate()
+                .map(move |(index, stop)| ObjectiveHighlight {
+                    player: turn.player,
+                    round: turn.round,
+                    turn: first + offset,
+                    index,
+                    stop: *stop,
+                })
+        })
+    }
+
+    /// Retient `self` à la place de `best` s'il obtient un meilleur score. Les objectifs étant
+    /// considérés dans l'ordre de la partie, le premier l'emporte en cas d'égalité.
+    fn keep_best(self, best: &mut Option<ObjectiveHighlight>) {
+        if best.is_none_or(|best| self.stop.score > best.stop.score) {
+            *best = Some(self);
+        }
+    }
+}
+
+/// Retourne l'objectif au meilleur score du joueur d'index donné, le premier de la partie en cas
+/// d'égalité, ou `None` s'il n'a arrêté aucun compteur.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::replay::{PlayerNames, StopRecord, TurnRecord};
+/// use dual_game::stats::{GameHistory, best_objective, mvp_objective};
+///
+/// let names = PlayerNames::from(vec![String::from("Alice"), String::from("Bob")]);
+/// let stop = |counter, score| StopRecord { objective: 40, counter, miss: 0, score, retried: false };
+/// let turn = |round, player, stops| TurnRecord { round, player, stops, average: 0, risk: None };
+/// let turns = [
+///     turn(1, 0, vec![stop(45, 100), stop(40, 150)]),
+///     turn(1, 1, vec![stop(40, 150), stop(41, 140)]),
+/// ];
+/// let history = GameHistory { names: &names, turns: &turns, damage: &[], draws: &[], rounds: &[] };
+/// let best = best_objective(&history, 1).unwrap();
+/// assert_eq!((best.round, best.turn, best.index, best.stop.score), (1, 1, 0, 150));
+/// // À égalité, l'objectif d'Alice, joué plus tôt, est l'objectif MVP.
+/// assert_eq!(mvp_objective(&history).map(|mvp| (mvp.player, mvp.index)), Some((0, 1)));
+/// ```
+pub fn best_objective(history: &GameHistory, player: usize) -> Option<ObjectiveHighlight> {
+    let mut best = None;
+    ObjectiveHighlight::all(history.turns, 0)
+        .filter(|highlight| highlight.player == player)
+        .for_each(|highlight| highlight.keep_best(&mut best));
+    best
+}
+
+/// Retourne l'objectif au meilleur score de la partie, tous joueurs confondus, le premier de la
+/// partie en cas d'égalité, ou `None` si aucun compteur n'a été arrêté.
+pub fn mvp_objective(history: &GameHistory) -> Option<ObjectiveHighlight> {
+    let mut best = None;
+    ObjectiveHighlight::all(history.turns, 0).for_each(|highlight| highlight.keep_best(&mut best));
+    best
+}
+
+/// Calcule les statistiques de chaque joueur à partir de l'historique d'une partie.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::replay::{PlayerNames, StopRecord, TurnRecord};
+/// use dual_game::stats::{GameHistory, summarize};
+///
+/// let names = PlayerNames::from(vec![String::from("Alice")]);
+/// let turns = [TurnRecord {
+///     round: 1,
+///     player: 0,
+///     stops: vec![
+///         StopRecord { objective: 40, counter: 40, miss: 0, score: 150, retried: false },
+///         StopRecord { objective: 60, counter: 70, miss: 1, score: 40, retried: false },
+///     ],
+///     average: 95,
+///     risk: None,
+/// }];
+/// let history = GameHistory { names: &names, turns: &turns, damage: &[], draws: &[], rounds: &[] };
+/// let stats = summarize(&history);
+/// let alice = &stats.players[0];
+/// assert_eq!(alice.average_diff, 5.0);
+/// assert_eq!((alice.perfect_stops, alice.misses), (1, 1));
+/// assert_eq!(alice.round_averages, [95]);
+/// ```
+pub fn summarize(history: &GameHistory) -> GameStats {
+    let mut totals = StatsAccumulator::new(history.names.len());
+    totals.add_turns(history.turns);
+    totals.add_damage(history.damage);
+    totals.add_draws(history.draws);
+    totals.finish(history.names)
+}
+
+/// Compteurs cumulés d'un joueur, à partir desquels ses statistiques sont calculées.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct PlayerTotals {
+    /// Somme des écarts entre l'objectif et le compteur.
+    total_diff: u64,
+    /// Nombre d'arrêts du compteur.
+    stops: u64,
+    /// Nombre d'arrêts parfaits.
+    perfect_stops: u32,
+    /// Nombre total de dépassements.
+    misses: u32,
+    /// Arrêt au meilleur score.
+    best: Option<StopRecord>,
+    /// Arrêt au pire score.
+    worst: Option<StopRecord>,
+    /// Dégâts infligés.
+    damage_dealt: u32,
+    /// Dégâts subis.
+    damage_taken: u32,
+    /// Manches remportées.
+    wins: u32,
+    /// Manches nulles.
+    draws: u32,
+    /// Score moyen de chaque manche.
+    round_averages: Vec<u32>,
+    /// Répartition des écarts signés.
+    errors: ErrorHistogram,
+    /// Objectif phare.
+    highlight: Option<ObjectiveHighlight>,
+}
+
+/// Compteurs cumulés de chaque joueur, alimentés au fil de la partie.
+///
+/// Les statistiques d'une partie peuvent ainsi être calculées sans conserver tout son historique :
+/// seuls ces compteurs et le score moyen de chaque manche sont retenus. [`summarize`] repose sur eux,
+/// de sorte que les deux calculs ne peuvent diverger.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StatsAccumulator {
+    /// Compteurs de chaque joueur, dans l'ordre des identifiants.
+    players: Vec<PlayerTotals>,
+    /// Nombre de tours ajoutés, qui situe les suivants dans la rediffusion.
+    turns: usize,
+}
+
+impl StatsAccumulator {
+    /// Crée des compteurs nuls pour le nombre de joueurs donné.
+    pub fn new(players: usize) -> Self {
+        StatsAccumulator {
+            players: vec![PlayerTotals::default(); players],
+            turns: 0,
+        }
+    }
+
+    /// Ajoute des tours joués, dans l'ordre ; les tours d'un joueur inconnu sont ignorés.
+    pub fn add_turns(&mut self, turns: &[TurnRecord]) {
+        for highlight in ObjectiveHighlight::all(turns, self.turns) {
+            if let Some(totals) = self.players.get_mut(highlight.player) {
+                highlight.keep_best(&mut totals.highlight);
+            }
+        }
+        self.turns += turns.len();
+        for turn in turns {
+            let Some(totals) = self.players.get_mut(turn.player) else {
+                continue;
+            };
+            for stop in &turn.stops {
+                totals.total_diff +=
+                    u64::from(ScoringCalculator::difference(stop.objective, stop.counter));
+                totals.stops += 1;
+                totals.perfect_stops += u32::from(is_perfect(stop));
+                totals.misses += stop.miss;
+                totals.errors.add(ScoringCalculator::signed_difference(
+                    stop.objective,
+                    stop.counter,
+                ));
+                // Le premier arrêt l'emporte en cas d'égalité : seule une amélioration stricte le
+                // remplace.
+                if totals.best.is_none_or(|best| stop.score > best.score) {
+                    totals.best = Some(*stop);
+                }
+                if totals.worst.is_none_or(|worst| stop.score < worst.score) {
+                    totals.worst = Some(*stop);
+                }
+            }
+            totals.round_averages.push(turn.average);
+        }
+    }
+
+    /// Ajoute les dégâts infligés à la fin de manches gagnées.
+    pub fn add_damage(&mut self, damage: &[DamageRecord]) {
+        for record in damage {
+            if let Some(winner) = self.players.get_mut(record.winner) {
+                winner.damage_dealt += record.dealt;
+                winner.wins += 1;
+            }
+            if let Some(loser) = self.players.get_mut(record.loser) {
+                loser.damage_taken += record.dealt;
+            }
+        }
+    }
+
+    /// Ajoute des manches nulles, comptées pour chaque joueur.
+    pub fn add_draws(&mut self, draws: &[DrawRecord]) {
+        for totals in &mut self.players {
+            totals.draws += draws.len() as u32;
+        }
+    }
+
+    /// Retourne le nombre de manches remportées par le joueur d'index donné.
+    pub fn wins(&self, index: usize) -> u32 {
+        self.players.get(index).map_or(0, |totals| totals.wins)
+    }
+
+    /// Calcule les statistiques de chaque joueur, nommé d'après son identifiant.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::replay::{DamageRecord, PlayerNames};
+    /// use dual_game::stats::StatsAccumulator;
+    ///
+    /// let names = PlayerNames::from(vec![String::from("Alice"), String::from("Bob")]);
+    /// let mut totals = StatsAccumulator::new(2);
+    /// totals.add_damage(&[DamageRecord { round: 1, winner: 0, loser: 1, dealt: 30, time_tiebreak: false, winners_curse: false }]);
+    /// totals.add_damage(&[DamageRecord { round: 2, winner: 0, loser: 1, dealt: 12, time_tiebreak: false, winners_curse: false }]);
+    /// assert_eq!(totals.wins(0), 2);
+    /// let stats = totals.finish(&names);
+    /// assert_eq!((stats.players[0].damage_dealt, stats.players[1].damage_taken), (42, 42));
+    /// ```
+    pub fn finish(&self, names: &PlayerNames) -> GameStats {
+        let players = self
+            .players
+            .iter()
+            .enumerate()
+            .map(|(index, totals)| PlayerStats {
+                name: names.name(PlayerId(index)).unwrap_or_default().to_string(),
+                average_diff: if totals.stops == 0 {
+                    0.0
+                } else {
+                    (totals.total_diff as f64 * 100.0 / totals.stops as f64).round() / 100.0
+                },
+                perfect_stops: totals.perfect_stops,
+                misses: totals.misses,
+                best: totals.best,
+                worst: totals.worst,
+                damage_dealt: totals.damage_dealt,
+                damage_taken: totals.damage_taken,
+                draws: totals.draws,
+                round_averages: totals.round_averages.clone(),
+                errors: totals.errors,
+                highlight: totals.highlight,
+            })
+            .collect();
+        // Les objectifs phares sont ceux de joueurs différents, donc de tours différents : à score
+        // égal, le tour joué le plus tôt l'emporte.
+        let mvp = self
+            .players
+            .iter()
+            .filter_map(|totals| totals.highlight)
+            .min_by_key(|highlight| (Reverse(highlight.stop.score), highlight.turn));
+        GameStats { players, mvp }
+    }
+}
+
+/// Nombre de tranches de l'histogramme des écarts signés : une par dizaine, de -50 à 50.
+pub const ERROR_BUCKETS: usize = 10;
+
+/// Largeur, en caractères, de la plus longue barre de l'histogramme des écarts signés.
+const ERROR_HISTOGRAM_WIDTH: usize = 20;
+
+/// Répartition des écarts signés des arrêts d'un joueur (voir
+/// [`ScoringCalculator::signed_difference`]) : indique s'il arrête le compteur plutôt trop tôt ou
+/// trop tard.
+///
+/// Les écarts sont regroupés par dizaine, de -50 à -41 pour la première tranche jusqu'à 40 à 50 pour
+/// la dernière : un arrêt exact rejoint la tranche de 0 à 9.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::stats::ErrorHistogram;
+///
+/// let mut errors = ErrorHistogram::default();
+/// for error in [-12, -3, -3, 0, 7, 50] {
+///     errors.add(error);
+/// }
+/// assert_eq!((errors.early, errors.exact, errors.late), (3, 1, 2));
+/// assert_eq!(errors.buckets, [0, 0, 0, 1, 2, 2, 0, 0, 0, 1]);
+/// assert_eq!(ErrorHistogram::label(4), "-10..-1");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorHistogram {
+    /// Nombre d'arrêts de chaque tranche, de l'arrêt le plus précoce au plus tardif.
+    pub buckets: [u32; ERROR_BUCKETS],
+    /// Nombre d'arrêts avant l'objectif.
+    pub early: u32,
+    /// Nombre d'arrêts exactement sur l'objectif.
+    pub exact: u32,
+    /// Nombre d'arrêts après l'objectif.
+    pub late: u32,
+}
+
+impl ErrorHistogram {
+    /// Retourne la tranche d'un écart signé.
+    pub fn bucket(error: i32) -> usize {
+        ((error.clamp(-50, 50) + 50) / 10).min(ERROR_BUCKETS as i32 - 1) as usize
+    }
+
+    /// Retourne les bornes d'une tranche, par exemple `-10..-1` ou `40..50`.
+    pub fn label(bucket: usize) -> String {
+        let low = bucket as i32 * 10 - 50;
+        let high = if bucket + 1 == ERROR_BUCKETS {
+            50
+        } else {
+            low + 9
+        };
+        format!("{low}..{high}")
+    }
+
+    /// Ajoute l'écart signé d'un arrêt.
+    pub fn add(&mut self, error: i32) {
+        self.buckets[Self::bucket(error)] += 1;
+        match error.signum() {
+            -1 => self.early += 1,
+            0 => self.exact += 1,
+            _ => self.late += 1,
+        }
+    }
+
+    /// Retourne le nombre d'arrêts pris en compte.
+    pub fn stops(&self) -> u32 {
+        self.buckets.iter().sum()
+    }
+}
+
+impl fmt::Display for ErrorHistogram {
+    /// Affiche une ligne par tranche : ses bornes, une barre à l'échelle de la tranche la plus fournie
+    /// et son nombre d'arrêts.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let highest = self.buckets.iter().copied().max().unwrap_or(0);
+        for (bucket, &count) in self.buckets.iter().enumerate() {
+            if bucket > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{:>8}  {}  {count}",
+                Self::label(bucket),
+                render::histogram_bar(f64::from(count), f64::from(highest), ERROR_HISTOGRAM_WIDTH)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Nombre de tranches d'objectifs de la carte de précision : une par dizaine, 100 rejoignant la
+/// dernière.
+pub const OBJECTIVE_DECILES: usize = 10;
+
+/// Largeur, en caractères, de la plus longue barre de l'histogramme de précision.
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Précision cumulée des arrêts sur les objectifs d'une tranche.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DecileTotals {
+    /// Nombre d'arrêts du compteur.
+    pub stops: u64,
+    /// Somme des écarts entre l'objectif et le compteur.
+    pub total_diff: u64,
+    /// Nombre d'arrêts après au moins un dépassement de 100.
+    pub missed: u64,
+}
+
+impl DecileTotals {
+    /// Retourne l'écart moyen à l'objectif, ou `None` sans arrêt dans la tranche.
+    pub fn average_diff(&self) -> Option<f64> {
+        (self.stops > 0).then(|| self.total_diff as f64 / self.stops as f64)
+    }
+
+    /// Retourne la part des arrêts après au moins un dépassement, en pourcentage, ou `None` sans
+    /// arrêt dans la tranche.
+    pub fn miss_rate(&self) -> Option<f64> {
+        (self.stops > 0).then(|| self.missed as f64 * 100.0 / self.stops as f64)
+    }
+}
+
+/// Précision des arrêts selon la valeur de l'objectif, cumulée sur des parties enregistrées.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::replay::{StopRecord, TurnRecord};
+/// use dual_game::stats::ObjectiveHeatmap;
+///
+/// let mut heatmap = ObjectiveHeatmap::default();
+/// heatmap.add_turns(&[TurnRecord {
+///     round: 1,
+///     player: 0,
+///     stops: vec![
+///         StopRecord { objective: 3, counter: 7, miss: 0, score: 110, retried: false },
+///         StopRecord { objective: 100, counter: 2, miss: 1, score: 60, retried: false },
+///     ],
+///     average: 85,
+///     risk: None,
+/// }]);
+/// assert_eq!(heatmap.deciles[0].average_diff(), Some(4.0));
+/// assert_eq!(heatmap.deciles[9].miss_rate(), Some(100.0));
+/// assert_eq!(heatmap.deciles[5].average_diff(), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectiveHeatmap {
+    /// Nombre de parties prises en compte.
+    pub games: u32,
+    /// Précision de chaque tranche, des objectifs 0 à 9 jusqu'aux objectifs 90 à 100.
+    pub deciles: [DecileTotals; OBJECTIVE_DECILES],
+}
+
+impl ObjectiveHeatmap {
+    /// Retourne la tranche d'un objectif.
+    pub fn decile(objective: u32) -> usize {
+        (objective as usize / 10).min(OBJECTIVE_DECILES - 1)
+    }
+
+    /// Ajoute les arrêts de tours joués.
+    pub fn add_turns(&mut self, turns: &[TurnRecord]) {
+        for stop in turns.iter().flat_map(|turn| &turn.stops) {
+            let totals = &mut self.deciles[Self::decile(stop.objective)];
+            totals.stops += 1;
+            totals.total_diff +=
+                u64::from(ScoringCalculator::difference(stop.objective, stop.counter));
+            totals.missed += u64::from(stop.miss > 0);
+        }
+    }
+
+    /// Ajoute les arrêts de tous les tours d'une partie enregistrée.
+    pub fn add_replay(&mut self, replay: &Replay) {
+        self.games += 1;
+        self.add_turns(&replay.turns);
+    }
+
+    /// Retourne le nombre total d'arrêts pris en compte.
+    pub fn stops(&self) -> u64 {
+        self.deciles.iter().map(|totals| totals.stops).sum()
+    }
+}
+
+impl fmt::Display for ObjectiveHeatmap {
+    /// Affiche l'histogramme de l'écart moyen par tranche d'objectifs : la barre de la tranche la
+    /// moins précise occupe toute la largeur, les autres sont à l'échelle.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Précision par objectif ({} parties, {} arrêts)",
+            self.games,
+            self.stops()
+        )?;
+        let widest = self
+            .deciles
+            .iter()
+            .filter_map(DecileTotals::average_diff)
+            .fold(0.0, f64::max);
+        for (index, totals) in self.deciles.iter().enumerate() {
+            let low = index * 10;
+            let high = if index + 1 == OBJECTIVE_DECILES {
+                100
+            } else {
+                low + 9
+            };
+            let label = format!("{low}-{high}");
+            match (totals.average_diff(), totals.miss_rate()) {
+                (Some(average), Some(miss_rate)) => {
+                    write!(
+                        f,
+                        "\n{label:>6}  {}  écart {average:>5.2}  miss {miss_rate:>3.0} %",
+                        render::histogram_bar(average, widest, HISTOGRAM_WIDTH)
+                    )?;
+                }
+                _ => write!(f, "\n{label:>6}  {:<HISTOGRAM_WIDTH$}  aucun arrêt", "")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Indique si le compteur a été arrêté exactement sur l'objectif, sans dépassement.
+pub(crate) fn is_perfect(stop: &StopRecord) -> bool {
+    stop.miss == 0 && stop.counter == stop.objective
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Construit un arrêt du compteur.
+    fn stop(objective: u32, counter: u32, miss: u32, score: u32) -> StopRecord {
+        StopRecord {
+            objective,
+            counter,
+            miss,
+            score,
+            retried: false,
+        }
+    }
+
+    /// Construit le tour d'un joueur.
+    fn turn(round: u32, player: usize, stops: Vec<StopRecord>, average: u32) -> TurnRecord {
+        TurnRecord {
+            round,
+            player,
+            stops,
+            average,
+            risk: None,
+        }
+    }
+
+    /// Construit des dégâts infligés lors d'une manche.
+    fn damage(round: u32, winner: usize, loser: usize, dealt: u32) -> DamageRecord {
+        DamageRecord {
+            round,
+            winner,
+            loser,
+            dealt,
+            time_tiebreak: false,
+            winners_curse: false,
+        }
+    }
+
+    /// Noms des joueurs des tests.
+    fn names() -> PlayerNames {
+        PlayerNames::from(vec![String::from("Alice"), String::from("Bob")])
+    }
+
+    /// Vérifie l'écart moyen, qui tient compte du passage de 100 à 0, ainsi que les arrêts parfaits et
+    /// les dépassements, propres à chaque joueur.
+    #[test]
+    fn test_summarize_precision() {
+        let names = names();
+        let turns = [
+            turn(1, 0, vec![stop(50, 50, 0, 150), stop(2, 99, 0, 100)], 125),
+            turn(1, 1, vec![stop(30, 30, 2, 50), stop(30, 40, 1, 40)], 45),
+            turn(2, 0, vec![stop(70, 70, 0, 150), stop(10, 20, 0, 80)], 115),
+        ];
+        let history = GameHistory {
+            names: &names,
+            turns: &turns,
+            damage: &[],
+            draws: &[],
+            rounds: &[],
+        };
+        let stats = summarize(&history);
+        let (alice, bob) = (&stats.players[0], &stats.players[1]);
+        // Écarts d'Alice : 0, 3 (de 99 à 2 en passant par 100 et 0), 0 et 10.
+        assert_eq!(alice.average_diff, 3.25);
+        assert_eq!((alice.perfect_stops, alice.misses), (2, 0));
+        // Un arrêt sur l'objectif après un dépassement n'est pas parfait.
+        assert_eq!(bob.average_diff, 5.0);
+        assert_eq!((bob.perfect_stops, bob.misses), (0, 3));
+
+        // L'écart moyen est arrondi au centième.
+        let stops = vec![
+            stop(50, 51, 0, 140),
+            stop(50, 50, 0, 150),
+            stop(50, 50, 0, 150),
+        ];
+        let turns = [turn(1, 0, stops, 146)];
+        let history = GameHistory {
+            names: &names,
+            turns: &turns,
+            damage: &[],
+            draws: &[],
+            rounds: &[],
+        };
+        assert_eq!(summarize(&history).players[0].average_diff, 0.33);
+    }
+
+    /// Vérifie le meilleur et le pire objectif, le premier arrêt l'emportant en cas d'égalité.
+    #[test]
+    fn test_summarize_best_worst() {
+        let names = names();
+        let turns = [
+            turn(1, 0, vec![stop(10, 12, 0, 140), stop(20, 60, 0, 60)], 100),
+            turn(2, 0, vec![stop(30, 32, 0, 140), stop(40, 80, 0, 60)], 100),
+        ];
+        let history = GameHistory {
+            names: &names,
+            turns: &turns,
+            damage: &[],
+            draws: &[],
+            rounds: &[],
+        };
+        let alice = &summarize(&history).players[0];
+        assert_eq!(alice.best, Some(stop(10, 12, 0, 140)));
+        assert_eq!(alice.worst, Some(stop(20, 60, 0, 60)));
+    }
+
+    /// Vérifie l'objectif phare de chaque joueur, avec sa manche et sa position dans la rediffusion :
+    /// le premier l'emporte en cas d'égalité, et un joueur sans arrêt n'en a pas.
+    #[test]
+    fn test_best_objective() {
+        let names = names();
+        let turns = [
+            turn(1, 0, vec![stop(10, 12, 0, 140), stop(20, 60, 0, 60)], 100),
+            turn(1, 1, vec![stop(50, 50, 0, 150)], 150),
+            turn(2, 0, vec![stop(30, 32, 0, 140), stop(40, 41, 0, 145)], 142),
+            turn(2, 1, vec![stop(70, 70, 0, 150)], 150),
+        ];
+        let history = GameHistory {
+            names: &names,
+            turns: &turns,
+            damage: &[],
+            draws: &[],
+            rounds: &[],
+        };
+        assert_eq!(
+            best_objective(&history, 0),
+            Some(ObjectiveHighlight {
+                player: 0,
+                round: 2,
+                turn: 2,
+                index: 1,
+                stop: stop(40, 41, 0, 145),
+            })
+        );
+        let bob = best_objective(&history, 1).unwrap();
+        assert_eq!((bob.round, bob.turn, bob.index), (1, 1, 0));
+        assert_eq!(best_objective(&history, 2), None);
+        let empty = GameHistory {
+            turns: &[],
+            ..history
+        };
+        assert_eq!(best_objective(&empty, 0), None);
+    }
+
+    /// Vérifie l'objectif MVP de la partie, le premier joué l'emportant à égalité entre joueurs, et
+    /// que les statistiques cumulées en plusieurs fois le situent dans toute la partie.
+    #[test]
+    fn test_mvp_objective() {
+        let names = names();
+        let turns = [
+            turn(1, 0, vec![stop(10, 12, 0, 140)], 140),
+            turn(1, 1, vec![stop(50, 55, 0, 120)], 120),
+            turn(2, 0, vec![stop(30, 35, 0, 120)], 120),
+            turn(2, 1, vec![stop(60, 62, 0, 140), stop(40, 40, 0, 140)], 140),
+        ];
+        let history = GameHistory {
+            names: &names,
+            turns: &turns,
+            damage: &[],
+            draws: &[],
+            rounds: &[],
+        };
+        let mvp = mvp_objective(&history).unwrap();
+        assert_eq!((mvp.player, mvp.round, mvp.turn, mvp.index), (0, 1, 0, 0));
+        assert_eq!(summarize(&history).mvp, Some(mvp));
+        assert_eq!(
+            mvp_objective(&GameHistory {
+                turns: &[],
+                ..history
+            }),
+            None
+        );
+
+        // Sans le premier tour, l'objectif de Bob l'emporte : les tours ajoutés en deux fois sont
+        // situés d'après leur index dans la partie.
+        let mut totals = StatsAccumulator::new(2);
+        totals.add_turns(&turns[1..2]);
+        totals.add_turns(&turns[2..]);
+        let stats = totals.finish(&names);
+        let mvp = stats.mvp.unwrap();
+        assert_eq!((mvp.player, mvp.round, mvp.turn, mvp.index), (1, 2, 2, 0));
+        assert_eq!(stats.players[0].highlight.unwrap().turn, 1);
+    }
+
+    /// Vérifie le cumul des dégâts infligés et subis par chaque joueur.
+    #[test]
+    fn test_summarize_damage() {
+        let names = names();
+        let damage = [damage(1, 0, 1, 30), damage(2, 1, 0, 12), damage(3, 0, 1, 8)];
+        let history = GameHistory {
+            names: &names,
+            turns: &[],
+            damage: &damage,
+            draws: &[],
+            rounds: &[],
+        };
+        let stats = summarize(&history);
+        assert_eq!(
+            (stats.players[0].damage_dealt, stats.players[0].damage_taken),
+            (38, 12)
+        );
+        assert_eq!(
+            (stats.players[1].damage_dealt, stats.players[1].damage_taken),
+            (12, 38)
+        );
+    }
+
+    /// Vérifie le score moyen de chaque manche, dans l'ordre des manches.
+    #[test]
+    fn test_summarize_round_averages() {
+        let names = names();
+        let turns = [
+            turn(1, 0, vec![stop(50, 50, 0, 150)], 150),
+            turn(1, 1, vec![stop(50, 60, 0, 90)], 90),
+            turn(2, 1, vec![stop(50, 55, 0, 120)], 120),
+            turn(2, 0, vec![stop(50, 80, 0, 40)], 40),
+        ];
+        let history = GameHistory {
+            names: &names,
+            turns: &turns,
+            damage: &[],
+            draws: &[],
+            rounds: &[],
+        };
+        let stats = summarize(&history);
+        assert_eq!(stats.players[0].round_averages, [150, 40]);
+        assert_eq!(stats.players[1].round_averages, [90, 120]);
+    }
+
+    /// Vérifie la répartition des écarts signés de chaque joueur, passage par 100 compris, et son
+    /// histogramme.
+    #[test]
+    fn test_summarize_errors() {
+        let names = names();
+        let turns = [
+            turn(1, 0, vec![stop(50, 45, 0, 100), stop(15, 95, 0, 60)], 80),
+            turn(1, 1, vec![stop(95, 5, 1, 60), stop(30, 30, 0, 150)], 105),
+            turn(2, 0, vec![stop(50, 48, 0, 110)], 110),
+        ];
+        let history = GameHistory {
+            names: &names,
+            turns: &turns,
+            damage: &[],
+            draws: &[],
+            rounds: &[],
+        };
+        let stats = summarize(&history);
+        let alice = stats.players[0].errors;
+        assert_eq!((alice.early, alice.exact, alice.late), (3, 0, 0));
+        assert_eq!(alice.buckets, [0, 0, 0, 1, 2, 0, 0, 0, 0, 0]);
+        let bob = stats.players[1].errors;
+        assert_eq!((bob.early, bob.exact, bob.late), (0, 1, 1));
+        assert_eq!(bob.buckets, [0, 0, 0, 0, 0, 1, 1, 0, 0, 0]);
+
+        let lines: Vec<String> = alice.to_string().lines().map(String::from).collect();
+        assert_eq!(lines.len(), ERROR_BUCKETS);
+        assert_eq!(lines[0], "-50..-41                        0");
+        assert_eq!(lines[3], "-20..-11  ##########            1");
+        assert_eq!(lines[4], " -10..-1  ####################  2");
+        assert_eq!(lines[9], "  40..50                        0");
+    }
+
+    /// Vérifie les statistiques d'une partie terminée avant le premier arrêt du compteur.
+    #[test]
+    fn test_summarize_empty() {
+        let names = names();
+        let history = GameHistory {
+            names: &names,
+            turns: &[],
+            damage: &[],
+            draws: &[],
+            rounds: &[],
+        };
+        let stats = summarize(&history);
+        assert_eq!(stats.players.len(), 2);
+        let alice = &stats.players[0];
+        assert_eq!(alice.name, "Alice");
+        assert_eq!(alice.average_diff, 0.0);
+        assert_eq!((alice.best, alice.worst), (None, None));
+        assert!(alice.round_averages.is_empty());
+    }
+
+    /// Vérifie la carte de précision des deux rediffusions de test, l'une au format d'origine, l'autre
+    /// au format JSON Lines actuel, ainsi que son histogramme.
+    #[test]
+    fn test_objective_heatmap_fixtures() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/replays");
+        let mut heatmap = ObjectiveHeatmap::default();
+        for replay in Replay::load_dir(&dir).unwrap() {
+            heatmap.add_replay(&replay.unwrap());
+        }
+        assert_eq!((heatmap.games, heatmap.stops()), (2, 8));
+        // Objectif 5 : écarts 0 et 10.
+        assert_eq!(
+            heatmap.deciles[0],
+            DecileTotals {
+                stops: 2,
+                total_diff: 10,
+                missed: 0
+            }
+        );
+        // Objectifs 50 et 52 : écarts 0 et 12.
+        assert_eq!(heatmap.deciles[5].average_diff(), Some(6.0));
+        // Objectifs 95 et 100 : écarts 5, 8 (de 3 à 95 en passant par 100 et 0), 2 et 1, deux
+        // arrêts après un dépassement.
+        assert_eq!(heatmap.deciles[9].average_diff(), Some(4.0));
+        assert_eq!(heatmap.deciles[9].miss_rate(), Some(50.0));
+        assert_eq!(heatmap.deciles[3].stops, 0);
+
+        let text = heatmap.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 1 + OBJECTIVE_DECILES);
+        assert_eq!(lines[0], "Précision par objectif (2 parties, 8 arrêts)");
+        assert_eq!(
+            lines[1],
+            format!("   0-9  {:<40}  écart  5.00  miss   0 %", "#".repeat(33))
+        );
+        assert_eq!(
+            lines[6],
+            format!(" 50-59  {}  écart  6.00  miss   0 %", "#".repeat(40))
+        );
+        assert_eq!(lines[4], format!(" 30-39  {:<40}  aucun arrêt", ""));
+        assert_eq!(
+            lines[10],
+            format!("90-100  {:<40}  écart  4.00  miss  50 %", "#".repeat(27))
+        );
+    }
+
+    /// Vérifie l'aller-retour JSON des statistiques.
+    #[test]
+    fn test_stats_round_trip() {
+        let names = names();
+        let turns = [turn(1, 0, vec![stop(50, 48, 0, 140)], 140)];
+        let damage = [damage(1, 0, 1, 20)];
+        let stats = summarize(&GameHistory {
+            names: &names,
+            turns: &turns,
+            damage: &damage,
+            draws: &[],
+            rounds: &[],
+        });
+        let json = serde_json::to_string(&stats).unwrap();
+        assert_eq!(serde_json::from_str::<GameStats>(&json).unwrap(), stats);
+    }
+}
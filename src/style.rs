@@ -0,0 +1,625 @@
+//! Module de mise en forme du texte affiché dans le terminal.
+//!
+//! Ce module fournit l'énumération [`Color`] et des fonctions enveloppant du texte dans les
+//! séquences d'échappement ANSI correspondantes. La couleur peut être désactivée globalement
+//! (option `--no-color` ou variable d'environnement `NO_COLOR`), de même que les caractères non ASCII
+//! (option `--ascii`) pour les terminaux rudimentaires.
+//!
+//! Aucun affichage ne choisit lui-même sa couleur : il nomme le rôle de son texte ([`Role`] : nom d'un
+//! joueur, niveau d'une barre de vie, titre…), que le [`Theme`] retenu (option `--theme` ou clé
+//! `theme` de la section `[ui]`) traduit en [`TextStyle`]. Outre le thème par défaut, un thème à fort
+//! contraste remplace les teintes par le gras, le soulignement et la vidéo inverse, et un thème pour la
+//! deutéranopie évite d'opposer le rouge au vert.
+//!
+//! Lorsque la sortie standard n'est pas un terminal (`dual_game | tee partie.log`), l'affichage se
+//! dégrade : le compteur n'affiche que sa valeur finale, et les séquences ANSI comme les retours
+//! chariot sont retirés du reste de l'affichage (voir [`tty_requested`]). Dans un terminal, sa largeur
+//! est relevée au démarrage, puis à chaque manche et à chaque compteur (voir [`refresh_terminal_width`]),
+//! pour y ajuster les lignes qui ne doivent pas déborder.
+//!
+//! Le mode accessible (`--accessible`, voir [`set_accessible`]) s'adresse aux lecteurs d'écran : il
+//! implique cet affichage dégradé sans couleurs et limité à l'ASCII, et le compteur y annonce son état
+//! par des phrases complètes, chacune sur sa propre ligne, à l'intervalle choisi par
+//! [`set_announce_interval`].
+
+use std::env;
+use std::fmt;
+use std::io::{IsTerminal, stdout};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Indique si les couleurs sont activées pour l'ensemble de l'affichage.
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Indique si l'affichage doit se limiter aux caractères ASCII.
+static ASCII: AtomicBool = AtomicBool::new(false);
+
+/// Indique si la sortie standard est un terminal, capable d'animer le compteur sur place.
+static TTY: AtomicBool = AtomicBool::new(true);
+
+/// Largeur du terminal en colonnes, ou 0 si elle est inconnue.
+static WIDTH: AtomicUsize = AtomicUsize::new(0);
+
+/// Thème retenu pour l'ensemble de l'affichage, par sa place dans [`Theme::ALL`].
+static THEME: AtomicU8 = AtomicU8::new(0);
+
+/// Indique si l'affichage s'adresse à un lecteur d'écran.
+static ACCESSIBLE: AtomicBool = AtomicBool::new(false);
+
+/// Intervalle entre deux annonces de la valeur du compteur en mode accessible, en millisecondes, ou
+/// 0 pour n'annoncer que la zone chaude.
+static ANNOUNCE_INTERVAL: AtomicU64 = AtomicU64::new(DEFAULT_ANNOUNCE_INTERVAL.as_millis() as u64);
+
+/// Intervalle par défaut entre deux annonces de la valeur du compteur en mode accessible.
+pub const DEFAULT_ANNOUNCE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Séquence ANSI réinitialisant la mise en forme.
+const RESET: &str = "\x1b[0m";
+
+/// Couleurs de terminal disponibles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    /// Liste de toutes les couleurs disponibles.
+    pub const ALL: [Color; 6] = [
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+    ];
+
+    /// Retourne l'identifiant de la couleur utilisé en ligne de commande.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Yellow => "yellow",
+            Color::Blue => "blue",
+            Color::Magenta => "magenta",
+            Color::Cyan => "cyan",
+        }
+    }
+
+    /// Retourne l'index de la couleur dans la palette du terminal.
+    fn index(&self) -> u8 {
+        match self {
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    /// Analyse une couleur à partir de son identifiant (insensible à la casse).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s.trim().to_lowercase();
+        Color::ALL
+            .into_iter()
+            .find(|color| color.id() == id)
+            .ok_or_else(|| {
+                let ids: Vec<&str> = Color::ALL.iter().map(|c| c.id()).collect();
+                format!(
+                    "couleur inconnue « {s} » (valeurs possibles : {})",
+                    ids.join(", ")
+                )
+            })
+    }
+}
+
+/// Rôle d'un texte mis en forme, traduit en style par chaque [`Theme`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// Nom d'un joueur de la couleur donnée.
+    Player(Color),
+    /// Barre de vie remplie à plus de 50 %.
+    HealthHigh,
+    /// Barre de vie remplie à plus de 25 %, et au plus à 50 %.
+    HealthMedium,
+    /// Barre de vie remplie à 25 % ou moins.
+    HealthLow,
+    /// Titre (début de la partie ou d'une manche, statistiques).
+    Title,
+    /// Mise en évidence d'un effet néfaste, comme la caractéristique réduite par un poison.
+    Alert,
+    /// Jauge du compteur de l'interface plein écran.
+    Gauge,
+}
+
+impl Role {
+    /// Liste de tous les rôles.
+    pub const ALL: [Role; 12] = [
+        Role::Player(Color::Red),
+        Role::Player(Color::Green),
+        Role::Player(Color::Yellow),
+        Role::Player(Color::Blue),
+        Role::Player(Color::Magenta),
+        Role::Player(Color::Cyan),
+        Role::HealthHigh,
+        Role::HealthMedium,
+        Role::HealthLow,
+        Role::Title,
+        Role::Alert,
+        Role::Gauge,
+    ];
+}
+
+/// Mise en forme d'un texte : couleur et attributs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TextStyle {
+    /// Couleur du texte, par son index dans la palette de 256 couleurs du terminal.
+    pub color: Option<u8>,
+    /// Texte en gras.
+    pub bold: bool,
+    /// Texte souligné.
+    pub underline: bool,
+    /// Vidéo inverse : couleurs du texte et du fond échangées.
+    pub reverse: bool,
+}
+
+impl TextStyle {
+    /// Style sans couleur ni attribut.
+    const PLAIN: TextStyle = TextStyle {
+        color: None,
+        bold: false,
+        underline: false,
+        reverse: false,
+    };
+
+    /// Style de la couleur donnée, sans attribut.
+    const fn fg(color: u8) -> Self {
+        TextStyle {
+            color: Some(color),
+            ..TextStyle::PLAIN
+        }
+    }
+
+    /// Ajoute le gras.
+    const fn bold(self) -> Self {
+        TextStyle { bold: true, ..self }
+    }
+
+    /// Ajoute le soulignement.
+    const fn underline(self) -> Self {
+        TextStyle {
+            underline: true,
+            ..self
+        }
+    }
+
+    /// Ajoute la vidéo inverse.
+    const fn reverse(self) -> Self {
+        TextStyle {
+            reverse: true,
+            ..self
+        }
+    }
+
+    /// Indique si le style ne change rien au texte.
+    pub fn is_plain(&self) -> bool {
+        *self == TextStyle::PLAIN
+    }
+
+    /// Retourne les paramètres de la séquence ANSI du style, par exemple `1;36` : les huit premières
+    /// couleurs de la palette gardent leur code habituel, les autres passent par `38;5`.
+    fn sgr(&self) -> String {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push(String::from("1"));
+        }
+        if self.underline {
+            codes.push(String::from("4"));
+        }
+        if self.reverse {
+            codes.push(String::from("7"));
+        }
+        match self.color {
+            Some(color) if color < 8 => codes.push(format!("3{color}")),
+            Some(color) => codes.push(format!("38;5;{color}")),
+            None => {}
+        }
+        codes.join(";")
+    }
+}
+
+/// Thème de l'affichage, traduisant chaque [`Role`] en [`TextStyle`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+    /// Couleurs habituelles : barres de vie vertes, jaunes puis rouges.
+    #[default]
+    Default,
+    /// Fort contraste : gras, soulignement et vidéo inverse au lieu des teintes.
+    HighContrast,
+    /// Palette distinguable en cas de deutéranopie : bleu, jaune et orange au lieu du vert et du rouge.
+    Deuteranopia,
+}
+
+impl Theme {
+    /// Liste de tous les thèmes.
+    pub const ALL: [Theme; 3] = [Theme::Default, Theme::HighContrast, Theme::Deuteranopia];
+
+    /// Retourne l'identifiant du thème utilisé en ligne de commande et dans la configuration.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Theme::Default => "default",
+            Theme::HighContrast => "high-contrast",
+            Theme::Deuteranopia => "deuteranopia",
+        }
+    }
+
+    /// Retourne le style d'un rôle dans ce thème.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::style::{Role, Theme};
+    ///
+    /// assert_eq!(Theme::Default.style(Role::HealthLow).color, Some(1));
+    /// assert_eq!(Theme::HighContrast.style(Role::HealthLow).color, None);
+    /// ```
+    pub fn style(&self, role: Role) -> TextStyle {
+        match self {
+            Theme::Default => match role {
+                Role::Player(color) => TextStyle::fg(color.index()),
+                Role::HealthHigh => TextStyle::fg(Color::Green.index()),
+                Role::HealthMedium | Role::Title | Role::Gauge => {
+                    TextStyle::fg(Color::Yellow.index())
+                }
+                Role::HealthLow | Role::Alert => TextStyle::fg(Color::Red.index()),
+            },
+            Theme::HighContrast => match role {
+                Role::Player(_) | Role::HealthHigh | Role::Gauge => TextStyle::PLAIN.bold(),
+                Role::HealthMedium => TextStyle::PLAIN.underline(),
+                Role::HealthLow => TextStyle::PLAIN.bold().underline(),
+                Role::Title | Role::Alert => TextStyle::PLAIN.bold().reverse(),
+            },
+            // Couleurs de la palette d'Okabe et Ito, ramenées aux plus proches des 256 couleurs.
+            Theme::Deuteranopia => match role {
+                Role::Player(Color::Red) => TextStyle::fg(208),
+                Role::Player(Color::Green) => TextStyle::fg(36),
+                Role::Player(Color::Yellow) => TextStyle::fg(227),
+                Role::Player(Color::Blue) => TextStyle::fg(33),
+                Role::Player(Color::Magenta) => TextStyle::fg(175),
+                Role::Player(Color::Cyan) => TextStyle::fg(117),
+                Role::HealthHigh => TextStyle::fg(33),
+                Role::HealthMedium | Role::Title | Role::Gauge => TextStyle::fg(227),
+                Role::HealthLow | Role::Alert => TextStyle::fg(202).bold(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    /// Analyse un thème à partir de son identifiant (insensible à la casse).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s.trim().to_lowercase();
+        Theme::ALL
+            .into_iter()
+            .find(|theme| theme.id() == id)
+            .ok_or_else(|| {
+                let ids: Vec<&str> = Theme::ALL.iter().map(|t| t.id()).collect();
+                format!(
+                    "thème inconnu « {s} » (valeurs possibles : {})",
+                    ids.join(", ")
+                )
+            })
+    }
+}
+
+/// Choisit le thème de l'ensemble de l'affichage.
+pub fn set_theme(theme: Theme) {
+    let index = Theme::ALL.iter().position(|t| *t == theme).unwrap_or(0);
+    THEME.store(index as u8, Ordering::Relaxed);
+}
+
+/// Retourne le thème retenu.
+pub fn theme() -> Theme {
+    Theme::ALL[usize::from(THEME.load(Ordering::Relaxed))]
+}
+
+/// Active ou désactive les couleurs pour l'ensemble de l'affichage.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Indique si les couleurs sont activées.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Limite ou non l'affichage aux caractères ASCII (avatars masqués, barres de vie en `#` et `-`).
+pub fn set_ascii(ascii: bool) {
+    ASCII.store(ascii, Ordering::Relaxed);
+}
+
+/// Indique si l'affichage est limité aux caractères ASCII.
+pub fn is_ascii() -> bool {
+    ASCII.load(Ordering::Relaxed)
+}
+
+/// Indique que la sortie standard est un terminal ou non.
+pub fn set_tty(tty: bool) {
+    TTY.store(tty, Ordering::Relaxed);
+}
+
+/// Indique si la sortie standard est un terminal.
+pub fn is_tty() -> bool {
+    TTY.load(Ordering::Relaxed)
+}
+
+/// Active ou désactive le mode accessible, destiné aux lecteurs d'écran.
+pub fn set_accessible(accessible: bool) {
+    ACCESSIBLE.store(accessible, Ordering::Relaxed);
+}
+
+/// Indique si l'affichage s'adresse à un lecteur d'écran.
+pub fn is_accessible() -> bool {
+    ACCESSIBLE.load(Ordering::Relaxed)
+}
+
+/// Fixe l'intervalle entre deux annonces de la valeur du compteur en mode accessible ; `None` (ou une
+/// durée nulle) limite les annonces à l'entrée dans la zone chaude.
+pub fn set_announce_interval(interval: Option<Duration>) {
+    let millis = interval.map_or(0, |interval| interval.as_millis() as u64);
+    ANNOUNCE_INTERVAL.store(millis, Ordering::Relaxed);
+}
+
+/// Retourne l'intervalle entre deux annonces de la valeur du compteur, `None` si seule la zone chaude
+/// est annoncée.
+pub fn announce_interval() -> Option<Duration> {
+    match ANNOUNCE_INTERVAL.load(Ordering::Relaxed) {
+        0 => None,
+        millis => Some(Duration::from_millis(millis)),
+    }
+}
+
+/// Détermine si la sortie standard doit être traitée comme un terminal, d'après les options
+/// `--force-tty` et `--no-tty`, ou à défaut d'après la sortie standard elle-même.
+///
+/// # Arguments
+///
+/// * `force_tty` - Indique si l'option `--force-tty` a été passée.
+/// * `no_tty` - Indique si l'option `--no-tty` a été passée.
+pub fn tty_requested(force_tty: bool, no_tty: bool) -> bool {
+    force_tty || (!no_tty && stdout().is_terminal())
+}
+
+/// Retourne la largeur du terminal en colonnes, ou `None` si elle est inconnue (sortie redirigée,
+/// moteur sans terminal).
+pub fn terminal_width() -> Option<usize> {
+    match WIDTH.load(Ordering::Relaxed) {
+        0 => None,
+        width => Some(width),
+    }
+}
+
+/// Fixe la largeur du terminal, ou l'oublie avec `None`.
+pub fn set_terminal_width(width: Option<usize>) {
+    WIDTH.store(width.unwrap_or(0), Ordering::Relaxed);
+}
+
+/// Relève la largeur actuelle du terminal, qui a pu être redimensionné.
+///
+/// La largeur n'est connue que si la sortie standard est un terminal (voir [`is_tty`]) et avec la
+/// fonctionnalité `cli` ; sinon, la largeur retenue est inchangée.
+pub fn refresh_terminal_width() {
+    #[cfg(feature = "cli")]
+    if is_tty()
+        && let Ok((columns, _)) = crossterm::terminal::size()
+        && columns > 0
+    {
+        set_terminal_width(Some(usize::from(columns)));
+    }
+}
+
+/// Retire d'un texte les séquences d'échappement ANSI et les retours chariot, qui n'ont de sens que
+/// dans un terminal.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::style::strip_controls;
+///
+/// assert_eq!(strip_controls("\r\x1b[36mAlice\x1b[0m gagne\r\n"), "Alice gagne\n");
+/// ```
+pub fn strip_controls(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            // Une séquence CSI se termine par une lettre.
+            '\x1b' => {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+            '\r' => {}
+            c => plain.push(c),
+        }
+    }
+    plain
+}
+
+/// Détermine si les couleurs doivent être activées d'après l'option `--no-color` et la variable
+/// d'environnement `NO_COLOR` (voir <https://no-color.org>).
+///
+/// # Arguments
+///
+/// * `no_color` - Indique si l'option `--no-color` a été passée.
+pub fn colors_requested(no_color: bool) -> bool {
+    !no_color && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+/// Colore le nom d'un joueur si les couleurs sont activées globalement (voir [`set_enabled`]).
+///
+/// # Arguments
+///
+/// * `text` - Le texte à colorer.
+/// * `color` - La couleur du joueur, traduite par le thème retenu ; `None` laisse le texte inchangé.
+pub fn paint(text: &str, color: Option<Color>) -> String {
+    paint_with(text, color, is_enabled())
+}
+
+/// Colore le nom d'un joueur si `enabled` est vrai.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::style::{Color, paint_with};
+///
+/// assert_eq!(paint_with("Alice", Some(Color::Cyan), true), "\x1b[36mAlice\x1b[0m");
+/// assert_eq!(paint_with("Alice", Some(Color::Cyan), false), "Alice");
+/// ```
+pub fn paint_with(text: &str, color: Option<Color>, enabled: bool) -> String {
+    match color {
+        Some(color) => paint_role_with(text, Role::Player(color), enabled),
+        None => text.to_string(),
+    }
+}
+
+/// Met en forme le texte selon son rôle dans le thème retenu, si les couleurs sont activées
+/// globalement.
+pub fn paint_role(text: &str, role: Role) -> String {
+    paint_role_with(text, role, is_enabled())
+}
+
+/// Met en forme le texte selon son rôle dans le thème retenu, si `enabled` est vrai.
+pub fn paint_role_with(text: &str, role: Role, enabled: bool) -> String {
+    if enabled {
+        paint_style(text, theme().style(role))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Entoure le texte de la séquence ANSI du style donné. Un texte vide n'est jamais entouré de
+/// séquences d'échappement, pas plus qu'un texte sans style.
+///
+/// # Exemples
+///
+/// ```
+/// use dual_game::style::{Role, Theme, paint_style};
+///
+/// let style = Theme::HighContrast.style(Role::Title);
+/// assert_eq!(paint_style("Manche 1", style), "\x1b[1;7mManche 1\x1b[0m");
+/// ```
+pub fn paint_style(text: &str, style: TextStyle) -> String {
+    if text.is_empty() || style.is_plain() {
+        return text.to_string();
+    }
+    format!("\x1b[{}m{text}{RESET}", style.sgr())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    use crate::player::Player;
+
+    /// Vérifie qu'aucune séquence d'échappement n'est produite lorsque les couleurs sont désactivées.
+    #[test]
+    fn test_no_escape_when_disabled() {
+        assert!(paint_with("Alice", Some(Color::Magenta), true).contains('\x1b'));
+        assert!(!paint_with("Alice", Some(Color::Magenta), false).contains('\x1b'));
+        assert!(!paint_with("Alice", None, true).contains('\x1b'));
+
+        set_enabled(false);
+        let player = Player::builder("Alice").color(Color::Cyan).build().unwrap();
+        assert!(!player.to_string().contains('\x1b'));
+        assert!(!player.colored_name().contains('\x1b'));
+    }
+
+    #[test]
+    fn test_color_from_str() {
+        assert_eq!("Cyan".parse::<Color>(), Ok(Color::Cyan));
+        assert!("orange".parse::<Color>().is_err());
+    }
+
+    /// Vérifie que chaque thème définit un style pour chaque rôle, et que les niveaux de la barre de
+    /// vie ainsi que les couleurs des joueurs y restent distincts.
+    #[test]
+    fn test_theme_roles() {
+        for theme in Theme::ALL {
+            for role in Role::ALL {
+                let style = theme.style(role);
+                assert!(!style.is_plain(), "{theme} {role:?}");
+                assert!(!paint_style("x", style).is_empty());
+            }
+            let levels =
+                [Role::HealthHigh, Role::HealthMedium, Role::HealthLow].map(|r| theme.style(r));
+            assert_ne!(levels[0], levels[1], "{theme}");
+            assert_ne!(levels[1], levels[2], "{theme}");
+            assert_ne!(levels[0], levels[2], "{theme}");
+        }
+        for theme in [Theme::Default, Theme::Deuteranopia] {
+            let colors: HashSet<TextStyle> = Color::ALL
+                .iter()
+                .map(|c| theme.style(Role::Player(*c)))
+                .collect();
+            assert_eq!(colors.len(), Color::ALL.len(), "{theme}");
+        }
+    }
+
+    /// Vérifie que le thème pour la deutéranopie n'utilise ni le rouge ni le vert du terminal, et que
+    /// le fort contraste n'utilise aucune teinte.
+    #[test]
+    fn test_theme_palettes() {
+        for role in Role::ALL {
+            let color = Theme::Deuteranopia.style(role).color;
+            assert!(!matches!(color, Some(1 | 2 | 9 | 10)), "{role:?}");
+            assert_eq!(Theme::HighContrast.style(role).color, None, "{role:?}");
+        }
+        assert_eq!(Theme::Default.style(Role::HealthHigh).sgr(), "32");
+        assert_eq!(
+            Theme::Deuteranopia.style(Role::HealthLow).sgr(),
+            "1;38;5;202"
+        );
+    }
+
+    /// Vérifie l'analyse des identifiants de thème.
+    #[test]
+    fn test_theme_from_str() {
+        assert_eq!("High-Contrast".parse(), Ok(Theme::HighContrast));
+        assert!("sepia".parse::<Theme>().is_err());
+        for theme in Theme::ALL {
+            assert_eq!(theme.to_string().parse(), Ok(theme));
+        }
+    }
+}
@@ -0,0 +1,508 @@
+//! Module d'entraînement des agents externes.
+//!
+//! Avant chacune des décisions d'un joueur (arrêt du compteur, choix du poison, contre-proposition),
+//! la partie décrit à son contrôleur l'état de la partie vu par ce joueur ([`GameState`], voir
+//! [`Controller::observe`]). Cet état se convertit en vecteur de caractéristiques à l'ordre stable
+//! ([`GameState::to_features`]), destiné par exemple à un agent d'apprentissage par renforcement, dont
+//! les actions possibles sont décrites par l'[`ActionSpace`].
+//!
+//! Le [`RecordingController`] enveloppe le contrôleur d'un joueur et consigne chacune de ses décisions
+//! dans un fichier JSONL : l'état observé, l'action choisie et sa récompense ([`Transition`]).
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cancel::CancelToken;
+use crate::controller::{Controller, CounterView, StopDecision};
+use crate::items::Item;
+use crate::mutators::RoundModifier;
+use crate::player::Player;
+use crate::poison::PoisonType;
+use crate::replay::StopRecord;
+use crate::risk::Risk;
+use crate::scoring::ScoringCalculator;
+
+/// Caractéristiques d'un joueur retenues dans l'état de la partie.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerState {
+    /// Vitalité restante.
+    pub vitality: u32,
+    /// Vitalité maximale.
+    pub max_vitality: u32,
+    /// Vitesse effective du compteur pour la manche, mutateur compris.
+    pub speed: u32,
+    /// Force effective pour la manche, fatigue comprise.
+    pub strength: u32,
+    /// Armure.
+    pub armor: u32,
+    /// Endurance restante.
+    pub stamina: u32,
+    /// Contre-propositions au poison encore disponibles.
+    pub counters_remaining: u32,
+    /// Nombre de poisons de vitesse subis.
+    pub speed_poisons: u32,
+    /// Nombre de poisons de force subis.
+    pub strength_poisons: u32,
+}
+
+impl PlayerState {
+    /// Retient l'état d'un joueur, avec sa vitesse et sa force effectives pour la manche.
+    pub fn new(player: &Player, speed: u32, strength: u32) -> Self {
+        let poisons =
+            |kind: PoisonType| player.poisons.iter().filter(|p| **p == kind).count() as u32;
+        PlayerState {
+            vitality: player.vitality(),
+            max_vitality: player.max_vitality(),
+            speed,
+            strength,
+            armor: player.armor,
+            stamina: player.stamina,
+            counters_remaining: player.counters_remaining,
+            speed_poisons: poisons(PoisonType::Speed),
+            strength_poisons: poisons(PoisonType::Strength),
+        }
+    }
+
+    /// Retourne les caractéristiques du joueur, dans l'ordre des champs.
+    fn features(&self) -> [f32; 9] {
+        [
+            self.vitality,
+            self.max_vitality,
+            self.speed,
+            self.strength,
+            self.armor,
+            self.stamina,
+            self.counters_remaining,
+            self.speed_poisons,
+            self.strength_poisons,
+        ]
+        .map(|value| value as f32)
+    }
+}
+
+/// État de la partie vu par un joueur au moment d'une de ses décisions.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameState {
+    /// Numéro de la manche en cours.
+    pub round: u32,
+    /// Le joueur qui décide.
+    pub me: PlayerState,
+    /// Son adversaire, `None` en solo.
+    pub opponent: Option<PlayerState>,
+    /// Identifiant du mutateur de la manche, le cas échéant.
+    pub mutator: Option<String>,
+    /// Indique si l'anti-stagnation est activée.
+    pub stalemate: bool,
+    /// Objectif visé par l'arrêt du compteur, `None` pour les autres décisions.
+    pub objective: Option<u32>,
+    /// Position de l'objectif visé dans le tour, `None` pour les autres décisions.
+    pub objective_index: Option<usize>,
+    /// « Miss » cumulés par le joueur pendant la manche, avant cette décision.
+    pub misses: u32,
+}
+
+impl GameState {
+    /// Noms des caractéristiques de [`GameState::to_features`], dans l'ordre du vecteur.
+    ///
+    /// Cet ordre est stable : une caractéristique ajoutée le sera en fin de vecteur.
+    pub const FEATURES: [&'static str; 28] = [
+        "round",
+        "vitality",
+        "max_vitality",
+        "speed",
+        "strength",
+        "armor",
+        "stamina",
+        "counters_remaining",
+        "speed_poisons",
+        "strength_poisons",
+        "opponent_vitality",
+        "opponent_max_vitality",
+        "opponent_speed",
+        "opponent_strength",
+        "opponent_armor",
+        "opponent_stamina",
+        "opponent_counters_remaining",
+        "opponent_speed_poisons",
+        "opponent_strength_poisons",
+        "mutator_fog",
+        "mutator_storm",
+        "mutator_gravity",
+        "mutator_jackpot",
+        "stalemate",
+        "has_objective",
+        "objective",
+        "objective_index",
+        "misses",
+    ];
+
+    /// Convertit l'état en vecteur de caractéristiques, dans l'ordre de [`GameState::FEATURES`].
+    ///
+    /// Les caractéristiques d'un adversaire absent valent 0 ; chaque mutateur
+    /// ([`POOL`](crate::mutators::POOL)) vaut 1 s'il s'applique à la manche et 0 sinon, de même que
+    /// l'anti-stagnation et la présence d'un objectif, sans lequel l'objectif et sa position valent 0.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use dual_game::training::GameState;
+    ///
+    /// let state = GameState { round: 3, objective: Some(42), ..GameState::default() };
+    /// let features = state.to_features();
+    /// assert_eq!(features.len(), GameState::FEATURES.len());
+    /// assert_eq!((features[0], features[24], features[25]), (3.0, 1.0, 42.0));
+    /// ```
+    pub fn to_features(&self) -> Vec<f32> {
+        let flag = |set: bool| if set { 1.0 } else { 0.0 };
+        let mut features = Vec::with_capacity(Self::FEATURES.len());
+        features.push(self.round as f32);
+        features.extend(self.me.features());
+        features.extend(self.opponent.clone().unwrap_or_default().features());
+        for mutator in crate::mutators::POOL {
+            features.push(flag(self.mutator.as_deref() == Some(mutator.id())));
+        }
+        features.push(flag(self.stalemate));
+        features.push(flag(self.objective.is_some()));
+        features.push(self.objective.unwrap_or(0) as f32);
+        features.push(self.objective_index.unwrap_or(0) as f32);
+        features.push(self.misses as f32);
+        features
+    }
+}
+
+/// Espace des actions d'un joueur, décrit pour l'entraînement.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActionSpace {
+    /// Valeur maximale du compteur à l'arrêt ([`Action::Stop`]), à partir de 0 ; chaque « miss »
+    /// ajoute un tour complet du compteur.
+    pub max_counter: u32,
+    /// Poisons proposés au vainqueur d'une manche, dans l'ordre des index de [`Action::Poison`] ; un
+    /// index hors limites n'applique aucun poison.
+    pub poisons: Vec<PoisonType>,
+    /// Réponses possibles à la contre-proposition ([`Action::Counter`]) : accepter le poison ou le
+    /// contrer.
+    pub counter: [bool; 2],
+}
+
+impl Default for ActionSpace {
+    fn default() -> Self {
+        ActionSpace {
+            max_counter: 100,
+            poisons: PoisonType::ALL.to_vec(),
+            counter: [false, true],
+        }
+    }
+}
+
+/// Décision d'un joueur.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Action {
+    /// Arrêt du compteur.
+    Stop {
+        /// Valeur du compteur à l'arrêt.
+        counter: u32,
+        /// Nombre de « miss » avant l'arrêt.
+        miss: u32,
+    },
+    /// Choix du poison, par son index parmi les options proposées.
+    Poison {
+        /// Index du poison choisi.
+        choice: usize,
+    },
+    /// Réponse à la contre-proposition au poison.
+    Counter {
+        /// Indique si le poison est contré.
+        counter: bool,
+    },
+}
+
+/// Décision consignée par le [`RecordingController`], sur une ligne du fichier JSONL.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Transition {
+    /// État de la partie observé avant la décision.
+    pub state: GameState,
+    /// Décision prise.
+    pub action: Action,
+    /// Récompense : le score de l'objectif pour un arrêt du compteur, le score de la manche (moyenne
+    /// des arrêts du joueur) pour les autres décisions.
+    pub reward: u32,
+}
+
+/// Contrôleur consignant chaque décision du contrôleur qu'il enveloppe, pour l'entraînement hors
+/// ligne.
+///
+/// Chaque décision est écrite sur une ligne JSON ([`Transition`]) ; une erreur d'écriture est
+/// seulement journalisée, sans interrompre la partie.
+pub struct RecordingController {
+    /// Contrôleur prenant les décisions.
+    inner: Box<dyn Controller>,
+    /// Destination des décisions consignées.
+    writer: Box<dyn Write>,
+    /// Dernier état observé, en attente de la décision correspondante.
+    state: Option<GameState>,
+    /// Manche des scores retenus.
+    round: u32,
+    /// Scores des arrêts du joueur pendant la manche.
+    scores: Vec<u32>,
+}
+
+impl RecordingController {
+    /// Enveloppe `inner` en consignant ses décisions dans `writer`.
+    pub fn new(inner: Box<dyn Controller>, writer: Box<dyn Write>) -> Self {
+        RecordingController {
+            inner,
+            writer,
+            state: None,
+            round: 0,
+            scores: Vec::new(),
+        }
+    }
+
+    /// Enveloppe `inner` en consignant ses décisions dans le fichier `path`, créé ou remplacé.
+    pub fn create(inner: Box<dyn Controller>, path: &Path) -> io::Result<Self> {
+        let file = File::create(path)?;
+        Ok(Self::new(inner, Box::new(BufWriter::new(file))))
+    }
+
+    /// Consigne la décision prise dans le dernier état observé, s'il y en a un.
+    fn record(&mut self, action: Action, reward: u32) {
+        let Some(state) = self.state.take() else {
+            return;
+        };
+        let transition = Transition {
+            state,
+            action,
+            reward,
+        };
+        let written = serde_json::to_string(&transition)
+            .map_err(io::Error::from)
+            .and_then(|line| writeln!(self.writer, "{line}"));
+        if let Err(err) = written {
+            log::warn!("écriture des décisions d'entraînement impossible : {err}");
+        }
+    }
+
+    /// Retourne le score de la manche en cours : la moyenne des arrêts du joueur.
+    fn round_score(&self) -> u32 {
+        ScoringCalculator::calculate_average(&self.scores)
+    }
+
+    /// Écrit sur disque les décisions consignées.
+    fn flush(&mut self) {
+        if let Err(err) = self.writer.flush() {
+            log::warn!("écriture des décisions d'entraînement impossible : {err}");
+        }
+    }
+}
+
+impl Controller for RecordingController {
+    /// Consigne l'arrêt avec le score de l'objectif, calculé comme par la partie à partir de la force
+    /// effective du joueur.
+    fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+        let stop = self.inner.stop_counter(ctx);
+        let score = ScoringCalculator::calculate_score(
+            ctx.objective,
+            stop.counter_value,
+            stop.miss,
+            ctx.strength,
+        );
+        self.scores.push(score);
+        let action = Action::Stop {
+            counter: stop.counter_value,
+            miss: stop.miss,
+        };
+        self.record(action, score);
+        stop
+    }
+
+    fn choose_poison(&mut self, options: &[PoisonType], opponent: &Player) -> usize {
+        let choice = self.inner.choose_poison(options, opponent);
+        self.record(Action::Poison { choice }, self.round_score());
+        self.flush();
+        choice
+    }
+
+    fn choose_counter(&mut self, poison: &PoisonType, extra_damage: u32, me: &Player) -> bool {
+        let counter = self.inner.choose_counter(poison, extra_damage, me);
+        self.record(Action::Counter { counter }, self.round_score());
+        self.flush();
+        counter
+    }
+
+    fn choose_item(&mut self, items: &[Item], me: &Player) -> Option<usize> {
+        self.inner.choose_item(items, me)
+    }
+
+    fn choose_risk(&mut self, options: &[Risk], me: &Player, opponent: &Player) -> usize {
+        self.inner.choose_risk(options, me, opponent)
+    }
+
+    fn choose_ban(
+        &mut self,
+        options: &[PoisonType],
+        me: &Player,
+        opponent: &Player,
+    ) -> Option<usize> {
+        self.inner.choose_ban(options, me, opponent)
+    }
+
+    fn choose_retry(&mut self, stop: &StopRecord, me: &Player) -> bool {
+        self.inner.choose_retry(stop, me)
+    }
+
+    fn choose_pick(&mut self, players: &[Player]) -> Option<usize> {
+        self.inner.choose_pick(players)
+    }
+
+    fn choose_mutator(&mut self, options: &[&'static dyn RoundModifier]) -> Option<usize> {
+        self.inner.choose_mutator(options)
+    }
+
+    /// Retient l'état en attente de la décision ; les scores retenus sont ceux de sa manche.
+    fn observe(&mut self, state: &GameState) {
+        if state.round != self.round {
+            self.round = state.round;
+            self.scores.clear();
+        }
+        self.state = Some(state.clone());
+        self.inner.observe(state);
+    }
+
+    fn acknowledge(&mut self, msg: &str) {
+        self.inner.acknowledge(msg);
+    }
+
+    fn last_pause(&self) -> Duration {
+        self.inner.last_pause()
+    }
+
+    fn displays_counter(&self) -> bool {
+        self.inner.displays_counter()
+    }
+
+    fn end_turn(&mut self) {
+        self.inner.end_turn();
+        self.flush();
+    }
+
+    fn set_cancel_token(&mut self, cancel: CancelToken) {
+        self.inner.set_cancel_token(cancel);
+    }
+
+    fn has_forfeited(&self) -> bool {
+        self.inner.has_forfeited()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::NullConsole;
+    use crate::controller::ScriptedController;
+    use crate::game::Game;
+    use crate::mutators::POOL;
+    use crate::output::{Output, Verbosity};
+
+    /// Vérifie la longueur et l'ordre du vecteur de caractéristiques.
+    #[test]
+    fn test_features_layout() {
+        let me = PlayerState {
+            vitality: 40,
+            max_vitality: 100,
+            speed: 75,
+            strength: 50,
+            armor: 3,
+            stamina: 2,
+            counters_remaining: 1,
+            speed_poisons: 2,
+            strength_poisons: 0,
+        };
+        let state = GameState {
+            round: 4,
+            me: me.clone(),
+            opponent: Some(PlayerState { vitality: 90, ..me }),
+            mutator: Some(String::from("gravity")),
+            stalemate: true,
+            objective: Some(17),
+            objective_index: Some(2),
+            misses: 5,
+        };
+        let expected = [
+            4, 40, 100, 75, 50, 3, 2, 1, 2, 0, 90, 100, 75, 50, 3, 2, 1, 2, 0, 0, 0, 1, 0, 1, 1,
+            17, 2, 5,
+        ];
+        assert_eq!(state.to_features(), expected.map(|value| value as f32));
+        let mutators: Vec<String> = POOL
+            .iter()
+            .map(|mutator| format!("mutator_{}", mutator.id()))
+            .collect();
+        assert_eq!(GameState::FEATURES[19..23], mutators);
+
+        // Sans adversaire ni objectif, les caractéristiques correspondantes valent 0.
+        let solo = GameState::default().to_features();
+        assert_eq!(solo.len(), GameState::FEATURES.len());
+        assert!(solo.iter().all(|value| *value == 0.0));
+    }
+
+    /// Joue une manche dont le premier joueur est enregistré, puis vérifie l'état observé et la
+    /// récompense de chacun de ses arrêts, puis de son choix du poison.
+    #[test]
+    fn test_recording_rewards() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("alice.jsonl");
+        let players = vec![
+            Player::new(String::from("Alice"), 100, 75, 50),
+            Player::new(String::from("Bob"), 100, 75, 50),
+        ];
+        let mut game = Game::new(players, 3);
+        game.set_output(Output::new(Box::new(NullConsole), Verbosity::Silent));
+        game.set_seed(199);
+        let alice = ScriptedController::new();
+        let bob = ScriptedController::new();
+        for offset in [0, 103, 7] {
+            alice.push_stop(StopDecision::from_offset(50, offset));
+            bob.push_stop(StopDecision::from_offset(50, 250));
+        }
+        alice.push_poison(1);
+        let recorder = RecordingController::create(Box::new(alice), &path).unwrap();
+        game.set_controller(0, Box::new(recorder));
+        game.set_controller(1, Box::new(bob));
+        game.start();
+        game.play_round().unwrap();
+
+        let transitions: Vec<Transition> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(transitions.len(), 4);
+        let stops = &game.history[0].stops;
+        for (index, (transition, stop)) in transitions.iter().zip(stops).enumerate() {
+            assert_eq!(transition.state.objective, Some(stop.objective));
+            assert_eq!(transition.state.objective_index, Some(index));
+            let misses: u32 = stops[..index].iter().map(|stop| stop.miss).sum();
+            assert_eq!(transition.state.misses, misses);
+            assert_eq!(
+                transition.action,
+                Action::Stop {
+                    counter: stop.counter,
+                    miss: stop.miss
+                }
+            );
+            assert_eq!(transition.reward, stop.score);
+        }
+        let poison = &transitions[3];
+        assert_eq!(poison.action, Action::Poison { choice: 1 });
+        assert_eq!(poison.reward, game.history[0].average);
+        assert_eq!(poison.state.objective, None);
+        assert_eq!(
+            poison.state.opponent.as_ref().unwrap().vitality,
+            100 - game.damage[0].dealt
+        );
+    }
+}
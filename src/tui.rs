@@ -0,0 +1,832 @@
+//! Module de l'interface plein écran du terminal.
+//!
+//! Avec `dual_game --tui`, chaque partie s'affiche dans un écran plein terminal : un bandeau avec la
+//! barre de vie et les effets actifs des deux joueurs, une jauge animant le compteur, un journal
+//! défilant des lignes de la partie et des fenêtres modales pour les choix. L'interface ne modifie pas
+//! la logique de la partie : elle s'y branche par ses abstractions, [`TuiConsole`] recevant l'affichage,
+//! [`TuiObserver`] les événements et [`TuiController`] les décisions du joueur au clavier.
+//!
+//! Le terminal est restauré en quittant l'écran, y compris en cas de panique. Un terminal trop petit
+//! affiche un message plutôt que l'écran de jeu, jusqu'à ce qu'il soit agrandi. Ce module n'est compilé
+//! qu'avec la fonctionnalité cargo `tui`.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{self, Stdout, Write};
+use std::rc::Rc;
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use crossterm::execute;
+use crossterm::terminal::{
+    self, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::{Color as TermColor, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Gauge, Paragraph, Wrap};
+use ratatui::{Frame, Terminal};
+
+use crate::cancel::CancelToken;
+use crate::console::{Console, InputEvent, InputQueue};
+use crate::controller::{Controller, CounterView, StopDecision};
+use crate::counter::{HIDDEN_COUNTER, slowed_delay};
+use crate::messages::Msg;
+use crate::observer::{GameEvent, GameObserver};
+use crate::player::Player;
+use crate::poison::PoisonType;
+use crate::scoring::ScorePreview;
+use crate::style::{self, Color, Role};
+use crate::tr;
+
+/// Largeur minimale du terminal, en caractères.
+pub const MIN_WIDTH: u16 = 60;
+
+/// Hauteur minimale du terminal, en lignes.
+pub const MIN_HEIGHT: u16 = 18;
+
+/// Nombre maximal de lignes conservées dans le journal.
+const LOG_CAPACITY: usize = 500;
+
+/// Délai maximal d'attente d'une touche avant de redessiner l'écran.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// État d'un joueur affiché dans le bandeau.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PlayerPanel {
+    /// Nom du joueur.
+    name: String,
+    /// Vitalité actuelle.
+    vitality: u32,
+    /// Vitalité maximale.
+    max_vitality: u32,
+    /// Couleur du nom et de la barre de vie.
+    color: Option<Color>,
+    /// Effets actifs (poisons).
+    effects: Vec<String>,
+    /// Malédiction du vainqueur : manche pendant laquelle le joueur est ralenti et points de vitesse
+    /// perdus.
+    curse: Option<(u32, u32)>,
+    /// Manches remportées.
+    wins: u32,
+    /// Score moyen du dernier tour joué.
+    average: Option<u32>,
+}
+
+/// État de la jauge du compteur.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct CounterGauge {
+    /// Objectif à atteindre.
+    objective: u32,
+    /// Valeur courante du compteur.
+    counter: u32,
+    /// Nombre de tours complets du compteur.
+    miss: u32,
+    /// Endurance restante, si la règle d'endurance est active.
+    stamina: Option<u32>,
+    /// Indique si la valeur du compteur est cachée jusqu'à son arrêt.
+    hidden: bool,
+    /// Aperçu du score obtenu en arrêtant le compteur sur sa valeur courante, le cas échéant.
+    preview: Option<ScorePreview>,
+}
+
+/// Fenêtre modale attendant une réponse du joueur.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Modal {
+    /// Choix parmi des options numérotées à partir de 1.
+    Choice {
+        /// Titre de la fenêtre.
+        title: String,
+        /// Options proposées.
+        options: Vec<String>,
+    },
+    /// Message à valider avec ENTREE.
+    Message(String),
+}
+
+/// Contenu de l'écran, dessiné à chaque changement.
+#[derive(Clone, Debug, Default)]
+pub struct Screen {
+    /// Joueurs affichés dans le bandeau.
+    players: Vec<PlayerPanel>,
+    /// Numéro de la manche en cours, une fois la partie commencée.
+    round: Option<u32>,
+    /// Jauge du compteur, pendant et après l'arrêt d'un compteur.
+    gauge: Option<CounterGauge>,
+    /// Lignes affichées par la partie, de la plus ancienne à la plus récente.
+    log: VecDeque<String>,
+    /// Fenêtre modale ouverte, le cas échéant.
+    modal: Option<Modal>,
+    /// Raccourcis clavier rappelés en bas de l'écran.
+    hint: String,
+}
+
+/// Convertit le style d'un rôle dans le thème retenu en style du terminal.
+fn term_style(role: Role) -> Style {
+    let text = style::theme().style(role);
+    let mut term = Style::default();
+    if let Some(color) = text.color {
+        term = term.fg(match color {
+            1 => TermColor::Red,
+            2 => TermColor::Green,
+            3 => TermColor::Yellow,
+            4 => TermColor::Blue,
+            5 => TermColor::Magenta,
+            6 => TermColor::Cyan,
+            index => TermColor::Indexed(index),
+        });
+    }
+    for (enabled, modifier) in [
+        (text.bold, Modifier::BOLD),
+        (text.underline, Modifier::UNDERLINED),
+        (text.reverse, Modifier::REVERSED),
+    ] {
+        if enabled {
+            term = term.add_modifier(modifier);
+        }
+    }
+    term
+}
+
+/// Retourne une zone de la taille donnée centrée dans `area`, bornée par celle-ci.
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+impl Screen {
+    /// Crée l'écran d'une partie entre les joueurs donnés.
+    pub fn new(players: &[Player]) -> Self {
+        Screen {
+            players: players
+                .iter()
+                .map(|player| PlayerPanel {
+                    name: player.name.clone(),
+                    vitality: player.vitality(),
+                    max_vitality: player.max_vitality(),
+                    color: player.color,
+                    effects: Vec::new(),
+                    curse: None,
+                    wins: 0,
+                    average: None,
+                })
+                .collect(),
+            ..Screen::default()
+        }
+    }
+
+    /// Ajoute une ligne au journal, en oubliant les plus anciennes au-delà de sa capacité.
+    fn push_log(&mut self, line: &str) {
+        if self.log.len() == LOG_CAPACITY {
+            self.log.pop_front();
+        }
+        self.log.push_back(style::strip_controls(line));
+    }
+
+    /// Retourne le panneau du joueur nommé `name`, le cas échéant.
+    fn panel(&mut self, name: &str) -> Option<&mut PlayerPanel> {
+        self.players.iter_mut().find(|panel| panel.name == name)
+    }
+
+    /// Met à jour l'écran selon un événement de la partie.
+    fn apply(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::RoundStarted { round, .. } => self.round = Some(*round),
+            GameEvent::TurnEnded { player, average } => {
+                if let Some(panel) = self.panel(player) {
+                    panel.average = Some(*average);
+                }
+            }
+            GameEvent::DamageDealt {
+                winner,
+                loser,
+                vitality,
+                ..
+            } => {
+                if let Some(panel) = self.panel(winner) {
+                    panel.wins += 1;
+                }
+                if let Some(panel) = self.panel(loser) {
+                    panel.vitality = *vitality;
+                }
+            }
+            GameEvent::PoisonCountered {
+                player: loser,
+                vitality,
+                ..
+            } => {
+                if let Some(panel) = self.panel(loser) {
+                    panel.vitality = *vitality;
+                }
+            }
+            GameEvent::PoisonApplied { player, poison } => {
+                if let Some(panel) = self.panel(player) {
+                    panel.effects.push(poison.label().to_string());
+                }
+            }
+            GameEvent::WinnersCurse {
+                player,
+                speed,
+                round,
+                ..
+            } => {
+                if let Some(panel) = self.panel(player) {
+                    panel.curse = Some((*round, *speed));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Dessine l'écran dans la zone du cadre, ou un message si le terminal est trop petit.
+    pub fn render(&self, frame: &mut Frame) {
+        let area = frame.area();
+        if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+            let message = tr!(Msg::TuiTooSmall, width = MIN_WIDTH, height = MIN_HEIGHT);
+            let paragraph = Paragraph::new(message)
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true });
+            frame.render_widget(paragraph, centered(area, area.width, 3));
+            return;
+        }
+
+        let header_height = 2 + 2 * self.players.len() as u16;
+        let [header, gauge, log, footer] = Layout::vertical([
+            Constraint::Length(header_height),
+            Constraint::Length(3),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+        self.render_header(frame, header);
+        self.render_gauge(frame, gauge);
+        self.render_log(frame, log);
+        frame.render_widget(Paragraph::new(self.hint.as_str()), footer);
+        if let Some(modal) = &self.modal {
+            Self::render_modal(frame, modal, area);
+        }
+    }
+
+    /// Dessine le bandeau : barre de vie et effets actifs de chaque joueur.
+    fn render_header(&self, frame: &mut Frame, area: Rect) {
+        let title = self
+            .round
+            .map(|round| style::strip_controls(&tr!(Msg::RoundStart, round = round)))
+            .unwrap_or_default();
+        let block = Block::default().borders(Borders::ALL).title(title);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::vertical(vec![Constraint::Length(2); self.players.len()]).split(inner);
+        for (panel, row) in self.players.iter().zip(rows.iter()) {
+            let [name, bar] =
+                Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(*row);
+            let look = term_style(Role::Player(panel.color.unwrap_or(Color::Cyan)));
+            let mut label = panel.name.clone();
+            let mut effects = panel.effects.clone();
+            if let Some((round, speed)) = panel.curse
+                && self.round == Some(round)
+            {
+                effects.push(tr!(Msg::TuiWinnersCurse, speed = speed));
+            }
+            if !effects.is_empty() {
+                label = format!("{label}  [{}]", effects.join(", "));
+            }
+            let average = panel
+                .average
+                .map_or_else(|| String::from("-"), |average| average.to_string());
+            let record = tr!(Msg::ScoreboardRecord, wins = panel.wins, average = average);
+            label = format!("{label}  {record}");
+            frame.render_widget(Paragraph::new(label).style(look), name);
+            let ratio = if panel.max_vitality == 0 {
+                0.0
+            } else {
+                f64::from(panel.vitality.min(panel.max_vitality)) / f64::from(panel.max_vitality)
+            };
+            let gauge = Gauge::default()
+                .gauge_style(look)
+                .ratio(ratio)
+                .label(format!("{}/{}", panel.vitality, panel.max_vitality));
+            frame.render_widget(gauge, bar);
+        }
+    }
+
+    /// Dessine la jauge du compteur.
+    fn render_gauge(&self, frame: &mut Frame, area: Rect) {
+        let Some(gauge) = self.gauge else {
+            frame.render_widget(Block::default().borders(Borders::ALL), area);
+            return;
+        };
+        let counter = if gauge.hidden {
+            HIDDEN_COUNTER.to_string()
+        } else {
+            gauge.counter.to_string()
+        };
+        let mut label = match gauge.stamina {
+            Some(stamina) => tr!(
+                Msg::CounterStatusStamina,
+                objective = gauge.objective,
+                miss = gauge.miss,
+                counter = counter,
+                stamina = stamina
+            ),
+            None => tr!(
+                Msg::CounterStatus,
+                objective = gauge.objective,
+                miss = gauge.miss,
+                counter = counter
+            ),
+        };
+        if let Some(preview) = gauge.preview {
+            let score = preview.score(gauge.counter, gauge.miss);
+            label += &tr!(Msg::CounterPreview, score = score);
+        }
+        let ratio = if gauge.hidden {
+            0.0
+        } else {
+            f64::from(gauge.counter.min(100)) / 100.0
+        };
+        let widget = Gauge::default()
+            .block(Block::default().borders(Borders::ALL))
+            .gauge_style(term_style(Role::Gauge))
+            .ratio(ratio)
+            .label(label);
+        frame.render_widget(widget, area);
+    }
+
+    /// Dessine les dernières lignes du journal qui tiennent dans la zone.
+    fn render_log(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(tr!(Msg::TuiLogTitle));
+        let visible = usize::from(block.inner(area).height);
+        let start = self.log.len().saturating_sub(visible);
+        let lines: Vec<Line> = self
+            .log
+            .iter()
+            .skip(start)
+            .map(|line| Line::from(line.as_str()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    /// Dessine une fenêtre modale centrée par-dessus l'écran.
+    fn render_modal(frame: &mut Frame, modal: &Modal, area: Rect) {
+        let (title, lines) = match modal {
+            Modal::Choice { title, options } => (
+                title.clone(),
+                options
+                    .iter()
+                    .enumerate()
+                    .map(|(n, option)| format!("{}: {option}", n + 1))
+                    .collect(),
+            ),
+            Modal::Message(message) => (String::new(), vec![message.clone()]),
+        };
+        let width = lines
+            .iter()
+            .chain([&title])
+            .map(|line| line.chars().count() as u16)
+            .max()
+            .unwrap_or(0)
+            .saturating_add(4);
+        let zone = centered(area, width, lines.len() as u16 + 2);
+        let text: Vec<Line> = lines.into_iter().map(Line::from).collect();
+        let block = Block::default().borders(Borders::ALL).title(title);
+        frame.render_widget(Clear, zone);
+        frame.render_widget(
+            Paragraph::new(text).block(block).wrap(Wrap { trim: true }),
+            zone,
+        );
+    }
+}
+
+/// Restaure le terminal : quitte l'écran alternatif et le mode brut.
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen, crossterm::cursor::Show);
+}
+
+/// Installe, une seule fois, un crochet de panique restaurant le terminal avant le message d'erreur.
+fn install_panic_hook() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            restore_terminal();
+            previous(info);
+        }));
+    });
+}
+
+/// Écran plein terminal d'une partie.
+pub struct Tui {
+    /// Terminal dessiné.
+    terminal: Terminal<CrosstermBackend<Stdout>>,
+    /// Contenu de l'écran.
+    screen: Screen,
+    /// Jeton d'interruption de la partie, annulé par Ctrl+C.
+    cancel: CancelToken,
+    /// Indique si l'écran est encore affiché.
+    active: bool,
+    /// File des saisies du clavier.
+    input: InputQueue,
+    /// Dimensions du terminal lors du dernier dessin.
+    size: (u16, u16),
+}
+
+/// Écran partagé entre la console, l'observateur et le contrôleur d'une partie.
+pub type SharedTui = Rc<RefCell<Tui>>;
+
+impl Tui {
+    /// Affiche l'écran d'une partie entre les joueurs donnés.
+    ///
+    /// # Arguments
+    ///
+    /// * `players` - Les joueurs de la partie.
+    /// * `cancel` - Le jeton d'interruption de la partie, annulé par Ctrl+C.
+    pub fn enter(players: &[Player], cancel: CancelToken) -> io::Result<SharedTui> {
+        install_panic_hook();
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        if let Err(err) = execute!(stdout, EnterAlternateScreen, crossterm::cursor::Hide) {
+            restore_terminal();
+            return Err(err);
+        }
+        let mut tui = Tui {
+            terminal: Terminal::new(CrosstermBackend::new(stdout))?,
+            screen: Screen::new(players),
+            cancel,
+            active: true,
+            input: InputQueue::stdin(),
+            size: terminal::size().unwrap_or_default(),
+        };
+        tui.draw();
+        Ok(Rc::new(RefCell::new(tui)))
+    }
+
+    /// Redessine l'écran.
+    fn draw(&mut self) {
+        let Tui {
+            terminal, screen, ..
+        } = self;
+        if let Err(err) = terminal.draw(|frame| screen.render(frame)) {
+            log::warn!("{err}");
+        }
+    }
+
+    /// Attend une touche pendant au plus `timeout`, en redessinant l'écran si le terminal est
+    /// redimensionné.
+    ///
+    /// Ctrl+C annule le jeton d'interruption : le mode brut l'intercepte avant le système. La fin de
+    /// l'entrée standard l'annule aussi, aucune touche ne pouvant plus arriver.
+    fn next_key(&mut self, timeout: Duration) -> Option<InputEvent> {
+        match self.input.next_event(&self.cancel, Some(timeout)) {
+            Ok(Some(InputEvent::Interrupt | InputEvent::Eof)) => {
+                self.cancel.cancel();
+                None
+            }
+            Ok(Some(key)) => Some(key),
+            Ok(None) => {
+                if let Ok(size) = terminal::size()
+                    && size != self.size
+                {
+                    self.size = size;
+                    self.draw();
+                }
+                None
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Affiche un message dans une fenêtre modale et attend ENTREE (ou l'interruption).
+    pub fn pause(&mut self, message: &str) {
+        self.screen.modal = Some(Modal::Message(style::strip_controls(message)));
+        self.screen.hint = tr!(Msg::TuiContinueHint);
+        self.draw();
+        self.input.skip_type_ahead();
+        while !self.cancel.is_cancelled() {
+            if self.next_key(POLL_INTERVAL) == Some(InputEvent::Enter) {
+                break;
+            }
+        }
+        self.screen.modal = None;
+        self.screen.hint.clear();
+        self.draw();
+    }
+
+    /// Propose des options numérotées et retourne l'index de l'option choisie, ou `None` si la partie
+    /// est interrompue.
+    fn choose(&mut self, title: String, options: Vec<String>) -> Option<usize> {
+        let count = options.len();
+        self.screen.modal = Some(Modal::Choice { title, options });
+        self.screen.hint = tr!(Msg::TuiChoiceHint, max = count);
+        self.draw();
+        self.input.skip_type_ahead();
+        let mut choice = None;
+        while !self.cancel.is_cancelled() {
+            if let Some(InputEvent::Char(c)) = self.next_key(POLL_INTERVAL)
+                && let Some(n) = c.to_digit(10)
+                && (1..=count).contains(&(n as usize))
+            {
+                choice = Some(n as usize - 1);
+                break;
+            }
+        }
+        self.screen.modal = None;
+        self.screen.hint.clear();
+        self.draw();
+        choice
+    }
+
+    /// Anime le compteur jusqu'à son arrêt par le joueur (ENTREE ou ESPACE) ou l'interruption.
+    ///
+    /// Avec la règle d'endurance, `s` dépense 1 point pour ralentir le compteur de 50 % jusqu'à la fin
+    /// de l'objectif, une fois par objectif.
+    fn run_counter(&mut self, ctx: &CounterView) -> StopDecision {
+        let mut gauge = CounterGauge {
+            objective: ctx.objective,
+            counter: 0,
+            miss: 0,
+            stamina: ctx.stamina,
+            hidden: ctx.hidden,
+            preview: ctx.score_preview(),
+        };
+        let mut delay = Duration::from_millis(u64::from(ctx.speed));
+        let mut breath_taken = false;
+        let mut next_tick = Instant::now() + delay;
+        self.screen.hint = tr!(Msg::TuiStopHint);
+        if let Some(stamina) = ctx.stamina {
+            self.screen.hint += &tr!(Msg::TuiBreathHint, stamina = stamina);
+        }
+        self.input.skip_type_ahead();
+        while !self.cancel.is_cancelled() && !ctx.cancel.is_cancelled() {
+            self.screen.gauge = Some(gauge);
+            self.draw();
+            let timeout = next_tick
+                .saturating_duration_since(Instant::now())
+                .min(POLL_INTERVAL);
+            match self.next_key(timeout) {
+                Some(InputEvent::Enter | InputEvent::Char(' ')) => break,
+                Some(InputEvent::Char('s' | 'S')) => {
+                    if let Some(stamina) = gauge.stamina.filter(|s| *s > 0 && !breath_taken) {
+                        breath_taken = true;
+                        gauge.stamina = Some(stamina - 1);
+                        delay = Duration::from_millis(u64::from(slowed_delay(ctx.speed)));
+                    }
+                }
+                _ => {}
+            }
+            while Instant::now() >= next_tick {
+                gauge.counter = (gauge.counter + 1) % 101;
+                if gauge.counter == 0 {
+                    gauge.miss += 1;
+                }
+                next_tick += delay;
+            }
+        }
+        self.screen.gauge = Some(gauge);
+        self.screen.hint.clear();
+        self.draw();
+        StopDecision {
+            counter_value: gauge.counter,
+            miss: gauge.miss,
+            stamina_spent: u32::from(breath_taken),
+        }
+    }
+
+    /// Quitte l'écran et restaure le terminal ; sans effet si c'est déjà fait.
+    pub fn leave(&mut self) {
+        if self.active {
+            self.active = false;
+            restore_terminal();
+        }
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        self.leave();
+    }
+}
+
+/// Console recopiant l'affichage de la partie dans le journal de l'écran.
+pub struct TuiConsole {
+    /// Écran de la partie.
+    tui: SharedTui,
+}
+
+impl TuiConsole {
+    /// Crée une console écrivant dans le journal de l'écran.
+    pub fn new(tui: SharedTui) -> Self {
+        TuiConsole { tui }
+    }
+}
+
+impl Console for TuiConsole {
+    /// Les sonneries des signaux sonores (`--sound`) sont transmises au terminal sans toucher l'écran.
+    fn print(&mut self, text: &str) {
+        if !text.is_empty() && text.chars().all(|c| c == '\x07') {
+            let mut stdout = io::stdout();
+            let _ = stdout.write_all(text.as_bytes());
+            let _ = stdout.flush();
+            return;
+        }
+        let mut tui = self.tui.borrow_mut();
+        for line in text.lines() {
+            tui.screen.push_log(line);
+        }
+        tui.draw();
+    }
+
+    /// Les saisies passent par le [`TuiController`].
+    fn read_line(&mut self) -> Option<String> {
+        None
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+/// Observateur tenant à jour le bandeau de l'écran.
+pub struct TuiObserver {
+    /// Écran de la partie.
+    tui: SharedTui,
+}
+
+impl TuiObserver {
+    /// Crée un observateur mettant à jour l'écran.
+    pub fn new(tui: SharedTui) -> Self {
+        TuiObserver { tui }
+    }
+}
+
+impl GameObserver for TuiObserver {
+    fn on_event(&mut self, event: &GameEvent) {
+        let mut tui = self.tui.borrow_mut();
+        tui.screen.apply(event);
+        tui.draw();
+    }
+}
+
+/// Contrôleur d'un joueur humain jouant dans l'écran plein terminal.
+pub struct TuiController {
+    /// Écran de la partie.
+    tui: SharedTui,
+}
+
+impl TuiController {
+    /// Crée un contrôleur lisant le clavier dans l'écran.
+    pub fn new(tui: SharedTui) -> Self {
+        TuiController { tui }
+    }
+}
+
+impl Controller for TuiController {
+    fn stop_counter(&mut self, ctx: &CounterView) -> StopDecision {
+        self.tui.borrow_mut().run_counter(ctx)
+    }
+
+    fn choose_poison(&mut self, options: &[PoisonType], opponent: &Player) -> usize {
+        let title = tr!(Msg::TuiPoisonTitle, opponent = opponent.name);
+        let labels = options.iter().map(|o| o.label().to_string()).collect();
+        self.tui
+            .borrow_mut()
+            .choose(title, labels)
+            .unwrap_or(usize::MAX)
+    }
+
+    fn choose_counter(&mut self, poison: &PoisonType, extra_damage: u32, me: &Player) -> bool {
+        let title = tr!(
+            Msg::CounterOffer,
+            player = me.name,
+            remaining = me.counters_remaining
+        );
+        // Les libellés du menu texte sont repris sans leur numéro, affiché par la fenêtre.
+        let strip_number = |line: String| match line.split_once(": ") {
+            Some((_, label)) => label.to_string(),
+            None => line,
+        };
+        let options = vec![
+            strip_number(tr!(Msg::AcceptPoison, poison = poison.label())),
+            strip_number(tr!(
+                Msg::CounterPoison,
+                damage = extra_damage,
+                vitality = me.vitality(),
+                remaining = me.vitality().saturating_sub(extra_damage)
+            )),
+        ];
+        self.tui.borrow_mut().choose(title, options) == Some(1)
+    }
+
+    fn acknowledge(&mut self, msg: &str) {
+        self.tui.borrow_mut().pause(msg);
+    }
+
+    fn displays_counter(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ScoringCalculator;
+    use ratatui::backend::TestBackend;
+
+    /// Dessine l'écran dans un terminal de test et retourne son contenu, ligne par ligne.
+    fn draw(screen: &Screen, width: u16, height: u16) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|frame| screen.render(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let mut text = String::new();
+        for y in 0..height {
+            for x in 0..width {
+                text.push_str(buffer[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Vérifie le bandeau, la jauge, le journal et la fenêtre modale de l'écran de jeu.
+    #[test]
+    fn test_screen_layout() {
+        let players = vec![
+            Player::new(String::from("Alice"), 50, 75, 50),
+            Player::new(String::from("Bob"), 50, 75, 50),
+        ];
+        let mut screen = Screen::new(&players);
+        screen.apply(&GameEvent::RoundStarted {
+            round: 2,
+            players: Vec::new(),
+        });
+        screen.apply(&GameEvent::TurnEnded {
+            player: String::from("Alice"),
+            average: 118,
+        });
+        screen.apply(&GameEvent::DamageDealt {
+            winner: String::from("Alice"),
+            loser: String::from("Bob"),
+            damage: 20,
+            absorbed: 0,
+            vitality: 30,
+        });
+        screen.apply(&GameEvent::PoisonApplied {
+            player: String::from("Bob"),
+            poison: PoisonType::Speed,
+        });
+        screen.apply(&GameEvent::WinnersCurse {
+            player: String::from("Alice"),
+            margin: 61,
+            speed: 3,
+            round: 2,
+        });
+        screen.push_log("\x1b[36mAlice\x1b[0m gagne la manche");
+        screen.gauge = Some(CounterGauge {
+            objective: 42,
+            counter: 40,
+            miss: 1,
+            stamina: None,
+            hidden: false,
+            preview: Some(ScoringCalculator::preview(42, 50)),
+        });
+        screen.modal = Some(Modal::Choice {
+            title: String::from("Poison"),
+            options: vec![String::from("-5 speed"), String::from("-5 strength")],
+        });
+
+        let text = draw(&screen, 80, 24);
+        assert!(text.contains("30/50"), "{text}");
+        assert!(text.contains("Bob  [-5 speed]"), "{text}");
+        assert!(
+            text.contains("Alice  [maudit : -3 vitesse]  victoires : 1  moyenne : 118"),
+            "{text}"
+        );
+        assert!(text.contains("Alice gagne la manche"), "{text}");
+        assert!(text.contains("2: -5 strength"), "{text}");
+        assert!(text.contains("si vous arrêtez ici : 65"), "{text}");
+        assert!(!text.contains('\x1b'));
+    }
+
+    /// Vérifie qu'un terminal trop petit affiche un message au lieu de l'écran de jeu.
+    #[test]
+    fn test_screen_too_small() {
+        let players = vec![Player::new(String::from("Alice"), 50, 75, 50)];
+        let screen = Screen::new(&players);
+        for (width, height) in [(40, 24), (80, 10), (1, 1)] {
+            let text = draw(&screen, width, height);
+            assert!(!text.contains("Alice"), "{text}");
+        }
+        assert!(draw(&screen, 59, 20).contains(&MIN_WIDTH.to_string()));
+    }
+}
@@ -0,0 +1,186 @@
+//! Module du serveur WebSocket destiné aux clients web.
+//!
+//! Le serveur (`dual_game serve`) parle le protocole du jeu en réseau ([`crate::net`]) : chaque
+//! [`Message`] JSON circule dans une trame texte. Un client s'annonce ([`Message::Hello`]), puis lance
+//! autant de parties contre un bot qu'il le souhaite ([`Message::StartGame`]). Il reçoit les lignes
+//! affichées et les événements de la partie, et répond aux demandes de la partie (arrêt du compteur
+//! mesuré de son côté, choix du poison) : le serveur reste seul juge des scores et des dégâts. Ce
+//! module n'est compilé qu'avec la fonctionnalité cargo `ws`.
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::io::{self, Read, Write};
+use std::net::TcpListener;
+use std::rc::Rc;
+use std::thread;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use tungstenite::{Message as Frame, WebSocket};
+
+use crate::console::NullConsole;
+use crate::controller::BotController;
+use crate::game::Game;
+use crate::net::{
+    Connection, Message, NetError, NetObserver, RemoteConsole, RemoteController, Transport,
+};
+use crate::output::{Output, Verbosity};
+use crate::player::Player;
+use crate::rules::GameRules;
+use crate::setup::DEFAULT_OBJECTIFS;
+
+/// Port d'écoute par défaut du serveur WebSocket.
+pub const DEFAULT_WS_PORT: u16 = 8080;
+
+/// Nom annoncé par le serveur aux clients ([`Message::Welcome`]).
+pub const SERVER_NAME: &str = "dual_game";
+
+/// Canal transportant chaque message dans une trame texte WebSocket.
+#[derive(Debug)]
+pub struct WsTransport<S> {
+    /// Connexion WebSocket établie.
+    socket: WebSocket<S>,
+}
+
+impl<S: Read + Write> WsTransport<S> {
+    /// Crée un canal sur une connexion WebSocket établie.
+    pub fn new(socket: WebSocket<S>) -> Self {
+        WsTransport { socket }
+    }
+}
+
+/// Traduit une erreur WebSocket ; une connexion fermée signifie que l'autre machine est partie.
+fn ws_error(err: tungstenite::Error) -> NetError {
+    match err {
+        tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed => {
+            NetError::Disconnected
+        }
+        tungstenite::Error::Io(err) => err.into(),
+        err => NetError::Io(io::Error::other(err)),
+    }
+}
+
+impl<S: Read + Write> Transport for WsTransport<S> {
+    fn send_message(&mut self, message: &Message) -> Result<(), NetError> {
+        let text = serde_json::to_string(message).expect("un message est toujours sérialisable");
+        self.socket.send(Frame::text(text)).map_err(ws_error)
+    }
+
+    /// Les trames de contrôle (ping, pong) sont traitées par la connexion et ignorées ici.
+    fn receive_message(&mut self) -> Result<Message, NetError> {
+        loop {
+            match self.socket.read().map_err(ws_error)? {
+                Frame::Text(text) => {
+                    return serde_json::from_str(&text).map_err(NetError::InvalidMessage);
+                }
+                Frame::Binary(bytes) => {
+                    return serde_json::from_slice(&bytes).map_err(NetError::InvalidMessage);
+                }
+                Frame::Close(_) => return Err(NetError::Disconnected),
+                Frame::Ping(_) | Frame::Pong(_) | Frame::Frame(_) => {}
+            }
+        }
+    }
+}
+
+/// Accepte les clients WebSocket, chacun étant servi par son propre thread.
+///
+/// # Arguments
+///
+/// * `listener` - La socket d'écoute.
+/// * `seed` - La graine des objectifs et des bots, le cas échéant.
+pub fn serve(listener: TcpListener, seed: Option<u64>) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            if let Err(err) = session(stream, seed) {
+                log::warn!("{err}");
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Sert un client jusqu'à sa déconnexion : poignée de main WebSocket, négociation du protocole, puis
+/// une partie contre un bot pour chaque [`Message::StartGame`].
+///
+/// # Arguments
+///
+/// * `stream` - La connexion du client.
+/// * `seed` - La graine des objectifs et des bots, le cas échéant.
+pub fn session<S: Read + Write + 'static>(
+    stream: S,
+    seed: Option<u64>,
+) -> Result<(), Box<dyn Error>> {
+    let socket = tungstenite::accept(stream).map_err(|err| io::Error::other(err.to_string()))?;
+    let connection = Rc::new(RefCell::new(Connection::new(WsTransport::new(socket))));
+    let name = connection.borrow_mut().accept(SERVER_NAME)?;
+
+    let mut games = 0;
+    loop {
+        let difficulty = match connection.borrow_mut().receive() {
+            Ok(Message::StartGame { difficulty }) => difficulty,
+            Ok(other) => return Err(NetError::unexpected(&other).into()),
+            Err(NetError::Disconnected) => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+        let players = vec![
+            Player::builder(name.as_str()).build()?,
+            Player::builder(format!("Bot ({difficulty})")).build()?,
+        ];
+        let mut game = Game::new_with_rules(players, DEFAULT_OBJECTIFS, GameRules::default())?;
+        let console = RemoteConsole::new(Box::new(NullConsole), Rc::clone(&connection));
+        game.set_output(Output::new(Box::new(console), Verbosity::Normal));
+        let bot = match seed.map(|seed| seed.wrapping_add(games)) {
+            Some(game_seed) => {
+                game.set_seed(game_seed);
+                BotController::with_rng(difficulty, StdRng::seed_from_u64(game_seed))
+            }
+            None => BotController::new(difficulty),
+        };
+        game.set_controller(0, Box::new(RemoteController::new(Rc::clone(&connection))));
+        game.set_controller(1, Box::new(bot));
+        game.add_observer(Box::new(NetObserver::new(Rc::clone(&connection))));
+        game.run()?;
+        if connection.borrow().is_closed() {
+            return Ok(());
+        }
+        games += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpStream;
+
+    use crate::cancel::CancelToken;
+    use crate::console::ScriptedConsole;
+    use crate::controller::{Difficulty, RandomBot};
+    use crate::net::run_client;
+
+    /// Joue deux parties scriptées contre le serveur, sur une vraie socket et un port éphémère.
+    #[test]
+    fn test_headless_client() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener, Some(3)));
+
+        let stream = TcpStream::connect(address).unwrap();
+        let (socket, _) = tungstenite::client(format!("ws://{address}/"), stream).unwrap();
+        let mut connection = Connection::new(WsTransport::new(socket));
+        assert_eq!(connection.join("Web").unwrap(), SERVER_NAME);
+
+        let mut bot = RandomBot::new(StdRng::seed_from_u64(5));
+        for difficulty in [Difficulty::Hard, Difficulty::Easy] {
+            connection.send(&Message::StartGame { difficulty }).unwrap();
+            let mut console = ScriptedConsole::default();
+            let result = run_client(&mut connection, &mut bot, &mut console, &CancelToken::new())
+                .unwrap()
+                .unwrap();
+            assert_eq!(result.players, ["Web", &format!("Bot ({difficulty})")]);
+            assert!(result.rounds > 0);
+            assert!(console.output.contains("Manche 1"), "{}", console.output);
+        }
+    }
+}
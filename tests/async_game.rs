@@ -0,0 +1,169 @@
+//! Mène deux parties asynchrones simultanées depuis une seule tâche tokio.
+#![cfg(feature = "async")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dual_game::async_game::{AsyncGame, AsyncGameError, GameIo, Prompt};
+use dual_game::pacing::Pacing;
+use dual_game::prelude::*;
+use dual_game::risk::Risk;
+
+/// Hôte d'une partie : le joueur `winner` s'arrête toujours sur l'objectif et son adversaire trois
+/// tours de compteur plus loin. Chaque décision est consignée dans un journal commun aux parties,
+/// puis rend la main à l'exécuteur avant d'être fournie.
+struct HostIo {
+    /// Nom de la partie dans le journal.
+    name: &'static str,
+    /// Index du joueur qui remporte chaque manche.
+    winner: usize,
+    /// Journal des décisions, commun aux parties.
+    log: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl GameIo for HostIo {
+    async fn stop_value(&mut self, player: PlayerId, view: &CounterView) -> StopDecision {
+        self.log.borrow_mut().push(self.name);
+        tokio::task::yield_now().await;
+        let offset = if player.index() == self.winner {
+            0
+        } else {
+            303
+        };
+        StopDecision::from_offset(view.objective, offset)
+    }
+
+    async fn prompt_choice(
+        &mut self,
+        _player: PlayerId,
+        _me: &Player,
+        prompt: Prompt<'_>,
+    ) -> usize {
+        self.log.borrow_mut().push(self.name);
+        tokio::task::yield_now().await;
+        match prompt {
+            Prompt::Poison { .. } => 0,
+            Prompt::Continue { .. } => {
+                self.log.borrow_mut().push("continue");
+                0
+            }
+            Prompt::Risk { options, .. } => {
+                options.iter().position(|risk| *risk == Risk::Safe).unwrap()
+            }
+            _ => usize::MAX,
+        }
+    }
+}
+
+/// Crée une partie silencieuse et déterministe entre Alice et Bob.
+fn game(rules: GameRules) -> Game {
+    let players = vec![
+        Player::new(String::from("Alice"), 500, 75, 50),
+        Player::new(String::from("Bob"), 500, 75, 50),
+    ];
+    let mut game = Game::new_with_rules(players, 2, rules).unwrap();
+    game.set_output(Output::new(Box::new(NullConsole), Verbosity::Silent));
+    game.set_clock(Box::new(ManualClock::new()));
+    game.set_seed(5);
+    game
+}
+
+/// Vérifie que deux parties menées par la même tâche progressent en alternance jusqu'à leur fin,
+/// chacune remportée par le joueur qui s'arrête sur l'objectif, et que les décisions demandées
+/// (poison, prise de risque) sont appliquées.
+#[tokio::test]
+async fn test_concurrent_games() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let io = |name, winner| HostIo {
+        name,
+        winner,
+        log: Rc::clone(&log),
+    };
+    let risky = GameRules {
+        risk_choice: true,
+        ..GameRules::default()
+    };
+    let mut first = AsyncGame::new(game(GameRules::default()), io("first", 0)).unwrap();
+    let mut second = AsyncGame::new(game(risky), io("second", 1)).unwrap();
+
+    let (first_result, second_result) = tokio::join!(first.run(), second.run());
+    assert_eq!(
+        first_result.unwrap(),
+        GameResult::Victory {
+            winner: PlayerId(0)
+        }
+    );
+    assert_eq!(
+        second_result.unwrap(),
+        GameResult::Victory {
+            winner: PlayerId(1)
+        }
+    );
+
+    let log = log.borrow();
+    assert_eq!(log[..2], ["first", "second"]);
+    let switches = log.windows(2).filter(|pair| pair[0] != pair[1]).count();
+    assert!(switches > 2, "{log:?}");
+
+    let first = first.game();
+    assert!(
+        first.players[1]
+            .poisons
+            .iter()
+            .all(|p| *p == PoisonType::Speed)
+    );
+    assert!(!first.players[1].poisons.is_empty());
+    let second = second.game();
+    assert!(
+        second
+            .history
+            .iter()
+            .all(|turn| turn.risk == Some(Risk::Safe))
+    );
+    assert_eq!(second.history[0].stops.len(), Risk::Safe.objectives());
+}
+
+/// Vérifie que seul le mode classique est pris en charge.
+#[test]
+fn test_unsupported_mode() {
+    let rules = GameRules {
+        mode: GameMode::Duel,
+        ..GameRules::default()
+    };
+    let io = HostIo {
+        name: "duel",
+        winner: 0,
+        log: Rc::default(),
+    };
+    let err = AsyncGame::new(game(rules), io).err().unwrap();
+    assert_eq!(err, AsyncGameError::UnsupportedMode(GameMode::Duel));
+    assert!(err.to_string().contains("duel"), "{err}");
+}
+
+/// Vérifie que la pause entre les manches est confirmée auprès de l'hôte par chaque joueur, avant
+/// chaque manche sauf la première.
+#[tokio::test]
+async fn test_pause_between_rounds() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let io = HostIo {
+        name: "paced",
+        winner: 0,
+        log: Rc::clone(&log),
+    };
+    let mut paced = game(GameRules::default());
+    paced.set_pacing(Pacing {
+        pause_between_rounds: true,
+        ..Pacing::default()
+    });
+    let mut game = AsyncGame::new(paced, io).unwrap();
+    game.run().await.unwrap();
+
+    let rounds = game.game().round - 1;
+    let continues = log
+        .borrow()
+        .iter()
+        .filter(|&&name| name == "continue")
+        .count();
+    assert!(rounds > 1);
+    assert_eq!(continues, 2 * (rounds as usize - 1));
+}
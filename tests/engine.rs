@@ -0,0 +1,34 @@
+//! Vérifie que le moteur du jeu s'utilise sans l'application en ligne de commande.
+//!
+//! Ce test n'importe que des éléments disponibles sans la fonctionnalité `cli` ; il se lance aussi
+//! avec `cargo test --no-default-features --test engine`.
+
+use dual_game::console::NullConsole;
+use dual_game::controller::{BotController, Difficulty};
+use dual_game::game::{Game, GameResult};
+use dual_game::output::{Output, Verbosity};
+use dual_game::player::Player;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Joue une partie silencieuse entre deux bots, sans terminal.
+#[test]
+fn test_engine_game_without_cli() {
+    let players = vec![
+        Player::new(String::from("Bot 1"), 30, 75, 50),
+        Player::new(String::from("Bot 2"), 30, 75, 50),
+    ];
+    let mut game = Game::new(players, 3);
+    game.set_output(Output::new(Box::new(NullConsole), Verbosity::Silent));
+    game.set_seed(7);
+    for index in 0..2 {
+        let rng = StdRng::seed_from_u64(index as u64);
+        let bot = BotController::with_rng(Difficulty::Normal, rng);
+        game.set_controller(index, Box::new(bot));
+    }
+    let result = game.run().unwrap();
+    assert!(matches!(
+        result,
+        GameResult::Victory { .. } | GameResult::Draw
+    ));
+}
@@ -0,0 +1,140 @@
+//! Compare la transcription complète d'une partie entièrement scriptée à un fichier de référence.
+//!
+//! La configuration est saisie dans l'assistant par une console scriptée, chaque arrêt du compteur et
+//! chaque choix de poison sont rejoués par des contrôleurs scriptés, et les objectifs sont tirés d'une
+//! graine fixe : la transcription ne dépend que du code du jeu. Toute modification de l'affichage ou
+//! du déroulement des manches apparaît ainsi dans le fichier de référence, relu avec le changement.
+//! Une variante rejoue la même partie avec la continuation automatique des tours (compte à rebours
+//! et pause après chaque objectif, selon une horloge manuelle).
+//!
+//! Pour régénérer le fichier après une modification voulue :
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test --test golden
+//! ```
+
+use std::cell::RefCell;
+use std::path::Path;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dual_game::clock::ManualClock;
+use dual_game::console::ScriptedConsole;
+use dual_game::controller::{ScriptedController, StopDecision};
+use dual_game::game::{Game, GameResult};
+use dual_game::messages::{Lang, set_lang};
+use dual_game::output::{Output, Verbosity};
+use dual_game::pacing::Pacing;
+use dual_game::setup::wizard;
+use dual_game::style;
+
+/// Fichier de référence de la partie scriptée.
+const GOLDEN: &str = "tests/snapshots/scripted_game.txt";
+
+/// Fichier de référence de la partie scriptée avec la continuation automatique des tours.
+const GOLDEN_AUTO: &str = "tests/snapshots/scripted_game_auto.txt";
+
+/// Valeurs auxquelles chaque joueur arrête le compteur, rejouées en boucle.
+const STOPS: [[u32; 5]; 2] = [[48, 12, 97, 60, 33], [70, 5, 51, 88, 24]];
+
+/// Nombre d'arrêts prévus pour chaque joueur, plus qu'il n'en faut pour terminer la partie.
+const SCRIPTED_STOPS: usize = 300;
+
+/// Compare le texte obtenu au fichier de référence, ou réécrit celui-ci si `UPDATE_GOLDEN` est défini.
+fn assert_golden(path: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        actual == expected,
+        "la transcription diffère de {} (UPDATE_GOLDEN=1 pour la régénérer)\n{}",
+        path.display(),
+        first_difference(&expected, actual)
+    );
+}
+
+/// Décrit la première ligne qui diffère entre le texte attendu et le texte obtenu.
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for number in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(a), Some(b)) if a == b => {}
+            (None, None) => break,
+            (a, b) => {
+                return format!(
+                    "ligne {number} :\n  attendu : {}\n  obtenu  : {}",
+                    a.unwrap_or("<fin>"),
+                    b.unwrap_or("<fin>")
+                );
+            }
+        }
+    }
+    String::from("fins de ligne différentes")
+}
+
+/// Prépare le contrôleur scripté d'un joueur : ses arrêts du compteur et ses choix de poison.
+fn scripted_player(index: usize) -> ScriptedController {
+    let controller = ScriptedController::new();
+    for stop in STOPS[index].iter().cycle().take(SCRIPTED_STOPS) {
+        controller.push_stop(StopDecision {
+            counter_value: *stop,
+            miss: 0,
+            stamina_spent: 0,
+        });
+    }
+    for choice in [0, 1, 2].iter().cycle().take(SCRIPTED_STOPS) {
+        controller.push_poison(*choice);
+    }
+    controller
+}
+
+/// Joue la partie scriptée avec la cadence donnée, de l'assistant de configuration au bilan final,
+/// et retourne sa transcription.
+fn scripted_game(pacing: Pacing) -> String {
+    set_lang(Lang::Fr);
+    style::set_enabled(false);
+    style::set_ascii(false);
+    let console = Rc::new(RefCell::new(ScriptedConsole::new([
+        "Alice", "", "Bob", "tank", "30", "3", "oui", "",
+    ])));
+    let choices = wizard(&mut Rc::clone(&console), [None, None]).unwrap();
+
+    let mut game = Game::new(choices.players, choices.objectifs);
+    game.rules = choices.rules;
+    game.set_output(Output::new(
+        Box::new(Rc::clone(&console)),
+        Verbosity::Verbose,
+    ));
+    game.set_clock(Box::new(ManualClock::new()));
+    game.set_pacing(pacing);
+    game.set_seed(163);
+    for index in 0..2 {
+        game.set_controller(index, Box::new(scripted_player(index)));
+    }
+    let result = game.run().unwrap();
+    assert!(matches!(result, GameResult::Victory { .. }));
+    console.borrow().output.clone()
+}
+
+/// Vérifie la transcription complète d'une partie scriptée, de l'assistant de configuration au
+/// bilan final.
+#[test]
+fn test_scripted_game_golden() {
+    assert_golden(GOLDEN, &scripted_game(Pacing::default()));
+}
+
+/// Vérifie la transcription de la même partie lorsque chaque tour commence après un compte à
+/// rebours plutôt qu'une attente d'ENTREE.
+#[test]
+fn test_auto_continue_golden() {
+    let pacing = Pacing {
+        auto_continue: true,
+        turn_delay: Duration::from_millis(500),
+        ..Pacing::default()
+    };
+    assert_golden(GOLDEN_AUTO, &scripted_game(pacing));
+}
@@ -0,0 +1,111 @@
+//! Vérifie les enregistrements du journal (`log`) émis aux points de décision d'une manche
+//! scriptée.
+//!
+//! Un seul enregistreur peut être installé par processus : ce fichier ne contient qu'un test, qui
+//! capture tous les enregistrements émis pendant la manche.
+
+use std::sync::Mutex;
+
+use dual_game::prelude::*;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Enregistrements capturés : niveau et message.
+static RECORDS: Mutex<Vec<(Level, String)>> = Mutex::new(Vec::new());
+
+/// Enregistreur retenant chaque enregistrement du jeu dans [`RECORDS`].
+struct CapturedLogger;
+
+impl Log for CapturedLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target().starts_with("dual_game")
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let message = record.args().to_string();
+            RECORDS.lock().unwrap().push((record.level(), message));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Retourne le message du premier enregistrement du niveau donné qui commence par `prefix`.
+fn find(records: &[(Level, String)], level: Level, prefix: &str) -> String {
+    records
+        .iter()
+        .find(|(l, message)| *l == level && message.starts_with(prefix))
+        .map(|(_, message)| message.clone())
+        .unwrap_or_else(|| panic!("aucun enregistrement {level} « {prefix} » : {records:#?}"))
+}
+
+/// Joue une manche où Alice arrête le compteur sur 50 et Bob trois tours plus loin, puis
+/// vérifie le tirage, les arrêts, le palier du barème, les dégâts, le résultat et le poison.
+#[test]
+fn test_round_decisions_logged() {
+    log::set_logger(&CapturedLogger).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+
+    let players = vec![
+        Player::new(String::from("Alice"), 100, 75, 50),
+        Player::new(String::from("Bob"), 100, 75, 50),
+    ];
+    let mut game = Game::new(players, 2);
+    game.set_output(Output::new(Box::new(NullConsole), Verbosity::Silent));
+    game.set_clock(Box::new(ManualClock::new()));
+    game.set_seed(5);
+    let alice = ScriptedController::new();
+    let bob = ScriptedController::new();
+    for _ in 0..2 {
+        alice.push_stop(StopDecision::from_offset(50, 0));
+        bob.push_stop(StopDecision::from_offset(50, 303));
+    }
+    alice.push_poison(0);
+    game.set_controller(0, Box::new(alice));
+    game.set_controller(1, Box::new(bob));
+    game.start();
+    assert_eq!(game.play_round().unwrap(), None);
+
+    let records = RECORDS.lock().unwrap().clone();
+    let draw = find(&records, Level::Debug, "tirage des objectifs player=Alice");
+    assert!(draw.contains("round=1 count=2"), "{draw}");
+    assert!(draw.contains("seed=Some(5) rng_state="), "{draw}");
+    assert!(find(&records, Level::Debug, "objectifs tirés player=Bob").contains("objectives=["));
+
+    // Le palier retenu pour le premier arrêt d'Alice correspond à son écart à l'objectif.
+    let stop = &game.history[0].stops[0];
+    let diff = ScoringCalculator::difference(stop.objective, stop.counter);
+    let base = ScoringConfig::default().base(diff);
+    let logged = find(&records, Level::Debug, "arrêt du compteur player=Alice");
+    assert!(
+        logged.starts_with(&format!(
+            "arrêt du compteur player=Alice objective={} counter=50 miss=0 strength=50 score={}",
+            stop.objective, stop.score
+        )),
+        "{logged}"
+    );
+    let tier = format!(
+        "palier du barème objective={} counter=50 diff={diff} base={base} strength=50 divisor=1 \
+         score={}",
+        stop.objective, stop.score
+    );
+    assert!(
+        records.contains(&(Level::Trace, tier.clone())),
+        "{tier} : {records:#?}"
+    );
+
+    let damage = find(&records, Level::Debug, "calcul des dégâts");
+    assert!(damage.contains("mode=Classic"), "{damage}");
+    assert!(damage.contains("mutator=none"), "{damage}");
+    let dealt = game.damage[0].dealt;
+    let result = find(&records, Level::Info, "manche remportée");
+    assert!(
+        result.starts_with(&format!(
+            "manche remportée round=1 winner=Alice loser=Bob dealt={dealt} vitality={}",
+            100 - dealt
+        )),
+        "{result}"
+    );
+    let poison = find(&records, Level::Debug, "résolution du poison target=Bob");
+    assert!(poison.contains("counter=false"), "{poison}");
+}
@@ -0,0 +1,72 @@
+//! Vérifie que le prélude suffit à créer, piloter et observer une partie.
+//!
+//! Ce test n'importe rien d'autre que `dual_game::prelude` : un élément retiré du prélude le fait
+//! échouer à la compilation. Il se lance aussi avec `cargo test --no-default-features --test prelude`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dual_game::prelude::*;
+
+/// Observateur comptant les manches terminées.
+#[derive(Default)]
+struct RoundCounter {
+    rounds: u32,
+}
+
+impl GameObserver for RoundCounter {
+    fn on_event(&mut self, event: &GameEvent) {
+        if let GameEvent::RoundEnded { .. } = event {
+            self.rounds += 1;
+        }
+    }
+}
+
+/// Joue une partie scriptée avec les seuls éléments du prélude : le joueur qui s'arrête pile sur
+/// l'objectif l'emporte, et l'observateur voit passer chaque manche.
+#[test]
+fn test_prelude_game() {
+    let players = vec![
+        Player::new(String::from("Alice"), 20, 75, 50),
+        Player::new(String::from("Bob"), 20, 75, 50),
+    ];
+    let rules = GameRules {
+        mode: GameMode::Classic,
+        ..GameRules::default()
+    };
+    let mut game = Game::new_with_rules(players, 1, rules).unwrap();
+    game.set_output(Output::new(Box::new(NullConsole), Verbosity::Silent));
+    game.set_clock(Box::new(ManualClock::new()));
+    game.set_seed(3);
+    let alice = ScriptedController::new();
+    let bob = ScriptedController::new();
+    for _ in 0..20 {
+        alice.push_stop(StopDecision::from_offset(50, 0));
+        bob.push_stop(StopDecision::from_offset(50, 303));
+        alice.push_poison(0);
+    }
+    game.set_controller(0, Box::new(alice));
+    game.set_controller(1, Box::new(bob));
+    let rounds = Rc::new(RefCell::new(RoundCounter::default()));
+    game.add_observer(Box::new(Rc::clone(&rounds)));
+
+    let result = game.run().unwrap();
+    assert_eq!(
+        result,
+        GameResult::Victory {
+            winner: PlayerId(0)
+        }
+    );
+    assert!(rounds.borrow().rounds > 0);
+    assert!(
+        game.players[1]
+            .poisons
+            .iter()
+            .all(|p| *p == PoisonType::Speed)
+    );
+    assert_eq!(ScoringCalculator::calculate_score(50, 50, 0, 50), 150);
+    assert_eq!(
+        ScoringConfig::default().score(50, 50, 0, 50),
+        ScoringCalculator::calculate_score(50, 50, 0, 50)
+    );
+}
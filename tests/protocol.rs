@@ -0,0 +1,123 @@
+//! Pilote une partie complète de `dual_game --protocol` par son entrée et sa sortie standard.
+#![cfg(feature = "cli")]
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+
+/// Analyse les champs `clé=valeur` d'un événement, sans valeur entre guillemets.
+fn fields(line: &str) -> HashMap<&str, &str> {
+    line.split_whitespace()
+        .filter_map(|word| word.split_once('='))
+        .collect()
+}
+
+/// Joue une partie où le premier joueur s'arrête toujours sur l'objectif et le second loin de lui,
+/// après une commande malformée.
+#[test]
+fn test_protocol_game() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_dual_game"))
+        .args(["--protocol", "--name1", "Alice", "--name2", "Bob"])
+        .args(["--seed", "3", "--objectifs", "2", "--vitality", "30"])
+        .env("LANG", "en_US.UTF-8")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    writeln!(stdin, "STOP 250").unwrap();
+
+    let mut events = Vec::new();
+    for line in stdout.lines() {
+        let line = line.unwrap();
+        let fields = fields(&line);
+        let reply = match line.split_whitespace().nth(1) {
+            Some("need_continue") => String::from("CONTINUE"),
+            Some("need_stop") => {
+                let objective: u32 = fields["objective"].parse().unwrap();
+                match fields["player"] {
+                    "0" => format!("STOP {objective}"),
+                    _ => format!("STOP {}", (objective + 50) % 101),
+                }
+            }
+            Some("need_poison") => String::from("POISON 1"),
+            Some("need_counter") => String::from("COUNTER no"),
+            _ => String::new(),
+        };
+        if !reply.is_empty() {
+            writeln!(stdin, "{reply}").unwrap();
+        }
+        let done = line.starts_with("EVENT game_end");
+        events.push(line);
+        if done {
+            break;
+        }
+    }
+    drop(stdin);
+    assert!(child.wait().unwrap().success());
+
+    assert_eq!(
+        events[0],
+        "EVENT game_start objectives=2 player0=Alice player1=Bob"
+    );
+    assert_eq!(events[1], "EVENT round_start 1");
+    assert!(
+        events.iter().any(|line| line.starts_with("ERROR line=1 ")),
+        "{events:#?}"
+    );
+    let end = events.last().unwrap();
+    assert!(
+        end.starts_with("EVENT game_end result=victory winner=0 "),
+        "{end}"
+    );
+    assert!(
+        events
+            .iter()
+            .all(|line| line.starts_with("EVENT ") || line.starts_with("ERROR "))
+    );
+}
+
+/// Vérifie qu'en mode miroir, les joueurs échangent leur vitesse et leur force avant la première
+/// manche : la première demande d'arrêt annonce les caractéristiques du second joueur.
+#[test]
+fn test_protocol_mirror() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_dual_game"))
+        .args([
+            "--protocol",
+            "--name1",
+            "Alice",
+            "--name2",
+            "Bob",
+            "--mirror",
+        ])
+        .args(["--speed1", "40", "--strength1", "70"])
+        .args(["--speed2", "90", "--strength2", "30"])
+        .args(["--seed", "3", "--objectifs", "2"])
+        .env("LANG", "en_US.UTF-8")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+
+    let mut request = String::new();
+    for line in stdout.lines() {
+        let line = line.unwrap();
+        if line.starts_with("EVENT need_continue ") {
+            writeln!(stdin, "CONTINUE").unwrap();
+        } else if line.starts_with("EVENT need_stop ") {
+            request = line;
+            break;
+        }
+    }
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    let fields = fields(&request);
+    assert_eq!(fields["player"], "0");
+    assert_eq!((fields["speed"], fields["strength"]), ("90", "30"));
+}
@@ -0,0 +1,114 @@
+//! Compare l'affichage de parties entre bots à des instantanés enregistrés.
+//!
+//! Les parties sont reproductibles (graine, bots et horloge manuelle), affichées en français, sans
+//! couleurs et en mode détaillé, afin de couvrir chaque ligne du rendu habituel (`PlainRenderer`)
+//! et du rendu destiné aux lecteurs d'écran (`AccessibleRenderer`).
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dual_game::cancel::CancelToken;
+use dual_game::clock::ManualClock;
+use dual_game::console::ScriptedConsole;
+use dual_game::controller::{BotController, Difficulty};
+use dual_game::game::Game;
+use dual_game::messages::{Lang, set_lang};
+use dual_game::output::{Output, Verbosity};
+use dual_game::player::Player;
+use dual_game::renderer::RenderStyle;
+use dual_game::style;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Prépare une partie reproductible entre deux bots, affichée dans la console retournée.
+fn bot_game() -> (Game, Rc<RefCell<ScriptedConsole>>) {
+    set_lang(Lang::Fr);
+    style::set_enabled(false);
+    style::set_ascii(false);
+    let players = vec![
+        Player::builder("Alice")
+            .vitality(60)
+            .armor(5)
+            .build()
+            .unwrap(),
+        Player::new(String::from("Bob"), 60, 75, 50),
+    ];
+    let console = Rc::new(RefCell::new(ScriptedConsole::default()));
+    let mut game = Game::new(players, 3);
+    game.set_output(Output::new(
+        Box::new(Rc::clone(&console)),
+        Verbosity::Verbose,
+    ));
+    game.set_clock(Box::new(ManualClock::new()));
+    game.set_seed(11);
+    for index in 0..2 {
+        let rng = StdRng::seed_from_u64(index as u64);
+        let bot = BotController::with_rng(Difficulty::Easy, rng);
+        game.set_controller(index, Box::new(bot));
+    }
+    (game, console)
+}
+
+/// Vérifie l'affichage complet d'une partie entre bots.
+#[test]
+fn test_plain_game_snapshot() {
+    let (mut game, console) = bot_game();
+    game.run().unwrap();
+    let output = console.borrow().output.clone();
+    assert_eq!(output, include_str!("snapshots/plain_game.txt"));
+}
+
+/// Vérifie l'affichage d'une partie interrompue avant sa première manche.
+#[test]
+fn test_plain_interrupted_snapshot() {
+    let (mut game, console) = bot_game();
+    let cancel = CancelToken::new();
+    cancel.cancel();
+    game.set_cancel_token(cancel);
+    game.run().unwrap();
+    let output = console.borrow().output.clone();
+    assert_eq!(output, include_str!("snapshots/plain_interrupted.txt"));
+}
+
+/// Vérifie que le rendu JSON d'une partie n'écrit que des objets JSON, du début de la partie à ses
+/// statistiques.
+#[test]
+fn test_json_game() {
+    let (mut game, console) = bot_game();
+    game.set_renderer(RenderStyle::Json.renderer());
+    game.run().unwrap();
+    let output = console.borrow().output.clone();
+    let events: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(events[0]["event"], "game_start");
+    assert_eq!(events[1]["event"], "round_start");
+    let end = &events[events.len() - 2];
+    assert_eq!(end["event"], "game_end");
+    assert_eq!(end["outcome"], "victory");
+    assert_eq!(end["winner"], "Alice");
+    let stats = events.last().unwrap();
+    assert_eq!(stats["event"], "game_stats");
+    assert_eq!(stats["players"][0]["damage_dealt"], 60);
+    assert_eq!(stats["players"][0]["highlight"]["round"], 3);
+    assert_eq!(stats["mvp"]["player"], 1);
+    assert_eq!(stats["mvp"]["stop"]["score"], 130);
+    assert_eq!(
+        stats["players"][1]["round_averages"]
+            .as_array()
+            .unwrap()
+            .len(),
+        4
+    );
+}
+
+/// Vérifie l'affichage complet d'une partie entre bots avec le rendu accessible.
+#[test]
+fn test_accessible_game_snapshot() {
+    let (mut game, console) = bot_game();
+    game.set_renderer(RenderStyle::Accessible.renderer());
+    game.run().unwrap();
+    let output = console.borrow().output.clone();
+    assert_eq!(output, include_str!("snapshots/accessible_game.txt"));
+}
@@ -0,0 +1,74 @@
+//! Vérifie par des tests de propriétés les invariants du calcul des scores.
+//!
+//! Chaque propriété est vérifiée sur des entrées tirées au hasard par `proptest`, qui réduit tout
+//! contre-exemple trouvé au cas le plus simple. Les mêmes invariants sont vérifiés par des
+//! `debug_assert!` dans [`ScoringCalculator`], de sorte qu'une simulation compilée en mode debug
+//! détecte aussi leurs régressions.
+
+use dual_game::scoring::ScoringCalculator;
+use proptest::prelude::*;
+
+/// Valeurs que peuvent prendre un objectif et le compteur.
+const VALUES: std::ops::RangeInclusive<u32> = 0..=100;
+
+proptest! {
+    /// Vérifie que l'écart ne dépend pas de l'ordre de l'objectif et du compteur.
+    #[test]
+    fn prop_difference_is_symmetric(a in VALUES, b in VALUES) {
+        prop_assert_eq!(ScoringCalculator::difference(a, b), ScoringCalculator::difference(b, a));
+    }
+
+    /// Vérifie que l'écart, compté dans le sens le plus court du cycle, ne dépasse jamais 50.
+    #[test]
+    fn prop_difference_is_at_most_half_cycle(a in VALUES, b in VALUES) {
+        prop_assert!(ScoringCalculator::difference(a, b) <= 50);
+    }
+
+    /// Vérifie qu'un arrêt plus éloigné de son objectif ne rapporte jamais plus qu'un arrêt plus
+    /// proche, à nombre de miss et force égaux, y compris pour des forces démesurées.
+    #[test]
+    fn prop_score_non_increasing_in_difference(
+        near in (VALUES, VALUES),
+        far in (VALUES, VALUES),
+        miss in 0..10u32,
+        strength in any::<u32>(),
+    ) {
+        let (near, far) = if ScoringCalculator::difference(near.0, near.1)
+            <= ScoringCalculator::difference(far.0, far.1)
+        {
+            (near, far)
+        } else {
+            (far, near)
+        };
+        prop_assert!(
+            ScoringCalculator::calculate_score(near.0, near.1, miss, strength)
+                >= ScoringCalculator::calculate_score(far.0, far.1, miss, strength)
+        );
+    }
+
+    /// Vérifie qu'un miss de plus ne fait jamais gagner de points, y compris aux limites des entiers.
+    #[test]
+    fn prop_score_non_increasing_in_miss(
+        objective in VALUES,
+        counter in VALUES,
+        miss in any::<u32>(),
+        strength in any::<u32>(),
+    ) {
+        let more = miss.saturating_add(1);
+        prop_assert!(
+            ScoringCalculator::calculate_score(objective, counter, miss, strength)
+                >= ScoringCalculator::calculate_score(objective, counter, more, strength)
+        );
+    }
+
+    /// Vérifie que la moyenne arrondie reste entre le plus petit et le plus grand score (celui-ci
+    /// augmenté de l'arrondi), sans déborder sur des scores démesurés.
+    #[test]
+    fn prop_average_within_bounds(scores in prop::collection::vec(any::<u32>(), 1..32)) {
+        let average = ScoringCalculator::calculate_average(&scores);
+        let min = *scores.iter().min().unwrap();
+        let max = *scores.iter().max().unwrap();
+        prop_assert!(average >= min);
+        prop_assert!(u64::from(average) <= u64::from(max) + 1);
+    }
+}